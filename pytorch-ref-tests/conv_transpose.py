@@ -0,0 +1,79 @@
+import json
+import os
+
+from torch import Tensor
+import torch
+import torch.nn as nn
+
+from .common import tensor_json, params_json
+
+
+def gen_conv_transpose_test_case(module: nn.Module, input: Tensor) -> dict:
+    output = module(input)
+    return {
+        "input": tensor_json(input),
+        "output": tensor_json(output),
+        "params": params_json(module),
+        "stride": list(module.stride),
+        "padding": list(module.padding),
+        "output_padding": list(module.output_padding),
+        "dilation": list(module.dilation),
+        "groups": module.groups,
+    }
+
+
+# Ensure we get the same output on every run.
+torch.manual_seed(1234)
+
+conv_transpose_1d = nn.ConvTranspose1d(
+    in_channels=4,
+    out_channels=6,
+    kernel_size=3,
+    stride=2,
+    padding=1,
+    output_padding=1,
+    groups=2,
+    dilation=2,
+)
+conv_transpose_2d = nn.ConvTranspose2d(
+    in_channels=4,
+    out_channels=6,
+    kernel_size=(3, 2),
+    stride=(2, 3),
+    padding=(1, 0),
+    output_padding=(1, 2),
+    groups=2,
+    dilation=(1, 2),
+)
+conv_transpose_2d_no_bias = nn.ConvTranspose2d(
+    in_channels=3, out_channels=2, kernel_size=3, bias=False
+)
+conv_transpose_3d = nn.ConvTranspose3d(
+    in_channels=3,
+    out_channels=4,
+    kernel_size=(2, 3, 2),
+    stride=(1, 2, 2),
+    padding=(0, 1, 1),
+    output_padding=(0, 1, 0),
+    dilation=(2, 1, 1),
+)
+
+test_cases = {
+    "__comment__": f"Generated with {os.path.basename(__file__)}",
+    "conv_transpose_1d": gen_conv_transpose_test_case(
+        conv_transpose_1d, torch.rand((1, 4, 7))
+    ),
+    "conv_transpose_2d": gen_conv_transpose_test_case(
+        conv_transpose_2d, torch.rand((2, 4, 5, 6))
+    ),
+    "conv_transpose_2d_no_bias": gen_conv_transpose_test_case(
+        conv_transpose_2d_no_bias, torch.rand((1, 3, 4, 4))
+    ),
+    "conv_transpose_3d": gen_conv_transpose_test_case(
+        conv_transpose_3d, torch.rand((1, 3, 3, 4, 5))
+    ),
+}
+
+script_dir = os.path.dirname(__file__)
+with open(f"{script_dir}/conv_transpose.json", "w") as f:
+    json.dump(test_cases, f, indent=2)
@@ -0,0 +1,527 @@
+"""
+Generate reference outputs using plain Python implementations of the
+operators, for environments where PyTorch and ONNX Runtime are not available.
+
+This writes the same test cases as `conv_transpose.py`, the `rnn_*` cases of
+`rnn.py` and `rnn_onnxruntime.py`, using the formulas from the PyTorch and
+ONNX operator documentation. Computations are done in double precision and
+the results are rounded to `f32`. Running the PyTorch and ONNX Runtime
+scripts replaces the generated files.
+
+This only uses the Python standard library.
+"""
+
+import json
+import math
+import os
+import random
+import struct
+
+
+def f32(x: float) -> float:
+    """
+    Round a Python float to the nearest `f32` value.
+    """
+    return struct.unpack("f", struct.pack("f", x))[0]
+
+
+class Array:
+    """
+    Minimal n-dimensional array of floats, stored in row-major order.
+    """
+
+    def __init__(self, shape: list[int], data: list[float] | None = None):
+        self.shape = list(shape)
+        size = math.prod(self.shape)
+        self.data = [0.0] * size if data is None else list(data)
+        assert len(self.data) == size
+
+    def strides(self) -> list[int]:
+        strides = []
+        stride = 1
+        for size in reversed(self.shape):
+            strides.insert(0, stride)
+            stride *= size
+        return strides
+
+    def offset(self, index) -> int:
+        return sum(i * s for i, s in zip(index, self.strides()))
+
+    def __getitem__(self, index) -> float:
+        return self.data[self.offset(index)]
+
+    def __setitem__(self, index, value: float):
+        self.data[self.offset(index)] = value
+
+    def item(self, i: int) -> "Array":
+        """
+        Return the sub-array at index `i` of the first axis.
+        """
+        size = math.prod(self.shape[1:])
+        return Array(self.shape[1:], self.data[i * size : (i + 1) * size])
+
+    def row(self, *index) -> list[float]:
+        """
+        Return the elements along the last axis at `index`.
+        """
+        start = self.offset(list(index) + [0])
+        return self.data[start : start + self.shape[-1]]
+
+    def json(self):
+        return [self.shape, [f32(x) for x in self.data]]
+
+
+def rand(shape: list[int], low: float = 0.0, high: float = 1.0) -> Array:
+    """
+    Create an array of uniformly distributed `f32` values.
+    """
+    size = math.prod(shape)
+    return Array(shape, [f32(rng.uniform(low, high)) for _ in range(size)])
+
+
+def dot(xs: list[float], ys: list[float]) -> float:
+    return sum(x * y for x, y in zip(xs, ys))
+
+
+def ndindex(shape: list[int]):
+    """
+    Iterate over all indices of an array with a given shape.
+    """
+    if not shape:
+        yield ()
+        return
+    for i in range(shape[0]):
+        for rest in ndindex(shape[1:]):
+            yield (i,) + rest
+
+
+def sigmoid(x: float) -> float:
+    if x >= 0:
+        return 1.0 / (1.0 + math.exp(-x))
+    e = math.exp(x)
+    return e / (1.0 + e)
+
+
+def relu(x: float) -> float:
+    return max(x, 0.0)
+
+
+# ConvTranspose
+
+
+def conv_transpose(
+    input: Array,
+    weight: Array,
+    bias: Array | None,
+    stride: list[int],
+    padding: list[int],
+    output_padding: list[int],
+    dilation: list[int],
+    groups: int,
+) -> Array:
+    """
+    Transposed convolution, as computed by PyTorch's `ConvTranspose{1,2,3}d`.
+
+    `weight` has shape `[in_channels, out_channels / groups, *kernel_size]`.
+    """
+    batch, in_chans, *in_spatial = input.shape
+    _, out_chans_per_group, *kernel = weight.shape
+    in_chans_per_group = in_chans // groups
+    out_chans = out_chans_per_group * groups
+
+    out_spatial = [
+        (size - 1) * s - 2 * p + d * (k - 1) + op + 1
+        for size, s, p, d, k, op in zip(
+            in_spatial, stride, padding, dilation, kernel, output_padding
+        )
+    ]
+    output = Array([batch, out_chans] + out_spatial)
+
+    for n in range(batch):
+        for in_c in range(in_chans):
+            group = in_c // in_chans_per_group
+            for in_pos in ndindex(in_spatial):
+                x = input[(n, in_c) + in_pos]
+                for k_pos in ndindex(kernel):
+                    out_pos = [
+                        i * s - p + k * d
+                        for i, s, p, k, d in zip(
+                            in_pos, stride, padding, k_pos, dilation
+                        )
+                    ]
+                    if any(o < 0 or o >= size for o, size in zip(out_pos, out_spatial)):
+                        continue
+                    for oc in range(out_chans_per_group):
+                        out_c = group * out_chans_per_group + oc
+                        index = (n, out_c) + tuple(out_pos)
+                        output[index] += x * weight[(in_c, oc) + k_pos]
+
+    if bias is not None:
+        for index in ndindex(output.shape):
+            output[index] += bias.data[index[1]]
+
+    return output
+
+
+def gen_conv_transpose_test_case(
+    in_channels: int,
+    out_channels: int,
+    kernel_size: list[int],
+    input_shape: list[int],
+    stride: list[int] | None = None,
+    padding: list[int] | None = None,
+    output_padding: list[int] | None = None,
+    dilation: list[int] | None = None,
+    groups: int = 1,
+    bias: bool = True,
+) -> dict:
+    ndim = len(kernel_size)
+    stride = stride or [1] * ndim
+    padding = padding or [0] * ndim
+    output_padding = output_padding or [0] * ndim
+    dilation = dilation or [1] * ndim
+
+    # Use the same parameter distribution as PyTorch's default initialization.
+    fan_in = (out_channels // groups) * math.prod(kernel_size)
+    bound = 1 / math.sqrt(fan_in)
+    params = {
+        "weight": rand(
+            [in_channels, out_channels // groups] + kernel_size, -bound, bound
+        )
+    }
+    if bias:
+        params["bias"] = rand([out_channels], -bound, bound)
+    input = rand(input_shape)
+
+    output = conv_transpose(
+        input,
+        params["weight"],
+        params.get("bias"),
+        stride,
+        padding,
+        output_padding,
+        dilation,
+        groups,
+    )
+    return {
+        "input": input.json(),
+        "output": output.json(),
+        "params": {name: param.json() for name, param in params.items()},
+        "stride": stride,
+        "padding": padding,
+        "output_padding": output_padding,
+        "dilation": dilation,
+        "groups": groups,
+    }
+
+
+# RNNs
+
+
+def rnn_direction(
+    seq: list[list[float]],
+    initial: list[float],
+    reverse: bool,
+    step,
+):
+    """
+    Run an RNN step function over a sequence in one direction.
+
+    `step` takes the input and hidden state for a step and returns the new
+    hidden state and the output for the step. Returns the output for each
+    sequence position, in sequence order.
+    """
+    outputs = [None] * len(seq)
+    state = initial
+    positions = range(len(seq) - 1, -1, -1) if reverse else range(len(seq))
+    for t in positions:
+        state, outputs[t] = step(seq[t], state)
+    return outputs
+
+
+def gen_pytorch_rnn_test_case(
+    nonlinearity: str, bidirectional: bool, with_initial: bool = False
+) -> dict:
+    """
+    Generate a case for PyTorch's `nn.RNN`, in the format used by `rnn.py`.
+
+    The input has shape `[seq, input_features]` and the output has shape
+    `[seq, directions * hidden_size]`.
+    """
+    act = {"tanh": math.tanh, "relu": relu}[nonlinearity]
+    bound = 1 / math.sqrt(hidden_size)
+
+    params = {}
+    suffixes = ["", "_reverse"] if bidirectional else [""]
+    for suffix in suffixes:
+        params[f"weight_ih_l0{suffix}"] = rand(
+            [hidden_size, input_features], -bound, bound
+        )
+        params[f"weight_hh_l0{suffix}"] = rand([hidden_size, hidden_size], -bound, bound)
+        params[f"bias_ih_l0{suffix}"] = rand([hidden_size], -bound, bound)
+        params[f"bias_hh_l0{suffix}"] = rand([hidden_size], -bound, bound)
+
+    x = rand([seq_len, input_features])
+    initial = rand([1, hidden_size]) if with_initial else None
+
+    seq = [x.row(t) for t in range(seq_len)]
+    dir_outputs = []
+    for suffix in suffixes:
+        w_ih = params[f"weight_ih_l0{suffix}"]
+        w_hh = params[f"weight_hh_l0{suffix}"]
+        b_ih = params[f"bias_ih_l0{suffix}"].data
+        b_hh = params[f"bias_hh_l0{suffix}"].data
+
+        def step(x_t, h):
+            h = [
+                act(dot(w_ih.row(j), x_t) + b_ih[j] + dot(w_hh.row(j), h) + b_hh[j])
+                for j in range(hidden_size)
+            ]
+            return h, h
+
+        h0 = initial.row(0) if initial is not None else [0.0] * hidden_size
+        dir_outputs.append(rnn_direction(seq, h0, suffix == "_reverse", step))
+
+    output = Array(
+        [seq_len, len(suffixes) * hidden_size],
+        [v for t in range(seq_len) for out in dir_outputs for v in out[t]],
+    )
+
+    case = {
+        "input": x.json(),
+        "output": output.json(),
+        "params": {name: param.json() for name, param in params.items()},
+    }
+    if initial is not None:
+        case["initial_hidden"] = initial.json()
+    return case
+
+
+def onnx_gru_step(
+    w: Array, r: Array, b: list[float], linear_before_reset: bool, clip: float | None
+):
+    """
+    Return a step function for one direction of an ONNX GRU operator.
+
+    `w` and `r` are the weights for the direction, with gates in the order
+    (update, reset, hidden).
+    """
+    hs = hidden_size
+
+    def clipped(x):
+        return x if clip is None else min(max(x, -clip), clip)
+
+    def wx(x_t, gate, j):
+        return dot(w.row(gate * hs + j), x_t) + b[gate * hs + j]
+
+    def rh(h, gate, j):
+        return dot(r.row(gate * hs + j), h) + b[(3 + gate) * hs + j]
+
+    def step(x_t, h):
+        z = [sigmoid(clipped(wx(x_t, 0, j) + rh(h, 0, j))) for j in range(hs)]
+        rg = [sigmoid(clipped(wx(x_t, 1, j) + rh(h, 1, j))) for j in range(hs)]
+        if linear_before_reset:
+            h_gate = [
+                math.tanh(clipped(wx(x_t, 2, j) + rg[j] * rh(h, 2, j)))
+                for j in range(hs)
+            ]
+        else:
+            reset_h = [rg[j] * h[j] for j in range(hs)]
+            h_gate = [
+                math.tanh(clipped(wx(x_t, 2, j) + rh(reset_h, 2, j)))
+                for j in range(hs)
+            ]
+        h = [(1 - z[j]) * h_gate[j] + z[j] * h[j] for j in range(hs)]
+        return h, h
+
+    return step
+
+
+def onnx_lstm_step(
+    w: Array, r: Array, b: list[float], p: list[float] | None, clip: float | None
+):
+    """
+    Return a step function for one direction of an ONNX LSTM operator.
+
+    `w` and `r` are the weights for the direction, with gates in the order
+    (input, output, forget, cell). `p` contains the peephole weights for the
+    input, output and forget gates.
+    """
+    hs = hidden_size
+    p = p or [0.0] * (3 * hs)
+
+    def clipped(x):
+        return x if clip is None else min(max(x, -clip), clip)
+
+    def gate(x_t, h, gate, j):
+        return (
+            dot(w.row(gate * hs + j), x_t)
+            + dot(r.row(gate * hs + j), h)
+            + b[gate * hs + j]
+            + b[(4 + gate) * hs + j]
+        )
+
+    def step(x_t, state):
+        h, c = state
+        i = [sigmoid(clipped(gate(x_t, h, 0, j) + p[j] * c[j])) for j in range(hs)]
+        f = [
+            sigmoid(clipped(gate(x_t, h, 2, j) + p[2 * hs + j] * c[j]))
+            for j in range(hs)
+        ]
+        c_gate = [math.tanh(clipped(gate(x_t, h, 3, j))) for j in range(hs)]
+        c = [f[j] * c[j] + i[j] * c_gate[j] for j in range(hs)]
+        o = [
+            sigmoid(clipped(gate(x_t, h, 1, j) + p[hs + j] * c[j])) for j in range(hs)
+        ]
+        h = [o[j] * math.tanh(clipped(c[j])) for j in range(hs)]
+        return (h, c), h
+
+    return step
+
+
+def gen_onnx_rnn_test_case(
+    op_type: str,
+    direction: str,
+    linear_before_reset: int = 0,
+    clip: float | None = None,
+    with_peephole: bool = False,
+) -> dict:
+    """
+    Generate a case for the ONNX GRU or LSTM operator, in the format used by
+    `rnn_onnxruntime.py`.
+    """
+    num_gates = {"GRU": 3, "LSTM": 4}[op_type]
+    num_directions = 2 if direction == "bidirectional" else 1
+
+    inputs = {
+        "X": rand([seq_len, batch, input_features], -1.0, 1.0),
+        "W": rand([num_directions, num_gates * hidden_size, input_features], -1.0, 1.0),
+        "R": rand([num_directions, num_gates * hidden_size, hidden_size], -1.0, 1.0),
+        "B": rand([num_directions, 2 * num_gates * hidden_size], -1.0, 1.0),
+    }
+    if with_peephole:
+        inputs["P"] = rand([num_directions, 3 * hidden_size], -1.0, 1.0)
+
+    x, w, r, b = inputs["X"], inputs["W"], inputs["R"], inputs["B"]
+    output = Array([seq_len, num_directions, batch, hidden_size])
+    for d in range(num_directions):
+        reverse = direction == "reverse" or d == 1
+        if op_type == "GRU":
+            step = onnx_gru_step(
+                w.item(d), r.item(d), b.row(d), linear_before_reset == 1, clip
+            )
+            initial = [0.0] * hidden_size
+        else:
+            p = inputs["P"].row(d) if with_peephole else None
+            step = onnx_lstm_step(w.item(d), r.item(d), b.row(d), p, clip)
+            initial = ([0.0] * hidden_size, [0.0] * hidden_size)
+
+        for n in range(batch):
+            seq = [x.row(t, n) for t in range(seq_len)]
+            for t, h in enumerate(rnn_direction(seq, initial, reverse, step)):
+                for j, value in enumerate(h):
+                    output[t, d, n, j] = value
+
+    return {
+        "op": op_type,
+        "direction": direction,
+        "linear_before_reset": linear_before_reset,
+        "clip": clip,
+        "inputs": {name: value.json() for name, value in inputs.items()},
+        "output": output.json(),
+    }
+
+
+script_name = os.path.basename(__file__)
+script_dir = os.path.dirname(__file__)
+
+# Ensure we get the same output on every run.
+rng = random.Random(1234)
+
+conv_transpose_cases = {
+    "__comment__": f"Generated with {script_name}",
+    "conv_transpose_1d": gen_conv_transpose_test_case(
+        in_channels=4,
+        out_channels=6,
+        kernel_size=[3],
+        input_shape=[1, 4, 7],
+        stride=[2],
+        padding=[1],
+        output_padding=[1],
+        groups=2,
+        dilation=[2],
+    ),
+    "conv_transpose_2d": gen_conv_transpose_test_case(
+        in_channels=4,
+        out_channels=6,
+        kernel_size=[3, 2],
+        input_shape=[2, 4, 5, 6],
+        stride=[2, 3],
+        padding=[1, 0],
+        output_padding=[1, 2],
+        groups=2,
+        dilation=[1, 2],
+    ),
+    "conv_transpose_2d_no_bias": gen_conv_transpose_test_case(
+        in_channels=3,
+        out_channels=2,
+        kernel_size=[3, 3],
+        input_shape=[1, 3, 4, 4],
+        bias=False,
+    ),
+    "conv_transpose_3d": gen_conv_transpose_test_case(
+        in_channels=3,
+        out_channels=4,
+        kernel_size=[2, 3, 2],
+        input_shape=[1, 3, 3, 4, 5],
+        stride=[1, 2, 2],
+        padding=[0, 1, 1],
+        output_padding=[0, 1, 0],
+        dilation=[2, 1, 1],
+    ),
+}
+
+input_features = 10
+hidden_size = 5
+seq_len = 7
+batch = 2
+
+rnn_cases = {
+    "rnn_tanh_forwards": gen_pytorch_rnn_test_case("tanh", bidirectional=False),
+    "rnn_tanh_initial": gen_pytorch_rnn_test_case(
+        "tanh", bidirectional=False, with_initial=True
+    ),
+    "rnn_tanh_bidirectional": gen_pytorch_rnn_test_case("tanh", bidirectional=True),
+    "rnn_relu_forwards": gen_pytorch_rnn_test_case("relu", bidirectional=False),
+    "rnn_relu_bidirectional": gen_pytorch_rnn_test_case("relu", bidirectional=True),
+}
+
+onnx_rnn_cases = {
+    "__comment__": f"Generated with {script_name}",
+    "gru_forwards": gen_onnx_rnn_test_case("GRU", "forward"),
+    "gru_bidirectional": gen_onnx_rnn_test_case("GRU", "bidirectional"),
+    "gru_clip": gen_onnx_rnn_test_case(
+        "GRU", "forward", linear_before_reset=1, clip=0.5
+    ),
+    "lstm_peephole": gen_onnx_rnn_test_case("LSTM", "forward", with_peephole=True),
+    "lstm_clip": gen_onnx_rnn_test_case("LSTM", "forward", clip=0.5),
+    "lstm_peephole_clip_bidirectional": gen_onnx_rnn_test_case(
+        "LSTM", "bidirectional", with_peephole=True, clip=0.5
+    ),
+}
+
+with open(f"{script_dir}/conv_transpose.json", "w") as f:
+    json.dump(conv_transpose_cases, f, indent=2)
+
+# Add the `rnn_*` cases to the ones generated by `rnn.py`.
+with open(f"{script_dir}/rnn.json") as f:
+    rnn_json = json.load(f)
+rnn_json["__comment__"] = (
+    f"Generated with rnn.py. The rnn_* cases were generated with {script_name}"
+)
+rnn_json.update(rnn_cases)
+with open(f"{script_dir}/rnn.json", "w") as f:
+    json.dump(rnn_json, f, indent=2)
+
+with open(f"{script_dir}/rnn_onnxruntime.json", "w") as f:
+    json.dump(onnx_rnn_cases, f, indent=2)
@@ -40,6 +40,20 @@ def gen_gru_test_case(
     return case
 
 
+def gen_rnn_test_case(
+    module: nn.Module, inputs: Tensor, initial: Tensor | None = None
+) -> dict:
+    output, last_hidden = module(x, initial)
+    case = {
+        "input": tensor_json(x),
+        "output": tensor_json(output),
+        "params": params_json(module),
+    }
+    if initial is not None:
+        case["initial_hidden"] = tensor_json(initial)
+    return case
+
+
 # Ensure we get the same output on every run.
 torch.manual_seed(1234)
 
@@ -61,6 +75,22 @@ gru_bidirectional = nn.GRU(
     input_size=input_features, hidden_size=hidden_size, bidirectional=True
 )
 
+# Modules added after this point must be created after the ones above, so that
+# the existing test cases are unchanged when the references are regenerated.
+rnn_tanh = nn.RNN(input_size=input_features, hidden_size=hidden_size)
+rnn_relu = nn.RNN(
+    input_size=input_features, hidden_size=hidden_size, nonlinearity="relu"
+)
+rnn_tanh_bidirectional = nn.RNN(
+    input_size=input_features, hidden_size=hidden_size, bidirectional=True
+)
+rnn_relu_bidirectional = nn.RNN(
+    input_size=input_features,
+    hidden_size=hidden_size,
+    nonlinearity="relu",
+    bidirectional=True,
+)
+
 test_cases = {
     "__comment__": f"Generated with {os.path.basename(__file__)}",
     "lstm_forwards": gen_lstm_test_case(lstm, x),
@@ -69,6 +99,11 @@ test_cases = {
     "gru_forwards": gen_gru_test_case(gru, x),
     "gru_bidirectional": gen_gru_test_case(gru_bidirectional, x),
     "gru_initial": gen_gru_test_case(gru, x, initial_hidden),
+    "rnn_tanh_forwards": gen_rnn_test_case(rnn_tanh, x),
+    "rnn_tanh_initial": gen_rnn_test_case(rnn_tanh, x, initial_hidden),
+    "rnn_tanh_bidirectional": gen_rnn_test_case(rnn_tanh_bidirectional, x),
+    "rnn_relu_forwards": gen_rnn_test_case(rnn_relu, x),
+    "rnn_relu_bidirectional": gen_rnn_test_case(rnn_relu_bidirectional, x),
 }
 
 script_dir = os.path.dirname(__file__)
@@ -0,0 +1,140 @@
+"""
+Generate reference outputs for RNN operator features which PyTorch does not
+implement, using ONNX Runtime.
+
+PyTorch's GRU always applies the hidden gate's linear transformation before
+the reset gate (ONNX `linear_before_reset=1`), and its LSTM has no peephole
+connections or clipping. This script builds single-operator ONNX models for
+these cases and records ONNX Runtime's output.
+
+Inputs and outputs are stored in ONNX layout, so they can be passed directly
+to RTen's operators without reordering gates.
+"""
+
+import json
+import os
+
+import numpy as np
+import onnxruntime as ort
+from onnx import TensorProto, helper
+
+
+def tensor_json(x: np.ndarray):
+    """
+    Convert an array to a JSON-serializable representation.
+    """
+    return [list(x.shape), x.flatten().tolist()]
+
+
+# Positional inputs of each operator. Optional inputs which are not used by a
+# test case are passed as empty names.
+OP_INPUTS = {
+    "GRU": ["X", "W", "R", "B", "sequence_lens", "initial_h"],
+    "LSTM": ["X", "W", "R", "B", "sequence_lens", "initial_h", "initial_c", "P"],
+}
+
+
+def gen_rnn_test_case(
+    op_type: str, inputs: dict[str, np.ndarray], direction: str, **attrs
+) -> dict:
+    hidden_size = inputs["R"].shape[-1]
+    node_inputs = [name if name in inputs else "" for name in OP_INPUTS[op_type]]
+    while node_inputs[-1] == "":
+        node_inputs.pop()
+
+    node = helper.make_node(
+        op_type,
+        node_inputs,
+        ["Y"],
+        direction=direction,
+        hidden_size=hidden_size,
+        **attrs,
+    )
+    graph = helper.make_graph(
+        [node],
+        f"{op_type.lower()}_test",
+        [
+            helper.make_tensor_value_info(name, TensorProto.FLOAT, value.shape)
+            for name, value in inputs.items()
+        ],
+        [helper.make_tensor_value_info("Y", TensorProto.FLOAT, None)],
+    )
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 14)])
+    session = ort.InferenceSession(
+        model.SerializeToString(), providers=["CPUExecutionProvider"]
+    )
+    (output,) = session.run(["Y"], inputs)
+
+    return {
+        "op": op_type,
+        "direction": direction,
+        "linear_before_reset": attrs.get("linear_before_reset", 0),
+        "clip": attrs.get("clip"),
+        "inputs": {name: tensor_json(value) for name, value in inputs.items()},
+        "output": tensor_json(output),
+    }
+
+
+def random_inputs(
+    op_type: str, direction: str, with_peephole: bool = False
+) -> dict[str, np.ndarray]:
+    num_gates = {"GRU": 3, "LSTM": 4}[op_type]
+    num_directions = 2 if direction == "bidirectional" else 1
+
+    def rand(*shape):
+        return rng.uniform(-1.0, 1.0, shape).astype(np.float32)
+
+    inputs = {
+        "X": rand(seq_len, batch, input_features),
+        "W": rand(num_directions, num_gates * hidden_size, input_features),
+        "R": rand(num_directions, num_gates * hidden_size, hidden_size),
+        "B": rand(num_directions, 2 * num_gates * hidden_size),
+    }
+    if with_peephole:
+        inputs["P"] = rand(num_directions, 3 * hidden_size)
+    return inputs
+
+
+# Ensure we get the same output on every run.
+rng = np.random.default_rng(1234)
+
+input_features = 10
+hidden_size = 5
+seq_len = 7
+batch = 2
+
+test_cases = {
+    "__comment__": f"Generated with {os.path.basename(__file__)}",
+    "gru_forwards": gen_rnn_test_case(
+        "GRU", random_inputs("GRU", "forward"), "forward", linear_before_reset=0
+    ),
+    "gru_bidirectional": gen_rnn_test_case(
+        "GRU",
+        random_inputs("GRU", "bidirectional"),
+        "bidirectional",
+        linear_before_reset=0,
+    ),
+    "gru_clip": gen_rnn_test_case(
+        "GRU",
+        random_inputs("GRU", "forward"),
+        "forward",
+        linear_before_reset=1,
+        clip=0.5,
+    ),
+    "lstm_peephole": gen_rnn_test_case(
+        "LSTM", random_inputs("LSTM", "forward", with_peephole=True), "forward"
+    ),
+    "lstm_clip": gen_rnn_test_case(
+        "LSTM", random_inputs("LSTM", "forward"), "forward", clip=0.5
+    ),
+    "lstm_peephole_clip_bidirectional": gen_rnn_test_case(
+        "LSTM",
+        random_inputs("LSTM", "bidirectional", with_peephole=True),
+        "bidirectional",
+        clip=0.5,
+    ),
+}
+
+script_dir = os.path.dirname(__file__)
+with open(f"{script_dir}/rnn_onnxruntime.json", "w") as f:
+    json.dump(test_cases, f, indent=2)
@@ -1,9 +1,14 @@
 use std::collections::{HashMap, VecDeque};
 use std::error::Error;
 use std::fs;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 
-use rten::{Dimension, Input, Model, ModelMetadata, NodeId, Output, RunOptions};
+use rten::ops::DataType;
+use rten::{
+    load_reference_dir, npy_file_stem, Dimension, Input, Model, ModelMetadata, NodeId, Output,
+    RunOptions,
+};
 use rten_tensor::prelude::*;
 use rten_tensor::Tensor;
 
@@ -19,6 +24,22 @@ struct Args {
 
     /// Map of `(input_name, dims)` with custom shapes for inputs.
     input_shapes: HashMap<String, Vec<usize>>,
+
+    /// Directory to write intermediate values to as `.npy` files.
+    dump_dir: Option<PathBuf>,
+
+    /// Only dump values whose names contain this string.
+    dump_filter: Option<String>,
+
+    /// Directory of `.npy` reference values to compare intermediate values
+    /// against.
+    reference_dir: Option<PathBuf>,
+
+    /// Maximum absolute error allowed when comparing against reference values.
+    tolerance: f32,
+
+    /// Compute output shapes and memory usage without running the model.
+    dry_run: bool,
 }
 
 /// Parse an input shape specifier in the form `input_name=dim0,dim1,...`.
@@ -49,6 +70,11 @@ fn parse_args() -> Result<Args, lexopt::Error> {
     let mut timing = false;
     let mut verbose = false;
     let mut input_shapes = HashMap::new();
+    let mut dump_dir = None;
+    let mut dump_filter = None;
+    let mut reference_dir = None;
+    let mut tolerance = 1e-4;
+    let mut dry_run = false;
 
     let mut parser = lexopt::Parser::from_env();
     while let Some(arg) = parser.next()? {
@@ -61,6 +87,11 @@ fn parse_args() -> Result<Args, lexopt::Error> {
                 let (name, shape) = parse_shape_spec(&value)?;
                 input_shapes.insert(name, shape);
             }
+            Long("dump-dir") => dump_dir = Some(parser.value()?.into()),
+            Long("dump-filter") => dump_filter = Some(parser.value()?.string()?),
+            Long("reference") => reference_dir = Some(parser.value()?.into()),
+            Long("tolerance") => tolerance = parser.value()?.parse()?,
+            Long("dry-run") => dry_run = true,
             Short('h') | Long("help") => {
                 println!(
                     "Inspect and run RTen models.
@@ -78,6 +109,27 @@ Options:
 
   -s, --shape <shape>
                  Specify shape for an input in the form `name=dim0,dim1,...`
+
+  --dump-dir <dir>
+                 Write the output of each operator to `<dir>` as a `.npy` file
+                 named after the output
+
+  --dump-filter <pattern>
+                 Only write outputs whose names contain `<pattern>` when using
+                 `--dump-dir`
+
+  --reference <dir>
+                 Compare the output of each operator against reference values
+                 in `<dir>`, stored as `.npy` files named after each output.
+                 Model inputs are also read from this directory if present.
+
+  --tolerance <tolerance>
+                 Maximum absolute error allowed when using `--reference`
+                 [default: 1e-4]
+
+  --dry-run
+                 Print the shape of each operator output and estimated memory
+                 usage, without running the model
 ",
                     bin_name = parser.bin_name().unwrap_or("rten")
                 );
@@ -94,6 +146,11 @@ Options:
         timing,
         verbose,
         input_shapes,
+        dump_dir,
+        dump_filter,
+        reference_dir,
+        tolerance,
+        dry_run,
     })
 }
 
@@ -123,17 +180,54 @@ fn print_metadata(metadata: &ModelMetadata) {
     print_field("Run URL", metadata.run_url());
 }
 
-/// Generate random inputs for `model` using shape metadata and heuristics,
-/// run it, and print details of the output.
+/// Determine the shape to use for input `name`, given the shape declared by
+/// the model.
+///
+/// If `custom_shapes` has an entry for the input, that is used. Otherwise
+/// sizes for symbolic dimensions are guessed based on their names.
+fn resolve_input_shape(
+    name: &str,
+    shape: &[Dimension],
+    custom_shapes: &HashMap<String, Vec<usize>>,
+) -> Vec<usize> {
+    if let Some(shape) = custom_shapes.get(name) {
+        return shape.clone();
+    }
+    shape
+        .iter()
+        .map(|dim| {
+            match dim {
+                // Guess a suitable size for an input dimension based on
+                // the name.
+                Dimension::Symbolic(name) => match name.as_str() {
+                    "batch" | "batch_size" => 1,
+                    "sequence" | "sequence_length" => 128,
+                    _ => 256,
+                },
+                Dimension::Fixed(size) => *size,
+            }
+        })
+        .collect()
+}
+
+/// Return the value in `provided` for the input called `name`.
+///
+/// Values are keyed by the `.npy` file stem of the input name (see
+/// [npy_file_stem]).
+fn provided_input<'a>(provided: &'a HashMap<String, Output>, name: &str) -> Option<&'a Output> {
+    provided.get(&npy_file_stem(name))
+}
+
+/// Generate inputs for `model` using shape metadata and heuristics.
 ///
 /// `custom_shapes` is a map of (input_name, dims) to use as shapes for inputs.
-/// If a shape is not specified for an input, one is generated using heuristics
-/// and the shape information specified by the model.
-fn run_with_random_input(
+/// Inputs which have an entry in `provided`, keyed by `.npy` file stem (see
+/// [npy_file_stem]), use that value instead of a randomly generated one.
+fn generate_inputs(
     model: &Model,
     custom_shapes: &HashMap<String, Vec<usize>>,
-    run_opts: RunOptions,
-) -> Result<(), Box<dyn Error>> {
+    provided: &HashMap<String, Output>,
+) -> Result<Vec<(NodeId, Output)>, Box<dyn Error>> {
     let mut rng = fastrand::Rng::new();
 
     // Generate random ints that are likely to be valid token IDs in a language
@@ -147,56 +241,61 @@ fn run_with_random_input(
         |mut inputs, id| {
             let info = model.node_info(id).ok_or("Unable to get input info")?;
             let name = info.name().unwrap_or("(unnamed input)");
+
+            if let Some(value) = provided_input(provided, name) {
+                inputs.push((id, value.clone()));
+                return Ok(inputs);
+            }
+
             let shape = info
                 .shape()
                 .ok_or(format!("Unable to get shape for input {}", name))?;
 
-            let resolved_shape = if let Some(shape) = custom_shapes.get(name) {
-                shape.clone()
-            } else {
-                shape
-                    .iter()
-                    .map(|dim| {
-                        match dim {
-                            // Guess a suitable size for an input dimension based on
-                            // the name.
-                            Dimension::Symbolic(name) => match name.as_str() {
-                                "batch" | "batch_size" => 1,
-                                "sequence" | "sequence_length" => 128,
-                                _ => 256,
-                            },
-                            Dimension::Fixed(size) => *size,
-                        }
-                    })
-                    .collect()
-            };
+            let resolved_shape = resolve_input_shape(name, &shape, custom_shapes);
 
-            // Guess suitable content for the input based on its name.
-            let tensor = match name {
+            // Guess suitable content for integer inputs based on the name.
+            let int_values = match name {
                 // If this is a mask, use all ones on the assumption that we
                 // don't want to mask anything out.
-                name if name.ends_with("_mask") => {
-                    Output::from(Tensor::full(&resolved_shape, 1i32))
-                }
+                name if name.ends_with("_mask") => Some(Tensor::full(&resolved_shape, 1i32)),
 
                 // For BERT-style models from Hugging Face, `token_type_ids`
                 // must be 0 or 1.
-                "token_type_ids" => Output::from(Tensor::<i32>::zeros(&resolved_shape)),
+                "token_type_ids" => Some(Tensor::<i32>::zeros(&resolved_shape)),
 
                 // For input names such as `input_ids`, generate some input that
                 // is likely to be a valid token ID.
                 name if name.ends_with("_ids") => {
-                    Output::from(Tensor::from_simple_fn(&resolved_shape, || {
+                    Some(Tensor::from_simple_fn(&resolved_shape, || {
                         generate_token_id(&mut rng)
                     }))
                 }
 
-                // For anything else, random floats in [0, 1].
-                //
-                // TODO - Value nodes in the model should include data types,
-                // so we can at least be sure to generate values of the correct
-                // type.
-                _ => Output::from(Tensor::from_simple_fn(&resolved_shape, || rng.f32())),
+                _ => None,
+            };
+
+            // Use the type declared by the model if available. Otherwise
+            // infer it from the name.
+            let dtype = info.dtype().unwrap_or(if int_values.is_some() {
+                DataType::Int32
+            } else {
+                DataType::Float
+            });
+
+            let tensor = if dtype == DataType::Float {
+                // Random floats in [0, 1].
+                Output::from(Tensor::from_simple_fn(&resolved_shape, || rng.f32()))
+            } else {
+                // For integer inputs without a recognized name, use random
+                // values that are valid for all integer types.
+                let values = int_values
+                    .unwrap_or_else(|| Tensor::from_simple_fn(&resolved_shape, || rng.i32(0..256)));
+                match dtype {
+                    DataType::Int64 => Output::from(values.map(|x| *x as i64)),
+                    DataType::Bool => Output::from(values.map(|x| *x != 0)),
+                    DataType::UInt8 => Output::from(values.map(|x| *x as u8)),
+                    _ => Output::from(values),
+                }
             };
 
             inputs.push((id, tensor));
@@ -205,21 +304,42 @@ fn run_with_random_input(
         },
     )?;
 
-    // Convert inputs from `Output` (owned) to `Input` (view).
-    let inputs: Vec<(NodeId, Input)> = inputs
-        .iter()
-        .map(|(id, output)| (*id, Input::from(output)))
-        .collect();
-
     for (id, input) in inputs.iter() {
         let info = model.node_info(*id);
         let name = info
             .as_ref()
             .and_then(|ni| ni.name())
             .unwrap_or("(unnamed)");
-        println!("  Input \"{name}\" generated shape {:?}", input.shape());
+        let source = if provided_input(provided, name).is_some() {
+            "provided"
+        } else {
+            "generated"
+        };
+        println!("  Input \"{name}\" {source} shape {:?}", input.shape());
     }
 
+    Ok(inputs)
+}
+
+/// Generate random inputs for `model` using shape metadata and heuristics,
+/// run it, and print details of the output.
+///
+/// `custom_shapes` is a map of (input_name, dims) to use as shapes for inputs.
+/// If a shape is not specified for an input, one is generated using heuristics
+/// and the shape information specified by the model.
+fn run_with_random_input(
+    model: &Model,
+    custom_shapes: &HashMap<String, Vec<usize>>,
+    run_opts: RunOptions,
+) -> Result<(), Box<dyn Error>> {
+    let inputs = generate_inputs(model, custom_shapes, &HashMap::new())?;
+
+    // Convert inputs from `Output` (owned) to `Input` (view).
+    let inputs: Vec<(NodeId, Input)> = inputs
+        .iter()
+        .map(|(id, output)| (*id, Input::from(output)))
+        .collect();
+
     // Run model and summarize outputs.
     let start = Instant::now();
     let outputs = model.run(&inputs, model.output_ids(), Some(run_opts))?;
@@ -248,6 +368,9 @@ fn run_with_random_input(
         let dtype = match output {
             Output::FloatTensor(_) => "f32",
             Output::IntTensor(_) => "i32",
+            Output::Int64Tensor(_) => "i64",
+            Output::BoolTensor(_) => "bool",
+            Output::UInt8Tensor(_) => "u8",
         };
         println!(
             "  Output {i} \"{name}\" data type {} shape: {:?}",
@@ -259,6 +382,79 @@ fn run_with_random_input(
     Ok(())
 }
 
+/// Propagate input shapes through `model` without running it, and print the
+/// shape of each operator output and the estimated memory usage.
+fn dry_run(
+    model: &Model,
+    custom_shapes: &HashMap<String, Vec<usize>>,
+) -> Result<(), Box<dyn Error>> {
+    let mut input_shapes: Vec<(NodeId, Vec<usize>)> = Vec::new();
+    for &id in model.input_ids() {
+        let info = model.node_info(id).ok_or("Unable to get input info")?;
+        let name = info.name().unwrap_or("(unnamed input)");
+        let shape = info
+            .shape()
+            .ok_or(format!("Unable to get shape for input {}", name))?;
+        let resolved_shape = resolve_input_shape(name, &shape, custom_shapes);
+        println!("  Input \"{name}\" shape {:?}", resolved_shape);
+        input_shapes.push((id, resolved_shape));
+    }
+    let input_shapes: Vec<(NodeId, &[usize])> = input_shapes
+        .iter()
+        .map(|(id, shape)| (*id, shape.as_slice()))
+        .collect();
+
+    let report = model.dry_run(&input_shapes)?;
+
+    println!();
+    println!("{}", report);
+
+    Ok(())
+}
+
+/// Run `model` and compare the output of each operator against reference
+/// values loaded from `reference_dir`.
+///
+/// Model inputs are read from `reference_dir` if present, or generated
+/// otherwise.
+fn compare_with_reference(
+    model: &Model,
+    custom_shapes: &HashMap<String, Vec<usize>>,
+    reference_dir: &Path,
+    tolerance: f32,
+    run_opts: RunOptions,
+) -> Result<(), Box<dyn Error>> {
+    let reference = load_reference_dir(reference_dir)?;
+    let inputs = generate_inputs(model, custom_shapes, &reference)?;
+    let inputs: Vec<(NodeId, Input)> = inputs
+        .iter()
+        .map(|(id, output)| (*id, Input::from(output)))
+        .collect();
+
+    let report = model.compare_layers(&inputs, &reference, tolerance, Some(run_opts))?;
+
+    println!();
+    if report.layers.is_empty() {
+        println!("  No operator outputs matched a reference value.");
+        return Ok(());
+    }
+    println!("{}", report);
+
+    match report.first_exceeding() {
+        Some(layer) => println!(
+            "  First output over tolerance {:e}: \"{}\" ({})",
+            tolerance, layer.name, layer.op
+        ),
+        None => println!(
+            "  All {} compared outputs are within tolerance {:e}.",
+            report.layers.len(),
+            tolerance
+        ),
+    }
+
+    Ok(())
+}
+
 /// Format an input or output shape as a `[dim0, dim1, ...]` string, where each
 /// dimension is represented by its fixed size or symbolic name.
 fn format_shape(shape: &[Dimension]) -> String {
@@ -323,16 +519,73 @@ fn main() -> Result<(), Box<dyn Error>> {
     print_metadata(model.metadata());
 
     println!();
-    println!("Running model with random inputs...");
-    run_with_random_input(
-        &model,
-        &args.input_shapes,
-        RunOptions {
-            timing: args.timing,
-            verbose: args.verbose,
-            ..Default::default()
-        },
-    )?;
+    if let Some(dump_dir) = &args.dump_dir {
+        fs::create_dir_all(dump_dir)?;
+    }
+
+    let run_opts = RunOptions {
+        timing: args.timing,
+        verbose: args.verbose,
+        dump_dir: args.dump_dir.clone(),
+        dump_filter: args.dump_filter.clone(),
+        ..Default::default()
+    };
+
+    if args.dry_run {
+        println!("Computing shapes without running model...");
+        dry_run(&model, &args.input_shapes)?;
+    } else if let Some(reference_dir) = &args.reference_dir {
+        println!("Comparing model against reference values...");
+        compare_with_reference(
+            &model,
+            &args.input_shapes,
+            reference_dir,
+            args.tolerance,
+            run_opts,
+        )?;
+    } else {
+        println!("Running model with random inputs...");
+        run_with_random_input(&model, &args.input_shapes, run_opts)?;
+    }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use rten::Output;
+    use rten_tensor::prelude::*;
+    use rten_tensor::Tensor;
+
+    use super::provided_input;
+
+    #[test]
+    fn test_provided_input() {
+        let provided = HashMap::from([
+            (
+                "input_ids".to_string(),
+                Output::from(Tensor::from([1i32, 2])),
+            ),
+            (
+                "past_key_values.0_key".to_string(),
+                Output::from(Tensor::from([0.5f32])),
+            ),
+        ]);
+
+        assert_eq!(
+            provided_input(&provided, "input_ids").map(|value| value.shape()),
+            Some([2].as_slice())
+        );
+
+        // Names are matched using their `.npy` file stem, which replaces
+        // characters that are not valid in file names.
+        assert_eq!(
+            provided_input(&provided, "past_key_values.0/key").map(|value| value.shape()),
+            Some([1].as_slice())
+        );
+
+        assert!(provided_input(&provided, "attention_mask").is_none());
+    }
+}
@@ -1,6 +1,7 @@
 #!/usr/bin/env python
 
 from argparse import ArgumentParser
+import copy
 from dataclasses import dataclass
 import hashlib
 import json
@@ -12,6 +13,7 @@ import flatbuffers
 import numpy as np
 import onnx
 import onnx.numpy_helper as numpy_helper
+import onnx.shape_inference as shape_inference
 from onnx import TensorProto, ValueInfoProto
 
 import rten_convert.schema_generated as sg
@@ -121,13 +123,17 @@ class ValueNode(Node):
     These are used for operator inputs and outputs.
 
     The shape can be missing, or a mix of fixed and symbolic (unknown at model
-    export time) sizes.
+    export time) sizes. The data type is a `sg.DataType` value, or `None` if
+    unknown or not representable in the model format.
     """
 
-    def __init__(self, name: str, shape: list[int | str] | None):
+    def __init__(
+        self, name: str, shape: list[int | str] | None, dtype: int | None = None
+    ):
         super().__init__(name)
 
         self.shape = shape
+        self.dtype = dtype
 
 
 class Graph:
@@ -172,9 +178,11 @@ class Metadata:
 # AttributeProto, you get a default value instead of an exception.
 value_fields = {
     onnx.AttributeProto.FLOAT: "f",
+    onnx.AttributeProto.FLOATS: "floats",
     onnx.AttributeProto.INT: "i",
     onnx.AttributeProto.INTS: "ints",
     onnx.AttributeProto.STRING: "s",
+    onnx.AttributeProto.STRINGS: "strings",
     onnx.AttributeProto.TENSOR: "t",
 }
 
@@ -244,6 +252,8 @@ class ONNXOperatorReader:
                 # them.
                 if expected_type == "string":
                     val = val.decode()
+                elif expected_type == "strings":
+                    val = [v.decode() for v in val]
 
                 return val
         return default
@@ -402,18 +412,6 @@ class ONNXOperatorReader:
         ]
 
 
-def check_ints_length(name: str, ints: list[int], allowed_length: int):
-    """
-    Check that an ints attribute has a fixed length.
-
-    Various ONNX operators allow for a wider range of dimensions and per-axis
-    values (eg. for strides, dilations, padding...) than this library currently
-    supports.
-    """
-    if len(ints) != allowed_length:
-        raise Exception(f'Attribute "{name}" must have {allowed_length} values')
-
-
 def constant_node_from_onnx_initializer(
     tensor: onnx.TensorProto, op_name: Optional[str]
 ) -> ConstantNode:
@@ -476,17 +474,44 @@ def constant_node_from_onnx_constant_op(onnx_op: onnx.OperatorProto) -> Constant
     return const_node
 
 
+def convert_data_type(onnx_type: int) -> int | None:
+    """
+    Convert an ONNX tensor element type to an `sg.DataType` value.
+
+    Returns `None` if the type is not supported.
+    """
+    match onnx_type:
+        case TensorProto.DataType.FLOAT:
+            return sg.DataType.Float
+        case TensorProto.DataType.INT32:
+            return sg.DataType.Int32
+        case TensorProto.DataType.INT64:
+            return sg.DataType.Int64
+        case TensorProto.DataType.BOOL:
+            return sg.DataType.Bool
+        case TensorProto.DataType.UINT8:
+            return sg.DataType.UInt8
+        case _:
+            return None
+
+
 def value_node_from_onnx_value(value: onnx.ValueInfoProto) -> ValueNode:
     if value.type.tensor_type.shape.dim:
         dims = [d.dim_param or d.dim_value for d in value.type.tensor_type.shape.dim]
     else:
         dims = None
-    return ValueNode(name=value.name, shape=dims)
+    dtype = convert_data_type(value.type.tensor_type.elem_type)
+    return ValueNode(name=value.name, shape=dims, dtype=dtype)
 
 
-def read_pads(op_reader: ONNXOperatorReader) -> tuple[str, list[int] | None]:
+def read_pads(
+    op_reader: ONNXOperatorReader, spatial_dims: int = 2
+) -> tuple[str, list[int] | None]:
     """
     Read a padding specification from an ONNX operator.
+
+    :param spatial_dims: Number of spatial dims. Used to determine the default
+      padding if the operator does not specify any.
     """
 
     pads = None
@@ -498,39 +523,161 @@ def read_pads(op_reader: ONNXOperatorReader) -> tuple[str, list[int] | None]:
             pads = []
         case "NOTSET":
             pad_mode = "fixed"
-            pads = op_reader.get_attr("pads", "ints", [0, 0, 0, 0])
-            if len(pads) not in [2, 4]:
-                raise Exception('"padding" attribute must have 2 or 4 values')
+            pads = op_reader.get_attr("pads", "ints", [0] * spatial_dims * 2)
+            if len(pads) not in [2, 4, 6]:
+                raise Exception('"padding" attribute must have 2, 4 or 6 values')
         case other:
             raise Exception(f"Unsupported auto_pad value {other}")
 
     return (pad_mode, pads)
 
 
-def read_strides(
-    op_reader: ONNXOperatorReader,
-):
+def read_strides(op_reader: ONNXOperatorReader, spatial_dims: int = 2):
     """
     Read a stride specification from an ONNX operator.
     """
-    strides = op_reader.get_attr("strides", "ints", [1, 1])
-    if len(strides) not in [1, 2]:
-        raise Exception('"strides" attribute must have 1 or 2 values')
+    strides = op_reader.get_attr("strides", "ints", [1] * spatial_dims)
+    if len(strides) not in [1, 2, 3]:
+        raise Exception('"strides" attribute must have 1, 2 or 3 values')
     return strides
 
 
-def read_dilations(
-    op_reader: ONNXOperatorReader,
-):
+def read_dilations(op_reader: ONNXOperatorReader, spatial_dims: int = 2):
     """
     Read a dilation specification from an ONNX operator.
     """
-    dilations = op_reader.get_attr("dilations", "ints", [1, 1])
-    if len(dilations) not in [1, 2]:
-        raise Exception('"dilations" attribute must have 1 or 2 values')
+    dilations = op_reader.get_attr("dilations", "ints", [1] * spatial_dims)
+    if len(dilations) not in [1, 2, 3]:
+        raise Exception('"dilations" attribute must have 1, 2 or 3 values')
     return dilations
 
 
+def read_kernel_spatial_dims(
+    op_reader: ONNXOperatorReader,
+    constant_nodes: dict[str, ConstantNode],
+    default: int | None = 2,
+) -> int | None:
+    """
+    Get the number of spatial dims of a convolution from its optional
+    `kernel_shape` attribute, or from the weights if they are a constant.
+
+    The kernel shape itself is inferred at runtime from the weight tensor, so
+    this is only used to determine defaults for other attributes. If the
+    spatial dims cannot be determined, `default` is returned.
+    """
+    kernel_shape = op_reader.get_attr("kernel_shape", "ints", None)
+    if kernel_shape:
+        return len(kernel_shape)
+
+    onnx_inputs = op_reader.onnx_op.input
+    weight = constant_nodes.get(onnx_inputs[1]) if len(onnx_inputs) > 1 else None
+    if weight is not None and len(weight.shape) > 2:
+        return len(weight.shape) - 2
+
+    return default
+
+
+def check_pool_kernel_shape(kernel_shape: list[int]):
+    """Check that a pooling operator has a 1D, 2D or 3D kernel."""
+    if len(kernel_shape) not in [1, 2, 3]:
+        raise Exception('"kernel_shape" attribute must have 1, 2 or 3 values')
+
+
+def constant_node_t_from_constant(constant: ConstantNode) -> sg.ConstantNodeT:
+    """
+    Convert a constant into the object-API type used for tensor attributes of
+    custom operators.
+
+    Raises a `ValueError` if the constant's data type is not float32 or int32.
+    """
+    node = sg.ConstantNodeT()
+    node.shape = constant.shape
+    match constant.data.dtype:
+        case np.float32:
+            node.dataType = sg.ConstantData.FloatData
+            node.data = sg.FloatDataT()
+        case np.int32:
+            node.dataType = sg.ConstantData.IntData
+            node.data = sg.IntDataT()
+        case _:
+            raise ValueError(
+                f"Unsupported tensor data type {constant.data.dtype.name} for constant {constant.name}"
+            )
+    node.data.data = constant.data.flatten().tolist()
+    return node
+
+
+def custom_attrs_from_onnx_operator(onnx_op: onnx.OperatorProto) -> sg.CustomAttrsT:
+    """
+    Generate attributes for an ONNX operator which is passed through to the
+    model as a custom operator.
+
+    The operator type, domain and all attributes with a supported type are
+    preserved. Custom operators must have an implementation registered with
+    the runtime's `OpRegistry` when the model is loaded.
+    """
+    attrs = sg.CustomAttrsT()
+    attrs.opType = onnx_op.op_type
+    attrs.domain = onnx_op.domain
+    attrs.attrs = []
+
+    for onnx_attr in onnx_op.attribute:
+        attr = sg.CustomAttrT()
+        attr.name = onnx_attr.name
+
+        match onnx_attr.type:
+            case onnx.AttributeProto.INT:
+                attr.type = sg.CustomAttrType.Int
+                attr.intValue = onnx_attr.i
+            case onnx.AttributeProto.FLOAT:
+                attr.type = sg.CustomAttrType.Float
+                attr.floatValue = onnx_attr.f
+            case onnx.AttributeProto.STRING:
+                attr.type = sg.CustomAttrType.String
+                attr.stringValue = onnx_attr.s.decode()
+            case onnx.AttributeProto.INTS:
+                attr.type = sg.CustomAttrType.Ints
+                attr.ints = list(onnx_attr.ints)
+            case onnx.AttributeProto.FLOATS:
+                attr.type = sg.CustomAttrType.Floats
+                attr.floats = list(onnx_attr.floats)
+            case onnx.AttributeProto.TENSOR:
+                try:
+                    constant = constant_node_from_onnx_initializer(
+                        onnx_attr.t, onnx_op.name
+                    )
+                    attr.tensor = constant_node_t_from_constant(constant)
+                except ValueError as err:
+                    warn_once(
+                        f"WARNING: Unsupported attribute {onnx_attr.name} for custom operator {onnx_op.op_type}: {err}"
+                    )
+                    continue
+                attr.type = sg.CustomAttrType.Tensor
+            case _:
+                warn_once(
+                    f"WARNING: Unsupported attribute {onnx_attr.name} for custom operator {onnx_op.op_type}"
+                )
+                continue
+
+        attrs.attrs.append(attr)
+
+    return attrs
+
+
+CONTRIB_OPS_TO_BUILTIN = {
+    "FastGelu",
+    "Gelu",
+    "MultiHeadAttention",
+    "SimplifiedLayerNormalization",
+}
+"""
+ONNX Runtime contrib operators which are converted to built-in operators.
+
+Most contrib operators are in the `com.microsoft` domain, but some are
+registered in the default ONNX domain.
+"""
+
+
 def op_node_from_onnx_operator(
     onnx_op: onnx.OperatorProto,
     node_index_from_name: dict[str, int],
@@ -583,6 +730,29 @@ def op_node_from_onnx_operator(
     # Operator type name in RTen models. By default assume this is the same as
     # the ONNX type.
     op_type = onnx_op.op_type
+
+    # ONNX Runtime contrib operators which map to built-in operators.
+    is_contrib_op = (
+        onnx_op.domain in ["", "ai.onnx", "com.microsoft"]
+        and op_type in CONTRIB_OPS_TO_BUILTIN
+    )
+
+    # Operators which are not built in to RTen, or which belong to a domain
+    # other than the standard ONNX one, are passed through as custom operators.
+    if not is_contrib_op and (
+        onnx_op.domain not in ["", "ai.onnx"] or not hasattr(sg.OperatorType, op_type)
+    ):
+        warn_once(
+            f"WARNING: Converting unsupported operator {op_type} to a custom operator"
+        )
+        return OperatorNode(
+            name=onnx_op.name,
+            op_type="Custom",
+            attrs=custom_attrs_from_onnx_operator(onnx_op),
+            inputs=input_indexes,
+            outputs=output_indexes,
+        )
+
     op_reader = ONNXOperatorReader(onnx_op, input_indexes, add_node)
 
     # Check / convert operator attributes and operator name, if different than
@@ -594,11 +764,28 @@ def op_node_from_onnx_operator(
             attrs.keepDims = bool(op_reader.get_attr("keepdims", "int", 1))
             op_reader.check_attr("select_last_index", "int", 0)
 
+        case "Attention":
+            attrs = sg.AttentionAttrsT()
+            attrs.numHeads = op_reader.get_attr("q_num_heads", "int", 0)
+            attrs.kvNumHeads = op_reader.get_attr("kv_num_heads", "int", 0)
+            attrs.isCausal = op_reader.get_bool_attr("is_causal", False)
+            attrs.scale = op_reader.get_attr("scale", "float", None)
+
+            op_reader.check_attr("qk_matmul_output_mode", "int", 0)
+            op_reader.check_attr("softcap", "float", 0.0)
+            op_reader.ignore_attr("softmax_precision")
+
+            if any(index is not None for index in input_indexes[4:]):
+                raise Exception("past_key and past_value inputs are not supported")
+            if len(output_indexes) > 1:
+                raise Exception(
+                    "present_key and present_value outputs are not supported"
+                )
+
         case "AveragePool":
             kernel_shape = op_reader.require_attr("kernel_shape", "ints")
-            check_ints_length("kernel_shape", kernel_shape, 2)
-            pad_mode, pads = read_pads(op_reader)
-            op_reader.check_attr("ceil_mode", "int", 0)
+            check_pool_kernel_shape(kernel_shape)
+            pad_mode, pads = read_pads(op_reader, len(kernel_shape))
 
             attrs = sg.AveragePoolAttrsT()
             attrs.kernelSize = kernel_shape
@@ -608,7 +795,9 @@ def op_node_from_onnx_operator(
                 attrs.padMode = sg.PadMode.Same
             else:
                 attrs.padMode = sg.PadMode.Fixed
-            attrs.strides = read_strides(op_reader)
+            attrs.strides = read_strides(op_reader, len(kernel_shape))
+            attrs.dilations = read_dilations(op_reader, len(kernel_shape))
+            attrs.ceilMode = op_reader.get_bool_attr("ceil_mode", False)
             attrs.countIncludePad = op_reader.get_bool_attr("count_include_pad", False)
 
         case "BatchNormalization":
@@ -623,17 +812,14 @@ def op_node_from_onnx_operator(
         case "Cast":
             attrs = sg.CastAttrsT()
             to = op_reader.get_attr("to", "int", TensorProto.DataType.FLOAT)
-            match to:
-                case TensorProto.DataType.FLOAT:
-                    attrs.to = sg.DataType.Float
-                case (
-                    TensorProto.DataType.BOOL
-                    | TensorProto.DataType.INT32
-                    | TensorProto.DataType.INT64
-                ):
-                    attrs.to = sg.DataType.Int32
-                case _:
-                    raise Exception(f"Unsupported target type for cast {to}")
+            to_type = convert_data_type(to)
+            if to_type is None:
+                raise Exception(f"Unsupported target type for cast {to}")
+            attrs.to = to_type
+
+        case "Celu":
+            attrs = sg.LeakyReluAttrsT()
+            attrs.alpha = op_reader.get_attr("alpha", "float", 1.0)
 
         case "Clip":
             op_reader.generate_input_from_attr(1, "min", "float")
@@ -670,34 +856,58 @@ def op_node_from_onnx_operator(
             attrs.value = scalar
 
         case "Conv":
+            spatial_dims = read_kernel_spatial_dims(op_reader, constant_nodes)
+
             attrs = sg.ConvAttrsT()
-            attrs.dilations = read_dilations(op_reader)
+            attrs.dilations = read_dilations(op_reader, spatial_dims)
             attrs.groups = op_reader.get_attr("group", "int", 1)
 
-            pad_mode, pads = read_pads(op_reader)
+            pad_mode, pads = read_pads(op_reader, spatial_dims)
             if pad_mode == "same":
                 attrs.padMode = sg.PadMode.Same
             else:
                 attrs.padMode = sg.PadMode.Fixed
                 attrs.pads = pads
-            attrs.strides = read_strides(op_reader)
-
-            # The kernel shape is inferred at runtime from the input weight tensor.
-            op_reader.ignore_attr("kernel_shape")
+            attrs.strides = read_strides(op_reader, spatial_dims)
 
         case "ConvTranspose":
+            # `output_shape` may specify only the spatial dims, or the full
+            # output shape including the batch and channel dims.
+            output_shape = op_reader.get_attr("output_shape", "ints", None)
+            spatial_dims = read_kernel_spatial_dims(
+                op_reader, constant_nodes, default=None
+            )
+            if output_shape and spatial_dims is None:
+                # A kernel has at most 3 spatial dims, so a longer shape must
+                # include the batch and channel dims. Shorter shapes are
+                # assumed to include only the spatial dims.
+                n = len(output_shape)
+                spatial_dims = n - 2 if n > 3 else n
+            if spatial_dims is None:
+                spatial_dims = 2
+
             attrs = sg.ConvTransposeAttrsT()
-            attrs.strides = read_strides(op_reader)
+            attrs.dilations = read_dilations(op_reader, spatial_dims)
+            attrs.groups = op_reader.get_attr("group", "int", 1)
+            attrs.strides = read_strides(op_reader, spatial_dims)
 
-            op_reader.check_attr("auto_pad", "string", "NOTSET")
-            op_reader.check_attr("dilations", "ints", ([1], [1, 1]))
-            op_reader.check_attr("group", "int", 1)
+            pad_mode, pads = read_pads(op_reader, spatial_dims)
+            if pad_mode == "same":
+                attrs.padMode = sg.PadMode.Same
+            else:
+                attrs.padMode = sg.PadMode.Fixed
+                attrs.pads = pads
 
-            # The kernel shape is inferred at runtime from the input weight tensor.
-            op_reader.ignore_attr("kernel_shape")
+            output_padding = op_reader.get_attr("output_padding", "ints", None)
+            if output_padding:
+                attrs.outputPadding = output_padding
 
-            op_reader.check_attr("output_padding", "ints", [0, 0, 0, 0])
-            op_reader.check_attr("pads", "ints", [0, 0, 0, 0])
+            if output_shape:
+                if len(output_shape) not in [spatial_dims, spatial_dims + 2]:
+                    raise Exception(
+                        f'"output_shape" must have {spatial_dims} or {spatial_dims + 2} values'
+                    )
+                attrs.outputShape = output_shape[-spatial_dims:]
 
         case "CumSum":
             op_reader.check_attr("exclusive", "int", 0)
@@ -707,10 +917,33 @@ def op_node_from_onnx_operator(
             attrs = sg.FlattenAttrsT()
             attrs.axis = op_reader.get_attr("axis", "int", 1)
 
+        case "Elu":
+            attrs = sg.LeakyReluAttrsT()
+            attrs.alpha = op_reader.get_attr("alpha", "float", 1.0)
+
+        case "FastGelu":
+            op_type = "Gelu"
+            attrs = sg.GeluAttrsT()
+            attrs.approximate = True
+
+            if len(input_indexes) > 1 and input_indexes[1] is not None:
+                raise Exception("FastGelu bias input is not supported")
+
         case "Gather" | "GatherElements":
             attrs = sg.GatherAttrsT()
             attrs.axis = op_reader.get_attr("axis", "int", 0)
 
+        case "Gelu":
+            attrs = sg.GeluAttrsT()
+            approximate = op_reader.get_attr("approximate", "string", "none")
+            match approximate:
+                case "none":
+                    attrs.approximate = False
+                case "tanh":
+                    attrs.approximate = True
+                case _:
+                    raise Exception(f'Unsupported Gelu approximation "{approximate}"')
+
         case "Gemm":
             attrs = sg.GemmAttrsT()
             attrs.alpha = op_reader.get_attr("alpha", "float", 1.0)
@@ -718,6 +951,16 @@ def op_node_from_onnx_operator(
             attrs.transposeA = bool(op_reader.get_attr("transA", "int", 0))
             attrs.transposeB = bool(op_reader.get_attr("transB", "int", 0))
 
+        case "GlobalLpPool":
+            attrs = sg.GlobalLpPoolAttrsT()
+            attrs.p = op_reader.get_attr("p", "int", 2)
+
+        case "GroupNormalization":
+            attrs = sg.GroupNormalizationAttrsT()
+            attrs.numGroups = op_reader.require_attr("num_groups", "int")
+            attrs.epsilon = op_reader.get_attr("epsilon", "float", 1e-5)
+            op_reader.ignore_attr("stash_type")
+
         case "GRU":
             attrs = sg.GRUAttrsT()
             attrs.direction = op_reader.get_enum_attr(
@@ -727,6 +970,7 @@ def op_node_from_onnx_operator(
             attrs.linearBeforeReset = bool(
                 op_reader.get_attr("linear_before_reset", "int", 0)
             )
+            attrs.clip = op_reader.get_attr("clip", "float", 0.0)
 
         case "HardSigmoid":
             attrs = sg.HardSigmoidAttrsT()
@@ -750,6 +994,23 @@ def op_node_from_onnx_operator(
             attrs = sg.SoftmaxAttrsT()
             attrs.axis = op_reader.get_attr("axis", "int", 0)
 
+        case "LpPool":
+            attrs = sg.LpPoolAttrsT()
+            kernel_shape = op_reader.require_attr("kernel_shape", "ints")
+            check_pool_kernel_shape(kernel_shape)
+            attrs.kernelSize = kernel_shape
+
+            pad_mode, pads = read_pads(op_reader, len(kernel_shape))
+            if pad_mode == "same":
+                attrs.padMode = sg.PadMode.Same
+            else:
+                attrs.padMode = sg.PadMode.Fixed
+                attrs.pads = pads
+            attrs.strides = read_strides(op_reader, len(kernel_shape))
+            attrs.dilations = read_dilations(op_reader, len(kernel_shape))
+            attrs.ceilMode = op_reader.get_bool_attr("ceil_mode", False)
+            attrs.p = op_reader.get_attr("p", "int", 2)
+
         case "LSTM":
             attrs = sg.LSTMAttrsT()
             attrs.direction = op_reader.get_enum_attr(
@@ -760,32 +1021,63 @@ def op_node_from_onnx_operator(
             op_reader.check_attr("activation_alpha", "floats", [])
             op_reader.check_attr("activation_beta", "floats", [])
             op_reader.check_attr("activations", "strings", [])
-            op_reader.check_attr("clip", "float", 0.0)
+            attrs.clip = op_reader.get_attr("clip", "float", 0.0)
             op_reader.check_attr("input_forget", "int", 0)
             op_reader.check_attr("layout", "int", 0)
 
         case "MaxPool":
             attrs = sg.MaxPoolAttrsT()
             kernel_shape = op_reader.require_attr("kernel_shape", "ints")
-            check_ints_length("kernel_shape", kernel_shape, 2)
+            check_pool_kernel_shape(kernel_shape)
             attrs.kernelSize = kernel_shape
 
-            pad_mode, pads = read_pads(op_reader)
+            pad_mode, pads = read_pads(op_reader, len(kernel_shape))
             if pad_mode == "same":
                 attrs.padMode = sg.PadMode.Same
             else:
                 attrs.padMode = sg.PadMode.Fixed
                 attrs.pads = pads
-            attrs.strides = read_strides(op_reader)
+            attrs.strides = read_strides(op_reader, len(kernel_shape))
+            attrs.dilations = read_dilations(op_reader, len(kernel_shape))
+            attrs.ceilMode = op_reader.get_bool_attr("ceil_mode", False)
 
-            op_reader.check_attr("ceil_mode", "int", 0)
-            op_reader.check_attr("dilations", "ints", ([1], [1, 1]))
             op_reader.check_attr("storage_order", "int", 0)
 
         case "Mod":
             attrs = sg.ModAttrsT()
             attrs.fmod = bool(op_reader.get_attr("fmod", "int", 0))
 
+        case "MultiHeadAttention":
+            # ONNX Runtime's `MultiHeadAttention` op is converted to `Attention`,
+            # with the key padding mask as an extra input.
+            op_type = "Attention"
+            attrs = sg.AttentionAttrsT()
+            attrs.numHeads = op_reader.require_attr("num_heads", "int")
+            attrs.isCausal = bool(op_reader.get_attr("unidirectional", "int", 0))
+
+            # A scale of zero means use the default of `1 / sqrt(head_size)`.
+            scale = op_reader.get_attr("scale", "float", 0.0)
+            attrs.scale = scale if scale != 0.0 else None
+
+            op_reader.ignore_attr("mask_filter_value")
+
+            def mha_input(index: int) -> int | None:
+                return input_indexes[index] if index < len(input_indexes) else None
+
+            if mha_input(1) is None or mha_input(2) is None:
+                raise Exception("Packed query, key and value inputs are not supported")
+            if mha_input(3) is not None:
+                raise Exception("bias input is not supported")
+            if any(index is not None for index in input_indexes[6:]):
+                raise Exception("past_key and past_value inputs are not supported")
+            if len(output_indexes) > 1:
+                raise Exception(
+                    "present_key and present_value outputs are not supported"
+                )
+
+            # Reorder `key_padding_mask` and `attention_bias` inputs.
+            op_reader.input_indexes = input_indexes[:3] + [mha_input(5), mha_input(4)]
+
         case "NonMaxSuppression":
             attrs = sg.NonMaxSuppressionAttrsT()
             center_point_box = op_reader.get_attr("center_point_box", "int", 0)
@@ -826,24 +1118,47 @@ def op_node_from_onnx_operator(
             attrs = sg.ReshapeAttrsT()
             attrs.allowZero = bool(op_reader.get_attr("allowzero", "int", 0))
 
-        case "Resize":
-            attrs = sg.ResizeAttrsT()
-            attrs.mode = op_reader.get_enum_attr(
-                "mode", sg.ResizeMode, "nearest", fallback="linear"
-            )
+        case "RMSNormalization" | "SimplifiedLayerNormalization":
+            op_type = "RMSNormalization"
+            attrs = sg.LayerNormalizationAttrsT()
+            attrs.axis = op_reader.get_attr("axis", "int", -1)
+            attrs.epsilon = op_reader.get_attr("epsilon", "float", 1e-5)
+            op_reader.ignore_attr("stash_type")
 
-            op_reader.check_attr("antialias", "int", 0)
+            if len(output_indexes) > 1:
+                raise Exception("inv_std_var output is not supported")
 
-            # We only support resizing HW dimensions of NCHW tensor
-            op_reader.check_attr("axes", "ints", [2, 3])
+        case "RNN":
+            attrs = sg.RNNAttrsT()
+            attrs.direction = op_reader.get_enum_attr(
+                "direction", sg.RNNDirection, "forward"
+            )
+            attrs.hiddenSize = op_reader.require_attr("hidden_size", "int")
+
+            activations = []
+            for name in op_reader.get_attr("activations", "strings", []):
+                if not hasattr(sg.RNNActivation, name):
+                    raise ValueError(f'Unsupported RNN activation "{name}"')
+                activations.append(getattr(sg.RNNActivation, name))
+            attrs.activations = activations
+            attrs.activationAlpha = op_reader.get_attr("activation_alpha", "floats", [])
+            attrs.activationBeta = op_reader.get_attr("activation_beta", "floats", [])
+            attrs.clip = op_reader.get_attr("clip", "float", 0.0)
+            op_reader.check_attr("layout", "int", 0)
 
+        case "Resize":
+            attrs = sg.ResizeAttrsT()
+            attrs.mode = op_reader.get_enum_attr("mode", sg.ResizeMode, "nearest")
+            attrs.antialias = bool(op_reader.get_attr("antialias", "int", 0))
+            attrs.axes = op_reader.get_attr("axes", "ints", None)
             attrs.coordMode = op_reader.get_enum_attr(
                 "coordinate_transformation_mode", sg.CoordTransformMode, "half_pixel"
             )
-
-            op_reader.check_attr("cubic_coeff_a", "float", -0.75, on_mismatch="warn")
-            op_reader.check_attr("exclude_outside", "int", 0)
-            op_reader.check_attr("extrapolation_value", "float", 0.0)
+            attrs.cubicCoeffA = op_reader.get_attr("cubic_coeff_a", "float", -0.75)
+            attrs.excludeOutside = bool(op_reader.get_attr("exclude_outside", "int", 0))
+            attrs.extrapolationValue = op_reader.get_attr(
+                "extrapolation_value", "float", 0.0
+            )
             op_reader.check_attr("keep_aspect_ratio_policy", "string", "stretch")
 
             attrs.nearestMode = op_reader.get_enum_attr(
@@ -851,7 +1166,8 @@ def op_node_from_onnx_operator(
             )
 
         case "Pad":
-            op_reader.check_attr("mode", "string", "constant")
+            attrs = sg.PadAttrsT()
+            attrs.mode = op_reader.get_enum_attr("mode", sg.PadOpMode, "constant")
 
         case "ScatterElements":
             attrs = sg.ScatterElementsAttrsT()
@@ -866,6 +1182,15 @@ def op_node_from_onnx_operator(
                 "reduction", sg.ScatterReduction, "none"
             )
 
+        case "Selu":
+            attrs = sg.SeluAttrsT()
+            attrs.alpha = op_reader.get_attr(
+                "alpha", "float", 1.67326319217681884765625
+            )
+            attrs.gamma = op_reader.get_attr(
+                "gamma", "float", 1.05070102214813232421875
+            )
+
         case "Shape":
             op_reader.check_attr("end", "int", 0)
             op_reader.check_attr("start", "int", 0)
@@ -883,6 +1208,10 @@ def op_node_from_onnx_operator(
         case "Squeeze":
             op_reader.generate_input_from_attr(1, "axes", "ints")
 
+        case "ThresholdedRelu":
+            attrs = sg.LeakyReluAttrsT()
+            attrs.alpha = op_reader.get_attr("alpha", "float", 1.0)
+
         case "TopK":
             attrs = sg.TopKAttrsT()
             attrs.axis = op_reader.get_attr("axis", "int", -1)
@@ -900,9 +1229,6 @@ def op_node_from_onnx_operator(
         case "Unsqueeze":
             op_reader.generate_input_from_attr(1, "axes", "ints")
 
-    if not hasattr(sg.OperatorType, op_type):
-        raise Exception(f"Unsupported operator {op_type}")
-
     # Display a warning for any attributes that were not handled above.
     for attr in op_reader.unhandled_attrs():
         warn_once(
@@ -931,6 +1257,263 @@ def duplicate_node_names(nodes: list[onnx.ValueInfoProto]) -> list[str]:
     return dupes
 
 
+def fuse_attention(model: onnx.ModelProto) -> int:
+    """
+    Replace decomposed scaled dot-product attention subgraphs with `Attention`
+    operators.
+
+    This matches the subgraph `Softmax(Q @ Transpose(K) * scale + mask) @ V`,
+    where the scale is a constant `Mul` or `Div` and the `Add` of a mask is
+    optional. `Q`, `K` and `V` must have a known rank of 4, with shape
+    `[batch, heads, seq, head_size]`, and the mask, if present, must have a
+    known shape which broadcasts to
+    `[batch, heads, q_seq, kv_seq]`. Intermediate values in the subgraph must
+    not be used elsewhere.
+
+    The graph is modified in place. Returns the number of subgraphs replaced.
+    """
+    graph = model.graph
+    nodes = [copy.deepcopy(node) for node in graph.node]
+
+    opset = next(
+        (
+            opset.version
+            for opset in model.opset_import
+            if opset.domain in ["", "ai.onnx"]
+        ),
+        None,
+    )
+
+    producers: dict[str, onnx.NodeProto] = {}
+    consumer_count: dict[str, int] = {}
+    for node in nodes:
+        for name in node.output:
+            producers[name] = node
+        for name in node.input:
+            consumer_count[name] = consumer_count.get(name, 0) + 1
+    graph_outputs = {output.name for output in graph.output}
+
+    def is_softmax_matmul(node: onnx.NodeProto) -> bool:
+        """Return true if `node` is a `MatMul` whose LHS is a `Softmax` output."""
+        if node.op_type != "MatMul" or node.domain not in ["", "ai.onnx"]:
+            return False
+        softmax = producers.get(node.input[0])
+        return softmax is not None and softmax.op_type == "Softmax"
+
+    # Shape inference can be slow for large models, so skip it if there are
+    # no candidate subgraphs.
+    if not any(is_softmax_matmul(node) for node in nodes):
+        return 0
+
+    def dim_size(dim: onnx.TensorShapeProto.Dimension) -> int | str | None:
+        if dim.HasField("dim_value"):
+            return dim.dim_value
+        if dim.HasField("dim_param"):
+            return dim.dim_param
+        return None
+
+    # Shapes of intermediate values, such as masks, are often only recorded
+    # after shape inference. This fails for models over 2GB, in which case
+    # only the shapes already in the graph are used.
+    try:
+        shape_graph = shape_inference.infer_shapes(model).graph
+    except Exception:
+        shape_graph = graph
+
+    # Shapes of values. Each dim is a size, a symbolic name or `None` if
+    # unknown.
+    value_shapes: dict[str, list[int | str | None]] = {}
+    values = (
+        list(shape_graph.input)
+        + list(shape_graph.value_info)
+        + list(shape_graph.output)
+    )
+    for value in values:
+        if value.type.tensor_type.HasField("shape"):
+            value_shapes[value.name] = [
+                dim_size(dim) for dim in value.type.tensor_type.shape.dim
+            ]
+
+    constants: dict[str, np.ndarray] = {}
+    for tensor in graph.initializer:
+        constants[tensor.name] = numpy_helper.to_array(tensor)
+    for node in nodes:
+        if node.op_type == "Constant":
+            for attr in node.attribute:
+                if attr.name == "value":
+                    constants[node.output[0]] = numpy_helper.to_array(attr.t)
+                elif attr.name == "value_float":
+                    constants[node.output[0]] = np.array(attr.f)
+    for name, value in constants.items():
+        value_shapes[name] = list(value.shape)
+
+    def get_attr(node: onnx.NodeProto, name: str, default):
+        for attr in node.attribute:
+            if attr.name == name:
+                return onnx.helper.get_attribute_value(attr)
+        return default
+
+    def intermediate_producer(name: str, op_type: str) -> onnx.NodeProto | None:
+        """
+        Return the operator of type `op_type` which produces `name`, if
+        `name` is an intermediate value that has no other consumers.
+        """
+        node = producers.get(name)
+        if (
+            node is None
+            or node.op_type != op_type
+            or node.domain not in ["", "ai.onnx"]
+            or consumer_count.get(name, 0) != 1
+            or name in graph_outputs
+        ):
+            return None
+        return node
+
+    def scalar_constant(name: str) -> float | None:
+        value = constants.get(name)
+        if value is None or value.size != 1:
+            return None
+        return float(value.item())
+
+    def match_qk_matmul(name: str) -> tuple[onnx.NodeProto, onnx.NodeProto] | None:
+        """Match `Q @ Transpose(K)`. Returns the `MatMul` and `Transpose` ops."""
+        matmul = intermediate_producer(name, "MatMul")
+        if matmul is None:
+            return None
+        transpose = intermediate_producer(matmul.input[1], "Transpose")
+        if transpose is None or get_attr(transpose, "perm", None) != [0, 1, 3, 2]:
+            return None
+        return (matmul, transpose)
+
+    def match_scores(
+        name: str,
+    ) -> tuple[float, list[onnx.NodeProto], onnx.NodeProto, onnx.NodeProto] | None:
+        """
+        Match `Q @ Transpose(K)` with an optional constant scale.
+
+        Returns the scale, the scale op (if any) and the `MatMul` and
+        `Transpose` ops.
+        """
+        for op_type in ["Div", "Mul"]:
+            scale_op = intermediate_producer(name, op_type)
+            if scale_op is None:
+                continue
+            # `Div` has the scale as its second operand. `Mul` may have it as
+            # either operand.
+            operands = [(1, 0)] if op_type == "Div" else [(1, 0), (0, 1)]
+            for scale_index, input_index in operands:
+                scale_value = scalar_constant(scale_op.input[scale_index])
+                if scale_value is None or scale_value == 0.0:
+                    continue
+                qk = match_qk_matmul(scale_op.input[input_index])
+                if qk is None:
+                    continue
+                scale = 1.0 / scale_value if op_type == "Div" else scale_value
+                return (scale, [scale_op], *qk)
+            return None
+
+        qk = match_qk_matmul(name)
+        if qk is None:
+            return None
+        return (1.0, [], *qk)
+
+    def dims_equal(a: int | str | None, b: int | str | None) -> bool:
+        return a is not None and a == b
+
+    def mask_broadcasts_to_scores(mask: str, query: str, key: str) -> bool:
+        """
+        Return true if `mask` is known to broadcast to the shape of the
+        attention scores, `[batch, heads, q_seq, kv_seq]`, without expanding
+        it.
+
+        `query` and `key` must have known shapes.
+        """
+        mask_shape = value_shapes.get(mask)
+        query_shape = value_shapes[query]
+        key_shape = value_shapes[key]
+        if mask_shape is None or len(mask_shape) > 4:
+            return False
+        scores_shape = query_shape[:3] + key_shape[2:3]
+        return all(
+            mask_dim == 1 or dims_equal(mask_dim, scores_dim)
+            for mask_dim, scores_dim in zip(
+                reversed(mask_shape), reversed(scores_shape)
+            )
+        )
+
+    # Map of output name of final `MatMul` in each matched subgraph to the
+    # replacement `Attention` operator.
+    replacements: dict[str, onnx.NodeProto] = {}
+    removed_nodes: set[int] = set()
+
+    for node in nodes:
+        if not is_softmax_matmul(node):
+            continue
+        softmax = intermediate_producer(node.input[0], "Softmax")
+        if softmax is None:
+            continue
+        default_axis = -1 if opset is None or opset >= 13 else 1
+        if get_attr(softmax, "axis", default_axis) not in [-1, 3]:
+            continue
+        matched = [node, softmax]
+
+        # The scores are identified by tracing back to the `Q @ Transpose(K)`
+        # product, rather than by the type of the op which produces them, since
+        # the mask may be produced by the same kinds of op.
+        mask = None
+        scores = match_scores(softmax.input[0])
+        if scores is None:
+            # Optional additive mask.
+            add = intermediate_producer(softmax.input[0], "Add")
+            if add is None:
+                continue
+            for scores_input, mask_input in [(0, 1), (1, 0)]:
+                scores = match_scores(add.input[scores_input])
+                if scores is not None:
+                    mask = add.input[mask_input]
+                    matched.append(add)
+                    break
+            else:
+                continue
+
+        scale, scale_ops, qk_matmul, transpose = scores
+        matched += scale_ops + [qk_matmul, transpose]
+
+        query = qk_matmul.input[0]
+        key = transpose.input[0]
+        value = node.input[1]
+        # Inputs whose rank is unknown are not fused, as they may not be 4D.
+        if any(len(value_shapes.get(name, [])) != 4 for name in [query, key, value]):
+            continue
+        if mask is not None and not mask_broadcasts_to_scores(mask, query, key):
+            continue
+
+        attention = onnx.helper.make_node(
+            "Attention",
+            inputs=[query, key, value] + ([mask] if mask is not None else []),
+            outputs=[node.output[0]],
+            name=node.name,
+            scale=scale,
+        )
+        replacements[node.output[0]] = attention
+        removed_nodes.update(id(op) for op in matched)
+
+    if not replacements:
+        return 0
+
+    new_nodes = []
+    for node in nodes:
+        replacement = replacements.get(node.output[0]) if node.output else None
+        if replacement is not None and node.op_type == "MatMul":
+            new_nodes.append(replacement)
+        elif id(node) not in removed_nodes:
+            new_nodes.append(node)
+    del graph.node[:]
+    graph.node.extend(new_nodes)
+
+    return len(replacements)
+
+
 def graph_from_onnx_graph(onnx_graph: onnx.GraphProto) -> Graph:
     """
     Parse an ONNX model into a graph representation compatible with this library.
@@ -1046,9 +1629,34 @@ def graph_from_onnx_graph(onnx_graph: onnx.GraphProto) -> Graph:
     return Graph(nodes=nodes, inputs=inputs, outputs=outputs)
 
 
-def build_constant_node(builder: flatbuffers.Builder, constant: ConstantNode):
+FloatStorageType = Literal["float32", "float16", "bfloat16"]
+"""Data type used to store float constants (eg. weights) in the model file."""
+
+
+def float32_to_bfloat16_bits(data: np.ndarray) -> np.ndarray:
+    """
+    Convert a float32 array to bfloat16, returned as an array of raw uint16 bits.
+
+    Values are rounded to nearest, with ties rounded to even.
+    """
+    bits = data.astype(np.float32).view(np.uint32)
+    rounding_bias = 0x7FFF + ((bits >> 16) & 1)
+    rounded = ((bits + rounding_bias) >> 16).astype(np.uint16)
+
+    # Keep NaNs as NaNs, as rounding can turn them into infinities.
+    nan_bits = (bits >> 16).astype(np.uint16) | 0x40
+    return np.where(np.isnan(data), nan_bits, rounded)
+
+
+def build_constant_node(
+    builder: flatbuffers.Builder,
+    constant: ConstantNode,
+    float_type: FloatStorageType = "float32",
+):
     """
     Serialize a constant tensor value (eg. model weights) into a FlatBuffers model.
+
+    :param float_type: Data type used to store float32 constants
     """
     shape_vec = write_vec(
         builder, sg.ConstantNodeStartShapeVector, constant.shape, "u32"
@@ -1056,15 +1664,30 @@ def build_constant_node(builder: flatbuffers.Builder, constant: ConstantNode):
 
     # Convert data to NumPy array then serialize. This is much faster than
     # serializing a Python array element by element.
-    data_vec = builder.CreateNumpyVector(constant.data.flatten())
+    data = constant.data.flatten()
 
     match constant.data.dtype:
+        case np.float32 if float_type == "float16":
+            f16_data = data.astype(np.float16).view(np.uint16)
+            data_vec = builder.CreateNumpyVector(f16_data)
+            sg.Float16DataStart(builder)
+            sg.Float16DataAddData(builder, data_vec)
+            const_data = sg.Float16DataEnd(builder)
+            const_data_type = sg.ConstantData.Float16Data
+        case np.float32 if float_type == "bfloat16":
+            data_vec = builder.CreateNumpyVector(float32_to_bfloat16_bits(data))
+            sg.BFloat16DataStart(builder)
+            sg.BFloat16DataAddData(builder, data_vec)
+            const_data = sg.BFloat16DataEnd(builder)
+            const_data_type = sg.ConstantData.BFloat16Data
         case np.float32:
+            data_vec = builder.CreateNumpyVector(data)
             sg.FloatDataStart(builder)
             sg.FloatDataAddData(builder, data_vec)
             const_data = sg.FloatDataEnd(builder)
             const_data_type = sg.ConstantData.FloatData
         case np.int32:
+            data_vec = builder.CreateNumpyVector(data)
             sg.IntDataStart(builder)
             sg.IntDataAddData(builder, data_vec)
             const_data = sg.IntDataEnd(builder)
@@ -1157,6 +1780,8 @@ def build_value_node(builder: flatbuffers.Builder, value: ValueNode):
     sg.ValueNodeStart(builder)
     if shape_vec:
         sg.ValueNodeAddShape(builder, shape_vec)
+    if value.dtype is not None:
+        sg.ValueNodeAddDtype(builder, value.dtype)
     return sg.ValueNodeEnd(builder)
 
 
@@ -1194,16 +1819,22 @@ def build_metadata(builder: flatbuffers.Builder, metadata: Metadata):
     return sg.MetadataEnd(builder)
 
 
-def build_graph(builder: flatbuffers.Builder, graph: Graph):
+def build_graph(
+    builder: flatbuffers.Builder,
+    graph: Graph,
+    float_type: FloatStorageType = "float32",
+):
     """
     Serialize a computation graph into a flatbuffers model.
+
+    :param float_type: Data type used to store float32 constants
     """
     node_offsets = []
     for node in graph.nodes:
         match node:
             case ConstantNode():
                 data_type = sg.NodeKind.ConstantNode
-                data = build_constant_node(builder, node)
+                data = build_constant_node(builder, node, float_type)
             case OperatorNode():
                 data_type = sg.NodeKind.OperatorNode
                 data = build_operator_node(builder, node)
@@ -1232,7 +1863,12 @@ def build_graph(builder: flatbuffers.Builder, graph: Graph):
     return sg.GraphEnd(builder)
 
 
-def write_model(graph: Graph, metadata: Metadata, out_path: str):
+def write_model(
+    graph: Graph,
+    metadata: Metadata,
+    out_path: str,
+    float_type: FloatStorageType = "float32",
+):
     """
     Serialize a model into a flatbuffers model.
 
@@ -1242,11 +1878,14 @@ def write_model(graph: Graph, metadata: Metadata, out_path: str):
     :param graph: The main graph for the model
     :param metadata: Model metadata
     :param out_path: Output .rten model path
+    :param float_type: Data type used to store float32 constants (eg. weights).
+        Reduced precision types halve the size of the weights. They are
+        converted back to float32 when the model is loaded.
     """
 
     builder = flatbuffers.Builder(initialSize=1024)
 
-    graph = build_graph(builder, graph)
+    graph = build_graph(builder, graph, float_type)
     metadata = build_metadata(builder, metadata)
 
     sg.ModelStart(builder)
@@ -1300,10 +1939,23 @@ def main():
     parser.add_argument(
         "-m", "--metadata", help="Path to JSON file containing model metadata."
     )
+    parser.add_argument(
+        "--float-type",
+        choices=["float32", "float16", "bfloat16"],
+        default="float32",
+        help="Data type used to store float weights in the model file.",
+    )
+    parser.add_argument(
+        "--no-fuse-attention",
+        action="store_true",
+        help="Do not replace decomposed attention subgraphs with `Attention` operators.",
+    )
     parser.add_argument("out_name", help="Output model file name", nargs="?")
     args = parser.parse_args()
 
     model = onnx.load(args.model)
+    if not args.no_fuse_attention:
+        fuse_attention(model)
     graph = graph_from_onnx_graph(model.graph)
     metadata = generate_metadata(args.model, args.metadata)
 
@@ -1312,7 +1964,7 @@ def main():
         model_basename = splitext(args.model)[0]
         output_path = f"{model_basename}.rten"
 
-    write_model(graph, metadata, output_path)
+    write_model(graph, metadata, output_path, args.float_type)
 
 
 if __name__ == "__main__":
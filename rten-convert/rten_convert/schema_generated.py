@@ -103,6 +103,23 @@ class OperatorType(object):
     LayerNormalization = 93
     ReduceSumSquare = 94
     RandomUniform = 95
+    Custom = 96
+    GlobalMaxPool = 97
+    LpPool = 98
+    GlobalLpPool = 99
+    RNN = 100
+    Attention = 101
+    GroupNormalization = 102
+    RMSNormalization = 103
+    Celu = 104
+    Elu = 105
+    Gelu = 106
+    Mish = 107
+    PRelu = 108
+    Selu = 109
+    Softplus = 110
+    Softsign = 111
+    ThresholdedRelu = 112
 
 
 class RNNDirection(object):
@@ -119,12 +136,17 @@ class PadMode(object):
 class DataType(object):
     Int32 = 0
     Float = 1
+    Int64 = 2
+    Bool = 3
+    UInt8 = 4
 
 
 class CoordTransformMode(object):
     HalfPixel = 0
     Asymmetric = 1
     AlignCorners = 2
+    PytorchHalfPixel = 3
+    TfCropAndResize = 4
 
 
 class NearestMode(object):
@@ -137,6 +159,16 @@ class NearestMode(object):
 class ResizeMode(object):
     Nearest = 0
     Linear = 1
+    Cubic = 2
+
+
+class CustomAttrType(object):
+    Int = 0
+    Float = 1
+    String = 2
+    Ints = 3
+    Floats = 4
+    Tensor = 5
 
 
 class OperatorAttrs(object):
@@ -172,6 +204,15 @@ class OperatorAttrs(object):
     NonMaxSuppressionAttrs = 29
     LayerNormalizationAttrs = 30
     RandomUniformAttrs = 31
+    CustomAttrs = 32
+    LpPoolAttrs = 33
+    GlobalLpPoolAttrs = 34
+    PadAttrs = 35
+    RNNAttrs = 36
+    AttentionAttrs = 37
+    GroupNormalizationAttrs = 38
+    GeluAttrs = 39
+    SeluAttrs = 40
 
 def OperatorAttrsCreator(unionType, table):
     from flatbuffers.table import Table
@@ -239,6 +280,24 @@ def OperatorAttrsCreator(unionType, table):
         return LayerNormalizationAttrsT.InitFromBuf(table.Bytes, table.Pos)
     if unionType == OperatorAttrs().RandomUniformAttrs:
         return RandomUniformAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().CustomAttrs:
+        return CustomAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().LpPoolAttrs:
+        return LpPoolAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().GlobalLpPoolAttrs:
+        return GlobalLpPoolAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().PadAttrs:
+        return PadAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().RNNAttrs:
+        return RNNAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().AttentionAttrs:
+        return AttentionAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().GroupNormalizationAttrs:
+        return GroupNormalizationAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().GeluAttrs:
+        return GeluAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().SeluAttrs:
+        return SeluAttrsT.InitFromBuf(table.Bytes, table.Pos)
     return None
 
 
@@ -258,11 +317,32 @@ def ScalarCreator(unionType, table):
     return None
 
 
+class RNNActivation(object):
+    Relu = 0
+    Tanh = 1
+    Sigmoid = 2
+    Affine = 3
+    LeakyRelu = 4
+    ThresholdedRelu = 5
+    ScaledTanh = 6
+    HardSigmoid = 7
+    Elu = 8
+    Softsign = 9
+    Softplus = 10
+
+
 class NMSBoxOrder(object):
     TopLeftBottomRight = 0
     CenterWidthHeight = 1
 
 
+class PadOpMode(object):
+    Constant = 0
+    Reflect = 1
+    Edge = 2
+    Wrap = 3
+
+
 class ScatterReduction(object):
     None_ = 0
     Add = 1
@@ -294,6 +374,8 @@ class ConstantData(object):
     NONE = 0
     FloatData = 1
     IntData = 2
+    Float16Data = 3
+    BFloat16Data = 4
 
 def ConstantDataCreator(unionType, table):
     from flatbuffers.table import Table
@@ -303,9 +385,129 @@ def ConstantDataCreator(unionType, table):
         return FloatDataT.InitFromBuf(table.Bytes, table.Pos)
     if unionType == ConstantData().IntData:
         return IntDataT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == ConstantData().Float16Data:
+        return Float16DataT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == ConstantData().BFloat16Data:
+        return BFloat16DataT.InitFromBuf(table.Bytes, table.Pos)
     return None
 
 
+class AttentionAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = AttentionAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsAttentionAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def AttentionAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # AttentionAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # AttentionAttrs
+    def NumHeads(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
+
+    # AttentionAttrs
+    def KvNumHeads(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
+
+    # AttentionAttrs
+    def IsCausal(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+    # AttentionAttrs
+    def Scale(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return None
+
+def AttentionAttrsStart(builder):
+    builder.StartObject(4)
+
+def AttentionAttrsAddNumHeads(builder, numHeads):
+    builder.PrependUint32Slot(0, numHeads, 0)
+
+def AttentionAttrsAddKvNumHeads(builder, kvNumHeads):
+    builder.PrependUint32Slot(1, kvNumHeads, 0)
+
+def AttentionAttrsAddIsCausal(builder, isCausal):
+    builder.PrependBoolSlot(2, isCausal, 0)
+
+def AttentionAttrsAddScale(builder, scale):
+    builder.PrependFloat32Slot(3, scale, None)
+
+def AttentionAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class AttentionAttrsT(object):
+
+    # AttentionAttrsT
+    def __init__(self):
+        self.numHeads = 0  # type: int
+        self.kvNumHeads = 0  # type: int
+        self.isCausal = False  # type: bool
+        self.scale = None  # type: Optional[float]
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        attentionAttrs = AttentionAttrs()
+        attentionAttrs.Init(buf, pos)
+        return cls.InitFromObj(attentionAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, attentionAttrs):
+        x = AttentionAttrsT()
+        x._UnPack(attentionAttrs)
+        return x
+
+    # AttentionAttrsT
+    def _UnPack(self, attentionAttrs):
+        if attentionAttrs is None:
+            return
+        self.numHeads = attentionAttrs.NumHeads()
+        self.kvNumHeads = attentionAttrs.KvNumHeads()
+        self.isCausal = attentionAttrs.IsCausal()
+        self.scale = attentionAttrs.Scale()
+
+    # AttentionAttrsT
+    def Pack(self, builder):
+        AttentionAttrsStart(builder)
+        AttentionAttrsAddNumHeads(builder, self.numHeads)
+        AttentionAttrsAddKvNumHeads(builder, self.kvNumHeads)
+        AttentionAttrsAddIsCausal(builder, self.isCausal)
+        AttentionAttrsAddScale(builder, self.scale)
+        attentionAttrs = AttentionAttrsEnd(builder)
+        return attentionAttrs
+
+
 class ArgMaxAttrs(object):
     __slots__ = ['_tab']
 
@@ -513,8 +715,42 @@ class AveragePoolAttrs(object):
             return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
         return False
 
+    # AveragePoolAttrs
+    def Dilations(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # AveragePoolAttrs
+    def DilationsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # AveragePoolAttrs
+    def DilationsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # AveragePoolAttrs
+    def DilationsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        return o == 0
+
+    # AveragePoolAttrs
+    def CeilMode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
 def AveragePoolAttrsStart(builder):
-    builder.StartObject(5)
+    builder.StartObject(7)
 
 def AveragePoolAttrsAddKernelSize(builder, kernelSize):
     builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(kernelSize), 0)
@@ -540,6 +776,15 @@ def AveragePoolAttrsStartStridesVector(builder, numElems):
 def AveragePoolAttrsAddCountIncludePad(builder, countIncludePad):
     builder.PrependBoolSlot(4, countIncludePad, 0)
 
+def AveragePoolAttrsAddDilations(builder, dilations):
+    builder.PrependUOffsetTRelativeSlot(5, flatbuffers.number_types.UOffsetTFlags.py_type(dilations), 0)
+
+def AveragePoolAttrsStartDilationsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def AveragePoolAttrsAddCeilMode(builder, ceilMode):
+    builder.PrependBoolSlot(6, ceilMode, 0)
+
 def AveragePoolAttrsEnd(builder):
     return builder.EndObject()
 
@@ -558,6 +803,8 @@ class AveragePoolAttrsT(object):
         self.pads = None  # type: List[int]
         self.strides = None  # type: List[int]
         self.countIncludePad = False  # type: bool
+        self.dilations = None  # type: List[int]
+        self.ceilMode = False  # type: bool
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
@@ -603,6 +850,14 @@ class AveragePoolAttrsT(object):
             else:
                 self.strides = averagePoolAttrs.StridesAsNumpy()
         self.countIncludePad = averagePoolAttrs.CountIncludePad()
+        if not averagePoolAttrs.DilationsIsNone():
+            if np is None:
+                self.dilations = []
+                for i in range(averagePoolAttrs.DilationsLength()):
+                    self.dilations.append(averagePoolAttrs.Dilations(i))
+            else:
+                self.dilations = averagePoolAttrs.DilationsAsNumpy()
+        self.ceilMode = averagePoolAttrs.CeilMode()
 
     # AveragePoolAttrsT
     def Pack(self, builder):
@@ -630,6 +885,14 @@ class AveragePoolAttrsT(object):
                 for i in reversed(range(len(self.strides))):
                     builder.PrependUint32(self.strides[i])
                 strides = builder.EndVector()
+        if self.dilations is not None:
+            if np is not None and type(self.dilations) is np.ndarray:
+                dilations = builder.CreateNumpyVector(self.dilations)
+            else:
+                AveragePoolAttrsStartDilationsVector(builder, len(self.dilations))
+                for i in reversed(range(len(self.dilations))):
+                    builder.PrependUint32(self.dilations[i])
+                dilations = builder.EndVector()
         AveragePoolAttrsStart(builder)
         if self.kernelSize is not None:
             AveragePoolAttrsAddKernelSize(builder, kernelSize)
@@ -639,6 +902,9 @@ class AveragePoolAttrsT(object):
         if self.strides is not None:
             AveragePoolAttrsAddStrides(builder, strides)
         AveragePoolAttrsAddCountIncludePad(builder, self.countIncludePad)
+        if self.dilations is not None:
+            AveragePoolAttrsAddDilations(builder, dilations)
+        AveragePoolAttrsAddCeilMode(builder, self.ceilMode)
         averagePoolAttrs = AveragePoolAttrsEnd(builder)
         return averagePoolAttrs
 
@@ -1424,8 +1690,130 @@ class ConvTransposeAttrs(object):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         return o == 0
 
+    # ConvTransposeAttrs
+    def PadMode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 1
+
+    # ConvTransposeAttrs
+    def Pads(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # ConvTransposeAttrs
+    def PadsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # ConvTransposeAttrs
+    def PadsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # ConvTransposeAttrs
+    def PadsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        return o == 0
+
+    # ConvTransposeAttrs
+    def Groups(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 1
+
+    # ConvTransposeAttrs
+    def Dilations(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # ConvTransposeAttrs
+    def DilationsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # ConvTransposeAttrs
+    def DilationsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # ConvTransposeAttrs
+    def DilationsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        return o == 0
+
+    # ConvTransposeAttrs
+    def OutputPadding(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # ConvTransposeAttrs
+    def OutputPaddingAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # ConvTransposeAttrs
+    def OutputPaddingLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # ConvTransposeAttrs
+    def OutputPaddingIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        return o == 0
+
+    # ConvTransposeAttrs
+    def OutputShape(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # ConvTransposeAttrs
+    def OutputShapeAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # ConvTransposeAttrs
+    def OutputShapeLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # ConvTransposeAttrs
+    def OutputShapeIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        return o == 0
+
 def ConvTransposeAttrsStart(builder):
-    builder.StartObject(1)
+    builder.StartObject(7)
 
 def ConvTransposeAttrsAddStrides(builder, strides):
     builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(strides), 0)
@@ -1433,6 +1821,36 @@ def ConvTransposeAttrsAddStrides(builder, strides):
 def ConvTransposeAttrsStartStridesVector(builder, numElems):
     return builder.StartVector(4, numElems, 4)
 
+def ConvTransposeAttrsAddPadMode(builder, padMode):
+    builder.PrependUint8Slot(1, padMode, 1)
+
+def ConvTransposeAttrsAddPads(builder, pads):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(pads), 0)
+
+def ConvTransposeAttrsStartPadsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def ConvTransposeAttrsAddGroups(builder, groups):
+    builder.PrependUint32Slot(3, groups, 1)
+
+def ConvTransposeAttrsAddDilations(builder, dilations):
+    builder.PrependUOffsetTRelativeSlot(4, flatbuffers.number_types.UOffsetTFlags.py_type(dilations), 0)
+
+def ConvTransposeAttrsStartDilationsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def ConvTransposeAttrsAddOutputPadding(builder, outputPadding):
+    builder.PrependUOffsetTRelativeSlot(5, flatbuffers.number_types.UOffsetTFlags.py_type(outputPadding), 0)
+
+def ConvTransposeAttrsStartOutputPaddingVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def ConvTransposeAttrsAddOutputShape(builder, outputShape):
+    builder.PrependUOffsetTRelativeSlot(6, flatbuffers.number_types.UOffsetTFlags.py_type(outputShape), 0)
+
+def ConvTransposeAttrsStartOutputShapeVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
 def ConvTransposeAttrsEnd(builder):
     return builder.EndObject()
 
@@ -1447,6 +1865,12 @@ class ConvTransposeAttrsT(object):
     # ConvTransposeAttrsT
     def __init__(self):
         self.strides = None  # type: List[int]
+        self.padMode = 1  # type: int
+        self.pads = None  # type: List[int]
+        self.groups = 1  # type: int
+        self.dilations = None  # type: List[int]
+        self.outputPadding = None  # type: List[int]
+        self.outputShape = None  # type: List[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
@@ -1476,8 +1900,38 @@ class ConvTransposeAttrsT(object):
                     self.strides.append(convTransposeAttrs.Strides(i))
             else:
                 self.strides = convTransposeAttrs.StridesAsNumpy()
-
-    # ConvTransposeAttrsT
+        self.padMode = convTransposeAttrs.PadMode()
+        if not convTransposeAttrs.PadsIsNone():
+            if np is None:
+                self.pads = []
+                for i in range(convTransposeAttrs.PadsLength()):
+                    self.pads.append(convTransposeAttrs.Pads(i))
+            else:
+                self.pads = convTransposeAttrs.PadsAsNumpy()
+        self.groups = convTransposeAttrs.Groups()
+        if not convTransposeAttrs.DilationsIsNone():
+            if np is None:
+                self.dilations = []
+                for i in range(convTransposeAttrs.DilationsLength()):
+                    self.dilations.append(convTransposeAttrs.Dilations(i))
+            else:
+                self.dilations = convTransposeAttrs.DilationsAsNumpy()
+        if not convTransposeAttrs.OutputPaddingIsNone():
+            if np is None:
+                self.outputPadding = []
+                for i in range(convTransposeAttrs.OutputPaddingLength()):
+                    self.outputPadding.append(convTransposeAttrs.OutputPadding(i))
+            else:
+                self.outputPadding = convTransposeAttrs.OutputPaddingAsNumpy()
+        if not convTransposeAttrs.OutputShapeIsNone():
+            if np is None:
+                self.outputShape = []
+                for i in range(convTransposeAttrs.OutputShapeLength()):
+                    self.outputShape.append(convTransposeAttrs.OutputShape(i))
+            else:
+                self.outputShape = convTransposeAttrs.OutputShapeAsNumpy()
+
+    # ConvTransposeAttrsT
     def Pack(self, builder):
         if self.strides is not None:
             if np is not None and type(self.strides) is np.ndarray:
@@ -1487,9 +1941,51 @@ class ConvTransposeAttrsT(object):
                 for i in reversed(range(len(self.strides))):
                     builder.PrependUint32(self.strides[i])
                 strides = builder.EndVector()
+        if self.pads is not None:
+            if np is not None and type(self.pads) is np.ndarray:
+                pads = builder.CreateNumpyVector(self.pads)
+            else:
+                ConvTransposeAttrsStartPadsVector(builder, len(self.pads))
+                for i in reversed(range(len(self.pads))):
+                    builder.PrependUint32(self.pads[i])
+                pads = builder.EndVector()
+        if self.dilations is not None:
+            if np is not None and type(self.dilations) is np.ndarray:
+                dilations = builder.CreateNumpyVector(self.dilations)
+            else:
+                ConvTransposeAttrsStartDilationsVector(builder, len(self.dilations))
+                for i in reversed(range(len(self.dilations))):
+                    builder.PrependUint32(self.dilations[i])
+                dilations = builder.EndVector()
+        if self.outputPadding is not None:
+            if np is not None and type(self.outputPadding) is np.ndarray:
+                outputPadding = builder.CreateNumpyVector(self.outputPadding)
+            else:
+                ConvTransposeAttrsStartOutputPaddingVector(builder, len(self.outputPadding))
+                for i in reversed(range(len(self.outputPadding))):
+                    builder.PrependUint32(self.outputPadding[i])
+                outputPadding = builder.EndVector()
+        if self.outputShape is not None:
+            if np is not None and type(self.outputShape) is np.ndarray:
+                outputShape = builder.CreateNumpyVector(self.outputShape)
+            else:
+                ConvTransposeAttrsStartOutputShapeVector(builder, len(self.outputShape))
+                for i in reversed(range(len(self.outputShape))):
+                    builder.PrependUint32(self.outputShape[i])
+                outputShape = builder.EndVector()
         ConvTransposeAttrsStart(builder)
         if self.strides is not None:
             ConvTransposeAttrsAddStrides(builder, strides)
+        ConvTransposeAttrsAddPadMode(builder, self.padMode)
+        if self.pads is not None:
+            ConvTransposeAttrsAddPads(builder, pads)
+        ConvTransposeAttrsAddGroups(builder, self.groups)
+        if self.dilations is not None:
+            ConvTransposeAttrsAddDilations(builder, dilations)
+        if self.outputPadding is not None:
+            ConvTransposeAttrsAddOutputPadding(builder, outputPadding)
+        if self.outputShape is not None:
+            ConvTransposeAttrsAddOutputShape(builder, outputShape)
         convTransposeAttrs = ConvTransposeAttrsEnd(builder)
         return convTransposeAttrs
 
@@ -1897,8 +2393,15 @@ class GRUAttrs(object):
             return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
         return False
 
+    # GRUAttrs
+    def Clip(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
 def GRUAttrsStart(builder):
-    builder.StartObject(3)
+    builder.StartObject(4)
 
 def GRUAttrsAddDirection(builder, direction):
     builder.PrependUint8Slot(0, direction, 0)
@@ -1909,6 +2412,9 @@ def GRUAttrsAddHiddenSize(builder, hiddenSize):
 def GRUAttrsAddLinearBeforeReset(builder, linearBeforeReset):
     builder.PrependBoolSlot(2, linearBeforeReset, 0)
 
+def GRUAttrsAddClip(builder, clip):
+    builder.PrependFloat32Slot(3, clip, 0.0)
+
 def GRUAttrsEnd(builder):
     return builder.EndObject()
 
@@ -1921,6 +2427,7 @@ class GRUAttrsT(object):
         self.direction = 0  # type: int
         self.hiddenSize = 0  # type: int
         self.linearBeforeReset = False  # type: bool
+        self.clip = 0.0  # type: float
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
@@ -1946,6 +2453,7 @@ class GRUAttrsT(object):
         self.direction = gruattrs.Direction()
         self.hiddenSize = gruattrs.HiddenSize()
         self.linearBeforeReset = gruattrs.LinearBeforeReset()
+        self.clip = gruattrs.Clip()
 
     # GRUAttrsT
     def Pack(self, builder):
@@ -1953,10 +2461,178 @@ class GRUAttrsT(object):
         GRUAttrsAddDirection(builder, self.direction)
         GRUAttrsAddHiddenSize(builder, self.hiddenSize)
         GRUAttrsAddLinearBeforeReset(builder, self.linearBeforeReset)
+        GRUAttrsAddClip(builder, self.clip)
         gruattrs = GRUAttrsEnd(builder)
         return gruattrs
 
 
+class GeluAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = GeluAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsGeluAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def GeluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # GeluAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # GeluAttrs
+    def Approximate(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def GeluAttrsStart(builder):
+    builder.StartObject(1)
+
+def GeluAttrsAddApproximate(builder, approximate):
+    builder.PrependBoolSlot(0, approximate, 0)
+
+def GeluAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class GeluAttrsT(object):
+
+    # GeluAttrsT
+    def __init__(self):
+        self.approximate = False  # type: bool
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        geluAttrs = GeluAttrs()
+        geluAttrs.Init(buf, pos)
+        return cls.InitFromObj(geluAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, geluAttrs):
+        x = GeluAttrsT()
+        x._UnPack(geluAttrs)
+        return x
+
+    # GeluAttrsT
+    def _UnPack(self, geluAttrs):
+        if geluAttrs is None:
+            return
+        self.approximate = geluAttrs.Approximate()
+
+    # GeluAttrsT
+    def Pack(self, builder):
+        GeluAttrsStart(builder)
+        GeluAttrsAddApproximate(builder, self.approximate)
+        geluAttrs = GeluAttrsEnd(builder)
+        return geluAttrs
+
+
+class GroupNormalizationAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = GroupNormalizationAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsGroupNormalizationAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def GroupNormalizationAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # GroupNormalizationAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # GroupNormalizationAttrs
+    def NumGroups(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
+
+    # GroupNormalizationAttrs
+    def Epsilon(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+def GroupNormalizationAttrsStart(builder):
+    builder.StartObject(2)
+
+def GroupNormalizationAttrsAddNumGroups(builder, numGroups):
+    builder.PrependUint32Slot(0, numGroups, 0)
+
+def GroupNormalizationAttrsAddEpsilon(builder, epsilon):
+    builder.PrependFloat32Slot(1, epsilon, 0.0)
+
+def GroupNormalizationAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class GroupNormalizationAttrsT(object):
+
+    # GroupNormalizationAttrsT
+    def __init__(self):
+        self.numGroups = 0  # type: int
+        self.epsilon = 0.0  # type: float
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        groupNormalizationAttrs = GroupNormalizationAttrs()
+        groupNormalizationAttrs.Init(buf, pos)
+        return cls.InitFromObj(groupNormalizationAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, groupNormalizationAttrs):
+        x = GroupNormalizationAttrsT()
+        x._UnPack(groupNormalizationAttrs)
+        return x
+
+    # GroupNormalizationAttrsT
+    def _UnPack(self, groupNormalizationAttrs):
+        if groupNormalizationAttrs is None:
+            return
+        self.numGroups = groupNormalizationAttrs.NumGroups()
+        self.epsilon = groupNormalizationAttrs.Epsilon()
+
+    # GroupNormalizationAttrsT
+    def Pack(self, builder):
+        GroupNormalizationAttrsStart(builder)
+        GroupNormalizationAttrsAddNumGroups(builder, self.numGroups)
+        GroupNormalizationAttrsAddEpsilon(builder, self.epsilon)
+        groupNormalizationAttrs = GroupNormalizationAttrsEnd(builder)
+        return groupNormalizationAttrs
+
+
 class HardSigmoidAttrs(object):
     __slots__ = ['_tab']
 
@@ -2160,8 +2836,15 @@ class LSTMAttrs(object):
             return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
         return 0
 
+    # LSTMAttrs
+    def Clip(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
 def LSTMAttrsStart(builder):
-    builder.StartObject(2)
+    builder.StartObject(3)
 
 def LSTMAttrsAddDirection(builder, direction):
     builder.PrependUint8Slot(0, direction, 0)
@@ -2169,6 +2852,9 @@ def LSTMAttrsAddDirection(builder, direction):
 def LSTMAttrsAddHiddenSize(builder, hiddenSize):
     builder.PrependUint32Slot(1, hiddenSize, 0)
 
+def LSTMAttrsAddClip(builder, clip):
+    builder.PrependFloat32Slot(2, clip, 0.0)
+
 def LSTMAttrsEnd(builder):
     return builder.EndObject()
 
@@ -2180,6 +2866,7 @@ class LSTMAttrsT(object):
     def __init__(self):
         self.direction = 0  # type: int
         self.hiddenSize = 0  # type: int
+        self.clip = 0.0  # type: float
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
@@ -2204,151 +2891,173 @@ class LSTMAttrsT(object):
             return
         self.direction = lstmattrs.Direction()
         self.hiddenSize = lstmattrs.HiddenSize()
+        self.clip = lstmattrs.Clip()
 
     # LSTMAttrsT
     def Pack(self, builder):
         LSTMAttrsStart(builder)
         LSTMAttrsAddDirection(builder, self.direction)
         LSTMAttrsAddHiddenSize(builder, self.hiddenSize)
+        LSTMAttrsAddClip(builder, self.clip)
         lstmattrs = LSTMAttrsEnd(builder)
         return lstmattrs
 
 
-class MaxPoolAttrs(object):
+class RNNAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = MaxPoolAttrs()
+        x = RNNAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsMaxPoolAttrs(cls, buf, offset=0):
+    def GetRootAsRNNAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def MaxPoolAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def RNNAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # MaxPoolAttrs
+    # RNNAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # MaxPoolAttrs
-    def KernelSize(self, j):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        if o != 0:
-            a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
-        return 0
-
-    # MaxPoolAttrs
-    def KernelSizeAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
-        return 0
-
-    # MaxPoolAttrs
-    def KernelSizeLength(self):
+    # RNNAttrs
+    def Direction(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.VectorLen(o)
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
         return 0
 
-    # MaxPoolAttrs
-    def KernelSizeIsNone(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        return o == 0
-
-    # MaxPoolAttrs
-    def PadMode(self):
+    # RNNAttrs
+    def HiddenSize(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
         return 0
 
-    # MaxPoolAttrs
-    def Pads(self, j):
+    # RNNAttrs
+    def Activations(self, j):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
         if o != 0:
             a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 1))
         return 0
 
-    # MaxPoolAttrs
-    def PadsAsNumpy(self):
+    # RNNAttrs
+    def ActivationsAsNumpy(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
         if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint8Flags, o)
         return 0
 
-    # MaxPoolAttrs
-    def PadsLength(self):
+    # RNNAttrs
+    def ActivationsLength(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
         if o != 0:
             return self._tab.VectorLen(o)
         return 0
 
-    # MaxPoolAttrs
-    def PadsIsNone(self):
+    # RNNAttrs
+    def ActivationsIsNone(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
         return o == 0
 
-    # MaxPoolAttrs
-    def Strides(self, j):
+    # RNNAttrs
+    def ActivationAlpha(self, j):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
         if o != 0:
             a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
         return 0
 
-    # MaxPoolAttrs
-    def StridesAsNumpy(self):
+    # RNNAttrs
+    def ActivationAlphaAsNumpy(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
         if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Float32Flags, o)
         return 0
 
-    # MaxPoolAttrs
-    def StridesLength(self):
+    # RNNAttrs
+    def ActivationAlphaLength(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
         if o != 0:
             return self._tab.VectorLen(o)
         return 0
 
-    # MaxPoolAttrs
-    def StridesIsNone(self):
+    # RNNAttrs
+    def ActivationAlphaIsNone(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
         return o == 0
 
-def MaxPoolAttrsStart(builder):
-    builder.StartObject(4)
+    # RNNAttrs
+    def ActivationBeta(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
 
-def MaxPoolAttrsAddKernelSize(builder, kernelSize):
-    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(kernelSize), 0)
+    # RNNAttrs
+    def ActivationBetaAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Float32Flags, o)
+        return 0
 
-def MaxPoolAttrsStartKernelSizeVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
+    # RNNAttrs
+    def ActivationBetaLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
 
-def MaxPoolAttrsAddPadMode(builder, padMode):
-    builder.PrependUint8Slot(1, padMode, 0)
+    # RNNAttrs
+    def ActivationBetaIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        return o == 0
 
-def MaxPoolAttrsAddPads(builder, pads):
-    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(pads), 0)
+    # RNNAttrs
+    def Clip(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
 
-def MaxPoolAttrsStartPadsVector(builder, numElems):
+def RNNAttrsStart(builder):
+    builder.StartObject(6)
+
+def RNNAttrsAddDirection(builder, direction):
+    builder.PrependUint8Slot(0, direction, 0)
+
+def RNNAttrsAddHiddenSize(builder, hiddenSize):
+    builder.PrependUint32Slot(1, hiddenSize, 0)
+
+def RNNAttrsAddActivations(builder, activations):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(activations), 0)
+
+def RNNAttrsStartActivationsVector(builder, numElems):
+    return builder.StartVector(1, numElems, 1)
+
+def RNNAttrsAddActivationAlpha(builder, activationAlpha):
+    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(activationAlpha), 0)
+
+def RNNAttrsStartActivationAlphaVector(builder, numElems):
     return builder.StartVector(4, numElems, 4)
 
-def MaxPoolAttrsAddStrides(builder, strides):
-    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(strides), 0)
+def RNNAttrsAddActivationBeta(builder, activationBeta):
+    builder.PrependUOffsetTRelativeSlot(4, flatbuffers.number_types.UOffsetTFlags.py_type(activationBeta), 0)
 
-def MaxPoolAttrsStartStridesVector(builder, numElems):
+def RNNAttrsStartActivationBetaVector(builder, numElems):
     return builder.StartVector(4, numElems, 4)
 
-def MaxPoolAttrsEnd(builder):
+def RNNAttrsAddClip(builder, clip):
+    builder.PrependFloat32Slot(5, clip, 0.0)
+
+def RNNAttrsEnd(builder):
     return builder.EndObject()
 
 
@@ -2357,20 +3066,614 @@ try:
 except:
     pass
 
-class MaxPoolAttrsT(object):
+class RNNAttrsT(object):
 
-    # MaxPoolAttrsT
+    # RNNAttrsT
     def __init__(self):
-        self.kernelSize = None  # type: List[int]
+        self.direction = 0  # type: int
+        self.hiddenSize = 0  # type: int
+        self.activations = None  # type: List[int]
+        self.activationAlpha = None  # type: List[float]
+        self.activationBeta = None  # type: List[float]
+        self.clip = 0.0  # type: float
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        rnnattrs = RNNAttrs()
+        rnnattrs.Init(buf, pos)
+        return cls.InitFromObj(rnnattrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, rnnattrs):
+        x = RNNAttrsT()
+        x._UnPack(rnnattrs)
+        return x
+
+    # RNNAttrsT
+    def _UnPack(self, rnnattrs):
+        if rnnattrs is None:
+            return
+        self.direction = rnnattrs.Direction()
+        self.hiddenSize = rnnattrs.HiddenSize()
+        if not rnnattrs.ActivationsIsNone():
+            if np is None:
+                self.activations = []
+                for i in range(rnnattrs.ActivationsLength()):
+                    self.activations.append(rnnattrs.Activations(i))
+            else:
+                self.activations = rnnattrs.ActivationsAsNumpy()
+        if not rnnattrs.ActivationAlphaIsNone():
+            if np is None:
+                self.activationAlpha = []
+                for i in range(rnnattrs.ActivationAlphaLength()):
+                    self.activationAlpha.append(rnnattrs.ActivationAlpha(i))
+            else:
+                self.activationAlpha = rnnattrs.ActivationAlphaAsNumpy()
+        if not rnnattrs.ActivationBetaIsNone():
+            if np is None:
+                self.activationBeta = []
+                for i in range(rnnattrs.ActivationBetaLength()):
+                    self.activationBeta.append(rnnattrs.ActivationBeta(i))
+            else:
+                self.activationBeta = rnnattrs.ActivationBetaAsNumpy()
+        self.clip = rnnattrs.Clip()
+
+    # RNNAttrsT
+    def Pack(self, builder):
+        if self.activations is not None:
+            if np is not None and type(self.activations) is np.ndarray:
+                activations = builder.CreateNumpyVector(self.activations)
+            else:
+                RNNAttrsStartActivationsVector(builder, len(self.activations))
+                for i in reversed(range(len(self.activations))):
+                    builder.PrependUint8(self.activations[i])
+                activations = builder.EndVector()
+        if self.activationAlpha is not None:
+            if np is not None and type(self.activationAlpha) is np.ndarray:
+                activationAlpha = builder.CreateNumpyVector(self.activationAlpha)
+            else:
+                RNNAttrsStartActivationAlphaVector(builder, len(self.activationAlpha))
+                for i in reversed(range(len(self.activationAlpha))):
+                    builder.PrependFloat32(self.activationAlpha[i])
+                activationAlpha = builder.EndVector()
+        if self.activationBeta is not None:
+            if np is not None and type(self.activationBeta) is np.ndarray:
+                activationBeta = builder.CreateNumpyVector(self.activationBeta)
+            else:
+                RNNAttrsStartActivationBetaVector(builder, len(self.activationBeta))
+                for i in reversed(range(len(self.activationBeta))):
+                    builder.PrependFloat32(self.activationBeta[i])
+                activationBeta = builder.EndVector()
+        RNNAttrsStart(builder)
+        RNNAttrsAddDirection(builder, self.direction)
+        RNNAttrsAddHiddenSize(builder, self.hiddenSize)
+        if self.activations is not None:
+            RNNAttrsAddActivations(builder, activations)
+        if self.activationAlpha is not None:
+            RNNAttrsAddActivationAlpha(builder, activationAlpha)
+        if self.activationBeta is not None:
+            RNNAttrsAddActivationBeta(builder, activationBeta)
+        RNNAttrsAddClip(builder, self.clip)
+        rnnattrs = RNNAttrsEnd(builder)
+        return rnnattrs
+
+
+class MaxPoolAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = MaxPoolAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsMaxPoolAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def MaxPoolAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # MaxPoolAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # MaxPoolAttrs
+    def KernelSize(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # MaxPoolAttrs
+    def KernelSizeAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # MaxPoolAttrs
+    def KernelSizeLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # MaxPoolAttrs
+    def KernelSizeIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
+
+    # MaxPoolAttrs
+    def PadMode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # MaxPoolAttrs
+    def Pads(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # MaxPoolAttrs
+    def PadsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # MaxPoolAttrs
+    def PadsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # MaxPoolAttrs
+    def PadsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        return o == 0
+
+    # MaxPoolAttrs
+    def Strides(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # MaxPoolAttrs
+    def StridesAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # MaxPoolAttrs
+    def StridesLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # MaxPoolAttrs
+    def StridesIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        return o == 0
+
+    # MaxPoolAttrs
+    def Dilations(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # MaxPoolAttrs
+    def DilationsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # MaxPoolAttrs
+    def DilationsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # MaxPoolAttrs
+    def DilationsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        return o == 0
+
+    # MaxPoolAttrs
+    def CeilMode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def MaxPoolAttrsStart(builder):
+    builder.StartObject(6)
+
+def MaxPoolAttrsAddKernelSize(builder, kernelSize):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(kernelSize), 0)
+
+def MaxPoolAttrsStartKernelSizeVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def MaxPoolAttrsAddPadMode(builder, padMode):
+    builder.PrependUint8Slot(1, padMode, 0)
+
+def MaxPoolAttrsAddPads(builder, pads):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(pads), 0)
+
+def MaxPoolAttrsStartPadsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def MaxPoolAttrsAddStrides(builder, strides):
+    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(strides), 0)
+
+def MaxPoolAttrsStartStridesVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def MaxPoolAttrsAddDilations(builder, dilations):
+    builder.PrependUOffsetTRelativeSlot(4, flatbuffers.number_types.UOffsetTFlags.py_type(dilations), 0)
+
+def MaxPoolAttrsStartDilationsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def MaxPoolAttrsAddCeilMode(builder, ceilMode):
+    builder.PrependBoolSlot(5, ceilMode, 0)
+
+def MaxPoolAttrsEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import List
+except:
+    pass
+
+class MaxPoolAttrsT(object):
+
+    # MaxPoolAttrsT
+    def __init__(self):
+        self.kernelSize = None  # type: List[int]
+        self.padMode = 0  # type: int
+        self.pads = None  # type: List[int]
+        self.strides = None  # type: List[int]
+        self.dilations = None  # type: List[int]
+        self.ceilMode = False  # type: bool
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        maxPoolAttrs = MaxPoolAttrs()
+        maxPoolAttrs.Init(buf, pos)
+        return cls.InitFromObj(maxPoolAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, maxPoolAttrs):
+        x = MaxPoolAttrsT()
+        x._UnPack(maxPoolAttrs)
+        return x
+
+    # MaxPoolAttrsT
+    def _UnPack(self, maxPoolAttrs):
+        if maxPoolAttrs is None:
+            return
+        if not maxPoolAttrs.KernelSizeIsNone():
+            if np is None:
+                self.kernelSize = []
+                for i in range(maxPoolAttrs.KernelSizeLength()):
+                    self.kernelSize.append(maxPoolAttrs.KernelSize(i))
+            else:
+                self.kernelSize = maxPoolAttrs.KernelSizeAsNumpy()
+        self.padMode = maxPoolAttrs.PadMode()
+        if not maxPoolAttrs.PadsIsNone():
+            if np is None:
+                self.pads = []
+                for i in range(maxPoolAttrs.PadsLength()):
+                    self.pads.append(maxPoolAttrs.Pads(i))
+            else:
+                self.pads = maxPoolAttrs.PadsAsNumpy()
+        if not maxPoolAttrs.StridesIsNone():
+            if np is None:
+                self.strides = []
+                for i in range(maxPoolAttrs.StridesLength()):
+                    self.strides.append(maxPoolAttrs.Strides(i))
+            else:
+                self.strides = maxPoolAttrs.StridesAsNumpy()
+        if not maxPoolAttrs.DilationsIsNone():
+            if np is None:
+                self.dilations = []
+                for i in range(maxPoolAttrs.DilationsLength()):
+                    self.dilations.append(maxPoolAttrs.Dilations(i))
+            else:
+                self.dilations = maxPoolAttrs.DilationsAsNumpy()
+        self.ceilMode = maxPoolAttrs.CeilMode()
+
+    # MaxPoolAttrsT
+    def Pack(self, builder):
+        if self.kernelSize is not None:
+            if np is not None and type(self.kernelSize) is np.ndarray:
+                kernelSize = builder.CreateNumpyVector(self.kernelSize)
+            else:
+                MaxPoolAttrsStartKernelSizeVector(builder, len(self.kernelSize))
+                for i in reversed(range(len(self.kernelSize))):
+                    builder.PrependUint32(self.kernelSize[i])
+                kernelSize = builder.EndVector()
+        if self.pads is not None:
+            if np is not None and type(self.pads) is np.ndarray:
+                pads = builder.CreateNumpyVector(self.pads)
+            else:
+                MaxPoolAttrsStartPadsVector(builder, len(self.pads))
+                for i in reversed(range(len(self.pads))):
+                    builder.PrependUint32(self.pads[i])
+                pads = builder.EndVector()
+        if self.strides is not None:
+            if np is not None and type(self.strides) is np.ndarray:
+                strides = builder.CreateNumpyVector(self.strides)
+            else:
+                MaxPoolAttrsStartStridesVector(builder, len(self.strides))
+                for i in reversed(range(len(self.strides))):
+                    builder.PrependUint32(self.strides[i])
+                strides = builder.EndVector()
+        if self.dilations is not None:
+            if np is not None and type(self.dilations) is np.ndarray:
+                dilations = builder.CreateNumpyVector(self.dilations)
+            else:
+                MaxPoolAttrsStartDilationsVector(builder, len(self.dilations))
+                for i in reversed(range(len(self.dilations))):
+                    builder.PrependUint32(self.dilations[i])
+                dilations = builder.EndVector()
+        MaxPoolAttrsStart(builder)
+        if self.kernelSize is not None:
+            MaxPoolAttrsAddKernelSize(builder, kernelSize)
+        MaxPoolAttrsAddPadMode(builder, self.padMode)
+        if self.pads is not None:
+            MaxPoolAttrsAddPads(builder, pads)
+        if self.strides is not None:
+            MaxPoolAttrsAddStrides(builder, strides)
+        if self.dilations is not None:
+            MaxPoolAttrsAddDilations(builder, dilations)
+        MaxPoolAttrsAddCeilMode(builder, self.ceilMode)
+        maxPoolAttrs = MaxPoolAttrsEnd(builder)
+        return maxPoolAttrs
+
+
+class LpPoolAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = LpPoolAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsLpPoolAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def LpPoolAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # LpPoolAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # LpPoolAttrs
+    def KernelSize(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # LpPoolAttrs
+    def KernelSizeAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # LpPoolAttrs
+    def KernelSizeLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # LpPoolAttrs
+    def KernelSizeIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
+
+    # LpPoolAttrs
+    def PadMode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # LpPoolAttrs
+    def Pads(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # LpPoolAttrs
+    def PadsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # LpPoolAttrs
+    def PadsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # LpPoolAttrs
+    def PadsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        return o == 0
+
+    # LpPoolAttrs
+    def Strides(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # LpPoolAttrs
+    def StridesAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # LpPoolAttrs
+    def StridesLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # LpPoolAttrs
+    def StridesIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        return o == 0
+
+    # LpPoolAttrs
+    def Dilations(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # LpPoolAttrs
+    def DilationsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # LpPoolAttrs
+    def DilationsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # LpPoolAttrs
+    def DilationsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        return o == 0
+
+    # LpPoolAttrs
+    def CeilMode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+    # LpPoolAttrs
+    def P(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 2
+
+def LpPoolAttrsStart(builder):
+    builder.StartObject(7)
+
+def LpPoolAttrsAddKernelSize(builder, kernelSize):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(kernelSize), 0)
+
+def LpPoolAttrsStartKernelSizeVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def LpPoolAttrsAddPadMode(builder, padMode):
+    builder.PrependUint8Slot(1, padMode, 0)
+
+def LpPoolAttrsAddPads(builder, pads):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(pads), 0)
+
+def LpPoolAttrsStartPadsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def LpPoolAttrsAddStrides(builder, strides):
+    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(strides), 0)
+
+def LpPoolAttrsStartStridesVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def LpPoolAttrsAddDilations(builder, dilations):
+    builder.PrependUOffsetTRelativeSlot(4, flatbuffers.number_types.UOffsetTFlags.py_type(dilations), 0)
+
+def LpPoolAttrsStartDilationsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def LpPoolAttrsAddCeilMode(builder, ceilMode):
+    builder.PrependBoolSlot(5, ceilMode, 0)
+
+def LpPoolAttrsAddP(builder, p):
+    builder.PrependInt32Slot(6, p, 2)
+
+def LpPoolAttrsEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import List
+except:
+    pass
+
+class LpPoolAttrsT(object):
+
+    # LpPoolAttrsT
+    def __init__(self):
+        self.kernelSize = None  # type: List[int]
         self.padMode = 0  # type: int
         self.pads = None  # type: List[int]
         self.strides = None  # type: List[int]
+        self.dilations = None  # type: List[int]
+        self.ceilMode = False  # type: bool
+        self.p = 2  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        maxPoolAttrs = MaxPoolAttrs()
-        maxPoolAttrs.Init(buf, pos)
-        return cls.InitFromObj(maxPoolAttrs)
+        lpPoolAttrs = LpPoolAttrs()
+        lpPoolAttrs.Init(buf, pos)
+        return cls.InitFromObj(lpPoolAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -2378,45 +3681,54 @@ class MaxPoolAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, maxPoolAttrs):
-        x = MaxPoolAttrsT()
-        x._UnPack(maxPoolAttrs)
+    def InitFromObj(cls, lpPoolAttrs):
+        x = LpPoolAttrsT()
+        x._UnPack(lpPoolAttrs)
         return x
 
-    # MaxPoolAttrsT
-    def _UnPack(self, maxPoolAttrs):
-        if maxPoolAttrs is None:
+    # LpPoolAttrsT
+    def _UnPack(self, lpPoolAttrs):
+        if lpPoolAttrs is None:
             return
-        if not maxPoolAttrs.KernelSizeIsNone():
+        if not lpPoolAttrs.KernelSizeIsNone():
             if np is None:
                 self.kernelSize = []
-                for i in range(maxPoolAttrs.KernelSizeLength()):
-                    self.kernelSize.append(maxPoolAttrs.KernelSize(i))
+                for i in range(lpPoolAttrs.KernelSizeLength()):
+                    self.kernelSize.append(lpPoolAttrs.KernelSize(i))
             else:
-                self.kernelSize = maxPoolAttrs.KernelSizeAsNumpy()
-        self.padMode = maxPoolAttrs.PadMode()
-        if not maxPoolAttrs.PadsIsNone():
+                self.kernelSize = lpPoolAttrs.KernelSizeAsNumpy()
+        self.padMode = lpPoolAttrs.PadMode()
+        if not lpPoolAttrs.PadsIsNone():
             if np is None:
                 self.pads = []
-                for i in range(maxPoolAttrs.PadsLength()):
-                    self.pads.append(maxPoolAttrs.Pads(i))
+                for i in range(lpPoolAttrs.PadsLength()):
+                    self.pads.append(lpPoolAttrs.Pads(i))
             else:
-                self.pads = maxPoolAttrs.PadsAsNumpy()
-        if not maxPoolAttrs.StridesIsNone():
+                self.pads = lpPoolAttrs.PadsAsNumpy()
+        if not lpPoolAttrs.StridesIsNone():
             if np is None:
                 self.strides = []
-                for i in range(maxPoolAttrs.StridesLength()):
-                    self.strides.append(maxPoolAttrs.Strides(i))
+                for i in range(lpPoolAttrs.StridesLength()):
+                    self.strides.append(lpPoolAttrs.Strides(i))
             else:
-                self.strides = maxPoolAttrs.StridesAsNumpy()
+                self.strides = lpPoolAttrs.StridesAsNumpy()
+        if not lpPoolAttrs.DilationsIsNone():
+            if np is None:
+                self.dilations = []
+                for i in range(lpPoolAttrs.DilationsLength()):
+                    self.dilations.append(lpPoolAttrs.Dilations(i))
+            else:
+                self.dilations = lpPoolAttrs.DilationsAsNumpy()
+        self.ceilMode = lpPoolAttrs.CeilMode()
+        self.p = lpPoolAttrs.P()
 
-    # MaxPoolAttrsT
+    # LpPoolAttrsT
     def Pack(self, builder):
         if self.kernelSize is not None:
             if np is not None and type(self.kernelSize) is np.ndarray:
                 kernelSize = builder.CreateNumpyVector(self.kernelSize)
             else:
-                MaxPoolAttrsStartKernelSizeVector(builder, len(self.kernelSize))
+                LpPoolAttrsStartKernelSizeVector(builder, len(self.kernelSize))
                 for i in reversed(range(len(self.kernelSize))):
                     builder.PrependUint32(self.kernelSize[i])
                 kernelSize = builder.EndVector()
@@ -2424,7 +3736,7 @@ class MaxPoolAttrsT(object):
             if np is not None and type(self.pads) is np.ndarray:
                 pads = builder.CreateNumpyVector(self.pads)
             else:
-                MaxPoolAttrsStartPadsVector(builder, len(self.pads))
+                LpPoolAttrsStartPadsVector(builder, len(self.pads))
                 for i in reversed(range(len(self.pads))):
                     builder.PrependUint32(self.pads[i])
                 pads = builder.EndVector()
@@ -2432,20 +3744,109 @@ class MaxPoolAttrsT(object):
             if np is not None and type(self.strides) is np.ndarray:
                 strides = builder.CreateNumpyVector(self.strides)
             else:
-                MaxPoolAttrsStartStridesVector(builder, len(self.strides))
+                LpPoolAttrsStartStridesVector(builder, len(self.strides))
                 for i in reversed(range(len(self.strides))):
                     builder.PrependUint32(self.strides[i])
                 strides = builder.EndVector()
-        MaxPoolAttrsStart(builder)
+        if self.dilations is not None:
+            if np is not None and type(self.dilations) is np.ndarray:
+                dilations = builder.CreateNumpyVector(self.dilations)
+            else:
+                LpPoolAttrsStartDilationsVector(builder, len(self.dilations))
+                for i in reversed(range(len(self.dilations))):
+                    builder.PrependUint32(self.dilations[i])
+                dilations = builder.EndVector()
+        LpPoolAttrsStart(builder)
         if self.kernelSize is not None:
-            MaxPoolAttrsAddKernelSize(builder, kernelSize)
-        MaxPoolAttrsAddPadMode(builder, self.padMode)
+            LpPoolAttrsAddKernelSize(builder, kernelSize)
+        LpPoolAttrsAddPadMode(builder, self.padMode)
         if self.pads is not None:
-            MaxPoolAttrsAddPads(builder, pads)
+            LpPoolAttrsAddPads(builder, pads)
         if self.strides is not None:
-            MaxPoolAttrsAddStrides(builder, strides)
-        maxPoolAttrs = MaxPoolAttrsEnd(builder)
-        return maxPoolAttrs
+            LpPoolAttrsAddStrides(builder, strides)
+        if self.dilations is not None:
+            LpPoolAttrsAddDilations(builder, dilations)
+        LpPoolAttrsAddCeilMode(builder, self.ceilMode)
+        LpPoolAttrsAddP(builder, self.p)
+        lpPoolAttrs = LpPoolAttrsEnd(builder)
+        return lpPoolAttrs
+
+
+class GlobalLpPoolAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = GlobalLpPoolAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsGlobalLpPoolAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def GlobalLpPoolAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # GlobalLpPoolAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # GlobalLpPoolAttrs
+    def P(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 2
+
+def GlobalLpPoolAttrsStart(builder):
+    builder.StartObject(1)
+
+def GlobalLpPoolAttrsAddP(builder, p):
+    builder.PrependInt32Slot(0, p, 2)
+
+def GlobalLpPoolAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class GlobalLpPoolAttrsT(object):
+
+    # GlobalLpPoolAttrsT
+    def __init__(self):
+        self.p = 2  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        globalLpPoolAttrs = GlobalLpPoolAttrs()
+        globalLpPoolAttrs.Init(buf, pos)
+        return cls.InitFromObj(globalLpPoolAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, globalLpPoolAttrs):
+        x = GlobalLpPoolAttrsT()
+        x._UnPack(globalLpPoolAttrs)
+        return x
+
+    # GlobalLpPoolAttrsT
+    def _UnPack(self, globalLpPoolAttrs):
+        if globalLpPoolAttrs is None:
+            return
+        self.p = globalLpPoolAttrs.P()
+
+    # GlobalLpPoolAttrsT
+    def Pack(self, builder):
+        GlobalLpPoolAttrsStart(builder)
+        GlobalLpPoolAttrsAddP(builder, self.p)
+        globalLpPoolAttrs = GlobalLpPoolAttrsEnd(builder)
+        return globalLpPoolAttrs
 
 
 class ModAttrs(object):
@@ -2679,6 +4080,83 @@ class OneHotAttrsT(object):
         return oneHotAttrs
 
 
+class PadAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = PadAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsPadAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def PadAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # PadAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # PadAttrs
+    def Mode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+def PadAttrsStart(builder):
+    builder.StartObject(1)
+
+def PadAttrsAddMode(builder, mode):
+    builder.PrependUint8Slot(0, mode, 0)
+
+def PadAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class PadAttrsT(object):
+
+    # PadAttrsT
+    def __init__(self):
+        self.mode = 0  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        padAttrs = PadAttrs()
+        padAttrs.Init(buf, pos)
+        return cls.InitFromObj(padAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, padAttrs):
+        x = PadAttrsT()
+        x._UnPack(padAttrs)
+        return x
+
+    # PadAttrsT
+    def _UnPack(self, padAttrs):
+        if padAttrs is None:
+            return
+        self.mode = padAttrs.Mode()
+
+    # PadAttrsT
+    def Pack(self, builder):
+        PadAttrsStart(builder)
+        PadAttrsAddMode(builder, self.mode)
+        padAttrs = PadAttrsEnd(builder)
+        return padAttrs
+
+
 class RandomUniformAttrs(object):
     __slots__ = ['_tab']
 
@@ -3089,8 +4567,63 @@ class ResizeAttrs(object):
             return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
         return 0
 
+    # ResizeAttrs
+    def CubicCoeffA(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return -0.75
+
+    # ResizeAttrs
+    def Antialias(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+    # ResizeAttrs
+    def ExcludeOutside(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+    # ResizeAttrs
+    def ExtrapolationValue(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+    # ResizeAttrs
+    def Axes(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(18))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # ResizeAttrs
+    def AxesAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(18))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Int32Flags, o)
+        return 0
+
+    # ResizeAttrs
+    def AxesLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(18))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # ResizeAttrs
+    def AxesIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(18))
+        return o == 0
+
 def ResizeAttrsStart(builder):
-    builder.StartObject(3)
+    builder.StartObject(8)
 
 def ResizeAttrsAddMode(builder, mode):
     builder.PrependUint8Slot(0, mode, 0)
@@ -3101,10 +4634,32 @@ def ResizeAttrsAddCoordMode(builder, coordMode):
 def ResizeAttrsAddNearestMode(builder, nearestMode):
     builder.PrependUint8Slot(2, nearestMode, 0)
 
+def ResizeAttrsAddCubicCoeffA(builder, cubicCoeffA):
+    builder.PrependFloat32Slot(3, cubicCoeffA, -0.75)
+
+def ResizeAttrsAddAntialias(builder, antialias):
+    builder.PrependBoolSlot(4, antialias, 0)
+
+def ResizeAttrsAddExcludeOutside(builder, excludeOutside):
+    builder.PrependBoolSlot(5, excludeOutside, 0)
+
+def ResizeAttrsAddExtrapolationValue(builder, extrapolationValue):
+    builder.PrependFloat32Slot(6, extrapolationValue, 0.0)
+
+def ResizeAttrsAddAxes(builder, axes):
+    builder.PrependUOffsetTRelativeSlot(7, flatbuffers.number_types.UOffsetTFlags.py_type(axes), 0)
+
+def ResizeAttrsStartAxesVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
 def ResizeAttrsEnd(builder):
     return builder.EndObject()
 
 
+try:
+    from typing import List
+except:
+    pass
 
 class ResizeAttrsT(object):
 
@@ -3113,6 +4668,11 @@ class ResizeAttrsT(object):
         self.mode = 0  # type: int
         self.coordMode = 0  # type: int
         self.nearestMode = 0  # type: int
+        self.cubicCoeffA = -0.75  # type: float
+        self.antialias = False  # type: bool
+        self.excludeOutside = False  # type: bool
+        self.extrapolationValue = 0.0  # type: float
+        self.axes = None  # type: List[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
@@ -3138,13 +4698,38 @@ class ResizeAttrsT(object):
         self.mode = resizeAttrs.Mode()
         self.coordMode = resizeAttrs.CoordMode()
         self.nearestMode = resizeAttrs.NearestMode()
+        self.cubicCoeffA = resizeAttrs.CubicCoeffA()
+        self.antialias = resizeAttrs.Antialias()
+        self.excludeOutside = resizeAttrs.ExcludeOutside()
+        self.extrapolationValue = resizeAttrs.ExtrapolationValue()
+        if not resizeAttrs.AxesIsNone():
+            if np is None:
+                self.axes = []
+                for i in range(resizeAttrs.AxesLength()):
+                    self.axes.append(resizeAttrs.Axes(i))
+            else:
+                self.axes = resizeAttrs.AxesAsNumpy()
 
     # ResizeAttrsT
     def Pack(self, builder):
+        if self.axes is not None:
+            if np is not None and type(self.axes) is np.ndarray:
+                axes = builder.CreateNumpyVector(self.axes)
+            else:
+                ResizeAttrsStartAxesVector(builder, len(self.axes))
+                for i in reversed(range(len(self.axes))):
+                    builder.PrependInt32(self.axes[i])
+                axes = builder.EndVector()
         ResizeAttrsStart(builder)
         ResizeAttrsAddMode(builder, self.mode)
         ResizeAttrsAddCoordMode(builder, self.coordMode)
         ResizeAttrsAddNearestMode(builder, self.nearestMode)
+        ResizeAttrsAddCubicCoeffA(builder, self.cubicCoeffA)
+        ResizeAttrsAddAntialias(builder, self.antialias)
+        ResizeAttrsAddExcludeOutside(builder, self.excludeOutside)
+        ResizeAttrsAddExtrapolationValue(builder, self.extrapolationValue)
+        if self.axes is not None:
+            ResizeAttrsAddAxes(builder, axes)
         resizeAttrs = ResizeAttrsEnd(builder)
         return resizeAttrs
 
@@ -3316,6 +4901,96 @@ class ScatterNDAttrsT(object):
         return scatterNdattrs
 
 
+class SeluAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = SeluAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsSeluAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def SeluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # SeluAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # SeluAttrs
+    def Alpha(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+    # SeluAttrs
+    def Gamma(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+def SeluAttrsStart(builder):
+    builder.StartObject(2)
+
+def SeluAttrsAddAlpha(builder, alpha):
+    builder.PrependFloat32Slot(0, alpha, 0.0)
+
+def SeluAttrsAddGamma(builder, gamma):
+    builder.PrependFloat32Slot(1, gamma, 0.0)
+
+def SeluAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class SeluAttrsT(object):
+
+    # SeluAttrsT
+    def __init__(self):
+        self.alpha = 0.0  # type: float
+        self.gamma = 0.0  # type: float
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        seluAttrs = SeluAttrs()
+        seluAttrs.Init(buf, pos)
+        return cls.InitFromObj(seluAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, seluAttrs):
+        x = SeluAttrsT()
+        x._UnPack(seluAttrs)
+        return x
+
+    # SeluAttrsT
+    def _UnPack(self, seluAttrs):
+        if seluAttrs is None:
+            return
+        self.alpha = seluAttrs.Alpha()
+        self.gamma = seluAttrs.Gamma()
+
+    # SeluAttrsT
+    def Pack(self, builder):
+        SeluAttrsStart(builder)
+        SeluAttrsAddAlpha(builder, self.alpha)
+        SeluAttrsAddGamma(builder, self.gamma)
+        seluAttrs = SeluAttrsEnd(builder)
+        return seluAttrs
+
+
 class SoftmaxAttrs(object):
     __slots__ = ['_tab']
 
@@ -3692,57 +5367,441 @@ class TransposeAttrsT(object):
         return transposeAttrs
 
 
-class TriluAttrs(object):
+class TriluAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = TriluAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsTriluAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def TriluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # TriluAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # TriluAttrs
+    def Upper(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def TriluAttrsStart(builder):
+    builder.StartObject(1)
+
+def TriluAttrsAddUpper(builder, upper):
+    builder.PrependBoolSlot(0, upper, 0)
+
+def TriluAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class TriluAttrsT(object):
+
+    # TriluAttrsT
+    def __init__(self):
+        self.upper = False  # type: bool
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        triluAttrs = TriluAttrs()
+        triluAttrs.Init(buf, pos)
+        return cls.InitFromObj(triluAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, triluAttrs):
+        x = TriluAttrsT()
+        x._UnPack(triluAttrs)
+        return x
+
+    # TriluAttrsT
+    def _UnPack(self, triluAttrs):
+        if triluAttrs is None:
+            return
+        self.upper = triluAttrs.Upper()
+
+    # TriluAttrsT
+    def Pack(self, builder):
+        TriluAttrsStart(builder)
+        TriluAttrsAddUpper(builder, self.upper)
+        triluAttrs = TriluAttrsEnd(builder)
+        return triluAttrs
+
+
+class CustomAttr(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = CustomAttr()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsCustomAttr(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def CustomAttrBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # CustomAttr
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # CustomAttr
+    def Name(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+    # CustomAttr
+    def Type(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # CustomAttr
+    def IntValue(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int64Flags, o + self._tab.Pos)
+        return 0
+
+    # CustomAttr
+    def FloatValue(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+    # CustomAttr
+    def StringValue(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+    # CustomAttr
+    def Ints(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Int64Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 8))
+        return 0
+
+    # CustomAttr
+    def IntsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Int64Flags, o)
+        return 0
+
+    # CustomAttr
+    def IntsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # CustomAttr
+    def IntsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        return o == 0
+
+    # CustomAttr
+    def Floats(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # CustomAttr
+    def FloatsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Float32Flags, o)
+        return 0
+
+    # CustomAttr
+    def FloatsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # CustomAttr
+    def FloatsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        return o == 0
+
+    # CustomAttr
+    def Tensor(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(18))
+        if o != 0:
+            x = self._tab.Indirect(o + self._tab.Pos)
+            obj = ConstantNode()
+            obj.Init(self._tab.Bytes, x)
+            return obj
+        return None
+
+def CustomAttrStart(builder):
+    builder.StartObject(8)
+
+def CustomAttrAddName(builder, name):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(name), 0)
+
+def CustomAttrAddType(builder, type):
+    builder.PrependUint8Slot(1, type, 0)
+
+def CustomAttrAddIntValue(builder, intValue):
+    builder.PrependInt64Slot(2, intValue, 0)
+
+def CustomAttrAddFloatValue(builder, floatValue):
+    builder.PrependFloat32Slot(3, floatValue, 0.0)
+
+def CustomAttrAddStringValue(builder, stringValue):
+    builder.PrependUOffsetTRelativeSlot(4, flatbuffers.number_types.UOffsetTFlags.py_type(stringValue), 0)
+
+def CustomAttrAddInts(builder, ints):
+    builder.PrependUOffsetTRelativeSlot(5, flatbuffers.number_types.UOffsetTFlags.py_type(ints), 0)
+
+def CustomAttrStartIntsVector(builder, numElems):
+    return builder.StartVector(8, numElems, 8)
+
+def CustomAttrAddFloats(builder, floats):
+    builder.PrependUOffsetTRelativeSlot(6, flatbuffers.number_types.UOffsetTFlags.py_type(floats), 0)
+
+def CustomAttrStartFloatsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def CustomAttrAddTensor(builder, tensor):
+    builder.PrependUOffsetTRelativeSlot(7, flatbuffers.number_types.UOffsetTFlags.py_type(tensor), 0)
+
+def CustomAttrEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import List, Optional
+except:
+    pass
+
+class CustomAttrT(object):
+
+    # CustomAttrT
+    def __init__(self):
+        self.name = None  # type: str
+        self.type = 0  # type: int
+        self.intValue = 0  # type: int
+        self.floatValue = 0.0  # type: float
+        self.stringValue = None  # type: str
+        self.ints = None  # type: List[int]
+        self.floats = None  # type: List[float]
+        self.tensor = None  # type: Optional[ConstantNodeT]
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        customAttr = CustomAttr()
+        customAttr.Init(buf, pos)
+        return cls.InitFromObj(customAttr)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, customAttr):
+        x = CustomAttrT()
+        x._UnPack(customAttr)
+        return x
+
+    # CustomAttrT
+    def _UnPack(self, customAttr):
+        if customAttr is None:
+            return
+        self.name = customAttr.Name()
+        self.type = customAttr.Type()
+        self.intValue = customAttr.IntValue()
+        self.floatValue = customAttr.FloatValue()
+        self.stringValue = customAttr.StringValue()
+        if not customAttr.IntsIsNone():
+            if np is None:
+                self.ints = []
+                for i in range(customAttr.IntsLength()):
+                    self.ints.append(customAttr.Ints(i))
+            else:
+                self.ints = customAttr.IntsAsNumpy()
+        if not customAttr.FloatsIsNone():
+            if np is None:
+                self.floats = []
+                for i in range(customAttr.FloatsLength()):
+                    self.floats.append(customAttr.Floats(i))
+            else:
+                self.floats = customAttr.FloatsAsNumpy()
+        if customAttr.Tensor() is not None:
+            self.tensor = ConstantNodeT.InitFromObj(customAttr.Tensor())
+
+    # CustomAttrT
+    def Pack(self, builder):
+        if self.name is not None:
+            name = builder.CreateString(self.name)
+        if self.stringValue is not None:
+            stringValue = builder.CreateString(self.stringValue)
+        if self.ints is not None:
+            if np is not None and type(self.ints) is np.ndarray:
+                ints = builder.CreateNumpyVector(self.ints)
+            else:
+                CustomAttrStartIntsVector(builder, len(self.ints))
+                for i in reversed(range(len(self.ints))):
+                    builder.PrependInt64(self.ints[i])
+                ints = builder.EndVector()
+        if self.floats is not None:
+            if np is not None and type(self.floats) is np.ndarray:
+                floats = builder.CreateNumpyVector(self.floats)
+            else:
+                CustomAttrStartFloatsVector(builder, len(self.floats))
+                for i in reversed(range(len(self.floats))):
+                    builder.PrependFloat32(self.floats[i])
+                floats = builder.EndVector()
+        if self.tensor is not None:
+            tensor = self.tensor.Pack(builder)
+        CustomAttrStart(builder)
+        if self.name is not None:
+            CustomAttrAddName(builder, name)
+        CustomAttrAddType(builder, self.type)
+        CustomAttrAddIntValue(builder, self.intValue)
+        CustomAttrAddFloatValue(builder, self.floatValue)
+        if self.stringValue is not None:
+            CustomAttrAddStringValue(builder, stringValue)
+        if self.ints is not None:
+            CustomAttrAddInts(builder, ints)
+        if self.floats is not None:
+            CustomAttrAddFloats(builder, floats)
+        if self.tensor is not None:
+            CustomAttrAddTensor(builder, tensor)
+        customAttr = CustomAttrEnd(builder)
+        return customAttr
+
+
+class CustomAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = TriluAttrs()
+        x = CustomAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsTriluAttrs(cls, buf, offset=0):
+    def GetRootAsCustomAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def TriluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def CustomAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # TriluAttrs
+    # CustomAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # TriluAttrs
-    def Upper(self):
+    # CustomAttrs
+    def OpType(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
-        return False
+            return self._tab.String(o + self._tab.Pos)
+        return None
 
-def TriluAttrsStart(builder):
-    builder.StartObject(1)
+    # CustomAttrs
+    def Domain(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
 
-def TriluAttrsAddUpper(builder, upper):
-    builder.PrependBoolSlot(0, upper, 0)
+    # CustomAttrs
+    def Attrs(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            x = self._tab.Vector(o)
+            x += flatbuffers.number_types.UOffsetTFlags.py_type(j) * 4
+            x = self._tab.Indirect(x)
+            obj = CustomAttr()
+            obj.Init(self._tab.Bytes, x)
+            return obj
+        return None
 
-def TriluAttrsEnd(builder):
+    # CustomAttrs
+    def AttrsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # CustomAttrs
+    def AttrsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        return o == 0
+
+def CustomAttrsStart(builder):
+    builder.StartObject(3)
+
+def CustomAttrsAddOpType(builder, opType):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(opType), 0)
+
+def CustomAttrsAddDomain(builder, domain):
+    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(domain), 0)
+
+def CustomAttrsAddAttrs(builder, attrs):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(attrs), 0)
+
+def CustomAttrsStartAttrsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def CustomAttrsEnd(builder):
     return builder.EndObject()
 
 
+try:
+    from typing import List
+except:
+    pass
 
-class TriluAttrsT(object):
+class CustomAttrsT(object):
 
-    # TriluAttrsT
+    # CustomAttrsT
     def __init__(self):
-        self.upper = False  # type: bool
+        self.opType = None  # type: str
+        self.domain = None  # type: str
+        self.attrs = None  # type: List[CustomAttrT]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        triluAttrs = TriluAttrs()
-        triluAttrs.Init(buf, pos)
-        return cls.InitFromObj(triluAttrs)
+        customAttrs = CustomAttrs()
+        customAttrs.Init(buf, pos)
+        return cls.InitFromObj(customAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3750,23 +5809,49 @@ class TriluAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, triluAttrs):
-        x = TriluAttrsT()
-        x._UnPack(triluAttrs)
+    def InitFromObj(cls, customAttrs):
+        x = CustomAttrsT()
+        x._UnPack(customAttrs)
         return x
 
-    # TriluAttrsT
-    def _UnPack(self, triluAttrs):
-        if triluAttrs is None:
+    # CustomAttrsT
+    def _UnPack(self, customAttrs):
+        if customAttrs is None:
             return
-        self.upper = triluAttrs.Upper()
+        self.opType = customAttrs.OpType()
+        self.domain = customAttrs.Domain()
+        if not customAttrs.AttrsIsNone():
+            self.attrs = []
+            for i in range(customAttrs.AttrsLength()):
+                if customAttrs.Attrs(i) is None:
+                    self.attrs.append(None)
+                else:
+                    customAttr_ = CustomAttrT.InitFromObj(customAttrs.Attrs(i))
+                    self.attrs.append(customAttr_)
 
-    # TriluAttrsT
+    # CustomAttrsT
     def Pack(self, builder):
-        TriluAttrsStart(builder)
-        TriluAttrsAddUpper(builder, self.upper)
-        triluAttrs = TriluAttrsEnd(builder)
-        return triluAttrs
+        if self.opType is not None:
+            opType = builder.CreateString(self.opType)
+        if self.domain is not None:
+            domain = builder.CreateString(self.domain)
+        if self.attrs is not None:
+            attrslist = []
+            for i in range(len(self.attrs)):
+                attrslist.append(self.attrs[i].Pack(builder))
+            CustomAttrsStartAttrsVector(builder, len(self.attrs))
+            for i in reversed(range(len(self.attrs))):
+                builder.PrependUOffsetTRelative(attrslist[i])
+            attrs = builder.EndVector()
+        CustomAttrsStart(builder)
+        if self.opType is not None:
+            CustomAttrsAddOpType(builder, opType)
+        if self.domain is not None:
+            CustomAttrsAddDomain(builder, domain)
+        if self.attrs is not None:
+            CustomAttrsAddAttrs(builder, attrs)
+        customAttrs = CustomAttrsEnd(builder)
+        return customAttrs
 
 
 class OperatorNode(object):
@@ -3908,7 +5993,7 @@ class OperatorNodeT(object):
     def __init__(self):
         self.type = 0  # type: int
         self.attrsType = 0  # type: int
-        self.attrs = None  # type: Union[None, ArgMaxAttrsT, AveragePoolAttrsT, BatchNormalizationAttrsT, CastAttrsT, ConcatAttrsT, ConstantOfShapeAttrsT, ConvAttrsT, ConvTransposeAttrsT, FlattenAttrsT, GatherAttrsT, GemmAttrsT, GRUAttrsT, LeakyReluAttrsT, LSTMAttrsT, MaxPoolAttrsT, ReduceMeanAttrsT, ReshapeAttrsT, ResizeAttrsT, SplitAttrsT, SoftmaxAttrsT, TransposeAttrsT, ModAttrsT, ScatterElementsAttrsT, OneHotAttrsT, TopKAttrsT, HardSigmoidAttrsT, TriluAttrsT, ScatterNDAttrsT, NonMaxSuppressionAttrsT, LayerNormalizationAttrsT, RandomUniformAttrsT]
+        self.attrs = None  # type: Union[None, ArgMaxAttrsT, AveragePoolAttrsT, BatchNormalizationAttrsT, CastAttrsT, ConcatAttrsT, ConstantOfShapeAttrsT, ConvAttrsT, ConvTransposeAttrsT, FlattenAttrsT, GatherAttrsT, GemmAttrsT, GRUAttrsT, LeakyReluAttrsT, LSTMAttrsT, MaxPoolAttrsT, ReduceMeanAttrsT, ReshapeAttrsT, ResizeAttrsT, SplitAttrsT, SoftmaxAttrsT, TransposeAttrsT, ModAttrsT, ScatterElementsAttrsT, OneHotAttrsT, TopKAttrsT, HardSigmoidAttrsT, TriluAttrsT, ScatterNDAttrsT, NonMaxSuppressionAttrsT, LayerNormalizationAttrsT, RandomUniformAttrsT, CustomAttrsT, LpPoolAttrsT, GlobalLpPoolAttrsT, PadAttrsT, RNNAttrsT, AttentionAttrsT, GroupNormalizationAttrsT, GeluAttrsT, SeluAttrsT]
         self.inputs = None  # type: List[int]
         self.outputs = None  # type: List[int]
 
@@ -4222,6 +6307,244 @@ class IntDataT(object):
         return intData
 
 
+class Float16Data(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = Float16Data()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsFloat16Data(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def Float16DataBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # Float16Data
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # Float16Data
+    def Data(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint16Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 2))
+        return 0
+
+    # Float16Data
+    def DataAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint16Flags, o)
+        return 0
+
+    # Float16Data
+    def DataLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # Float16Data
+    def DataIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
+
+def Float16DataStart(builder):
+    builder.StartObject(1)
+
+def Float16DataAddData(builder, data):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(data), 0)
+
+def Float16DataStartDataVector(builder, numElems):
+    return builder.StartVector(2, numElems, 2)
+
+def Float16DataEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import List
+except:
+    pass
+
+class Float16DataT(object):
+
+    # Float16DataT
+    def __init__(self):
+        self.data = None  # type: List[int]
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        float16Data = Float16Data()
+        float16Data.Init(buf, pos)
+        return cls.InitFromObj(float16Data)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, float16Data):
+        x = Float16DataT()
+        x._UnPack(float16Data)
+        return x
+
+    # Float16DataT
+    def _UnPack(self, float16Data):
+        if float16Data is None:
+            return
+        if not float16Data.DataIsNone():
+            if np is None:
+                self.data = []
+                for i in range(float16Data.DataLength()):
+                    self.data.append(float16Data.Data(i))
+            else:
+                self.data = float16Data.DataAsNumpy()
+
+    # Float16DataT
+    def Pack(self, builder):
+        if self.data is not None:
+            if np is not None and type(self.data) is np.ndarray:
+                data = builder.CreateNumpyVector(self.data)
+            else:
+                Float16DataStartDataVector(builder, len(self.data))
+                for i in reversed(range(len(self.data))):
+                    builder.PrependUint16(self.data[i])
+                data = builder.EndVector()
+        Float16DataStart(builder)
+        if self.data is not None:
+            Float16DataAddData(builder, data)
+        float16Data = Float16DataEnd(builder)
+        return float16Data
+
+
+class BFloat16Data(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = BFloat16Data()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsBFloat16Data(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def BFloat16DataBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # BFloat16Data
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # BFloat16Data
+    def Data(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint16Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 2))
+        return 0
+
+    # BFloat16Data
+    def DataAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint16Flags, o)
+        return 0
+
+    # BFloat16Data
+    def DataLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # BFloat16Data
+    def DataIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
+
+def BFloat16DataStart(builder):
+    builder.StartObject(1)
+
+def BFloat16DataAddData(builder, data):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(data), 0)
+
+def BFloat16DataStartDataVector(builder, numElems):
+    return builder.StartVector(2, numElems, 2)
+
+def BFloat16DataEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import List
+except:
+    pass
+
+class BFloat16DataT(object):
+
+    # BFloat16DataT
+    def __init__(self):
+        self.data = None  # type: List[int]
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        bFloat16Data = BFloat16Data()
+        bFloat16Data.Init(buf, pos)
+        return cls.InitFromObj(bFloat16Data)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, bFloat16Data):
+        x = BFloat16DataT()
+        x._UnPack(bFloat16Data)
+        return x
+
+    # BFloat16DataT
+    def _UnPack(self, bFloat16Data):
+        if bFloat16Data is None:
+            return
+        if not bFloat16Data.DataIsNone():
+            if np is None:
+                self.data = []
+                for i in range(bFloat16Data.DataLength()):
+                    self.data.append(bFloat16Data.Data(i))
+            else:
+                self.data = bFloat16Data.DataAsNumpy()
+
+    # BFloat16DataT
+    def Pack(self, builder):
+        if self.data is not None:
+            if np is not None and type(self.data) is np.ndarray:
+                data = builder.CreateNumpyVector(self.data)
+            else:
+                BFloat16DataStartDataVector(builder, len(self.data))
+                for i in reversed(range(len(self.data))):
+                    builder.PrependUint16(self.data[i])
+                data = builder.EndVector()
+        BFloat16DataStart(builder)
+        if self.data is not None:
+            BFloat16DataAddData(builder, data)
+        bFloat16Data = BFloat16DataEnd(builder)
+        return bFloat16Data
+
+
 class ConstantNode(object):
     __slots__ = ['_tab']
 
@@ -4318,7 +6641,7 @@ class ConstantNodeT(object):
     def __init__(self):
         self.shape = None  # type: List[int]
         self.dataType = 0  # type: int
-        self.data = None  # type: Union[None, FloatDataT, IntDataT]
+        self.data = None  # type: Union[None, FloatDataT, IntDataT, Float16DataT, BFloat16DataT]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
@@ -4512,8 +6835,15 @@ class ValueNode(object):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         return o == 0
 
+    # ValueNode
+    def Dtype(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return None
+
 def ValueNodeStart(builder):
-    builder.StartObject(1)
+    builder.StartObject(2)
 
 def ValueNodeAddShape(builder, shape):
     builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(shape), 0)
@@ -4521,6 +6851,9 @@ def ValueNodeAddShape(builder, shape):
 def ValueNodeStartShapeVector(builder, numElems):
     return builder.StartVector(4, numElems, 4)
 
+def ValueNodeAddDtype(builder, dtype):
+    builder.PrependUint8Slot(1, dtype, None)
+
 def ValueNodeEnd(builder):
     return builder.EndObject()
 
@@ -4535,6 +6868,7 @@ class ValueNodeT(object):
     # ValueNodeT
     def __init__(self):
         self.shape = None  # type: List[DimT]
+        self.dtype = None  # type: Optional[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
@@ -4565,6 +6899,7 @@ class ValueNodeT(object):
                 else:
                     dim_ = DimT.InitFromObj(valueNode.Shape(i))
                     self.shape.append(dim_)
+        self.dtype = valueNode.Dtype()
 
     # ValueNodeT
     def Pack(self, builder):
@@ -4579,6 +6914,7 @@ class ValueNodeT(object):
         ValueNodeStart(builder)
         if self.shape is not None:
             ValueNodeAddShape(builder, shape)
+        ValueNodeAddDtype(builder, self.dtype)
         valueNode = ValueNodeEnd(builder)
         return valueNode
 
@@ -0,0 +1,215 @@
+import unittest
+from unittest import mock
+
+import numpy as np
+import onnx
+from onnx import TensorProto, helper, numpy_helper, shape_inference
+
+from rten_convert.converter import fuse_attention
+
+
+def make_attention_model(
+    mask_shape: list[int] | None = None,
+    mask_from_mul: bool = False,
+    mask_first: bool = False,
+    scale_op: str = "Div",
+    scale_first: bool = False,
+    transpose_perm: list[int] | None = None,
+    scores_is_output: bool = False,
+    query_rank_known: bool = True,
+) -> onnx.ModelProto:
+    """
+    Build a model containing a decomposed attention subgraph:
+
+        Softmax(Q @ Transpose(K) {/,*} scale [+ mask]) @ V
+
+    `Q` has shape `[1, 2, 3, 4]` and `K` and `V` have shape `[1, 2, 5, 4]`.
+    If `query_rank_known` is false, `Q` is declared without a shape.
+    """
+    batch, heads, q_seq, kv_seq, head_size = 1, 2, 3, 5, 4
+    if transpose_perm is None:
+        transpose_perm = [0, 1, 3, 2]
+
+    inputs = [
+        helper.make_tensor_value_info(
+            "query",
+            TensorProto.FLOAT,
+            [batch, heads, q_seq, head_size] if query_rank_known else None,
+        ),
+        helper.make_tensor_value_info(
+            "key", TensorProto.FLOAT, [batch, heads, kv_seq, head_size]
+        ),
+        helper.make_tensor_value_info(
+            "value", TensorProto.FLOAT, [batch, heads, kv_seq, head_size]
+        ),
+    ]
+    initializers = [
+        numpy_helper.from_array(np.array(2.0, dtype=np.float32), "scale"),
+    ]
+    nodes = [
+        helper.make_node("Transpose", ["key"], ["key_t"], perm=transpose_perm),
+        helper.make_node("MatMul", ["query", "key_t"], ["qk"]),
+        helper.make_node(
+            scale_op,
+            ["scale", "qk"] if scale_first else ["qk", "scale"],
+            ["scores"],
+        ),
+    ]
+
+    softmax_input = "scores"
+    if mask_shape is not None or mask_from_mul:
+        inputs.append(
+            helper.make_tensor_value_info("mask_input", TensorProto.FLOAT, mask_shape)
+        )
+        mask = "mask_input"
+        if mask_from_mul:
+            initializers.append(
+                numpy_helper.from_array(np.array(-1e4, dtype=np.float32), "mask_scale")
+            )
+            nodes.append(helper.make_node("Mul", [mask, "mask_scale"], ["mask"]))
+            mask = "mask"
+        add_inputs = [mask, "scores"] if mask_first else ["scores", mask]
+        nodes.append(helper.make_node("Add", add_inputs, ["masked_scores"]))
+        softmax_input = "masked_scores"
+
+    nodes += [
+        helper.make_node("Softmax", [softmax_input], ["probs"], axis=-1),
+        helper.make_node("MatMul", ["probs", "value"], ["output"], name="attn_out"),
+    ]
+
+    outputs = [helper.make_tensor_value_info("output", TensorProto.FLOAT, None)]
+    if scores_is_output:
+        outputs.append(helper.make_tensor_value_info("scores", TensorProto.FLOAT, None))
+
+    graph = helper.make_graph(nodes, "attention", inputs, outputs, initializers)
+    return helper.make_model(graph, opset_imports=[helper.make_opsetid("", 13)])
+
+
+def get_attr(node: onnx.NodeProto, name: str):
+    for attr in node.attribute:
+        if attr.name == name:
+            return helper.get_attribute_value(attr)
+    return None
+
+
+class TestFuseAttention(unittest.TestCase):
+    def test_fuse(self):
+        cases = [
+            # Scale only
+            ({}, ["query", "key", "value"], 0.5),
+            ({"scale_op": "Mul"}, ["query", "key", "value"], 2.0),
+            ({"scale_op": "Mul", "scale_first": True}, ["query", "key", "value"], 2.0),
+            # Masks which broadcast to the scores shape.
+            (
+                {"mask_shape": [1, 1, 3, 5]},
+                ["query", "key", "value", "mask_input"],
+                0.5,
+            ),
+            (
+                {"mask_shape": [5], "mask_first": True},
+                ["query", "key", "value", "mask_input"],
+                0.5,
+            ),
+            (
+                {"mask_shape": [1, 2, 3, 5], "scale_op": "Mul"},
+                ["query", "key", "value", "mask_input"],
+                2.0,
+            ),
+        ]
+
+        for kwargs, expected_inputs, expected_scale in cases:
+            with self.subTest(**kwargs):
+                model = make_attention_model(**kwargs)
+
+                fused = fuse_attention(model)
+
+                self.assertEqual(fused, 1)
+                op_types = [node.op_type for node in model.graph.node]
+                self.assertEqual(op_types, ["Attention"])
+                attention = model.graph.node[0]
+                self.assertEqual(list(attention.input), expected_inputs)
+                self.assertEqual(list(attention.output), ["output"])
+                self.assertEqual(attention.name, "attn_out")
+                self.assertAlmostEqual(get_attr(attention, "scale"), expected_scale)
+
+    def test_fuse_mask_produced_by_mul(self):
+        # The mask is produced by the same kind of op as the scaled scores,
+        # so the scores must be found by tracing back to `Q @ Transpose(K)`.
+        for mask_first in [False, True]:
+            with self.subTest(mask_first=mask_first):
+                model = make_attention_model(
+                    mask_shape=[1, 1, 1, 5],
+                    mask_from_mul=True,
+                    mask_first=mask_first,
+                    scale_op="Mul",
+                )
+
+                fused = fuse_attention(model)
+
+                self.assertEqual(fused, 1)
+                self.assertEqual(
+                    [node.op_type for node in model.graph.node], ["Mul", "Attention"]
+                )
+                mask_mul, attention = model.graph.node
+                self.assertEqual(list(mask_mul.output), ["mask"])
+                self.assertEqual(
+                    list(attention.input), ["query", "key", "value", "mask"]
+                )
+                self.assertAlmostEqual(get_attr(attention, "scale"), 2.0)
+
+    def test_no_fuse(self):
+        cases = [
+            # Masks which don't broadcast to the scores shape, or which would
+            # expand it.
+            {"mask_shape": [1, 2, 4, 5]},
+            {"mask_shape": [2, 1, 3, 5]},
+            {"mask_shape": [1, 1, 1, 3, 5]},
+            # Mask with unknown shape.
+            {"mask_from_mul": True},
+            # Transpose which is not `K^T`.
+            {"transpose_perm": [0, 2, 1, 3]},
+            # Intermediate value used outside the subgraph.
+            {"scores_is_output": True},
+            # Input whose rank is unknown.
+            {"query_rank_known": False},
+        ]
+
+        for kwargs in cases:
+            with self.subTest(**kwargs):
+                model = make_attention_model(**kwargs)
+                op_types = [node.op_type for node in model.graph.node]
+
+                fused = fuse_attention(model)
+
+                self.assertEqual(fused, 0)
+                self.assertEqual([node.op_type for node in model.graph.node], op_types)
+
+    def test_shape_inference_skipped_without_candidates(self):
+        graph = helper.make_graph(
+            [helper.make_node("MatMul", ["a", "b"], ["c"])],
+            "matmul",
+            [
+                helper.make_tensor_value_info("a", TensorProto.FLOAT, [2, 3]),
+                helper.make_tensor_value_info("b", TensorProto.FLOAT, [3, 4]),
+            ],
+            [helper.make_tensor_value_info("c", TensorProto.FLOAT, None)],
+        )
+        cases = [
+            (helper.make_model(graph), False),
+            (make_attention_model(), True),
+        ]
+
+        for model, expect_inference in cases:
+            with self.subTest(graph=model.graph.name):
+                with mock.patch.object(
+                    shape_inference,
+                    "infer_shapes",
+                    wraps=shape_inference.infer_shapes,
+                ) as infer_shapes:
+                    fuse_attention(model)
+
+                self.assertEqual(infer_shapes.called, expect_inference)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -22,7 +22,7 @@ pub fn erf(x: f32) -> f32 {
 #[cfg_attr(target_arch = "x86_64", target_feature(enable = "avx2"))]
 #[cfg_attr(target_arch = "x86_64", target_feature(enable = "fma"))]
 #[inline]
-unsafe fn simd_erf<S: SimdFloat>(x: S) -> S {
+pub(crate) unsafe fn simd_erf<S: SimdFloat>(x: S) -> S {
     let neg_mask = x.lt(S::zero());
 
     // x = x.abs()
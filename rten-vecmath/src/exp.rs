@@ -174,6 +174,51 @@ pub fn vec_sigmoid_in_place(xs: &mut [f32]) {
     dispatch_unary_op!(xs, simd_sigmoid, sigmoid);
 }
 
+/// Compute the [Mish](https://arxiv.org/abs/1908.08681) activation function
+/// of each element in a SIMD vector.
+///
+/// ie. This computes `x * tanh(ln(1 + exp(x)))`. Using the identity
+/// `tanh(ln(y)) = (y^2 - 1) / (y^2 + 1)`, this is evaluated as
+/// `x * n / (n + 2)` where `n = exp(x) * (exp(x) + 2)`.
+///
+/// Safety: The caller must ensure the `SimdFloat` impl is usable on the current system.
+#[cfg_attr(target_arch = "x86_64", target_feature(enable = "avx2"))]
+#[cfg_attr(target_arch = "x86_64", target_feature(enable = "fma"))]
+#[inline]
+unsafe fn simd_mish<S: SimdFloat>(x: S) -> S {
+    // Above this threshold `tanh(ln(1 + exp(x)))` rounds to 1, and `n` below
+    // can overflow.
+    let x_large = x.ge(S::splat(20.0));
+
+    let exp_x = simd_exp(x);
+    let n = exp_x.mul(exp_x.add(S::splat(2.0)));
+    let y = x.mul(n).div(n.add(S::splat(2.0)));
+    y.blend(x, x_large)
+}
+
+/// Computes the [Mish](https://arxiv.org/abs/1908.08681) activation function,
+/// `x * tanh(ln(1 + exp(x)))`.
+///
+/// This is a scalar variant of [vec_mish] that uses the same algorithm.
+pub fn mish(x: f32) -> f32 {
+    // f32 is available on all systems
+    unsafe { simd_mish(x) }
+}
+
+/// Vectorized Mish function.
+///
+/// This is a vectorized version of [mish] that computes the function for
+/// each element in `xs` and writes the result to `out`. `xs` and `out` must be
+/// equal in length.
+pub fn vec_mish(xs: &[f32], out: &mut [f32]) {
+    dispatch_unary_op!(xs, out, simd_mish, mish);
+}
+
+/// Variant of [vec_mish] that modifies elements in-place.
+pub fn vec_mish_in_place(xs: &mut [f32]) {
+    dispatch_unary_op!(xs, simd_mish, mish);
+}
+
 /// Vectorized exponential function.
 ///
 /// This is a vectorized version of [exp] that computes the function for each
@@ -191,7 +236,7 @@ pub fn vec_exp_in_place(xs: &mut [f32]) {
 #[cfg(test)]
 mod tests {
     use crate::testing::{arange, benchmark_op, check_f32s_are_equal_ulps, check_with_all_f32s};
-    use crate::{exp, vec_exp, vec_sigmoid};
+    use crate::{exp, vec_exp, vec_mish, vec_sigmoid};
 
     // Maximum error of `vec_expf` compared to Rust standard library
     // implementation.
@@ -201,10 +246,17 @@ mod tests {
     // below.
     const MAX_SIGMOID_ERROR_ULPS: f32 = 4.0;
 
+    // Maximum error of `vec_mish` compared to reference implementation below.
+    const MAX_MISH_ERROR_ULPS: f32 = 4.0;
+
     fn reference_sigmoid(x: f32) -> f32 {
         1. / (1. + (-x).exp())
     }
 
+    fn reference_mish(x: f32) -> f32 {
+        x * x.exp().ln_1p().tanh()
+    }
+
     #[test]
     fn test_expf() {
         // A few simple test cases, including "typical" +/-ve inputs with
@@ -285,6 +337,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_mish() {
+        let cases: Vec<_> = arange(-6., 6., 0.001f32)
+            .chain([-105., -20., 20., 30., 105.])
+            .collect();
+        let expected: Vec<_> = cases.iter().copied().map(reference_mish).collect();
+        let mut actual = cases.clone();
+        vec_mish(&cases, &mut actual);
+
+        let results = cases
+            .iter()
+            .zip(actual.iter().zip(expected.iter()))
+            .map(|(x, (actual, expected))| (*x, *actual, *expected));
+        check_f32s_are_equal_ulps(results, MAX_MISH_ERROR_ULPS);
+    }
+
     #[test]
     #[ignore]
     fn bench_expf() {
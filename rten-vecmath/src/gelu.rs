@@ -0,0 +1,133 @@
+use std::f32::consts::{FRAC_1_SQRT_2, FRAC_2_SQRT_PI};
+
+use crate::dispatch_unary_op;
+use crate::erf::simd_erf;
+use crate::simd_vec::SimdFloat;
+use crate::tanh::simd_tanh;
+
+/// Computes the [GELU](https://arxiv.org/abs/1606.08415) activation function,
+/// `0.5 * x * (1 + erf(x / sqrt(2)))`.
+pub fn gelu(x: f32) -> f32 {
+    // Safety: f32 is available on all platforms
+    unsafe { simd_gelu(x) }
+}
+
+/// Vectorized GELU function, computed using [erf](crate::erf).
+///
+/// Safety: The caller must ensure the `SimdFloat` impl is usable on the current system.
+#[cfg_attr(target_arch = "x86_64", target_feature(enable = "avx2"))]
+#[cfg_attr(target_arch = "x86_64", target_feature(enable = "fma"))]
+#[inline]
+unsafe fn simd_gelu<S: SimdFloat>(x: S) -> S {
+    let half_x = x.mul(S::splat(0.5));
+    let y = simd_erf(x.mul(S::splat(FRAC_1_SQRT_2))).add(S::one());
+    half_x.mul(y)
+}
+
+/// Vectorized GELU function.
+///
+/// This is a vectorized version of [gelu] that computes the function for each
+/// element in `xs` and writes the result to `out`. `xs` and `out` must be equal
+/// in length.
+pub fn vec_gelu(xs: &[f32], out: &mut [f32]) {
+    dispatch_unary_op!(xs, out, simd_gelu, gelu);
+}
+
+/// Variant of [vec_gelu] that modifies elements in-place.
+pub fn vec_gelu_in_place(xs: &mut [f32]) {
+    dispatch_unary_op!(xs, simd_gelu, gelu);
+}
+
+/// Computes an approximation of the GELU activation function using tanh,
+/// `0.5 * x * (1 + tanh(sqrt(2 / pi) * (x + 0.044715 * x^3)))`.
+pub fn approx_gelu(x: f32) -> f32 {
+    // Safety: f32 is available on all platforms
+    unsafe { simd_approx_gelu(x) }
+}
+
+/// Vectorized tanh approximation of GELU, computed using [tanh](crate::tanh).
+///
+/// Safety: The caller must ensure the `SimdFloat` impl is usable on the current system.
+#[cfg_attr(target_arch = "x86_64", target_feature(enable = "avx2"))]
+#[cfg_attr(target_arch = "x86_64", target_feature(enable = "fma"))]
+#[inline]
+unsafe fn simd_approx_gelu<S: SimdFloat>(x: S) -> S {
+    // sqrt(2 / pi)
+    let sqrt_2_pi = S::splat(FRAC_2_SQRT_PI * FRAC_1_SQRT_2);
+
+    let x_cubed = x.mul(x).mul(x);
+    let inner = x_cubed.mul_add(S::splat(0.044715), x).mul(sqrt_2_pi);
+    let y = simd_tanh(inner).add(S::one());
+    x.mul(S::splat(0.5)).mul(y)
+}
+
+/// Vectorized tanh approximation of GELU.
+///
+/// This is a vectorized version of [approx_gelu] that computes the function
+/// for each element in `xs` and writes the result to `out`. `xs` and `out`
+/// must be equal in length.
+pub fn vec_approx_gelu(xs: &[f32], out: &mut [f32]) {
+    dispatch_unary_op!(xs, out, simd_approx_gelu, approx_gelu);
+}
+
+/// Variant of [vec_approx_gelu] that modifies elements in-place.
+pub fn vec_approx_gelu_in_place(xs: &mut [f32]) {
+    dispatch_unary_op!(xs, simd_approx_gelu, approx_gelu);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::{FRAC_1_SQRT_2, FRAC_2_SQRT_PI};
+
+    use super::{vec_approx_gelu, vec_gelu};
+    use crate::testing::{arange, benchmark_op, check_f32s_are_equal_atol, triples};
+
+    // Maximum difference between our GELU functions and reference
+    // implementations using `libm::erff` and `f32::tanh`. This is dominated by
+    // the error of the `erf` approximation, scaled by `x / 2`.
+    const MAX_EXPECTED_DIFF: f32 = 3e-6;
+
+    fn reference_gelu(x: f32) -> f32 {
+        0.5 * x * (1. + libm::erff(x * FRAC_1_SQRT_2))
+    }
+
+    fn reference_approx_gelu(x: f32) -> f32 {
+        let sqrt_2_pi = FRAC_2_SQRT_PI * FRAC_1_SQRT_2;
+        0.5 * x * (1. + (sqrt_2_pi * (x + 0.044715 * x.powi(3))).tanh())
+    }
+
+    #[test]
+    fn test_gelu() {
+        let input: Vec<_> = arange(-6., 6., 0.001f32).collect();
+        let mut actual = vec![0.; input.len()];
+        let expected: Vec<_> = input.iter().copied().map(reference_gelu).collect();
+
+        vec_gelu(&input, &mut actual);
+
+        check_f32s_are_equal_atol(triples(&input, &actual, &expected), MAX_EXPECTED_DIFF);
+    }
+
+    #[test]
+    fn test_approx_gelu() {
+        let input: Vec<_> = arange(-6., 6., 0.001f32).collect();
+        let mut actual = vec![0.; input.len()];
+        let expected: Vec<_> = input.iter().copied().map(reference_approx_gelu).collect();
+
+        vec_approx_gelu(&input, &mut actual);
+
+        check_f32s_are_equal_atol(triples(&input, &actual, &expected), MAX_EXPECTED_DIFF);
+    }
+
+    #[test]
+    #[ignore]
+    fn bench_gelu() {
+        benchmark_op(
+            |xs, ys| {
+                xs.iter()
+                    .zip(ys.iter_mut())
+                    .for_each(|(x, y)| *y = reference_gelu(*x))
+            },
+            vec_gelu,
+        );
+    }
+}
@@ -25,8 +25,10 @@
 
 mod erf;
 mod exp;
+mod gelu;
 pub mod simd_vec;
 mod softmax;
+mod softsign;
 mod tanh;
 mod ulp;
 
@@ -34,9 +36,16 @@ mod ulp;
 mod testing;
 
 pub use erf::{erf, vec_erf, vec_erf_in_place};
-pub use exp::{exp, sigmoid, vec_exp, vec_exp_in_place, vec_sigmoid, vec_sigmoid_in_place};
+pub use exp::{
+    exp, mish, sigmoid, vec_exp, vec_exp_in_place, vec_mish, vec_mish_in_place, vec_sigmoid,
+    vec_sigmoid_in_place,
+};
+pub use gelu::{
+    approx_gelu, gelu, vec_approx_gelu, vec_approx_gelu_in_place, vec_gelu, vec_gelu_in_place,
+};
 use simd_vec::SimdFloat;
 pub use softmax::{vec_softmax, vec_softmax_in_place};
+pub use softsign::{softsign, vec_softsign, vec_softsign_in_place};
 pub use tanh::{tanh, vec_tanh, vec_tanh_in_place};
 
 /// Maximum SIMD vector size supported by this library, in units of 32-byte lanes.
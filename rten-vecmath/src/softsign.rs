@@ -0,0 +1,78 @@
+use crate::dispatch_unary_op;
+use crate::simd_vec::SimdFloat;
+
+/// Computes the softsign function, `x / (1 + |x|)`.
+///
+/// This is a scalar variant of [vec_softsign] that uses the same algorithm.
+pub fn softsign(x: f32) -> f32 {
+    // f32 is available on all systems
+    unsafe { simd_softsign(x) }
+}
+
+/// Safety: The caller must ensure the `SimdFloat` impl is usable on the current system.
+#[cfg_attr(target_arch = "x86_64", target_feature(enable = "avx2"))]
+#[cfg_attr(target_arch = "x86_64", target_feature(enable = "fma"))]
+#[inline]
+unsafe fn simd_softsign<S: SimdFloat>(x: S) -> S {
+    x.div(x.abs().add(S::one()))
+}
+
+/// Vectorized softsign function.
+///
+/// This is a vectorized version of [softsign] that computes the function for
+/// each element in `xs` and writes the result to `out`. `xs` and `out` must be
+/// equal in length.
+pub fn vec_softsign(xs: &[f32], out: &mut [f32]) {
+    dispatch_unary_op!(xs, out, simd_softsign, softsign);
+}
+
+/// Variant of [vec_softsign] that modifies elements in-place.
+pub fn vec_softsign_in_place(xs: &mut [f32]) {
+    dispatch_unary_op!(xs, simd_softsign, softsign);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{arange, benchmark_op, check_f32s_are_equal_ulps};
+    use crate::{vec_softsign, vec_softsign_in_place};
+
+    // Maximum error of `vec_softsign` compared to the reference implementation.
+    const MAX_SOFTSIGN_ERROR_ULPS: f32 = 1.0;
+
+    fn reference_softsign(x: f32) -> f32 {
+        x / (1. + x.abs())
+    }
+
+    #[test]
+    fn test_softsign() {
+        let cases: Vec<_> = arange(-100., 100., 0.01f32)
+            .chain([f32::MIN, f32::MAX, 0.])
+            .collect();
+        let expected: Vec<_> = cases.iter().copied().map(reference_softsign).collect();
+        let mut actual = cases.clone();
+        vec_softsign(&cases, &mut actual);
+
+        let results = cases
+            .iter()
+            .zip(actual.iter().zip(expected.iter()))
+            .map(|(x, (actual, expected))| (*x, *actual, *expected));
+        check_f32s_are_equal_ulps(results, MAX_SOFTSIGN_ERROR_ULPS);
+
+        let mut actual_in_place = cases.clone();
+        vec_softsign_in_place(&mut actual_in_place);
+        assert_eq!(actual_in_place, actual);
+    }
+
+    #[test]
+    #[ignore]
+    fn bench_softsign() {
+        benchmark_op(
+            |xs, ys| {
+                xs.iter()
+                    .zip(ys.iter_mut())
+                    .for_each(|(x, y)| *y = reference_softsign(*x))
+            },
+            vec_softsign,
+        );
+    }
+}
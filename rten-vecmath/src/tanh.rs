@@ -11,7 +11,7 @@ pub fn tanh(x: f32) -> f32 {
 #[cfg_attr(target_arch = "x86_64", target_feature(enable = "avx2"))]
 #[cfg_attr(target_arch = "x86_64", target_feature(enable = "fma"))]
 #[inline]
-unsafe fn simd_tanh<S: SimdFloat>(x: S) -> S {
+pub(crate) unsafe fn simd_tanh<S: SimdFloat>(x: S) -> S {
     let x_negative = x.le(S::zero());
     let abs_x = x.abs();
 
@@ -0,0 +1,949 @@
+//! Utilities for splitting model inputs into batches and combining the
+//! outputs, and for combining concurrent requests into batches.
+
+use std::collections::VecDeque;
+use std::iter::zip;
+use std::ops::Range;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{mpsc, Arc, Condvar, Mutex, MutexGuard, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use rten_tensor::prelude::*;
+use rten_tensor::{NdTensor, SliceItem, Tensor, TensorView};
+
+use crate::graph::{DimBindings, Dimension, NodeId, RunError};
+use crate::model::Model;
+use crate::ops::{concat, pad, Input, OpError, Output};
+
+/// Options for splitting graph inputs into smaller batches, which are run
+/// separately. See [RunOptions::micro_batch](crate::RunOptions::micro_batch).
+///
+/// The batch dimension is identified by the name of a symbolic dimension in
+/// the input and output shapes declared by the model. Inputs whose declared
+/// shape does not have this dimension are passed unmodified to every
+/// micro-batch, so inputs which have a batch dimension must declare it.
+/// Inputs without a declared shape are rejected. Every requested output must
+/// have the batch dimension, so that the outputs of micro-batches can be
+/// concatenated.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MicroBatch {
+    /// Name of the symbolic batch dimension (eg. "batch").
+    pub dim: String,
+
+    /// Maximum number of batch items in each micro-batch.
+    pub size: usize,
+
+    /// Whether to run micro-batches in parallel instead of one after
+    /// another. Running in parallel can reduce latency, but peak memory usage
+    /// will be higher.
+    pub parallel: bool,
+}
+
+impl MicroBatch {
+    /// Create options which split inputs along the batch dimension `dim` into
+    /// micro-batches of at most `size` items, which are run sequentially.
+    pub fn new(dim: &str, size: usize) -> MicroBatch {
+        MicroBatch {
+            dim: dim.to_string(),
+            size,
+            parallel: false,
+        }
+    }
+
+    /// Return the ranges of batch items for each micro-batch.
+    pub(crate) fn ranges(&self, batch_size: usize) -> Vec<Range<usize>> {
+        let size = self.size.max(1);
+        (0..batch_size)
+            .step_by(size)
+            .map(|start| start..(start + size).min(batch_size))
+            .collect()
+    }
+}
+
+/// Return a view of the items in `range` along `axis` of `input`.
+pub(crate) fn slice_input<'a>(input: &Input<'a>, axis: usize, range: Range<usize>) -> Input<'a> {
+    fn slice<'a, T>(
+        view: &TensorView<'a, T>,
+        axis: usize,
+        range: Range<usize>,
+    ) -> TensorView<'a, T> {
+        let items: Vec<SliceItem> = (0..view.ndim())
+            .map(|dim| {
+                if dim == axis {
+                    range.clone().into()
+                } else {
+                    SliceItem::full_range()
+                }
+            })
+            .collect();
+        view.slice_dyn(items.as_slice())
+    }
+
+    match input {
+        Input::FloatTensor(t) => Input::FloatTensor(slice(t, axis, range)),
+        Input::IntTensor(t) => Input::IntTensor(slice(t, axis, range)),
+        Input::Int64Tensor(t) => Input::Int64Tensor(slice(t, axis, range)),
+        Input::BoolTensor(t) => Input::BoolTensor(slice(t, axis, range)),
+        Input::UInt8Tensor(t) => Input::UInt8Tensor(slice(t, axis, range)),
+    }
+}
+
+/// Return a copy of the region of `output` specified by `range`, which has
+/// one entry per dimension.
+///
+/// Returns `None` if `range` has the wrong length or is out of bounds.
+fn slice_output(output: &Output, range: &[Range<usize>]) -> Option<Output> {
+    if range.len() != output.ndim()
+        || zip(range, output.shape()).any(|(r, &size)| r.start > r.end || r.end > size)
+    {
+        return None;
+    }
+    let range: Vec<SliceItem> = range.iter().map(|r| r.clone().into()).collect();
+    let range = range.as_slice();
+
+    let sliced = match output {
+        Output::FloatTensor(t) => t.slice_dyn(range).to_tensor().into(),
+        Output::IntTensor(t) => t.slice_dyn(range).to_tensor().into(),
+        Output::Int64Tensor(t) => t.slice_dyn(range).to_tensor().into(),
+        Output::BoolTensor(t) => t.slice_dyn(range).to_tensor().into(),
+        Output::UInt8Tensor(t) => t.slice_dyn(range).to_tensor().into(),
+    };
+    Some(sliced)
+}
+
+/// Concatenate values of the same type along `axis`, after padding them at
+/// the end of each other dimension to the largest size of that dimension.
+fn pad_and_concat(values: &[&Output], axis: usize) -> Result<Output, OpError> {
+    fn pad_and_concat_typed<'a, T: Copy + Default + 'a>(
+        values: &[&'a Output],
+        axis: usize,
+    ) -> Result<Tensor<T>, OpError>
+    where
+        TensorView<'a, T>: TryFrom<&'a Output, Error = OpError>,
+    {
+        let views = values
+            .iter()
+            .map(|value| TensorView::try_from(*value))
+            .collect::<Result<Vec<_>, _>>()?;
+        let ndim = views.first().map(|view| view.ndim()).unwrap_or(0);
+        if views.iter().any(|view| view.ndim() != ndim) {
+            return Err(OpError::IncompatibleInputShapes(
+                "Values must have the same rank",
+            ));
+        }
+        let max_shape: Vec<usize> = (0..ndim)
+            .map(|dim| views.iter().map(|view| view.size(dim)).max().unwrap_or(0))
+            .collect();
+
+        let padded = views
+            .iter()
+            .map(|view| {
+                let mut padding = vec![0i32; ndim * 2];
+                for (dim, &max_size) in max_shape.iter().enumerate() {
+                    if dim != axis {
+                        padding[ndim + dim] = (max_size - view.size(dim)) as i32;
+                    }
+                }
+                if padding.iter().all(|&p| p == 0) {
+                    return Ok(None);
+                }
+                let padding = NdTensor::from_data([ndim * 2], padding);
+                pad(view.view(), &padding.view(), T::default()).map(Some)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let views: Vec<TensorView<T>> = zip(&views, &padded)
+            .map(|(view, padded)| match padded {
+                Some(padded) => padded.view(),
+                None => view.view(),
+            })
+            .collect();
+
+        concat(&views, axis as isize)
+    }
+
+    match values.first().ok_or(OpError::MissingInputs)? {
+        Output::FloatTensor(_) => pad_and_concat_typed::<f32>(values, axis).map(|t| t.into()),
+        Output::IntTensor(_) => pad_and_concat_typed::<i32>(values, axis).map(|t| t.into()),
+        Output::Int64Tensor(_) => pad_and_concat_typed::<i64>(values, axis).map(|t| t.into()),
+        Output::BoolTensor(_) => pad_and_concat_typed::<bool>(values, axis).map(|t| t.into()),
+        Output::UInt8Tensor(_) => pad_and_concat_typed::<u8>(values, axis).map(|t| t.into()),
+    }
+}
+
+/// Concatenate values of the same type along `axis`.
+pub(crate) fn concat_outputs(outputs: &[&Output], axis: usize) -> Result<Output, OpError> {
+    fn concat_typed<'a, T: Copy + 'a>(
+        outputs: &[&'a Output],
+        axis: usize,
+    ) -> Result<Tensor<T>, OpError>
+    where
+        TensorView<'a, T>: TryFrom<&'a Output, Error = OpError>,
+    {
+        let views = outputs
+            .iter()
+            .map(|output| TensorView::try_from(*output))
+            .collect::<Result<Vec<_>, _>>()?;
+        concat(&views, axis as isize)
+    }
+
+    match outputs.first().ok_or(OpError::MissingInputs)? {
+        Output::FloatTensor(_) => concat_typed::<f32>(outputs, axis).map(|t| t.into()),
+        Output::IntTensor(_) => concat_typed::<i32>(outputs, axis).map(|t| t.into()),
+        Output::Int64Tensor(_) => concat_typed::<i64>(outputs, axis).map(|t| t.into()),
+        Output::BoolTensor(_) => concat_typed::<bool>(outputs, axis).map(|t| t.into()),
+        Output::UInt8Tensor(_) => concat_typed::<u8>(outputs, axis).map(|t| t.into()),
+    }
+}
+
+/// Options for a [Batcher].
+#[derive(Clone, Debug)]
+pub struct BatcherOptions {
+    /// Name of the symbolic batch dimension (eg. "batch") in the input and
+    /// output shapes declared by the model.
+    pub batch_dim: String,
+
+    /// Maximum number of batch items to run together.
+    pub max_batch_size: usize,
+
+    /// Maximum time to wait for more requests after the first request in a
+    /// batch is received.
+    pub max_delay: Duration,
+
+    /// Whether requests whose inputs differ in size along dimensions other
+    /// than the batch dimension can be run together. If enabled, inputs are
+    /// padded at the end with zeros to the largest size in the batch.
+    /// Output dimensions that have the same symbolic name as a padded input
+    /// dimension are then trimmed to the size of each request's input.
+    ///
+    /// If disabled, only requests with the same input shapes are run
+    /// together.
+    pub pad: bool,
+}
+
+impl Default for BatcherOptions {
+    fn default() -> BatcherOptions {
+        BatcherOptions {
+            batch_dim: "batch".to_string(),
+            max_batch_size: 16,
+            max_delay: Duration::from_millis(5),
+            pad: false,
+        }
+    }
+}
+
+/// A request queued in a [Batcher].
+struct Request {
+    inputs: Vec<(NodeId, Output)>,
+
+    /// Position of the batch dimension in each input.
+    input_axes: Vec<usize>,
+
+    batch_size: usize,
+
+    /// Sizes of symbolic dimensions in this request's inputs.
+    bindings: DimBindings,
+
+    queued_at: Instant,
+    result: mpsc::Sender<Result<Vec<Output>, RunError>>,
+}
+
+impl Request {
+    /// Return true if this request can be run in the same batch as `other`.
+    fn compatible_with(&self, other: &Request, pad: bool) -> bool {
+        self.input_axes == other.input_axes
+            && self.inputs.len() == other.inputs.len()
+            && zip(&self.inputs, &other.inputs).all(|((id_a, a), (id_b, b))| {
+                id_a == id_b
+                    && Input::from(a).dtype() == Input::from(b).dtype()
+                    && a.ndim() == b.ndim()
+                    && (pad || batch_items_shape(a.shape(), b.shape(), &self.input_axes))
+            })
+    }
+}
+
+/// Return true if shapes `a` and `b` match in all dimensions except those in
+/// `axes`.
+fn batch_items_shape(a: &[usize], b: &[usize], axes: &[usize]) -> bool {
+    zip(a, b)
+        .enumerate()
+        .all(|(dim, (a, b))| a == b || axes.contains(&dim))
+}
+
+struct Queue {
+    requests: VecDeque<Request>,
+
+    /// Set when the [Batcher] is dropped.
+    shutdown: bool,
+
+    /// Set when the worker thread has exited. New requests are rejected.
+    stopped: bool,
+}
+
+/// State shared between a [Batcher] and its worker thread.
+struct Shared {
+    queue: Mutex<Queue>,
+    ready: Condvar,
+}
+
+impl Shared {
+    /// Lock the request queue.
+    ///
+    /// The queue is not left in an inconsistent state if a thread panics
+    /// while holding the lock, so poisoning is ignored.
+    fn lock_queue(&self) -> MutexGuard<'_, Queue> {
+        self.queue.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Return the error reported for requests that cannot be run because the
+/// worker thread has stopped.
+fn worker_stopped() -> RunError {
+    RunError::BatcherError("worker thread stopped".to_string())
+}
+
+/// Combines concurrent requests to run a model into batches.
+///
+/// Many requests with a small batch size use the CPU less efficiently than a
+/// single request with a larger batch size. A `Batcher` queues requests
+/// submitted via [Batcher::run] from any number of threads. A worker thread
+/// waits until [BatcherOptions::max_batch_size] batch items are queued, or
+/// [BatcherOptions::max_delay] has elapsed since the oldest request was
+/// queued. It then stacks the inputs of queued requests along the batch
+/// dimension, runs the model once and splits the outputs among the requests.
+///
+/// Every input and output of the model that is used must have the batch
+/// dimension in its declared shape.
+pub struct Batcher {
+    model: Arc<Model>,
+    opts: BatcherOptions,
+    shared: Arc<Shared>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl Batcher {
+    /// Create a batcher which runs `model` and returns the values of
+    /// `outputs`, and start its worker thread.
+    pub fn new(
+        model: Arc<Model>,
+        outputs: &[NodeId],
+        opts: BatcherOptions,
+    ) -> Result<Batcher, RunError> {
+        if opts.max_batch_size == 0 {
+            return Err(RunError::BatcherError(
+                "max batch size must be > 0".to_string(),
+            ));
+        }
+
+        let output_shapes = outputs
+            .iter()
+            .map(|&id| {
+                let shape = model
+                    .node_info(id)
+                    .ok_or(RunError::InvalidNodeId)?
+                    .shape()
+                    .unwrap_or_default();
+                if batch_axis(&shape, &opts.batch_dim).is_none() {
+                    return Err(RunError::BatcherError(format!(
+                        "output \"{}\" does not have batch dimension \"{}\"",
+                        model.node_name(id),
+                        opts.batch_dim
+                    )));
+                }
+                Ok(shape)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let shared = Arc::new(Shared {
+            queue: Mutex::new(Queue {
+                requests: VecDeque::new(),
+                shutdown: false,
+                stopped: false,
+            }),
+            ready: Condvar::new(),
+        });
+        let worker = Worker {
+            model: model.clone(),
+            outputs: zip(outputs.iter().copied(), output_shapes).collect(),
+            opts: opts.clone(),
+            shared: shared.clone(),
+        };
+
+        Ok(Batcher {
+            model,
+            opts,
+            shared,
+            worker: Some(thread::spawn(move || worker.run())),
+        })
+    }
+
+    /// Queue a request and wait for its outputs.
+    ///
+    /// The request's inputs can have any batch size. Requests with a batch
+    /// size larger than [BatcherOptions::max_batch_size] are run on their
+    /// own.
+    pub fn run(&self, inputs: Vec<(NodeId, Output)>) -> Result<Vec<Output>, RunError> {
+        let input_axes = inputs
+            .iter()
+            .map(|&(id, _)| {
+                let shape = self.model.node_info(id).and_then(|info| info.shape());
+                shape
+                    .and_then(|shape| batch_axis(&shape, &self.opts.batch_dim))
+                    .ok_or_else(|| {
+                        RunError::BatcherError(format!(
+                            "input \"{}\" does not have batch dimension \"{}\"",
+                            self.model.node_name(id),
+                            self.opts.batch_dim
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let input_views: Vec<(NodeId, Input)> = inputs
+            .iter()
+            .map(|(id, value)| (*id, value.into()))
+            .collect();
+        let bindings = self.model.bind_input_shapes(&input_views)?;
+        let batch_size = bindings.get(&self.opts.batch_dim).unwrap_or(0);
+
+        let (tx, rx) = mpsc::channel();
+        let request = Request {
+            inputs,
+            input_axes,
+            batch_size,
+            bindings,
+            queued_at: Instant::now(),
+            result: tx,
+        };
+        {
+            let mut queue = self.shared.lock_queue();
+            if queue.stopped {
+                return Err(worker_stopped());
+            }
+            queue.requests.push_back(request);
+        }
+        self.shared.ready.notify_one();
+
+        rx.recv().map_err(|_| worker_stopped())?
+    }
+}
+
+impl Drop for Batcher {
+    fn drop(&mut self) {
+        self.shared.lock_queue().shutdown = true;
+        self.shared.ready.notify_one();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+/// Return the position of the symbolic dimension `name` in `shape`.
+fn batch_axis(shape: &[Dimension], name: &str) -> Option<usize> {
+    shape
+        .iter()
+        .position(|dim| matches!(dim, Dimension::Symbolic(dim_name) if dim_name == name))
+}
+
+/// Worker thread of a [Batcher].
+struct Worker {
+    model: Arc<Model>,
+
+    /// IDs and declared shapes of outputs.
+    outputs: Vec<(NodeId, Vec<Dimension>)>,
+
+    opts: BatcherOptions,
+    shared: Arc<Shared>,
+}
+
+/// Fails queued requests and rejects new ones when the worker thread exits,
+/// including if it panics.
+struct StopGuard<'a>(&'a Shared);
+
+impl Drop for StopGuard<'_> {
+    fn drop(&mut self) {
+        let mut queue = self.0.lock_queue();
+        queue.stopped = true;
+        for request in queue.requests.drain(..) {
+            let _ = request.result.send(Err(worker_stopped()));
+        }
+    }
+}
+
+impl Worker {
+    fn run(&self) {
+        let _stop_guard = StopGuard(&self.shared);
+        while let Some(batch) = self.next_batch() {
+            // A panic while running a batch fails the requests in the batch,
+            // but not later requests. Requests which were already sent their
+            // outputs ignore the error.
+            let result = panic::catch_unwind(AssertUnwindSafe(|| self.run_batch(&batch)));
+            if result.is_err() {
+                for request in &batch {
+                    let _ = request.result.send(Err(RunError::BatcherError(
+                        "worker thread panicked while running batch".to_string(),
+                    )));
+                }
+            }
+        }
+    }
+
+    /// Wait for requests and remove the next batch from the queue. Returns
+    /// `None` when the batcher is shutting down and the queue is empty.
+    fn next_batch(&self) -> Option<Vec<Request>> {
+        let mut queue = self.shared.lock_queue();
+        while queue.requests.is_empty() {
+            if queue.shutdown {
+                return None;
+            }
+            queue = self
+                .shared
+                .ready
+                .wait(queue)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+
+        let deadline = queue.requests[0].queued_at + self.opts.max_delay;
+        loop {
+            let queued_items: usize = queue.requests.iter().map(|r| r.batch_size).sum();
+            let now = Instant::now();
+            if queue.shutdown || queued_items >= self.opts.max_batch_size || now >= deadline {
+                break;
+            }
+            queue = self
+                .shared
+                .ready
+                .wait_timeout(queue, deadline - now)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+
+        let first = queue.requests.pop_front()?;
+        let mut batch_size = first.batch_size;
+        let mut batch = vec![first];
+        let mut remaining = VecDeque::new();
+        while let Some(request) = queue.requests.pop_front() {
+            if batch_size + request.batch_size <= self.opts.max_batch_size
+                && request.compatible_with(&batch[0], self.opts.pad)
+            {
+                batch_size += request.batch_size;
+                batch.push(request);
+            } else {
+                remaining.push_back(request);
+            }
+        }
+        queue.requests = remaining;
+
+        Some(batch)
+    }
+
+    /// Run a batch of requests and send each request its outputs.
+    fn run_batch(&self, batch: &[Request]) {
+        let output_ids: Vec<NodeId> = self.outputs.iter().map(|(id, _)| *id).collect();
+
+        if let [request] = batch {
+            let inputs: Vec<(NodeId, Input)> = request
+                .inputs
+                .iter()
+                .map(|(id, value)| (*id, value.into()))
+                .collect();
+            let result = self.model.run(&inputs, &output_ids, None);
+            let _ = request.result.send(result);
+            return;
+        }
+
+        let first = &batch[0];
+        let inputs = zip(&first.inputs, &first.input_axes)
+            .enumerate()
+            .map(|(i, ((id, _), &axis))| {
+                let values: Vec<&Output> = batch.iter().map(|r| &r.inputs[i].1).collect();
+                pad_and_concat(&values, axis)
+                    .map(|value| (*id, value))
+                    .map_err(|err| {
+                        RunError::BatcherError(format!(
+                            "failed to combine input \"{}\": {}",
+                            self.model.node_name(*id),
+                            err
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>();
+        let result = inputs.and_then(|inputs| {
+            let inputs: Vec<(NodeId, Input)> = inputs
+                .iter()
+                .map(|(id, value)| (*id, value.into()))
+                .collect();
+            self.model.run(&inputs, &output_ids, None)
+        });
+
+        let outputs = match result {
+            Ok(outputs) => outputs,
+            Err(err) => {
+                for request in batch {
+                    let _ = request.result.send(Err(err.clone()));
+                }
+                return;
+            }
+        };
+
+        let mut start = 0;
+        for request in batch {
+            let end = start + request.batch_size;
+            let request_outputs = zip(&self.outputs, &outputs)
+                .map(|((id, shape), output)| {
+                    let range: Vec<Range<usize>> = zip(shape, output.shape())
+                        .map(|(dim, &size)| match dim {
+                            Dimension::Symbolic(name) if *name == self.opts.batch_dim => start..end,
+                            Dimension::Symbolic(name) if self.opts.pad => {
+                                let request_size = request.bindings.get(name).unwrap_or(size);
+                                0..request_size.min(size)
+                            }
+                            _ => 0..size,
+                        })
+                        .collect();
+                    let sliced = if shape.len() == output.ndim() {
+                        slice_output(output, &range)
+                    } else {
+                        None
+                    };
+                    sliced.ok_or_else(|| {
+                        RunError::BatcherError(format!(
+                            "output \"{}\" with shape {:?} does not match declared shape {:?}",
+                            self.model.node_name(*id),
+                            output.shape(),
+                            shape
+                        ))
+                    })
+                })
+                .collect();
+            let _ = request.result.send(request_outputs);
+            start = end;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::iter::zip;
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+
+    use rten_tensor::prelude::*;
+    use rten_tensor::{tensor, Tensor};
+
+    use super::{
+        concat_outputs, pad_and_concat, slice_input, slice_output, Batcher, BatcherOptions,
+        MicroBatch,
+    };
+    use crate::graph::{Dimension, RunError};
+    use crate::model::Model;
+    use crate::model_builder::{ModelBuilder, OpType};
+    use crate::ops::{DataType, Input, OpError, Output, ReduceSum};
+
+    /// Create a model which applies `Relu` to an input of shape
+    /// `[batch, seq]`. The model also has a `shape` output without a batch
+    /// dimension.
+    fn relu_model() -> Model {
+        let mut builder = ModelBuilder::new();
+        let shape = [
+            Dimension::Symbolic("batch".to_string()),
+            Dimension::Symbolic("seq".to_string()),
+        ];
+        let input = builder.add_value_with_dtype("input", Some(&shape), Some(DataType::Float));
+        let output = builder.add_value("output", Some(&shape));
+        let shape_out = builder.add_value("shape", None);
+        builder.add_input(input);
+        builder.add_output(output);
+        builder.add_output(shape_out);
+        builder.add_operator("relu", OpType::Relu, &[Some(input)], &[output]);
+        builder.add_operator("shape", OpType::Shape, &[Some(input)], &[shape_out]);
+        Model::load(&builder.finish()).unwrap()
+    }
+
+    /// Create a model with an input of shape `[batch, seq]` and two outputs:
+    ///
+    /// - `output`: The input plus the sum of the input over the batch
+    ///   dimension. This reveals which batch items were run together.
+    /// - `sum`: The sum over the batch dimension, which is incorrectly
+    ///   declared as having a batch dimension.
+    fn batch_sum_model() -> Model {
+        let mut builder = ModelBuilder::new();
+        let shape = [
+            Dimension::Symbolic("batch".to_string()),
+            Dimension::Symbolic("seq".to_string()),
+        ];
+        let input = builder.add_value_with_dtype("input", Some(&shape), Some(DataType::Float));
+        let sum = builder.add_value("sum", Some(&shape));
+        let output = builder.add_value("output", Some(&shape));
+        builder.add_input(input);
+        builder.add_output(output);
+        builder.add_output(sum);
+        builder.add_operator(
+            "sum",
+            OpType::ReduceSum(ReduceSum {
+                axes: Some(vec![0]),
+                keep_dims: true,
+            }),
+            &[Some(input)],
+            &[sum],
+        );
+        builder.add_operator("add", OpType::Add, &[input, sum].map(Some), &[output]);
+        Model::load(&builder.finish()).unwrap()
+    }
+
+    #[test]
+    fn test_micro_batch_ranges() {
+        let micro_batch = MicroBatch::new("batch", 3);
+        assert_eq!(micro_batch.ranges(7), [0..3, 3..6, 6..7]);
+        assert_eq!(micro_batch.ranges(4), [0..3, 3..4]);
+        assert!(micro_batch.ranges(0).is_empty());
+    }
+
+    #[test]
+    fn test_slice_input() {
+        let tensor = Tensor::from_data(&[2, 3], vec![1, 2, 3, 4, 5, 6]);
+        let input: Input = tensor.view().into();
+
+        let sliced = slice_input(&input, 1, 1..3);
+        let Input::IntTensor(sliced) = sliced else {
+            panic!("wrong type");
+        };
+        assert_eq!(sliced.shape(), &[2, 2]);
+        assert_eq!(sliced.to_vec(), &[2, 3, 5, 6]);
+    }
+
+    #[test]
+    fn test_slice_output() {
+        let output: Output = tensor!((2, 3); [1, 2, 3, 4, 5, 6]).into();
+
+        let sliced = slice_output(&output, &[1..2, 0..2]);
+        assert_eq!(sliced, Some(Output::from(tensor!((1, 2); [4, 5]))));
+
+        // Out of bounds range.
+        assert_eq!(slice_output(&output, &[1..3, 0..3]), None);
+
+        // Range with the wrong number of dimensions.
+        assert_eq!(slice_output(&output, &[0..1, 0..1, 0..1]), None);
+    }
+
+    #[test]
+    fn test_concat_outputs() {
+        let a: Output = tensor!((1, 2); [1., 2.]).into();
+        let b: Output = tensor!((2, 2); [3., 4., 5., 6.]).into();
+        let result = concat_outputs(&[&a, &b], 0).unwrap();
+        assert_eq!(
+            result,
+            Output::from(tensor!((3, 2); [1., 2., 3., 4., 5., 6.]))
+        );
+
+        // Values with different types.
+        let c: Output = tensor!((1, 2); [1, 2]).into();
+        let result = concat_outputs(&[&a, &c], 0);
+        assert_eq!(result.err(), Some(OpError::IncorrectOutputType));
+    }
+
+    #[test]
+    fn test_pad_and_concat() {
+        let a: Output = tensor!((1, 1); [1]).into();
+        let b: Output = tensor!((1, 3); [2, 3, 4]).into();
+        let result = pad_and_concat(&[&a, &b], 0).unwrap();
+        assert_eq!(result, Output::from(tensor!((2, 3); [1, 0, 0, 2, 3, 4])));
+    }
+
+    #[test]
+    fn test_batcher() {
+        let model = Arc::new(relu_model());
+        let input_id = model.input_ids()[0];
+        let output_id = model.output_ids()[0];
+        let batcher = Batcher::new(
+            model.clone(),
+            &[output_id],
+            BatcherOptions {
+                max_batch_size: 4,
+                max_delay: Duration::from_millis(50),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        thread::scope(|s| {
+            let batcher = &batcher;
+            let handles: Vec<_> = (0..6)
+                .map(|i| {
+                    s.spawn(move || {
+                        let input = Tensor::from_data(&[1, 3], vec![i as f32, -1., 2.]);
+                        batcher.run(vec![(input_id, input.into())])
+                    })
+                })
+                .collect();
+            for (i, handle) in handles.into_iter().enumerate() {
+                let outputs = handle.join().unwrap().unwrap();
+                assert_eq!(
+                    outputs,
+                    [Output::from(Tensor::from_data(
+                        &[1, 3],
+                        vec![i as f32, 0., 2.]
+                    ))]
+                );
+            }
+        });
+
+        // Request with a batch size larger than the max batch size.
+        let input = Tensor::from_data(&[5, 1], vec![-1., 1., -2., 2., 3.]);
+        let outputs = batcher.run(vec![(input_id, input.into())]).unwrap();
+        assert_eq!(
+            outputs,
+            [Output::from(Tensor::from_data(
+                &[5, 1],
+                vec![0., 1., 0., 2., 3.]
+            ))]
+        );
+    }
+
+    #[test]
+    fn test_batcher_merges_requests() {
+        let model = Arc::new(batch_sum_model());
+        let input_id = model.input_ids()[0];
+        let output_id = model.output_ids()[0];
+        let batcher = Batcher::new(
+            model,
+            &[output_id],
+            BatcherOptions {
+                max_batch_size: 3,
+                max_delay: Duration::from_secs(10),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        // The batch is run as soon as all requests are queued. Each output
+        // includes the sum of all inputs, so the requests must have been
+        // run together in a single model run.
+        thread::scope(|s| {
+            let batcher = &batcher;
+            let handles: Vec<_> = [1., 10., 100.]
+                .into_iter()
+                .map(|x| {
+                    s.spawn(move || {
+                        let input = Tensor::from_data(&[1, 1], vec![x]);
+                        batcher.run(vec![(input_id, input.into())])
+                    })
+                })
+                .collect();
+            for (handle, expected) in zip(handles, [112., 121., 211.]) {
+                assert_eq!(
+                    handle.join().unwrap().unwrap(),
+                    [Output::from(Tensor::from_data(&[1, 1], vec![expected]))]
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn test_batcher_output_mismatch() {
+        let model = Arc::new(batch_sum_model());
+        let input_id = model.input_ids()[0];
+        let sum_id = model.output_ids()[1];
+        let batcher = Batcher::new(
+            model,
+            &[sum_id],
+            BatcherOptions {
+                max_batch_size: 2,
+                max_delay: Duration::from_secs(10),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        // The `sum` output has a batch size of 1, so only the first request in
+        // a batch gets its output. This fails the other request, but the
+        // worker keeps serving later requests.
+        for _ in 0..2 {
+            thread::scope(|s| {
+                let batcher = &batcher;
+                let handles: Vec<_> = (0..2)
+                    .map(|_| {
+                        s.spawn(move || {
+                            let input = Tensor::from_data(&[1, 1], vec![1.]);
+                            batcher.run(vec![(input_id, input.into())])
+                        })
+                    })
+                    .collect();
+                let errors: Vec<RunError> = handles
+                    .into_iter()
+                    .filter_map(|handle| handle.join().unwrap().err())
+                    .collect();
+                assert_eq!(errors.len(), 1);
+                assert!(matches!(errors[0], RunError::BatcherError(_)));
+                assert!(errors[0]
+                    .to_string()
+                    .contains("does not match declared shape"));
+            });
+        }
+    }
+
+    #[test]
+    fn test_batcher_pad() {
+        let model = Arc::new(relu_model());
+        let input_id = model.input_ids()[0];
+        let output_id = model.output_ids()[0];
+        let batcher = Batcher::new(
+            model,
+            &[output_id],
+            BatcherOptions {
+                max_batch_size: 2,
+                max_delay: Duration::from_secs(10),
+                pad: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        // The batch is run as soon as both requests are queued, and outputs
+        // are trimmed to the length of each request's input.
+        thread::scope(|s| {
+            let batcher = &batcher;
+            let short = s.spawn(move || {
+                let input = Tensor::from_data(&[1, 1], vec![-1.]);
+                batcher.run(vec![(input_id, input.into())])
+            });
+            let long = s.spawn(move || {
+                let input = Tensor::from_data(&[1, 3], vec![1., -2., 3.]);
+                batcher.run(vec![(input_id, input.into())])
+            });
+            assert_eq!(
+                short.join().unwrap().unwrap(),
+                [Output::from(Tensor::from_data(&[1, 1], vec![0.]))]
+            );
+            assert_eq!(
+                long.join().unwrap().unwrap(),
+                [Output::from(Tensor::from_data(&[1, 3], vec![1., 0., 3.]))]
+            );
+        });
+    }
+
+    #[test]
+    fn test_batcher_invalid() {
+        let model = Arc::new(relu_model());
+        let shape_id = model.output_ids()[1];
+
+        let result = Batcher::new(model.clone(), &[shape_id], BatcherOptions::default());
+        assert_eq!(
+            result.err(),
+            Some(RunError::BatcherError(
+                "output \"shape\" does not have batch dimension \"batch\"".to_string()
+            ))
+        );
+
+        let result = Batcher::new(
+            model,
+            &[],
+            BatcherOptions {
+                max_batch_size: 0,
+                ..Default::default()
+            },
+        );
+        assert!(matches!(result, Err(RunError::BatcherError(_))));
+    }
+}
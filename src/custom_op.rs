@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+
+use crate::ops::Output;
+
+/// Value of an attribute of a custom operator.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AttrValue {
+    Int(i64),
+    Float(f32),
+    String(String),
+    Ints(Vec<i64>),
+    Floats(Vec<f32>),
+    Tensor(Output),
+}
+
+/// Type name and attributes of a custom operator node in a model file.
+///
+/// Custom operators are identified by a `(domain, op_type)` pair rather than
+/// a built-in operator type. They are instantiated by factories registered
+/// using [OpRegistry::register_custom_op](crate::OpRegistry::register_custom_op).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CustomOpAttrs {
+    /// Domain (namespace) of the operator. This is empty for operators in the
+    /// default domain.
+    pub domain: String,
+
+    /// Name of the operator type.
+    pub op_type: String,
+
+    /// Map of attribute name to value.
+    pub attrs: HashMap<String, AttrValue>,
+}
+
+impl CustomOpAttrs {
+    /// Create an attribute set for a custom operator with no attributes.
+    pub fn new(domain: &str, op_type: &str) -> CustomOpAttrs {
+        CustomOpAttrs {
+            domain: domain.to_string(),
+            op_type: op_type.to_string(),
+            attrs: HashMap::new(),
+        }
+    }
+
+    /// Add or replace an attribute. Returns `self` for chaining.
+    pub fn with_attr(mut self, name: &str, value: AttrValue) -> CustomOpAttrs {
+        self.attrs.insert(name.to_string(), value);
+        self
+    }
+
+    /// Return the value of an attribute, if present.
+    pub fn get(&self, name: &str) -> Option<&AttrValue> {
+        self.attrs.get(name)
+    }
+
+    /// Return the value of an integer attribute.
+    pub fn get_int(&self, name: &str) -> Option<i64> {
+        match self.get(name) {
+            Some(AttrValue::Int(val)) => Some(*val),
+            _ => None,
+        }
+    }
+
+    /// Return the value of a float attribute.
+    pub fn get_float(&self, name: &str) -> Option<f32> {
+        match self.get(name) {
+            Some(AttrValue::Float(val)) => Some(*val),
+            _ => None,
+        }
+    }
+
+    /// Return the value of a string attribute.
+    pub fn get_string(&self, name: &str) -> Option<&str> {
+        match self.get(name) {
+            Some(AttrValue::String(val)) => Some(val),
+            _ => None,
+        }
+    }
+
+    /// Return the value of an integer list attribute.
+    pub fn get_ints(&self, name: &str) -> Option<&[i64]> {
+        match self.get(name) {
+            Some(AttrValue::Ints(val)) => Some(val),
+            _ => None,
+        }
+    }
+
+    /// Return the value of a float list attribute.
+    pub fn get_floats(&self, name: &str) -> Option<&[f32]> {
+        match self.get(name) {
+            Some(AttrValue::Floats(val)) => Some(val),
+            _ => None,
+        }
+    }
+
+    /// Return the value of a tensor attribute.
+    pub fn get_tensor(&self, name: &str) -> Option<&Output> {
+        match self.get(name) {
+            Some(AttrValue::Tensor(val)) => Some(val),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rten_tensor::Tensor;
+
+    use super::{AttrValue, CustomOpAttrs};
+    use crate::ops::Output;
+
+    #[test]
+    fn test_custom_op_attrs() {
+        let tensor = Tensor::from_data(&[2], vec![1, 2]);
+        let attrs = CustomOpAttrs::new("com.example", "MyOp")
+            .with_attr("int", AttrValue::Int(3))
+            .with_attr("float", AttrValue::Float(0.5))
+            .with_attr("string", AttrValue::String("foo".into()))
+            .with_attr("ints", AttrValue::Ints(vec![1, 2]))
+            .with_attr("floats", AttrValue::Floats(vec![1., 2.]))
+            .with_attr("tensor", AttrValue::Tensor(tensor.clone().into()));
+
+        assert_eq!(attrs.get_int("int"), Some(3));
+        assert_eq!(attrs.get_float("float"), Some(0.5));
+        assert_eq!(attrs.get_string("string"), Some("foo"));
+        assert_eq!(attrs.get_ints("ints"), Some([1, 2].as_slice()));
+        assert_eq!(attrs.get_floats("floats"), Some([1., 2.].as_slice()));
+        assert_eq!(attrs.get_tensor("tensor"), Some(&Output::IntTensor(tensor)));
+
+        // Missing attribute or wrong type
+        assert_eq!(attrs.get_int("missing"), None);
+        assert_eq!(attrs.get_int("float"), None);
+    }
+}
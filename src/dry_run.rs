@@ -0,0 +1,205 @@
+//! Reports produced by shape-only "dry runs" of a model, which propagate
+//! shapes through the graph without computing values.
+
+use std::fmt;
+
+use crate::graph::{DimBindings, NodeId};
+use crate::timing::Table;
+
+/// Shape of a value produced by a step in a dry run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DryRunValue {
+    /// ID of the value node.
+    pub id: NodeId,
+
+    /// Name of the value node.
+    pub name: String,
+
+    /// Inferred shape of the value.
+    pub shape: Vec<usize>,
+}
+
+/// A single operator in a dry run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DryRunStep {
+    /// Name of the operator node.
+    pub name: String,
+
+    /// Name of the operator type (eg. "MatMul").
+    pub op: String,
+
+    /// Shapes of the operator's outputs.
+    pub outputs: Vec<DryRunValue>,
+
+    /// Estimated size in bytes of all graph inputs and intermediate values
+    /// that are alive after this step's outputs are allocated and before any
+    /// inputs that are no longer needed are freed.
+    pub live_bytes: usize,
+
+    /// Estimated number of arithmetic operations, or `None` if the operator
+    /// does not provide an estimate. See
+    /// [Operator::flops](crate::ops::Operator::flops).
+    pub flops: Option<u64>,
+}
+
+/// Result of a shape-only dry run of a model. See
+/// [Model::dry_run](crate::Model::dry_run).
+///
+/// Memory sizes are estimates which assume that every operator allocates a
+/// new buffer for each output. Operators which run in place use less memory
+/// in an actual run. Graph inputs use the sizes of their declared element
+/// types, or 4 bytes per element if the type is unknown. Operator outputs use
+/// 4 bytes per element unless their values were computed.
+///
+/// This implements [Display](fmt::Display) to format the steps as a table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DryRunReport {
+    /// Operators in execution order.
+    pub steps: Vec<DryRunStep>,
+
+    /// Total size in bytes of all operator outputs.
+    pub total_activation_bytes: usize,
+
+    /// Maximum size in bytes of the graph inputs and intermediate values that
+    /// are alive at any one time.
+    pub peak_bytes: usize,
+
+    /// Index of the step in [DryRunReport::steps] at which memory usage
+    /// peaked, or `None` if there are no steps.
+    pub peak_step: Option<usize>,
+
+    /// Sizes of symbolic dimensions, bound from the shapes of the inputs.
+    pub dim_bindings: DimBindings,
+}
+
+impl DryRunReport {
+    /// Return the inferred shape of value node `id`, if it is produced by
+    /// one of the steps.
+    pub fn shape(&self, id: NodeId) -> Option<&[usize]> {
+        self.steps
+            .iter()
+            .flat_map(|step| step.outputs.iter())
+            .find(|value| value.id == id)
+            .map(|value| value.shape.as_slice())
+    }
+
+    /// Return the sum of the estimated number of arithmetic operations for
+    /// all steps. Operators which do not provide an estimate are not counted.
+    pub fn total_flops(&self) -> u64 {
+        self.steps.iter().filter_map(|step| step.flops).sum()
+    }
+}
+
+/// Format a size in bytes using binary units.
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64;
+    let mut unit = "B";
+    for next_unit in UNITS {
+        if size < 1024. {
+            break;
+        }
+        size /= 1024.;
+        unit = next_unit;
+    }
+    format!("{:.2} {}", size, unit)
+}
+
+impl Table for DryRunReport {
+    fn rows(&self) -> usize {
+        self.steps.len()
+    }
+
+    fn headings(&self) -> &[&str] {
+        &["#", "Node", "Op", "Output shapes", "Live memory", ""]
+    }
+
+    fn cell(&self, row: usize, col: usize) -> String {
+        let step = self.steps.get(row).expect("invalid row");
+        match col {
+            0 => row.to_string(),
+            1 => step.name.clone(),
+            2 => step.op.clone(),
+            3 => step
+                .outputs
+                .iter()
+                .map(|value| format!("{:?}", value.shape))
+                .collect::<Vec<_>>()
+                .join(", "),
+            4 => format_bytes(step.live_bytes),
+            5 => {
+                if self.peak_step == Some(row) {
+                    "<-- peak".to_string()
+                } else {
+                    String::new()
+                }
+            }
+            _ => panic!("invalid column"),
+        }
+    }
+}
+
+impl fmt::Display for DryRunReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(0 /* indent */).fmt(f)?;
+        writeln!(f)?;
+        writeln!(
+            f,
+            "Total activations: {}",
+            format_bytes(self.total_activation_bytes)
+        )?;
+        write!(f, "Peak memory: {}", format_bytes(self.peak_bytes))?;
+        if let Some(step) = self.peak_step.and_then(|idx| self.steps.get(idx)) {
+            write!(f, " at step \"{}\" ({})", step.name, step.op)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_bytes, DimBindings, DryRunReport, DryRunStep, DryRunValue};
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1024), "1.00 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024 / 2), "1.50 MiB");
+        assert_eq!(format_bytes(5 * 1024 * 1024 * 1024), "5.00 GiB");
+    }
+
+    #[test]
+    fn test_dry_run_report() {
+        let step = |name: &str, id, shape: &[usize], live_bytes| DryRunStep {
+            name: name.to_string(),
+            op: "Relu".to_string(),
+            outputs: vec![DryRunValue {
+                id,
+                name: format!("{}_out", name),
+                shape: shape.to_vec(),
+            }],
+            live_bytes,
+            flops: Some(shape.iter().product::<usize>() as u64),
+        };
+        let report = DryRunReport {
+            steps: vec![step("a", 1, &[2, 3], 24), step("b", 2, &[4, 8], 152)],
+            total_activation_bytes: 152,
+            peak_bytes: 152,
+            peak_step: Some(1),
+            dim_bindings: DimBindings::default(),
+        };
+
+        assert_eq!(report.shape(2), Some([4, 8].as_slice()));
+        assert_eq!(report.shape(3), None);
+        assert_eq!(report.total_flops(), 38);
+
+        let text = report.to_string();
+        assert!(text.contains("[4, 8]"));
+        assert!(text.contains("<-- peak"));
+        assert!(text.ends_with("Peak memory: 152 B at step \"b\" (Relu)"));
+    }
+}
@@ -1,18 +1,27 @@
 use std::collections::{HashMap, HashSet};
 use std::error::Error;
 use std::fmt;
+use std::iter;
 use std::iter::zip;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 
+use rayon::prelude::*;
 use rten_tensor::prelude::*;
 use rten_tensor::Tensor;
 
-use crate::ops::{Input, InputList, OpError, Operator, Output};
+use crate::batching::{concat_outputs, slice_input, MicroBatch};
+use crate::dry_run::{DryRunReport, DryRunStep, DryRunValue};
+use crate::npy::{npy_file_stem, write_npy_file};
+use crate::ops::{
+    DataType, Input, InputList, MetaInput, MetaInputList, MetaOutput, OpError, Operator, Output,
+};
 use crate::timer::Timer;
 use crate::timing::{InputShape, RunTiming, TimingRecord, TimingSort};
 
 /// Represents the size of a dimension of a runtime-provided value, such as
 /// an operator input, output or intermediate value.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Dimension {
     /// A dimension whose expected size is fixed and specified as part of the
     /// model.
@@ -23,6 +32,66 @@ pub enum Dimension {
     Symbolic(String),
 }
 
+/// Sizes of symbolic dimensions, bound from the shapes of graph inputs.
+///
+/// The first input which has a dimension with a given symbolic name determines
+/// the size of that symbol. All other dimensions with the same name must then
+/// have the same size.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DimBindings {
+    sizes: HashMap<String, usize>,
+}
+
+impl DimBindings {
+    /// Return the size bound to the symbolic dimension `name`.
+    pub fn get(&self, name: &str) -> Option<usize> {
+        self.sizes.get(name).copied()
+    }
+
+    /// Return the size of a dimension, or `None` if it is a symbolic dimension
+    /// which has not been bound.
+    pub fn resolve(&self, dim: &Dimension) -> Option<usize> {
+        match dim {
+            Dimension::Fixed(size) => Some(*size),
+            Dimension::Symbolic(name) => self.get(name),
+        }
+    }
+
+    /// Resolve all dimensions in `shape`, or return `None` if any are
+    /// symbolic dimensions which have not been bound.
+    pub fn resolve_shape(&self, shape: &[Dimension]) -> Option<Vec<usize>> {
+        shape.iter().map(|dim| self.resolve(dim)).collect()
+    }
+
+    /// Return an iterator over `(name, size)` bindings.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, usize)> {
+        self.sizes.iter().map(|(name, size)| (name.as_str(), *size))
+    }
+
+    /// Return the number of bound symbols.
+    pub fn len(&self) -> usize {
+        self.sizes.len()
+    }
+
+    /// Return true if no symbols have been bound.
+    pub fn is_empty(&self) -> bool {
+        self.sizes.is_empty()
+    }
+}
+
+/// Information about a completed graph step, passed to a [RunObserver].
+pub(crate) struct StepInfo<'a> {
+    /// Name of the operator type (eg. "MatMul").
+    pub op: &'a str,
+
+    /// IDs and values of the step's outputs.
+    pub outputs: &'a [(NodeId, &'a Output)],
+}
+
+/// Callback invoked after each step of a graph run. See
+/// [Graph::run_with_observer].
+pub(crate) type RunObserver<'a> = &'a mut dyn FnMut(&StepInfo);
+
 pub struct OperatorNode {
     name: Option<String>,
     inputs: Vec<Option<NodeId>>,
@@ -33,6 +102,7 @@ pub struct OperatorNode {
 pub struct ValueNode {
     name: Option<String>,
     shape: Option<Vec<Dimension>>,
+    dtype: Option<DataType>,
 }
 
 pub struct ConstantNode<T> {
@@ -103,6 +173,22 @@ impl Node {
             Node::Value(node) => node.shape.clone(),
         }
     }
+
+    /// Return the element type associated with this node.
+    ///
+    /// For constants this is the type of the tensor. Operator nodes have no
+    /// type. For values (eg. inputs/outputs) this is the expected type, if
+    /// known.
+    pub fn dtype(&self) -> Option<DataType> {
+        match self {
+            Node::Operator(_) => None,
+            Node::Constant(constant) => match constant {
+                Constant::Float(_) => Some(DataType::Float),
+                Constant::Int(_) => Some(DataType::Int32),
+            },
+            Node::Value(node) => node.dtype,
+        }
+    }
 }
 
 /// ID of a node in a [Model](crate::Model) graph.
@@ -118,10 +204,79 @@ pub type NodeId = usize;
 /// or output value, or a computation step.
 pub struct Graph {
     nodes: Vec<Node>,
+
+    /// Recently used execution plans, most recently used first. Models are
+    /// usually run repeatedly with the same inputs and outputs, or alternate
+    /// between a few sets of them (eg. the prompt and generation steps of a
+    /// decoder), so this avoids the cost of planning on each run.
+    cached_plans: Mutex<Vec<Arc<CachedPlan>>>,
+}
+
+/// Maximum number of execution plans that a [Graph] caches.
+const MAX_CACHED_PLANS: usize = 4;
+
+/// Maximum number of elements in a value that is computed during a
+/// [Graph::dry_run]. Larger values are replaced by their shapes.
+const MAX_DRY_RUN_VALUE_LEN: usize = 4096;
+
+/// Summary statistics for the values in a float tensor.
+#[derive(Clone, Debug)]
+pub struct ValueStats {
+    /// Minimum value, ignoring NaNs.
+    pub min: f32,
+
+    /// Maximum value, ignoring NaNs.
+    pub max: f32,
+
+    /// Whether any values are NaN.
+    pub has_nan: bool,
+}
+
+impl ValueStats {
+    /// Compute statistics for the values in `values`.
+    ///
+    /// If the tensor is empty or contains only NaNs, `min` will be infinity
+    /// and `max` will be negative infinity.
+    pub fn from_values<'a>(values: impl Iterator<Item = &'a f32>) -> ValueStats {
+        values.fold(
+            ValueStats {
+                min: f32::INFINITY,
+                max: f32::NEG_INFINITY,
+                has_nan: false,
+            },
+            |stats, &x| ValueStats {
+                min: stats.min.min(x),
+                max: stats.max.max(x),
+                has_nan: stats.has_nan || x.is_nan(),
+            },
+        )
+    }
+}
+
+/// Statistics are compared bitwise, so that `ValueStats` can implement `Eq`
+/// and be used in [RunError].
+impl PartialEq for ValueStats {
+    fn eq(&self, other: &Self) -> bool {
+        self.min.to_bits() == other.min.to_bits()
+            && self.max.to_bits() == other.max.to_bits()
+            && self.has_nan == other.has_nan
+    }
+}
+
+impl Eq for ValueStats {}
+
+impl fmt::Display for ValueStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "min {} max {}", self.min, self.max)?;
+        if self.has_nan {
+            write!(f, " has NaN")?;
+        }
+        Ok(())
+    }
 }
 
 /// Reasons why a graph execution failed
-#[derive(Eq, PartialEq, Debug)]
+#[derive(Clone, Eq, PartialEq, Debug)]
 pub enum RunError {
     /// An input or output node ID is invalid
     InvalidNodeId,
@@ -133,12 +288,92 @@ pub enum RunError {
     /// from the input.
     PlanningError(String),
 
-    /// Execution of an operator failed
-    OperatorError { name: String, error: OpError },
+    /// Execution of an operator failed.
+    ///
+    /// `input_shapes` and `input_dtypes` contain the shapes and element types
+    /// of the operator's inputs, or `None` for omitted optional inputs. The
+    /// type is also `None` if unknown, such as during a dry run. `operator`
+    /// is the debug representation of the operator, including its attributes.
+    /// It is boxed to limit the size of `RunError`.
+    OperatorError {
+        name: String,
+        error: OpError,
+        input_shapes: Vec<Option<Vec<usize>>>,
+        input_dtypes: Vec<Option<DataType>>,
+        operator: Box<str>,
+    },
+
+    /// A graph input could not be converted to a type supported by
+    /// operators (eg. an `i64` value was outside the range of `i32`).
+    InvalidInput { name: String, error: OpError },
+
+    /// The element type of a graph input did not match the type declared by
+    /// the model.
+    IncorrectInputType {
+        name: String,
+        expected: DataType,
+        actual: DataType,
+    },
+
+    /// The number of dimensions of a graph input did not match the shape
+    /// declared by the model.
+    InputRankMismatch {
+        name: String,
+        expected: usize,
+        actual: usize,
+    },
+
+    /// The size of a graph input dimension did not match the shape declared
+    /// by the model.
+    ///
+    /// If the dimension is symbolic, `symbol` is its name and `expected` is
+    /// the size bound to it by an earlier dimension or input.
+    InputShapeMismatch {
+        name: String,
+        dim: usize,
+        expected: usize,
+        actual: usize,
+        symbol: Option<String>,
+    },
 
     /// The output of a graph operator did not match expectations (eg. the
     /// count, types or shapes of outputs did not match what was expected.)
     OutputMismatch(&'static str),
+
+    /// An operator produced an output containing NaN or infinite values.
+    ///
+    /// This is only checked if [RunOptions::check_finite] is enabled.
+    /// `input_shapes` and `input_stats` describe the operator's inputs.
+    /// `input_stats` is `None` for missing or non-float inputs.
+    NonFiniteOutput {
+        name: String,
+        op: String,
+        input_shapes: Vec<Option<Vec<usize>>>,
+        input_stats: Vec<Option<ValueStats>>,
+    },
+
+    /// The shape of an operator output did not match the shape declared by
+    /// the model, with symbolic dimensions resolved using the sizes bound from
+    /// the graph inputs.
+    ///
+    /// This is only checked if [RunOptions::check_shapes] is enabled.
+    OutputShapeMismatch {
+        name: String,
+        op: String,
+        expected: Vec<Dimension>,
+        actual: Vec<usize>,
+    },
+
+    /// Writing a value to [RunOptions::dump_dir] failed.
+    DumpError { name: String, error: String },
+
+    /// Inputs could not be split into micro-batches, or the outputs of
+    /// micro-batches could not be combined. See [RunOptions::micro_batch].
+    MicroBatchError(String),
+
+    /// A [Batcher](crate::Batcher) could not combine requests into a batch or
+    /// split the outputs among them, or its worker thread stopped.
+    BatcherError(String),
 }
 
 impl fmt::Display for RunError {
@@ -150,12 +385,140 @@ impl fmt::Display for RunError {
             RunError::OperatorError {
                 name,
                 error: ref err,
-            } => write!(f, "operator \"{}\" failed: {:?}", name, err),
+                input_shapes,
+                input_dtypes,
+                operator,
+            } => write!(
+                f,
+                "operator \"{}\" failed: {} (input shapes: {:?}, input types: {:?}, operator: {})",
+                name, err, input_shapes, input_dtypes, operator
+            ),
+            RunError::InvalidInput {
+                name,
+                error: ref err,
+            } => write!(f, "input \"{}\" is invalid: {:?}", name, err),
+            RunError::IncorrectInputType {
+                name,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "input \"{}\" has type {:?} but the model expects {:?}",
+                name, actual, expected
+            ),
+            RunError::InputRankMismatch {
+                name,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "input \"{}\" has {} dims but the model expects {}",
+                name, actual, expected
+            ),
+            RunError::InputShapeMismatch {
+                name,
+                dim,
+                expected,
+                actual,
+                symbol,
+            } => {
+                write!(
+                    f,
+                    "input \"{}\" has size {} in dim {} but the model expects {}",
+                    name, actual, dim, expected
+                )?;
+                if let Some(symbol) = symbol {
+                    write!(f, " (bound to \"{}\")", symbol)?;
+                }
+                Ok(())
+            }
             RunError::OutputMismatch(err) => write!(f, "output mismatch {:?}", err),
+            RunError::OutputShapeMismatch {
+                name,
+                op,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "output \"{}\" of operator \"{}\" has shape {:?} but the model expects {:?}",
+                name, op, actual, expected
+            ),
+            RunError::NonFiniteOutput {
+                name,
+                op,
+                input_shapes,
+                input_stats,
+            } => {
+                write!(
+                    f,
+                    "operator \"{}\" ({}) produced NaN or infinite values.",
+                    name, op
+                )?;
+                for (index, (shape, stats)) in zip(input_shapes, input_stats).enumerate() {
+                    let Some(shape) = shape else {
+                        continue;
+                    };
+                    write!(f, " input {}: shape {:?}", index, shape)?;
+                    if let Some(stats) = stats {
+                        write!(f, " {}", stats)?;
+                    }
+                    write!(f, ";")?;
+                }
+                Ok(())
+            }
+            RunError::DumpError { name, error } => {
+                write!(f, "failed to write value \"{}\": {}", name, error)
+            }
+            RunError::MicroBatchError(err) => write!(f, "micro-batching failed: {}", err),
+            RunError::BatcherError(err) => write!(f, "batcher failed: {}", err),
         }
     }
 }
 
+/// Report timings for a graph run of `n_steps` operators.
+///
+/// If the `log` feature is enabled, this emits info-level records. Otherwise
+/// it prints to stdout.
+fn log_timing(timing: &RunTiming, n_steps: usize, sort: TimingSort, include_shapes: bool) {
+    let report = timing.display(sort, include_shapes);
+
+    #[cfg(feature = "log")]
+    {
+        log::info!(
+            target: "rten::timing",
+            steps = n_steps,
+            elapsed_ms = timing.total_time,
+            alloc_ms = timing.alloc_time;
+            "Graph run of {} ops finished in {}ms",
+            n_steps,
+            timing.total_time
+        );
+        log::info!(target: "rten::timing", "\n{}", report);
+    }
+
+    #[cfg(not(feature = "log"))]
+    {
+        println!(
+            "Graph run of {} ops finished in {}ms",
+            n_steps, timing.total_time
+        );
+        print!("{}", report);
+    }
+}
+
+/// Return true if `shape` matches the declared shape `expected`.
+///
+/// Symbolic dimensions are resolved using `bindings`. Dimensions of unknown
+/// size and unbound symbols match any size.
+fn shape_matches(expected: &[Dimension], shape: &[usize], bindings: &DimBindings) -> bool {
+    expected.len() == shape.len()
+        && zip(expected, shape).all(|(expected, &actual)| match expected {
+            // Dimension of unknown size. See `Graph::bind_shapes`.
+            Dimension::Fixed(0) => true,
+            dim => !matches!(bindings.resolve(dim), Some(size) if size != actual),
+        })
+}
+
 /// Return true if all elements in `xs` are unique according to the comparison
 /// function `eq`.
 ///
@@ -165,43 +528,30 @@ fn all_unique<T, F: Fn(&T, &T) -> bool>(xs: &[T], eq: F) -> bool {
         .all(|x| xs.iter().filter(|y| eq(x, y)).count() == 1)
 }
 
-/// Counter that tracks the remaining usage count of a graph node value.
-///
-/// This is used to keep intermediate graph outputs alive until they are no
-/// longer needed.
-struct NodeRefCount {
-    rc: HashMap<NodeId, usize>,
-}
+/// An execution plan for a set of graph inputs and outputs, along with data
+/// that is precomputed from the plan to reduce the cost of each run.
+struct CachedPlan {
+    /// IDs of the graph inputs the plan was created for.
+    inputs: Vec<NodeId>,
 
-impl NodeRefCount {
-    fn new() -> NodeRefCount {
-        NodeRefCount { rc: HashMap::new() }
-    }
+    /// IDs of the graph outputs the plan was created for.
+    outputs: Vec<NodeId>,
 
-    /// Increment ref count of node
-    fn inc(&mut self, id: NodeId) {
-        self.rc
-            .entry(id)
-            .and_modify(|count| *count += 1)
-            .or_insert(1);
-    }
+    /// IDs of operator nodes, in execution order.
+    steps: Vec<NodeId>,
 
-    /// Decrement ref count of node and return new count
-    fn dec(&mut self, id: NodeId) -> usize {
-        let Some(rc) = self.rc.get_mut(&id) else {
-            return 0;
-        };
-        *rc = rc.saturating_sub(1);
-        if *rc == 0 {
-            self.rc.remove(&id);
-            0
-        } else {
-            *rc
-        }
-    }
+    /// Number of times each value is used as an input by a step in the plan,
+    /// or requested as a graph output. This is indexed by node ID.
+    ///
+    /// This is used to keep intermediate values alive until they are no
+    /// longer needed.
+    use_counts: Vec<usize>,
+}
 
-    fn count(&self, id: NodeId) -> usize {
-        *self.rc.get(&id).unwrap_or(&0)
+impl CachedPlan {
+    /// Return true if this plan was created for the given inputs and outputs.
+    fn matches(&self, inputs: &[NodeId], outputs: &[NodeId]) -> bool {
+        self.inputs == inputs && self.outputs == outputs
     }
 }
 
@@ -209,7 +559,7 @@ impl Error for RunError {}
 
 /// Options that control logging and other behaviors when executing a
 /// [Model](crate::Model).
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct RunOptions {
     /// Whether to log times spent in different operators when run completes.
     pub timing: bool,
@@ -226,12 +576,56 @@ pub struct RunOptions {
     /// including input shapes and execution time. This will slow down
     /// execution.
     pub verbose: bool,
+
+    /// Whether to check the outputs of each operator for NaN or infinite
+    /// values. If enabled, execution stops at the first operator which
+    /// produces such values, with a [RunError::NonFiniteOutput] error. This
+    /// will slow down execution.
+    pub check_finite: bool,
+
+    /// Whether to check the shape of each operator output against the shape
+    /// declared by the model. Symbolic dimensions are resolved using the
+    /// sizes bound from the graph inputs (see [Graph::bind_input_shapes]).
+    /// Dimensions whose size is unknown, or whose symbol is not bound by any
+    /// input, are not checked. If enabled, execution stops at the first
+    /// mismatch with a [RunError::OutputShapeMismatch] error.
+    pub check_shapes: bool,
+
+    /// Directory to write the outputs of each operator to, as NumPy `.npy`
+    /// files. Files are named after the output's node name, with characters
+    /// that are not valid in file names replaced. If several names map to
+    /// the same file name, a suffix with the node ID is added. This is useful
+    /// for comparing intermediate values against other runtimes.
+    ///
+    /// The directory must already exist.
+    pub dump_dir: Option<PathBuf>,
+
+    /// If set, only operator outputs whose node names contain this string are
+    /// written to [RunOptions::dump_dir].
+    pub dump_filter: Option<String>,
+
+    /// Split inputs along a symbolic batch dimension into smaller batches,
+    /// which are run separately and whose outputs are concatenated. This
+    /// reduces peak memory usage for large batches, for models where batch
+    /// items are processed independently.
+    ///
+    /// Inputs are not split if the batch size is no larger than the
+    /// micro-batch size. Inputs whose declared shape does not have the batch
+    /// dimension are passed unmodified to every micro-batch, and inputs
+    /// without a declared shape are an error.
+    ///
+    /// When [RunOptions::dump_dir] is set, the index of the micro-batch is
+    /// appended to the names of dumped files (eg. `output_batch0.npy`).
+    pub micro_batch: Option<MicroBatch>,
 }
 
 impl Graph {
     /// Create a new empty dataflow graph.
     pub fn new() -> Graph {
-        Graph { nodes: Vec::new() }
+        Graph {
+            nodes: Vec::new(),
+            cached_plans: Mutex::new(Vec::new()),
+        }
     }
 
     /// Add an operator node to the graph.
@@ -254,6 +648,7 @@ impl Graph {
         inputs: &[Option<NodeId>],
         outputs: &[Option<NodeId>],
     ) -> NodeId {
+        self.invalidate_plan();
         self.nodes.push(Node::Operator(OperatorNode {
             name: name.map(|s| s.to_owned()),
             inputs: Vec::from(inputs),
@@ -276,6 +671,7 @@ impl Graph {
             name: name.map(|s| s.to_owned()),
             data: value,
         };
+        self.invalidate_plan();
         self.nodes.push(Node::Constant(node.into()));
         self.nodes.len() - 1
     }
@@ -283,21 +679,36 @@ impl Graph {
     /// Add a value node to the graph.
     ///
     /// `name` is an identifier for this node that is used in debug messages etc.
-    /// `shape` is the expected shape of the value at runtime, or None if not
-    /// known.
+    /// `shape` is the expected shape of the value at runtime, and `dtype` its
+    /// element type, or None if not known.
     ///
     /// This serves as a placeholder for a value which is available only when
     /// the graph is executed, such as an input or operator output.
     ///
     /// Returns the ID of the added node.
-    pub fn add_value(&mut self, name: Option<&str>, shape: Option<Vec<Dimension>>) -> NodeId {
+    pub fn add_value_with_dtype(
+        &mut self,
+        name: Option<&str>,
+        shape: Option<Vec<Dimension>>,
+        dtype: Option<DataType>,
+    ) -> NodeId {
+        self.invalidate_plan();
         self.nodes.push(Node::Value(ValueNode {
             name: name.map(|s| s.to_owned()),
             shape,
+            dtype,
         }));
         self.nodes.len() - 1
     }
 
+    /// Discard the cached execution plans after the graph is modified.
+    fn invalidate_plan(&mut self) {
+        self.cached_plans
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+
     /// Return the debug name for a node.
     pub fn node_name(&self, id: NodeId) -> String {
         self.get_node(id)
@@ -323,6 +734,94 @@ impl Graph {
             .sum()
     }
 
+    /// Check that the types of graph inputs match the types declared by the
+    /// corresponding value nodes.
+    ///
+    /// Integer inputs may be passed as `i32` tensors in place of any of the
+    /// other integer types, since that is the type operators work with.
+    fn check_input_types(&self, inputs: &[(NodeId, Input)]) -> Result<(), RunError> {
+        for (node_id, input) in inputs {
+            let Some(expected) = self.get_node(*node_id).and_then(|node| node.dtype()) else {
+                continue;
+            };
+            let actual = input.dtype();
+            let compatible = match (expected, actual) {
+                (expected, actual) if expected == actual => true,
+                (DataType::Int64 | DataType::Bool | DataType::UInt8, DataType::Int32) => true,
+                _ => false,
+            };
+            if !compatible {
+                return Err(RunError::IncorrectInputType {
+                    name: self.node_name(*node_id),
+                    expected,
+                    actual,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Check the shapes of graph inputs against the shapes declared by the
+    /// corresponding value nodes, and bind the sizes of symbolic dimensions.
+    ///
+    /// Inputs are checked in order. The first dimension with a given symbolic
+    /// name determines the size for that symbol.
+    pub fn bind_input_shapes(&self, inputs: &[(NodeId, Input)]) -> Result<DimBindings, RunError> {
+        let shapes: Vec<(NodeId, &[usize])> = inputs
+            .iter()
+            .map(|(node_id, input)| (*node_id, input.shape()))
+            .collect();
+        self.bind_shapes(&shapes)
+    }
+
+    /// Variant of [Graph::bind_input_shapes] which takes the shapes of inputs
+    /// instead of their values.
+    fn bind_shapes(&self, inputs: &[(NodeId, &[usize])]) -> Result<DimBindings, RunError> {
+        let mut bindings = DimBindings::default();
+
+        for &(node_id, shape) in inputs {
+            let Some(expected_shape) = self.get_node(node_id).and_then(|node| node.shape()) else {
+                continue;
+            };
+            let name = || self.node_name(node_id);
+
+            if shape.len() != expected_shape.len() {
+                return Err(RunError::InputRankMismatch {
+                    name: name(),
+                    expected: expected_shape.len(),
+                    actual: shape.len(),
+                });
+            }
+
+            for (dim, (expected, &actual)) in zip(expected_shape, shape).enumerate() {
+                let (expected, symbol) = match expected {
+                    // Models produced by older versions of rten-convert use a
+                    // size of zero for dimensions whose size is unknown.
+                    Dimension::Fixed(0) => continue,
+                    Dimension::Fixed(size) => (size, None),
+                    Dimension::Symbolic(symbol) => match bindings.get(&symbol) {
+                        Some(size) => (size, Some(symbol)),
+                        None => {
+                            bindings.sizes.insert(symbol, actual);
+                            continue;
+                        }
+                    },
+                };
+                if actual != expected {
+                    return Err(RunError::InputShapeMismatch {
+                        name: name(),
+                        dim,
+                        expected,
+                        actual,
+                        symbol,
+                    });
+                }
+            }
+        }
+
+        Ok(bindings)
+    }
+
     /// Compute a set of output values given a set of inputs, using the
     /// processing steps and constant values defined by the graph.
     pub fn run(
@@ -331,7 +830,152 @@ impl Graph {
         outputs: &[NodeId],
         opts: Option<RunOptions>,
     ) -> Result<Vec<Output>, RunError> {
-        let plan = self.create_plan(inputs, outputs)?;
+        if let Some(micro_batch) = opts.as_ref().and_then(|opts| opts.micro_batch.clone()) {
+            return self.run_micro_batched(inputs, outputs, opts.unwrap(), &micro_batch);
+        }
+        self.run_with_observer(inputs, outputs, opts, None)
+    }
+
+    /// Return the position of the symbolic dimension `dim` in the shape
+    /// declared by a value node.
+    fn symbolic_dim_axis(&self, node_id: NodeId, dim: &str) -> Option<usize> {
+        self.get_node(node_id)?
+            .shape()?
+            .iter()
+            .position(|d| matches!(d, Dimension::Symbolic(name) if name == dim))
+    }
+
+    /// Variant of [Graph::run] which splits inputs into micro-batches. See
+    /// [RunOptions::micro_batch].
+    fn run_micro_batched(
+        &self,
+        inputs: &[(NodeId, Input)],
+        outputs: &[NodeId],
+        mut opts: RunOptions,
+        micro_batch: &MicroBatch,
+    ) -> Result<Vec<Output>, RunError> {
+        opts.micro_batch = None;
+
+        if micro_batch.size == 0 {
+            return Err(RunError::MicroBatchError(
+                "micro-batch size must be > 0".to_string(),
+            ));
+        }
+
+        let batch_size = self.bind_input_shapes(inputs)?.get(&micro_batch.dim);
+        let batch_size = match batch_size {
+            Some(size) if size > micro_batch.size => size,
+            _ => return self.run_with_observer(inputs, outputs, Some(opts), None),
+        };
+
+        // Inputs whose declared shape does not have the batch dimension are
+        // passed to every micro-batch unmodified. Inputs without a declared
+        // shape are rejected, as we can't tell whether they should be split.
+        let input_axes = inputs
+            .iter()
+            .map(|(node_id, _)| {
+                if self
+                    .get_node(*node_id)
+                    .and_then(|node| node.shape())
+                    .is_none()
+                {
+                    return Err(RunError::MicroBatchError(format!(
+                        "input \"{}\" does not have a declared shape",
+                        self.node_name(*node_id),
+                    )));
+                }
+                Ok(self.symbolic_dim_axis(*node_id, &micro_batch.dim))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let output_axes = outputs
+            .iter()
+            .map(|&node_id| {
+                self.symbolic_dim_axis(node_id, &micro_batch.dim)
+                    .ok_or_else(|| {
+                        RunError::MicroBatchError(format!(
+                            "output \"{}\" does not have batch dimension \"{}\"",
+                            self.node_name(node_id),
+                            micro_batch.dim
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let run_batch = |(index, range): (usize, &std::ops::Range<usize>)| {
+            let batch_inputs: Vec<(NodeId, Input)> = zip(inputs, &input_axes)
+                .map(|((node_id, input), axis)| match axis {
+                    Some(axis) => (*node_id, slice_input(input, *axis, range.clone())),
+                    None => (*node_id, input.clone()),
+                })
+                .collect();
+            self.run_plan(
+                &batch_inputs,
+                outputs,
+                Some(opts.clone()),
+                None,
+                Some(index),
+            )
+        };
+
+        let ranges = micro_batch.ranges(batch_size);
+        let batch_outputs: Vec<Vec<Output>> = if micro_batch.parallel {
+            ranges
+                .par_iter()
+                .enumerate()
+                .map(run_batch)
+                .collect::<Result<_, _>>()?
+        } else {
+            ranges
+                .iter()
+                .enumerate()
+                .map(run_batch)
+                .collect::<Result<_, _>>()?
+        };
+
+        zip(outputs, output_axes)
+            .enumerate()
+            .map(|(i, (&node_id, axis))| {
+                let values: Vec<&Output> =
+                    batch_outputs.iter().map(|outputs| &outputs[i]).collect();
+                concat_outputs(&values, axis).map_err(|err| {
+                    RunError::MicroBatchError(format!(
+                        "failed to concatenate output \"{}\": {:?}",
+                        self.node_name(node_id),
+                        err
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    /// Variant of [Graph::run] which calls `observer` after each operator is
+    /// run, with details of the operator's outputs.
+    pub(crate) fn run_with_observer(
+        &self,
+        inputs: &[(NodeId, Input)],
+        outputs: &[NodeId],
+        opts: Option<RunOptions>,
+        observer: Option<RunObserver>,
+    ) -> Result<Vec<Output>, RunError> {
+        self.run_plan(inputs, outputs, opts, observer, None)
+    }
+
+    /// Run the graph, optionally calling `observer` after each operator.
+    ///
+    /// `micro_batch` is the index of the micro-batch being run, if the inputs
+    /// were split by [Graph::run_micro_batched]. It is added to the names of
+    /// dumped files and to verbose logs, so that the outputs of different
+    /// micro-batches can be told apart.
+    fn run_plan(
+        &self,
+        inputs: &[(NodeId, Input)],
+        outputs: &[NodeId],
+        opts: Option<RunOptions>,
+        mut observer: Option<RunObserver>,
+        micro_batch: Option<usize>,
+    ) -> Result<Vec<Output>, RunError> {
+        let input_ids: Vec<NodeId> = inputs.iter().map(|(node_id, _)| *node_id).collect();
+        let plan = self.get_plan(&input_ids, outputs)?;
         let opts = opts.unwrap_or_default();
 
         let mut run_timer = Timer::new();
@@ -339,40 +983,64 @@ impl Graph {
             run_timer.start();
         }
 
-        // Collect operator inputs
-        let mut values: HashMap<NodeId, Input> = inputs.iter().cloned().collect();
-        for (node_id, node) in self.nodes.iter().enumerate() {
-            if let Node::Constant(constant) = node {
-                let input = match constant {
-                    Constant::Float(node) => Input::FloatTensor(node.data.view()),
-                    Constant::Int(node) => Input::IntTensor(node.data.view()),
-                };
-                values.insert(node_id, input);
+        self.check_input_types(inputs)?;
+
+        // Execution plans depend only on which values are provided and
+        // requested, not their shapes. The bindings are used to resolve the
+        // declared shapes of operator outputs if `check_shapes` is enabled.
+        let bindings = self.bind_input_shapes(inputs)?;
+
+        // Convert inputs with types that are accepted at the graph boundary,
+        // but not by operators, into i32 tensors.
+        let mut converted_inputs: Vec<(NodeId, Tensor<i32>)> = Vec::new();
+        for (node_id, input) in inputs {
+            if let Some(converted) = input.to_int_tensor() {
+                let converted = converted.map_err(|error| RunError::InvalidInput {
+                    name: self.node_name(*node_id),
+                    error,
+                })?;
+                converted_inputs.push((*node_id, converted));
             }
         }
 
-        // Count how often each temporary output is used, so we can free them
-        // when no longer needed.
-        let mut temp_value_refcount = NodeRefCount::new();
-        for (_, op_node) in plan.iter() {
-            for node_id in op_node.inputs.iter().filter_map(|node| *node) {
-                temp_value_refcount.inc(node_id);
-            }
+        // Slots for graph inputs and temporary values, indexed by node ID.
+        // Constants are looked up from the graph when needed.
+        let mut input_values: Vec<Option<Input>> = vec![None; self.nodes.len()];
+        for (node_id, input) in inputs {
+            input_values[*node_id] = Some(input.clone());
         }
+        for (node_id, tensor) in converted_inputs.iter() {
+            input_values[*node_id] = Some(tensor.view().into());
+        }
+        let mut temp_values: Vec<Option<Output>> =
+            iter::repeat_with(|| None).take(self.nodes.len()).collect();
+
+        // Remaining number of uses of each value, so we can free temporary
+        // values when no longer needed. This includes a use for each
+        // requested output, so we retain them after the operator has run.
+        let mut use_counts = plan.use_counts.clone();
 
-        // Increment usage count of all output nodes, so we retain them after
-        // the operator has run.
-        for node_id in outputs {
-            temp_value_refcount.inc(*node_id);
+        // Look up the value of a graph input, constant or temporary value.
+        fn get_value<'a>(
+            graph: &'a Graph,
+            node_id: NodeId,
+            input_values: &[Option<Input<'a>>],
+            temp_values: &'a [Option<Output>],
+        ) -> Option<Input<'a>> {
+            graph
+                .constant_input(node_id)
+                .or_else(|| input_values[node_id].clone())
+                .or_else(|| temp_values[node_id].as_ref().map(|value| value.into()))
         }
 
         // Execute the plan
-        let mut temp_values: HashMap<NodeId, Output> = HashMap::new();
         let mut op_elapsed: Vec<TimingRecord> = Vec::new();
         let record_timing = opts.timing || opts.verbose;
+        let dump_stems = opts.dump_dir.is_some().then(|| self.npy_file_stems());
         let mut alloc_timer = Timer::new();
 
-        for (step, (op_node_id, op_node)) in plan.iter().enumerate() {
+        for (step, &op_node_id) in plan.steps.iter().enumerate() {
+            let op_node = self.operator_node(op_node_id);
             let mut op_timer = Timer::new();
             if record_timing {
                 op_timer.start();
@@ -396,7 +1064,7 @@ impl Graph {
                         .iter()
                         .max_by_key(|input_id| {
                             input_id
-                                .and_then(|id| temp_values.get(&id))
+                                .and_then(|id| temp_values[id].as_ref())
                                 .map(|val| val.len())
                                 .unwrap_or(0)
                         })
@@ -414,16 +1082,28 @@ impl Graph {
             // is not a constant (eg. weights) and is not going to be used by
             // other ops in future.
             let in_place_input = in_place_input_id.and_then(|first_input| {
-                if temp_values.contains_key(&first_input)
-                    && temp_value_refcount.count(first_input) == 1
-                {
-                    temp_value_refcount.dec(first_input);
-                    Some(temp_values.remove(&first_input).unwrap())
+                if temp_values[first_input].is_some() && use_counts[first_input] == 1 {
+                    use_counts[first_input] -= 1;
+                    temp_values[first_input].take()
                 } else {
                     None
                 }
             });
 
+            // The in-place input is consumed by the operator, so record its
+            // shape and statistics in case we need to report them in an error.
+            let in_place_shape: Option<Vec<usize>> =
+                in_place_input.as_ref().map(|input| input.shape().to_vec());
+            let in_place_dtype: Option<DataType> = in_place_input
+                .as_ref()
+                .map(|input| Input::from(input).dtype());
+            let in_place_stats: Option<ValueStats> = match in_place_input {
+                Some(Output::FloatTensor(ref t)) if opts.check_finite => {
+                    Some(ValueStats::from_values(t.iter()))
+                }
+                _ => None,
+            };
+
             // Collect all or remaining inputs for the operator
             let mut op_inputs: Vec<Option<Input>> = Vec::new();
             for node_id in op_node.inputs.iter() {
@@ -432,20 +1112,14 @@ impl Graph {
                 }
 
                 if let Some(node_id) = node_id {
-                    if let Some(value) = values.get(node_id) {
-                        op_inputs.push(Some(value.clone()));
-                    } else if let Some(value) = temp_values.get(node_id) {
-                        let input = match value {
-                            Output::IntTensor(t) => Input::IntTensor(t.view()),
-                            Output::FloatTensor(t) => Input::FloatTensor(t.view()),
-                        };
-                        op_inputs.push(Some(input));
+                    if let Some(value) = get_value(self, *node_id, &input_values, &temp_values) {
+                        op_inputs.push(Some(value));
                     } else {
                         // If this is reached, there was a bug in plan creation.
                         panic!(
                             "Invalid plan did not produce input value {} for operator {}",
                             self.node_name(*node_id),
-                            self.node_name(*op_node_id),
+                            self.node_name(op_node_id),
                         );
                     }
                 } else {
@@ -454,7 +1128,7 @@ impl Graph {
             }
 
             // Collect input shapes if we'll need them for timing or logging.
-            let input_shapes = if opts.timing_by_shape || opts.verbose {
+            let input_shapes = if record_timing {
                 let mut shapes: Vec<InputShape> = Vec::new();
                 if let Some(ref input) = in_place_input {
                     shapes.push(Some(input.shape().into()));
@@ -478,13 +1152,29 @@ impl Graph {
                     .run(InputList::from_optional(&op_inputs[..]))
             };
 
+            // Stop the timer before estimating the cost, so the estimate is
+            // not included in the operator's timing.
             if record_timing {
                 op_timer.end();
+            }
+
+            let cost = if record_timing {
+                op_result.as_ref().ok().and_then(|outputs| {
+                    let input_shapes: Vec<_> =
+                        input_shapes.iter().map(|shape| shape.as_deref()).collect();
+                    let output_shapes: Vec<_> = outputs.iter().map(|out| out.shape()).collect();
+                    op_node.operator.cost(&input_shapes, &output_shapes)
+                })
+            } else {
+                None
+            };
 
+            if record_timing {
                 op_elapsed.push(TimingRecord {
                     name: op_node.operator.name().to_string(),
                     input_shapes: input_shapes.clone(),
                     elapsed_micros: op_timer.elapsed_micros(),
+                    cost,
                 });
             }
 
@@ -492,62 +1182,144 @@ impl Graph {
             // result so that in the event of an error, the verbose log includes
             // the failing operator's inputs.
             if opts.verbose {
-                println!(
-                    "#{} {} ({})",
+                self.log_step(
                     step,
-                    op_node.operator.name(),
-                    op_node.name.as_ref().unwrap_or(&String::new())
+                    micro_batch,
+                    op_node,
+                    &input_shapes,
+                    op_result.as_deref().ok(),
+                    op_timer.elapsed_ms(),
                 );
-                for (index, (id, shape)) in
-                    zip(op_node.inputs.iter(), input_shapes.iter()).enumerate()
-                {
-                    if let (Some(id), Some(shape)) = (id, shape) {
-                        let name = self.node_name(*id);
-                        println!("  input {}: {} ({:?})", index, name, shape);
-                    }
-                }
-
-                if let Ok(outputs) = op_result.as_ref() {
-                    for (index, (id, output)) in
-                        zip(op_node.outputs.iter(), outputs.iter()).enumerate()
-                    {
-                        let name = id.map(|id| self.node_name(id)).unwrap_or(String::new());
-                        println!("  output {}: {} ({:?})", index, name, output.shape());
-                    }
-                }
-
-                println!("  time: {}ms", op_timer.elapsed_ms());
             }
 
+            // Look up the inputs to this step, for use in error reports. This
+            // returns `None` for the in-place input, which has been consumed.
+            let step_inputs = || {
+                op_node
+                    .inputs
+                    .iter()
+                    .map(|node_id| get_value(self, (*node_id)?, &input_values, &temp_values))
+            };
+            let step_input_shapes = || -> Vec<Option<Vec<usize>>> {
+                zip(op_node.inputs.iter(), step_inputs())
+                    .map(|(node_id, input)| match input {
+                        Some(input) => Some(input.shape().to_vec()),
+                        None => node_id.and(in_place_shape.clone()),
+                    })
+                    .collect()
+            };
+            let step_input_dtypes = || -> Vec<Option<DataType>> {
+                zip(op_node.inputs.iter(), step_inputs())
+                    .map(|(node_id, input)| match input {
+                        Some(input) => Some(input.dtype()),
+                        None => node_id.and(in_place_dtype),
+                    })
+                    .collect()
+            };
+
             let outputs = match op_result {
                 Ok(outputs) => outputs,
                 Err(op_error) => {
                     let err = RunError::OperatorError {
                         name: op_node.name.as_deref().unwrap_or("").to_string(),
                         error: op_error,
+                        input_shapes: step_input_shapes(),
+                        input_dtypes: step_input_dtypes(),
+                        operator: format!("{:?}", op_node.operator).into(),
                     };
                     return Err(err);
                 }
             };
 
+            if opts.check_finite {
+                let has_non_finite = outputs.iter().any(|output| match output {
+                    Output::FloatTensor(t) => t.iter().any(|x| !x.is_finite()),
+                    _ => false,
+                });
+                if has_non_finite {
+                    let input_stats = zip(op_node.inputs.iter(), step_inputs())
+                        .map(|(node_id, input)| match input {
+                            Some(Input::FloatTensor(t)) => Some(ValueStats::from_values(t.iter())),
+                            Some(_) => None,
+                            None => node_id.and(in_place_stats.clone()),
+                        })
+                        .collect();
+                    return Err(RunError::NonFiniteOutput {
+                        name: self.node_name(op_node_id),
+                        op: op_node.operator.name().to_string(),
+                        input_shapes: step_input_shapes(),
+                        input_stats,
+                    });
+                }
+            }
+
             if op_node.outputs.len() != outputs.len() {
                 return Err(RunError::OutputMismatch(
                     "operator output count did not match expected count",
                 ));
             }
 
+            if opts.check_shapes {
+                for (output_id, output) in zip(op_node.outputs.iter(), outputs.iter()) {
+                    let Some(output_id) = output_id else {
+                        continue;
+                    };
+                    let Some(expected) = self.get_node(*output_id).and_then(|node| node.shape())
+                    else {
+                        continue;
+                    };
+                    if !shape_matches(&expected, output.shape(), &bindings) {
+                        return Err(RunError::OutputShapeMismatch {
+                            name: self.node_name(*output_id),
+                            op: self.node_name(op_node_id),
+                            expected,
+                            actual: output.shape().to_vec(),
+                        });
+                    }
+                }
+            }
+
+            if let (Some(dump_dir), Some(dump_stems)) = (opts.dump_dir.as_deref(), &dump_stems) {
+                for (output_id, output) in zip(op_node.outputs.iter(), outputs.iter()) {
+                    if let Some(output_id) = output_id {
+                        let stem = match micro_batch {
+                            Some(index) => format!("{}_batch{}", dump_stems[output_id], index),
+                            None => dump_stems[output_id].clone(),
+                        };
+                        self.dump_value(
+                            dump_dir,
+                            opts.dump_filter.as_deref(),
+                            *output_id,
+                            &stem,
+                            output,
+                        )?;
+                    }
+                }
+            }
+
+            if let Some(observer) = observer.as_mut() {
+                let step_outputs: Vec<(NodeId, &Output)> =
+                    zip(op_node.outputs.iter(), outputs.iter())
+                        .filter_map(|(output_id, output)| Some(((*output_id)?, output)))
+                        .collect();
+                observer(&StepInfo {
+                    op: op_node.operator.name(),
+                    outputs: &step_outputs,
+                });
+            }
+
             for (&output_id, output) in zip(op_node.outputs.iter(), outputs.into_iter()) {
                 if let Some(output_id) = output_id {
-                    temp_values.insert(output_id, output);
+                    temp_values[output_id] = Some(output);
                 }
             }
 
             // Remove temporary values that are no longer needed
             record_timing.then(|| alloc_timer.start());
             for node_id in op_node.inputs.iter().filter_map(|node| *node) {
-                let rc = temp_value_refcount.dec(node_id);
-                if rc == 0 {
-                    temp_values.remove(&node_id);
+                use_counts[node_id] = use_counts[node_id].saturating_sub(1);
+                if use_counts[node_id] == 0 {
+                    temp_values[node_id] = None;
                 }
             }
             record_timing.then(|| alloc_timer.end());
@@ -555,56 +1327,510 @@ impl Graph {
 
         if opts.timing {
             run_timer.end();
-            println!(
-                "Graph run of {} ops finished in {}ms",
-                plan.len(),
-                run_timer.elapsed_ms()
-            );
             let timing = RunTiming {
                 records: &op_elapsed,
                 alloc_time: alloc_timer.elapsed_ms(),
                 total_time: run_timer.elapsed_ms(),
             };
-            print!("{}", timing.display(opts.timing_sort, opts.timing_by_shape));
+            log_timing(
+                &timing,
+                plan.steps.len(),
+                opts.timing_sort,
+                opts.timing_by_shape,
+            );
         }
 
         // Return the requested outputs
         let result = outputs
             .iter()
-            .map(|output_id| {
-                if let Some(value) = values.get(output_id) {
+            .map(|&output_id| {
+                let value = self
+                    .constant_input(output_id)
+                    .or_else(|| input_values[output_id].clone());
+                if let Some(value) = value {
                     match value {
                         Input::IntTensor(t) => Output::IntTensor(t.to_tensor()),
                         Input::FloatTensor(t) => Output::FloatTensor(t.to_tensor()),
+                        Input::Int64Tensor(t) => Output::Int64Tensor(t.to_tensor()),
+                        Input::BoolTensor(t) => Output::BoolTensor(t.to_tensor()),
+                        Input::UInt8Tensor(t) => Output::UInt8Tensor(t.to_tensor()),
                     }
                 } else {
                     // During execution planning we verified that each output
                     // ID is valid and unique, so this should always succeed.
-                    temp_values.remove(output_id).expect("missing output value")
+                    temp_values[output_id].take().expect("missing output value")
                 }
             })
             .collect();
         Ok(result)
     }
 
-    /// Create an execution plan for a sequence of computation steps that begin
-    /// with `inputs` and eventually produces `outputs`.
+    /// Propagate the shapes of inputs through the graph without computing
+    /// values, and estimate the memory required for intermediate values.
     ///
-    /// The set of input and output node IDs must be unique.
+    /// `inputs` specifies the shape of each graph input. The shapes of
+    /// operator outputs are computed using [Operator::infer_shapes]. Values
+    /// which depend only on shapes and constants, such as the output of a
+    /// `Shape` operator, are computed if they are small, so that operators
+    /// which use them (eg. `Reshape`) can determine their output shapes.
     ///
-    /// Any node IDs in `outputs` which reference constant or input values are
-    /// omitted from the plan.
-    fn create_plan(
+    /// Symbolic dimensions are bound from the shapes of `inputs`. If an
+    /// operator does not support shape inference, the declared shapes of its
+    /// outputs are used instead, with symbolic dimensions resolved using
+    /// these bindings.
+    ///
+    /// Returns an [RunError::OperatorError] if an operator does not support
+    /// shape inference, its inputs are not all known and its output shapes
+    /// cannot be resolved from the declared shapes.
+    pub fn dry_run(
         &self,
-        inputs: &[(NodeId, Input)],
+        inputs: &[(NodeId, &[usize])],
         outputs: &[NodeId],
-    ) -> Result<Vec<(NodeId, &OperatorNode)>, RunError> {
-        if !all_unique(outputs, |x, y| x == y) {
-            return Err(RunError::PlanningError("output IDs are not unique".into()));
-        }
+    ) -> Result<DryRunReport, RunError> {
+        let input_ids: Vec<NodeId> = inputs.iter().map(|(node_id, _)| *node_id).collect();
+        let plan = self.get_plan(&input_ids, outputs)?;
+        let bindings = self.bind_shapes(inputs)?;
 
-        if !all_unique(inputs, |(x_id, _), (y_id, _)| x_id == y_id) {
-            return Err(RunError::PlanningError("input IDs are not unique".into()));
+        let values: HashMap<NodeId, MetaInput> = inputs
+            .iter()
+            .map(|&(node_id, shape)| (node_id, MetaInput::Shape(shape)))
+            .collect();
+        let mut use_counts = plan.use_counts.clone();
+
+        // Operators only produce `f32` and `i32` values, so outputs whose
+        // values were not computed are assumed to use 4 bytes per element.
+        let value_bytes = |value: &MetaOutput| {
+            let elem_size = match value {
+                MetaOutput::Shape(_) => DataType::Float.size(),
+                MetaOutput::Value(value) => Input::from(value).dtype().size(),
+            };
+            value.shape().iter().product::<usize>() * elem_size
+        };
+
+        // Graph inputs are alive from the start of the run until their last
+        // use. Their sizes use the declared element types.
+        let input_bytes = |node_id: NodeId, shape: &[usize]| {
+            let elem_size = self
+                .get_node(node_id)
+                .and_then(|node| node.dtype())
+                .unwrap_or(DataType::Float)
+                .size();
+            shape.iter().product::<usize>() * elem_size
+        };
+        let mut live_bytes: usize = inputs
+            .iter()
+            .filter(|(node_id, _)| use_counts[*node_id] > 0)
+            .map(|&(node_id, shape)| input_bytes(node_id, shape))
+            .sum();
+
+        let mut temp_values: HashMap<NodeId, MetaOutput> = HashMap::new();
+        let mut steps = Vec::with_capacity(plan.steps.len());
+        let mut total_activation_bytes = 0;
+        let mut peak_bytes = 0;
+        let mut peak_step = None;
+
+        for (step, &op_node_id) in plan.steps.iter().enumerate() {
+            let op_node = self.operator_node(op_node_id);
+            let op_inputs: Vec<Option<MetaInput>> = op_node
+                .inputs
+                .iter()
+                .map(|node_id| {
+                    let node_id = (*node_id)?;
+                    let input = self
+                        .constant_input(node_id)
+                        .map(MetaInput::Value)
+                        .or_else(|| values.get(&node_id).cloned())
+                        .or_else(|| temp_values.get(&node_id).map(|value| value.into()));
+
+                    // If this is reached, there was a bug in plan creation.
+                    assert!(
+                        input.is_some(),
+                        "Invalid plan did not produce input value {}",
+                        self.node_name(node_id)
+                    );
+                    input
+                })
+                .collect();
+
+            // Inputs whose values are known, or `None` if any value is
+            // unknown.
+            let known_inputs: Option<Vec<Option<Input>>> = op_inputs
+                .iter()
+                .map(|input| match input {
+                    Some(MetaInput::Value(value)) => Some(Some(value.clone())),
+                    Some(MetaInput::Shape(_)) => None,
+                    None => Some(None),
+                })
+                .collect();
+            let known_inputs_len = known_inputs.as_ref().map(|inputs| {
+                inputs
+                    .iter()
+                    .flatten()
+                    .map(|input| input.len())
+                    .sum::<usize>()
+            });
+
+            let run_op = |inputs: &[Option<Input>]| {
+                op_node
+                    .operator
+                    .run(InputList::from_optional(inputs))
+                    .map(|outputs| outputs.into_iter().map(MetaOutput::Value).collect())
+            };
+            let meta_inputs = MetaInputList::from_optional(&op_inputs);
+            let op_result: Result<Vec<MetaOutput>, OpError> = match known_inputs {
+                Some(ref inputs) if known_inputs_len <= Some(MAX_DRY_RUN_VALUE_LEN) => {
+                    run_op(inputs)
+                }
+                _ => match (op_node.operator.infer_shapes(&meta_inputs), known_inputs) {
+                    (Some(result), _) => result,
+                    (None, Some(inputs)) => run_op(&inputs),
+                    (None, None) => self.declared_output_shapes(op_node, &bindings).ok_or(
+                        OpError::UnsupportedValue("operator does not support shape inference"),
+                    ),
+                },
+            };
+
+            let op_outputs = op_result.map_err(|error| RunError::OperatorError {
+                name: op_node.name.as_deref().unwrap_or("").to_string(),
+                error,
+                input_shapes: op_inputs
+                    .iter()
+                    .map(|input| input.as_ref().map(|input| input.shape().to_vec()))
+                    .collect(),
+                input_dtypes: op_inputs
+                    .iter()
+                    .map(|input| match input {
+                        Some(MetaInput::Value(value)) => Some(value.dtype()),
+                        _ => None,
+                    })
+                    .collect(),
+                operator: format!("{:?}", op_node.operator).into(),
+            })?;
+
+            if op_node.outputs.len() != op_outputs.len() {
+                return Err(RunError::OutputMismatch(
+                    "operator output count did not match expected count",
+                ));
+            }
+
+            let flops = {
+                let input_shapes: Vec<_> = op_inputs
+                    .iter()
+                    .map(|input| input.as_ref().map(|input| input.shape()))
+                    .collect();
+                let output_shapes: Vec<_> =
+                    op_outputs.iter().map(|output| output.shape()).collect();
+                op_node.operator.flops(&input_shapes, &output_shapes)
+            };
+
+            let mut step_outputs = Vec::with_capacity(op_outputs.len());
+            for (&output_id, output) in zip(op_node.outputs.iter(), op_outputs) {
+                let Some(output_id) = output_id else {
+                    continue;
+                };
+                let output_bytes = value_bytes(&output);
+                live_bytes += output_bytes;
+                total_activation_bytes += output_bytes;
+
+                // Only retain values that are small enough to be used by
+                // later steps.
+                let output = match output {
+                    MetaOutput::Value(value) if value.len() > MAX_DRY_RUN_VALUE_LEN => {
+                        MetaOutput::Shape(value.shape().to_vec())
+                    }
+                    output => output,
+                };
+                step_outputs.push(DryRunValue {
+                    id: output_id,
+                    name: self.node_name(output_id),
+                    shape: output.shape().to_vec(),
+                });
+                temp_values.insert(output_id, output);
+            }
+
+            if live_bytes > peak_bytes || peak_step.is_none() {
+                peak_bytes = live_bytes;
+                peak_step = Some(step);
+            }
+            steps.push(DryRunStep {
+                name: op_node.name.as_deref().unwrap_or("").to_string(),
+                op: op_node.operator.name().to_string(),
+                outputs: step_outputs,
+                live_bytes,
+                flops,
+            });
+
+            for node_id in op_node.inputs.iter().filter_map(|node| *node) {
+                use_counts[node_id] = use_counts[node_id].saturating_sub(1);
+                if use_counts[node_id] == 0 {
+                    if let Some(value) = temp_values.remove(&node_id) {
+                        live_bytes -= value_bytes(&value);
+                    } else if let Some(MetaInput::Shape(shape)) = values.get(&node_id) {
+                        live_bytes -= input_bytes(node_id, shape);
+                    }
+                }
+            }
+        }
+
+        Ok(DryRunReport {
+            steps,
+            total_activation_bytes,
+            peak_bytes,
+            peak_step,
+            dim_bindings: bindings,
+        })
+    }
+
+    /// Resolve the declared shapes of an operator's outputs using the sizes
+    /// of bound symbolic dimensions.
+    ///
+    /// Returns `None` if any output does not have a declared shape, or its
+    /// shape contains an unbound or unknown dimension.
+    fn declared_output_shapes(
+        &self,
+        op_node: &OperatorNode,
+        bindings: &DimBindings,
+    ) -> Option<Vec<MetaOutput>> {
+        op_node
+            .outputs
+            .iter()
+            .map(|output_id| {
+                let shape = self.get_node((*output_id)?)?.shape()?;
+                if shape.contains(&Dimension::Fixed(0)) {
+                    // Dimension of unknown size. See `bind_shapes`.
+                    return None;
+                }
+                bindings.resolve_shape(&shape).map(MetaOutput::Shape)
+            })
+            .collect()
+    }
+
+    /// Return the stems of the `.npy` file names used for value nodes by
+    /// [RunOptions::dump_dir].
+    ///
+    /// Stems are created from node names using [npy_file_stem]. If several
+    /// names map to the same stem, names which did not need to be changed
+    /// keep the stem and the others get a suffix with their node ID, so that
+    /// their files do not overwrite each other.
+    pub(crate) fn npy_file_stems(&self) -> HashMap<NodeId, String> {
+        let mut names: Vec<(NodeId, String, String)> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| matches!(node, Node::Value(_)))
+            .map(|(id, _)| {
+                let name = self.node_name(id);
+                let stem = npy_file_stem(&name);
+                (id, name, stem)
+            })
+            .collect();
+        names.sort_by_key(|(id, name, stem)| (name != stem, *id));
+
+        let mut used_stems = HashSet::new();
+        let mut stems = HashMap::with_capacity(names.len());
+        for (id, _name, stem) in names {
+            let mut unique_stem = stem.clone();
+            let mut suffix = id;
+            while used_stems.contains(&unique_stem) {
+                unique_stem = format!("{}_{}", stem, suffix);
+                suffix += 1;
+            }
+            used_stems.insert(unique_stem.clone());
+            stems.insert(id, unique_stem);
+        }
+        stems
+    }
+
+    /// Write the value of node `id` to `{stem}.npy` in `dir`, if its name
+    /// matches `filter`.
+    fn dump_value(
+        &self,
+        dir: &Path,
+        filter: Option<&str>,
+        id: NodeId,
+        stem: &str,
+        value: &Output,
+    ) -> Result<(), RunError> {
+        let name = self.node_name(id);
+        if filter.is_some_and(|filter| !name.contains(filter)) {
+            return Ok(());
+        }
+
+        let path = dir.join(format!("{}.npy", stem));
+
+        write_npy_file(&path, value).map_err(|err| RunError::DumpError {
+            name,
+            error: err.to_string(),
+        })
+    }
+
+    /// Log the inputs, outputs and execution time of a graph step, when
+    /// verbose output is enabled.
+    ///
+    /// If the `log` feature is enabled, this emits a debug-level record with
+    /// structured fields. Otherwise it prints to stdout.
+    fn log_step(
+        &self,
+        step: usize,
+        micro_batch: Option<usize>,
+        op_node: &OperatorNode,
+        input_shapes: &[InputShape],
+        outputs: Option<&[Output]>,
+        elapsed_ms: f32,
+    ) {
+        let op = op_node.operator.name();
+        let node = op_node.name.as_deref().unwrap_or("");
+        let inputs: Vec<(String, &[usize])> = zip(op_node.inputs.iter(), input_shapes.iter())
+            .filter_map(|(id, shape)| match (id, shape) {
+                (Some(id), Some(shape)) => Some((self.node_name(*id), shape.as_slice())),
+                _ => None,
+            })
+            .collect();
+        let outputs: Vec<(String, &[usize])> = outputs
+            .map(|outputs| {
+                zip(op_node.outputs.iter(), outputs.iter())
+                    .map(|(id, output)| {
+                        let name = id.map(|id| self.node_name(id)).unwrap_or_default();
+                        (name, output.shape())
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let batch_label = micro_batch
+            .map(|index| format!(" [micro-batch {}]", index))
+            .unwrap_or_default();
+
+        #[cfg(feature = "log")]
+        log::debug!(
+            target: "rten::graph",
+            step,
+            micro_batch:? = micro_batch,
+            op,
+            node,
+            inputs:? = inputs,
+            outputs:? = outputs,
+            elapsed_ms;
+            "#{} {} ({}){} finished in {}ms",
+            step,
+            op,
+            node,
+            batch_label,
+            elapsed_ms
+        );
+
+        #[cfg(not(feature = "log"))]
+        {
+            println!("#{} {} ({}){}", step, op, node, batch_label);
+            for (index, (name, shape)) in inputs.iter().enumerate() {
+                println!("  input {}: {} ({:?})", index, name, shape);
+            }
+            for (index, (name, shape)) in outputs.iter().enumerate() {
+                println!("  output {}: {} ({:?})", index, name, shape);
+            }
+            println!("  time: {}ms", elapsed_ms);
+        }
+    }
+
+    /// Return an execution plan for the given inputs and outputs, reusing a
+    /// cached plan if one matches.
+    fn get_plan(&self, inputs: &[NodeId], outputs: &[NodeId]) -> Result<Arc<CachedPlan>, RunError> {
+        {
+            let mut plans = self.lock_cached_plans();
+            if let Some(index) = plans.iter().position(|plan| plan.matches(inputs, outputs)) {
+                let plan = plans.remove(index);
+                plans.insert(0, plan.clone());
+                return Ok(plan);
+            }
+        }
+
+        // Create the plan without holding the lock, so concurrent runs that
+        // need different plans are not serialized. If several threads create
+        // the same plan at the same time, each of them is cached and the
+        // duplicates are eventually evicted.
+
+        let steps: Vec<NodeId> = self
+            .create_plan(inputs, outputs)?
+            .into_iter()
+            .map(|(op_node_id, _)| op_node_id)
+            .collect();
+
+        let mut use_counts = vec![0; self.nodes.len()];
+        for &op_node_id in &steps {
+            for &input_id in self.operator_node(op_node_id).inputs.iter().flatten() {
+                use_counts[input_id] += 1;
+            }
+        }
+        for &output_id in outputs {
+            use_counts[output_id] += 1;
+        }
+
+        let plan = Arc::new(CachedPlan {
+            inputs: inputs.to_vec(),
+            outputs: outputs.to_vec(),
+            steps,
+            use_counts,
+        });
+        let mut plans = self.lock_cached_plans();
+        plans.insert(0, plan.clone());
+        plans.truncate(MAX_CACHED_PLANS);
+
+        Ok(plan)
+    }
+
+    /// Lock the cached execution plans.
+    ///
+    /// Plans are only ever inserted or removed as a whole, so the cache is
+    /// still valid if a thread panicked while holding the lock.
+    fn lock_cached_plans(&self) -> MutexGuard<'_, Vec<Arc<CachedPlan>>> {
+        self.cached_plans
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Return the operator node with a given ID.
+    ///
+    /// Panics if `id` is not an operator node. This is intended for use with
+    /// IDs from an execution plan.
+    fn operator_node(&self, id: NodeId) -> &OperatorNode {
+        match self.nodes.get(id) {
+            Some(Node::Operator(op_node)) => op_node,
+            _ => panic!("node {} is not an operator", id),
+        }
+    }
+
+    /// Return the value of a constant node as an operator input, or `None`
+    /// if `id` is not a constant.
+    fn constant_input(&self, id: NodeId) -> Option<Input<'_>> {
+        match self.nodes.get(id)? {
+            Node::Constant(Constant::Float(node)) => Some(Input::FloatTensor(node.data.view())),
+            Node::Constant(Constant::Int(node)) => Some(Input::IntTensor(node.data.view())),
+            _ => None,
+        }
+    }
+
+    /// Create an execution plan for a sequence of computation steps that begin
+    /// with `inputs` and eventually produces `outputs`.
+    ///
+    /// The set of input and output node IDs must be unique.
+    ///
+    /// Any node IDs in `outputs` which reference constant or input values are
+    /// omitted from the plan.
+    fn create_plan(
+        &self,
+        inputs: &[NodeId],
+        outputs: &[NodeId],
+    ) -> Result<Vec<(NodeId, &OperatorNode)>, RunError> {
+        if !all_unique(outputs, |x, y| x == y) {
+            return Err(RunError::PlanningError("output IDs are not unique".into()));
+        }
+
+        if !all_unique(inputs, |x, y| x == y) {
+            return Err(RunError::PlanningError("input IDs are not unique".into()));
+        }
+
+        if inputs.iter().any(|&id| id >= self.nodes.len()) {
+            return Err(RunError::InvalidNodeId);
         }
 
         // Map of output node to source operator
@@ -618,8 +1844,7 @@ impl Graph {
         }
 
         // Set of values that are available after executing the plan
-        let mut resolved_values: HashSet<NodeId> =
-            inputs.iter().map(|(node_id, _)| *node_id).collect();
+        let mut resolved_values: HashSet<NodeId> = inputs.iter().copied().collect();
         for (node_id, node) in self.nodes.iter().enumerate() {
             if let Node::Constant(_) = node {
                 resolved_values.insert(node_id);
@@ -713,9 +1938,11 @@ mod tests {
     use rten_tensor::test_util::{expect_equal, expect_equal_with_tolerance};
     use rten_tensor::{tensor, Tensor, TensorView};
 
-    use crate::graph::{Dimension, Graph, RunError};
+    use crate::batching::MicroBatch;
+    use crate::graph::{Dimension, Graph, NodeId, RunError, RunOptions, ValueStats};
     use crate::ops::{
-        Concat, Conv, InputList, IntoOpResult, OpError, Operator, Output, Relu, Shape,
+        Add, Concat, Conv, DataType, InputList, IntoOpResult, Log, MatMul, NonZero, OpError,
+        Operator, Output, Relu, Reshape, Shape, Sqrt, Transpose,
     };
 
     #[derive(Clone, Debug, Default)]
@@ -790,9 +2017,9 @@ mod tests {
             ],
         );
         let weights_id = g.add_constant(Some("weight"), weights);
-        let input_id = g.add_value(Some("input"), None);
+        let input_id = g.add_value_with_dtype(Some("input"), None, None);
 
-        let conv_out = g.add_value(Some("conv_out"), None);
+        let conv_out = g.add_value_with_dtype(Some("conv_out"), None, None);
         g.add_op(
             Some("conv"),
             Box::new(Conv {
@@ -804,7 +2031,7 @@ mod tests {
             &[input_id, weights_id].map(Some),
             &[conv_out].map(Some),
         );
-        let relu_out = g.add_value(Some("relu_out"), None);
+        let relu_out = g.add_value_with_dtype(Some("relu_out"), None, None);
         g.add_op(
             Some("relu"),
             Box::new(Relu {}),
@@ -841,8 +2068,8 @@ mod tests {
 
         let weights = Tensor::from_data(&[1], vec![0.3230]);
         let weights_id = g.add_constant(Some("weights"), weights.clone());
-        let input_id = g.add_value(Some("input"), None);
-        let relu_out_id = g.add_value(Some("relu_out"), None);
+        let input_id = g.add_value_with_dtype(Some("input"), None, None);
+        let relu_out_id = g.add_value_with_dtype(Some("relu_out"), None, None);
         let relu_op_id = g.add_op(
             Some("relu"),
             Box::new(Relu {}),
@@ -855,8 +2082,8 @@ mod tests {
         assert_eq!(g.node_name(relu_op_id), "relu");
 
         let anon_weights_id = g.add_constant(None, weights);
-        let anon_input_id = g.add_value(None, None);
-        let anon_out_id = g.add_value(None, None);
+        let anon_input_id = g.add_value_with_dtype(None, None, None);
+        let anon_out_id = g.add_value_with_dtype(None, None, None);
         let anon_op_id = g.add_op(
             None,
             Box::new(Relu {}),
@@ -881,7 +2108,7 @@ mod tests {
 
         let weights = Tensor::from_data(&[1, 1, 2], vec![0.3230, 0.5]);
         let weights_id = g.add_constant(Some("weights"), weights.clone());
-        let input_id = g.add_value(
+        let input_id = g.add_value_with_dtype(
             Some("input"),
             Some(
                 [
@@ -892,8 +2119,9 @@ mod tests {
                 ]
                 .to_vec(),
             ),
+            Some(DataType::Float),
         );
-        let relu_out_id = g.add_value(Some("relu_out"), None);
+        let relu_out_id = g.add_value_with_dtype(Some("relu_out"), None, None);
         let relu_op_id = g.add_op(
             Some("relu"),
             Box::new(Relu {}),
@@ -938,16 +2166,16 @@ mod tests {
     fn test_graph_planning_order() -> Result<(), Box<dyn Error>> {
         let mut g = Graph::new();
 
-        let input_id = g.add_value(Some("input"), None);
+        let input_id = g.add_value_with_dtype(Some("input"), None, None);
 
-        let op_a_out = g.add_value(Some("op_a_out"), None);
+        let op_a_out = g.add_value_with_dtype(Some("op_a_out"), None, None);
         g.add_op(
             Some("op_a"),
             Box::new(AddOne {}),
             &[Some(input_id)],
             &[Some(op_a_out)],
         );
-        let op_b_out = g.add_value(Some("op_b_out"), None);
+        let op_b_out = g.add_value_with_dtype(Some("op_b_out"), None, None);
         g.add_op(
             Some("op_b"),
             Box::new(AddOne {}),
@@ -957,7 +2185,7 @@ mod tests {
 
         // op_c has both op_a and op_b as inputs. Since op_b depends on op_a,
         // execution must run op_a, then op_b, then op_c.
-        let op_c_out = g.add_value(Some("op_c_out"), None);
+        let op_c_out = g.add_value_with_dtype(Some("op_c_out"), None, None);
         g.add_op(
             Some("op_c"),
             Box::new(Concat { axis: 0 }),
@@ -966,7 +2194,7 @@ mod tests {
         );
 
         // op_d is the same as op_c, but input order is reversed
-        let op_d_out = g.add_value(Some("op_d_out"), None);
+        let op_d_out = g.add_value_with_dtype(Some("op_d_out"), None, None);
         g.add_op(
             Some("op_d"),
             Box::new(Concat { axis: 0 }),
@@ -997,15 +2225,15 @@ mod tests {
     fn test_graph_intermediate_output() {
         let mut g = Graph::new();
 
-        let input_id = g.add_value(Some("input"), None);
-        let op_a_out = g.add_value(Some("op_a_out"), None);
+        let input_id = g.add_value_with_dtype(Some("input"), None, None);
+        let op_a_out = g.add_value_with_dtype(Some("op_a_out"), None, None);
         g.add_op(
             Some("op_a"),
             Box::new(AddOne {}),
             &[Some(input_id)],
             &[Some(op_a_out)],
         );
-        let op_b_out = g.add_value(Some("op_b_out"), None);
+        let op_b_out = g.add_value_with_dtype(Some("op_b_out"), None, None);
         g.add_op(
             Some("op_b"),
             Box::new(AddOne {}),
@@ -1026,11 +2254,11 @@ mod tests {
         let mut g = Graph::new();
 
         let input = Tensor::from_data(&[5], vec![1., 2., 3., 4., 5.]);
-        let input_id = g.add_value(Some("input"), None);
+        let input_id = g.add_value_with_dtype(Some("input"), None, None);
 
         let mut prev_output = input_id;
         for _ in 0..100 {
-            let next_output = g.add_value(None, None);
+            let next_output = g.add_value_with_dtype(None, None, None);
             g.add_op(
                 None,
                 Box::new(AddOne {}),
@@ -1050,12 +2278,118 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_graph_cached_plan() -> Result<(), Box<dyn Error>> {
+        let mut g = Graph::new();
+
+        let input = Tensor::from_data(&[2], vec![1., 2.]);
+        let input_id = g.add_value_with_dtype(Some("input"), None, None);
+        let mid_id = g.add_value_with_dtype(Some("mid"), None, None);
+        g.add_op(
+            Some("add_one_a"),
+            Box::new(AddOne {}),
+            &[Some(input_id)],
+            &[Some(mid_id)],
+        );
+        let output_id = g.add_value_with_dtype(Some("output"), None, None);
+        g.add_op(
+            Some("add_one_b"),
+            Box::new(AddOne {}),
+            &[Some(mid_id)],
+            &[Some(output_id)],
+        );
+
+        let cached_plan = |g: &Graph| g.lock_cached_plans().first().cloned();
+
+        // Repeated runs with the same inputs and outputs reuse the plan.
+        let mut plans = Vec::new();
+        for _ in 0..2 {
+            let results = g.run(&[(input_id, (&input).into())], &[output_id], None)?;
+            expect_equal(
+                results[0].as_float_ref().unwrap(),
+                &Tensor::from_data(&[2], vec![3., 4.]),
+            )?;
+            plans.push(cached_plan(&g).unwrap());
+        }
+        assert!(Arc::ptr_eq(&plans[0], &plans[1]));
+
+        // Requesting different outputs creates a new plan.
+        let results = g.run(&[(input_id, (&input).into())], &[mid_id, output_id], None)?;
+        assert_eq!(results.len(), 2);
+        expect_equal(
+            results[0].as_float_ref().unwrap(),
+            &Tensor::from_data(&[2], vec![2., 3.]),
+        )?;
+        let plan = cached_plan(&g).unwrap();
+        assert!(!Arc::ptr_eq(&plans[0], &plan));
+        assert!(plan.matches(&[input_id], &[mid_id, output_id]));
+
+        // Switching back to the previous outputs reuses the earlier plan.
+        g.run(&[(input_id, (&input).into())], &[output_id], None)?;
+        assert!(Arc::ptr_eq(&plans[0], &cached_plan(&g).unwrap()));
+
+        // Providing different inputs creates a new plan.
+        let mid = Tensor::from_data(&[2], vec![5., 6.]);
+        let results = g.run(&[(mid_id, (&mid).into())], &[output_id], None)?;
+        expect_equal(
+            results[0].as_float_ref().unwrap(),
+            &Tensor::from_data(&[2], vec![6., 7.]),
+        )?;
+        let plan = cached_plan(&g).unwrap();
+        assert!(plan.matches(&[mid_id], &[output_id]));
+        assert_eq!(plan.steps.len(), 1);
+
+        // A panic while the cached plan is locked does not prevent later runs.
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _guard = g.cached_plans.lock();
+            panic!("poison the lock");
+        }));
+        assert!(result.is_err());
+        assert!(g.cached_plans.is_poisoned());
+        g.run(&[(input_id, (&input).into())], &[output_id], None)?;
+
+        // The number of cached plans is limited.
+        let output_sets: [&[NodeId]; 4] = [
+            &[mid_id],
+            &[output_id],
+            &[mid_id, output_id],
+            &[output_id, mid_id],
+        ];
+        for outputs in output_sets {
+            g.run(&[(input_id, (&input).into())], outputs, None)?;
+        }
+        g.run(&[(mid_id, (&mid).into())], &[output_id], None)?;
+        assert_eq!(g.lock_cached_plans().len(), super::MAX_CACHED_PLANS);
+        assert!(cached_plan(&g).unwrap().matches(&[mid_id], &[output_id]));
+
+        // Modifying the graph invalidates the cached plan.
+        let final_id = g.add_value_with_dtype(Some("final"), None, None);
+        g.add_op(
+            Some("add_one_c"),
+            Box::new(AddOne {}),
+            &[Some(output_id)],
+            &[Some(final_id)],
+        );
+        assert!(g.lock_cached_plans().is_empty());
+        let results = g.run(&[(input_id, (&input).into())], &[final_id], None)?;
+        expect_equal(
+            results[0].as_float_ref().unwrap(),
+            &Tensor::from_data(&[2], vec![4., 5.]),
+        )?;
+
+        // Invalid input node IDs are reported as an error.
+        let result = g.run(&[(final_id + 100, (&input).into())], &[final_id], None);
+        assert_eq!(result.err(), Some(RunError::InvalidNodeId));
+
+        Ok(())
+    }
+
     #[test]
     fn test_noop_graph() -> Result<(), Box<dyn Error>> {
         let mut g = Graph::new();
 
         let input = Tensor::from_data(&[5], vec![1., 2., 3., 4., 5.]);
-        let input_id = g.add_value(Some("input"), None);
+        let input_id = g.add_value_with_dtype(Some("input"), None, None);
 
         let results = g
             .run(&[(input_id, (&input).into())], &[input_id], None)
@@ -1066,6 +2400,483 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_graph_boundary_input_types() {
+        let mut g = Graph::new();
+
+        let int64_id = g.add_value_with_dtype(Some("int64"), None, Some(DataType::Int64));
+        let bool_id = g.add_value_with_dtype(Some("bool"), None, Some(DataType::Bool));
+        let u8_id = g.add_value_with_dtype(Some("u8"), None, None);
+        let output_id = g.add_value_with_dtype(Some("output"), None, None);
+        g.add_op(
+            Some("concat"),
+            Box::new(Concat { axis: 0 }),
+            &[int64_id, bool_id, u8_id].map(Some),
+            &[Some(output_id)],
+        );
+
+        let int64_input = tensor!([-5i64, 1 << 20]);
+        let bool_input = tensor!([true, false]);
+        let u8_input = tensor!([0u8, 255]);
+
+        // Inputs with types that operators don't support are converted to
+        // i32 before being passed to operators.
+        let results = g
+            .run(
+                &[
+                    (int64_id, (&int64_input).into()),
+                    (bool_id, (&bool_input).into()),
+                    (u8_id, (&u8_input).into()),
+                ],
+                &[output_id, bool_id],
+                None,
+            )
+            .unwrap();
+        assert_eq!(
+            results[0].as_int_ref(),
+            Some(&tensor!([-5, 1 << 20, 1, 0, 0, 255]))
+        );
+        assert_eq!(results[1].as_int_ref(), Some(&tensor!([1, 0])));
+
+        // Outputs can be converted back to the types the model declares.
+        let output: Tensor<i64> = results[0].clone().try_into().unwrap();
+        assert_eq!(output, tensor!([-5i64, 1 << 20, 1, 0, 0, 255]));
+        let output: Tensor<bool> = results[1].clone().try_into().unwrap();
+        assert_eq!(output, tensor!([true, false]));
+
+        // Int64 values which don't fit in an i32 are an error.
+        let int64_input = tensor!([i64::MAX]);
+        let result = g.run(
+            &[
+                (int64_id, (&int64_input).into()),
+                (bool_id, (&bool_input).into()),
+                (u8_id, (&u8_input).into()),
+            ],
+            &[output_id],
+            None,
+        );
+        assert_eq!(
+            result.err(),
+            Some(RunError::InvalidInput {
+                name: "int64".to_string(),
+                error: OpError::InvalidValue("Int64 value is out of range for i32")
+                    .with_details("value 9223372036854775807"),
+            })
+        );
+
+        // Inputs with a declared integer type can also be passed as `i32`.
+        let int32_input = tensor!([-5, 1 << 20]);
+        let result = g.run(
+            &[
+                (int64_id, (&int32_input).into()),
+                (bool_id, (&bool_input).into()),
+                (u8_id, (&u8_input).into()),
+            ],
+            &[output_id],
+            None,
+        );
+        assert!(result.is_ok());
+
+        // Inputs whose type doesn't match the declared type are an error.
+        let float_input = tensor!([1., 0.]);
+        let result = g.run(
+            &[
+                (int64_id, (&int64_input).into()),
+                (bool_id, (&float_input).into()),
+                (u8_id, (&u8_input).into()),
+            ],
+            &[output_id],
+            None,
+        );
+        assert_eq!(
+            result.err(),
+            Some(RunError::IncorrectInputType {
+                name: "bool".to_string(),
+                expected: DataType::Bool,
+                actual: DataType::Float,
+            })
+        );
+    }
+
+    #[test]
+    fn test_graph_run_micro_batched() -> Result<(), Box<dyn Error>> {
+        let mut g = Graph::new();
+
+        let batch = || Dimension::Symbolic("batch".to_string());
+        let x_id =
+            g.add_value_with_dtype(Some("x"), Some(vec![batch(), Dimension::Fixed(3)]), None);
+        let weights_id = g.add_constant(
+            Some("weights"),
+            Tensor::from_data(&[3, 2], vec![1., 2., 3., 4., 5., 6.]),
+        );
+        let matmul_id = g.add_value_with_dtype(Some("matmul_out"), None, None);
+        g.add_op(
+            Some("matmul"),
+            Box::new(MatMul {}),
+            &[x_id, weights_id].map(Some),
+            &[Some(matmul_id)],
+        );
+        let bias_id = g.add_value_with_dtype(Some("bias"), Some(vec![Dimension::Fixed(2)]), None);
+        let output_id = g.add_value_with_dtype(
+            Some("output"),
+            Some(vec![batch(), Dimension::Fixed(2)]),
+            None,
+        );
+        g.add_op(
+            Some("add"),
+            Box::new(Add {}),
+            &[matmul_id, bias_id].map(Some),
+            &[Some(output_id)],
+        );
+        let shape_id = g.add_value_with_dtype(Some("shape"), None, None);
+        g.add_op(
+            Some("shape"),
+            Box::new(Shape {}),
+            &[Some(output_id)],
+            &[Some(shape_id)],
+        );
+
+        let x = Tensor::from_data(&[5, 3], (0..15).map(|x| x as f32).collect::<Vec<_>>());
+        let bias = Tensor::from_data(&[2], vec![10., 20.]);
+        let inputs = [(x_id, x.view().into()), (bias_id, bias.view().into())];
+        let expected = g.run(&inputs, &[output_id], None)?;
+
+        let run_opts = |size, parallel| {
+            Some(RunOptions {
+                micro_batch: Some(MicroBatch {
+                    dim: "batch".to_string(),
+                    size,
+                    parallel,
+                }),
+                ..Default::default()
+            })
+        };
+
+        // Inputs without the batch dimension, such as `bias`, are passed to
+        // every micro-batch.
+        for size in [1, 2, 5, 8] {
+            for parallel in [false, true] {
+                let result = g.run(&inputs, &[output_id], run_opts(size, parallel))?;
+                assert_eq!(result, expected);
+            }
+        }
+
+        // Dumped values include the micro-batch index in file names.
+        let dump_dir =
+            std::env::temp_dir().join(format!("rten-micro-batch-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dump_dir).unwrap();
+        let mut opts = run_opts(2, false).unwrap();
+        opts.dump_dir = Some(dump_dir.clone());
+        opts.dump_filter = Some("output".to_string());
+        g.run(&inputs, &[output_id], Some(opts))?;
+        let mut dumped_files: Vec<_> = std::fs::read_dir(&dump_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        dumped_files.sort();
+        std::fs::remove_dir_all(&dump_dir).unwrap();
+        assert_eq!(
+            dumped_files,
+            [
+                "output_batch0.npy",
+                "output_batch1.npy",
+                "output_batch2.npy"
+            ]
+        );
+
+        // Input without a declared shape.
+        let y_id = g.add_value_with_dtype(Some("y"), None, None);
+        let y_out_id = g.add_value_with_dtype(Some("y_out"), Some(vec![batch()]), None);
+        g.add_op(
+            Some("relu"),
+            Box::new(Relu {}),
+            &[Some(y_id)],
+            &[Some(y_out_id)],
+        );
+        let y = Tensor::from_data(&[5], vec![1., 2., 3., 4., 5.]);
+        let result = g.run(
+            &[
+                (x_id, x.view().into()),
+                (bias_id, bias.view().into()),
+                (y_id, y.view().into()),
+            ],
+            &[output_id, y_out_id],
+            run_opts(2, false),
+        );
+        assert_eq!(
+            result.err().map(|err| err.to_string()),
+            Some("micro-batching failed: input \"y\" does not have a declared shape".to_string())
+        );
+
+        // Output without the batch dimension.
+        let result = g.run(&inputs, &[shape_id], run_opts(2, false));
+        assert_eq!(
+            result.err().map(|err| err.to_string()),
+            Some(
+                "micro-batching failed: output \"shape\" does not have batch dimension \"batch\""
+                    .to_string()
+            )
+        );
+
+        // Invalid micro-batch size.
+        let result = g.run(&inputs, &[output_id], run_opts(0, false));
+        assert!(matches!(result, Err(RunError::MicroBatchError(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_graph_dry_run() {
+        let mut g = Graph::new();
+
+        let x_id = g.add_value_with_dtype(Some("x"), None, None);
+        let transposed_id = g.add_value_with_dtype(Some("transposed"), None, None);
+        g.add_op(
+            Some("transpose"),
+            Box::new(Transpose { perm: None }),
+            &[Some(x_id)],
+            &[Some(transposed_id)],
+        );
+        let shape_id = g.add_value_with_dtype(Some("shape"), None, None);
+        g.add_op(
+            Some("shape"),
+            Box::new(Shape {}),
+            &[Some(transposed_id)],
+            &[Some(shape_id)],
+        );
+        let reshaped_id = g.add_value_with_dtype(Some("reshaped"), None, None);
+        g.add_op(
+            Some("reshape"),
+            Box::new(Reshape { allow_zero: false }),
+            &[x_id, shape_id].map(Some),
+            &[Some(reshaped_id)],
+        );
+        let output_id = g.add_value_with_dtype(Some("output"), None, None);
+        g.add_op(
+            Some("add"),
+            Box::new(Add {}),
+            &[reshaped_id, transposed_id].map(Some),
+            &[Some(output_id)],
+        );
+
+        let report = g.dry_run(&[(x_id, &[64, 4])], &[output_id]).unwrap();
+
+        let ops: Vec<_> = report.steps.iter().map(|step| step.op.as_str()).collect();
+        assert_eq!(ops, ["Transpose", "Shape", "Reshape", "Add"]);
+        assert_eq!(report.shape(transposed_id), Some([4, 64].as_slice()));
+        assert_eq!(report.shape(shape_id), Some([2].as_slice()));
+        assert_eq!(report.shape(reshaped_id), Some([4, 64].as_slice()));
+        assert_eq!(report.shape(output_id), Some([4, 64].as_slice()));
+
+        // Each `[4, 64]` value uses 1024 bytes and the shape uses 8 bytes.
+        // The input is alive until the `Reshape` has run, so memory peaks
+        // when its output is allocated.
+        let live_bytes: Vec<_> = report.steps.iter().map(|step| step.live_bytes).collect();
+        assert_eq!(live_bytes, [2048, 2056, 3080, 3072]);
+        assert_eq!(report.total_activation_bytes, 3080);
+        assert_eq!(report.peak_bytes, 3080);
+        assert_eq!(report.peak_step, Some(2));
+
+        // Input sizes use the declared element type.
+        let mut g = Graph::new();
+        let x_id = g.add_value_with_dtype(Some("x"), None, Some(DataType::Int64));
+        let output_id = g.add_value_with_dtype(Some("output"), None, None);
+        g.add_op(
+            Some("relu"),
+            Box::new(Relu {}),
+            &[Some(x_id)],
+            &[Some(output_id)],
+        );
+        let report = g.dry_run(&[(x_id, &[2, 3])], &[output_id]).unwrap();
+        assert_eq!(report.steps[0].live_bytes, 6 * 8 + 6 * 4);
+        assert_eq!(report.total_activation_bytes, 6 * 4);
+
+        // Operator which does not support shape inference, with an input
+        // whose value is unknown.
+        let mut g = Graph::new();
+        let x_id = g.add_value_with_dtype(Some("x"), None, None);
+        let output_id = g.add_value_with_dtype(Some("output"), None, None);
+        g.add_op(
+            Some("nonzero"),
+            Box::new(NonZero {}),
+            &[Some(x_id)],
+            &[Some(output_id)],
+        );
+        let result = g.dry_run(&[(x_id, &[2, 3])], &[output_id]);
+        assert_eq!(
+            result.err(),
+            Some(RunError::OperatorError {
+                name: "nonzero".to_string(),
+                error: OpError::UnsupportedValue("operator does not support shape inference"),
+                input_shapes: vec![Some(vec![2, 3])],
+                input_dtypes: vec![None],
+                operator: "NonZero".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_graph_dry_run_uses_dim_bindings() {
+        let mut g = Graph::new();
+        let x_id = g.add_value_with_dtype(
+            Some("x"),
+            Some(vec![
+                Dimension::Symbolic("batch".to_string()),
+                Dimension::Fixed(3),
+            ]),
+            None,
+        );
+
+        // `NonZero` does not support shape inference, so its output shape
+        // has to be determined from the declared shape.
+        let nonzero_id = g.add_value_with_dtype(
+            Some("nonzero"),
+            Some(vec![
+                Dimension::Fixed(2),
+                Dimension::Symbolic("n".to_string()),
+            ]),
+            None,
+        );
+        g.add_op(
+            Some("nonzero"),
+            Box::new(NonZero {}),
+            &[Some(x_id)],
+            &[Some(nonzero_id)],
+        );
+        let result = g.dry_run(&[(x_id, &[5, 3])], &[nonzero_id]);
+        assert!(matches!(result, Err(RunError::OperatorError { .. })));
+
+        // Declared output shape which uses the bound `batch` symbol.
+        let mut g = Graph::new();
+        let x_id = g.add_value_with_dtype(
+            Some("x"),
+            Some(vec![
+                Dimension::Symbolic("batch".to_string()),
+                Dimension::Fixed(3),
+            ]),
+            None,
+        );
+        let output_id = g.add_value_with_dtype(
+            Some("output"),
+            Some(vec![
+                Dimension::Fixed(2),
+                Dimension::Symbolic("batch".to_string()),
+            ]),
+            None,
+        );
+        g.add_op(
+            Some("nonzero"),
+            Box::new(NonZero {}),
+            &[Some(x_id)],
+            &[Some(output_id)],
+        );
+        let relu_id = g.add_value_with_dtype(Some("relu"), None, None);
+        g.add_op(
+            Some("relu"),
+            Box::new(Relu {}),
+            &[Some(output_id)],
+            &[Some(relu_id)],
+        );
+
+        let report = g.dry_run(&[(x_id, &[5, 3])], &[relu_id]).unwrap();
+        assert_eq!(report.dim_bindings.get("batch"), Some(5));
+        assert_eq!(report.shape(output_id), Some([2, 5].as_slice()));
+        assert_eq!(report.shape(relu_id), Some([2, 5].as_slice()));
+    }
+
+    #[test]
+    fn test_graph_input_shapes() {
+        let mut g = Graph::new();
+
+        let batch = || Dimension::Symbolic("batch".to_string());
+        let x_id =
+            g.add_value_with_dtype(Some("x"), Some(vec![batch(), Dimension::Fixed(3)]), None);
+        let y_id =
+            g.add_value_with_dtype(Some("y"), Some(vec![batch(), Dimension::Fixed(3)]), None);
+        let output_id = g.add_value_with_dtype(Some("output"), None, None);
+        g.add_op(
+            Some("concat"),
+            Box::new(Concat { axis: 0 }),
+            &[x_id, y_id].map(Some),
+            &[Some(output_id)],
+        );
+
+        let x = Tensor::<f32>::zeros(&[2, 3]);
+        let y = Tensor::<f32>::zeros(&[2, 3]);
+        let bindings = g
+            .bind_input_shapes(&[(x_id, x.view().into()), (y_id, y.view().into())])
+            .unwrap();
+        assert_eq!(bindings.get("batch"), Some(2));
+        assert_eq!(
+            bindings.resolve_shape(&[batch(), Dimension::Fixed(4)]),
+            Some(vec![2, 4])
+        );
+
+        let result = g.run(
+            &[(x_id, x.view().into()), (y_id, y.view().into())],
+            &[output_id],
+            None,
+        );
+        assert!(result.is_ok());
+
+        // Input with the wrong rank.
+        let bad_rank = Tensor::<f32>::zeros(&[2, 3, 1]);
+        let result = g.run(
+            &[(x_id, x.view().into()), (y_id, bad_rank.view().into())],
+            &[output_id],
+            None,
+        );
+        assert_eq!(
+            result.err(),
+            Some(RunError::InputRankMismatch {
+                name: "y".to_string(),
+                expected: 2,
+                actual: 3,
+            })
+        );
+
+        // Input with the wrong size for a fixed dimension.
+        let bad_fixed = Tensor::<f32>::zeros(&[2, 4]);
+        let result = g.run(
+            &[(x_id, x.view().into()), (y_id, bad_fixed.view().into())],
+            &[output_id],
+            None,
+        );
+        assert_eq!(
+            result.err(),
+            Some(RunError::InputShapeMismatch {
+                name: "y".to_string(),
+                dim: 1,
+                expected: 3,
+                actual: 4,
+                symbol: None,
+            })
+        );
+
+        // Inputs with inconsistent sizes for the same symbolic dimension.
+        let bad_symbol = Tensor::<f32>::zeros(&[5, 3]);
+        let result = g.run(
+            &[(x_id, x.view().into()), (y_id, bad_symbol.view().into())],
+            &[output_id],
+            None,
+        );
+        let err = result.err().unwrap();
+        assert_eq!(
+            err,
+            RunError::InputShapeMismatch {
+                name: "y".to_string(),
+                dim: 0,
+                expected: 2,
+                actual: 5,
+                symbol: Some("batch".to_string()),
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "input \"y\" has size 5 in dim 0 but the model expects 2 (bound to \"batch\")"
+        );
+    }
+
     #[test]
     fn test_constant_graph() -> Result<(), Box<dyn Error>> {
         let mut g = Graph::new();
@@ -1098,7 +2909,7 @@ mod tests {
     #[test]
     fn test_duplicate_inputs() {
         let mut g = Graph::new();
-        let input_id = g.add_value(Some("input"), None);
+        let input_id = g.add_value_with_dtype(Some("input"), None, None);
         let input = tensor!([1.]);
         let result = g.run(
             &[(input_id, (&input).into()), (input_id, (&input).into())],
@@ -1115,8 +2926,8 @@ mod tests {
     fn test_duplicate_outputs() {
         let mut g = Graph::new();
 
-        let input_id = g.add_value(Some("input"), None);
-        let op_a_out = g.add_value(Some("op_a_out"), None);
+        let input_id = g.add_value_with_dtype(Some("input"), None, None);
+        let op_a_out = g.add_value_with_dtype(Some("op_a_out"), None, None);
         g.add_op(
             Some("op_a"),
             Box::new(AddOne {}),
@@ -1137,7 +2948,7 @@ mod tests {
     #[test]
     fn test_call_op_with_missing_input() {
         let mut g = Graph::new();
-        let output = g.add_value(None, None);
+        let output = g.add_value_with_dtype(None, None, None);
 
         // Call an operator with an input omitted by setting it to `None`,
         // as opposed to passing a shorter input list. This enables omitting
@@ -1150,9 +2961,402 @@ mod tests {
             results.err(),
             Some(RunError::OperatorError {
                 name: "shape".to_string(),
-                error: OpError::MissingInputs
+                error: OpError::MissingInputs,
+                input_shapes: vec![None],
+                input_dtypes: vec![None],
+                operator: "Shape".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_operator_error_includes_inputs_and_attrs() {
+        let mut g = Graph::new();
+        let a_id = g.add_value_with_dtype(Some("a"), None, None);
+        let b_id = g.add_value_with_dtype(Some("b"), None, None);
+        let output = g.add_value_with_dtype(None, None, None);
+        g.add_op(
+            Some("concat"),
+            Box::new(Concat { axis: 0 }),
+            &[Some(a_id), None, Some(b_id)],
+            &[Some(output)],
+        );
+
+        let a = Tensor::<f32>::zeros(&[2, 3]);
+        let b = Tensor::<f32>::zeros(&[2, 4]);
+        let err = g
+            .run(
+                &[(a_id, a.view().into()), (b_id, b.view().into())],
+                &[output],
+                None,
+            )
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            err,
+            RunError::OperatorError {
+                name: "concat".to_string(),
+                error: OpError::IncompatibleInputShapes(
+                    "Dimensions must be the same except for concat axis"
+                )
+                .with_details("[2, 3] and [2, 4]"),
+                input_shapes: vec![Some(vec![2, 3]), None, Some(vec![2, 4])],
+                input_dtypes: vec![Some(DataType::Float), None, Some(DataType::Float)],
+                operator: "Concat { axis: 0 }".into(),
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "operator \"concat\" failed: incompatible input shapes: Dimensions must be the same \
+             except for concat axis ([2, 3] and [2, 4]) (input shapes: [Some([2, 3]), None, Some([2, 4])], \
+             input types: [Some(Float), None, Some(Float)], operator: Concat { axis: 0 })"
+        );
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn test_verbose_output_is_logged() {
+        use log::kv::Key;
+
+        /// Logger which records the fields of `rten::graph` records.
+        struct TestLogger {
+            records: Mutex<Vec<Vec<(String, String)>>>,
+        }
+
+        impl log::Log for TestLogger {
+            fn enabled(&self, _metadata: &log::Metadata) -> bool {
+                true
+            }
+
+            fn log(&self, record: &log::Record) {
+                if record.target() != "rten::graph" {
+                    return;
+                }
+                let kvs = record.key_values();
+                let fields = ["step", "op", "node", "inputs", "outputs"]
+                    .iter()
+                    .filter_map(|key| {
+                        kvs.get(Key::from_str(key))
+                            .map(|val| (key.to_string(), val.to_string()))
+                    })
+                    .collect();
+                self.records.lock().unwrap().push(fields);
+            }
+
+            fn flush(&self) {}
+        }
+
+        static LOGGER: TestLogger = TestLogger {
+            records: Mutex::new(Vec::new()),
+        };
+        log::set_logger(&LOGGER).unwrap();
+        log::set_max_level(log::LevelFilter::Debug);
+
+        let mut g = Graph::new();
+        let input_id = g.add_value_with_dtype(Some("input"), None, None);
+        let output_id = g.add_value_with_dtype(Some("output"), None, None);
+        g.add_op(
+            Some("relu"),
+            Box::new(Relu {}),
+            &[Some(input_id)],
+            &[Some(output_id)],
+        );
+
+        let input = Tensor::<f32>::zeros(&[2, 3]);
+        g.run(
+            &[(input_id, input.view().into())],
+            &[output_id],
+            Some(RunOptions {
+                verbose: true,
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+        let records = LOGGER.records.lock().unwrap();
+        let field = |key: &str| {
+            records[0]
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.as_str())
+        };
+        assert_eq!(records.len(), 1);
+        assert_eq!(field("step"), Some("0"));
+        assert_eq!(field("op"), Some("Relu"));
+        assert_eq!(field("node"), Some("relu"));
+        assert_eq!(field("inputs"), Some("[(\"input\", [2, 3])]"));
+        assert_eq!(field("outputs"), Some("[(\"output\", [2, 3])]"));
+    }
+
+    #[test]
+    fn test_value_stats() {
+        let stats = ValueStats::from_values([1., f32::NAN, -2.].iter());
+        assert_eq!(
+            stats,
+            ValueStats {
+                min: -2.,
+                max: 1.,
+                has_nan: true,
+            }
+        );
+
+        // Statistics for an empty or all-NaN tensor compare equal to
+        // themselves.
+        let stats = ValueStats::from_values([f32::NAN].iter());
+        assert_eq!(stats, stats.clone());
+        assert_ne!(stats, ValueStats::from_values([].iter()));
+    }
+
+    #[test]
+    fn test_check_shapes() {
+        let batch = || Dimension::Symbolic("batch".to_string());
+
+        let mut g = Graph::new();
+        let x_id =
+            g.add_value_with_dtype(Some("x"), Some(vec![batch(), Dimension::Fixed(3)]), None);
+        let relu_out = g.add_value_with_dtype(
+            Some("relu_out"),
+            Some(vec![batch(), Dimension::Fixed(3)]),
+            None,
+        );
+        let nonzero_out = g.add_value_with_dtype(
+            Some("nonzero_out"),
+            Some(vec![
+                Dimension::Fixed(2),
+                Dimension::Symbolic("n".to_string()),
+            ]),
+            None,
+        );
+        let log_out = g.add_value_with_dtype(
+            Some("log_out"),
+            Some(vec![batch(), Dimension::Fixed(4)]),
+            None,
+        );
+        g.add_op(
+            Some("relu"),
+            Box::new(Relu {}),
+            &[Some(x_id)],
+            &[Some(relu_out)],
+        );
+        g.add_op(
+            Some("nonzero"),
+            Box::new(NonZero {}),
+            &[Some(relu_out)],
+            &[Some(nonzero_out)],
+        );
+        g.add_op(
+            Some("log"),
+            Box::new(Log {}),
+            &[Some(x_id)],
+            &[Some(log_out)],
+        );
+
+        let check_shapes = || {
+            Some(RunOptions {
+                check_shapes: true,
+                ..Default::default()
+            })
+        };
+        let x = Tensor::from([[1., 2., 3.], [4., 5., 6.]]);
+
+        // Outputs which match the declared shape, with `batch` bound from the
+        // input and `n` not bound by any input.
+        let result = g.run(
+            &[(x_id, x.view().into())],
+            &[relu_out, nonzero_out],
+            check_shapes(),
+        );
+        assert!(result.is_ok());
+
+        // Output which does not match the declared shape. This is only
+        // reported if checking is enabled.
+        let result = g.run(&[(x_id, x.view().into())], &[log_out], None);
+        assert!(result.is_ok());
+
+        let err = g
+            .run(&[(x_id, x.view().into())], &[log_out], check_shapes())
+            .err()
+            .unwrap();
+        assert_eq!(
+            err,
+            RunError::OutputShapeMismatch {
+                name: "log_out".to_string(),
+                op: "log".to_string(),
+                expected: vec![batch(), Dimension::Fixed(4)],
+                actual: vec![2, 3],
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "output \"log_out\" of operator \"log\" has shape [2, 3] but the model expects [Symbolic(\"batch\"), Fixed(4)]"
+        );
+    }
+
+    #[test]
+    fn test_check_finite() {
+        let mut g = Graph::new();
+        let input_id = g.add_value_with_dtype(Some("input"), None, None);
+        let relu_out = g.add_value_with_dtype(Some("relu_out"), None, None);
+        let log_out = g.add_value_with_dtype(Some("log_out"), None, None);
+        let sqrt_out = g.add_value_with_dtype(Some("sqrt_out"), None, None);
+        g.add_op(
+            Some("relu"),
+            Box::new(Relu {}),
+            &[Some(input_id)],
+            &[Some(relu_out)],
+        );
+        g.add_op(
+            Some("log"),
+            Box::new(Log {}),
+            &[Some(relu_out)],
+            &[Some(log_out)],
+        );
+        g.add_op(
+            Some("sqrt"),
+            Box::new(Sqrt {}),
+            &[Some(input_id)],
+            &[Some(sqrt_out)],
+        );
+
+        let input = tensor!([-1., 0., 4.]);
+        let check_finite = || {
+            Some(RunOptions {
+                check_finite: true,
+                ..Default::default()
             })
+        };
+
+        // Non-finite values are not reported unless checking is enabled.
+        let result = g.run(&[(input_id, input.view().into())], &[log_out], None);
+        assert!(result.is_ok());
+
+        // The error describes the first operator which produced non-finite
+        // values. Here the input is a temporary value that `Log` runs on
+        // in-place, so its statistics are recorded before the op runs.
+        let result = g.run(
+            &[(input_id, input.view().into())],
+            &[log_out],
+            check_finite(),
+        );
+        let err = result.err().unwrap();
+        assert_eq!(
+            err,
+            RunError::NonFiniteOutput {
+                name: "log".to_string(),
+                op: "Log".to_string(),
+                input_shapes: vec![Some(vec![3])],
+                input_stats: vec![Some(ValueStats {
+                    min: 0.,
+                    max: 4.,
+                    has_nan: false,
+                })],
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "operator \"log\" (Log) produced NaN or infinite values. input 0: shape [3] min 0 max 4;"
         );
+
+        // Inputs which are not consumed by the operator.
+        let input = tensor!([f32::NAN, -4., 1.]);
+        let result = g.run(
+            &[(input_id, input.view().into())],
+            &[sqrt_out],
+            check_finite(),
+        );
+        assert_eq!(
+            result.err(),
+            Some(RunError::NonFiniteOutput {
+                name: "sqrt".to_string(),
+                op: "Sqrt".to_string(),
+                input_shapes: vec![Some(vec![3])],
+                input_stats: vec![Some(ValueStats {
+                    min: -4.,
+                    max: 1.,
+                    has_nan: true,
+                })],
+            })
+        );
+    }
+
+    #[test]
+    fn test_dump_values() {
+        let mut g = Graph::new();
+        let input_id = g.add_value_with_dtype(Some("input"), None, None);
+        let relu_out = g.add_value_with_dtype(Some("encoder/relu:0"), None, None);
+        let sqrt_out = g.add_value_with_dtype(Some("sqrt_out"), None, None);
+        g.add_op(
+            Some("relu"),
+            Box::new(Relu {}),
+            &[Some(input_id)],
+            &[Some(relu_out)],
+        );
+        g.add_op(
+            Some("sqrt"),
+            Box::new(Sqrt {}),
+            &[Some(relu_out)],
+            &[Some(sqrt_out)],
+        );
+
+        let dump_dir = std::env::temp_dir().join(format!("rten-dump-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dump_dir).unwrap();
+        let input = tensor!([-1., 4.]);
+        let run_with_filter = |filter: Option<&str>| {
+            g.run(
+                &[(input_id, input.view().into())],
+                &[sqrt_out],
+                Some(RunOptions {
+                    dump_dir: Some(dump_dir.clone()),
+                    dump_filter: filter.map(|f| f.to_string()),
+                    ..Default::default()
+                }),
+            )
+        };
+        let dumped_files = || {
+            let mut names: Vec<_> = std::fs::read_dir(&dump_dir)
+                .unwrap()
+                .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+                .collect();
+            names.sort();
+            names
+        };
+
+        // Characters other than alphanumerics, `-`, `_` and `.` are replaced
+        // in file names.
+        run_with_filter(None).unwrap();
+        assert_eq!(dumped_files(), ["encoder_relu_0.npy", "sqrt_out.npy"]);
+        let relu_data = std::fs::read(dump_dir.join("encoder_relu_0.npy")).unwrap();
+        assert!(relu_data.starts_with(b"\x93NUMPY"));
+        assert!(relu_data.ends_with(&[0f32, 4.].map(|x| x.to_le_bytes()).concat()));
+
+        // Values whose names map to the same file stem don't overwrite each
+        // other.
+        let stems = g.npy_file_stems();
+        assert_eq!(stems[&relu_out], "encoder_relu_0");
+
+        let mut g = Graph::new();
+        let a_slash_id = g.add_value_with_dtype(Some("a/b"), None, None);
+        let a_colon_id = g.add_value_with_dtype(Some("a:b"), None, None);
+        let a_id = g.add_value_with_dtype(Some("a_b"), None, None);
+        let stems = g.npy_file_stems();
+        assert_eq!(stems[&a_id], "a_b");
+        assert_eq!(stems[&a_slash_id], format!("a_b_{}", a_slash_id));
+        assert_eq!(stems[&a_colon_id], format!("a_b_{}", a_colon_id));
+
+        // Only outputs matching the filter are written.
+        std::fs::remove_dir_all(&dump_dir).unwrap();
+        std::fs::create_dir_all(&dump_dir).unwrap();
+        run_with_filter(Some("sqrt")).unwrap();
+        assert_eq!(dumped_files(), ["sqrt_out.npy"]);
+
+        // Errors writing files are reported.
+        std::fs::remove_dir_all(&dump_dir).unwrap();
+        let result = run_with_filter(None);
+        assert!(matches!(
+            result,
+            Err(RunError::DumpError { name, .. }) if name == "encoder/relu:0"
+        ));
     }
 
     #[test]
@@ -1168,7 +3372,7 @@ mod tests {
     #[test]
     fn test_err_if_missing_operator_input() {
         let mut g = Graph::new();
-        let output = g.add_value(None, None);
+        let output = g.add_value_with_dtype(None, None, None);
         g.add_op(Some("op"), Box::new(Relu {}), &[Some(42)], &[Some(output)]);
         let result = g.run(&[], &[output], None);
         assert_eq!(
@@ -1210,30 +3414,30 @@ mod tests {
     #[test]
     fn test_runs_op_in_place() {
         let mut g = Graph::new();
-        let input_id = g.add_value(Some("input"), None);
+        let input_id = g.add_value_with_dtype(Some("input"), None, None);
 
-        let op1_out = g.add_value(Some("op1_out"), None);
+        let op1_out = g.add_value_with_dtype(Some("op1_out"), None, None);
         g.add_op(
             Some("op1"),
             Box::new(AddOneInPlace {}),
             &[Some(input_id)],
             &[Some(op1_out)],
         );
-        let op2_out = g.add_value(Some("op2_out"), None);
+        let op2_out = g.add_value_with_dtype(Some("op2_out"), None, None);
         g.add_op(
             Some("op2"),
             Box::new(AddOneInPlace {}),
             &[Some(op1_out)],
             &[Some(op2_out)],
         );
-        let op3_out = g.add_value(Some("op3_out"), None);
+        let op3_out = g.add_value_with_dtype(Some("op3_out"), None, None);
         g.add_op(
             Some("op3"),
             Box::new(AddOneInPlace {}),
             &[Some(op2_out)],
             &[Some(op3_out)],
         );
-        let op4_out = g.add_value(Some("op4_out"), None);
+        let op4_out = g.add_value_with_dtype(Some("op4_out"), None, None);
         g.add_op(
             Some("op4"),
             Box::new(AddOneInPlace {}),
@@ -1273,8 +3477,8 @@ mod tests {
         use crate::ops::Add; // A commutative operator
 
         let mut g = Graph::new();
-        let input_id = g.add_value(Some("input"), None);
-        let bias_id = g.add_value(Some("bias"), None);
+        let input_id = g.add_value_with_dtype(Some("input"), None, None);
+        let bias_id = g.add_value_with_dtype(Some("bias"), None, None);
 
         let op1 = TrackUsage::new(Add {});
         let op1_metrics = op1.metrics();
@@ -1282,14 +3486,14 @@ mod tests {
         let op2 = TrackUsage::new(Add {});
         let op2_metrics = op2.metrics();
 
-        let op1_out = g.add_value(Some("op1_out"), None);
+        let op1_out = g.add_value_with_dtype(Some("op1_out"), None, None);
         g.add_op(
             Some("op1"),
             Box::new(op1),
             &[Some(input_id), Some(bias_id)],
             &[Some(op1_out)],
         );
-        let op2_out = g.add_value(Some("op2_out"), None);
+        let op2_out = g.add_value_with_dtype(Some("op2_out"), None, None);
         g.add_op(
             Some("op2"),
             Box::new(op2),
@@ -1369,9 +3573,9 @@ mod tests {
     #[test]
     fn test_multiple_outputs() {
         let mut g = Graph::new();
-        let input_id = g.add_value(Some("input"), None);
-        let left_split_out = g.add_value(Some("left_split"), None);
-        let right_split_out = g.add_value(Some("right_split"), None);
+        let input_id = g.add_value_with_dtype(Some("input"), None, None);
+        let left_split_out = g.add_value_with_dtype(Some("left_split"), None, None);
+        let right_split_out = g.add_value_with_dtype(Some("right_split"), None, None);
 
         let split_op = Box::new(Split::new());
         let run_count = split_op.run_count.clone();
@@ -0,0 +1,307 @@
+//! Comparison of values computed by a model against reference values, such
+//! as those produced by another runtime.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+use rten_tensor::prelude::*;
+
+use crate::npy::read_npy_file;
+use crate::ops::Output;
+use crate::timing::Table;
+
+/// Numerical comparison between a value computed by a model and the
+/// corresponding reference value.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LayerDiff {
+    /// Name of the value node.
+    pub name: String,
+
+    /// Name of the operator type which produced the value (eg. "MatMul").
+    pub op: String,
+
+    /// Shape of the computed value.
+    pub shape: Vec<usize>,
+
+    /// Shape of the reference value.
+    pub reference_shape: Vec<usize>,
+
+    /// Maximum absolute difference between corresponding elements.
+    ///
+    /// This and the other metrics are NaN if the shapes do not match.
+    pub max_abs_error: f32,
+
+    /// Maximum of `|actual - expected| / |expected|`, ignoring elements where
+    /// the expected value is zero.
+    pub max_rel_error: f32,
+
+    /// Cosine similarity between the computed and reference values, treating
+    /// each as a flat vector.
+    pub cosine_similarity: f32,
+}
+
+/// Convert the elements of a value to `f32` for comparison.
+fn to_f32_vec(value: &Output) -> Vec<f32> {
+    match value {
+        Output::FloatTensor(t) => t.iter().copied().collect(),
+        Output::IntTensor(t) => t.iter().map(|x| *x as f32).collect(),
+        Output::Int64Tensor(t) => t.iter().map(|x| *x as f32).collect(),
+        Output::BoolTensor(t) => t.iter().map(|x| *x as i32 as f32).collect(),
+        Output::UInt8Tensor(t) => t.iter().map(|x| *x as f32).collect(),
+    }
+}
+
+/// Return the maximum of `a` and `b`, or NaN if either is NaN.
+fn nan_max(a: f32, b: f32) -> f32 {
+    if a.is_nan() || b.is_nan() {
+        f32::NAN
+    } else {
+        a.max(b)
+    }
+}
+
+impl LayerDiff {
+    /// Compare a value computed by a model against a reference value.
+    pub fn new(name: &str, op: &str, actual: &Output, expected: &Output) -> LayerDiff {
+        let mut diff = LayerDiff {
+            name: name.to_string(),
+            op: op.to_string(),
+            shape: actual.shape().to_vec(),
+            reference_shape: expected.shape().to_vec(),
+            max_abs_error: f32::NAN,
+            max_rel_error: f32::NAN,
+            cosine_similarity: f32::NAN,
+        };
+        if diff.shape != diff.reference_shape {
+            return diff;
+        }
+
+        let actual = to_f32_vec(actual);
+        let expected = to_f32_vec(expected);
+
+        let mut max_abs_error = 0.;
+        let mut max_rel_error = 0.;
+        let mut dot = 0f64;
+        let mut actual_norm = 0f64;
+        let mut expected_norm = 0f64;
+        for (&x, &y) in actual.iter().zip(expected.iter()) {
+            let abs_error = (x - y).abs();
+            max_abs_error = nan_max(max_abs_error, abs_error);
+            if y != 0. {
+                max_rel_error = nan_max(max_rel_error, abs_error / y.abs());
+            }
+            dot += x as f64 * y as f64;
+            actual_norm += x as f64 * x as f64;
+            expected_norm += y as f64 * y as f64;
+        }
+
+        diff.max_abs_error = max_abs_error;
+        diff.max_rel_error = max_rel_error;
+        diff.cosine_similarity = if actual_norm == 0. && expected_norm == 0. {
+            1.
+        } else {
+            (dot / (actual_norm.sqrt() * expected_norm.sqrt())) as f32
+        };
+        diff
+    }
+
+    /// Return true if the shapes of the values differ or the maximum absolute
+    /// error is greater than `tolerance`.
+    pub fn exceeds(&self, tolerance: f32) -> bool {
+        self.shape != self.reference_shape
+            || self.max_abs_error.is_nan()
+            || self.max_abs_error > tolerance
+    }
+}
+
+/// Comparisons of the values computed by a model against reference values.
+///
+/// This implements [Display](fmt::Display) to format the results as a table,
+/// marking the first node which exceeds the tolerance.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LayerDiffReport {
+    /// Comparisons for each operator output that has a reference value, in
+    /// execution order.
+    pub layers: Vec<LayerDiff>,
+
+    /// Maximum absolute error allowed before a value is considered to differ
+    /// from the reference.
+    pub tolerance: f32,
+}
+
+impl LayerDiffReport {
+    /// Return the first comparison, in execution order, which exceeds the
+    /// tolerance.
+    pub fn first_exceeding(&self) -> Option<&LayerDiff> {
+        self.layers
+            .iter()
+            .find(|layer| layer.exceeds(self.tolerance))
+    }
+}
+
+impl Table for LayerDiffReport {
+    fn rows(&self) -> usize {
+        self.layers.len()
+    }
+
+    fn headings(&self) -> &[&str] {
+        &[
+            "#",
+            "Node",
+            "Op",
+            "Shape",
+            "Max abs err",
+            "Max rel err",
+            "Cosine sim",
+            "",
+        ]
+    }
+
+    fn cell(&self, row: usize, col: usize) -> String {
+        let layer = self.layers.get(row).expect("invalid row");
+        match col {
+            0 => row.to_string(),
+            1 => layer.name.clone(),
+            2 => layer.op.clone(),
+            3 => format!("{:?}", layer.shape),
+            4 => format!("{:.3e}", layer.max_abs_error),
+            5 => format!("{:.3e}", layer.max_rel_error),
+            6 => format!("{:.6}", layer.cosine_similarity),
+            7 => {
+                let first_exceeding = self
+                    .layers
+                    .iter()
+                    .position(|layer| layer.exceeds(self.tolerance));
+                if layer.shape != layer.reference_shape {
+                    format!("shape mismatch (reference {:?})", layer.reference_shape)
+                } else if first_exceeding == Some(row) {
+                    "<-- first over tolerance".to_string()
+                } else {
+                    String::new()
+                }
+            }
+            _ => panic!("invalid column"),
+        }
+    }
+}
+
+impl fmt::Display for LayerDiffReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(0 /* indent */).fmt(f)
+    }
+}
+
+/// Load reference values from `.npy` files in a directory.
+///
+/// The returned map is keyed by file stem. Files produced using
+/// [RunOptions::dump_dir](crate::RunOptions::dump_dir) are named after the
+/// node, with invalid characters replaced. Files without a `.npy` extension
+/// are ignored.
+pub fn load_reference_dir(dir: &Path) -> io::Result<HashMap<String, Output>> {
+    let mut values = HashMap::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "npy") {
+            let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            let value = read_npy_file(&path).map_err(|err| {
+                io::Error::new(err.kind(), format!("{}: {}", path.display(), err))
+            })?;
+            values.insert(stem.to_string(), value);
+        }
+    }
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use rten_tensor::tensor;
+
+    use super::{load_reference_dir, LayerDiff, LayerDiffReport};
+    use crate::npy::write_npy_file;
+    use crate::ops::Output;
+
+    #[test]
+    fn test_layer_diff() {
+        let expected: Output = tensor!([1., -2., 0., 4.]).into();
+
+        let actual: Output = tensor!([1., -2., 0., 4.]).into();
+        let diff = LayerDiff::new("x", "Relu", &actual, &expected);
+        assert_eq!(diff.max_abs_error, 0.);
+        assert_eq!(diff.max_rel_error, 0.);
+        assert!((diff.cosine_similarity - 1.).abs() < 1e-6);
+        assert!(!diff.exceeds(1e-5));
+
+        // Relative error ignores elements where the expected value is zero.
+        let actual: Output = tensor!([1.5, -2., 0.1, 4.]).into();
+        let diff = LayerDiff::new("x", "Relu", &actual, &expected);
+        assert_eq!(diff.max_abs_error, 0.5);
+        assert_eq!(diff.max_rel_error, 0.5);
+        assert!(diff.cosine_similarity < 1.);
+        assert!(diff.exceeds(0.1));
+        assert!(!diff.exceeds(0.5));
+
+        // NaN values always exceed the tolerance.
+        let actual: Output = tensor!([f32::NAN, -2., 0., 4.]).into();
+        let diff = LayerDiff::new("x", "Relu", &actual, &expected);
+        assert!(diff.max_abs_error.is_nan());
+        assert!(diff.exceeds(1.));
+
+        // Int values are compared as floats.
+        let actual: Output = tensor!([1, -2, 0, 3]).into();
+        let diff = LayerDiff::new("x", "Relu", &actual, &expected);
+        assert_eq!(diff.max_abs_error, 1.);
+
+        // Shape mismatch
+        let actual: Output = tensor!((2, 2); [1., -2., 0., 4.]).into();
+        let diff = LayerDiff::new("x", "Relu", &actual, &expected);
+        assert!(diff.max_abs_error.is_nan());
+        assert!(diff.exceeds(1.));
+    }
+
+    #[test]
+    fn test_layer_diff_report() {
+        let expected: Output = tensor!([1., 2.]).into();
+        let close: Output = tensor!([1., 2.00001]).into();
+        let far: Output = tensor!([1., 3.]).into();
+        let report = LayerDiffReport {
+            layers: vec![
+                LayerDiff::new("a", "Conv", &close, &expected),
+                LayerDiff::new("b", "Relu", &far, &expected),
+                LayerDiff::new("c", "Add", &far, &expected),
+            ],
+            tolerance: 1e-4,
+        };
+        assert_eq!(report.first_exceeding().map(|l| l.name.as_str()), Some("b"));
+
+        let lines: Vec<_> = report.to_string().lines().map(|l| l.to_string()).collect();
+        assert_eq!(lines.len(), 5);
+        assert!(lines[0].starts_with("#  Node  Op    Shape"));
+        assert!(!lines[2].contains("first over tolerance"));
+        assert!(lines[3].contains("<-- first over tolerance"));
+        assert!(!lines[4].contains("first over tolerance"));
+    }
+
+    #[test]
+    fn test_load_reference_dir() {
+        let dir = std::env::temp_dir().join(format!("rten-reference-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let value: Output = tensor!([1., 2.]).into();
+        write_npy_file(&dir.join("conv_out.npy"), &value).unwrap();
+        std::fs::write(dir.join("README.txt"), "not a tensor").unwrap();
+
+        let reference = load_reference_dir(&dir).unwrap();
+        assert_eq!(reference.len(), 1);
+        assert_eq!(reference.get("conv_out"), Some(&value));
+
+        // Invalid `.npy` files are reported with their path.
+        std::fs::write(dir.join("invalid.npy"), "not a tensor").unwrap();
+        let err = load_reference_dir(&dir).err().unwrap();
+        assert!(err.to_string().contains("invalid.npy"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -44,11 +44,16 @@
 #[allow(unused)] // Docs only
 use rten_tensor::{NdTensor, Tensor};
 
+mod batching;
+mod custom_op;
+mod dry_run;
 mod gemm;
 mod graph;
 mod iter_util;
+mod layer_diff;
 mod model;
 mod model_metadata;
+mod npy;
 mod number;
 mod slice_reductions;
 mod timer;
@@ -63,9 +68,17 @@ pub mod ctc;
 
 pub mod ops;
 
-pub use graph::{Dimension, NodeId, RunOptions};
-pub use model::{DefaultOperatorFactory, Model, ModelLoadError, NodeInfo, OpRegistry, ReadOpError};
+pub use batching::{Batcher, BatcherOptions, MicroBatch};
+pub use custom_op::{AttrValue, CustomOpAttrs};
+pub use dry_run::{DryRunReport, DryRunStep, DryRunValue};
+pub use graph::{DimBindings, Dimension, NodeId, RunError, RunOptions, ValueStats};
+pub use layer_diff::{load_reference_dir, LayerDiff, LayerDiffReport};
+pub use model::{
+    CustomOpFunction, DefaultOperatorFactory, Model, ModelLoadError, NodeInfo, OpRegistry,
+    ReadOpError, ReadOpResult,
+};
 pub use model_metadata::ModelMetadata;
+pub use npy::npy_file_stem;
 pub use ops::{FloatOperators, Input, Operators, Output};
 pub use timer::Timer;
 pub use timing::TimingSort;
@@ -6,14 +6,18 @@ use std::error::Error;
 use std::fmt::{Display, Formatter};
 
 use rten_tensor::Tensor;
-use smallvec::smallvec;
+use smallvec::{smallvec, SmallVec};
 
-use crate::graph::{Dimension, Graph, Node, NodeId, RunError, RunOptions};
+use crate::custom_op::{AttrValue, CustomOpAttrs};
+use crate::dry_run::DryRunReport;
+use crate::graph::{DimBindings, Dimension, Graph, Node, NodeId, RunError, RunOptions, StepInfo};
+use crate::layer_diff::{LayerDiff, LayerDiffReport};
 use crate::model_metadata::ModelMetadata;
+use crate::number::{bf16_to_f32, f16_to_f32};
 use crate::ops;
 use crate::ops::{
     BoxOrder, CoordTransformMode, DataType, Direction, Input, NearestMode, Operator, Output,
-    Padding, ResizeMode, Scalar, ScatterReduction,
+    Padding, ResizeMode, RnnActivation, Scalar, ScatterReduction,
 };
 use crate::schema_generated as sg;
 use crate::schema_generated::{root_as_model, OperatorNode, OperatorType, PadMode};
@@ -73,6 +77,11 @@ impl<'a> NodeInfo<'a> {
     pub fn shape(&self) -> Option<Vec<Dimension>> {
         self.node.shape()
     }
+
+    /// Return the element type associated with a node, if known.
+    pub fn dtype(&self) -> Option<DataType> {
+        self.node.dtype()
+    }
 }
 
 /// Parse profiling flags from the `RTEN_TIMING` environment variable and
@@ -216,24 +225,22 @@ impl Model {
                             })
                             .collect()
                     });
-                    let graph_node = graph.add_value(node.name(), shape);
+                    let dtype = value_node.dtype().and_then(read_data_type);
+                    let graph_node = graph.add_value_with_dtype(node.name(), shape, dtype);
 
                     add_node_id(node.name(), graph_node);
                     node_id_from_index.insert(node_index, graph_node);
                 } else if let Some(constant) = node.data_as_constant_node() {
-                    let shape: Vec<usize> = constant.shape().iter().map(|x| x as usize).collect();
-                    let graph_node = if let Some(float_data) = constant.data_as_float_data() {
-                        let data: Vec<f32> = vec_from_flatbuffers_vec(float_data.data());
-                        let tensor = Tensor::from_data(&shape, data);
-                        graph.add_constant(node.name(), tensor)
-                    } else if let Some(int_data) = constant.data_as_int_data() {
-                        let data: Vec<i32> = vec_from_flatbuffers_vec(int_data.data());
-                        let tensor = Tensor::from_data(&shape, data);
-                        graph.add_constant(node.name(), tensor)
-                    } else {
-                        return Err(ModelLoadError::GraphError(
-                            "unsupported constant data type".to_string(),
-                        ));
+                    let graph_node = match read_constant(&constant) {
+                        Some(Output::FloatTensor(tensor)) => {
+                            graph.add_constant(node.name(), tensor)
+                        }
+                        Some(Output::IntTensor(tensor)) => graph.add_constant(node.name(), tensor),
+                        _ => {
+                            return Err(ModelLoadError::GraphError(
+                                "unsupported constant data type".to_string(),
+                            ));
+                        }
                     };
 
                     add_node_id(node.name(), graph_node);
@@ -280,6 +287,11 @@ impl Model {
         self.graph.get_node(id).map(|node| NodeInfo { node })
     }
 
+    /// Return the debug name for a node.
+    pub(crate) fn node_name(&self, id: NodeId) -> String {
+        self.graph.node_name(id)
+    }
+
     /// Return metadata about the model.
     pub fn metadata(&self) -> &ModelMetadata {
         &self.metadata
@@ -309,6 +321,16 @@ impl Model {
         node_info.shape()
     }
 
+    /// Check the shapes of `inputs` against the shapes declared by the model,
+    /// and return the sizes bound to symbolic dimensions (eg. "batch").
+    ///
+    /// The same checks are performed by [Model::run], so this is only needed
+    /// to access the bound sizes, or to validate inputs without running the
+    /// model.
+    pub fn bind_input_shapes(&self, inputs: &[(NodeId, Input)]) -> Result<DimBindings, RunError> {
+        self.graph.bind_input_shapes(inputs)
+    }
+
     /// Execute the model and return the outputs specified by `outputs`.
     ///
     /// This method allows for running a model with a variable number of inputs
@@ -317,6 +339,9 @@ impl Model {
     /// known number of inputs and outputs.
     ///
     /// The input and output nodes are specified via IDs looked up via `find_node`.
+    ///
+    /// Large batches can be split into smaller batches that are run
+    /// separately, to limit memory usage. See [RunOptions::micro_batch].
     pub fn run(
         &self,
         inputs: &[(NodeId, Input)],
@@ -331,6 +356,62 @@ impl Model {
         self.graph.run(inputs, outputs, Some(opts))
     }
 
+    /// Run a model and compare the value of each operator output against a
+    /// reference value, such as one computed by ONNX Runtime.
+    ///
+    /// `reference` is a map of node name to value. Values are looked up by
+    /// node name, and then by the `.npy` file stem used by
+    /// [RunOptions::dump_dir], so that the result of
+    /// [load_reference_dir](crate::load_reference_dir) can be used directly.
+    /// Operator outputs without a reference value are skipped.
+    ///
+    /// `tolerance` is the maximum absolute error allowed before a value is
+    /// considered to differ. See [LayerDiffReport::first_exceeding].
+    pub fn compare_layers(
+        &self,
+        inputs: &[(NodeId, Input)],
+        reference: &HashMap<String, Output>,
+        tolerance: f32,
+        opts: Option<RunOptions>,
+    ) -> Result<LayerDiffReport, RunError> {
+        let mut layers = Vec::new();
+        let stems = self.graph.npy_file_stems();
+        let mut compare = |step: &StepInfo| {
+            for (id, value) in step.outputs {
+                let name = self.graph.node_name(*id);
+                let expected = reference.get(&name).or_else(|| reference.get(&stems[id]));
+                if let Some(expected) = expected {
+                    layers.push(LayerDiff::new(&name, step.op, value, expected));
+                }
+            }
+        };
+        self.graph
+            .run_with_observer(inputs, self.output_ids(), opts, Some(&mut compare))?;
+        Ok(LayerDiffReport { layers, tolerance })
+    }
+
+    /// Estimate the total number of arithmetic operations needed to run the
+    /// model with inputs of the given shapes.
+    ///
+    /// This uses a shape-only [dry run](Model::dry_run) and sums the
+    /// estimates from [Operator::flops](crate::ops::Operator::flops), so no
+    /// operators are actually run. Operators which do not provide an estimate
+    /// are not counted.
+    pub fn total_flops(&self, input_shapes: &[(NodeId, &[usize])]) -> Result<u64, RunError> {
+        Ok(self.dry_run(input_shapes)?.total_flops())
+    }
+
+    /// Compute the shapes of all intermediate values and estimate the memory
+    /// needed to run the model with inputs of the given shapes, without
+    /// running the model.
+    ///
+    /// This is much cheaper than an actual run, so it can be used to plan
+    /// the capacity needed for large inputs (eg. a large batch size). See
+    /// [DryRunReport] for details of the memory estimates.
+    pub fn dry_run(&self, input_shapes: &[(NodeId, &[usize])]) -> Result<DryRunReport, RunError> {
+        self.graph.dry_run(input_shapes, self.output_ids())
+    }
+
     /// Run a model and retrieve `N` outputs.
     ///
     /// This is a simplified version of [Model::run] for the common case of
@@ -365,25 +446,15 @@ fn padding_from_attrs(mode: PadMode, pads: Option<flatbuffers::Vector<'_, u32>>)
     }
 }
 
-/// Read the first N items from `iter` into an array.
-///
-/// Panics if the iterator yields fewer than N items.
-fn array_from_iter<const N: usize, T: Default + Copy, I: Iterator<Item = T>>(
-    mut iter: I,
-) -> [T; N] {
-    let mut result = [T::default(); N];
-    for i in 0..N {
-        result[i] = iter.next().expect("incorrect array size");
-    }
-    result
-}
-
 /// Result of deserializing an operator node from a model file.
 pub type ReadOpResult = Result<Box<dyn Operator + Send + Sync>, ReadOpError>;
 
 /// A function that deserializes an operator node.
 pub type ReadOpFunction = dyn Fn(&OperatorNode) -> ReadOpResult;
 
+/// A function that creates a custom operator from its attributes.
+pub type CustomOpFunction = dyn Fn(&CustomOpAttrs) -> ReadOpResult;
+
 /// Trait that that creates the default/built-in implementation of an operator,
 /// for use with [OpRegistry::register_op].
 ///
@@ -432,10 +503,12 @@ impl_default_factory!(ArgMax, read_arg_max_op);
 impl_default_factory!(ArgMin, read_arg_min_op);
 impl_default_factory!(Asin);
 impl_default_factory!(Atan);
+impl_default_factory!(Attention, read_attention_op);
 impl_default_factory!(AveragePool, read_average_pool_op);
 impl_default_factory!(BatchNormalization, read_batch_normalization_op);
 impl_default_factory!(Cast, read_cast_op);
 impl_default_factory!(Ceil);
+impl_default_factory!(Celu, read_celu_op);
 impl_default_factory!(Clip);
 impl_default_factory!(Concat, read_concat_op);
 impl_default_factory!(Conv, read_conv_op);
@@ -444,6 +517,7 @@ impl_default_factory!(ConvTranspose, read_conv_transpose_op);
 impl_default_factory!(Cos);
 impl_default_factory!(CumSum);
 impl_default_factory!(Div);
+impl_default_factory!(Elu, read_elu_op);
 impl_default_factory!(Equal);
 impl_default_factory!(Erf);
 impl_default_factory!(Exp);
@@ -452,10 +526,14 @@ impl_default_factory!(Flatten, read_flatten_op);
 impl_default_factory!(Floor);
 impl_default_factory!(Gather, read_gather_op);
 impl_default_factory!(GatherElements, read_gather_elements_op);
+impl_default_factory!(Gelu, read_gelu_op);
 impl_default_factory!(Gemm, read_gemm_op);
 impl_default_factory!(GlobalAveragePool);
+impl_default_factory!(GlobalLpPool, read_global_lp_pool_op);
+impl_default_factory!(GlobalMaxPool);
 impl_default_factory!(Greater);
 impl_default_factory!(GreaterOrEqual);
+impl_default_factory!(GroupNormalization, read_group_normalization_op);
 impl_default_factory!(GRU, read_gru_op);
 impl_default_factory!(HardSigmoid, read_hard_sigmoid_op);
 impl_default_factory!(HardSwish);
@@ -467,12 +545,14 @@ impl_default_factory!(Less);
 impl_default_factory!(LessOrEqual);
 impl_default_factory!(Log);
 impl_default_factory!(LogSoftmax, read_log_softmax_op);
+impl_default_factory!(LpPool, read_lp_pool_op);
 impl_default_factory!(LSTM, read_lstm_op);
 impl_default_factory!(MatMul);
 impl_default_factory!(Max);
 impl_default_factory!(MaxPool, read_max_pool_op);
 impl_default_factory!(Mean);
 impl_default_factory!(Min);
+impl_default_factory!(Mish);
 impl_default_factory!(Mod, read_mod_op);
 impl_default_factory!(Mul);
 impl_default_factory!(Neg);
@@ -481,8 +561,9 @@ impl_default_factory!(NonZero);
 impl_default_factory!(Not);
 impl_default_factory!(OneHot, read_onehot_op);
 impl_default_factory!(Or);
-impl_default_factory!(Pad);
+impl_default_factory!(Pad, read_pad_op);
 impl_default_factory!(Pow);
+impl_default_factory!(PRelu);
 
 #[cfg(feature = "random")]
 impl_default_factory!(RandomUniform, read_random_uniform_op);
@@ -499,9 +580,12 @@ impl_default_factory!(ReduceSumSquare, read_reduce_sum_square_op);
 impl_default_factory!(Relu);
 impl_default_factory!(Reshape, read_reshape_op);
 impl_default_factory!(Resize, read_resize_op);
+impl_default_factory!(RMSNormalization, read_rms_normalization_op);
+impl_default_factory!(RNN, read_rnn_op);
 impl_default_factory!(Round);
 impl_default_factory!(ScatterElements, read_scatter_elements_op);
 impl_default_factory!(ScatterND, read_scatter_nd_op);
+impl_default_factory!(Selu, read_selu_op);
 impl_default_factory!(Shape);
 impl_default_factory!(Sigmoid);
 impl_default_factory!(Sign);
@@ -509,6 +593,8 @@ impl_default_factory!(Sin);
 impl_default_factory!(Size);
 impl_default_factory!(Slice);
 impl_default_factory!(Softmax, read_softmax_op);
+impl_default_factory!(Softplus);
+impl_default_factory!(Softsign);
 impl_default_factory!(Split, read_split_op);
 impl_default_factory!(Sqrt);
 impl_default_factory!(Squeeze);
@@ -516,6 +602,7 @@ impl_default_factory!(Sub);
 impl_default_factory!(Sum);
 impl_default_factory!(Tan);
 impl_default_factory!(Tanh);
+impl_default_factory!(ThresholdedRelu, read_thresholded_relu_op);
 impl_default_factory!(Tile);
 impl_default_factory!(TopK, read_topk_op);
 impl_default_factory!(Transpose, read_transpose_op);
@@ -532,9 +619,16 @@ impl_default_factory!(Xor);
 /// just selectively register the operators you need using
 /// [OpRegistry::register_op]. This can be useful to reduce binary sizes or
 /// customize the implementation of an operator.
+///
+/// Models can also contain custom operators which are identified by a
+/// domain and type name instead of a built-in operator type. Implementations
+/// for these are registered using [OpRegistry::register_custom_op].
 #[derive(Default)]
 pub struct OpRegistry {
     ops: HashMap<sg::OperatorType, Box<ReadOpFunction>>,
+
+    /// Map of `(domain, op_type)` to custom operator factory.
+    custom_ops: HashMap<(String, String), Box<CustomOpFunction>>,
 }
 
 impl OpRegistry {
@@ -542,6 +636,7 @@ impl OpRegistry {
     pub fn new() -> OpRegistry {
         OpRegistry {
             ops: HashMap::new(),
+            custom_ops: HashMap::new(),
         }
     }
 
@@ -550,9 +645,28 @@ impl OpRegistry {
         self.register_op_with_factory(Op::op_type(), Op::factory());
     }
 
+    /// Register a factory for a custom operator with a given domain and type
+    /// name.
+    ///
+    /// When a model containing a custom operator node with a matching domain
+    /// and type is loaded, `factory` is called with the node's attributes to
+    /// create the operator. Registering a factory for a name that is already
+    /// registered replaces the previous factory.
+    pub fn register_custom_op<F>(&mut self, domain: &str, op_type: &str, factory: F)
+    where
+        F: Fn(&CustomOpAttrs) -> ReadOpResult + 'static,
+    {
+        self.custom_ops
+            .insert((domain.to_string(), op_type.to_string()), Box::new(factory));
+    }
+
     /// Deserialize an operator from a model file using the operators in the
     /// registry.
     fn read_op(&self, op: &OperatorNode) -> ReadOpResult {
+        if op.type_() == OperatorType::Custom {
+            return self.read_custom_op(op);
+        }
+
         self.ops
             .get(&op.type_())
             .ok_or_else(|| {
@@ -563,6 +677,23 @@ impl OpRegistry {
             .and_then(|read_fn| read_fn(op))
     }
 
+    /// Deserialize a custom operator using the custom operator factories in
+    /// the registry.
+    fn read_custom_op(&self, op: &OperatorNode) -> ReadOpResult {
+        let attrs = op.attrs_as_custom_attrs().ok_or(ReadOpError::AttrError)?;
+        let attrs = read_custom_op_attrs(attrs)?;
+        let key = (attrs.domain.clone(), attrs.op_type.clone());
+        let factory = self.custom_ops.get(&key).ok_or_else(|| {
+            let name = if attrs.domain.is_empty() {
+                attrs.op_type.clone()
+            } else {
+                format!("{}.{}", attrs.domain, attrs.op_type)
+            };
+            ReadOpError::UnsupportedOperator(name)
+        })?;
+        factory(&attrs)
+    }
+
     /// Register an operator with a custom factory to deserialize it from a
     /// model file.
     fn register_op_with_factory(
@@ -591,10 +722,12 @@ impl OpRegistry {
         register_op!(ArgMin);
         register_op!(Asin);
         register_op!(Atan);
+        register_op!(Attention);
         register_op!(AveragePool);
         register_op!(BatchNormalization);
         register_op!(Cast);
         register_op!(Ceil);
+        register_op!(Celu);
         register_op!(Clip);
         register_op!(Concat);
         register_op!(Conv);
@@ -603,6 +736,7 @@ impl OpRegistry {
         register_op!(Cos);
         register_op!(CumSum);
         register_op!(Div);
+        register_op!(Elu);
         register_op!(Equal);
         register_op!(Erf);
         register_op!(Exp);
@@ -611,10 +745,14 @@ impl OpRegistry {
         register_op!(Floor);
         register_op!(Gather);
         register_op!(GatherElements);
+        register_op!(Gelu);
         register_op!(Gemm);
         register_op!(GlobalAveragePool);
+        register_op!(GlobalLpPool);
+        register_op!(GlobalMaxPool);
         register_op!(Greater);
         register_op!(GreaterOrEqual);
+        register_op!(GroupNormalization);
         register_op!(GRU);
         register_op!(HardSigmoid);
         register_op!(HardSwish);
@@ -626,12 +764,14 @@ impl OpRegistry {
         register_op!(LessOrEqual);
         register_op!(Log);
         register_op!(LogSoftmax);
+        register_op!(LpPool);
         register_op!(LSTM);
         register_op!(MatMul);
         register_op!(Max);
         register_op!(MaxPool);
         register_op!(Mean);
         register_op!(Min);
+        register_op!(Mish);
         register_op!(Mod);
         register_op!(Mul);
         register_op!(Neg);
@@ -642,6 +782,7 @@ impl OpRegistry {
         register_op!(Or);
         register_op!(Pad);
         register_op!(Pow);
+        register_op!(PRelu);
 
         #[cfg(feature = "random")]
         register_op!(RandomUniform);
@@ -658,9 +799,12 @@ impl OpRegistry {
         register_op!(Relu);
         register_op!(Reshape);
         register_op!(Resize);
+        register_op!(RMSNormalization);
+        register_op!(RNN);
         register_op!(Round);
         register_op!(ScatterElements);
         register_op!(ScatterND);
+        register_op!(Selu);
         register_op!(Shape);
         register_op!(Sigmoid);
         register_op!(Sign);
@@ -668,6 +812,8 @@ impl OpRegistry {
         register_op!(Size);
         register_op!(Slice);
         register_op!(Softmax);
+        register_op!(Softplus);
+        register_op!(Softsign);
         register_op!(Split);
         register_op!(Sqrt);
         register_op!(Squeeze);
@@ -675,6 +821,7 @@ impl OpRegistry {
         register_op!(Sum);
         register_op!(Tan);
         register_op!(Tanh);
+        register_op!(ThresholdedRelu);
         register_op!(Tile);
         register_op!(TopK);
         register_op!(Transpose);
@@ -741,23 +888,42 @@ fn read_arg_min_op(node: &OperatorNode) -> ReadOpResult {
     }))
 }
 
+fn read_attention_op(node: &OperatorNode) -> ReadOpResult {
+    let attrs = node
+        .attrs_as_attention_attrs()
+        .ok_or(ReadOpError::AttrError)?;
+    let num_heads = |n: u32| if n > 0 { Some(n as usize) } else { None };
+    Ok(Box::new(ops::Attention {
+        num_heads: num_heads(attrs.num_heads()),
+        kv_num_heads: num_heads(attrs.kv_num_heads()),
+        is_causal: attrs.is_causal(),
+        scale: attrs.scale(),
+    }))
+}
+
 fn read_average_pool_op(node: &OperatorNode) -> ReadOpResult {
     let attrs = node
         .attrs_as_average_pool_attrs()
         .ok_or(ReadOpError::AttrError)?;
 
-    let kernel_size = array_from_iter(attrs.kernel_size().iter().map(|x| x as usize));
+    let kernel_size: Vec<usize> = attrs.kernel_size().iter().map(|x| x as usize).collect();
     let padding = padding_from_attrs(attrs.pad_mode(), attrs.pads());
-    let strides = attrs
+    let strides: Vec<usize> = attrs
         .strides()
-        .map(|stride| array_from_iter(stride.iter().map(|x| x as usize)))
-        .unwrap_or([1, 1]);
+        .map(|stride| stride.iter().map(|x| x as usize).collect())
+        .unwrap_or(vec![1; kernel_size.len()]);
+    let dilations: Vec<usize> = attrs
+        .dilations()
+        .map(|dilation| dilation.iter().map(|x| x as usize).collect())
+        .unwrap_or(vec![1; kernel_size.len()]);
 
     Ok(Box::new(ops::AveragePool {
         kernel_size,
         padding,
         count_include_pad: attrs.count_include_pad(),
         strides,
+        dilations,
+        ceil_mode: attrs.ceil_mode(),
     }))
 }
 
@@ -770,18 +936,36 @@ fn read_batch_normalization_op(node: &OperatorNode) -> ReadOpResult {
     }))
 }
 
+/// Convert a data type from the model file. Returns `None` if the type is
+/// not recognized.
+fn read_data_type(dtype: sg::DataType) -> Option<DataType> {
+    match dtype {
+        sg::DataType::Int32 => Some(DataType::Int32),
+        sg::DataType::Float => Some(DataType::Float),
+        sg::DataType::Int64 => Some(DataType::Int64),
+        sg::DataType::Bool => Some(DataType::Bool),
+        sg::DataType::UInt8 => Some(DataType::UInt8),
+        _ => None,
+    }
+}
+
 fn read_cast_op(node: &OperatorNode) -> ReadOpResult {
     let attrs = node.attrs_as_cast_attrs().ok_or(ReadOpError::AttrError)?;
-    let to = match attrs.to() {
-        sg::DataType::Int32 => DataType::Int32,
-        sg::DataType::Float => DataType::Float,
-        _ => DataType::Float,
-    };
+    let to = read_data_type(attrs.to()).unwrap_or(DataType::Float);
     Ok(Box::new(ops::Cast { to }))
 }
 
 read_axis_op!(read_concat_op, attrs_as_concat_attrs, Concat);
 
+fn read_celu_op(node: &OperatorNode) -> ReadOpResult {
+    let attrs = node
+        .attrs_as_leaky_relu_attrs()
+        .ok_or(ReadOpError::AttrError)?;
+    Ok(Box::new(ops::Celu {
+        alpha: attrs.alpha(),
+    }))
+}
+
 fn read_conv_op(node: &OperatorNode) -> ReadOpResult {
     let attrs = node.attrs_as_conv_attrs().ok_or(ReadOpError::AttrError)?;
 
@@ -822,11 +1006,36 @@ fn read_conv_transpose_op(node: &OperatorNode) -> ReadOpResult {
     let attrs = node
         .attrs_as_conv_transpose_attrs()
         .ok_or(ReadOpError::AttrError)?;
-    let strides = attrs
+    // Attributes which are not specified are left empty, and default to
+    // values with one entry per spatial dim of the kernel when the operator
+    // is run.
+    let strides: Vec<usize> = attrs
         .strides()
-        .map(|stride| array_from_iter(stride.iter().map(|x| x as usize)))
-        .unwrap_or([1, 1]);
-    Ok(Box::new(ops::ConvTranspose { strides }))
+        .map(|stride| stride.iter().map(|x| x as usize).collect())
+        .unwrap_or_default();
+    let padding = match (attrs.pad_mode(), attrs.pads()) {
+        (PadMode::Fixed, None) => Padding::Fixed(SmallVec::new()),
+        (pad_mode, pads) => padding_from_attrs(pad_mode, pads),
+    };
+    let dilations: Vec<usize> = attrs
+        .dilations()
+        .map(|dilation| dilation.iter().map(|x| x as usize).collect())
+        .unwrap_or_default();
+    let output_padding = attrs
+        .output_padding()
+        .map(|pads| pads.iter().map(|x| x as usize).collect());
+    let output_shape = attrs
+        .output_shape()
+        .map(|shape| shape.iter().map(|x| x as usize).collect());
+
+    Ok(Box::new(ops::ConvTranspose {
+        padding,
+        groups: attrs.groups() as usize,
+        strides,
+        dilations,
+        output_padding,
+        output_shape,
+    }))
 }
 
 read_axis_op!(read_flatten_op, attrs_as_flatten_attrs, Flatten);
@@ -837,6 +1046,22 @@ read_axis_op!(
     GatherElements
 );
 
+fn read_elu_op(node: &OperatorNode) -> ReadOpResult {
+    let attrs = node
+        .attrs_as_leaky_relu_attrs()
+        .ok_or(ReadOpError::AttrError)?;
+    Ok(Box::new(ops::Elu {
+        alpha: attrs.alpha(),
+    }))
+}
+
+fn read_gelu_op(node: &OperatorNode) -> ReadOpResult {
+    let attrs = node.attrs_as_gelu_attrs().ok_or(ReadOpError::AttrError)?;
+    Ok(Box::new(ops::Gelu {
+        approximate: attrs.approximate(),
+    }))
+}
+
 fn read_gemm_op(node: &OperatorNode) -> ReadOpResult {
     let attrs = node.attrs_as_gemm_attrs().ok_or(ReadOpError::AttrError)?;
     Ok(Box::new(ops::Gemm {
@@ -847,6 +1072,23 @@ fn read_gemm_op(node: &OperatorNode) -> ReadOpResult {
     }))
 }
 
+fn read_global_lp_pool_op(node: &OperatorNode) -> ReadOpResult {
+    let attrs = node
+        .attrs_as_global_lp_pool_attrs()
+        .ok_or(ReadOpError::AttrError)?;
+    Ok(Box::new(ops::GlobalLpPool { p: attrs.p() }))
+}
+
+fn read_group_normalization_op(node: &OperatorNode) -> ReadOpResult {
+    let attrs = node
+        .attrs_as_group_normalization_attrs()
+        .ok_or(ReadOpError::AttrError)?;
+    Ok(Box::new(ops::GroupNormalization {
+        num_groups: attrs.num_groups() as usize,
+        epsilon: Some(attrs.epsilon()),
+    }))
+}
+
 fn read_gru_op(node: &OperatorNode) -> ReadOpResult {
     let attrs = node.attrs_as_gruattrs().ok_or(ReadOpError::AttrError)?;
 
@@ -862,6 +1104,7 @@ fn read_gru_op(node: &OperatorNode) -> ReadOpResult {
         direction,
         hidden_size,
         linear_before_reset: attrs.linear_before_reset(),
+        clip: read_rnn_clip(attrs.clip()),
     }))
 }
 
@@ -919,6 +1162,81 @@ fn read_lstm_op(node: &OperatorNode) -> ReadOpResult {
     Ok(Box::new(ops::LSTM {
         direction,
         hidden_size,
+        clip: read_rnn_clip(attrs.clip()),
+    }))
+}
+
+/// Convert the `clip` attribute of an RNN operator, where zero means no
+/// clipping.
+fn read_rnn_clip(clip: f32) -> Option<f32> {
+    (clip != 0.).then_some(clip)
+}
+
+fn read_rms_normalization_op(node: &OperatorNode) -> ReadOpResult {
+    let attrs = node
+        .attrs_as_layer_normalization_attrs()
+        .ok_or(ReadOpError::AttrError)?;
+    Ok(Box::new(ops::RMSNormalization {
+        axis: attrs.axis() as isize,
+        epsilon: Some(attrs.epsilon()),
+    }))
+}
+
+fn read_rnn_op(node: &OperatorNode) -> ReadOpResult {
+    let attrs = node.attrs_as_rnnattrs().ok_or(ReadOpError::AttrError)?;
+
+    let hidden_size = attrs.hidden_size() as usize;
+    let direction = match attrs.direction() {
+        sg::RNNDirection::Forward => Direction::Forward,
+        sg::RNNDirection::Reverse => Direction::Reverse,
+        sg::RNNDirection::Bidirectional => Direction::Bidirectional,
+        _ => Direction::Forward,
+    };
+
+    // Alpha and beta values are consumed in order by the activations that
+    // use them. Missing values use the ONNX defaults.
+    let mut alphas = attrs.activation_alpha().into_iter().flatten();
+    let mut betas = attrs.activation_beta().into_iter().flatten();
+    let activations = attrs
+        .activations()
+        .into_iter()
+        .flatten()
+        .map(|act| {
+            let mut alpha = |default| alphas.next().unwrap_or(default);
+            let act = match act {
+                sg::RNNActivation::Relu => RnnActivation::Relu,
+                sg::RNNActivation::Tanh => RnnActivation::Tanh,
+                sg::RNNActivation::Sigmoid => RnnActivation::Sigmoid,
+                sg::RNNActivation::Affine => RnnActivation::Affine {
+                    alpha: alpha(1.),
+                    beta: betas.next().unwrap_or(0.),
+                },
+                sg::RNNActivation::LeakyRelu => RnnActivation::LeakyRelu { alpha: alpha(0.01) },
+                sg::RNNActivation::ThresholdedRelu => {
+                    RnnActivation::ThresholdedRelu { alpha: alpha(1.) }
+                }
+                sg::RNNActivation::ScaledTanh => RnnActivation::ScaledTanh {
+                    alpha: alpha(1.),
+                    beta: betas.next().unwrap_or(1.),
+                },
+                sg::RNNActivation::HardSigmoid => RnnActivation::HardSigmoid {
+                    alpha: alpha(0.2),
+                    beta: betas.next().unwrap_or(0.5),
+                },
+                sg::RNNActivation::Elu => RnnActivation::Elu { alpha: alpha(1.) },
+                sg::RNNActivation::Softsign => RnnActivation::Softsign,
+                sg::RNNActivation::Softplus => RnnActivation::Softplus,
+                _ => return Err(ReadOpError::AttrError),
+            };
+            Ok(act)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Box::new(ops::RNN {
+        direction,
+        hidden_size,
+        activations,
+        clip: read_rnn_clip(attrs.clip()),
     }))
 }
 
@@ -927,17 +1245,54 @@ fn read_max_pool_op(node: &OperatorNode) -> ReadOpResult {
         .attrs_as_max_pool_attrs()
         .ok_or(ReadOpError::AttrError)?;
 
-    let kernel_size = array_from_iter(attrs.kernel_size().iter().map(|x| x as usize));
+    let kernel_size: Vec<usize> = attrs.kernel_size().iter().map(|x| x as usize).collect();
     let padding = padding_from_attrs(attrs.pad_mode(), attrs.pads());
-    let strides = attrs
+    let strides: Vec<usize> = attrs
         .strides()
-        .map(|stride| array_from_iter(stride.iter().map(|x| x as usize)))
-        .unwrap_or([1, 1]);
+        .map(|stride| stride.iter().map(|x| x as usize).collect())
+        .unwrap_or(vec![1; kernel_size.len()]);
+    let dilations: Vec<usize> = attrs
+        .dilations()
+        .map(|dilation| dilation.iter().map(|x| x as usize).collect())
+        .unwrap_or(vec![1; kernel_size.len()]);
+
+    // The optional "Indices" output is computed only if the node has a second
+    // output.
+    let return_indices = node.outputs().is_some_and(|outputs| outputs.len() > 1);
 
     Ok(Box::new(ops::MaxPool {
         kernel_size,
         padding,
         strides,
+        dilations,
+        ceil_mode: attrs.ceil_mode(),
+        return_indices,
+    }))
+}
+
+fn read_lp_pool_op(node: &OperatorNode) -> ReadOpResult {
+    let attrs = node
+        .attrs_as_lp_pool_attrs()
+        .ok_or(ReadOpError::AttrError)?;
+
+    let kernel_size: Vec<usize> = attrs.kernel_size().iter().map(|x| x as usize).collect();
+    let padding = padding_from_attrs(attrs.pad_mode(), attrs.pads());
+    let strides: Vec<usize> = attrs
+        .strides()
+        .map(|stride| stride.iter().map(|x| x as usize).collect())
+        .unwrap_or(vec![1; kernel_size.len()]);
+    let dilations: Vec<usize> = attrs
+        .dilations()
+        .map(|dilation| dilation.iter().map(|x| x as usize).collect())
+        .unwrap_or(vec![1; kernel_size.len()]);
+
+    Ok(Box::new(ops::LpPool {
+        kernel_size,
+        padding,
+        strides,
+        dilations,
+        ceil_mode: attrs.ceil_mode(),
+        p: attrs.p(),
     }))
 }
 
@@ -946,6 +1301,18 @@ fn read_mod_op(node: &OperatorNode) -> ReadOpResult {
     Ok(Box::new(ops::Mod { fmod: attrs.fmod() }))
 }
 
+fn read_pad_op(node: &OperatorNode) -> ReadOpResult {
+    // Models created before the `mode` attribute was added have no attributes
+    // and use constant padding.
+    let mode = match node.attrs_as_pad_attrs().map(|attrs| attrs.mode()) {
+        Some(sg::PadOpMode::Reflect) => ops::PadMode::Reflect,
+        Some(sg::PadOpMode::Edge) => ops::PadMode::Edge,
+        Some(sg::PadOpMode::Wrap) => ops::PadMode::Wrap,
+        _ => ops::PadMode::Constant,
+    };
+    Ok(Box::new(ops::Pad { mode }))
+}
+
 fn read_non_max_suppression_op(node: &OperatorNode) -> ReadOpResult {
     let attrs = node
         .attrs_as_non_max_suppression_attrs()
@@ -1018,6 +1385,7 @@ fn read_resize_op(node: &OperatorNode) -> ReadOpResult {
     let mode = match attrs.mode() {
         sg::ResizeMode::Nearest => ResizeMode::Nearest,
         sg::ResizeMode::Linear => ResizeMode::Linear,
+        sg::ResizeMode::Cubic => ResizeMode::Cubic,
         _ => ResizeMode::Nearest,
     };
     let nearest_mode = match attrs.nearest_mode() {
@@ -1032,6 +1400,8 @@ fn read_resize_op(node: &OperatorNode) -> ReadOpResult {
         sg::CoordTransformMode::Asymmetric => CoordTransformMode::Asymmetric,
         sg::CoordTransformMode::HalfPixel => CoordTransformMode::HalfPixel,
         sg::CoordTransformMode::AlignCorners => CoordTransformMode::AlignCorners,
+        sg::CoordTransformMode::PytorchHalfPixel => CoordTransformMode::PytorchHalfPixel,
+        sg::CoordTransformMode::TfCropAndResize => CoordTransformMode::TfCropAndResize,
         _ => CoordTransformMode::default(),
     };
 
@@ -1039,6 +1409,11 @@ fn read_resize_op(node: &OperatorNode) -> ReadOpResult {
         mode,
         coord_mode,
         nearest_mode,
+        cubic_coeff_a: attrs.cubic_coeff_a(),
+        antialias: attrs.antialias(),
+        exclude_outside: attrs.exclude_outside(),
+        extrapolation_value: attrs.extrapolation_value(),
+        axes: attrs.axes().map(|axes| axes.iter().collect()),
     }))
 }
 
@@ -1077,9 +1452,26 @@ fn read_scatter_nd_op(node: &OperatorNode) -> ReadOpResult {
     }))
 }
 
+fn read_selu_op(node: &OperatorNode) -> ReadOpResult {
+    let attrs = node.attrs_as_selu_attrs().ok_or(ReadOpError::AttrError)?;
+    Ok(Box::new(ops::Selu {
+        alpha: attrs.alpha(),
+        gamma: attrs.gamma(),
+    }))
+}
+
 read_axis_op!(read_softmax_op, attrs_as_softmax_attrs, Softmax);
 read_axis_op!(read_split_op, attrs_as_split_attrs, Split);
 
+fn read_thresholded_relu_op(node: &OperatorNode) -> ReadOpResult {
+    let attrs = node
+        .attrs_as_leaky_relu_attrs()
+        .ok_or(ReadOpError::AttrError)?;
+    Ok(Box::new(ops::ThresholdedRelu {
+        alpha: attrs.alpha(),
+    }))
+}
+
 fn read_topk_op(node: &OperatorNode) -> ReadOpResult {
     let attrs = node.attrs_as_top_kattrs().ok_or(ReadOpError::AttrError)?;
     let largest = attrs.largest();
@@ -1138,6 +1530,61 @@ impl Display for ModelLoadError {
 
 impl Error for ModelLoadError {}
 
+/// Read the data from a constant node in a model file into a tensor.
+///
+/// Returns `None` if the constant uses an unsupported data type.
+fn read_constant(constant: &sg::ConstantNode) -> Option<Output> {
+    let shape: Vec<usize> = constant.shape().iter().map(|x| x as usize).collect();
+    let output = if let Some(float_data) = constant.data_as_float_data() {
+        let data: Vec<f32> = vec_from_flatbuffers_vec(float_data.data());
+        Tensor::from_data(&shape, data).into()
+    } else if let Some(int_data) = constant.data_as_int_data() {
+        let data: Vec<i32> = vec_from_flatbuffers_vec(int_data.data());
+        Tensor::from_data(&shape, data).into()
+    } else if let Some(f16_data) = constant.data_as_float_16_data() {
+        let data: Vec<f32> = f16_data.data().iter().map(f16_to_f32).collect();
+        Tensor::from_data(&shape, data).into()
+    } else if let Some(bf16_data) = constant.data_as_bfloat_16_data() {
+        let data: Vec<f32> = bf16_data.data().iter().map(bf16_to_f32).collect();
+        Tensor::from_data(&shape, data).into()
+    } else {
+        return None;
+    };
+    Some(output)
+}
+
+/// Read the type name and attributes of a custom operator from a model file.
+fn read_custom_op_attrs(attrs: sg::CustomAttrs) -> Result<CustomOpAttrs, ReadOpError> {
+    let mut op_attrs = CustomOpAttrs::new(attrs.domain().unwrap_or(""), attrs.op_type());
+    for attr in attrs.attrs().into_iter().flatten() {
+        let value = match attr.type_() {
+            sg::CustomAttrType::Int => AttrValue::Int(attr.int_value()),
+            sg::CustomAttrType::Float => AttrValue::Float(attr.float_value()),
+            sg::CustomAttrType::String => {
+                AttrValue::String(attr.string_value().unwrap_or("").to_string())
+            }
+            sg::CustomAttrType::Ints => {
+                AttrValue::Ints(attr.ints().map(|v| v.iter().collect()).unwrap_or_default())
+            }
+            sg::CustomAttrType::Floats => AttrValue::Floats(
+                attr.floats()
+                    .map(|v| v.iter().collect())
+                    .unwrap_or_default(),
+            ),
+            sg::CustomAttrType::Tensor => {
+                let tensor = attr
+                    .tensor()
+                    .and_then(|t| read_constant(&t))
+                    .ok_or(ReadOpError::AttrError)?;
+                AttrValue::Tensor(tensor)
+            }
+            _ => return Err(ReadOpError::AttrError),
+        };
+        op_attrs.attrs.insert(attr.name().to_string(), value);
+    }
+    Ok(op_attrs)
+}
+
 /// Optimized conversion of a `flatbuffers::Vector<T>` into a `Vec<T>` for
 /// primitive types.
 ///
@@ -1165,6 +1612,8 @@ fn vec_from_flatbuffers_vec<'a, T: Copy + flatbuffers::Follow<'a, Inner = T>>(
 mod tests {
     extern crate flatbuffers;
 
+    use std::collections::HashMap;
+
     use rten_tensor::prelude::*;
     use rten_tensor::{tensor, Tensor};
 
@@ -1172,8 +1621,10 @@ mod tests {
     use crate::model::Model;
     use crate::model_builder::{MetadataArgs, ModelBuilder, OpType};
     use crate::ops;
-    use crate::ops::{BoxOrder, CoordTransformMode, NearestMode, OpError, ResizeMode, Scalar};
-    use crate::{ModelLoadError, OpRegistry, ReadOpError};
+    use crate::ops::{
+        BoxOrder, CoordTransformMode, DataType, NearestMode, OpError, Output, ResizeMode, Scalar,
+    };
+    use crate::{AttrValue, CustomOpAttrs, ModelLoadError, OpRegistry, ReadOpError};
 
     fn generate_model_buffer() -> Vec<u8> {
         let mut builder = ModelBuilder::new();
@@ -1187,7 +1638,8 @@ mod tests {
             .copied()
             .map(Dimension::Fixed)
             .collect();
-        let input_node = builder.add_value("input", Some(&input_shape));
+        let input_node =
+            builder.add_value_with_dtype("input", Some(&input_shape), Some(DataType::Float));
         let output_node = builder.add_value("output", None);
 
         builder.add_input(input_node);
@@ -1247,6 +1699,68 @@ mod tests {
         );
     }
 
+    fn generate_custom_op_model_buffer() -> Vec<u8> {
+        let mut builder = ModelBuilder::new();
+
+        let input_node = builder.add_value("input", None);
+        let output_node = builder.add_value("output", None);
+        builder.add_input(input_node);
+        builder.add_output(output_node);
+
+        let attrs = CustomOpAttrs::new("com.example", "ScaledLeakyRelu")
+            .with_attr("alpha", AttrValue::Float(0.5))
+            .with_attr("mode", AttrValue::String("fast".into()))
+            .with_attr("axes", AttrValue::Ints(vec![0, -1]))
+            .with_attr("weights", AttrValue::Tensor(tensor!([1, 2, 3]).into()));
+        builder.add_operator(
+            "custom",
+            OpType::Custom(attrs),
+            &[Some(input_node)],
+            &[output_node],
+        );
+
+        builder.finish()
+    }
+
+    #[test]
+    fn test_custom_operator() {
+        let buffer = generate_custom_op_model_buffer();
+
+        let mut registry = OpRegistry::with_all_ops();
+        registry.register_custom_op("com.example", "ScaledLeakyRelu", |attrs| {
+            assert_eq!(attrs.get_string("mode"), Some("fast"));
+            assert_eq!(attrs.get_ints("axes"), Some([0, -1].as_slice()));
+            assert_eq!(
+                attrs.get_tensor("weights"),
+                Some(&Output::IntTensor(tensor!([1, 2, 3])))
+            );
+            let alpha = attrs.get_float("alpha").ok_or(ReadOpError::AttrError)?;
+            Ok(Box::new(ops::LeakyRelu { alpha }))
+        });
+        let model = Model::load_with_ops(&buffer, &registry).unwrap();
+
+        let input = tensor!([-2., 1.]);
+        let result = model
+            .run_one((&input).into(), None)
+            .unwrap()
+            .into_float()
+            .unwrap();
+        assert_eq!(result, tensor!([-1., 1.]));
+    }
+
+    #[test]
+    fn test_unsupported_custom_operator() {
+        let buffer = generate_custom_op_model_buffer();
+        let registry = OpRegistry::with_all_ops();
+        let result = Model::load_with_ops(&buffer, &registry);
+        assert_eq!(
+            result.err(),
+            Some(ModelLoadError::OperatorInvalid(
+                ReadOpError::UnsupportedOperator("com.example.ScaledLeakyRelu".to_string())
+            ))
+        );
+    }
+
     #[test]
     fn test_shape_info() {
         let buffer = generate_model_buffer();
@@ -1260,6 +1774,21 @@ mod tests {
         assert_eq!(shape, &[1, 2, 2].map(Dimension::Fixed));
     }
 
+    #[test]
+    fn test_dtype_info() {
+        let buffer = generate_model_buffer();
+        let model = Model::load(&buffer).unwrap();
+        let input_id = model.input_ids()[0];
+        let output_id = model.output_ids()[0];
+
+        let input_dtype = model.node_info(input_id).and_then(|ni| ni.dtype());
+        assert_eq!(input_dtype, Some(DataType::Float));
+
+        // Value nodes without a declared type.
+        let output_dtype = model.node_info(output_id).and_then(|ni| ni.dtype());
+        assert_eq!(output_dtype, None);
+    }
+
     #[test]
     fn test_metadata() {
         let buffer = generate_model_buffer();
@@ -1303,6 +1832,26 @@ mod tests {
         assert_eq!(result_tensor.to_vec(), &[0.5, 0., 0.1, 0., 1., 2., 0., 0.]);
     }
 
+    #[test]
+    fn test_run_with_incorrect_input_type() {
+        let buffer = generate_model_buffer();
+        let model = Model::load(&buffer).unwrap();
+        let input_id = model.input_ids()[0];
+        let output_id = model.output_ids()[0];
+
+        let input = tensor!((1, 2, 2); [1, 2, -1, -2]);
+        let result = model.run(&[(input_id, (&input).into())], &[output_id], None);
+
+        assert_eq!(
+            result.err(),
+            Some(RunError::IncorrectInputType {
+                name: "input".to_string(),
+                expected: DataType::Float,
+                actual: DataType::Int32,
+            })
+        );
+    }
+
     #[test]
     fn test_run_one() {
         let buffer = generate_model_buffer();
@@ -1319,6 +1868,127 @@ mod tests {
         assert_eq!(result.to_vec(), &[0.5, 0., 0.1, 0., 1., 2., 0., 0.]);
     }
 
+    #[test]
+    fn test_total_flops() {
+        let buffer = generate_model_buffer();
+        let model = Model::load(&buffer).unwrap();
+        let input_id = model.input_ids()[0];
+
+        // `Concat` only moves data. `Relu` performs one operation for each
+        // of the 8 output elements.
+        let flops = model.total_flops(&[(input_id, &[1, 2, 2])]).unwrap();
+        assert_eq!(flops, 8);
+
+        let err = model.total_flops(&[(input_id, &[1, 2, 3])]).err();
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn test_dry_run() {
+        let buffer = generate_model_buffer();
+        let model = Model::load(&buffer).unwrap();
+        let input_id = model.input_ids()[0];
+        let output_id = model.output_ids()[0];
+
+        let report = model.dry_run(&[(input_id, &[1, 2, 2])]).unwrap();
+        let ops: Vec<_> = report.steps.iter().map(|step| step.op.as_str()).collect();
+        assert_eq!(ops, ["Concat", "Relu"]);
+        assert_eq!(report.shape(output_id), Some([2, 2, 2].as_slice()));
+        assert_eq!(report.total_activation_bytes, 64);
+        assert_eq!(report.peak_bytes, 64);
+        assert_eq!(report.peak_step, Some(1));
+
+        let err = model.dry_run(&[(input_id, &[1, 2, 3])]).err();
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn test_compare_layers() {
+        let buffer = generate_model_buffer();
+        let model = Model::load(&buffer).unwrap();
+        let input_id = model.input_ids()[0];
+        let input = tensor!((1, 2, 2); [1., 2., -1., -2.]);
+
+        let reference: HashMap<String, Output> = [
+            (
+                "concat_out",
+                Tensor::from_data(&[2, 2, 2], vec![0.5, -0.5, 0.1, -0.1, 1., 2., -1., -2.]),
+            ),
+            (
+                "output",
+                Tensor::from_data(&[2, 2, 2], vec![0.5, 0., 0.1, 0., 1., 2.5, 0., 0.]),
+            ),
+            ("unused", Tensor::zeros(&[2])),
+        ]
+        .into_iter()
+        .map(|(name, value)| (name.to_string(), value.into()))
+        .collect();
+
+        let report = model
+            .compare_layers(&[(input_id, input.view().into())], &reference, 1e-4, None)
+            .unwrap();
+
+        let names: Vec<_> = report.layers.iter().map(|l| l.name.as_str()).collect();
+        assert_eq!(names, ["concat_out", "output"]);
+        assert_eq!(report.layers[0].op, "Concat");
+        assert_eq!(report.layers[0].max_abs_error, 0.);
+        assert_eq!(report.layers[1].op, "Relu");
+        assert_eq!(report.layers[1].max_abs_error, 0.5);
+        assert_eq!(report.layers[1].max_rel_error, 0.2);
+        assert_eq!(
+            report.first_exceeding().map(|l| l.name.as_str()),
+            Some("output")
+        );
+    }
+
+    #[test]
+    fn test_load_reduced_precision_constants() {
+        let mut builder = ModelBuilder::new();
+
+        // Values which are exactly representable in both f16 and bf16.
+        let const_val = Tensor::from_data(&[2, 2], vec![0.5, -1.5, 2., 0.]);
+        let f16_node = builder.add_float16_constant(&const_val);
+        let bf16_node = builder.add_bfloat16_constant(&const_val);
+
+        let f16_out = builder.add_value("f16_out", None);
+        let bf16_out = builder.add_value("bf16_out", None);
+        builder.add_output(f16_out);
+        builder.add_output(bf16_out);
+        builder.add_operator("f16_id", OpType::Identity, &[Some(f16_node)], &[f16_out]);
+        builder.add_operator("bf16_id", OpType::Identity, &[Some(bf16_node)], &[bf16_out]);
+
+        let buffer = builder.finish();
+        let model = Model::load(&buffer).unwrap();
+
+        let result = model.run(&[], model.output_ids(), None).unwrap();
+        assert_eq!(result.len(), 2);
+        for output in result {
+            let output = output.as_float_ref().unwrap();
+            assert_eq!(output.shape(), const_val.shape());
+            assert_eq!(output.to_vec(), const_val.to_vec());
+        }
+    }
+
+    #[test]
+    fn test_load_rounded_float16_constant() {
+        let mut builder = ModelBuilder::new();
+
+        let const_val = Tensor::from_data(&[2], vec![0.1, 1000.3]);
+        let const_node = builder.add_float16_constant(&const_val);
+        let output = builder.add_value("output", None);
+        builder.add_output(output);
+        builder.add_operator("id", OpType::Identity, &[Some(const_node)], &[output]);
+
+        let buffer = builder.finish();
+        let model = Model::load(&buffer).unwrap();
+        let result = model.run(&[], model.output_ids(), None).unwrap();
+        let output = result[0].as_float_ref().unwrap();
+
+        for (actual, expected) in output.iter().zip(const_val.iter()) {
+            assert!((actual - expected).abs() <= expected.abs() * 1e-3);
+        }
+    }
+
     #[test]
     fn test_omitted_optional_inputs() {
         let mut builder = ModelBuilder::new();
@@ -1336,7 +2006,10 @@ mod tests {
             result.err(),
             Some(RunError::OperatorError {
                 name: "shape".to_string(),
-                error: OpError::MissingInputs
+                error: OpError::MissingInputs,
+                input_shapes: vec![None],
+                input_dtypes: vec![None],
+                operator: "Shape".into(),
             })
         );
     }
@@ -1402,11 +2075,19 @@ mod tests {
         add_operator!(ArgMin, [input_node], { axis: 3, keep_dims: false });
         add_operator!(Asin, [input_node]);
         add_operator!(Atan, [input_node]);
+        add_operator!(Attention, [input_node, input_node, input_node], {
+            num_heads: None,
+            kv_num_heads: None,
+            is_causal: true,
+            scale: None,
+        });
         add_operator!(AveragePool, [input_node], {
-            kernel_size: [2, 2],
-            strides: [2, 2],
+            kernel_size: vec![2, 2],
+            strides: vec![2, 2],
             padding: [0, 0, 0, 0].into(),
             count_include_pad: false,
+            dilations: vec![1, 1],
+            ceil_mode: false,
         });
 
         // Dummy value for BatchNormalization inputs which are vectors with
@@ -1427,6 +2108,7 @@ mod tests {
 
         add_operator!(Cast, [input_node], { to: ops::DataType::Float });
         add_operator!(Ceil, [input_node]);
+        add_operator!(Celu, [input_node], { alpha: 1.0 });
 
         let clip_min = builder.add_float_constant(&tensor!(1.));
         let clip_max = builder.add_float_constant(&tensor!(6.));
@@ -1443,9 +2125,17 @@ mod tests {
             strides: vec![1, 1],
         });
 
-        add_operator!(ConvTranspose, [input_node, kernel], { strides: [2, 2] });
+        add_operator!(ConvTranspose, [input_node, kernel], {
+            padding: [0, 0, 0, 0].into(),
+            groups: 1,
+            strides: vec![2, 2],
+            dilations: vec![1, 1],
+            output_padding: None,
+            output_shape: None,
+        });
         add_operator!(Cos, [input_node]);
         add_operator!(Div, [input_node, input_node]);
+        add_operator!(Elu, [input_node], { alpha: 1.0 });
         add_operator!(Equal, [input_node, input_node]);
         add_operator!(Erf, [input_node]);
         add_operator!(Exp, [input_node]);
@@ -1471,9 +2161,21 @@ mod tests {
             transpose_a: false,
             transpose_b: false,
         });
+        add_operator!(Gelu, [input_node], { approximate: false });
         add_operator!(GlobalAveragePool, [input_node]);
+        add_operator!(GlobalLpPool, [input_node], { p: 2 });
+        add_operator!(GlobalMaxPool, [input_node]);
         add_operator!(Greater, [input_node, input_node]);
         add_operator!(GreaterOrEqual, [input_node, input_node]);
+
+        let group_norm_scale_val = tensor!([1.0]);
+        let group_norm_scale = builder.add_float_constant(&group_norm_scale_val);
+        let group_norm_bias_val = tensor!([1.0]);
+        let group_norm_bias = builder.add_float_constant(&group_norm_bias_val);
+        add_operator!(GroupNormalization, [
+            input_node, group_norm_scale, group_norm_bias
+        ], { num_groups: 1, epsilon: Some(1e-5) });
+
         add_operator!(HardSigmoid, [input_node], {
             alpha: 0.2,
             beta: 0.5,
@@ -1505,18 +2207,46 @@ mod tests {
         add_operator!(LessOrEqual, [input_node, input_node]);
         add_operator!(Log, [input_node]);
         add_operator!(LogSoftmax, [input_node], { axis: 1 });
+        add_operator!(LpPool, [input_node], {
+            kernel_size: vec![2, 2],
+            strides: vec![2, 2],
+            padding: [0, 0, 0, 0].into(),
+            dilations: vec![1, 1],
+            ceil_mode: false,
+            p: 2,
+        });
 
         // TODO - Add LSTM operator
 
         add_operator!(MatMul, [input_2d, input_2d]);
         add_operator!(Max, [input_node, input_node]);
         add_operator!(MaxPool, [input_node], {
-            kernel_size: [2, 2],
-            strides: [2, 2],
+            kernel_size: vec![2, 2],
+            strides: vec![2, 2],
             padding: [0, 0, 0, 0].into(),
+            dilations: vec![1, 1],
+            ceil_mode: false,
+            return_indices: false,
         });
+
+        let max_pool_out_values = builder.add_value("MaxPool_out_values", None);
+        let max_pool_out_indices = builder.add_value("MaxPool_out_indices", None);
+        builder.add_operator(
+            "MaxPool_indices",
+            OpType::MaxPool(ops::MaxPool {
+                kernel_size: vec![2, 2],
+                strides: vec![2, 2],
+                padding: [0, 0, 0, 0].into(),
+                dilations: vec![1, 1],
+                ceil_mode: true,
+                return_indices: true,
+            }),
+            &[input_node].map(Some),
+            &[max_pool_out_values, max_pool_out_indices],
+        );
         add_operator!(Mean, [input_node, input_node]);
         add_operator!(Min, [input_node, input_node]);
+        add_operator!(Mish, [input_node]);
         add_operator!(Mod, [input_node, input_node], {
             fmod: false,
         });
@@ -1549,8 +2279,9 @@ mod tests {
         add_operator!(Or, [input_bool, input_bool]);
 
         let pads = builder.add_int_constant(&Tensor::from_data(&[8], vec![0, 0, 1, 1, 0, 0, 1, 1]));
-        add_operator!(Pad, [input_node, pads]);
+        add_operator!(Pad, [input_node, pads], { mode: ops::PadMode::Reflect });
         add_operator!(Pow, [input_node, input_node]);
+        add_operator!(PRelu, [input_node, input_node]);
 
         add_operator!(RandomUniform, [], {
             shape: vec![50, 50],
@@ -1568,6 +2299,7 @@ mod tests {
         );
 
         add_operator!(Reciprocal, [input_node]);
+
         add_operator!(ReduceMean, [input_node], {
             axes: None,
             keep_dims: false,
@@ -1606,11 +2338,24 @@ mod tests {
         add_operator!(Resize, [input_node, resize_roi, resize_scales], {
             mode: ResizeMode::Nearest,
             nearest_mode: NearestMode::default(),
-            coord_mode: CoordTransformMode::default()
+            coord_mode: CoordTransformMode::default(),
+            cubic_coeff_a: -0.75,
+            antialias: false,
+            exclude_outside: false,
+            extrapolation_value: 0.,
+            axes: None
+        });
+
+        let rms_norm_scale_val = tensor!([1.0]);
+        let rms_norm_scale = builder.add_float_constant(&rms_norm_scale_val);
+        add_operator!(RMSNormalization, [input_node, rms_norm_scale], {
+            axis: -1,
+            epsilon: Some(1e-5),
         });
 
         add_operator!(Round, [input_node]);
 
+        add_operator!(Selu, [input_node], { alpha: 1.67, gamma: 1.05 });
         add_operator!(Shape, [input_node]);
         add_operator!(Sigmoid, [input_node]);
         add_operator!(Sign, [input_node]);
@@ -1632,6 +2377,8 @@ mod tests {
         add_operator!(Slice, [input_node, const_0, const_1, const_0]);
 
         add_operator!(Softmax, [input_node], { axis: 1 });
+        add_operator!(Softplus, [input_node]);
+        add_operator!(Softsign, [input_node]);
         add_operator!(Sqrt, [input_node]);
         add_operator!(Squeeze, [input_node]);
 
@@ -1649,6 +2396,7 @@ mod tests {
         add_operator!(Sum, [input_node, input_node]);
         add_operator!(Tan, [input_node]);
         add_operator!(Tanh, [input_node]);
+        add_operator!(ThresholdedRelu, [input_node], { alpha: 1.0 });
 
         let tile_repeats = builder.add_int_constant(&tensor!([1, 2, 3, 4]));
         add_operator!(Tile, [input_node, tile_repeats]);
@@ -4,14 +4,18 @@ use flatbuffers::{FlatBufferBuilder, UnionWIPOffset, Vector, WIPOffset};
 use rten_tensor::prelude::*;
 use rten_tensor::Tensor;
 
+use crate::custom_op::{AttrValue, CustomOpAttrs};
 use crate::graph::Dimension;
+use crate::number::{f32_to_bf16, f32_to_f16};
 use crate::ops::{
-    ArgMax, ArgMin, AveragePool, BatchNormalization, BoxOrder, Cast, Concat, ConstantOfShape, Conv,
-    ConvTranspose, CoordTransformMode, DataType, Flatten, Gather, GatherElements, Gemm,
-    HardSigmoid, InstanceNormalization, LayerNormalization, LeakyRelu, LogSoftmax, MaxPool, Mod,
-    NearestMode, NonMaxSuppression, OneHot, Padding, ReduceMax, ReduceMean, ReduceMin, ReduceProd,
-    ReduceSum, ReduceSumSquare, Reshape, Resize, ResizeMode, Scalar, ScatterElements,
-    ScatterReduction, Softmax, Split, TopK, Transpose, Trilu,
+    ArgMax, ArgMin, Attention, AveragePool, BatchNormalization, BoxOrder, Cast, Celu, Concat,
+    ConstantOfShape, Conv, ConvTranspose, CoordTransformMode, DataType, Elu, Flatten, Gather,
+    GatherElements, Gelu, Gemm, GlobalLpPool, GroupNormalization, HardSigmoid,
+    InstanceNormalization, LayerNormalization, LeakyRelu, LogSoftmax, LpPool, MaxPool, Mod,
+    NearestMode, NonMaxSuppression, OneHot, Output, Pad, PadMode, Padding, RMSNormalization,
+    ReduceMax, ReduceMean, ReduceMin, ReduceProd, ReduceSum, ReduceSumSquare, Reshape, Resize,
+    ResizeMode, Scalar, ScatterElements, ScatterReduction, Selu, Softmax, Split, ThresholdedRelu,
+    TopK, Transpose, Trilu,
 };
 use crate::schema_generated as sg;
 
@@ -28,17 +32,21 @@ pub enum OpType {
     ArgMin(ArgMin),
     Asin,
     Atan,
+    Attention(Attention),
     AveragePool(AveragePool),
     BatchNormalization(BatchNormalization),
     Cast(Cast),
     Ceil,
+    Celu(Celu),
     Clip,
     Concat(Concat),
     ConstantOfShape(ConstantOfShape),
     Conv(Conv),
     ConvTranspose(ConvTranspose),
     Cos,
+    Custom(CustomOpAttrs),
     Div,
+    Elu(Elu),
     Equal,
     Erf,
     Exp,
@@ -47,9 +55,13 @@ pub enum OpType {
     Floor,
     Gather(Gather),
     GatherElements(GatherElements),
+    Gelu(Gelu),
     Gemm(Gemm),
     GlobalAveragePool,
+    GlobalLpPool(GlobalLpPool),
+    GlobalMaxPool,
     Greater,
+    GroupNormalization(GroupNormalization),
     GreaterOrEqual,
     HardSigmoid(HardSigmoid),
     HardSwish,
@@ -61,11 +73,13 @@ pub enum OpType {
     LessOrEqual,
     Log,
     LogSoftmax(LogSoftmax),
+    LpPool(LpPool),
     MatMul,
     Max,
     MaxPool(MaxPool),
     Mean,
     Min,
+    Mish,
     Mod(Mod),
     Mul,
     Neg,
@@ -74,14 +88,16 @@ pub enum OpType {
     Not,
     OneHot(OneHot),
     Or,
-    Pad,
+    Pad(Pad),
     Pow,
+    PRelu,
 
     #[cfg(feature = "random")]
     RandomUniform(RandomUniform),
 
     Range,
     Reciprocal,
+    RMSNormalization(RMSNormalization),
     ReduceMax(ReduceMax),
     ReduceMean(ReduceMean),
     ReduceMin(ReduceMin),
@@ -93,6 +109,7 @@ pub enum OpType {
     Resize(Resize),
     Round,
     ScatterElements(ScatterElements),
+    Selu(Selu),
     Shape,
     Sigmoid,
     Sign,
@@ -100,6 +117,8 @@ pub enum OpType {
     Size,
     Slice,
     Softmax(Softmax),
+    Softplus,
+    Softsign,
     Split(Split),
     Sqrt,
     Squeeze,
@@ -107,6 +126,7 @@ pub enum OpType {
     Sum,
     Tan,
     Tanh,
+    ThresholdedRelu(ThresholdedRelu),
     Tile,
     TopK(TopK),
     Transpose(Transpose),
@@ -145,6 +165,16 @@ struct PadArgs {
     pads: Option<Vec<usize>>,
 }
 
+fn convert_data_type(dtype: DataType) -> sg::DataType {
+    match dtype {
+        DataType::Int32 => sg::DataType::Int32,
+        DataType::Float => sg::DataType::Float,
+        DataType::Int64 => sg::DataType::Int64,
+        DataType::Bool => sg::DataType::Bool,
+        DataType::UInt8 => sg::DataType::UInt8,
+    }
+}
+
 fn pad_args_from_padding(padding: Padding) -> PadArgs {
     match padding {
         Padding::Same => PadArgs {
@@ -205,6 +235,50 @@ impl<'a> ModelBuilder<'a> {
         )
     }
 
+    /// Add a constant node (eg. weights, biases) to the model, with the data
+    /// stored in IEEE 754 half-precision format.
+    ///
+    /// The data is converted back to f32 when the model is loaded.
+    pub fn add_float16_constant(&mut self, input: &Tensor) -> u32 {
+        let elts: Vec<u16> = input.iter().copied().map(f32_to_f16).collect();
+        let data_vec = self.builder.create_vector(&elts);
+
+        let f16_data = sg::Float16Data::create(
+            &mut self.builder,
+            &sg::Float16DataArgs {
+                data: Some(data_vec),
+            },
+        );
+
+        self.add_constant_node(
+            input.shape(),
+            sg::ConstantData::Float16Data,
+            f16_data.as_union_value(),
+        )
+    }
+
+    /// Add a constant node (eg. weights, biases) to the model, with the data
+    /// stored in bfloat16 format.
+    ///
+    /// The data is converted back to f32 when the model is loaded.
+    pub fn add_bfloat16_constant(&mut self, input: &Tensor) -> u32 {
+        let elts: Vec<u16> = input.iter().copied().map(f32_to_bf16).collect();
+        let data_vec = self.builder.create_vector(&elts);
+
+        let bf16_data = sg::BFloat16Data::create(
+            &mut self.builder,
+            &sg::BFloat16DataArgs {
+                data: Some(data_vec),
+            },
+        );
+
+        self.add_constant_node(
+            input.shape(),
+            sg::ConstantData::BFloat16Data,
+            bf16_data.as_union_value(),
+        )
+    }
+
     /// Add a constant node (eg. weights, biases) to the model
     pub fn add_int_constant(&mut self, input: &Tensor<i32>) -> u32 {
         let elts: Vec<i32> = input.to_vec();
@@ -230,22 +304,112 @@ impl<'a> ModelBuilder<'a> {
         data_type: sg::ConstantData,
         data: WIPOffset<UnionWIPOffset>,
     ) -> u32 {
+        let const_node = self.create_constant_node(shape, data_type, data);
+        self.add_node(None, NodeData::Constant(const_node))
+    }
+
+    fn create_constant_node(
+        &mut self,
+        shape: &[usize],
+        data_type: sg::ConstantData,
+        data: WIPOffset<UnionWIPOffset>,
+    ) -> WIPOffset<sg::ConstantNode<'a>> {
         let shape: Vec<u32> = shape.iter().map(|&x| x as u32).collect();
         let shape_vec = self.builder.create_vector(&shape[..]);
 
-        let const_node = sg::ConstantNode::create(
+        sg::ConstantNode::create(
             &mut self.builder,
             &sg::ConstantNodeArgs {
                 shape: Some(shape_vec),
                 data_type,
                 data: Some(data),
             },
-        );
-        self.add_node(None, NodeData::Constant(const_node))
+        )
+    }
+
+    /// Write the attributes of a custom operator into the model buffer.
+    ///
+    /// Attributes are written in name order so that the output is
+    /// deterministic.
+    fn create_custom_attrs(&mut self, op_attrs: &CustomOpAttrs) -> sg::CustomAttrsArgs<'a> {
+        let mut names: Vec<&String> = op_attrs.attrs.keys().collect();
+        names.sort();
+
+        let mut attrs = Vec::with_capacity(names.len());
+        for name in names {
+            let mut args = sg::CustomAttrArgs {
+                name: Some(self.builder.create_string(name)),
+                ..Default::default()
+            };
+            match &op_attrs.attrs[name] {
+                AttrValue::Int(val) => {
+                    args.type_ = sg::CustomAttrType::Int;
+                    args.int_value = *val;
+                }
+                AttrValue::Float(val) => {
+                    args.type_ = sg::CustomAttrType::Float;
+                    args.float_value = *val;
+                }
+                AttrValue::String(val) => {
+                    args.type_ = sg::CustomAttrType::String;
+                    args.string_value = Some(self.builder.create_string(val));
+                }
+                AttrValue::Ints(val) => {
+                    args.type_ = sg::CustomAttrType::Ints;
+                    args.ints = Some(self.builder.create_vector(val));
+                }
+                AttrValue::Floats(val) => {
+                    args.type_ = sg::CustomAttrType::Floats;
+                    args.floats = Some(self.builder.create_vector(val));
+                }
+                AttrValue::Tensor(val) => {
+                    args.type_ = sg::CustomAttrType::Tensor;
+                    let (data_type, data) = match val {
+                        Output::FloatTensor(tensor) => {
+                            let elts: Vec<f32> = tensor.to_vec();
+                            let data = Some(self.builder.create_vector(&elts));
+                            let float_data = sg::FloatData::create(
+                                &mut self.builder,
+                                &sg::FloatDataArgs { data },
+                            );
+                            (sg::ConstantData::FloatData, float_data.as_union_value())
+                        }
+                        Output::IntTensor(tensor) => {
+                            let elts: Vec<i32> = tensor.to_vec();
+                            let data = Some(self.builder.create_vector(&elts));
+                            let int_data =
+                                sg::IntData::create(&mut self.builder, &sg::IntDataArgs { data });
+                            (sg::ConstantData::IntData, int_data.as_union_value())
+                        }
+                        _ => panic!("unsupported tensor type for custom operator attribute"),
+                    };
+                    args.tensor = Some(self.create_constant_node(val.shape(), data_type, data));
+                }
+            }
+            attrs.push(sg::CustomAttr::create(&mut self.builder, &args));
+        }
+
+        let op_type = self.builder.create_string(&op_attrs.op_type);
+        let domain = self.builder.create_string(&op_attrs.domain);
+        sg::CustomAttrsArgs {
+            op_type: Some(op_type),
+            domain: Some(domain),
+            attrs: Some(self.builder.create_vector(&attrs)),
+        }
     }
 
     /// Add a value node to the model
     pub fn add_value(&mut self, id: &str, shape: Option<&[Dimension]>) -> u32 {
+        self.add_value_with_dtype(id, shape, None)
+    }
+
+    /// Add a value node to the model with an expected element type.
+    pub fn add_value_with_dtype(
+        &mut self,
+        id: &str,
+        shape: Option<&[Dimension]>,
+        dtype: Option<DataType>,
+    ) -> u32 {
         let shape = shape.map(|shape| {
             let dim_vec: Vec<_> = shape
                 .iter()
@@ -271,7 +435,13 @@ impl<'a> ModelBuilder<'a> {
                 .collect();
             self.builder.create_vector(&dim_vec[..])
         });
-        let value_node = sg::ValueNode::create(&mut self.builder, &sg::ValueNodeArgs { shape });
+        let value_node = sg::ValueNode::create(
+            &mut self.builder,
+            &sg::ValueNodeArgs {
+                shape,
+                dtype: dtype.map(convert_data_type),
+            },
+        );
         self.add_node(Some(id), NodeData::Value(value_node))
     }
 
@@ -348,17 +518,28 @@ impl<'a> ModelBuilder<'a> {
             }),
             OpType::Asin => op!(Asin),
             OpType::Atan => op!(Atan),
+            OpType::Attention(args) => op_with_attrs!(Attention, AttentionAttrs, {
+                sg::AttentionAttrsArgs {
+                    num_heads: args.num_heads.unwrap_or(0) as u32,
+                    kv_num_heads: args.kv_num_heads.unwrap_or(0) as u32,
+                    is_causal: args.is_causal,
+                    scale: args.scale,
+                }
+            }),
             OpType::AveragePool(args) => op_with_attrs!(AveragePool, AveragePoolAttrs, {
                 let pad_args = pad_args_from_padding(args.padding);
                 let pads = self.create_vec(pad_args.pads, |pad| pad as u32);
-                let kernel_size = self.create_vec(Some(args.kernel_size.into()), |sz| sz as u32);
-                let strides = self.create_vec(Some(args.strides.into()), |s| s as u32);
+                let kernel_size = self.create_vec(Some(args.kernel_size), |sz| sz as u32);
+                let strides = self.create_vec(Some(args.strides), |s| s as u32);
+                let dilations = self.create_vec(Some(args.dilations), |d| d as u32);
                 sg::AveragePoolAttrsArgs {
                     kernel_size,
                     pad_mode: pad_args.pad_mode,
                     pads,
                     strides,
                     count_include_pad: args.count_include_pad,
+                    dilations,
+                    ceil_mode: args.ceil_mode,
                 }
             }),
             OpType::BatchNormalization(args) => op_with_attrs!(
@@ -372,13 +553,15 @@ impl<'a> ModelBuilder<'a> {
                 Cast,
                 CastAttrs,
                 sg::CastAttrsArgs {
-                    to: match args.to {
-                        DataType::Int32 => sg::DataType::Int32,
-                        DataType::Float => sg::DataType::Float,
-                    },
+                    to: convert_data_type(args.to),
                 }
             ),
             OpType::Ceil => op!(Ceil),
+            OpType::Celu(args) => op_with_attrs!(
+                Celu,
+                LeakyReluAttrs,
+                sg::LeakyReluAttrsArgs { alpha: args.alpha }
+            ),
             OpType::Clip => op!(Clip),
             OpType::Concat(args) => op_with_attrs!(
                 Concat,
@@ -428,11 +611,33 @@ impl<'a> ModelBuilder<'a> {
                 }
             }),
             OpType::ConvTranspose(args) => op_with_attrs!(ConvTranspose, ConvTransposeAttrs, {
-                let strides = self.create_vec(Some(args.strides.into()), |s| s as u32);
-                sg::ConvTransposeAttrsArgs { strides }
+                let pad_args = pad_args_from_padding(args.padding);
+                let pads = self.create_vec(pad_args.pads, |pad| pad as u32);
+                let strides = self.create_vec(Some(args.strides), |s| s as u32);
+                let dilations = self.create_vec(Some(args.dilations), |d| d as u32);
+                let output_padding = self.create_vec(args.output_padding, |p| p as u32);
+                let output_shape = self.create_vec(args.output_shape, |s| s as u32);
+
+                sg::ConvTransposeAttrsArgs {
+                    strides,
+                    pad_mode: pad_args.pad_mode,
+                    pads,
+                    groups: args.groups as u32,
+                    dilations,
+                    output_padding,
+                    output_shape,
+                }
             }),
             OpType::Cos => op!(Cos),
+            OpType::Custom(args) => {
+                op_with_attrs!(Custom, CustomAttrs, { self.create_custom_attrs(&args) })
+            }
             OpType::Div => op!(Div),
+            OpType::Elu(args) => op_with_attrs!(
+                Elu,
+                LeakyReluAttrs,
+                sg::LeakyReluAttrsArgs { alpha: args.alpha }
+            ),
             OpType::Equal => op!(Equal),
             OpType::Erf => op!(Erf),
             OpType::Exp => op!(Exp),
@@ -459,6 +664,13 @@ impl<'a> ModelBuilder<'a> {
                     axis: args.axis as i32,
                 }
             ),
+            OpType::Gelu(args) => op_with_attrs!(
+                Gelu,
+                GeluAttrs,
+                sg::GeluAttrsArgs {
+                    approximate: args.approximate
+                }
+            ),
             OpType::Gemm(args) => op_with_attrs!(
                 Gemm,
                 GemmAttrs,
@@ -470,7 +682,21 @@ impl<'a> ModelBuilder<'a> {
                 }
             ),
             OpType::GlobalAveragePool => op!(GlobalAveragePool),
+            OpType::GlobalLpPool(args) => op_with_attrs!(
+                GlobalLpPool,
+                GlobalLpPoolAttrs,
+                sg::GlobalLpPoolAttrsArgs { p: args.p }
+            ),
+            OpType::GlobalMaxPool => op!(GlobalMaxPool),
             OpType::Greater => op!(Greater),
+            OpType::GroupNormalization(args) => op_with_attrs!(
+                GroupNormalization,
+                GroupNormalizationAttrs,
+                sg::GroupNormalizationAttrsArgs {
+                    num_groups: args.num_groups as u32,
+                    epsilon: args.epsilon.unwrap_or(1e-5)
+                }
+            ),
             OpType::GreaterOrEqual => op!(GreaterOrEqual),
             OpType::HardSigmoid(args) => op_with_attrs!(
                 HardSigmoid,
@@ -512,22 +738,42 @@ impl<'a> ModelBuilder<'a> {
                     axis: args.axis as i32,
                 }
             ),
+            OpType::LpPool(args) => op_with_attrs!(LpPool, LpPoolAttrs, {
+                let pad_args = pad_args_from_padding(args.padding);
+                let pads = self.create_vec(pad_args.pads, |pad| pad as u32);
+                let kernel_size = self.create_vec(Some(args.kernel_size), |sz| sz as u32);
+                let strides = self.create_vec(Some(args.strides), |s| s as u32);
+                let dilations = self.create_vec(Some(args.dilations), |d| d as u32);
+                sg::LpPoolAttrsArgs {
+                    kernel_size,
+                    pad_mode: pad_args.pad_mode,
+                    pads,
+                    strides,
+                    dilations,
+                    ceil_mode: args.ceil_mode,
+                    p: args.p,
+                }
+            }),
             OpType::MatMul => op!(MatMul),
             OpType::Max => op!(Max),
             OpType::MaxPool(args) => op_with_attrs!(MaxPool, MaxPoolAttrs, {
                 let pad_args = pad_args_from_padding(args.padding);
                 let pads = self.create_vec(pad_args.pads, |pad| pad as u32);
-                let kernel_size = self.create_vec(Some(args.kernel_size.into()), |sz| sz as u32);
-                let strides = self.create_vec(Some(args.strides.into()), |s| s as u32);
+                let kernel_size = self.create_vec(Some(args.kernel_size), |sz| sz as u32);
+                let strides = self.create_vec(Some(args.strides), |s| s as u32);
+                let dilations = self.create_vec(Some(args.dilations), |d| d as u32);
                 sg::MaxPoolAttrsArgs {
                     kernel_size,
                     pad_mode: pad_args.pad_mode,
                     pads,
                     strides,
+                    dilations,
+                    ceil_mode: args.ceil_mode,
                 }
             }),
             OpType::Mean => op!(Mean),
             OpType::Min => op!(Min),
+            OpType::Mish => op!(Mish),
             OpType::Mod(args) => {
                 op_with_attrs!(Mod, ModAttrs, sg::ModAttrsArgs { fmod: args.fmod })
             }
@@ -557,8 +803,17 @@ impl<'a> ModelBuilder<'a> {
                     }
                 )
             }
-            OpType::Pad => op!(Pad),
+            OpType::Pad(args) => op_with_attrs!(Pad, PadAttrs, {
+                let mode = match args.mode {
+                    PadMode::Constant => sg::PadOpMode::Constant,
+                    PadMode::Reflect => sg::PadOpMode::Reflect,
+                    PadMode::Edge => sg::PadOpMode::Edge,
+                    PadMode::Wrap => sg::PadOpMode::Wrap,
+                };
+                sg::PadAttrsArgs { mode }
+            }),
             OpType::Pow => op!(Pow),
+            OpType::PRelu => op!(PRelu),
 
             #[cfg(feature = "random")]
             OpType::RandomUniform(args) => {
@@ -575,6 +830,14 @@ impl<'a> ModelBuilder<'a> {
 
             OpType::Range => op!(Range),
             OpType::Reciprocal => op!(Reciprocal),
+            OpType::RMSNormalization(args) => op_with_attrs!(
+                RMSNormalization,
+                LayerNormalizationAttrs,
+                sg::LayerNormalizationAttrsArgs {
+                    axis: args.axis as i32,
+                    epsilon: args.epsilon.unwrap_or(1e-5)
+                }
+            ),
             OpType::ReduceMax(args) => {
                 op_with_attrs!(ReduceMax, ReduceMeanAttrs, reduce_attrs!(args))
             }
@@ -603,11 +866,16 @@ impl<'a> ModelBuilder<'a> {
                 let mode = match args.mode {
                     ResizeMode::Nearest => sg::ResizeMode::Nearest,
                     ResizeMode::Linear => sg::ResizeMode::Linear,
+                    ResizeMode::Cubic => sg::ResizeMode::Cubic,
                 };
                 let coord_mode = match args.coord_mode {
                     CoordTransformMode::Asymmetric => sg::CoordTransformMode::Asymmetric,
                     CoordTransformMode::HalfPixel => sg::CoordTransformMode::HalfPixel,
                     CoordTransformMode::AlignCorners => sg::CoordTransformMode::AlignCorners,
+                    CoordTransformMode::PytorchHalfPixel => {
+                        sg::CoordTransformMode::PytorchHalfPixel
+                    }
+                    CoordTransformMode::TfCropAndResize => sg::CoordTransformMode::TfCropAndResize,
                 };
                 let nearest_mode = match args.nearest_mode {
                     NearestMode::Ceil => sg::NearestMode::Ceil,
@@ -615,10 +883,16 @@ impl<'a> ModelBuilder<'a> {
                     NearestMode::RoundPreferCeil => sg::NearestMode::RoundPreferCeil,
                     NearestMode::RoundPreferFloor => sg::NearestMode::RoundPreferFloor,
                 };
+                let axes = self.create_vec(args.axes, |axis| axis);
                 sg::ResizeAttrsArgs {
                     mode,
                     coord_mode,
                     nearest_mode,
+                    cubic_coeff_a: args.cubic_coeff_a,
+                    antialias: args.antialias,
+                    exclude_outside: args.exclude_outside,
+                    extrapolation_value: args.extrapolation_value,
+                    axes,
                 }
             }),
             OpType::Round => op!(Round),
@@ -637,6 +911,14 @@ impl<'a> ModelBuilder<'a> {
                     }
                 })
             }
+            OpType::Selu(args) => op_with_attrs!(
+                Selu,
+                SeluAttrs,
+                sg::SeluAttrsArgs {
+                    alpha: args.alpha,
+                    gamma: args.gamma
+                }
+            ),
             OpType::Shape => op!(Shape),
             OpType::Sigmoid => op!(Sigmoid),
             OpType::Slice => op!(Slice),
@@ -650,6 +932,8 @@ impl<'a> ModelBuilder<'a> {
                     axis: args.axis as i32,
                 }
             ),
+            OpType::Softplus => op!(Softplus),
+            OpType::Softsign => op!(Softsign),
             OpType::Split(args) => op_with_attrs!(Split, SplitAttrs, {
                 sg::SplitAttrsArgs {
                     axis: args.axis as i32,
@@ -661,6 +945,11 @@ impl<'a> ModelBuilder<'a> {
             OpType::Sum => op!(Sum),
             OpType::Tan => op!(Tan),
             OpType::Tanh => op!(Tanh),
+            OpType::ThresholdedRelu(args) => op_with_attrs!(
+                ThresholdedRelu,
+                LeakyReluAttrs,
+                sg::LeakyReluAttrsArgs { alpha: args.alpha }
+            ),
             OpType::Tile => op!(Tile),
             OpType::TopK(args) => op_with_attrs!(TopK, TopKAttrs, {
                 sg::TopKAttrsArgs {
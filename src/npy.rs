@@ -0,0 +1,355 @@
+//! Serialization of tensors in NumPy's `.npy` format.
+//!
+//! See https://numpy.org/doc/stable/reference/generated/numpy.lib.format.html.
+
+use std::fs::File;
+use std::io;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use rten_tensor::prelude::*;
+use rten_tensor::{Tensor, TensorView};
+
+use crate::ops::Output;
+
+/// Element types that can be written to an `.npy` file.
+trait NpyElement: Copy {
+    /// NumPy type descriptor for this element type.
+    const DESCR: &'static str;
+
+    /// Write the little-endian encoding of this value.
+    fn write_le<W: Write>(self, out: &mut W) -> io::Result<()>;
+}
+
+macro_rules! impl_npy_element {
+    ($type:ty, $descr:expr) => {
+        impl NpyElement for $type {
+            const DESCR: &'static str = $descr;
+
+            fn write_le<W: Write>(self, out: &mut W) -> io::Result<()> {
+                out.write_all(&self.to_le_bytes())
+            }
+        }
+    };
+}
+
+impl_npy_element!(f32, "<f4");
+impl_npy_element!(i32, "<i4");
+impl_npy_element!(i64, "<i8");
+impl_npy_element!(u8, "|u1");
+
+impl NpyElement for bool {
+    const DESCR: &'static str = "|b1";
+
+    fn write_le<W: Write>(self, out: &mut W) -> io::Result<()> {
+        out.write_all(&[self as u8])
+    }
+}
+
+/// Write the header for an `.npy` file containing an array with a given
+/// element type and shape.
+fn write_header<W: Write>(out: &mut W, descr: &str, shape: &[usize]) -> io::Result<()> {
+    let shape_str = match shape {
+        [size] => format!("({},)", size),
+        _ => {
+            let dims: Vec<_> = shape.iter().map(|size| size.to_string()).collect();
+            format!("({})", dims.join(", "))
+        }
+    };
+    let mut header = format!(
+        "{{'descr': '{}', 'fortran_order': False, 'shape': {}, }}",
+        descr, shape_str
+    );
+
+    // Pad the header with spaces and a terminating newline, so that the data
+    // starts at an offset that is a multiple of 64 bytes.
+    const MAGIC: &[u8] = b"\x93NUMPY";
+    let prefix_len = MAGIC.len() + 2 /* version */ + 2 /* header len */;
+    let unpadded_len = prefix_len + header.len() + 1;
+    let padding = (64 - unpadded_len % 64) % 64;
+    header.push_str(&" ".repeat(padding));
+    header.push('\n');
+
+    let header_len: u16 = header
+        .len()
+        .try_into()
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "shape is too large"))?;
+
+    out.write_all(MAGIC)?;
+    out.write_all(&[1, 0])?;
+    out.write_all(&header_len.to_le_bytes())?;
+    out.write_all(header.as_bytes())
+}
+
+fn write_tensor<W: Write, T: NpyElement>(out: &mut W, tensor: TensorView<T>) -> io::Result<()> {
+    write_header(out, T::DESCR, tensor.shape())?;
+    for &x in tensor.iter() {
+        x.write_le(out)?;
+    }
+    Ok(())
+}
+
+/// Write a tensor to `out` in `.npy` format.
+pub fn write_npy<W: Write>(out: &mut W, tensor: &Output) -> io::Result<()> {
+    match tensor {
+        Output::FloatTensor(t) => write_tensor(out, t.view()),
+        Output::IntTensor(t) => write_tensor(out, t.view()),
+        Output::Int64Tensor(t) => write_tensor(out, t.view()),
+        Output::BoolTensor(t) => write_tensor(out, t.view()),
+        Output::UInt8Tensor(t) => write_tensor(out, t.view()),
+    }
+}
+
+/// Write a tensor to a new `.npy` file at `path`.
+pub fn write_npy_file(path: &Path, tensor: &Output) -> io::Result<()> {
+    let mut out = BufWriter::new(File::create(path)?);
+    write_npy(&mut out, tensor)?;
+    out.flush()
+}
+
+/// Return the stem of the `.npy` file name used for a value with a given
+/// node name.
+///
+/// Characters other than ASCII alphanumerics, `-`, `_` and `.` are replaced
+/// with `_`. This can be used to look up values in the map returned by
+/// [load_reference_dir](crate::load_reference_dir).
+pub fn npy_file_stem(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' | '.' => c,
+            _ => '_',
+        })
+        .collect()
+}
+
+fn invalid_data(msg: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg.into())
+}
+
+/// Find the value for `key` in an `.npy` header, which is formatted as a
+/// Python dict literal.
+fn header_value<'a>(header: &'a str, key: &str) -> Option<&'a str> {
+    let key_pos = header.find(&format!("'{}':", key))?;
+    let rest = header[key_pos + key.len() + 3..].trim_start();
+    let end = if rest.starts_with('(') {
+        rest.find(')')? + 1
+    } else {
+        rest.find([',', '}'])?
+    };
+    Some(rest[..end].trim())
+}
+
+/// Read `len` elements of `N` bytes each and convert them using `convert`.
+///
+/// `len` comes from the file header, so the buffer grows as data is read
+/// instead of being allocated upfront. This avoids a huge allocation if the
+/// header claims more data than the file contains.
+fn read_elements<R: Read, T, const N: usize>(
+    input: &mut R,
+    len: usize,
+    convert: impl Fn([u8; N]) -> T,
+) -> io::Result<Vec<T>> {
+    let n_bytes = len
+        .checked_mul(N)
+        .ok_or_else(|| invalid_data("array size is too large"))?;
+    let mut bytes = Vec::new();
+    input.take(n_bytes as u64).read_to_end(&mut bytes)?;
+    if bytes.len() != n_bytes {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "array data is shorter than the size in the header",
+        ));
+    }
+    Ok(bytes
+        .chunks_exact(N)
+        .map(|chunk| convert(chunk.try_into().unwrap()))
+        .collect())
+}
+
+/// Read a tensor in `.npy` format from `input`.
+///
+/// Supported element types are `f4`, `f8` (converted to `f32`), `i4`, `i8`,
+/// `b1` and `u1`. Arrays must be little-endian and in C order.
+pub fn read_npy<R: Read>(input: &mut R) -> io::Result<Output> {
+    let mut prefix = [0u8; 8];
+    input.read_exact(&mut prefix)?;
+    if &prefix[..6] != b"\x93NUMPY" {
+        return Err(invalid_data("not an .npy file"));
+    }
+    let header_len = match prefix[6] {
+        1 => {
+            let mut len = [0u8; 2];
+            input.read_exact(&mut len)?;
+            u16::from_le_bytes(len) as usize
+        }
+        2 | 3 => {
+            let mut len = [0u8; 4];
+            input.read_exact(&mut len)?;
+            u32::from_le_bytes(len) as usize
+        }
+        version => return Err(invalid_data(format!("unsupported version {}", version))),
+    };
+    let mut header = vec![0; header_len];
+    input.read_exact(&mut header)?;
+    let header = String::from_utf8(header).map_err(|_| invalid_data("header is not UTF-8"))?;
+
+    let descr = header_value(&header, "descr")
+        .ok_or_else(|| invalid_data("missing descr"))?
+        .trim_matches('\'');
+    if header_value(&header, "fortran_order") != Some("False") {
+        return Err(invalid_data("Fortran-order arrays are not supported"));
+    }
+    let shape: Vec<usize> = header_value(&header, "shape")
+        .ok_or_else(|| invalid_data("missing shape"))?
+        .trim_matches(|c| c == '(' || c == ')')
+        .split(',')
+        .map(|dim| dim.trim())
+        .filter(|dim| !dim.is_empty())
+        .map(|dim| dim.parse().map_err(|_| invalid_data("invalid shape")))
+        .collect::<Result<_, _>>()?;
+    let len = shape
+        .iter()
+        .try_fold(1usize, |len, &dim| len.checked_mul(dim))
+        .ok_or_else(|| invalid_data("array size is too large"))?;
+
+    let output = match descr {
+        "<f4" => Tensor::from_data(&shape, read_elements(input, len, f32::from_le_bytes)?).into(),
+        "<f8" => Tensor::from_data(
+            &shape,
+            read_elements(input, len, |x| f64::from_le_bytes(x) as f32)?,
+        )
+        .into(),
+        "<i4" => Tensor::from_data(&shape, read_elements(input, len, i32::from_le_bytes)?).into(),
+        "<i8" => Tensor::from_data(&shape, read_elements(input, len, i64::from_le_bytes)?).into(),
+        "|b1" => Tensor::from_data(&shape, read_elements(input, len, |[x]| x != 0)?).into(),
+        "|u1" => Tensor::from_data(&shape, read_elements(input, len, |[x]| x)?).into(),
+        _ => return Err(invalid_data(format!("unsupported dtype {}", descr))),
+    };
+    Ok(output)
+}
+
+/// Read a tensor from an `.npy` file at `path`.
+pub fn read_npy_file(path: &Path) -> io::Result<Output> {
+    let mut input = BufReader::new(File::open(path)?);
+    read_npy(&mut input)
+}
+
+#[cfg(test)]
+mod tests {
+    use rten_tensor::{tensor, Tensor};
+
+    use super::{npy_file_stem, read_npy, write_npy};
+    use crate::ops::Output;
+
+    /// Split an `.npy` file into its header and data.
+    fn split_npy(buf: &[u8]) -> (&str, &[u8]) {
+        assert_eq!(&buf[..8], b"\x93NUMPY\x01\x00");
+        let header_len = u16::from_le_bytes([buf[8], buf[9]]) as usize;
+        let data_offset = 10 + header_len;
+        assert_eq!(data_offset % 64, 0);
+        let header = std::str::from_utf8(&buf[10..data_offset]).unwrap();
+        (header, &buf[data_offset..])
+    }
+
+    #[test]
+    fn test_write_npy() {
+        let float_tensor: Output = tensor!((2, 2); [1., 2., 3., 4.]).into();
+        let mut buf = Vec::new();
+        write_npy(&mut buf, &float_tensor).unwrap();
+        let (header, data) = split_npy(&buf);
+        assert_eq!(
+            header.trim_end(),
+            "{'descr': '<f4', 'fortran_order': False, 'shape': (2, 2), }"
+        );
+        assert!(header.ends_with('\n'));
+        let expected_data: Vec<u8> = [1f32, 2., 3., 4.]
+            .iter()
+            .flat_map(|x| x.to_le_bytes())
+            .collect();
+        assert_eq!(data, expected_data);
+
+        // Vector. One-element tuples need a trailing comma.
+        let int_tensor: Output = tensor!([-1, 2]).into();
+        let mut buf = Vec::new();
+        write_npy(&mut buf, &int_tensor).unwrap();
+        let (header, data) = split_npy(&buf);
+        assert!(header.starts_with("{'descr': '<i4', 'fortran_order': False, 'shape': (2,), }"));
+        assert_eq!(data, [255, 255, 255, 255, 2, 0, 0, 0]);
+
+        // Scalar
+        let bool_tensor: Output = Tensor::from_scalar(true).into();
+        let mut buf = Vec::new();
+        write_npy(&mut buf, &bool_tensor).unwrap();
+        let (header, data) = split_npy(&buf);
+        assert!(header.starts_with("{'descr': '|b1', 'fortran_order': False, 'shape': (), }"));
+        assert_eq!(data, [1]);
+    }
+
+    #[test]
+    fn test_read_npy() {
+        let values: [Output; 4] = [
+            tensor!((2, 2); [1., 2., 3., 4.]).into(),
+            tensor!([1i64, -2, 3]).into(),
+            Tensor::from_scalar(true).into(),
+            Tensor::<u8>::zeros(&[0, 3]).into(),
+        ];
+        for value in values {
+            let mut buf = Vec::new();
+            write_npy(&mut buf, &value).unwrap();
+            let read_value = read_npy(&mut buf.as_slice()).unwrap();
+            assert_eq!(read_value, value);
+        }
+
+        // Header as written by NumPy for a float64 array.
+        let mut buf = b"\x93NUMPY\x01\x00".to_vec();
+        let header = "{'descr': '<f8', 'fortran_order': False, 'shape': (2,), }";
+        buf.extend((header.len() as u16).to_le_bytes());
+        buf.extend(header.as_bytes());
+        buf.extend([0.5f64, -1.].iter().flat_map(|x| x.to_le_bytes()));
+        let read_value = read_npy(&mut buf.as_slice()).unwrap();
+        assert_eq!(read_value, tensor!([0.5, -1.]).into());
+
+        // Unsupported types and truncated data.
+        let mut invalid_buf = buf.clone();
+        let descr_pos = invalid_buf.windows(3).position(|w| w == b"<f8").unwrap();
+        invalid_buf[descr_pos] = b'>';
+        let err = read_npy(&mut invalid_buf.as_slice()).err().unwrap();
+        assert_eq!(err.to_string(), "unsupported dtype >f8");
+        let err = read_npy(&mut &buf[..buf.len() - 1]).err().unwrap();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+
+        // Headers with sizes that overflow, or exceed the available data.
+        let npy_with_shape = |shape: &str| {
+            let mut buf = b"\x93NUMPY\x01\x00".to_vec();
+            let header = format!(
+                "{{'descr': '<f4', 'fortran_order': False, 'shape': {}, }}",
+                shape
+            );
+            buf.extend((header.len() as u16).to_le_bytes());
+            buf.extend(header.as_bytes());
+            buf.extend([0u8; 16]);
+            buf
+        };
+        for shape in [
+            format!("({}, {})", usize::MAX, 2),
+            format!("({},)", usize::MAX / 2),
+        ] {
+            let buf = npy_with_shape(&shape);
+            let err = read_npy(&mut buf.as_slice()).err().unwrap();
+            assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+            assert_eq!(err.to_string(), "array size is too large");
+        }
+        let buf = npy_with_shape("(1000000000000,)");
+        let err = read_npy(&mut buf.as_slice()).err().unwrap();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_npy_file_stem() {
+        assert_eq!(npy_file_stem("conv_1.out"), "conv_1.out");
+        assert_eq!(
+            npy_file_stem("/encoder/layer.0/Add:0"),
+            "_encoder_layer.0_Add_0"
+        );
+    }
+}
@@ -150,9 +150,107 @@ macro_rules! impl_fastdiv {
 // unsigned type.
 impl_fastdiv!(usize);
 
+/// Convert an IEEE 754 half-precision float, given as raw bits, to an f32.
+///
+/// The conversion is exact, since every f16 value is representable as an f32.
+pub fn f16_to_f32(bits: u16) -> f32 {
+    let sign = ((bits & 0x8000) as u32) << 16;
+    let exp = ((bits >> 10) & 0x1f) as u32;
+    let mant = (bits & 0x3ff) as u32;
+
+    let f32_bits = match (exp, mant) {
+        // Signed zero
+        (0, 0) => sign,
+        // Subnormal. The value is `mant * 2^-24`, which is a normal f32.
+        (0, _) => {
+            let val = mant as f32 * (1.0 / (1 << 24) as f32);
+            return if sign != 0 { -val } else { val };
+        }
+        // Infinity
+        (0x1f, 0) => sign | 0x7f80_0000,
+        // NaN. Preserve the payload and make sure the result is quiet.
+        (0x1f, _) => sign | 0x7fc0_0000 | (mant << 13),
+        // Normal. Re-bias the exponent from 15 to 127.
+        _ => sign | ((exp + 112) << 23) | (mant << 13),
+    };
+    f32::from_bits(f32_bits)
+}
+
+/// Convert an f32 to an IEEE 754 half-precision float, returned as raw bits.
+///
+/// Values are rounded to the nearest representable value, with ties rounded
+/// to even. Values which are too large become infinities and values which are
+/// too small become (signed) zero.
+pub fn f32_to_f16(val: f32) -> u16 {
+    let bits = val.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exp = ((bits >> 23) & 0xff) as i32;
+    let mant = bits & 0x7f_ffff;
+
+    if exp == 0xff {
+        let nan_bits = if mant != 0 {
+            0x200 | (mant >> 13) as u16
+        } else {
+            0
+        };
+        return sign | 0x7c00 | nan_bits;
+    }
+
+    // Round `mant >> shift` to nearest, ties to even.
+    let round_shift = |mant: u32, shift: u32| -> u32 {
+        let truncated = mant >> shift;
+        let rem = mant & ((1 << shift) - 1);
+        let halfway = 1 << (shift - 1);
+        if rem > halfway || (rem == halfway && truncated & 1 == 1) {
+            truncated + 1
+        } else {
+            truncated
+        }
+    };
+
+    let half_exp = exp - 127 + 15;
+    if half_exp >= 0x1f {
+        // Overflow
+        sign | 0x7c00
+    } else if half_exp <= 0 {
+        // Subnormal or zero in f16. Include the implicit leading bit and
+        // express the value in units of 2^-24.
+        if half_exp < -10 {
+            return sign;
+        }
+        let shift = (14 - half_exp) as u32;
+        sign | round_shift(mant | 0x80_0000, shift) as u16
+    } else {
+        // Normal. If rounding overflows the mantissa, the carry increments the
+        // exponent, which is the correct result (including overflow to
+        // infinity).
+        let half_bits = ((half_exp as u32) << 10) + round_shift(mant, 13);
+        sign | half_bits as u16
+    }
+}
+
+/// Convert a bfloat16 value, given as raw bits, to an f32.
+pub fn bf16_to_f32(bits: u16) -> f32 {
+    f32::from_bits((bits as u32) << 16)
+}
+
+/// Convert an f32 to a bfloat16 value, returned as raw bits.
+///
+/// Values are rounded to the nearest representable value, with ties rounded
+/// to even.
+pub fn f32_to_bf16(val: f32) -> u16 {
+    let bits = val.to_bits();
+    if val.is_nan() {
+        // Make sure the NaN stays a NaN after truncating the mantissa.
+        return ((bits >> 16) as u16) | 0x40;
+    }
+    let rounding_bias = 0x7fff + ((bits >> 16) & 1);
+    ((bits + rounding_bias) >> 16) as u16
+}
+
 #[cfg(test)]
 mod tests {
-    use super::FastDiv;
+    use super::{bf16_to_f32, f16_to_f32, f32_to_bf16, f32_to_f16, FastDiv};
 
     #[test]
     fn test_fast_div_divide() {
@@ -203,4 +301,79 @@ mod tests {
         test(2);
         test(8);
     }
+
+    #[test]
+    fn test_f16_conversion() {
+        // (f32 value, f16 bits)
+        let cases = [
+            (0., 0x0000),
+            (-0., 0x8000),
+            (1., 0x3c00),
+            (-2., 0xc000),
+            (0.5, 0x3800),
+            (65504., 0x7bff),
+            (f32::INFINITY, 0x7c00),
+            (f32::NEG_INFINITY, 0xfc00),
+            // Smallest positive subnormal
+            (5.960_464_5e-8, 0x0001),
+            // Largest subnormal
+            (6.097_555e-5, 0x03ff),
+            // Smallest positive normal
+            (6.103_515_6e-5, 0x0400),
+        ];
+
+        for (val, bits) in cases {
+            assert_eq!(f32_to_f16(val), bits, "f32_to_f16({})", val);
+            assert_eq!(f16_to_f32(bits), val, "f16_to_f32({:#x})", bits);
+        }
+
+        // Values that round to nearest, ties to even.
+        assert_eq!(f32_to_f16(1. + 1. / 2048.), 0x3c00);
+        assert_eq!(f32_to_f16(1. + 3. / 2048.), 0x3c02);
+        assert_eq!(f32_to_f16(1. + 1.5 / 1024.), 0x3c02);
+
+        // Overflow and underflow
+        assert_eq!(f32_to_f16(1e6), 0x7c00);
+        assert_eq!(f32_to_f16(65520.), 0x7c00);
+        assert_eq!(f32_to_f16(1e-10), 0x0000);
+        assert_eq!(f32_to_f16(-1e-10), 0x8000);
+
+        // NaN
+        assert!(f16_to_f32(f32_to_f16(f32::NAN)).is_nan());
+        assert!(f16_to_f32(0x7e00).is_nan());
+
+        // All finite f16 values should round-trip.
+        for bits in 0..=u16::MAX {
+            let val = f16_to_f32(bits);
+            if val.is_finite() {
+                assert_eq!(f32_to_f16(val), bits);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bf16_conversion() {
+        let cases = [
+            (0., 0x0000),
+            (-0., 0x8000),
+            (1., 0x3f80),
+            (-2., 0xc000),
+            (f32::INFINITY, 0x7f80),
+        ];
+        for (val, bits) in cases {
+            assert_eq!(f32_to_bf16(val), bits, "f32_to_bf16({})", val);
+            assert_eq!(bf16_to_f32(bits), val, "bf16_to_f32({:#x})", bits);
+        }
+
+        // Round to nearest, ties to even.
+        assert_eq!(f32_to_bf16(f32::from_bits(0x3f80_8000)), 0x3f80);
+        assert_eq!(f32_to_bf16(f32::from_bits(0x3f81_8000)), 0x3f82);
+        assert_eq!(f32_to_bf16(f32::from_bits(0x3f80_8001)), 0x3f81);
+
+        // Rounding up the largest finite values overflows to infinity.
+        assert_eq!(f32_to_bf16(f32::MAX), 0x7f80);
+
+        assert!(bf16_to_f32(f32_to_bf16(f32::NAN)).is_nan());
+        assert!(bf16_to_f32(f32_to_bf16(f32::from_bits(0x7f80_0001))).is_nan());
+    }
 }
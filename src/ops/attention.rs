@@ -0,0 +1,843 @@
+use rayon::prelude::*;
+
+use rten_tensor::prelude::*;
+use rten_tensor::{NdTensor, NdTensorView, Tensor, TensorView};
+use rten_vecmath::vec_softmax_in_place;
+
+use crate::gemm::{GemmExecutor, GemmInputA, GemmInputB};
+use crate::ops::{
+    num_elements, Input, InputList, IntoOpResult, MetaInputList, MetaOutput, OpError, Operator,
+    Output,
+};
+
+/// Number of query rows processed together in each block.
+const Q_BLOCK_SIZE: usize = 64;
+
+/// Number of keys processed together in each block.
+const KV_BLOCK_SIZE: usize = 256;
+
+/// Options for [attention].
+#[derive(Clone, Debug, Default)]
+pub struct AttentionOptions {
+    /// Number of query heads. Required if the inputs have shape
+    /// `[batch, seq, hidden]`, in which case the last dimension is split into
+    /// heads.
+    pub num_heads: Option<usize>,
+
+    /// Number of key and value heads, for inputs with shape
+    /// `[batch, seq, hidden]`. Defaults to `num_heads`.
+    pub kv_num_heads: Option<usize>,
+
+    /// Prevent query position `i` from attending to key positions `j > i`.
+    pub is_causal: bool,
+
+    /// Scale applied to query-key products. Defaults to `1 / sqrt(head_size)`.
+    pub scale: Option<f32>,
+}
+
+/// Convert a boolean or int mask, where true / non-zero values indicate
+/// positions that can be attended to, into an additive mask.
+fn additive_mask(mask: Input) -> Result<Tensor, OpError> {
+    let to_additive = |attend: bool| if attend { 0. } else { f32::NEG_INFINITY };
+    match mask {
+        Input::IntTensor(mask) => Ok(mask.map(|x| to_additive(*x != 0))),
+        Input::BoolTensor(mask) => Ok(mask.map(|x| to_additive(*x))),
+        _ => Err(OpError::IncorrectInputType),
+    }
+}
+
+/// View a `[batch, seq, heads * head_size]` tensor as
+/// `[batch, heads, seq, head_size]`.
+fn split_heads<'a>(
+    x: &'a NdTensor<f32, 3>,
+    num_heads: usize,
+) -> Result<NdTensorView<'a, f32, 4>, OpError> {
+    let [batch, seq, hidden] = x.shape();
+    if num_heads == 0 || hidden % num_heads != 0 {
+        return Err(OpError::InvalidValue(
+            "hidden size must be a multiple of the number of heads",
+        ));
+    }
+    Ok(x.reshaped([batch, seq, num_heads, hidden / num_heads])
+        .permuted([0, 2, 1, 3]))
+}
+
+/// Compute scaled dot-product attention.
+///
+/// `query`, `key` and `value` have shapes `[batch, q_heads, q_seq, head_size]`,
+/// `[batch, kv_heads, kv_seq, head_size]` and `[batch, kv_heads, kv_seq, v_head_size]`.
+/// Alternatively they can have shape `[batch, seq, heads * head_size]`, with
+/// the number of heads specified in `opts`. The output has shape
+/// `[batch, q_heads, q_seq, v_head_size]` or `[batch, q_seq, q_heads * v_head_size]`
+/// respectively. If `q_heads > kv_heads`, each group of `q_heads / kv_heads`
+/// query heads shares a key and value head (grouped-query attention).
+///
+/// `attn_mask` is broadcast to `[batch, q_heads, q_seq, kv_seq]`. A float
+/// mask is added to the scores. For a boolean or int mask, true / non-zero
+/// values indicate positions that can be attended to.
+///
+/// `key_padding_mask` is a boolean or int mask with shape `[batch, kv_seq]`,
+/// where zero / false values indicate padding positions in the keys.
+///
+/// The output is computed blockwise, so the full
+/// `[batch, q_heads, q_seq, kv_seq]` score matrix is never materialized.
+/// Query positions which cannot attend to any key produce zeros.
+pub fn attention(
+    query: TensorView,
+    key: TensorView,
+    value: TensorView,
+    attn_mask: Option<Input>,
+    key_padding_mask: Option<Input>,
+    opts: &AttentionOptions,
+) -> Result<Tensor, OpError> {
+    if key.ndim() != query.ndim() || value.ndim() != query.ndim() {
+        return Err(OpError::IncompatibleInputShapes(
+            "query, key and value must have the same number of dims",
+        ));
+    }
+
+    // Inputs in `[batch, seq, hidden]` form are viewed as
+    // `[batch, heads, seq, head_size]`.
+    let packed_heads = match query.ndim() {
+        3 => {
+            let num_heads = opts
+                .num_heads
+                .ok_or(OpError::InvalidValue("num_heads must be set for 3D inputs"))?;
+            let kv_num_heads = opts.kv_num_heads.unwrap_or(num_heads);
+            Some((num_heads, kv_num_heads))
+        }
+        4 => None,
+        _ => {
+            return Err(OpError::InvalidValue(
+                "query, key and value must have 3 or 4 dims",
+            ))
+        }
+    };
+    let packed_inputs = packed_heads.map(|_| {
+        (
+            query.nd_view::<3>().to_tensor(),
+            key.nd_view::<3>().to_tensor(),
+            value.nd_view::<3>().to_tensor(),
+        )
+    });
+    let (query, key, value) = match (&packed_inputs, packed_heads) {
+        (Some((query, key, value)), Some((num_heads, kv_num_heads))) => (
+            split_heads(query, num_heads)?,
+            split_heads(key, kv_num_heads)?,
+            split_heads(value, kv_num_heads)?,
+        ),
+        _ => (query.nd_view(), key.nd_view(), value.nd_view()),
+    };
+
+    let [batch, q_heads, q_seq, head_size] = query.shape();
+    let [k_batch, kv_heads, kv_seq, k_head_size] = key.shape();
+    let [v_batch, v_heads, v_seq, v_head_size] = value.shape();
+
+    if k_batch != batch || v_batch != batch {
+        return Err(OpError::IncompatibleInputShapes(
+            "query, key and value must have the same batch size",
+        ));
+    }
+    if k_head_size != head_size {
+        return Err(OpError::IncompatibleInputShapes(
+            "query and key must have the same head size",
+        ));
+    }
+    if v_heads != kv_heads || v_seq != kv_seq {
+        return Err(OpError::IncompatibleInputShapes(
+            "key and value must have the same number of heads and sequence length",
+        ));
+    }
+    if kv_heads == 0 || q_heads % kv_heads != 0 {
+        return Err(OpError::IncompatibleInputShapes(
+            "query heads must be a multiple of key/value heads",
+        ));
+    }
+
+    // Convert masks to additive form. Float masks are used as-is.
+    let scores_shape = [batch, q_heads, q_seq, kv_seq];
+    let mut owned_masks = Vec::new();
+    let mut float_mask = None;
+    match attn_mask {
+        Some(Input::FloatTensor(mask)) => float_mask = Some(mask),
+        Some(mask) => owned_masks.push(additive_mask(mask)?),
+        None => {}
+    }
+    if let Some(mask) = key_padding_mask {
+        if mask.shape() != [batch, kv_seq] {
+            return Err(OpError::IncompatibleInputShapes(
+                "key padding mask must have shape [batch, kv_seq]",
+            ));
+        }
+        let mut mask = additive_mask(mask)?;
+        mask.reshape(&[batch, 1, 1, kv_seq]);
+        owned_masks.push(mask);
+    }
+    let masks: Vec<NdTensorView<f32, 4>> = float_mask
+        .into_iter()
+        .chain(owned_masks.iter().map(|m| m.view()))
+        .map(|mask| {
+            if !mask.can_broadcast_to(&scores_shape) {
+                return Err(OpError::IncompatibleInputShapes(
+                    "attention mask cannot be broadcast to scores shape",
+                ));
+            }
+            Ok(mask.broadcast(scores_shape))
+        })
+        .collect::<Result<_, _>>()?;
+
+    let scale = opts.scale.unwrap_or_else(|| 1. / (head_size as f32).sqrt());
+
+    let mut output = NdTensor::zeros([batch, q_heads, q_seq, v_head_size]);
+    let gemm = GemmExecutor::new();
+    let q_heads_per_kv_head = q_heads / kv_heads;
+
+    if !output.is_empty() {
+        output
+            .data_mut()
+            .unwrap()
+            .par_chunks_mut(q_seq * v_head_size)
+            .enumerate()
+            .for_each(|(batch_head, out_head)| {
+                let n = batch_head / q_heads;
+                let q_head = batch_head % q_heads;
+                let kv_head = q_head / q_heads_per_kv_head;
+
+                out_head
+                    .par_chunks_mut(Q_BLOCK_SIZE * v_head_size)
+                    .enumerate()
+                    .for_each(|(q_block, out_block)| {
+                        let q_start = q_block * Q_BLOCK_SIZE;
+                        let q_len = out_block.len() / v_head_size;
+                        attention_block(
+                            &gemm,
+                            out_block,
+                            query.slice::<2, _>((n, q_head, q_start..q_start + q_len)),
+                            key.slice::<2, _>((n, kv_head)),
+                            value.slice::<2, _>((n, kv_head)),
+                            &masks,
+                            [n, q_head, q_start],
+                            opts.is_causal,
+                            scale,
+                        );
+                    });
+            });
+    }
+
+    let output = match packed_heads {
+        Some(_) => output
+            .permuted([0, 2, 1, 3])
+            .to_tensor()
+            .into_shape([batch, q_seq, q_heads * v_head_size])
+            .into_dyn(),
+        None => output.into_dyn(),
+    };
+    Ok(output)
+}
+
+/// Compute the attention output for a block of query positions.
+///
+/// `output` is a `[q_len, v_head_size]` buffer, `query` has shape
+/// `[q_len, head_size]`, and `key` and `value` have shape `[kv_seq, head_size]`
+/// and `[kv_seq, v_head_size]`. `q_pos` is the `(batch, head, seq)` index of
+/// the first query in the block, used to look up mask values.
+///
+/// Keys are processed in blocks. The softmax of the scores for each block is
+/// computed with `vec_softmax_in_place` and merged with the results of
+/// previous blocks using the online softmax algorithm from FlashAttention
+/// (https://arxiv.org/abs/2205.14135).
+#[allow(clippy::too_many_arguments)]
+fn attention_block(
+    gemm: &GemmExecutor,
+    output: &mut [f32],
+    query: NdTensorView<f32, 2>,
+    key: NdTensorView<f32, 2>,
+    value: NdTensorView<f32, 2>,
+    masks: &[NdTensorView<f32, 4>],
+    q_pos: [usize; 3],
+    is_causal: bool,
+    scale: f32,
+) {
+    let [n, head, q_start] = q_pos;
+    let [q_len, _] = query.shape();
+    let [kv_seq, v_head_size] = value.shape();
+
+    // With a causal mask, keys after the last query in the block are never
+    // attended to.
+    let kv_end = if is_causal {
+        (q_start + q_len).min(kv_seq)
+    } else {
+        kv_seq
+    };
+
+    // Running max and sum of `exp(score - max)` for each query.
+    let mut row_max = vec![f32::NEG_INFINITY; q_len];
+    let mut row_sum = vec![0.; q_len];
+    let mut scores = vec![0.; q_len * KV_BLOCK_SIZE.min(kv_end)];
+    output.fill(0.);
+
+    for kv_start in (0..kv_end).step_by(KV_BLOCK_SIZE) {
+        let kv_len = KV_BLOCK_SIZE.min(kv_end - kv_start);
+        let scores = &mut scores[..q_len * kv_len];
+
+        gemm.gemm(
+            scores,
+            kv_len,
+            GemmInputA::Unpacked(query),
+            GemmInputB::Unpacked(key.slice::<2, _>(kv_start..kv_start + kv_len).transposed()),
+            scale, /* alpha */
+            0.,    /* beta */
+        );
+
+        for (i, (scores, (out, (max, sum)))) in scores
+            .chunks_mut(kv_len)
+            .zip(
+                output
+                    .chunks_mut(v_head_size)
+                    .zip(row_max.iter_mut().zip(row_sum.iter_mut())),
+            )
+            .enumerate()
+        {
+            let q_pos = q_start + i;
+            for mask in masks {
+                let mask = mask.slice::<1, _>((n, head, q_pos, kv_start..kv_start + kv_len));
+                for (score, mask) in scores.iter_mut().zip(mask.iter()) {
+                    *score += mask;
+                }
+            }
+            if is_causal {
+                let first_masked = (q_pos + 1).saturating_sub(kv_start).min(kv_len);
+                scores[first_masked..].fill(f32::NEG_INFINITY);
+            }
+
+            let (block_argmax, block_max) = scores.iter().copied().enumerate().fold(
+                (0, f32::NEG_INFINITY),
+                |(max_idx, max), (idx, x)| if x > max { (idx, x) } else { (max_idx, max) },
+            );
+            if block_max == f32::NEG_INFINITY {
+                // All keys in this block are masked.
+                scores.fill(0.);
+                continue;
+            }
+
+            // After the softmax, the score for the max element is
+            // `exp(0) / block_sum`.
+            vec_softmax_in_place(scores);
+            let block_sum = 1. / scores[block_argmax];
+
+            // Rescale the previous output and this block's probabilities to
+            // use the new running max.
+            let new_max = max.max(block_max);
+            let prev_scale = (*max - new_max).exp();
+            let block_scale = (block_max - new_max).exp() * block_sum;
+            for x in out.iter_mut() {
+                *x *= prev_scale;
+            }
+            for x in scores.iter_mut() {
+                *x *= block_scale;
+            }
+            *sum = *sum * prev_scale + block_scale;
+            *max = new_max;
+        }
+
+        // Accumulate `probs @ value` for this block.
+        gemm.gemm(
+            output,
+            v_head_size,
+            GemmInputA::Unpacked(NdTensorView::from_data([q_len, kv_len], &scores[..])),
+            GemmInputB::Unpacked(value.slice::<2, _>(kv_start..kv_start + kv_len)),
+            1., /* alpha */
+            1., /* beta */
+        );
+    }
+
+    for (out, sum) in output.chunks_mut(v_head_size).zip(row_sum) {
+        if sum > 0. {
+            for x in out.iter_mut() {
+                *x /= sum;
+            }
+        }
+    }
+}
+
+/// Fused scaled dot-product attention.
+///
+/// See [attention] for details of the inputs.
+#[derive(Debug, Default)]
+pub struct Attention {
+    pub num_heads: Option<usize>,
+    pub kv_num_heads: Option<usize>,
+    pub is_causal: bool,
+    pub scale: Option<f32>,
+}
+
+impl Attention {
+    fn options(&self) -> AttentionOptions {
+        AttentionOptions {
+            num_heads: self.num_heads,
+            kv_num_heads: self.kv_num_heads,
+            is_causal: self.is_causal,
+            scale: self.scale,
+        }
+    }
+}
+
+impl Operator for Attention {
+    fn name(&self) -> &str {
+        "Attention"
+    }
+
+    fn run(&self, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let query = inputs.require_as(0)?;
+        let key = inputs.require_as(1)?;
+        let value = inputs.require_as(2)?;
+        let attn_mask = inputs.get(3);
+        let key_padding_mask = inputs.get(4);
+
+        attention(
+            query,
+            key,
+            value,
+            attn_mask,
+            key_padding_mask,
+            &self.options(),
+        )
+        .into_op_result()
+    }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], output_shapes: &[&[usize]]) -> Option<u64> {
+        // Dominated by the `query @ key^T` and `probs @ value` products.
+        let query_shape = input_shapes.first().copied().flatten()?;
+        let key_shape = input_shapes.get(1).copied().flatten()?;
+        let kv_seq = *key_shape.get(key_shape.len().checked_sub(2)?)?;
+        let output_len = num_elements(output_shapes.first()?);
+        Some(2 * kv_seq as u64 * (num_elements(query_shape) + output_len))
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| {
+            let query = inputs.require_shape(0)?;
+            let value = inputs.require_shape(2)?;
+            match (query, value) {
+                (&[batch, q_heads, q_seq, _], &[_, _, _, v_head_size]) => {
+                    Ok(vec![vec![batch, q_heads, q_seq, v_head_size].into()])
+                }
+                (&[batch, q_seq, _], &[_, _, v_hidden]) => {
+                    let options = self.options();
+                    let num_heads = options
+                        .num_heads
+                        .ok_or(OpError::InvalidValue("num_heads must be set for 3D inputs"))?;
+                    let kv_num_heads = options.kv_num_heads.unwrap_or(num_heads);
+                    if kv_num_heads == 0 || v_hidden % kv_num_heads != 0 {
+                        return Err(OpError::InvalidValue(
+                            "hidden size must be a multiple of the number of heads",
+                        ));
+                    }
+                    let v_head_size = v_hidden / kv_num_heads;
+                    Ok(vec![vec![batch, q_seq, num_heads * v_head_size].into()])
+                }
+                _ => Err(OpError::InvalidValue(
+                    "query, key and value must have 3 or 4 dims",
+                )),
+            }
+        })())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use rten_tensor::prelude::*;
+    use rten_tensor::rng::XorShiftRng;
+    use rten_tensor::test_util::expect_equal_with_tolerance;
+    use rten_tensor::{NdTensor, Tensor, TensorView};
+
+    use super::{attention, Attention, AttentionOptions, Q_BLOCK_SIZE};
+    use crate::ops::{add, Input, MetaInput, MetaInputList, OpError, Operator};
+
+    /// Naive implementation of attention for `[batch, heads, seq, head_size]`
+    /// inputs, which materializes the full score matrix.
+    fn reference_attention(
+        query: &NdTensor<f32, 4>,
+        key: &NdTensor<f32, 4>,
+        value: &NdTensor<f32, 4>,
+        mask: Option<&Tensor>,
+        is_causal: bool,
+        scale: f32,
+    ) -> NdTensor<f32, 4> {
+        let [batch, q_heads, q_seq, head_size] = query.shape();
+        let [_, kv_heads, kv_seq, _] = key.shape();
+        let v_head_size = value.size(3);
+        let mask = mask.map(|m| m.broadcast([batch, q_heads, q_seq, kv_seq]));
+
+        let mut output = NdTensor::zeros([batch, q_heads, q_seq, v_head_size]);
+        for n in 0..batch {
+            for h in 0..q_heads {
+                let kv_h = h / (q_heads / kv_heads);
+                for i in 0..q_seq {
+                    let mut scores: Vec<f32> = (0..kv_seq)
+                        .map(|j| {
+                            let dot: f32 = (0..head_size)
+                                .map(|d| query[[n, h, i, d]] * key[[n, kv_h, j, d]])
+                                .sum();
+                            let mut score = dot * scale;
+                            if let Some(mask) = &mask {
+                                score += mask[[n, h, i, j]];
+                            }
+                            if is_causal && j > i {
+                                score = f32::NEG_INFINITY;
+                            }
+                            score
+                        })
+                        .collect();
+
+                    let max = scores.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+                    if max == f32::NEG_INFINITY {
+                        continue;
+                    }
+                    let mut sum = 0.;
+                    for x in scores.iter_mut() {
+                        *x = (*x - max).exp();
+                        sum += *x;
+                    }
+
+                    for d in 0..v_head_size {
+                        output[[n, h, i, d]] = (0..kv_seq)
+                            .map(|j| scores[j] / sum * value[[n, kv_h, j, d]])
+                            .sum();
+                    }
+                }
+            }
+        }
+        output
+    }
+
+    fn rand(shape: [usize; 4], rng: &mut XorShiftRng) -> NdTensor<f32, 4> {
+        NdTensor::rand(shape, rng).map(|x| x - 0.5)
+    }
+
+    #[test]
+    fn test_attention() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+
+        #[derive(Debug)]
+        struct Case {
+            batch: usize,
+            q_heads: usize,
+            kv_heads: usize,
+            q_seq: usize,
+            kv_seq: usize,
+            is_causal: bool,
+            scale: Option<f32>,
+        }
+
+        let case = |q_seq, kv_seq| Case {
+            batch: 2,
+            q_heads: 2,
+            kv_heads: 2,
+            q_seq,
+            kv_seq,
+            is_causal: false,
+            scale: None,
+        };
+
+        let cases = [
+            // Single query and key block
+            case(5, 7),
+            // Multiple query and key blocks
+            case(Q_BLOCK_SIZE + 3, 300),
+            // Self-attention with causal mask
+            Case {
+                is_causal: true,
+                ..case(10, 10)
+            },
+            Case {
+                is_causal: true,
+                ..case(Q_BLOCK_SIZE * 2, Q_BLOCK_SIZE * 2)
+            },
+            // Causal mask with more queries than keys.
+            Case {
+                is_causal: true,
+                ..case(10, 4)
+            },
+            // Grouped-query attention
+            Case {
+                q_heads: 4,
+                kv_heads: 2,
+                ..case(5, 7)
+            },
+            // Custom scale
+            Case {
+                scale: Some(0.5),
+                ..case(5, 7)
+            },
+            // Empty sequence
+            case(0, 7),
+        ];
+
+        for case in cases {
+            let head_size = 8;
+            let v_head_size = 6;
+            let query = rand([case.batch, case.q_heads, case.q_seq, head_size], &mut rng);
+            let key = rand(
+                [case.batch, case.kv_heads, case.kv_seq, head_size],
+                &mut rng,
+            );
+            let value = rand(
+                [case.batch, case.kv_heads, case.kv_seq, v_head_size],
+                &mut rng,
+            );
+            let opts = AttentionOptions {
+                is_causal: case.is_causal,
+                scale: case.scale,
+                ..Default::default()
+            };
+
+            let result = attention(
+                query.as_dyn(),
+                key.as_dyn(),
+                value.as_dyn(),
+                None,
+                None,
+                &opts,
+            )?;
+
+            let scale = case.scale.unwrap_or(1. / (head_size as f32).sqrt());
+            let expected = reference_attention(&query, &key, &value, None, case.is_causal, scale);
+            expect_equal_with_tolerance(&result, &expected.into_dyn(), 1e-5, 0.)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_attention_masks() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+        let [batch, heads, q_seq, kv_seq, head_size] = [2, 3, 4, 300, 8];
+        let query = rand([batch, heads, q_seq, head_size], &mut rng);
+        let key = rand([batch, heads, kv_seq, head_size], &mut rng);
+        let value = rand([batch, heads, kv_seq, head_size], &mut rng);
+        let scale = 1. / (head_size as f32).sqrt();
+        let opts = AttentionOptions::default();
+
+        let bool_mask = Tensor::from_data(
+            &[batch, 1, 1, kv_seq],
+            (0..batch * kv_seq)
+                .map(|i| i % 3 != 0 && i != 1)
+                .collect::<Vec<_>>(),
+        );
+        let bool_mask_as_float = bool_mask.map(|&x| if x { 0. } else { f32::NEG_INFINITY });
+
+        // Float mask with fewer dims than the scores.
+        let float_mask = Tensor::rand(&[q_seq, kv_seq], &mut rng);
+        let result = attention(
+            query.as_dyn(),
+            key.as_dyn(),
+            value.as_dyn(),
+            Some(float_mask.view().into()),
+            None,
+            &opts,
+        )?;
+        let expected = reference_attention(&query, &key, &value, Some(&float_mask), false, scale);
+        expect_equal_with_tolerance(&result, &expected.into_dyn(), 1e-5, 0.)?;
+
+        // Boolean and int masks.
+        let expected = reference_attention(
+            &query,
+            &key,
+            &value,
+            Some(&bool_mask_as_float),
+            false,
+            scale,
+        )
+        .into_dyn();
+        let int_mask = bool_mask.map(|&x| x as i32);
+        let masks: [Input; 2] = [bool_mask.view().into(), int_mask.view().into()];
+        for mask in masks {
+            let result = attention(
+                query.as_dyn(),
+                key.as_dyn(),
+                value.as_dyn(),
+                Some(mask),
+                None,
+                &opts,
+            )?;
+            expect_equal_with_tolerance(&result, &expected, 1e-5, 0.)?;
+        }
+
+        // Key padding mask, combined with a float mask.
+        let padding_mask = int_mask.clone().into_shape([batch, kv_seq].as_slice());
+        let result = attention(
+            query.as_dyn(),
+            key.as_dyn(),
+            value.as_dyn(),
+            Some(float_mask.view().into()),
+            Some(padding_mask.view().into()),
+            &opts,
+        )?;
+        let combined_mask = add(bool_mask_as_float.view(), float_mask.view())?;
+        let expected =
+            reference_attention(&query, &key, &value, Some(&combined_mask), false, scale);
+        expect_equal_with_tolerance(&result, &expected.into_dyn(), 1e-5, 0.)?;
+
+        // Fully masked rows produce zeros.
+        let zero_mask = Tensor::<i32>::zeros(&[batch, kv_seq]);
+        let result = attention(
+            query.as_dyn(),
+            key.as_dyn(),
+            value.as_dyn(),
+            None,
+            Some(zero_mask.view().into()),
+            &opts,
+        )?;
+        assert!(result.iter().all(|x| *x == 0.));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_attention_packed_heads() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+        let [batch, q_heads, kv_heads, q_seq, kv_seq, head_size] = [2, 4, 2, 5, 7, 8];
+
+        let query = rand([batch, q_heads, q_seq, head_size], &mut rng);
+        let key = rand([batch, kv_heads, kv_seq, head_size], &mut rng);
+        let value = rand([batch, kv_heads, kv_seq, head_size], &mut rng);
+
+        // Convert from `[batch, heads, seq, head_size]` to `[batch, seq, heads * head_size]`.
+        let merge_heads = |x: TensorView| {
+            let [batch, heads, seq, head_size] = x.shape().try_into().unwrap();
+            x.permuted(&[0, 2, 1, 3])
+                .to_tensor()
+                .into_shape([batch, seq, heads * head_size].as_slice())
+        };
+
+        let result = attention(
+            merge_heads(query.as_dyn()).view(),
+            merge_heads(key.as_dyn()).view(),
+            merge_heads(value.as_dyn()).view(),
+            None,
+            None,
+            &AttentionOptions {
+                num_heads: Some(q_heads),
+                kv_num_heads: Some(kv_heads),
+                ..Default::default()
+            },
+        )?;
+
+        let scale = 1. / (head_size as f32).sqrt();
+        let expected = reference_attention(&query, &key, &value, None, false, scale);
+        expect_equal_with_tolerance(&result, &merge_heads(expected.as_dyn()), 1e-5, 0.)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_attention_invalid() {
+        let query = Tensor::<f32>::zeros(&[1, 2, 3, 4]);
+        let key = Tensor::<f32>::zeros(&[1, 2, 5, 4]);
+        let value = Tensor::<f32>::zeros(&[1, 2, 5, 4]);
+        let opts = AttentionOptions::default();
+
+        let result = attention(
+            query.view(),
+            key.view(),
+            Tensor::<f32>::zeros(&[1, 2, 6, 4]).view(),
+            None,
+            None,
+            &opts,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::IncompatibleInputShapes(
+                "key and value must have the same number of heads and sequence length"
+            ))
+        );
+
+        let result = attention(
+            Tensor::<f32>::zeros(&[1, 3, 3, 4]).view(),
+            key.view(),
+            value.view(),
+            None,
+            None,
+            &opts,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::IncompatibleInputShapes(
+                "query heads must be a multiple of key/value heads"
+            ))
+        );
+
+        let mask = Tensor::<f32>::zeros(&[3, 4]);
+        let result = attention(
+            query.view(),
+            key.view(),
+            value.view(),
+            Some(mask.view().into()),
+            None,
+            &opts,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::IncompatibleInputShapes(
+                "attention mask cannot be broadcast to scores shape"
+            ))
+        );
+
+        let result = attention(
+            Tensor::<f32>::zeros(&[1, 3, 8]).view(),
+            Tensor::<f32>::zeros(&[1, 5, 8]).view(),
+            Tensor::<f32>::zeros(&[1, 5, 8]).view(),
+            None,
+            None,
+            &opts,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("num_heads must be set for 3D inputs"))
+        );
+    }
+
+    #[test]
+    fn test_attention_infer_shapes() {
+        let infer = |op: &Attention, shapes: [&[usize]; 3]| {
+            let inputs = shapes.map(|shape| Some(MetaInput::Shape(shape)));
+            op.infer_shapes(&MetaInputList::from_optional(&inputs))
+                .expect("shape inference not supported")
+                .map(|outputs| outputs[0].shape().to_vec())
+        };
+
+        let op = Attention::default();
+        let shape = infer(&op, [&[1, 2, 3, 4], &[1, 2, 5, 4], &[1, 2, 5, 6]]);
+        assert_eq!(shape, Ok(vec![1, 2, 3, 6]));
+
+        // 3D inputs require `num_heads`, as when running the operator.
+        let result = infer(&op, [&[1, 3, 8], &[1, 5, 8], &[1, 5, 8]]);
+        assert_eq!(
+            result,
+            Err(OpError::InvalidValue("num_heads must be set for 3D inputs"))
+        );
+
+        let op = Attention {
+            num_heads: Some(4),
+            kv_num_heads: Some(2),
+            ..Default::default()
+        };
+        let shape = infer(&op, [&[1, 3, 8], &[1, 5, 4], &[1, 5, 6]]);
+        assert_eq!(shape, Ok(vec![1, 3, 12]));
+
+        let op = Attention {
+            num_heads: Some(3),
+            ..Default::default()
+        };
+        let result = infer(&op, [&[1, 3, 8], &[1, 5, 8], &[1, 5, 8]]);
+        assert_eq!(
+            result,
+            Err(OpError::InvalidValue(
+                "hidden size must be a multiple of the number of heads"
+            ))
+        );
+    }
+}
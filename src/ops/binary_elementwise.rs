@@ -5,7 +5,10 @@ use rten_tensor::prelude::*;
 use rten_tensor::{Tensor, TensorView, TensorViewMut};
 
 use crate::number::{AsBool, Identities, IsInt};
-use crate::ops::{Input, InputList, IntoOpResult, OpError, Operator, Output};
+use crate::ops::{
+    elementwise_flops, Input, InputList, IntoOpResult, MetaInputList, MetaOutput, OpError,
+    Operator, Output,
+};
 
 /// Given the shapes of two inputs to a binary operation, return the shape
 /// that will result from broadcasting them following NumPy rules or `None`
@@ -42,6 +45,21 @@ pub fn broadcast_shapes(a: &[usize], b: &[usize]) -> Option<Vec<usize>> {
     Some(result)
 }
 
+/// Shape inference for operators which broadcast all of their inputs to a
+/// common output shape.
+pub(crate) fn broadcast_infer_shapes(inputs: &MetaInputList) -> Result<Vec<MetaOutput>, OpError> {
+    let mut shape = Vec::new();
+    for input in inputs.iter() {
+        let input_shape = input.ok_or(OpError::MissingInputs)?.shape();
+        shape = broadcast_shapes(&shape, input_shape).ok_or_else(|| {
+            let shapes: Vec<_> = inputs.iter().flatten().map(|input| input.shape()).collect();
+            OpError::IncompatibleInputShapes("Cannot broadcast inputs")
+                .with_details(format!("{:?}", shapes))
+        })?;
+    }
+    Ok(vec![shape.into()])
+}
+
 /// Return true if an elementwise binary operation can be performed in-place
 /// on `a` given `b` as the other argument.
 fn can_run_binary_op_in_place<L1: Layout, L2: Layout>(a: &L1, b: &L2) -> bool {
@@ -158,8 +176,13 @@ fn binary_op<T: Copy + Debug, R: Copy + Default, F: Fn(T, T) -> R>(
     b: TensorView<T>,
     op: F,
 ) -> Result<Tensor<R>, OpError> {
-    let out_shape = broadcast_shapes(a.shape(), b.shape())
-        .ok_or(OpError::IncompatibleInputShapes("Cannot broadcast inputs"))?;
+    let out_shape = broadcast_shapes(a.shape(), b.shape()).ok_or_else(|| {
+        OpError::IncompatibleInputShapes("Cannot broadcast inputs").with_details(format!(
+            "{:?} and {:?}",
+            a.shape(),
+            b.shape()
+        ))
+    })?;
 
     // Fast path for when LHS and RHS are contiguous, and fast broadcasting is
     // possible.
@@ -287,6 +310,7 @@ macro_rules! run_typed_op {
                 let b = $inputs.require_as::<i32>(1)?;
                 $op_func(a, b).into_op_result()
             }
+            _ => Err(OpError::IncorrectInputType),
         }
     }};
 }
@@ -315,6 +339,7 @@ macro_rules! run_typed_op_in_place {
                     $op_func(a.view(), b.view()).map(|t| t.into())
                 }
             }
+            _ => Err(OpError::IncorrectInputType),
         }
     }};
 }
@@ -358,6 +383,14 @@ impl Operator for Add {
     fn run_in_place(&self, input: Output, other: InputList) -> Result<Output, OpError> {
         run_typed_op_in_place!(input, other, add_in_place, add)
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], output_shapes: &[&[usize]]) -> Option<u64> {
+        elementwise_flops(output_shapes)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(broadcast_infer_shapes(inputs))
+    }
 }
 
 /// Define a logical boolean operator.
@@ -393,6 +426,17 @@ macro_rules! logical_boolean_op {
                 let b: TensorView<i32> = inputs.require_as(1)?;
                 $op_fn(a, b).into_op_result()
             }
+
+            fn flops(&self, _: &[Option<&[usize]>], output_shapes: &[&[usize]]) -> Option<u64> {
+                elementwise_flops(output_shapes)
+            }
+
+            fn infer_shapes(
+                &self,
+                inputs: &MetaInputList,
+            ) -> Option<Result<Vec<MetaOutput>, OpError>> {
+                Some(broadcast_infer_shapes(inputs))
+            }
         }
     };
 }
@@ -455,6 +499,14 @@ impl Operator for Div {
     fn run_in_place(&self, input: Output, other: InputList) -> Result<Output, OpError> {
         run_typed_op_in_place!(input, other, div_in_place, div)
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], output_shapes: &[&[usize]]) -> Option<u64> {
+        elementwise_flops(output_shapes)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(broadcast_infer_shapes(inputs))
+    }
 }
 
 enum BooleanOp {
@@ -510,6 +562,17 @@ macro_rules! boolean_cmp_op {
             fn run(&self, inputs: InputList) -> Result<Vec<Output>, OpError> {
                 run_typed_op!(inputs, $func)
             }
+
+            fn flops(&self, _: &[Option<&[usize]>], output_shapes: &[&[usize]]) -> Option<u64> {
+                elementwise_flops(output_shapes)
+            }
+
+            fn infer_shapes(
+                &self,
+                inputs: &MetaInputList,
+            ) -> Option<Result<Vec<MetaOutput>, OpError>> {
+                Some(broadcast_infer_shapes(inputs))
+            }
         }
     };
 }
@@ -599,8 +662,17 @@ impl Operator for Mod {
                 let b = inputs.require_as::<i32>(1)?;
                 mod_op(a, b, mode).into_op_result()
             }
+            _ => Err(OpError::IncorrectInputType),
         }
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], output_shapes: &[&[usize]]) -> Option<u64> {
+        elementwise_flops(output_shapes)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(broadcast_infer_shapes(inputs))
+    }
 }
 
 /// Multiply two tensors elementwise.
@@ -642,6 +714,14 @@ impl Operator for Mul {
     fn run_in_place(&self, input: Output, other: InputList) -> Result<Output, OpError> {
         run_typed_op_in_place!(input, other, mul_in_place, mul)
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], output_shapes: &[&[usize]]) -> Option<u64> {
+        elementwise_flops(output_shapes)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(broadcast_infer_shapes(inputs))
+    }
 }
 
 /// Like [f32::powf] but with fast paths for common values.
@@ -702,6 +782,72 @@ impl Operator for Pow {
             pow(a.view(), b).map(|t| t.into())
         }
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], output_shapes: &[&[usize]]) -> Option<u64> {
+        elementwise_flops(output_shapes)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(broadcast_infer_shapes(inputs))
+    }
+}
+
+fn prelu_element(x: f32, slope: f32) -> f32 {
+    if x < 0. {
+        slope * x
+    } else {
+        x
+    }
+}
+
+/// Parametric ReLU. Computes `slope * x` for negative elements of `x` and `x`
+/// otherwise, where `slope` is broadcast to the shape of `x`.
+pub fn prelu(x: TensorView, slope: TensorView) -> Result<Tensor, OpError> {
+    binary_op(x, slope, prelu_element)
+}
+
+/// Perform in-place parametric ReLU. See [prelu].
+pub fn prelu_in_place(x: TensorViewMut, slope: TensorView) {
+    binary_op_in_place(x, slope, prelu_element);
+}
+
+#[derive(Debug)]
+pub struct PRelu {}
+
+impl Operator for PRelu {
+    fn name(&self) -> &str {
+        "PRelu"
+    }
+
+    fn run(&self, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let x = inputs.require_as(0)?;
+        let slope = inputs.require_as(1)?;
+        prelu(x, slope).into_op_result()
+    }
+
+    fn can_run_in_place(&self) -> bool {
+        true
+    }
+
+    fn run_in_place(&self, input: Output, other: InputList) -> Result<Output, OpError> {
+        let mut x = input.into_float().ok_or(OpError::IncorrectInputType)?;
+        let slope = other.require_as(0)?;
+
+        if can_run_binary_op_in_place(&x, &slope) {
+            prelu_in_place(x.view_mut(), slope);
+            Ok(x.into())
+        } else {
+            prelu(x.view(), slope).map(|t| t.into())
+        }
+    }
+
+    fn flops(&self, _: &[Option<&[usize]>], output_shapes: &[&[usize]]) -> Option<u64> {
+        elementwise_flops(output_shapes)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(broadcast_infer_shapes(inputs))
+    }
 }
 
 /// Perform elementwise subtraction of two tensors.
@@ -739,6 +885,14 @@ impl Operator for Sub {
     fn run_in_place(&self, input: Output, other: InputList) -> Result<Output, OpError> {
         run_typed_op_in_place!(input, other, sub_in_place, sub)
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], output_shapes: &[&[usize]]) -> Option<u64> {
+        elementwise_flops(output_shapes)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(broadcast_infer_shapes(inputs))
+    }
 }
 
 pub fn where_op<T: Copy>(
@@ -746,10 +900,17 @@ pub fn where_op<T: Copy>(
     x: TensorView<T>,
     y: TensorView<T>,
 ) -> Result<Tensor<T>, OpError> {
-    let broadcast_xy_shape = broadcast_shapes(x.shape(), y.shape())
-        .ok_or(OpError::IncompatibleInputShapes("Cannot broadcast inputs"))?;
-    let result_shape = broadcast_shapes(cond.shape(), &broadcast_xy_shape)
-        .ok_or(OpError::IncompatibleInputShapes("Cannot broadcast inputs"))?;
+    let broadcast_error = || {
+        OpError::IncompatibleInputShapes("Cannot broadcast inputs").with_details(format!(
+            "{:?}, {:?} and {:?}",
+            cond.shape(),
+            x.shape(),
+            y.shape()
+        ))
+    };
+    let broadcast_xy_shape = broadcast_shapes(x.shape(), y.shape()).ok_or_else(broadcast_error)?;
+    let result_shape =
+        broadcast_shapes(cond.shape(), &broadcast_xy_shape).ok_or_else(broadcast_error)?;
 
     let cond_cycles = fast_broadcast_cycles(cond.shape(), &result_shape);
     let x_cycles = fast_broadcast_cycles(x.shape(), &result_shape);
@@ -807,8 +968,17 @@ impl Operator for Where {
                 let y: TensorView<i32> = y.try_into()?;
                 where_op(condition, x, y).into_op_result()
             }
+            _ => Err(OpError::IncorrectInputType),
         }
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], output_shapes: &[&[usize]]) -> Option<u64> {
+        elementwise_flops(output_shapes)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(broadcast_infer_shapes(inputs))
+    }
 }
 
 #[cfg(test)]
@@ -822,8 +992,8 @@ mod tests {
     use super::{fast_broadcast_cycles, fast_broadcast_cycles_repeats};
     use crate::ops::{
         add, add_in_place, and, div, div_in_place, equal, greater, greater_or_equal, less,
-        less_or_equal, mod_op, mul, mul_in_place, or, pow, pow_in_place, sub, sub_in_place,
-        where_op, xor, Add, DivMode, OpError, Operator, Output,
+        less_or_equal, mod_op, mul, mul_in_place, or, pow, pow_in_place, prelu, prelu_in_place,
+        sub, sub_in_place, where_op, xor, Add, DivMode, OpError, Operator, Output,
     };
 
     #[test]
@@ -1041,7 +1211,10 @@ mod tests {
 
         assert_eq!(
             result.err(),
-            Some(OpError::IncompatibleInputShapes("Cannot broadcast inputs"))
+            Some(
+                OpError::IncompatibleInputShapes("Cannot broadcast inputs")
+                    .with_details("[2, 3] and [2, 2]")
+            )
         );
     }
 
@@ -1279,6 +1452,43 @@ mod tests {
         assert_eq!(&result, &expected);
     }
 
+    #[test]
+    fn test_prelu() -> Result<(), Box<dyn Error>> {
+        struct Case {
+            x: Tensor<f32>,
+            slope: Tensor<f32>,
+            expected: Tensor<f32>,
+        }
+
+        let cases = [
+            // Scalar slope
+            Case {
+                x: tensor!([-2., -1., 0., 1., 2.]),
+                slope: tensor!(0.5),
+                expected: tensor!([-1., -0.5, 0., 1., 2.]),
+            },
+            // Per-channel slope, broadcast over spatial dims
+            Case {
+                x: tensor!((2, 2); [-1., 2., -3., 4.]),
+                slope: tensor!((2, 1); [0.1, 0.2]),
+                expected: tensor!((2, 2); [-0.1, 2., -0.6, 4.]),
+            },
+        ];
+
+        for case in cases {
+            // Copying variant
+            let result = prelu(case.x.view(), case.slope.view()).unwrap();
+            expect_equal(&result, &case.expected)?;
+
+            // In-place variant
+            let mut x = case.x.clone();
+            prelu_in_place(x.view_mut(), case.slope.view());
+            expect_equal(&x, &case.expected)?;
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_pow() -> Result<(), Box<dyn Error>> {
         struct Case {
@@ -1446,14 +1656,20 @@ mod tests {
         let result = where_op(cond.view(), x.view(), y.view());
         assert_eq!(
             result.err(),
-            Some(OpError::IncompatibleInputShapes("Cannot broadcast inputs"))
+            Some(
+                OpError::IncompatibleInputShapes("Cannot broadcast inputs")
+                    .with_details("[2], [3] and [2]")
+            )
         );
 
         // Failure to broadcast `y` to match `cond`
         let result = where_op(cond.view(), y.view(), x.view());
         assert_eq!(
             result.err(),
-            Some(OpError::IncompatibleInputShapes("Cannot broadcast inputs"))
+            Some(
+                OpError::IncompatibleInputShapes("Cannot broadcast inputs")
+                    .with_details("[2], [2] and [3]")
+            )
         );
     }
 
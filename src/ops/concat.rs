@@ -1,7 +1,10 @@
 use rten_tensor::prelude::*;
 use rten_tensor::{Iter, NdTensorView, Tensor, TensorView};
 
-use crate::ops::{resolve_axis, Input, InputList, IntoOpResult, OpError, Operator, Output};
+use crate::ops::{
+    resolve_axis, Input, InputList, IntoOpResult, MetaInputList, MetaOutput, OpError, Operator,
+    Output,
+};
 use crate::static_dims;
 
 enum ChunkSource<'a, T: Copy> {
@@ -50,30 +53,40 @@ impl<'a, T: Copy> TensorChunks<'a, T> {
     }
 }
 
-pub fn concat<T: Copy>(inputs: &[TensorView<T>], axis: isize) -> Result<Tensor<T>, OpError> {
-    let first_shape = inputs[0].shape();
+/// Compute the output shape of a [concat] of tensors with the given shapes.
+fn concat_output_shape(shapes: &[&[usize]], axis: isize) -> Result<Vec<usize>, OpError> {
+    let first_shape = shapes.first().ok_or(OpError::MissingInputs)?;
     let axis = resolve_axis(first_shape.len(), axis)?;
 
-    for other in &inputs[1..] {
-        let other_shape = other.shape();
+    for other_shape in &shapes[1..] {
+        let shapes = || format!("{:?} and {:?}", first_shape, other_shape);
         if other_shape.len() != first_shape.len() {
             return Err(OpError::IncompatibleInputShapes(
                 "Tensors must have the same number of dimensions",
-            ));
+            )
+            .with_details(shapes()));
         }
         for d in 0..first_shape.len() {
             if d != axis && first_shape[d] != other_shape[d] {
                 return Err(OpError::IncompatibleInputShapes(
                     "Dimensions must be the same except for concat axis",
-                ));
+                )
+                .with_details(shapes()));
             }
         }
     }
 
-    let mut out_shape: Vec<_> = first_shape.into();
-    for other in &inputs[1..] {
-        out_shape[axis] += other.size(axis);
+    let mut out_shape: Vec<_> = first_shape.to_vec();
+    for other_shape in &shapes[1..] {
+        out_shape[axis] += other_shape[axis];
     }
+    Ok(out_shape)
+}
+
+pub fn concat<T: Copy>(inputs: &[TensorView<T>], axis: isize) -> Result<Tensor<T>, OpError> {
+    let shapes: Vec<&[usize]> = inputs.iter().map(|input| input.shape()).collect();
+    let out_shape = concat_output_shape(&shapes, axis)?;
+    let axis = resolve_axis(out_shape.len(), axis)?;
     let mut out_data = Vec::with_capacity(out_shape.iter().product());
 
     let mut input_iters: Vec<TensorChunks<'_, T>> = inputs
@@ -117,8 +130,27 @@ impl Operator for Concat {
                 }
                 concat(&typed_inputs, self.axis).into_op_result()
             }
+            _ => Err(OpError::IncorrectInputType),
         }
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        Some(0)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| {
+            let shapes = inputs
+                .iter()
+                .map(|input| {
+                    input
+                        .map(|input| input.shape())
+                        .ok_or(OpError::MissingInputs)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(vec![concat_output_shape(&shapes, self.axis)?.into()])
+        })())
+    }
 }
 
 /// Recursively tile (ie. repeatly copy) chunks of `input` to `output`.
@@ -160,21 +192,24 @@ fn tile_inner<T: Copy>(input: &[T], output: &mut [T], input_shape: &[usize], rep
     }
 }
 
+/// Compute the output shape of a [tile] operation.
+fn tiled_shape(shape: &[usize], repeats: NdTensorView<i32, 1>) -> Result<Vec<usize>, OpError> {
+    if repeats.size(0) != shape.len() || repeats.iter().any(|n| *n < 0) {
+        return Err(OpError::InvalidValue("invalid repeats"));
+    }
+    Ok(shape
+        .iter()
+        .zip(repeats.iter())
+        .map(|(&size, &repeat)| size * repeat as usize)
+        .collect())
+}
+
 pub fn tile<T: Copy + Default>(
     input: TensorView<T>,
     repeats: NdTensorView<i32, 1>,
 ) -> Result<Tensor<T>, OpError> {
-    if repeats.size(0) != input.ndim() || repeats.iter().any(|n| *n < 0) {
-        return Err(OpError::InvalidValue("invalid repeats"));
-    }
-
+    let out_shape = tiled_shape(input.shape(), repeats)?;
     let repeats: Vec<usize> = repeats.iter().map(|r| *r as usize).collect();
-    let out_shape: Vec<_> = input
-        .shape()
-        .iter()
-        .zip(repeats.iter())
-        .map(|(size, repeat)| size * repeat)
-        .collect();
     let mut output = Tensor::zeros(&out_shape);
 
     if output.is_empty() {
@@ -207,6 +242,7 @@ impl Operator for Tile {
         match input {
             Input::IntTensor(input) => tile(input, repeats).into_op_result(),
             Input::FloatTensor(input) => tile(input, repeats).into_op_result(),
+            _ => Err(OpError::IncorrectInputType),
         }
     }
 
@@ -226,8 +262,21 @@ impl Operator for Tile {
         match output {
             Output::IntTensor(input) => tile(input.view(), repeats).map(|t| t.into()),
             Output::FloatTensor(input) => tile(input.view(), repeats).map(|t| t.into()),
+            _ => Err(OpError::IncorrectInputType),
         }
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        Some(0)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| {
+            let repeats = inputs.require_value_as::<i32>(1)?;
+            let repeats = static_dims!(repeats, 1)?;
+            Ok(vec![tiled_shape(inputs.require_shape(0)?, repeats)?.into()])
+        })())
+    }
 }
 
 #[cfg(test)]
@@ -291,9 +340,10 @@ mod tests {
         let result = concat(&[a.view(), b.view()], 0);
         assert_eq!(
             result.err(),
-            Some(OpError::IncompatibleInputShapes(
-                "Tensors must have the same number of dimensions"
-            ))
+            Some(
+                OpError::IncompatibleInputShapes("Tensors must have the same number of dimensions")
+                    .with_details("[1] and [1, 2]")
+            )
         );
 
         // Shape mismatch in non-`dim` dimension
@@ -302,9 +352,12 @@ mod tests {
         let result = concat(&[a.view(), b.view()], 0);
         assert_eq!(
             result.err(),
-            Some(OpError::IncompatibleInputShapes(
-                "Dimensions must be the same except for concat axis"
-            ))
+            Some(
+                OpError::IncompatibleInputShapes(
+                    "Dimensions must be the same except for concat axis"
+                )
+                .with_details("[5, 10] and [5, 11]")
+            )
         );
     }
 
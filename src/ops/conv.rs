@@ -5,15 +5,18 @@ use std::ops::Range;
 use rayon::prelude::*;
 use rten_tensor::prelude::*;
 use rten_tensor::{NdTensorView, NdTensorViewMut, Tensor, TensorView};
-use smallvec::SmallVec;
+use smallvec::{smallvec, SmallVec};
 
 use crate::check_dims;
 use crate::gemm::{
     add_scaled_vector, div_ceil, gemm, round_up, GemmExecutor, GemmInputA, GemmInputB,
     VirtualMatrix,
 };
-use crate::ops::pooling::calc_output_size_and_padding;
-use crate::ops::{InputList, IntoOpResult, OpError, Operator, Output, Padding};
+use crate::ops::pooling::{calc_output_size_and_padding, calc_output_size_and_padding_nd};
+use crate::ops::{
+    num_elements, InputList, IntoOpResult, MetaInputList, MetaOutput, OpError, Operator, Output,
+    Padding,
+};
 
 // Calculate the min and max output X coordinates that are valid when updating
 // a row of convolution output using a loop:
@@ -41,111 +44,115 @@ fn min_max_out_x_coords(
 
 /// Unrolls patches of an image as columns of a virtual matrix.
 ///
-/// The input image has shape [C,H,W] and is transformed into a matrix with
-/// shape [C * Kh * kW, Oh * Ow] where Kh/Kw are convolution kernel sizes and
-/// Oh/Ow are the number of patches in the Y and X directions.
+/// The input image has shape [C,D,H,W] and is transformed into a matrix with
+/// shape [C * Kd * Kh * Kw, Od * Oh * Ow] where Kd/Kh/Kw are convolution kernel
+/// sizes and Od/Oh/Ow are the number of patches in the Z, Y and X directions.
+/// 2D images are handled as images with a depth of 1.
 ///
 /// The transform is virtual because the matrix is not actually materialized
 /// in memory. Instead blocks of it are produced on-demand during a matrix
 /// multiplication operation.
 struct VirtualIm2Col<'a> {
-    image: NdTensorView<'a, f32, 3>,
-    kernel: [usize; 2],
-    padding: [usize; 4],
-    strides: [usize; 2],
-    dilations: [usize; 2],
-    y_patches: usize,
-    x_patches: usize,
+    image: NdTensorView<'a, f32, 4>,
+    kernel: [usize; 3],
+
+    /// Padding at the start of each spatial dimension.
+    pad_start: [usize; 3],
+    strides: [usize; 3],
+    dilations: [usize; 3],
+
+    /// Number of patches along each spatial dimension.
+    patches: [usize; 3],
 }
 
 impl<'a> VirtualIm2Col<'a> {
     fn new(
-        image: NdTensorView<'a, f32, 3>,
-        kernel: [usize; 2],
-        padding: [usize; 4],
-        strides: [usize; 2],
-        dilations: [usize; 2],
+        image: NdTensorView<'a, f32, 4>,
+        kernel: [usize; 3],
+        pad_start: [usize; 3],
+        strides: [usize; 3],
+        dilations: [usize; 3],
+        patches: [usize; 3],
     ) -> VirtualIm2Col {
-        let [_, h, w] = image.shape();
-        let [k_h, k_w] = kernel;
-        let [stride_h, stride_w] = strides;
-        let [dilation_y, dilation_x] = dilations;
-        let (y_patches, x_patches, _) = calc_output_size_and_padding(
-            (h, w),
-            (k_h, k_w),
-            (stride_h, stride_w),
-            Padding::Fixed(padding.into()),
-            Some((dilation_y, dilation_x)),
-        )
-        .unwrap();
-
         VirtualIm2Col {
             image,
             kernel,
-            padding,
+            pad_start,
             strides,
             dilations,
-            y_patches,
-            x_patches,
+            patches,
         }
     }
 }
 
 impl<'a> VirtualMatrix for VirtualIm2Col<'a> {
     fn rows(&self) -> usize {
-        let [chans, _h, _w] = self.image.shape();
-        let [k_h, k_w] = self.kernel;
-        chans * k_h * k_w
+        let [chans, _d, _h, _w] = self.image.shape();
+        let kernel_len: usize = self.kernel.iter().product();
+        chans * kernel_len
     }
 
     fn cols(&self) -> usize {
-        self.y_patches * self.x_patches
+        self.patches.iter().product()
     }
 
     fn pack_b(&self, out: &mut [f32], panel_width: usize, rows: Range<usize>, cols: Range<usize>) {
-        let [k_h, k_w] = self.kernel;
-        let [stride_y, stride_x] = self.strides;
-        let [dilation_y, dilation_x] = self.dilations;
-        let [pad_top, pad_left, _pad_bottom, _pad_right] = self.padding;
+        let [k_d, k_h, k_w] = self.kernel;
+        let [stride_z, stride_y, stride_x] = self.strides;
+        let [dilation_z, dilation_y, dilation_x] = self.dilations;
+        let [pad_front, pad_top, pad_left] = self.pad_start;
+        let [_z_patches, y_patches, x_patches] = self.patches;
 
         // Build lookup table of column index in the virtual im2col matrix to
         // patch coordinate in the input image.
-        let patch_coords: Vec<[i32; 2]> = (cols.start..round_up(cols.end, panel_width))
+        let patch_coords: Vec<[i32; 3]> = (cols.start..round_up(cols.end, panel_width))
             .map(|col| {
-                let patch_y = col as i32 / self.x_patches as i32;
-                let patch_x = col as i32 % self.x_patches as i32;
+                let patch_z = col as i32 / (y_patches * x_patches) as i32;
+                let patch_y = (col as i32 / x_patches as i32) % y_patches as i32;
+                let patch_x = col as i32 % x_patches as i32;
                 let img_x = (patch_x * stride_x as i32) - pad_left as i32;
                 let img_y = (patch_y * stride_y as i32) - pad_top as i32;
-                [img_y, img_x]
+                let img_z = (patch_z * stride_z as i32) - pad_front as i32;
+                [img_z, img_y, img_x]
             })
             .collect();
 
         // Build lookup table of row index in the virtual im2col matrix to input
         // channel and kernel coordinates.
-        let kernel_coords: Vec<[i32; 3]> = rows
+        let kernel_coords: Vec<[i32; 4]> = rows
             .map(|row| {
-                let in_chan = row as i32 / (k_h * k_w) as i32;
-                let kernel_element = row as i32 % (k_h * k_w) as i32;
-                let k_y = kernel_element / k_w as i32;
+                let in_chan = row as i32 / (k_d * k_h * k_w) as i32;
+                let kernel_element = row as i32 % (k_d * k_h * k_w) as i32;
+                let k_z = kernel_element / (k_h * k_w) as i32;
+                let k_y = (kernel_element / k_w as i32) % k_h as i32;
                 let k_x = kernel_element % k_w as i32;
-                [in_chan, k_y, k_x]
+                [in_chan, k_z, k_y, k_x]
             })
             .collect();
 
         // Loop over the output by column panel, then row, then element.
         let mut out_rows = out.chunks_exact_mut(panel_width);
         for panel_patch_coords in patch_coords.chunks_exact(panel_width) {
-            for [in_chan, k_y, k_x] in kernel_coords.iter().copied() {
+            for [in_chan, k_z, k_y, k_x] in kernel_coords.iter().copied() {
                 let out_row = out_rows.next().unwrap();
-                for ([img_y, img_x], out_el) in zip(panel_patch_coords.iter(), out_row.iter_mut()) {
+                for ([img_z, img_y, img_x], out_el) in
+                    zip(panel_patch_coords.iter(), out_row.iter_mut())
+                {
+                    let in_z = img_z + k_z * dilation_z as i32;
                     let in_y = img_y + k_y * dilation_y as i32;
                     let in_x = img_x + k_x * dilation_x as i32;
 
-                    // `in_y` or `in_x` may be negative here, in which case it will
-                    // wrap around and `image.get` will still return None.
+                    // `in_z`, `in_y` or `in_x` may be negative here, in which
+                    // case it will wrap around and `image.get` will still
+                    // return None.
                     *out_el = self
                         .image
-                        .get([in_chan as usize, in_y as usize, in_x as usize])
+                        .get([
+                            in_chan as usize,
+                            in_z as usize,
+                            in_y as usize,
+                            in_x as usize,
+                        ])
                         .copied()
                         .unwrap_or(0.);
                 }
@@ -321,14 +328,44 @@ fn conv_2d_depthwise(
     output
 }
 
+/// Check that the channel counts of a convolution's input and kernel are
+/// compatible with the group count.
+fn check_conv_groups(
+    in_c: usize,
+    out_c: usize,
+    k_in_c: usize,
+    groups: usize,
+) -> Result<(), OpError> {
+    if in_c.checked_rem(groups) != Some(0) || out_c.checked_rem(groups) != Some(0) {
+        return Err(OpError::IncompatibleInputShapes(
+            "Input channels and output channels must be divisible by group count",
+        ));
+    }
+
+    let in_channels_per_group = in_c / groups;
+    if in_channels_per_group != k_in_c {
+        return Err(OpError::IncompatibleInputShapes(
+            "Input channels (per group) does not match kernel input channels",
+        )
+        .with_details(format!(
+            "input has {} channels per group, kernel has {}",
+            in_channels_per_group, k_in_c
+        )));
+    }
+
+    Ok(())
+}
+
 /// Perform a convolution of `input` with `kernel`.
 ///
 /// For a 2D convolution `input` has dimensions NCHW while `kernel` has OGHW
-/// where `G` is `C / groups`. 1D convolutions are similar except the "H"
-/// dimension is omitted.
+/// where `G` is `C / groups`. 1D and 3D convolutions are similar except the
+/// "H" dimension is omitted for 1D convolutions and a "D" (depth) dimension
+/// is added before "H" for 3D convolutions.
 ///
-/// - `padding` specifies the amount of horizontal and vertical padding respectively
-///   that is added to each side.
+/// - `padding` specifies the amount of padding that is added to the start
+///   and end of each spatial dimension, in ONNX order. For a 2D convolution
+///   this is `[top, left, bottom, right]`.
 /// - `groups` controls which input and output channels are convolved. It must
 ///   be a positive integer that divides the input and output channel count.
 ///   A value of 1 convolves every input channel with every output channel.
@@ -399,6 +436,10 @@ pub fn conv(
         });
     }
 
+    if input.ndim() == 5 {
+        return conv_3d(input, kernel, bias, padding, groups, strides, dilations);
+    }
+
     let [batch, in_c, in_h, in_w] = check_dims!(input, 4, "NCHW");
     let [out_c, k_in_c, k_h, k_w] = check_dims!(kernel, 4, "OCHW");
     check_dims!(bias?, 1);
@@ -441,20 +482,7 @@ pub fn conv(
         ));
     }
 
-    let out_channels_per_group = out_c / groups;
-    let in_channels_per_group = in_c / groups;
-
-    if in_channels_per_group != k_in_c {
-        return Err(OpError::IncompatibleInputShapes(
-            "Input channels (per group) does not match kernel input channels",
-        ));
-    }
-
-    if groups == 0 || in_c % groups != 0 || out_c % groups != 0 {
-        return Err(OpError::IncompatibleInputShapes(
-            "Input channels and output channels must be divisible by group count",
-        ));
-    }
+    check_conv_groups(in_c, out_c, k_in_c, groups)?;
 
     if in_c == out_c && groups == in_c {
         return Ok(conv_2d_depthwise(
@@ -468,7 +496,102 @@ pub fn conv(
         ));
     }
 
-    let n_patches = out_h * out_w;
+    // Use the general 3D implementation with a depth of 1.
+    let mut input_3d = input.clone();
+    input_3d.insert_axis(2);
+    let kernel = kernel.to_contiguous();
+    let kernel_3d = kernel.reshaped([out_c, k_in_c, 1, k_h, k_w]);
+
+    let mut output = conv_im2col(
+        input_3d.nd_view(),
+        kernel_3d,
+        bias,
+        groups,
+        [0, pad_top, pad_left],
+        [1, stride_y, stride_x],
+        [1, dilation_y, dilation_x],
+        [1, out_h, out_w],
+    );
+    output.reshape(&[batch, out_c, out_h, out_w]);
+
+    Ok(output)
+}
+
+/// Perform a 3D convolution of an NCDHW `input` with an OGDHW `kernel`.
+///
+/// See [conv] for details of the parameters.
+fn conv_3d(
+    input: TensorView,
+    kernel: TensorView,
+    bias: Option<TensorView>,
+    padding: Padding,
+    groups: usize,
+    strides: &[usize],
+    dilations: &[usize],
+) -> Result<Tensor, OpError> {
+    let [batch, in_c, in_d, in_h, in_w] = check_dims!(input, 5, "NCDHW");
+    let [out_c, k_in_c, k_d, k_h, k_w] = check_dims!(kernel, 5, "OCDHW");
+    check_dims!(bias?, 1);
+
+    let strides: [usize; 3] = strides
+        .try_into()
+        .map_err(|_| OpError::InvalidValue("expected 3 stride values"))?;
+    let dilations: [usize; 3] = dilations
+        .try_into()
+        .map_err(|_| OpError::InvalidValue("expected 3 dilation values"))?;
+
+    let (out_size, pads) = calc_output_size_and_padding_nd(
+        &[in_d, in_h, in_w],
+        &[k_d, k_h, k_w],
+        &strides,
+        padding,
+        Some(&dilations),
+    )?;
+
+    check_conv_groups(in_c, out_c, k_in_c, groups)?;
+
+    let out_size: [usize; 3] = out_size.try_into().unwrap();
+    let kernel = kernel.to_contiguous();
+    let mut output = conv_im2col(
+        input.nd_view(),
+        kernel.nd_view(),
+        bias,
+        groups,
+        [pads[0], pads[1], pads[2]],
+        strides,
+        dilations,
+        out_size,
+    );
+    let [out_d, out_h, out_w] = out_size;
+    output.reshape(&[batch, out_c, out_d, out_h, out_w]);
+
+    Ok(output)
+}
+
+/// Perform a convolution of an NCDHW input with an OGDHW kernel by
+/// transforming it into a matrix multiplication of the kernel with a virtual
+/// im2col matrix.
+///
+/// The input and kernel must have been validated by the caller. Returns an
+/// output with shape `[N, O, Od * Oh * Ow]`.
+#[allow(clippy::too_many_arguments)]
+fn conv_im2col(
+    input: NdTensorView<f32, 5>,
+    kernel: NdTensorView<f32, 5>,
+    bias: Option<TensorView>,
+    groups: usize,
+    pad_start: [usize; 3],
+    strides: [usize; 3],
+    dilations: [usize; 3],
+    out_size: [usize; 3],
+) -> Tensor {
+    let [batch, in_c, _, _, _] = input.shape();
+    let [out_c, _, k_d, k_h, k_w] = kernel.shape();
+
+    let out_channels_per_group = out_c / groups;
+    let in_channels_per_group = in_c / groups;
+
+    let n_patches: usize = out_size.iter().product();
     let mut output = Tensor::zeros(&[batch, out_c, n_patches]);
     let gemm = GemmExecutor::new();
 
@@ -482,9 +605,10 @@ pub fn conv(
         let out_chan_start = group * out_channels_per_group;
         let out_chans = out_chan_start..out_chan_start + out_channels_per_group;
 
-        let kernel_mat = kernel
-            .slice::<4, _>([out_chans.clone()])
-            .reshaped([out_channels_per_group, in_channels_per_group * k_h * k_w]);
+        let kernel_mat = kernel.slice::<5, _>([out_chans.clone()]).reshaped([
+            out_channels_per_group,
+            in_channels_per_group * k_d * k_h * k_w,
+        ]);
         let prepacked_kernel = gemm.prepack_a(kernel_mat);
 
         let in_group = input.slice_dyn((.., in_chan_start..in_chan_end));
@@ -493,15 +617,16 @@ pub fn conv(
         zip(out_group.axis_iter_mut(0), in_group.axis_iter(0))
             .par_bridge()
             .for_each(|(mut out_item, in_item)| {
-                let mut out_mat = out_item.reshaped_mut([out_channels_per_group, out_h * out_w]);
+                let mut out_mat = out_item.reshaped_mut([out_channels_per_group, n_patches]);
                 let out_row_stride = out_mat.stride(0);
 
                 let im2col = VirtualIm2Col::new(
                     in_item.nd_view(),
-                    [k_h, k_w],
-                    fixed_padding,
-                    [stride_y, stride_x],
-                    [dilation_y, dilation_x],
+                    [k_d, k_h, k_w],
+                    pad_start,
+                    strides,
+                    dilations,
+                    out_size,
                 );
 
                 gemm.gemm_bias(
@@ -516,9 +641,74 @@ pub fn conv(
             });
     }
 
-    output.reshape(&[batch, out_c, out_h, out_w]);
+    output
+}
 
-    Ok(output)
+/// Compute the output shape of a [conv] with the given input and kernel
+/// shapes.
+fn conv_output_shape(
+    input_shape: &[usize],
+    kernel_shape: &[usize],
+    padding: Padding,
+    strides: &[usize],
+    dilations: &[usize],
+) -> Result<Vec<usize>, OpError> {
+    match (input_shape, kernel_shape) {
+        // 1D convolution. Compute the shape for the equivalent 2D convolution.
+        (&[n, c, w], &[out_c, k_in_c, k_w]) => {
+            let padding_2d: Padding = match padding {
+                Padding::Same => Padding::Same,
+                Padding::Fixed(pads) => match pads.as_slice() {
+                    &[pad_start, pad_end] => [0, pad_start, 0, pad_end].into(),
+                    _ => {
+                        return Err(OpError::InvalidValue("expected 2 pad values"));
+                    }
+                },
+            };
+            let (&[stride], &[dilation]) = (strides, dilations) else {
+                return Err(OpError::InvalidValue(
+                    "expected 1 stride and dilation value",
+                ));
+            };
+            let shape = conv_output_shape(
+                &[n, c, 1, w],
+                &[out_c, k_in_c, 1, k_w],
+                padding_2d,
+                &[1, stride],
+                &[1, dilation],
+            )?;
+            Ok(vec![shape[0], shape[1], shape[3]])
+        }
+        (&[batch, _, in_h, in_w], &[out_c, _, k_h, k_w]) => {
+            let [stride_y, stride_x]: [usize; 2] = strides
+                .try_into()
+                .map_err(|_| OpError::InvalidValue("expected 2 stride values"))?;
+            let [dilation_y, dilation_x]: [usize; 2] = dilations
+                .try_into()
+                .map_err(|_| OpError::InvalidValue("expected 2 dilation values"))?;
+            let (out_h, out_w, _) = calc_output_size_and_padding(
+                (in_h, in_w),
+                (k_h, k_w),
+                (stride_y, stride_x),
+                padding,
+                Some((dilation_y, dilation_x)),
+            )?;
+            Ok(vec![batch, out_c, out_h, out_w])
+        }
+        (&[batch, _, in_d, in_h, in_w], &[out_c, _, k_d, k_h, k_w]) => {
+            let (out_size, _) = calc_output_size_and_padding_nd(
+                &[in_d, in_h, in_w],
+                &[k_d, k_h, k_w],
+                strides,
+                padding,
+                Some(dilations),
+            )?;
+            Ok([batch, out_c].into_iter().chain(out_size).collect())
+        }
+        _ => Err(OpError::InvalidValue(
+            "Input and kernel must both have 3, 4 or 5 dims",
+        )),
+    }
 }
 
 #[derive(Debug)]
@@ -549,27 +739,64 @@ impl Operator for Conv {
         )
         .into_op_result()
     }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], output_shapes: &[&[usize]]) -> Option<u64> {
+        // Each output element is the dot product of a kernel with
+        // `in_channels / groups * kernel_size` elements.
+        let weight_shape = input_shapes.get(1).copied().flatten()?;
+        let kernel_len = num_elements(weight_shape.get(1..)?);
+        let bias_flops = if input_shapes.get(2).copied().flatten().is_some() {
+            1
+        } else {
+            0
+        };
+        Some(num_elements(output_shapes.first()?) * (2 * kernel_len + bias_flops))
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| {
+            let shape = conv_output_shape(
+                inputs.require_shape(0)?,
+                inputs.require_shape(1)?,
+                self.padding.clone(),
+                &self.strides,
+                &self.dilations,
+            )?;
+            Ok(vec![shape.into()])
+        })())
+    }
 }
 
 /// Unpack columns of a matrix into an image. This is the inverse of the
 /// `im2col` operation.
 ///
-/// `output` has shape [O,H,W] where O is the number of output channels and H/W
-/// are the output height/width.
+/// `output` has shape [O,D,H,W] where O is the number of output channels and
+/// D/H/W are the output depth, height and width.
+///
+/// `columns` is a view of a matrix (Di x Hi x Wi, O x Kd x Kh x Kw) reshaped to
+/// [Di,Hi,Wi,O,Kd,Kh,Kw], where Di, Hi and Wi are the image size, and Kd/Kh/Kw
+/// are the patch sizes. This matrix is passed as a view to avoid needing to
+/// pass the sub-dimensions separately.
 ///
-/// `columns` is a view of a matrix (Hi x Wi, O x Kh x Kw) reshaped to
-/// [Hi,Wi,O,Kh,Kw], where Hi and Wi are the image size, and Kh/Kw are the patch
-/// sizes. This matrix is passed as a view to avoid needing to pass the
-/// sub-dimensions separately.
+/// Each patch is placed in the unpadded output at an offset given by the
+/// patch position multiplied by `strides`, with kernel elements spaced
+/// according to `dilations`. `pad_start` specifies how much padding is removed
+/// from the start of each spatial axis. Elements which fall outside `output`
+/// after removing padding are discarded.
 ///
 /// The unpacked columns are added to the existing output values to preserve
 /// any bias stored in the output.
 fn col2im(
-    output: &mut NdTensorViewMut<f32, 3>,
-    columns: &NdTensorView<f32, 5>,
-    strides: [usize; 2],
+    output: &mut NdTensorViewMut<f32, 4>,
+    columns: &NdTensorView<f32, 7>,
+    strides: [usize; 3],
+    dilations: [usize; 3],
+    pad_start: [usize; 3],
 ) {
-    let [stride_h, stride_w] = strides;
+    let [stride_d, stride_h, stride_w] = strides;
+    let [dilation_d, dilation_h, dilation_w] = dilations;
+    let [pad_d, pad_h, pad_w] = pad_start;
+    let [_, out_d, out_h, out_w] = output.shape();
 
     // If we assume `columns` is likely already contiguous, we can avoid offset
     // calculations and just iterate over the underlying data.
@@ -580,14 +807,27 @@ fn col2im(
     let mut out_view = output.weakly_checked_view_mut();
 
     // Loop order must match dim order of `columns`.
-    for y in 0..columns_shape[0] {
-        for x in 0..columns_shape[1] {
-            for out_c in 0..columns_shape[2] {
-                for k_y in 0..columns_shape[3] {
-                    let out_y = y * stride_h + k_y;
-                    for k_x in 0..columns_shape[4] {
-                        let out_x = x * stride_w + k_x;
-                        out_view[[out_c, out_y, out_x]] += col_data_iter.next().unwrap();
+    //
+    // Output coordinates are computed with wrapping subtraction, so that
+    // positions which fall inside the start padding wrap around to large
+    // values and are rejected by the same check as positions in the end
+    // padding.
+    for z in 0..columns_shape[0] {
+        for y in 0..columns_shape[1] {
+            for x in 0..columns_shape[2] {
+                for out_c in 0..columns_shape[3] {
+                    for k_z in 0..columns_shape[4] {
+                        let out_z = (z * stride_d + k_z * dilation_d).wrapping_sub(pad_d);
+                        for k_y in 0..columns_shape[5] {
+                            let out_y = (y * stride_h + k_y * dilation_h).wrapping_sub(pad_h);
+                            for k_x in 0..columns_shape[6] {
+                                let out_x = (x * stride_w + k_x * dilation_w).wrapping_sub(pad_w);
+                                let val = col_data_iter.next().unwrap();
+                                if out_z < out_d && out_y < out_h && out_x < out_w {
+                                    out_view[[out_c, out_z, out_y, out_x]] += val;
+                                }
+                            }
+                        }
                     }
                 }
             }
@@ -595,77 +835,271 @@ fn col2im(
     }
 }
 
-/// Perform a transposed 2D convolution of a tensor by a kernel.
+/// Compute the output size and start padding of each spatial axis for a
+/// transposed convolution.
 ///
-/// `input` has dimensions NCHW and `kernel` has dimensions COHW where `O` is
-/// the number of output channels.
+/// The padding-related arguments have the same meaning as the corresponding
+/// attributes of the ONNX `ConvTranspose` operator. If `output_shape` is
+/// specified, it determines the output size and the padding is computed from
+/// it.
+fn calc_conv_transpose_output_size_and_padding(
+    in_size: &[usize],
+    kernel_size: &[usize],
+    padding: &Padding,
+    strides: &[usize],
+    dilations: &[usize],
+    output_padding: Option<&[usize]>,
+    output_shape: Option<&[usize]>,
+) -> Result<(Vec<usize>, Vec<usize>), OpError> {
+    let ndim = in_size.len();
+    if kernel_size.len() != ndim {
+        return Err(OpError::InvalidValue(
+            "Kernel size does not match input spatial dims",
+        ));
+    }
+
+    // Use defaults for attributes whose size depends on the kernel rank, if
+    // they are not specified.
+    let ones = vec![1; ndim];
+    let strides = if strides.is_empty() { &ones } else { strides };
+    let dilations = if dilations.is_empty() {
+        &ones
+    } else {
+        dilations
+    };
+    let zero_pads;
+    let padding = match padding {
+        Padding::Fixed(pads) if pads.is_empty() => {
+            zero_pads = Padding::Fixed(smallvec![0; ndim * 2]);
+            &zero_pads
+        }
+        padding => padding,
+    };
+
+    if strides.len() != ndim {
+        return Err(OpError::InvalidValue("Wrong number of stride values"));
+    }
+    if dilations.len() != ndim {
+        return Err(OpError::InvalidValue("Wrong number of dilation values"));
+    }
+    if strides.contains(&0) {
+        return Err(OpError::InvalidValue("Strides must be > 0"));
+    }
+    if dilations.contains(&0) {
+        return Err(OpError::InvalidValue("Dilations must be > 0"));
+    }
+    if in_size.contains(&0) || kernel_size.contains(&0) {
+        return Err(OpError::InvalidValue(
+            "Input and kernel spatial dims must be > 0",
+        ));
+    }
+
+    let output_padding = output_padding.unwrap_or(&[]);
+    if !output_padding.is_empty() && output_padding.len() != ndim {
+        return Err(OpError::InvalidValue(
+            "Wrong number of output padding values",
+        ));
+    }
+    if output_shape.is_some_and(|shape| shape.len() != ndim) {
+        return Err(OpError::InvalidValue("Wrong number of output shape values"));
+    }
+    if let Padding::Fixed(pads) = padding {
+        if pads.len() != ndim * 2 {
+            return Err(OpError::InvalidValue("Wrong number of padding values"));
+        }
+    }
+
+    let mut out_size = Vec::with_capacity(ndim);
+    let mut pad_start = Vec::with_capacity(ndim);
+
+    for i in 0..ndim {
+        // Size of the output before any padding is removed.
+        let full_size = (in_size[i] - 1) * strides[i]
+            + (kernel_size[i] - 1) * dilations[i]
+            + 1
+            + output_padding.get(i).copied().unwrap_or(0);
+
+        let (size, start) = match (output_shape, padding) {
+            (Some(shape), _) => {
+                // If the requested size exceeds the full size, the extra
+                // positions receive only the bias.
+                let pad_total = full_size.saturating_sub(shape[i]);
+                let start = match padding {
+                    Padding::Same => pad_total / 2,
+                    Padding::Fixed(_) => div_ceil(pad_total, 2),
+                };
+                (shape[i], start)
+            }
+            (None, Padding::Same) => {
+                let size = in_size[i] * strides[i];
+                (size, full_size.saturating_sub(size) / 2)
+            }
+            (None, Padding::Fixed(pads)) => {
+                let size = full_size
+                    .checked_sub(pads[i] + pads[ndim + i])
+                    .filter(|&size| size > 0)
+                    .ok_or(OpError::InvalidValue(
+                        "Padding is too large for output size",
+                    ))?;
+                (size, pads[i])
+            }
+        };
+        out_size.push(size);
+        pad_start.push(start);
+    }
+
+    Ok((out_size, pad_start))
+}
+
+/// Expand a list of values for 1-3 spatial dims to a list of 3 values by
+/// prepending `fill`.
+fn expand_to_3d<T: Copy>(vals: &[T], fill: T) -> [T; 3] {
+    let mut expanded = [fill; 3];
+    expanded[3 - vals.len()..].copy_from_slice(vals);
+    expanded
+}
+
+/// Perform a transposed convolution of a tensor by a kernel.
+///
+/// For a 2D transposed convolution `input` has dimensions NCHW and `kernel`
+/// has dimensions C(O/G)HW where `O` is the number of output channels and `G`
+/// is the number of groups. 1D and 3D transposed convolutions are similar but
+/// have one fewer or one more spatial dimension respectively.
+///
+/// `output_padding` adds extra positions to the end of each spatial axis of
+/// the output. If `output_shape` is specified, it sets the size of the spatial
+/// axes of the output and `padding` is computed from it, as for the ONNX
+/// `ConvTranspose` operator.
+///
+/// If `strides` or `dilations` are empty, they default to 1 for each spatial
+/// axis of the kernel. If `padding` is [Padding::Fixed] with no values, it
+/// defaults to zero.
+#[allow(clippy::too_many_arguments)]
 pub fn conv_transpose(
     input: TensorView,
     kernel: TensorView,
     bias: Option<TensorView>,
-    strides: [usize; 2],
+    padding: Padding,
+    groups: usize,
+    strides: &[usize],
+    dilations: &[usize],
+    output_padding: Option<&[usize]>,
+    output_shape: Option<&[usize]>,
 ) -> Result<Tensor, OpError> {
-    let [batch, in_c, in_h, in_w] = check_dims!(input, 4, "NCHW");
-    let [k_in_c, out_c, k_h, k_w] = check_dims!(kernel, 4, "OCHW");
+    let ndim = input.ndim();
+    if !(3..=5).contains(&ndim) || kernel.ndim() != ndim {
+        return Err(OpError::InvalidValue(
+            "Input and kernel must both have 3, 4 or 5 dims",
+        ));
+    }
     check_dims!(bias?, 1);
 
-    let bias = bias.map(|b| b.nd_view());
+    let [batch, in_c] = [input.size(0), input.size(1)];
+    let [k_in_c, k_out_c] = [kernel.size(0), kernel.size(1)];
 
     if in_c != k_in_c {
         return Err(OpError::IncompatibleInputShapes(
             "Input channels does not match kernel input channels",
         ));
     }
+    if in_c.checked_rem(groups) != Some(0) {
+        return Err(OpError::IncompatibleInputShapes(
+            "Input channels must be divisible by group count",
+        ));
+    }
+    let out_c = k_out_c * groups;
+    if bias.as_ref().is_some_and(|b| b.size(0) != out_c) {
+        return Err(OpError::IncompatibleInputShapes(
+            "Bias length does not match output channels",
+        ));
+    }
 
-    let [stride_h, stride_w] = strides;
-    let out_h = (in_h - 1) * stride_h + k_h;
-    let out_w = (in_w - 1) * stride_w + k_w;
+    let (out_size, pad_start) = calc_conv_transpose_output_size_and_padding(
+        &input.shape()[2..],
+        &kernel.shape()[2..],
+        &padding,
+        strides,
+        dilations,
+        output_padding,
+        output_shape,
+    )?;
 
+    // Handle 1D and 2D transposed convolutions as 3D transposed convolutions
+    // with leading spatial dims of size 1.
+    let [in_d, in_h, in_w] = expand_to_3d(&input.shape()[2..], 1);
+    let [k_d, k_h, k_w] = expand_to_3d(&kernel.shape()[2..], 1);
+    let [out_d, out_h, out_w] = expand_to_3d(&out_size, 1);
+    let strides = expand_to_3d(strides, 1);
+    let dilations = expand_to_3d(dilations, 1);
+    let pad_start = expand_to_3d(&pad_start, 0);
+
+    let bias = bias.map(|b| b.nd_view());
     let mut output = if let Some(bias) = bias {
-        init_tensor_with_channel_bias(&[batch, out_c, out_h, out_w], 1, &bias)
+        init_tensor_with_channel_bias(&[batch, out_c, out_d, out_h, out_w], 1, &bias)
     } else {
-        Tensor::zeros(&[batch, out_c, out_h, out_w])
+        Tensor::zeros(&[batch, out_c, out_d, out_h, out_w])
     };
 
     // Ensure input and kernel are contiguous to support reshaping.
     let input = input.to_contiguous();
+    let input = input.reshaped([batch, in_c, in_d, in_h, in_w]);
     let kernel = kernel.to_contiguous();
+    let kernel = kernel.reshaped([k_in_c, k_out_c, k_d, k_h, k_w]);
 
-    let mut col2im_mat = Tensor::zeros(&[in_h * in_w, out_c * k_h * k_w]);
-    let kernel_mat = kernel.reshaped([k_in_c, out_c * k_h * k_w]);
+    let in_len = in_d * in_h * in_w;
+    let in_channels_per_group = in_c / groups;
+    let mut col2im_mat = Tensor::zeros(&[in_len, k_out_c * k_d * k_h * k_w]);
 
     // The implementation here is the inverse of the im2col-based convolution.
     for n in 0..batch {
-        let input_mat = input
-            .slice::<3, _>([n])
-            .reshaped([in_c, in_h * in_w])
-            .transposed();
-
-        let col2im_row_stride = col2im_mat.stride(0);
-        gemm(
-            col2im_mat.data_mut().unwrap(),
-            col2im_row_stride,
-            input_mat,
-            kernel_mat,
-            1., /* alpha */
-            0., /* beta */
-        );
+        for group in 0..groups {
+            let in_chans = group * in_channels_per_group..(group + 1) * in_channels_per_group;
+            let out_chans = group * k_out_c..(group + 1) * k_out_c;
+
+            let input_mat = input
+                .slice::<4, _>((n, in_chans.clone()))
+                .reshaped([in_channels_per_group, in_len])
+                .transposed();
+            let kernel_mat = kernel
+                .slice::<5, _>([in_chans])
+                .reshaped([in_channels_per_group, k_out_c * k_d * k_h * k_w]);
+
+            let col2im_row_stride = col2im_mat.stride(0);
+            gemm(
+                col2im_mat.data_mut().unwrap(),
+                col2im_row_stride,
+                input_mat,
+                kernel_mat,
+                1., /* alpha */
+                0., /* beta */
+            );
 
-        col2im(
-            &mut output.nd_view_mut::<4>().slice_mut([n]),
-            &col2im_mat
-                .nd_view::<2>()
-                .reshaped([in_h, in_w, out_c, k_h, k_w]),
-            strides,
-        );
+            col2im(
+                &mut output.nd_view_mut::<5>().slice_mut((n, out_chans)),
+                &col2im_mat
+                    .nd_view::<2>()
+                    .reshaped([in_d, in_h, in_w, k_out_c, k_d, k_h, k_w]),
+                strides,
+                dilations,
+                pad_start,
+            );
+        }
     }
 
+    let out_shape: Vec<usize> = [batch, out_c].into_iter().chain(out_size).collect();
+    output.reshape(&out_shape);
+
     Ok(output)
 }
 
 #[derive(Debug)]
 pub struct ConvTranspose {
-    pub strides: [usize; 2],
+    pub padding: Padding,
+    pub groups: usize,
+    pub strides: Vec<usize>,
+    pub dilations: Vec<usize>,
+    pub output_padding: Option<Vec<usize>>,
+    pub output_shape: Option<Vec<usize>>,
 }
 
 impl Operator for ConvTranspose {
@@ -677,7 +1111,60 @@ impl Operator for ConvTranspose {
         let input = inputs.require_as(0)?;
         let weight = inputs.require_as(1)?;
         let bias = inputs.get_as(2)?;
-        conv_transpose(input, weight, bias, self.strides).into_op_result()
+        conv_transpose(
+            input,
+            weight,
+            bias,
+            self.padding.clone(),
+            self.groups,
+            &self.strides,
+            &self.dilations,
+            self.output_padding.as_deref(),
+            self.output_shape.as_deref(),
+        )
+        .into_op_result()
+    }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], output_shapes: &[&[usize]]) -> Option<u64> {
+        // Each input element is multiplied by `out_channels / groups *
+        // kernel_size` weights and accumulated into the output.
+        let input_shape = input_shapes.first().copied().flatten()?;
+        let weight_shape = input_shapes.get(1).copied().flatten()?;
+        let in_channels = *weight_shape.first()? as u64;
+        let weight_len = num_elements(weight_shape);
+        let input_positions = num_elements(input_shape).checked_div(in_channels)?;
+        let bias_flops = if input_shapes.get(2).copied().flatten().is_some() {
+            num_elements(output_shapes.first()?)
+        } else {
+            0
+        };
+        Some(2 * input_positions * weight_len + bias_flops)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| {
+            let input_shape = inputs.require_shape(0)?;
+            let kernel_shape = inputs.require_shape(1)?;
+            if !matches!(input_shape.len(), 3..=5) || kernel_shape.len() != input_shape.len() {
+                return Err(OpError::InvalidValue(
+                    "Input and kernel must both have 3, 4 or 5 dims",
+                ));
+            }
+            let (out_size, _) = calc_conv_transpose_output_size_and_padding(
+                &input_shape[2..],
+                &kernel_shape[2..],
+                &self.padding,
+                &self.strides,
+                &self.dilations,
+                self.output_padding.as_deref(),
+                self.output_shape.as_deref(),
+            )?;
+            let shape: Vec<usize> = [input_shape[0], kernel_shape[1] * self.groups]
+                .into_iter()
+                .chain(out_size)
+                .collect();
+            Ok(vec![shape.into()])
+        })())
     }
 }
 
@@ -689,9 +1176,10 @@ mod tests {
     use rten_tensor::rng::XorShiftRng;
     use rten_tensor::test_util::{expect_equal, ExpectEqualError};
     use rten_tensor::{Tensor, TensorView};
+    use smallvec::SmallVec;
 
     use crate::ops::pooling::calc_output_size_and_padding;
-    use crate::ops::tests::expect_eq_1e4;
+    use crate::ops::tests::{expect_eq_1e4, read_json_file, read_tensor};
     use crate::ops::{conv, conv_transpose, Conv, OpError, Operator, Padding};
 
     /// Un-optimized reference implementation of convolution.
@@ -1244,6 +1732,282 @@ mod tests {
         assert_eq!(result.shape(), &[n, out_c, in_w]);
     }
 
+    /// Un-optimized reference implementation of 3D convolution with fixed
+    /// padding.
+    fn reference_conv_3d(
+        input: TensorView,
+        kernel: TensorView,
+        pads: [usize; 6],
+        groups: usize,
+        strides: [usize; 3],
+        dilations: [usize; 3],
+    ) -> Tensor {
+        let [batch, in_chans, in_d, in_h, in_w]: [usize; 5] = input.shape().try_into().unwrap();
+        let [out_chans, k_in_chans, k_d, k_h, k_w]: [usize; 5] = kernel.shape().try_into().unwrap();
+        let out_size: Vec<usize> = [in_d, in_h, in_w]
+            .into_iter()
+            .enumerate()
+            .map(|(i, size)| {
+                let k_size = [k_d, k_h, k_w][i];
+                (size + pads[i] + pads[i + 3] - dilations[i] * (k_size - 1) - 1) / strides[i] + 1
+            })
+            .collect();
+        let [out_d, out_h, out_w]: [usize; 3] = out_size.try_into().unwrap();
+        let in_per_group = in_chans / groups;
+        let out_per_group = out_chans / groups;
+
+        let mut output = Tensor::zeros(&[batch, out_chans, out_d, out_h, out_w]);
+        for n in 0..batch {
+            for out_c in 0..out_chans {
+                let group = out_c / out_per_group;
+                for [z, y, x] in (0..out_d)
+                    .flat_map(|z| (0..out_h).flat_map(move |y| (0..out_w).map(move |x| [z, y, x])))
+                {
+                    let mut accum = 0.;
+                    for k_c in 0..k_in_chans {
+                        let in_c = group * in_per_group + k_c;
+                        for [k_z, k_y, k_x] in (0..k_d).flat_map(|k_z| {
+                            (0..k_h).flat_map(move |k_y| (0..k_w).map(move |k_x| [k_z, k_y, k_x]))
+                        }) {
+                            let in_z =
+                                (z * strides[0] + k_z * dilations[0]) as isize - pads[0] as isize;
+                            let in_y =
+                                (y * strides[1] + k_y * dilations[1]) as isize - pads[1] as isize;
+                            let in_x =
+                                (x * strides[2] + k_x * dilations[2]) as isize - pads[2] as isize;
+                            if in_z < 0
+                                || in_y < 0
+                                || in_x < 0
+                                || in_z >= in_d as isize
+                                || in_y >= in_h as isize
+                                || in_x >= in_w as isize
+                            {
+                                continue;
+                            }
+                            accum += input[[n, in_c, in_z as usize, in_y as usize, in_x as usize]]
+                                * kernel[[out_c, k_c, k_z, k_y, k_x]];
+                        }
+                    }
+                    output[[n, out_c, z, y, x]] = accum;
+                }
+            }
+        }
+        output
+    }
+
+    #[test]
+    fn test_conv_3d() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+
+        struct Case {
+            in_c: usize,
+            out_c: usize,
+            groups: usize,
+            kernel: [usize; 3],
+            pads: [usize; 6],
+            strides: [usize; 3],
+            dilations: [usize; 3],
+        }
+
+        let cases = [
+            // Simple case with no padding
+            Case {
+                in_c: 2,
+                out_c: 3,
+                groups: 1,
+                kernel: [3, 3, 3],
+                pads: [0; 6],
+                strides: [1; 3],
+                dilations: [1; 3],
+            },
+            // Uneven padding
+            Case {
+                in_c: 2,
+                out_c: 3,
+                groups: 1,
+                kernel: [3, 2, 3],
+                pads: [1, 0, 2, 0, 1, 1],
+                strides: [1; 3],
+                dilations: [1; 3],
+            },
+            // Strides and dilations
+            Case {
+                in_c: 2,
+                out_c: 2,
+                groups: 1,
+                kernel: [2, 2, 2],
+                pads: [1; 6],
+                strides: [2, 1, 2],
+                dilations: [1, 2, 2],
+            },
+            // Grouped convolution
+            Case {
+                in_c: 4,
+                out_c: 6,
+                groups: 2,
+                kernel: [2, 3, 3],
+                pads: [0, 1, 1, 0, 1, 1],
+                strides: [1; 3],
+                dilations: [1; 3],
+            },
+        ];
+
+        for Case {
+            in_c,
+            out_c,
+            groups,
+            kernel,
+            pads,
+            strides,
+            dilations,
+        } in cases
+        {
+            let input = Tensor::rand(&[2, in_c, 5, 6, 7], &mut rng);
+            let [k_d, k_h, k_w] = kernel;
+            let kernel = Tensor::rand(&[out_c, in_c / groups, k_d, k_h, k_w], &mut rng);
+
+            let result = conv(
+                input.view(),
+                kernel.view(),
+                None,
+                pads.into(),
+                groups,
+                &strides,
+                &dilations,
+            )
+            .unwrap();
+            let expected = reference_conv_3d(
+                input.view(),
+                kernel.view(),
+                pads,
+                groups,
+                strides,
+                dilations,
+            );
+            expect_equal(&result, &expected)?;
+        }
+
+        // Same padding, with a bias.
+        let input = Tensor::rand(&[1, 2, 4, 5, 6], &mut rng);
+        let kernel = Tensor::rand(&[3, 2, 3, 3, 3], &mut rng);
+        let bias = Tensor::from([0.5, -1., 2.]);
+        let result = conv(
+            input.view(),
+            kernel.view(),
+            Some(bias.view()),
+            Padding::Same,
+            1,
+            &[1, 1, 1],
+            &[1, 1, 1],
+        )
+        .unwrap();
+        let mut expected =
+            reference_conv_3d(input.view(), kernel.view(), [1; 6], 1, [1; 3], [1; 3]);
+        for c in 0..3 {
+            expected.slice_mut_dyn((.., c)).apply(|x| x + bias[[c]]);
+        }
+        assert_eq!(result.shape(), &[1, 3, 4, 5, 6]);
+        expect_equal(&result, &expected)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_conv_3d_invalid() {
+        let input = Tensor::zeros(&[1, 2, 4, 4, 4]);
+        let kernel = Tensor::zeros(&[2, 2, 3, 3, 3]);
+
+        let result = conv(
+            input.view(),
+            kernel.view(),
+            None,
+            [0; 6].into(),
+            1,
+            &[1, 1],
+            &[1, 1, 1],
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("expected 3 stride values"))
+        );
+
+        let result = conv(
+            input.view(),
+            kernel.view(),
+            None,
+            [0; 4].into(),
+            1,
+            &[1, 1, 1],
+            &[1, 1, 1],
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("Wrong number of padding values"))
+        );
+    }
+
+    #[test]
+    fn test_conv_transpose_3d() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+
+        // A 3D transposed convolution with a depth of 1 should match the
+        // equivalent 2D transposed convolution.
+        let input = Tensor::rand(&[2, 3, 4, 5], &mut rng);
+        let kernel = Tensor::rand(&[3, 2, 3, 3], &mut rng);
+        let expected = conv_transpose(
+            input.view(),
+            kernel.view(),
+            None,
+            [0, 0, 0, 0].into(),
+            1,
+            &[2, 1],
+            &[1, 1],
+            None,
+            None,
+        )
+        .unwrap();
+
+        let input_3d = input.to_shape([2, 3, 1, 4, 5].as_slice());
+        let kernel_3d = kernel.to_shape([3, 2, 1, 3, 3].as_slice());
+        let mut result = conv_transpose(
+            input_3d.view(),
+            kernel_3d.view(),
+            None,
+            [0; 6].into(),
+            1,
+            &[1, 2, 1],
+            &[1, 1, 1],
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(result.shape(), &[2, 2, 1, 9, 7]);
+        result.reshape(expected.shape());
+        expect_equal(&result, &expected)?;
+
+        // Each input element contributes a scaled copy of the kernel to the
+        // output, at an offset given by the strides.
+        let input = Tensor::from_data(&[1, 1, 2, 1, 1], vec![1., 2.]);
+        let kernel = Tensor::from_data(&[1, 1, 2, 1, 1], vec![1., 10.]);
+        let bias = Tensor::from([0.5]);
+        let result = conv_transpose(
+            input.view(),
+            kernel.view(),
+            Some(bias.view()),
+            [0; 6].into(),
+            1,
+            &[2, 1, 1],
+            &[1, 1, 1],
+            None,
+            None,
+        )
+        .unwrap();
+        let expected = Tensor::from_data(&[1, 1, 4, 1, 1], vec![1.5, 10.5, 2.5, 20.5]);
+        expect_equal(&result, &expected)?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_conv_transpose() -> Result<(), Box<dyn Error>> {
         let input = Tensor::from_data(&[1, 1, 2, 2], vec![1.0, 2.0, 3.0, 4.0]);
@@ -1256,7 +2020,18 @@ mod tests {
             ],
         );
 
-        let result = conv_transpose(input.view(), kernel.view(), None, [2, 2]).unwrap();
+        let result = conv_transpose(
+            input.view(),
+            kernel.view(),
+            None,
+            [0, 0, 0, 0].into(),
+            1,
+            &[2, 2],
+            &[1, 1],
+            None,
+            None,
+        )
+        .unwrap();
         expect_equal(&result, &expected)?;
 
         let mut expected_with_bias = Tensor::from_data(expected.shape().into(), expected.to_vec());
@@ -1264,13 +2039,502 @@ mod tests {
             *eb += 1.234;
         }
         let bias = Tensor::from_data(&[1], vec![1.234]);
-        let result =
-            conv_transpose(input.view(), kernel.view(), Some(bias.view()), [2, 2]).unwrap();
+        let result = conv_transpose(
+            input.view(),
+            kernel.view(),
+            Some(bias.view()),
+            [0, 0, 0, 0].into(),
+            1,
+            &[2, 2],
+            &[1, 1],
+            None,
+            None,
+        )
+        .unwrap();
         expect_equal(&result, &expected_with_bias)?;
 
         Ok(())
     }
 
+    /// Un-optimized reference implementation of a 2D transposed convolution.
+    ///
+    /// `out_size` and `pad_start` specify the spatial size of the output and
+    /// the amount of padding removed from the start of each spatial axis.
+    #[allow(clippy::too_many_arguments)]
+    fn reference_conv_transpose(
+        input: TensorView,
+        kernel: TensorView,
+        bias: Option<TensorView>,
+        groups: usize,
+        strides: [usize; 2],
+        dilations: [usize; 2],
+        out_size: [usize; 2],
+        pad_start: [usize; 2],
+    ) -> Tensor {
+        let [batch, in_chans, in_h, in_w]: [usize; 4] =
+            input.shape().try_into().expect("expected NCHW input");
+        let [_, out_channels_per_group, k_h, k_w]: [usize; 4] =
+            kernel.shape().try_into().expect("expected COHW kernel");
+        let in_channels_per_group = in_chans / groups;
+        let [out_h, out_w] = out_size;
+
+        let mut output = Tensor::zeros(&[batch, out_channels_per_group * groups, out_h, out_w]);
+        if let Some(bias) = bias {
+            for n in 0..batch {
+                for c in 0..output.size(1) {
+                    output.slice_mut::<2, _>([n, c]).fill(bias[[c]]);
+                }
+            }
+        }
+
+        for n in 0..batch {
+            for in_chan in 0..in_chans {
+                let group = in_chan / in_channels_per_group;
+                for k_out_chan in 0..out_channels_per_group {
+                    let out_chan = group * out_channels_per_group + k_out_chan;
+                    for y in 0..in_h {
+                        for x in 0..in_w {
+                            for k_y in 0..k_h {
+                                for k_x in 0..k_w {
+                                    let out_y = (y * strides[0] + k_y * dilations[0]) as isize
+                                        - pad_start[0] as isize;
+                                    let out_x = (x * strides[1] + k_x * dilations[1]) as isize
+                                        - pad_start[1] as isize;
+                                    if out_y < 0
+                                        || out_y >= out_h as isize
+                                        || out_x < 0
+                                        || out_x >= out_w as isize
+                                    {
+                                        continue;
+                                    }
+                                    output[[n, out_chan, out_y as usize, out_x as usize]] += input
+                                        [[n, in_chan, y, x]]
+                                        * kernel[[in_chan, k_out_chan, k_y, k_x]];
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        output
+    }
+
+    #[test]
+    fn test_conv_transpose_attrs() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+
+        struct Case {
+            input_shape: [usize; 4],
+            kernel_shape: [usize; 4],
+            padding: Padding,
+            groups: usize,
+            strides: [usize; 2],
+            dilations: [usize; 2],
+            output_padding: Option<[usize; 2]>,
+            output_shape: Option<[usize; 2]>,
+
+            // Expected spatial output size and start padding.
+            expected_size: [usize; 2],
+            expected_pad_start: [usize; 2],
+        }
+
+        let cases = [
+            // Fixed padding
+            Case {
+                input_shape: [1, 2, 5, 6],
+                kernel_shape: [2, 3, 3, 3],
+                padding: [1, 2, 1, 0].into(),
+                groups: 1,
+                strides: [2, 2],
+                dilations: [1, 1],
+                output_padding: None,
+                output_shape: None,
+                expected_size: [9, 11],
+                expected_pad_start: [1, 2],
+            },
+            // Groups
+            Case {
+                input_shape: [2, 4, 3, 4],
+                kernel_shape: [4, 3, 2, 2],
+                padding: [0, 0, 0, 0].into(),
+                groups: 2,
+                strides: [1, 2],
+                dilations: [1, 1],
+                output_padding: None,
+                output_shape: None,
+                expected_size: [4, 8],
+                expected_pad_start: [0, 0],
+            },
+            // Depthwise
+            Case {
+                input_shape: [1, 3, 4, 4],
+                kernel_shape: [3, 1, 3, 3],
+                padding: [1, 1, 1, 1].into(),
+                groups: 3,
+                strides: [2, 2],
+                dilations: [1, 1],
+                output_padding: None,
+                output_shape: None,
+                expected_size: [7, 7],
+                expected_pad_start: [1, 1],
+            },
+            // Dilations
+            Case {
+                input_shape: [1, 2, 4, 5],
+                kernel_shape: [2, 2, 3, 2],
+                padding: [0, 1, 0, 1].into(),
+                groups: 1,
+                strides: [1, 2],
+                dilations: [2, 3],
+                output_padding: None,
+                output_shape: None,
+                expected_size: [8, 10],
+                expected_pad_start: [0, 1],
+            },
+            // Output padding
+            Case {
+                input_shape: [1, 2, 3, 3],
+                kernel_shape: [2, 2, 3, 3],
+                padding: [1, 1, 1, 1].into(),
+                groups: 1,
+                strides: [2, 3],
+                dilations: [1, 1],
+                output_padding: Some([1, 2]),
+                output_shape: None,
+                expected_size: [6, 9],
+                expected_pad_start: [1, 1],
+            },
+            // Output shape. The padding is computed from the output shape,
+            // with any odd unit of padding added at the start.
+            Case {
+                input_shape: [1, 2, 3, 3],
+                kernel_shape: [2, 2, 3, 3],
+                padding: [0, 0, 0, 0].into(),
+                groups: 1,
+                strides: [2, 2],
+                dilations: [1, 1],
+                output_padding: None,
+                output_shape: Some([6, 4]),
+                expected_size: [6, 4],
+                expected_pad_start: [1, 2],
+            },
+            // Output shape larger than the unpadded output
+            Case {
+                input_shape: [1, 2, 3, 3],
+                kernel_shape: [2, 2, 3, 3],
+                padding: [0, 0, 0, 0].into(),
+                groups: 1,
+                strides: [2, 2],
+                dilations: [1, 1],
+                output_padding: None,
+                output_shape: Some([8, 7]),
+                expected_size: [8, 7],
+                expected_pad_start: [0, 0],
+            },
+            // "Same" padding. The output size is the input size multiplied by
+            // the stride, with any odd unit of padding added at the end.
+            Case {
+                input_shape: [1, 2, 3, 4],
+                kernel_shape: [2, 2, 3, 4],
+                padding: Padding::Same,
+                groups: 1,
+                strides: [2, 1],
+                dilations: [1, 1],
+                output_padding: None,
+                output_shape: None,
+                expected_size: [6, 4],
+                expected_pad_start: [0, 1],
+            },
+        ];
+
+        for Case {
+            input_shape,
+            kernel_shape,
+            padding,
+            groups,
+            strides,
+            dilations,
+            output_padding,
+            output_shape,
+            expected_size,
+            expected_pad_start,
+        } in cases
+        {
+            let input = Tensor::rand(&input_shape, &mut rng);
+            let kernel = Tensor::rand(&kernel_shape, &mut rng);
+            let bias = Tensor::rand(&[kernel_shape[1] * groups], &mut rng);
+
+            let result = conv_transpose(
+                input.view(),
+                kernel.view(),
+                Some(bias.view()),
+                padding,
+                groups,
+                &strides,
+                &dilations,
+                output_padding.as_ref().map(|p| p.as_slice()),
+                output_shape.as_ref().map(|s| s.as_slice()),
+            )
+            .unwrap();
+            let expected = reference_conv_transpose(
+                input.view(),
+                kernel.view(),
+                Some(bias.view()),
+                groups,
+                strides,
+                dilations,
+                expected_size,
+                expected_pad_start,
+            );
+            assert_eq!(result.shape(), expected.shape());
+            expect_eq_1e4(&result, &expected)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_conv_transpose_1d() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+
+        // A 1D transposed convolution should match the equivalent 2D
+        // transposed convolution with a height of 1.
+        let input = Tensor::rand(&[2, 4, 6], &mut rng);
+        let kernel = Tensor::rand(&[4, 3, 3], &mut rng);
+        let bias = Tensor::rand(&[6], &mut rng);
+
+        let result = conv_transpose(
+            input.view(),
+            kernel.view(),
+            Some(bias.view()),
+            [1, 2].into(),
+            2,
+            &[3],
+            &[2],
+            Some(&[1]),
+            None,
+        )
+        .unwrap();
+        assert_eq!(result.shape(), &[2, 6, 18]);
+
+        let input_2d = input.to_shape([2, 4, 1, 6].as_slice());
+        let kernel_2d = kernel.to_shape([4, 3, 1, 3].as_slice());
+        let mut expected = conv_transpose(
+            input_2d.view(),
+            kernel_2d.view(),
+            Some(bias.view()),
+            [0, 1, 0, 2].into(),
+            2,
+            &[1, 3],
+            &[1, 2],
+            Some(&[0, 1]),
+            None,
+        )
+        .unwrap();
+        expected.reshape(result.shape());
+        expect_equal(&result, &expected)?;
+
+        // Each input element contributes a scaled copy of the dilated kernel
+        // to the output. Output padding adds extra positions at the end which
+        // receive only the bias.
+        let input = Tensor::from_data(&[1, 1, 2], vec![1., 2.]);
+        let kernel = Tensor::from_data(&[1, 1, 2], vec![1., 10.]);
+        let bias = Tensor::from([0.5]);
+        let result = conv_transpose(
+            input.view(),
+            kernel.view(),
+            Some(bias.view()),
+            [0, 0].into(),
+            1,
+            &[1],
+            &[2],
+            Some(&[1]),
+            None,
+        )
+        .unwrap();
+        let expected = Tensor::from_data(&[1, 1, 5], vec![1.5, 2.5, 10.5, 20.5, 0.5]);
+        expect_equal(&result, &expected)?;
+
+        // Strides, dilations and padding that are not specified default to
+        // values for the kernel's spatial dims.
+        let result = conv_transpose(
+            input.view(),
+            kernel.view(),
+            None,
+            Padding::Fixed(SmallVec::new()),
+            1,
+            &[],
+            &[],
+            None,
+            None,
+        )
+        .unwrap();
+        let expected = Tensor::from_data(&[1, 1, 3], vec![1., 12., 20.]);
+        expect_equal(&result, &expected)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_conv_transpose_pytorch() -> Result<(), Box<dyn Error>> {
+        let dict = read_json_file("pytorch-ref-tests/conv_transpose.json");
+
+        let cases = [
+            "conv_transpose_1d",
+            "conv_transpose_2d",
+            "conv_transpose_2d_no_bias",
+            "conv_transpose_3d",
+        ];
+
+        for name in cases {
+            let case = &dict[name];
+            let read_ints = |key: &str| -> Vec<usize> {
+                case[key]
+                    .as_array()
+                    .expect("expected array")
+                    .iter()
+                    .map(|v| v.as_u64().expect("expected int") as usize)
+                    .collect()
+            };
+
+            let input = read_tensor(&case["input"])?;
+            let expected = read_tensor(&case["output"])?;
+            let weight = read_tensor(&case["params"]["weight"])?;
+            let bias = case["params"].get("bias").map(read_tensor).transpose()?;
+
+            // PyTorch pads the start and end of each spatial axis equally.
+            let padding = read_ints("padding");
+            let pads: SmallVec<_> = padding.iter().chain(&padding).copied().collect();
+
+            let result = conv_transpose(
+                input.view(),
+                weight.view(),
+                bias.as_ref().map(|b| b.view()),
+                Padding::Fixed(pads),
+                case["groups"].as_u64().expect("expected int") as usize,
+                &read_ints("stride"),
+                &read_ints("dilation"),
+                Some(&read_ints("output_padding")),
+                None,
+            )?;
+            expect_eq_1e4(&result, &expected)
+                .map_err(|err| format!("case {} failed: {}", name, err))?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_conv_transpose_invalid() {
+        let mut rng = XorShiftRng::new(1234);
+        let input = Tensor::rand(&[1, 4, 3, 3], &mut rng);
+        let kernel = Tensor::rand(&[4, 2, 3, 3], &mut rng);
+
+        struct Case<'a> {
+            padding: Padding,
+            groups: usize,
+            strides: &'a [usize],
+            dilations: &'a [usize],
+            output_padding: Option<&'a [usize]>,
+            output_shape: Option<&'a [usize]>,
+            expected: OpError,
+        }
+
+        let cases = [
+            Case {
+                padding: [0, 0, 0, 0].into(),
+                groups: 3,
+                strides: &[1, 1],
+                dilations: &[1, 1],
+                output_padding: None,
+                output_shape: None,
+                expected: OpError::IncompatibleInputShapes(
+                    "Input channels must be divisible by group count",
+                ),
+            },
+            Case {
+                padding: [0, 0, 0, 0].into(),
+                groups: 1,
+                strides: &[1],
+                dilations: &[1, 1],
+                output_padding: None,
+                output_shape: None,
+                expected: OpError::InvalidValue("Wrong number of stride values"),
+            },
+            Case {
+                padding: [0, 0, 0, 0].into(),
+                groups: 1,
+                strides: &[1, 1],
+                dilations: &[0, 1],
+                output_padding: None,
+                output_shape: None,
+                expected: OpError::InvalidValue("Dilations must be > 0"),
+            },
+            Case {
+                padding: [0, 0].into(),
+                groups: 1,
+                strides: &[1, 1],
+                dilations: &[1, 1],
+                output_padding: None,
+                output_shape: None,
+                expected: OpError::InvalidValue("Wrong number of padding values"),
+            },
+            Case {
+                padding: [3, 0, 2, 0].into(),
+                groups: 1,
+                strides: &[1, 1],
+                dilations: &[1, 1],
+                output_padding: None,
+                output_shape: None,
+                expected: OpError::InvalidValue("Padding is too large for output size"),
+            },
+            Case {
+                padding: [0, 0, 0, 0].into(),
+                groups: 1,
+                strides: &[1, 1],
+                dilations: &[1, 1],
+                output_padding: Some(&[1]),
+                output_shape: None,
+                expected: OpError::InvalidValue("Wrong number of output padding values"),
+            },
+            Case {
+                padding: [0, 0, 0, 0].into(),
+                groups: 1,
+                strides: &[1, 1],
+                dilations: &[1, 1],
+                output_padding: None,
+                output_shape: Some(&[1, 4, 5, 5]),
+                expected: OpError::InvalidValue("Wrong number of output shape values"),
+            },
+        ];
+
+        for Case {
+            padding,
+            groups,
+            strides,
+            dilations,
+            output_padding,
+            output_shape,
+            expected,
+        } in cases
+        {
+            let result = conv_transpose(
+                input.view(),
+                kernel.view(),
+                None,
+                padding,
+                groups,
+                strides,
+                dilations,
+                output_padding,
+                output_shape,
+            );
+            assert_eq!(result.err(), Some(expected));
+        }
+    }
+
     #[test]
     #[ignore]
     fn bench_depthwise_conv() {
@@ -1328,9 +2592,17 @@ mod tests {
         let out_width = (in_width - 1) * stride_x + (kernel_width - 1) + 1;
 
         let mut rng = XorShiftRng::new(1234);
-        let mut output = NdTensor::zeros([out_chans, out_height, out_width]);
+        let mut output = NdTensor::zeros([out_chans, 1, out_height, out_width]);
         let columns = NdTensor::rand(
-            [in_height, in_width, out_chans, kernel_height, kernel_width],
+            [
+                1,
+                in_height,
+                in_width,
+                out_chans,
+                1,
+                kernel_height,
+                kernel_width,
+            ],
             &mut rng,
         );
 
@@ -1338,7 +2610,9 @@ mod tests {
             col2im(
                 &mut output.view_mut(),
                 &columns.view(),
-                [stride_y, stride_x],
+                [1, stride_y, stride_x],
+                [1, 1, 1], /* dilations */
+                [0, 0, 0], /* pad_start */
             );
         });
     }
@@ -1,6 +1,92 @@
 use rten_tensor::prelude::*;
+use rten_tensor::TensorView;
 
-use crate::ops::{DataType, Input, InputList, IntoOpResult, OpError, Operator, Output};
+use crate::ops::{
+    same_shape_as_input, DataType, Input, InputList, IntoOpResult, MetaInputList, MetaOutput,
+    OpError, Operator, Output,
+};
+
+/// Element conversions used by [Cast].
+///
+/// Conversions follow the semantics of Rust's `as` operator, except for
+/// conversions to bool, which map zero to false and other values to true.
+trait CastElement: Copy {
+    fn to_f32(self) -> f32;
+    fn to_i32(self) -> i32;
+    fn to_i64(self) -> i64;
+    fn to_u8(self) -> u8;
+    fn to_bool(self) -> bool;
+}
+
+macro_rules! impl_cast_element {
+    ($type:ty, $zero:expr) => {
+        impl CastElement for $type {
+            fn to_f32(self) -> f32 {
+                self as f32
+            }
+
+            fn to_i32(self) -> i32 {
+                self as i32
+            }
+
+            fn to_i64(self) -> i64 {
+                self as i64
+            }
+
+            fn to_u8(self) -> u8 {
+                self as u8
+            }
+
+            fn to_bool(self) -> bool {
+                self != $zero
+            }
+        }
+    };
+}
+
+impl_cast_element!(f32, 0.);
+impl_cast_element!(i32, 0);
+impl_cast_element!(i64, 0);
+impl_cast_element!(u8, 0);
+
+impl CastElement for bool {
+    fn to_f32(self) -> f32 {
+        self as i32 as f32
+    }
+
+    fn to_i32(self) -> i32 {
+        self as i32
+    }
+
+    fn to_i64(self) -> i64 {
+        self as i64
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn to_bool(self) -> bool {
+        self
+    }
+}
+
+/// Convert the elements of `input` to the type given by `to`.
+///
+/// `Int64`, `Bool` and `UInt8` values are represented as `i32` tensors within
+/// a graph, so the output for these types is an `i32` tensor containing the
+/// converted values. Int64 values are saturated to the `i32` range.
+fn cast<T: CastElement>(input: TensorView<T>, to: DataType) -> Output {
+    match to {
+        DataType::Float => input.map(|x| x.to_f32()).into(),
+        DataType::Int32 => input.map(|x| x.to_i32()).into(),
+        DataType::Int64 => input
+            .map(|x| x.to_i64().clamp(i32::MIN as i64, i32::MAX as i64) as i32)
+            .into(),
+        DataType::UInt8 => input.map(|x| x.to_u8() as i32).into(),
+        DataType::Bool => input.map(|x| x.to_bool() as i32).into(),
+    }
+}
 
 #[derive(Debug)]
 pub struct Cast {
@@ -15,14 +101,11 @@ impl Operator for Cast {
     fn run(&self, inputs: InputList) -> Result<Vec<Output>, OpError> {
         let input = inputs.require(0)?;
         let result: Output = match input {
-            Input::IntTensor(t) => match self.to {
-                DataType::Int32 => t.to_tensor().into(),
-                DataType::Float => t.map(|x| *x as f32).into(),
-            },
-            Input::FloatTensor(t) => match self.to {
-                DataType::Int32 => t.map(|x| *x as i32).into(),
-                DataType::Float => t.to_tensor().into(),
-            },
+            Input::IntTensor(t) => cast(t, self.to),
+            Input::FloatTensor(t) => cast(t, self.to),
+            Input::Int64Tensor(t) => cast(t, self.to),
+            Input::BoolTensor(t) => cast(t, self.to),
+            Input::UInt8Tensor(t) => cast(t, self.to),
         };
         result.into_op_result()
     }
@@ -33,23 +116,31 @@ impl Operator for Cast {
 
     fn run_in_place(&self, input: Output, _: InputList) -> Result<Output, OpError> {
         match (input, self.to) {
-            (Output::IntTensor(t), DataType::Int32) => Ok(t.into()),
+            (Output::IntTensor(t), DataType::Int32 | DataType::Int64) => Ok(t.into()),
             (Output::FloatTensor(t), DataType::Float) => Ok(t.into()),
             (input, _) => self
                 .run(InputList::from(&[(&input).into()]))
                 .map(|mut outputs| outputs.remove(0)),
         }
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        Some(0)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(same_shape_as_input(inputs))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::error::Error;
 
-    use rten_tensor::tensor;
     use rten_tensor::test_util::expect_equal;
+    use rten_tensor::{tensor, Tensor};
 
-    use crate::ops::{Cast, DataType, Operator};
+    use crate::ops::{Cast, DataType, Input, InputList, Operator};
 
     #[test]
     fn test_cast() -> Result<(), Box<dyn Error>> {
@@ -133,4 +224,89 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_cast_boundary_types() {
+        // Casts to int64, bool and uint8 produce i32 tensors.
+        let float_input = tensor!([-1.5, 0.0, 0.5, 300.0]);
+        let int_input = tensor!([-1, 0, 1, 300]);
+        let int64_input = tensor!([i64::MIN, -1, i64::MAX]);
+        let bool_input = tensor!([true, false]);
+        let u8_input = tensor!([0u8, 255u8]);
+
+        struct Case<'a> {
+            input: Input<'a>,
+            to: DataType,
+            expected: Tensor<i32>,
+        }
+
+        let cases = [
+            Case {
+                input: (&float_input).into(),
+                to: DataType::Bool,
+                expected: tensor!([1, 0, 1, 1]),
+            },
+            Case {
+                input: (&float_input).into(),
+                to: DataType::UInt8,
+                expected: tensor!([0, 0, 0, 255]),
+            },
+            Case {
+                input: (&float_input).into(),
+                to: DataType::Int64,
+                expected: tensor!([-1, 0, 0, 300]),
+            },
+            Case {
+                input: (&int_input).into(),
+                to: DataType::Bool,
+                expected: tensor!([1, 0, 1, 1]),
+            },
+            Case {
+                input: (&int_input).into(),
+                to: DataType::UInt8,
+                expected: tensor!([255, 0, 1, 44]),
+            },
+            Case {
+                input: (&int64_input).into(),
+                to: DataType::Int64,
+                expected: tensor!([i32::MIN, -1, i32::MAX]),
+            },
+            Case {
+                input: (&bool_input).into(),
+                to: DataType::Int32,
+                expected: tensor!([1, 0]),
+            },
+            Case {
+                input: (&u8_input).into(),
+                to: DataType::Int32,
+                expected: tensor!([0, 255]),
+            },
+        ];
+
+        for Case {
+            input,
+            to,
+            expected,
+        } in cases
+        {
+            let result = Cast { to }
+                .run(InputList::from(&[input]))
+                .unwrap()
+                .remove(0)
+                .into_int()
+                .unwrap();
+            assert_eq!(result, expected, "cast to {:?}", to);
+        }
+
+        // Cast from bool to float
+        let result = Cast {
+            to: DataType::Float,
+        }
+        .run((&bool_input).into())
+        .unwrap()
+        .remove(0)
+        .into_float()
+        .unwrap();
+        assert_eq!(result, tensor!([1., 0.]));
+    }
 }
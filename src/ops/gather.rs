@@ -9,7 +9,8 @@ use smallvec::SmallVec;
 
 use crate::ops::reduce::{cmp_nan_greater, cmp_nan_less};
 use crate::ops::{
-    resolve_axis, resolve_index, Input, InputList, IntoOpResult, OpError, Operator, Output,
+    resolve_axis, resolve_index, same_shape_as_input, Input, InputList, IntoOpResult,
+    MetaInputList, MetaOutput, OpError, Operator, Output,
 };
 
 /// Gather elements from `input` specified by `indices`.
@@ -28,7 +29,12 @@ pub fn gather<T: Copy + Default>(
     for index in indices.iter().copied() {
         let size = input.size(axis) as i32;
         if index < -size || index >= size {
-            return Err(OpError::InvalidValue("Entry in `indices` is out of range"));
+            return Err(
+                OpError::InvalidValue("Entry in `indices` is out of range").with_details(format!(
+                    "index {} for axis {} with size {}",
+                    index, axis, size
+                )),
+            );
         }
     }
 
@@ -85,8 +91,23 @@ impl Operator for Gather {
         match input {
             Input::IntTensor(input) => gather(input, self.axis, indices).into_op_result(),
             Input::FloatTensor(input) => gather(input, self.axis, indices).into_op_result(),
+            _ => Err(OpError::IncorrectInputType),
         }
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        Some(0)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| {
+            let shape = inputs.require_shape(0)?;
+            let indices_shape = inputs.require_shape(1)?;
+            let axis = resolve_axis(shape.len(), self.axis)?;
+            let out_shape = [&shape[..axis], indices_shape, &shape[axis + 1..]].concat();
+            Ok(vec![out_shape.into()])
+        })())
+    }
 }
 
 /// Optimized implementation of `gather_elements` for tensor with static rank.
@@ -227,8 +248,22 @@ impl Operator for GatherElements {
             Input::FloatTensor(input) => {
                 gather_elements(input, indices, self.axis).into_op_result()
             }
+            _ => Err(OpError::IncorrectInputType),
         }
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        Some(0)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        // The output has the same shape as the indices.
+        Some(
+            inputs
+                .require_shape(1)
+                .map(|shape| vec![shape.to_vec().into()]),
+        )
+    }
 }
 
 // Specifies how to combine an existing element value with an update in a
@@ -341,6 +376,14 @@ impl Operator for ScatterElements {
             _ => Err(OpError::IncorrectInputType),
         }
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        Some(0)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(same_shape_as_input(inputs))
+    }
 }
 
 pub fn scatter_nd<
@@ -426,6 +469,14 @@ impl Operator for ScatterND {
             _ => Err(OpError::IncorrectInputType),
         }
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        Some(0)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(same_shape_as_input(inputs))
+    }
 }
 
 #[cfg(test)]
@@ -505,7 +556,10 @@ mod tests {
         let result = gather(input.view(), 0, indices.view());
         assert_eq!(
             result.err(),
-            Some(OpError::InvalidValue("Entry in `indices` is out of range"))
+            Some(
+                OpError::InvalidValue("Entry in `indices` is out of range")
+                    .with_details("index 130 for axis 0 with size 128")
+            )
         );
     }
 
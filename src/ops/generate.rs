@@ -5,7 +5,8 @@ use rten_tensor::prelude::*;
 use rten_tensor::{NdTensorView, Tensor, TensorView};
 
 use crate::ops::{
-    resolve_axis, resolve_index, Input, InputList, IntoOpResult, OpError, Operator, Output, Scalar,
+    resolve_axis, resolve_index, Input, InputList, IntoOpResult, MetaInputList, MetaOutput,
+    OpError, Operator, Output, Scalar,
 };
 use crate::static_dims;
 
@@ -34,6 +35,22 @@ impl Operator for ConstantOfShape {
             Scalar::Float(value) => constant_of_shape(value, &shape).into_op_result(),
         }
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        Some(0)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| {
+            let shape = inputs.require_value_as::<i32>(0)?;
+            let shape = static_dims!(shape, 1)?;
+            if shape.iter().any(|&size| size < 0) {
+                return Err(OpError::InvalidValue("shape must be non-negative"));
+            }
+            let shape: Vec<usize> = shape.iter().map(|&size| size as usize).collect();
+            Ok(vec![shape.into()])
+        })())
+    }
 }
 
 pub fn onehot<T: Copy + Default + PartialEq>(
@@ -102,8 +119,28 @@ impl Operator for OneHot {
                 let (on_value, off_value) = extract_on_off_values(values)?;
                 onehot(indices, self.axis, depth, on_value, off_value).into_op_result()
             }
+            _ => Err(OpError::IncorrectInputType),
         }
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        Some(0)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| {
+            let indices_shape = inputs.require_shape(0)?;
+            let depth = inputs.require_value_as::<i32>(1)?;
+            let depth = depth
+                .item()
+                .and_then(|&val| if val > 0 { Some(val as usize) } else { None })
+                .ok_or(OpError::InvalidValue("`depth` must be a positive scalar"))?;
+            let axis = resolve_axis(indices_shape.len() + 1, self.axis)?;
+            let mut out_shape = indices_shape.to_vec();
+            out_shape.insert(axis, depth);
+            Ok(vec![out_shape.into()])
+        })())
+    }
 }
 
 pub fn range<T: Copy + Default + ops::Add<Output = T> + PartialOrd>(
@@ -153,8 +190,13 @@ impl Operator for Range {
                 let delta = delta.try_into()?;
                 range::<i32>(start, limit, delta).into_op_result()
             }
+            _ => Err(OpError::IncorrectInputType),
         }
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        Some(0)
+    }
 }
 
 #[cfg(test)]
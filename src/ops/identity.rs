@@ -1,6 +1,9 @@
 use rten_tensor::prelude::*;
 
-use crate::ops::{Input, InputList, IntoOpResult, OpError, Operator, Output};
+use crate::ops::{
+    same_shape_as_input, Input, InputList, IntoOpResult, MetaInputList, MetaOutput, OpError,
+    Operator, Output,
+};
 
 #[derive(Debug)]
 pub struct Identity {}
@@ -15,6 +18,9 @@ impl Operator for Identity {
         let result: Output = match input {
             Input::IntTensor(t) => t.to_tensor().into(),
             Input::FloatTensor(t) => t.to_tensor().into(),
+            Input::Int64Tensor(t) => t.to_tensor().into(),
+            Input::BoolTensor(t) => t.to_tensor().into(),
+            Input::UInt8Tensor(t) => t.to_tensor().into(),
         };
         result.into_op_result()
     }
@@ -26,6 +32,14 @@ impl Operator for Identity {
     fn run_in_place(&self, input: Output, _: InputList) -> Result<Output, OpError> {
         Ok(input)
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        Some(0)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(same_shape_as_input(inputs))
+    }
 }
 
 #[cfg(test)]
@@ -7,7 +7,8 @@ use rten_tensor::{is_valid_permutation, tensor, NdTensorView, Tensor, TensorView
 
 use crate::ops::binary_elementwise::{broadcast_shapes, fast_broadcast_cycles_repeats};
 use crate::ops::{
-    resolve_axes, resolve_axis, Input, InputList, IntoOpResult, OpError, Operator, Output,
+    resolve_axes, resolve_axis, Input, InputList, IntoOpResult, MetaInputList, MetaOutput, OpError,
+    Operator, Output,
 };
 use crate::static_dims;
 
@@ -17,9 +18,10 @@ fn expand_output_shape(
     shape: &NdTensorView<i32, 1>,
 ) -> Result<Vec<usize>, OpError> {
     let shape_vec: Vec<_> = shape.iter().map(|el| *el as usize).collect();
-    broadcast_shapes(input_shape, &shape_vec).ok_or(OpError::IncompatibleInputShapes(
-        "Cannot broadcast input with target shape",
-    ))
+    broadcast_shapes(input_shape, &shape_vec).ok_or_else(|| {
+        OpError::IncompatibleInputShapes("Cannot broadcast input with target shape")
+            .with_details(format!("{:?} and {:?}", input_shape, shape_vec))
+    })
 }
 
 /// Broadcast `input` to `out_shape`. This assumes that `out_shape` has already
@@ -88,6 +90,7 @@ impl Operator for Expand {
         match input {
             Input::FloatTensor(input) => expand(input, &shape).into_op_result(),
             Input::IntTensor(input) => expand(input, &shape).into_op_result(),
+            _ => Err(OpError::IncorrectInputType),
         }
     }
 
@@ -109,9 +112,23 @@ impl Operator for Expand {
         let output: Output = match input {
             Output::FloatTensor(input) => expand_to(input.view(), &out_shape).into(),
             Output::IntTensor(input) => expand_to(input.view(), &out_shape).into(),
+            _ => return Err(OpError::IncorrectInputType),
         };
         Ok(output)
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        Some(0)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| {
+            let shape = inputs.require_value_as(1)?;
+            let shape = static_dims!(shape, 1)?;
+            let out_shape = expand_output_shape(inputs.require_shape(0)?, &shape)?;
+            Ok(vec![out_shape.into()])
+        })())
+    }
 }
 
 fn flattened_shape(shape: &[usize], axis: isize) -> Result<[usize; 2], OpError> {
@@ -150,6 +167,7 @@ impl Operator for Flatten {
         match input {
             Input::FloatTensor(input) => flatten(input, self.axis).into_op_result(),
             Input::IntTensor(input) => flatten(input, self.axis).into_op_result(),
+            _ => Err(OpError::IncorrectInputType),
         }
     }
 
@@ -167,8 +185,20 @@ impl Operator for Flatten {
                 flatten_in_place(&mut output, self.axis)?;
                 Ok(output.into())
             }
+            _ => Err(OpError::IncorrectInputType),
         }
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        Some(0)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| {
+            let shape = flattened_shape(inputs.require_shape(0)?, self.axis)?;
+            Ok(vec![shape.to_vec().into()])
+        })())
+    }
 }
 
 /// Compute the target shape for a reshape operation, given the shape of the
@@ -231,7 +261,12 @@ fn resolve_shape(
     if remainder != 0 {
         return Err(OpError::InvalidValue(
             "Input length must be a multiple of specified dimensions",
-        ));
+        )
+        .with_details(format!(
+            "input shape {:?}, target shape {:?}",
+            input_shape,
+            shape.to_vec()
+        )));
     }
 
     Ok(shape
@@ -284,6 +319,7 @@ impl Operator for Reshape {
         match input {
             Input::IntTensor(t) => reshape(t, &shape, self.allow_zero).into_op_result(),
             Input::FloatTensor(t) => reshape(t, &shape, self.allow_zero).into_op_result(),
+            _ => Err(OpError::IncorrectInputType),
         }
     }
 
@@ -304,8 +340,22 @@ impl Operator for Reshape {
                 reshape_in_place(&mut output, &shape, self.allow_zero)?;
                 Ok(output.into())
             }
+            _ => Err(OpError::IncorrectInputType),
         }
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        Some(0)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| {
+            let shape = inputs.require_value_as(1)?;
+            let shape = static_dims!(shape, 1)?;
+            let out_shape = resolve_shape(inputs.require_shape(0)?, &shape, self.allow_zero)?;
+            Ok(vec![out_shape.into()])
+        })())
+    }
 }
 
 #[derive(Debug)]
@@ -328,6 +378,23 @@ impl Operator for Shape {
         );
         shape.into_op_result()
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        Some(0)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        // The output is known, since it depends only on the input's shape.
+        Some((|| {
+            let shape: Vec<i32> = inputs
+                .require_shape(0)?
+                .iter()
+                .map(|&el| el as i32)
+                .collect();
+            let shape = Tensor::from_data(&[shape.len()], shape);
+            Ok(vec![MetaOutput::Value(shape.into())])
+        })())
+    }
 }
 
 #[derive(Debug)]
@@ -343,21 +410,32 @@ impl Operator for Size {
         let len = input.len() as i32;
         tensor!(len).into_op_result()
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        Some(0)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| {
+            let len = inputs.require_shape(0)?.iter().product::<usize>() as i32;
+            Ok(vec![MetaOutput::Value(tensor!(len).into())])
+        })())
+    }
 }
 
-pub fn squeeze_in_place<T: Clone>(
-    input: &mut Tensor<T>,
+fn squeezed_shape(
+    shape: &[usize],
     axes: Option<NdTensorView<i32, 1>>,
-) -> Result<(), OpError> {
+) -> Result<Vec<usize>, OpError> {
     let axes = axes
-        .map(|axes| resolve_axes(input.ndim(), axes.iter()))
+        .map(|axes| resolve_axes(shape.len(), axes.iter()))
         .transpose()?;
     if let Some(ref axes) = axes {
         for &axis in axes.iter() {
-            if axis >= input.ndim() {
+            if axis >= shape.len() {
                 return Err(OpError::InvalidValue("Axis is invalid"));
             }
-            if input.size(axis) != 1 {
+            if shape[axis] != 1 {
                 return Err(OpError::InvalidValue(
                     "Can only remove dimensions of size 1",
                 ));
@@ -365,8 +443,7 @@ pub fn squeeze_in_place<T: Clone>(
         }
     }
 
-    let new_shape: Vec<_> = input
-        .shape()
+    Ok(shape
         .iter()
         .enumerate()
         .filter(|(dim, &size)| {
@@ -377,7 +454,14 @@ pub fn squeeze_in_place<T: Clone>(
             }
         })
         .map(|(_, &size)| size)
-        .collect();
+        .collect())
+}
+
+pub fn squeeze_in_place<T: Clone>(
+    input: &mut Tensor<T>,
+    axes: Option<NdTensorView<i32, 1>>,
+) -> Result<(), OpError> {
+    let new_shape = squeezed_shape(input.shape(), axes)?;
     input.reshape(&new_shape);
     Ok(())
 }
@@ -407,6 +491,7 @@ impl Operator for Squeeze {
         match input {
             Input::FloatTensor(t) => squeeze(t, axes).into_op_result(),
             Input::IntTensor(t) => squeeze(t, axes).into_op_result(),
+            _ => Err(OpError::IncorrectInputType),
         }
     }
 
@@ -427,9 +512,35 @@ impl Operator for Squeeze {
                 squeeze_in_place(&mut t, axes)?;
                 t.into()
             }
+            _ => return Err(OpError::IncorrectInputType),
         };
         Ok(result)
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        Some(0)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| {
+            let axes = inputs.get_value_as(1)?;
+            let axes = axes.map(|axes| static_dims!(axes, 1)).transpose()?;
+            let shape = squeezed_shape(inputs.require_shape(0)?, axes)?;
+            Ok(vec![shape.into()])
+        })())
+    }
+}
+
+fn transposed_shape(shape: &[usize], permutation: Option<&[usize]>) -> Result<Vec<usize>, OpError> {
+    match permutation {
+        Some(order) => {
+            if !is_valid_permutation(shape.len(), order) {
+                return Err(OpError::InvalidValue("Permutation is invalid"));
+            }
+            Ok(order.iter().map(|&dim| shape[dim]).collect())
+        }
+        None => Ok(shape.iter().copied().rev().collect()),
+    }
 }
 
 pub fn transpose<T: Clone>(
@@ -469,16 +580,25 @@ impl Operator for Transpose {
         match input {
             Input::FloatTensor(input) => transpose(input, perm_slice).into_op_result(),
             Input::IntTensor(input) => transpose(input, perm_slice).into_op_result(),
+            _ => Err(OpError::IncorrectInputType),
         }
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        Some(0)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| {
+            let shape = transposed_shape(inputs.require_shape(0)?, self.perm.as_deref())?;
+            Ok(vec![shape.into()])
+        })())
+    }
 }
 
-pub fn unsqueeze<T: Clone>(
-    input: TensorView<T>,
-    axes: &NdTensorView<i32, 1>,
-) -> Result<Tensor<T>, OpError> {
-    let mut new_shape: Vec<_> = input.shape().to_vec();
-    let mut sorted_axes: Vec<_> = resolve_axes(input.ndim() + axes.len(), axes.iter())?;
+fn unsqueezed_shape(shape: &[usize], axes: &NdTensorView<i32, 1>) -> Result<Vec<usize>, OpError> {
+    let mut new_shape: Vec<_> = shape.to_vec();
+    let mut sorted_axes: Vec<_> = resolve_axes(shape.len() + axes.len(), axes.iter())?;
     sorted_axes.sort();
 
     let axes_unique =
@@ -490,6 +610,14 @@ pub fn unsqueeze<T: Clone>(
     for axis in sorted_axes {
         new_shape.insert(axis, 1);
     }
+    Ok(new_shape)
+}
+
+pub fn unsqueeze<T: Clone>(
+    input: TensorView<T>,
+    axes: &NdTensorView<i32, 1>,
+) -> Result<Tensor<T>, OpError> {
+    let new_shape = unsqueezed_shape(input.shape(), axes)?;
     let mut output = input.to_tensor();
     output.reshape(&new_shape);
     Ok(output)
@@ -511,8 +639,22 @@ impl Operator for Unsqueeze {
         match input {
             Input::FloatTensor(input) => unsqueeze(input, &axes).into_op_result(),
             Input::IntTensor(input) => unsqueeze(input, &axes).into_op_result(),
+            _ => Err(OpError::IncorrectInputType),
         }
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        Some(0)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| {
+            let axes = inputs.require_value_as(1)?;
+            let axes = static_dims!(axes, 1)?;
+            let shape = unsqueezed_shape(inputs.require_shape(0)?, &axes)?;
+            Ok(vec![shape.into()])
+        })())
+    }
 }
 
 #[cfg(test)]
@@ -582,9 +724,10 @@ mod tests {
         let result = expand(input.view(), &shape.view());
         assert_eq!(
             result.err(),
-            Some(OpError::IncompatibleInputShapes(
-                "Cannot broadcast input with target shape"
-            ))
+            Some(
+                OpError::IncompatibleInputShapes("Cannot broadcast input with target shape")
+                    .with_details("[3] and [2, 2]")
+            )
         );
     }
 
@@ -679,20 +822,27 @@ mod tests {
 
     #[test]
     fn test_reshape_with_unsolvable_unspecified_dim() {
-        let expected_err = Some(OpError::InvalidValue(
-            "Input length must be a multiple of specified dimensions",
-        ));
+        let expected_err =
+            OpError::InvalidValue("Input length must be a multiple of specified dimensions");
 
         let input = Tensor::from_data(&[2, 2], vec![-0.5, 0.5, 3.0, -5.5]);
         let shape = ndtensor!([5, -1]);
         let result = reshape(input.view(), &shape.view(), false /* allow_zero */);
-        assert_eq!(result.err(), expected_err);
+        assert_eq!(
+            result.err(),
+            Some(expected_err.with_details("input shape [2, 2], target shape [5, -1]"))
+        );
 
         // Case when allow_zero is true
+        let expected_err =
+            OpError::InvalidValue("Input length must be a multiple of specified dimensions");
         let input = Tensor::from_data(&[1], vec![1]);
         let shape = ndtensor!([0, -1]);
         let result = reshape(input.view(), &shape.view(), true /* allow_zero */);
-        assert_eq!(result.err(), expected_err);
+        assert_eq!(
+            result.err(),
+            Some(expected_err.with_details("input shape [1], target shape [0, -1]"))
+        );
     }
 
     #[test]
@@ -7,7 +7,9 @@ use crate::check_dims;
 use crate::gemm::{gemm, GemmExecutor, GemmInputA, GemmInputB};
 use crate::ops::binary_elementwise::broadcast_shapes;
 use crate::ops::layout::expand_to;
-use crate::ops::{InputList, IntoOpResult, OpError, Operator, Output};
+use crate::ops::{
+    num_elements, InputList, IntoOpResult, MetaInputList, MetaOutput, OpError, Operator, Output,
+};
 
 #[derive(Debug)]
 pub struct Gemm {
@@ -42,9 +44,10 @@ pub fn gemm_op(
     let mut output = match c {
         Some(c) if beta != 0. => {
             if !c.can_broadcast_to(out_shape) {
-                return Err(OpError::IncompatibleInputShapes(
-                    "Cannot broadcast c to output shape",
-                ));
+                return Err(
+                    OpError::IncompatibleInputShapes("Cannot broadcast c to output shape")
+                        .with_details(format!("{:?} and {:?}", c.shape(), out_shape)),
+                );
             }
             expand_to(c, out_shape)
         }
@@ -85,6 +88,42 @@ impl Operator for Gemm {
         )
         .into_op_result()
     }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], output_shapes: &[&[usize]]) -> Option<u64> {
+        let a_shape = input_shapes.first().copied().flatten()?;
+        let k = if self.transpose_a {
+            a_shape.first()
+        } else {
+            a_shape.get(1)
+        };
+        let c_flops = if input_shapes.get(2).copied().flatten().is_some() {
+            num_elements(output_shapes.first()?)
+        } else {
+            0
+        };
+        Some(2 * *k? as u64 * num_elements(output_shapes.first()?) + c_flops)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| {
+            let a_shape = inputs.require_shape(0)?;
+            let b_shape = inputs.require_shape(1)?;
+            if a_shape.len() != 2 || b_shape.len() != 2 {
+                return Err(OpError::InvalidValue("Inputs must have 2 dimensions"));
+            }
+            let rows = if self.transpose_a {
+                a_shape[1]
+            } else {
+                a_shape[0]
+            };
+            let cols = if self.transpose_b {
+                b_shape[0]
+            } else {
+                b_shape[1]
+            };
+            Ok(vec![vec![rows, cols].into()])
+        })())
+    }
 }
 
 /// Hints for how a batched MatMul should be performed. This exists to enable
@@ -118,7 +157,8 @@ fn matmul_impl(a: TensorView, b: TensorView, strategy: MatmulStrategy) -> Result
     if a_cols != b_rows {
         return Err(OpError::IncompatibleInputShapes(
             "Columns of first matrix does not match rows of second matrix",
-        ));
+        )
+        .with_details(format!("{:?} and {:?}", a.shape(), b.shape())));
     }
 
     let a_prefix = &a.shape()[..a.ndim() - 2];
@@ -127,8 +167,13 @@ fn matmul_impl(a: TensorView, b: TensorView, strategy: MatmulStrategy) -> Result
     let num_a_matrices: usize = a_prefix.iter().product();
     let num_b_matrices: usize = b_prefix.iter().product();
 
-    let out_prefix = broadcast_shapes(a_prefix, b_prefix)
-        .ok_or(OpError::IncompatibleInputShapes("Cannot broadcast shapes"))?;
+    let out_prefix = broadcast_shapes(a_prefix, b_prefix).ok_or_else(|| {
+        OpError::IncompatibleInputShapes("Cannot broadcast shapes").with_details(format!(
+            "{:?} and {:?}",
+            a.shape(),
+            b.shape()
+        ))
+    })?;
     let out_shape = &[out_prefix.as_slice(), &[a_rows, b_cols]].concat();
 
     // A batched matrix multiplication with `[A, M, K] x [K, N]`, where `A` can
@@ -212,6 +257,28 @@ fn matmul_impl(a: TensorView, b: TensorView, strategy: MatmulStrategy) -> Result
     Ok(output)
 }
 
+/// Compute the output shape of a [matmul] of tensors with shapes `a` and `b`.
+fn matmul_output_shape(a: &[usize], b: &[usize]) -> Result<Vec<usize>, OpError> {
+    if a.len() < 2 || b.len() < 2 {
+        return Err(OpError::InvalidValue("Inputs must have >= 2 dimensions"));
+    }
+    let (a_prefix, a_matrix) = a.split_at(a.len() - 2);
+    let (b_prefix, b_matrix) = b.split_at(b.len() - 2);
+
+    if a_matrix[1] != b_matrix[0] {
+        return Err(OpError::IncompatibleInputShapes(
+            "Columns of first matrix does not match rows of second matrix",
+        )
+        .with_details(format!("{:?} and {:?}", a, b)));
+    }
+
+    let out_prefix = broadcast_shapes(a_prefix, b_prefix).ok_or_else(|| {
+        OpError::IncompatibleInputShapes("Cannot broadcast shapes")
+            .with_details(format!("{:?} and {:?}", a, b))
+    })?;
+    Ok([out_prefix.as_slice(), &[a_matrix[0], b_matrix[1]]].concat())
+}
+
 #[derive(Debug)]
 pub struct MatMul {}
 
@@ -225,6 +292,19 @@ impl Operator for MatMul {
         let b = inputs.require_as(1)?;
         matmul(a, b).into_op_result()
     }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], output_shapes: &[&[usize]]) -> Option<u64> {
+        let k = input_shapes.first().copied().flatten()?.last()?;
+        Some(2 * *k as u64 * num_elements(output_shapes.first()?))
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| {
+            let a_shape = inputs.require_shape(0)?;
+            let b_shape = inputs.require_shape(1)?;
+            Ok(vec![matmul_output_shape(a_shape, b_shape)?.into()])
+        })())
+    }
 }
 
 #[cfg(test)]
@@ -346,9 +426,10 @@ mod tests {
 
         assert_eq!(
             result.err(),
-            Some(OpError::IncompatibleInputShapes(
-                "Cannot broadcast c to output shape"
-            ))
+            Some(
+                OpError::IncompatibleInputShapes("Cannot broadcast c to output shape")
+                    .with_details("[3, 5] and [3, 8]")
+            )
         );
     }
 
@@ -430,12 +511,14 @@ mod tests {
                 b_shape: &[11, 8],
                 error: OpError::IncompatibleInputShapes(
                     "Columns of first matrix does not match rows of second matrix",
-                ),
+                )
+                .with_details("[3, 10] and [11, 8]"),
             },
             Case {
                 a_shape: &[2, 3, 10],
                 b_shape: &[3, 10, 8],
-                error: OpError::IncompatibleInputShapes("Cannot broadcast shapes"),
+                error: OpError::IncompatibleInputShapes("Cannot broadcast shapes")
+                    .with_details("[2, 3, 10] and [3, 10, 8]"),
             },
         ];
 
@@ -21,6 +21,7 @@ use smallvec::SmallVec;
 use rten_tensor::prelude::*;
 use rten_tensor::{DynLayout, NdTensor, NdTensorView, Tensor, TensorView};
 
+mod attention;
 mod binary_elementwise;
 mod concat;
 mod conv;
@@ -47,11 +48,12 @@ mod trilu;
 mod unary_elementwise;
 mod variadic_elementwise;
 
+pub use attention::{attention, Attention, AttentionOptions};
 pub use binary_elementwise::{
     add, add_in_place, and, div, div_in_place, equal, greater, greater_or_equal, less,
-    less_or_equal, mod_op, mul, mul_in_place, or, pow, pow_in_place, sub, sub_in_place, where_op,
-    xor, Add, And, Div, DivMode, Equal, Greater, GreaterOrEqual, Less, LessOrEqual, Mod, Mul, Or,
-    Pow, Sub, Where, Xor,
+    less_or_equal, mod_op, mul, mul_in_place, or, pow, pow_in_place, prelu, prelu_in_place, sub,
+    sub_in_place, where_op, xor, Add, And, Div, DivMode, Equal, Greater, GreaterOrEqual, Less,
+    LessOrEqual, Mod, Mul, Or, PRelu, Pow, Sub, Where, Xor,
 };
 pub use concat::{concat, tile, Concat, Tile};
 pub use conv::{conv, conv_transpose, Conv, ConvTranspose};
@@ -69,12 +71,16 @@ pub use layout::{
 pub use matmul::{gemm_op, matmul, Gemm, MatMul};
 pub use non_max_suppression::{non_max_suppression, BoxOrder, NonMaxSuppression};
 pub use norm::{
-    batch_norm, batch_norm_in_place, instance_normalization, layer_normalization, log_softmax,
-    softmax, BatchNormalization, InstanceNormalization, LayerNormalization, LogSoftmax, Softmax,
+    batch_norm, batch_norm_in_place, group_normalization, instance_normalization,
+    layer_normalization, log_softmax, rms_normalization, softmax, BatchNormalization,
+    GroupNormalization, InstanceNormalization, LayerNormalization, LogSoftmax, RMSNormalization,
+    Softmax,
 };
-pub use pad::{pad, Pad};
+pub use pad::{pad, pad_with_mode, Pad, PadMode};
 pub use pooling::{
-    average_pool, global_average_pool, max_pool, AveragePool, GlobalAveragePool, MaxPool,
+    average_pool, global_average_pool, global_lp_pool, global_max_pool, lp_pool, max_pool,
+    max_pool_with_indices, AveragePool, GlobalAveragePool, GlobalLpPool, GlobalMaxPool, LpPool,
+    MaxPool,
 };
 
 #[cfg(feature = "random")]
@@ -86,22 +92,28 @@ pub use reduce::{
     ReduceMax, ReduceMean, ReduceMin, ReduceProd, ReduceSum, ReduceSumSquare, TopK,
 };
 pub use resize::{
-    resize, resize_image, CoordTransformMode, NearestMode, Resize, ResizeMode, ResizeTarget,
+    resize, resize_image, CoordTransformMode, NearestMode, Resize, ResizeMode, ResizeOptions,
+    ResizeTarget,
+};
+pub use rnn::{
+    gru, gru_with_clip, lstm, lstm_with_peepholes_and_clip, rnn, Direction, RnnActivation, GRU,
+    LSTM, RNN,
 };
-pub use rnn::{gru, lstm, Direction, GRU, LSTM};
 pub use slice::{slice, slice_in_place, Slice};
 pub use split::{split, Split};
 pub use trilu::{trilu, Trilu};
 pub use unary_elementwise::{
     abs, abs_in_place, acos, acos_in_place, asin, asin_in_place, atan, atan_in_place, ceil,
-    ceil_in_place, clip, clip_in_place, cos, cos_in_place, erf, erf_in_place, exp, exp_in_place,
-    floor, floor_in_place, hard_sigmoid, hard_sigmoid_in_place, hard_swish, hard_swish_in_place,
-    leaky_relu, leaky_relu_in_place, log, log_in_place, neg, neg_in_place, not, not_in_place,
-    reciprocal, reciprocal_in_place, relu, relu_in_place, round, round_in_place, sigmoid,
-    sigmoid_in_place, sign, sign_in_place, sin, sin_in_place, sqrt, sqrt_in_place, tan,
-    tan_in_place, tanh, tanh_in_place, Abs, Acos, Asin, Atan, Ceil, Clip, Cos, Erf, Exp, Floor,
-    HardSigmoid, HardSwish, LeakyRelu, Log, Neg, Not, Reciprocal, Relu, Round, Sigmoid, Sign, Sin,
-    Sqrt, Tan, Tanh,
+    ceil_in_place, celu, celu_in_place, clip, clip_in_place, cos, cos_in_place, elu, elu_in_place,
+    erf, erf_in_place, exp, exp_in_place, floor, floor_in_place, gelu, gelu_in_place, hard_sigmoid,
+    hard_sigmoid_in_place, hard_swish, hard_swish_in_place, leaky_relu, leaky_relu_in_place, log,
+    log_in_place, mish, mish_in_place, neg, neg_in_place, not, not_in_place, reciprocal,
+    reciprocal_in_place, relu, relu_in_place, round, round_in_place, selu, selu_in_place, sigmoid,
+    sigmoid_in_place, sign, sign_in_place, sin, sin_in_place, softplus, softplus_in_place,
+    softsign, softsign_in_place, sqrt, sqrt_in_place, tan, tan_in_place, tanh, tanh_in_place,
+    thresholded_relu, thresholded_relu_in_place, Abs, Acos, Asin, Atan, Ceil, Celu, Clip, Cos, Elu,
+    Erf, Exp, Floor, Gelu, HardSigmoid, HardSwish, LeakyRelu, Log, Mish, Neg, Not, Reciprocal,
+    Relu, Round, Selu, Sigmoid, Sign, Sin, Softplus, Softsign, Sqrt, Tan, Tanh, ThresholdedRelu,
 };
 pub use variadic_elementwise::{max, mean, min, sum, Max, Mean, Min, Sum};
 
@@ -131,17 +143,45 @@ impl<S: AsRef<[usize]>> From<S> for Padding {
     }
 }
 
-#[derive(Copy, Clone, Debug)]
+/// Element types that the [Cast] operator can convert to.
+///
+/// Operators inside a graph only produce `f32` and `i32` tensors. Casts to
+/// `Int64`, `Bool` and `UInt8` produce `i32` tensors whose values have been
+/// converted as if they had the target type (eg. a cast to `Bool` produces
+/// zeros and ones).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum DataType {
     Int32,
     Float,
+    Int64,
+    Bool,
+    UInt8,
+}
+
+impl DataType {
+    /// Return the size in bytes of an element of this type.
+    pub fn size(self) -> usize {
+        match self {
+            DataType::Int32 | DataType::Float => 4,
+            DataType::Int64 => 8,
+            DataType::Bool | DataType::UInt8 => 1,
+        }
+    }
 }
 
 /// Enum of the different types of input tensor that an operator can accept.
+///
+/// Operators work with `f32` and `i32` tensors. The `i64`, `bool` and `u8`
+/// variants exist so that model inputs can be supplied with the types declared
+/// by the original model. These are converted to `i32` tensors when passed
+/// to [Model::run](crate::Model::run).
 #[derive(Clone)]
 pub enum Input<'a> {
     FloatTensor(TensorView<'a, f32>),
     IntTensor(TensorView<'a, i32>),
+    Int64Tensor(TensorView<'a, i64>),
+    BoolTensor(TensorView<'a, bool>),
+    UInt8Tensor(TensorView<'a, u8>),
 }
 
 impl<'a> Input<'a> {
@@ -149,6 +189,44 @@ impl<'a> Input<'a> {
         match self {
             Input::FloatTensor(t) => t.layout(),
             Input::IntTensor(t) => t.layout(),
+            Input::Int64Tensor(t) => t.layout(),
+            Input::BoolTensor(t) => t.layout(),
+            Input::UInt8Tensor(t) => t.layout(),
+        }
+    }
+
+    /// Return the element type of this input.
+    pub fn dtype(&self) -> DataType {
+        match self {
+            Input::FloatTensor(_) => DataType::Float,
+            Input::IntTensor(_) => DataType::Int32,
+            Input::Int64Tensor(_) => DataType::Int64,
+            Input::BoolTensor(_) => DataType::Bool,
+            Input::UInt8Tensor(_) => DataType::UInt8,
+        }
+    }
+
+    /// Convert an input with one of the element types that is only supported
+    /// at the model boundary (`i64`, `bool`, `u8`) into an `i32` tensor.
+    ///
+    /// Returns `None` if the input already has a type that operators support.
+    pub(crate) fn to_int_tensor(&self) -> Option<Result<Tensor<i32>, OpError>> {
+        match self {
+            Input::FloatTensor(_) | Input::IntTensor(_) => None,
+            Input::Int64Tensor(t) => {
+                let data: Result<Vec<i32>, OpError> = t
+                    .iter()
+                    .map(|&x| {
+                        i32::try_from(x).map_err(|_| {
+                            OpError::InvalidValue("Int64 value is out of range for i32")
+                                .with_details(format!("value {}", x))
+                        })
+                    })
+                    .collect();
+                Some(data.map(|data| Tensor::from_data(t.shape(), data)))
+            }
+            Input::BoolTensor(t) => Some(Ok(t.map(|&x| x as i32))),
+            Input::UInt8Tensor(t) => Some(Ok(t.map(|&x| x as i32))),
         }
     }
 }
@@ -264,21 +342,34 @@ macro_rules! impl_input_conversions {
 
 impl_input_conversions!(FloatTensor, f32);
 impl_input_conversions!(IntTensor, i32);
+impl_input_conversions!(Int64Tensor, i64);
+impl_input_conversions!(BoolTensor, bool);
+impl_input_conversions!(UInt8Tensor, u8);
 
 impl<'a> From<&'a Output> for Input<'a> {
     fn from(output: &'a Output) -> Input {
         match output {
             Output::FloatTensor(t) => Input::FloatTensor(t.view()),
             Output::IntTensor(t) => Input::IntTensor(t.view()),
+            Output::Int64Tensor(t) => Input::Int64Tensor(t.view()),
+            Output::BoolTensor(t) => Input::BoolTensor(t.view()),
+            Output::UInt8Tensor(t) => Input::UInt8Tensor(t.view()),
         }
     }
 }
 
 /// Enum of the different types of output tensor that an operator can produce.
+///
+/// Operators produce `f32` and `i32` tensors. An `i32` output can be converted
+/// to an `i64`, `bool` or `u8` tensor using `TryFrom`, for models whose
+/// outputs have one of these types.
 #[derive(Debug, Clone, PartialEq)]
 pub enum Output {
     FloatTensor(Tensor<f32>),
     IntTensor(Tensor<i32>),
+    Int64Tensor(Tensor<i64>),
+    BoolTensor(Tensor<bool>),
+    UInt8Tensor(Tensor<u8>),
 }
 
 impl Output {
@@ -318,6 +409,9 @@ impl Output {
         match self {
             Output::IntTensor(t) => t.layout(),
             Output::FloatTensor(t) => t.layout(),
+            Output::Int64Tensor(t) => t.layout(),
+            Output::BoolTensor(t) => t.layout(),
+            Output::UInt8Tensor(t) => t.layout(),
         }
     }
 }
@@ -364,8 +458,12 @@ impl Layout for Output {
 }
 
 /// Declare conversions between `Output` and `Tensor<T>` / `NdTensor<T, N>`.
+///
+/// If a conversion function is given, owned `i32` outputs can also be
+/// converted to `Tensor<T>` using that function. The conversion fails if any
+/// element is out of range for the target type.
 macro_rules! impl_output_conversions {
-    ($variant:ident, $element_type:ty) => {
+    ($variant:ident, $element_type:ty $(, $from_int:expr)?) => {
         // Tensor<T> => Output
         impl From<Tensor<$element_type>> for Output {
             fn from(t: Tensor<$element_type>) -> Output {
@@ -380,6 +478,11 @@ macro_rules! impl_output_conversions {
             fn try_from(o: Output) -> Result<Tensor<$element_type>, OpError> {
                 match o {
                     Output::$variant(t) => Ok(t),
+                    $(Output::IntTensor(t) => {
+                        let data: Result<Vec<$element_type>, OpError> =
+                            t.iter().map($from_int).collect();
+                        data.map(|data| Tensor::from_data(t.shape(), data))
+                    })?
                     _ => Err(OpError::IncorrectOutputType),
                 }
             }
@@ -421,6 +524,14 @@ macro_rules! impl_output_conversions {
 
 impl_output_conversions!(FloatTensor, f32);
 impl_output_conversions!(IntTensor, i32);
+impl_output_conversions!(Int64Tensor, i64, |x: &i32| Ok(*x as i64));
+impl_output_conversions!(BoolTensor, bool, |x: &i32| Ok(*x != 0));
+impl_output_conversions!(UInt8Tensor, u8, |x: &i32| {
+    u8::try_from(*x).map_err(|_| {
+        OpError::InvalidValue("Int32 value is out of range for u8")
+            .with_details(format!("value {}", x))
+    })
+});
 
 /// Trait for values that can be converted into the result type used by
 /// `Operator::run`.
@@ -479,7 +590,7 @@ where
 }
 
 /// Possible reasons why an operator may fail on a given input.
-#[derive(Eq, PartialEq, Debug)]
+#[derive(Clone, Eq, PartialEq, Debug)]
 pub enum OpError {
     /// Input tensors have an element type that is unsupported or incompatible
     /// with other inputs.
@@ -500,6 +611,33 @@ pub enum OpError {
 
     /// An input or attribute has a value that is valid, but not currently supported.
     UnsupportedValue(&'static str),
+
+    /// An error with additional details about the inputs or attributes that
+    /// caused it, such as their shapes or values.
+    ///
+    /// Use [OpError::with_details] to create these.
+    Detailed {
+        error: Box<OpError>,
+        details: String,
+    },
+}
+
+impl OpError {
+    /// Attach details about the inputs or attributes that caused this error.
+    pub fn with_details(self, details: impl Into<String>) -> OpError {
+        OpError::Detailed {
+            error: Box::new(self),
+            details: details.into(),
+        }
+    }
+
+    /// Return this error with any attached details removed.
+    pub fn without_details(&self) -> &OpError {
+        match self {
+            OpError::Detailed { error, .. } => error.without_details(),
+            _ => self,
+        }
+    }
 }
 
 impl Display for OpError {
@@ -517,6 +655,7 @@ impl Display for OpError {
             OpError::UnsupportedValue(details) => {
                 write!(f, "unsupported input or attribute value: {}", details)
             }
+            OpError::Detailed { error, details } => write!(f, "{} ({})", error, details),
         }
     }
 }
@@ -622,6 +761,48 @@ macro_rules! static_dims {
     }};
 }
 
+/// Estimated cost of running an operator. See [Operator::cost].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OpCost {
+    /// Number of arithmetic operations. A multiply-add counts as two.
+    pub flops: u64,
+
+    /// Number of bytes read from inputs.
+    pub bytes_read: u64,
+
+    /// Number of bytes written to outputs.
+    pub bytes_written: u64,
+}
+
+impl std::ops::Add for OpCost {
+    type Output = OpCost;
+
+    fn add(self, other: OpCost) -> OpCost {
+        OpCost {
+            flops: self.flops + other.flops,
+            bytes_read: self.bytes_read + other.bytes_read,
+            bytes_written: self.bytes_written + other.bytes_written,
+        }
+    }
+}
+
+/// Return the number of elements in a tensor with a given shape.
+fn num_elements(shape: &[usize]) -> u64 {
+    shape.iter().product::<usize>() as u64
+}
+
+/// Estimate FLOPs for an elementwise operator which performs one operation
+/// per output element.
+fn elementwise_flops(output_shapes: &[&[usize]]) -> Option<u64> {
+    Some(output_shapes.iter().map(|s| num_elements(s)).sum())
+}
+
+/// Estimate FLOPs for an operator which performs `per_element` operations for
+/// each element of its first input, such as a reduction.
+fn input_elementwise_flops(input_shapes: &[Option<&[usize]>], per_element: u64) -> Option<u64> {
+    Some(num_elements(input_shapes.first().copied().flatten()?) * per_element)
+}
+
 /// An Operator performs a computation step when executing a data flow graph.
 ///
 /// Operators take zero or more dynamic input values, plus a set of static
@@ -664,6 +845,195 @@ pub trait Operator: Debug {
     fn run_in_place(&self, _input: Output, _other: InputList) -> Result<Output, OpError> {
         unimplemented!("in-place execution not supported")
     }
+
+    /// Estimate the number of arithmetic operations performed when running
+    /// this operator with inputs and outputs of the given shapes.
+    ///
+    /// A multiply-add counts as two operations. Operators which only move data
+    /// (eg. `Transpose`) return zero. Returns `None` if the operator does not
+    /// provide an estimate.
+    fn flops(
+        &self,
+        _input_shapes: &[Option<&[usize]>],
+        _output_shapes: &[&[usize]],
+    ) -> Option<u64> {
+        None
+    }
+
+    /// Estimate the cost of running this operator with inputs and outputs of
+    /// the given shapes.
+    ///
+    /// The default implementation uses [Operator::flops] and assumes that
+    /// each input and output element is read or written once. Returns `None`
+    /// if the operator does not provide a FLOP estimate.
+    fn cost(
+        &self,
+        input_shapes: &[Option<&[usize]>],
+        output_shapes: &[&[usize]],
+    ) -> Option<OpCost> {
+        let flops = self.flops(input_shapes, output_shapes)?;
+
+        // Operators use 32-bit float or int tensors.
+        let elem_size = 4;
+        let bytes_read = input_shapes
+            .iter()
+            .flatten()
+            .map(|shape| num_elements(shape) * elem_size)
+            .sum();
+        let bytes_written = output_shapes
+            .iter()
+            .map(|shape| num_elements(shape) * elem_size)
+            .sum();
+
+        Some(OpCost {
+            flops,
+            bytes_read,
+            bytes_written,
+        })
+    }
+
+    /// Infer the shapes of this operator's outputs from its inputs, without
+    /// computing the output values.
+    ///
+    /// `inputs` provides the shape of each input, plus the value of inputs
+    /// which are known, such as constants. Returns `None` if the operator
+    /// does not support shape inference.
+    fn infer_shapes(&self, _inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        None
+    }
+}
+
+/// An operator input during shape inference. See [Operator::infer_shapes].
+#[derive(Clone)]
+pub enum MetaInput<'a> {
+    /// An input whose value is unknown.
+    Shape(&'a [usize]),
+
+    /// An input whose value is known, such as a constant or a value computed
+    /// from the shapes of other values.
+    Value(Input<'a>),
+}
+
+impl<'a> MetaInput<'a> {
+    /// Return the shape of this input.
+    pub fn shape(&self) -> &[usize] {
+        match self {
+            MetaInput::Shape(shape) => shape,
+            MetaInput::Value(value) => value.shape(),
+        }
+    }
+}
+
+/// An operator output during shape inference. See [Operator::infer_shapes].
+#[derive(Clone, Debug, PartialEq)]
+pub enum MetaOutput {
+    /// An output whose value was not computed.
+    Shape(Vec<usize>),
+
+    /// An output whose value was computed, such as the output of `Shape`.
+    Value(Output),
+}
+
+impl MetaOutput {
+    /// Return the shape of this output.
+    pub fn shape(&self) -> &[usize] {
+        match self {
+            MetaOutput::Shape(shape) => shape,
+            MetaOutput::Value(value) => value.shape(),
+        }
+    }
+}
+
+impl<'a> From<&'a MetaOutput> for MetaInput<'a> {
+    fn from(output: &'a MetaOutput) -> MetaInput<'a> {
+        match output {
+            MetaOutput::Shape(shape) => MetaInput::Shape(shape),
+            MetaOutput::Value(value) => MetaInput::Value(value.into()),
+        }
+    }
+}
+
+impl From<Vec<usize>> for MetaOutput {
+    fn from(shape: Vec<usize>) -> MetaOutput {
+        MetaOutput::Shape(shape)
+    }
+}
+
+/// List of inputs for shape inference. See [Operator::infer_shapes].
+///
+/// This is the shape inference counterpart to [InputList].
+pub struct MetaInputList<'a> {
+    inputs: Vec<Option<MetaInput<'a>>>,
+}
+
+impl<'a> MetaInputList<'a> {
+    pub fn from_optional(inputs: &[Option<MetaInput<'a>>]) -> MetaInputList<'a> {
+        MetaInputList {
+            inputs: inputs.to_vec(),
+        }
+    }
+
+    /// Return the number of inputs, including missing optional inputs.
+    pub fn len(&self) -> usize {
+        self.inputs.len()
+    }
+
+    /// Return true if there are no inputs.
+    pub fn is_empty(&self) -> bool {
+        self.inputs.is_empty()
+    }
+
+    /// Get an optional input.
+    pub fn get(&self, index: usize) -> Option<&MetaInput<'a>> {
+        self.inputs.get(index).and_then(|input| input.as_ref())
+    }
+
+    /// Get the shape of an optional input.
+    pub fn get_shape(&self, index: usize) -> Option<&[usize]> {
+        self.get(index).map(|input| input.shape())
+    }
+
+    /// Get the shape of a required input.
+    pub fn require_shape(&self, index: usize) -> Result<&[usize], OpError> {
+        self.get_shape(index).ok_or(OpError::MissingInputs)
+    }
+
+    /// Get the value of an optional input as a tensor.
+    ///
+    /// Returns an error if the input is present but its value is not known.
+    pub fn get_value_as<T>(&self, index: usize) -> Result<Option<TensorView<'a, T>>, OpError>
+    where
+        TensorView<'a, T>: TryFrom<Input<'a>, Error = OpError>,
+    {
+        match self.get(index) {
+            None => Ok(None),
+            Some(MetaInput::Value(value)) => value.clone().try_into().map(Some),
+            Some(MetaInput::Shape(_)) => Err(OpError::UnsupportedValue(
+                "input value must be known for shape inference",
+            )),
+        }
+    }
+
+    /// Get the value of a required input as a tensor.
+    ///
+    /// Returns an error if the input is missing or its value is not known.
+    pub fn require_value_as<T>(&self, index: usize) -> Result<TensorView<'a, T>, OpError>
+    where
+        TensorView<'a, T>: TryFrom<Input<'a>, Error = OpError>,
+    {
+        self.get_value_as(index)?.ok_or(OpError::MissingInputs)
+    }
+
+    /// Iterate over all inputs, including missing optional inputs.
+    pub fn iter(&self) -> impl Iterator<Item = Option<&MetaInput<'a>>> {
+        self.inputs.iter().map(|input| input.as_ref())
+    }
+}
+
+/// Shape inference for operators with a single output that has the same shape
+/// as their first input.
+fn same_shape_as_input(inputs: &MetaInputList) -> Result<Vec<MetaOutput>, OpError> {
+    Ok(vec![inputs.require_shape(0)?.to_vec().into()])
 }
 
 /// List of inputs for an operator evaluation.
@@ -821,11 +1191,19 @@ pub fn resolve_axes<'a, I: ExactSizeIterator<Item = &'a i32>>(
 
 #[cfg(test)]
 mod tests {
+    use std::fs::File;
+    use std::io::BufReader;
+
     use rten_tensor::prelude::*;
     use rten_tensor::test_util::{expect_equal_with_tolerance, ExpectEqualError};
-    use rten_tensor::NdTensor;
+    use rten_tensor::{tensor, NdTensor, Tensor};
+    use serde_json::Value;
 
-    use super::Input;
+    use super::{
+        Add, Concat, Conv, Gather, GlobalMaxPool, Input, InputList, LpPool, MatMul, MaxPool,
+        MetaInput, MetaInputList, MetaOutput, OpCost, OpError, Operator, Output, Pad, Padding,
+        ReduceMean, Relu, Reshape, Shape, Slice, Split, Transpose,
+    };
 
     /// Compare two f32 tensors with a higher absolute tolerance (1e-4) than
     /// the default (1e-5).
@@ -839,6 +1217,40 @@ mod tests {
         expect_equal_with_tolerance(result, expected, 1e-4, 0.)
     }
 
+    /// Read a float tensor from a JSON value.
+    ///
+    /// The JSON value is expected to be of the form `[shape, data]` where
+    /// `shape` is an int array and `data` is a float array.
+    pub fn read_tensor(val: &Value) -> Result<Tensor<f32>, &'static str> {
+        let vec = match val {
+            Value::Array(vec) => vec,
+            _ => return Err("Expected array"),
+        };
+
+        let (shape, data) = match vec.as_slice() {
+            [Value::Array(shape), Value::Array(data)] => (shape, data),
+            _ => return Err("Expected [shape, data] array"),
+        };
+
+        let shape = shape
+            .iter()
+            .map(|v| v.as_i64().map(|v| v as usize).ok_or("Expected int array"))
+            .collect::<Result<Vec<usize>, _>>()?;
+
+        let data = data
+            .iter()
+            .map(|v| v.as_f64().map(|v| v as f32).ok_or("Expected float array"))
+            .collect::<Result<Vec<f32>, _>>()?;
+
+        Ok(Tensor::from_data(&shape, data))
+    }
+
+    pub fn read_json_file(path: &str) -> Value {
+        let file = File::open(path).unwrap();
+        let reader = BufReader::new(file);
+        serde_json::from_reader(reader).unwrap()
+    }
+
     #[test]
     fn test_input_from_tensor() {
         let tensor = NdTensor::<i32, 3>::zeros([1, 2, 3]);
@@ -851,4 +1263,205 @@ mod tests {
         assert!(matches!(input, Input::FloatTensor(_)));
         assert_eq!(input.shape(), &[5, 5]);
     }
+
+    #[test]
+    fn test_output_from_int_tensor() {
+        let output: Output = tensor!([0, 1, 255]).into();
+        let result: Tensor<u8> = output.try_into().unwrap();
+        assert_eq!(result, tensor!([0u8, 1, 255]));
+
+        let output: Output = tensor!([-1, 2]).into();
+        let result: Tensor<i64> = output.try_into().unwrap();
+        assert_eq!(result, tensor!([-1i64, 2]));
+
+        let output: Output = tensor!([0, 2]).into();
+        let result: Tensor<bool> = output.try_into().unwrap();
+        assert_eq!(result, tensor!([false, true]));
+
+        for value in [-1, 256] {
+            let output: Output = tensor!([0, value]).into();
+            let err = Tensor::<u8>::try_from(output).err().unwrap();
+            assert!(matches!(err.without_details(), OpError::InvalidValue(_)));
+        }
+    }
+
+    #[test]
+    fn test_operator_cost() {
+        // Elementwise ops count one operation per output element.
+        let cost = Relu {}.cost(&[Some(&[2, 3])], &[&[2, 3]]);
+        assert_eq!(
+            cost,
+            Some(OpCost {
+                flops: 6,
+                bytes_read: 24,
+                bytes_written: 24,
+            })
+        );
+        let flops = Add {}.flops(&[Some(&[2, 3]), Some(&[3])], &[&[2, 3]]);
+        assert_eq!(flops, Some(6));
+
+        // Layout ops only move data.
+        let transpose = Transpose { perm: None };
+        let cost = transpose.cost(&[Some(&[2, 3])], &[&[3, 2]]).unwrap();
+        assert_eq!(cost.flops, 0);
+        assert_eq!(cost.bytes_read + cost.bytes_written, 48);
+
+        // MatMul of [2, 3, 4] x [4, 5] performs a 4-element multiply-add for
+        // each of the [2, 3, 5] output elements.
+        let flops = MatMul {}.flops(&[Some(&[2, 3, 4]), Some(&[4, 5])], &[&[2, 3, 5]]);
+        assert_eq!(flops, Some(2 * 4 * 30));
+
+        // Conv with a [8, 3, 3, 3] kernel and bias.
+        let conv = Conv {
+            groups: 1,
+            dilations: vec![1, 1],
+            padding: Padding::Fixed([0; 4].into()),
+            strides: vec![1, 1],
+        };
+        let flops = conv.flops(
+            &[Some(&[1, 3, 10, 10]), Some(&[8, 3, 3, 3]), Some(&[8])],
+            &[&[1, 8, 8, 8]],
+        );
+        assert_eq!(flops, Some(8 * 8 * 8 * (2 * 27 + 1)));
+
+        // Missing inputs
+        assert_eq!(MatMul {}.flops(&[None, Some(&[4, 5])], &[&[3, 5]]), None);
+    }
+
+    #[test]
+    fn test_infer_shapes() {
+        struct Case {
+            op: Box<dyn Operator>,
+            inputs: Vec<Output>,
+        }
+
+        let case = |op: Box<dyn Operator>, inputs: Vec<Output>| Case { op, inputs };
+        let float = |shape: &[usize]| Output::from(Tensor::<f32>::zeros(shape));
+
+        let cases = [
+            case(Box::new(Relu {}), vec![float(&[2, 3])]),
+            case(Box::new(Add {}), vec![float(&[2, 3]), float(&[3])]),
+            case(Box::new(MatMul {}), vec![float(&[2, 3, 4]), float(&[4, 5])]),
+            case(
+                Box::new(Conv {
+                    groups: 1,
+                    dilations: vec![1, 1],
+                    padding: Padding::Same,
+                    strides: vec![2, 2],
+                }),
+                vec![float(&[1, 3, 10, 10]), float(&[8, 3, 3, 3])],
+            ),
+            case(
+                Box::new(MaxPool {
+                    kernel_size: vec![2, 2],
+                    padding: Padding::Fixed([0; 4].into()),
+                    strides: vec![2, 2],
+                    dilations: vec![1, 1],
+                    ceil_mode: true,
+                    return_indices: true,
+                }),
+                vec![float(&[1, 2, 5, 5])],
+            ),
+            case(
+                Box::new(LpPool {
+                    kernel_size: vec![3],
+                    padding: [1, 1].into(),
+                    strides: vec![2],
+                    dilations: vec![2],
+                    ceil_mode: false,
+                    p: 2,
+                }),
+                vec![float(&[1, 2, 9])],
+            ),
+            case(Box::new(GlobalMaxPool {}), vec![float(&[1, 2, 3, 4, 5])]),
+            case(
+                Box::new(ReduceMean {
+                    axes: Some(vec![1]),
+                    keep_dims: false,
+                }),
+                vec![float(&[2, 3, 4])],
+            ),
+            case(
+                Box::new(Concat { axis: 1 }),
+                vec![float(&[2, 3]), float(&[2, 5])],
+            ),
+            case(
+                Box::new(Gather { axis: 0 }),
+                vec![float(&[5, 3]), tensor!((2, 2); [0, 1, 2, 3]).into()],
+            ),
+            case(
+                Box::new(Slice {}),
+                vec![
+                    float(&[4, 6]),
+                    tensor!([1]).into(),
+                    tensor!([-1]).into(),
+                    tensor!([1]).into(),
+                ],
+            ),
+            case(
+                Box::new(Split { axis: 1 }),
+                vec![float(&[2, 6]), tensor!([2, 4]).into()],
+            ),
+            case(
+                Box::new(Reshape { allow_zero: false }),
+                vec![float(&[2, 6]), tensor!([3, -1]).into()],
+            ),
+            case(
+                Box::new(Transpose {
+                    perm: Some(vec![1, 0, 2]),
+                }),
+                vec![float(&[2, 3, 4])],
+            ),
+            case(
+                Box::<Pad>::default(),
+                vec![float(&[2, 3]), tensor!([1, 0, 1, 2]).into()],
+            ),
+            case(Box::new(Shape {}), vec![float(&[2, 3])]),
+        ];
+
+        for Case { op, inputs } in cases {
+            // The value of the first input is unknown, and the values of other
+            // inputs are known.
+            let meta_inputs: Vec<_> = inputs
+                .iter()
+                .enumerate()
+                .map(|(i, input)| {
+                    Some(if i == 0 {
+                        MetaInput::Shape(input.shape())
+                    } else {
+                        MetaInput::Value(input.into())
+                    })
+                })
+                .collect();
+            let inferred = op
+                .infer_shapes(&MetaInputList::from_optional(&meta_inputs))
+                .expect("shape inference not supported")
+                .unwrap();
+
+            let inputs: Vec<Input> = inputs.iter().map(|input| input.into()).collect();
+            let outputs = op.run(InputList::from(inputs.as_slice())).unwrap();
+
+            let inferred_shapes: Vec<_> = inferred.iter().map(|out| out.shape()).collect();
+            let output_shapes: Vec<_> = outputs.iter().map(|out| out.shape()).collect();
+            assert_eq!(inferred_shapes, output_shapes, "mismatch for {}", op.name());
+        }
+
+        // `Shape` outputs are known from the input's shape.
+        let input = [Some(MetaInput::Shape(&[2, 3]))];
+        let inferred = Shape {}
+            .infer_shapes(&MetaInputList::from_optional(&input))
+            .unwrap()
+            .unwrap();
+        assert_eq!(inferred, [MetaOutput::Value(tensor!([2, 3]).into())]);
+
+        // Shape inference for `Reshape` requires the target shape.
+        let input = [
+            Some(MetaInput::Shape(&[2, 6])),
+            Some(MetaInput::Shape(&[2])),
+        ];
+        let result = Reshape { allow_zero: false }
+            .infer_shapes(&MetaInputList::from_optional(&input))
+            .unwrap();
+        assert!(result.is_err());
+    }
 }
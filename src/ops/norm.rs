@@ -6,8 +6,11 @@ use rten_vecmath::vec_softmax_in_place;
 use smallvec::SmallVec;
 
 use crate::ops::{add, mul, reduce_mean, sub};
-use crate::ops::{resolve_axis, InputList, IntoOpResult, OpError, Operator, Output};
-use crate::slice_reductions::{slice_max, slice_sum};
+use crate::ops::{
+    input_elementwise_flops, resolve_axis, same_shape_as_input, InputList, IntoOpResult,
+    MetaInputList, MetaOutput, OpError, Operator, Output,
+};
+use crate::slice_reductions::{slice_max, slice_sum, slice_sum_square};
 use crate::static_dims;
 
 /// Perform in-place batch normalization on the `NC*` tensor `out`.
@@ -117,6 +120,15 @@ impl Operator for BatchNormalization {
 
         Ok(output.into())
     }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        // Scale and shift of each element.
+        input_elementwise_flops(input_shapes, 2)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(same_shape_as_input(inputs))
+    }
 }
 
 pub fn instance_normalization(
@@ -226,6 +238,157 @@ impl Operator for InstanceNormalization {
 
         Ok(output.into())
     }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        // Mean and variance calculation, normalization, scale and shift.
+        input_elementwise_flops(input_shapes, 7)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(same_shape_as_input(inputs))
+    }
+}
+
+pub fn group_normalization(
+    input: TensorView,
+    scale: NdTensorView<f32, 1>,
+    bias: NdTensorView<f32, 1>,
+    num_groups: usize,
+    epsilon: Option<f32>,
+) -> Result<Tensor, OpError> {
+    let mut output = input.to_tensor();
+    group_normalization_in_place(&mut output, scale, bias, num_groups, epsilon)?;
+    Ok(output)
+}
+
+/// Perform in-place group normalization on the `NC*` tensor `input`.
+///
+/// Channels are divided into `num_groups` groups and each group is normalized
+/// to have zero mean and unit variance. `scale` and `bias` have either one
+/// entry per channel or, as in version 18 of the ONNX operator, one entry per
+/// group.
+///
+/// See <https://github.com/onnx/onnx/blob/main/docs/Operators.md#groupnormalization>.
+pub fn group_normalization_in_place(
+    input: &mut Tensor,
+    scale: NdTensorView<f32, 1>,
+    bias: NdTensorView<f32, 1>,
+    num_groups: usize,
+    epsilon: Option<f32>,
+) -> Result<(), OpError> {
+    let &[batch, chans, ..] = input.shape() else {
+        return Err(OpError::InvalidValue("expected input with >= 2 dims"));
+    };
+
+    // If epsilon is None, use default from ONNX spec.
+    let epsilon = epsilon.unwrap_or(1e-5);
+
+    if num_groups == 0 || chans % num_groups != 0 {
+        return Err(OpError::InvalidValue(
+            "channel count should be a multiple of group count",
+        ));
+    }
+    let group_chans = chans / num_groups;
+
+    let per_group_params = scale.size(0) == num_groups;
+    if scale.size(0) != chans && !per_group_params {
+        return Err(OpError::InvalidValue(
+            "scale length should match channel or group count",
+        ));
+    }
+    if bias.size(0) != scale.size(0) {
+        return Err(OpError::InvalidValue(
+            "bias length should match scale length",
+        ));
+    }
+
+    if input.is_empty() {
+        return Ok(());
+    }
+
+    // Each group is a contiguous slice of channels.
+    input.make_contiguous();
+    let chan_len = input.len() / (batch * chans);
+
+    input
+        .data_mut()
+        .unwrap()
+        .par_chunks_mut(group_chans * chan_len)
+        .enumerate()
+        .for_each(|(batch_group, group)| {
+            let g = batch_group % num_groups;
+            let mean = slice_sum(group) / group.len() as f32;
+            let variance = slice_sum_square(group, mean) / group.len() as f32;
+            let inv_std_dev = 1. / (variance + epsilon).sqrt();
+
+            for (i, chan) in group.chunks_mut(chan_len).enumerate() {
+                let param_idx = if per_group_params {
+                    g
+                } else {
+                    g * group_chans + i
+                };
+
+                // Fold normalization, scale and shift into `x * a + b`.
+                let chan_scale = scale[[param_idx]] * inv_std_dev;
+                let chan_bias = bias[[param_idx]] - mean * chan_scale;
+                for x in chan.iter_mut() {
+                    *x = *x * chan_scale + chan_bias;
+                }
+            }
+        });
+
+    Ok(())
+}
+
+#[derive(Debug)]
+pub struct GroupNormalization {
+    pub num_groups: usize,
+    pub epsilon: Option<f32>,
+}
+
+impl Operator for GroupNormalization {
+    fn name(&self) -> &str {
+        "GroupNormalization"
+    }
+
+    fn run(&self, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let input = inputs.require_as(0)?;
+
+        let scale = inputs.require_as(1)?;
+        let scale = static_dims!(scale, 1)?;
+
+        let bias = inputs.require_as(2)?;
+        let bias = static_dims!(bias, 1)?;
+
+        group_normalization(input, scale, bias, self.num_groups, self.epsilon).into_op_result()
+    }
+
+    fn can_run_in_place(&self) -> bool {
+        true
+    }
+
+    fn run_in_place(&self, output: Output, inputs: InputList) -> Result<Output, OpError> {
+        let mut output = output.into_float().ok_or(OpError::IncorrectInputType)?;
+
+        let scale = inputs.require_as(0)?;
+        let scale = static_dims!(scale, 1)?;
+
+        let bias = inputs.require_as(1)?;
+        let bias = static_dims!(bias, 1)?;
+
+        group_normalization_in_place(&mut output, scale, bias, self.num_groups, self.epsilon)?;
+
+        Ok(output.into())
+    }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        // Mean and variance calculation, normalization, scale and shift.
+        input_elementwise_flops(input_shapes, 5)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(same_shape_as_input(inputs))
+    }
 }
 
 pub fn layer_normalization(
@@ -299,6 +462,113 @@ impl Operator for LayerNormalization {
 
         layer_normalization(input.view(), scale, bias, self.axis, self.epsilon).into_op_result()
     }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        // Mean and variance calculation, normalization, scale and shift.
+        input_elementwise_flops(input_shapes, 7)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(same_shape_as_input(inputs))
+    }
+}
+
+pub fn rms_normalization(
+    input: TensorView,
+    scale: TensorView,
+    axis: isize,
+    epsilon: Option<f32>,
+) -> Result<Tensor, OpError> {
+    let mut output = input.to_tensor();
+    rms_normalization_in_place(&mut output, scale, axis, epsilon)?;
+    Ok(output)
+}
+
+/// Perform in-place RMS normalization of `input`.
+///
+/// Elements in the dimensions from `axis` onwards are divided by their
+/// root-mean-square and multiplied by `scale`, which must be broadcastable to
+/// the shape of those dimensions. This is the `SimplifiedLayerNormalization`
+/// operator in ONNX Runtime.
+///
+/// See <https://github.com/onnx/onnx/blob/main/docs/Operators.md#rmsnormalization>.
+pub fn rms_normalization_in_place(
+    input: &mut Tensor,
+    scale: TensorView,
+    axis: isize,
+    epsilon: Option<f32>,
+) -> Result<(), OpError> {
+    let resolved_axis = resolve_axis(input.ndim(), axis)?;
+    let normalized_shape = &input.shape()[resolved_axis..];
+    if !scale.can_broadcast_to(normalized_shape) {
+        return Err(OpError::IncompatibleInputShapes(
+            "`scale` cannot be broadcast to normalized shape",
+        ));
+    }
+    let scale = scale.broadcast(normalized_shape).to_vec();
+
+    let epsilon = epsilon.unwrap_or(1e-5);
+
+    if input.is_empty() {
+        return Ok(());
+    }
+
+    // The normalized dimensions of each row are contiguous.
+    input.make_contiguous();
+    input
+        .data_mut()
+        .unwrap()
+        .par_chunks_mut(scale.len())
+        .for_each(|row| {
+            let mean_square = slice_sum_square(row, 0.) / row.len() as f32;
+            let inv_rms = 1. / (mean_square + epsilon).sqrt();
+            for (x, scale) in row.iter_mut().zip(&scale) {
+                *x *= inv_rms * scale;
+            }
+        });
+
+    Ok(())
+}
+
+#[derive(Debug)]
+pub struct RMSNormalization {
+    pub axis: isize,
+    pub epsilon: Option<f32>,
+}
+
+impl Operator for RMSNormalization {
+    fn name(&self) -> &str {
+        "RMSNormalization"
+    }
+
+    fn run(&self, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let input = inputs.require_as(0)?;
+        let scale = inputs.require_as(1)?;
+
+        rms_normalization(input, scale, self.axis, self.epsilon).into_op_result()
+    }
+
+    fn can_run_in_place(&self) -> bool {
+        true
+    }
+
+    fn run_in_place(&self, output: Output, inputs: InputList) -> Result<Output, OpError> {
+        let mut output = output.into_float().ok_or(OpError::IncorrectInputType)?;
+        let scale = inputs.require_as(0)?;
+
+        rms_normalization_in_place(&mut output, scale, self.axis, self.epsilon)?;
+
+        Ok(output.into())
+    }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        // Mean square calculation and scale.
+        input_elementwise_flops(input_shapes, 4)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(same_shape_as_input(inputs))
+    }
 }
 
 pub fn log_softmax(input: TensorView, axis: isize) -> Result<Tensor, OpError> {
@@ -398,6 +668,15 @@ impl Operator for LogSoftmax {
         log_softmax_in_place(&mut output, self.axis)?;
         Ok(output.into())
     }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        // Max, exponentiation, sum and normalization.
+        input_elementwise_flops(input_shapes, 4)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(same_shape_as_input(inputs))
+    }
 }
 
 pub fn softmax(input: TensorView, axis: isize) -> Result<Tensor, OpError> {
@@ -435,6 +714,15 @@ impl Operator for Softmax {
         softmax_in_place(&mut output, self.axis)?;
         Ok(output.into())
     }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        // Max, exponentiation, sum and normalization.
+        input_elementwise_flops(input_shapes, 4)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(same_shape_as_input(inputs))
+    }
 }
 
 #[cfg(test)]
@@ -449,8 +737,8 @@ mod tests {
     use crate::ops::tests::expect_eq_1e4;
     use crate::ops::OpError;
     use crate::ops::{
-        batch_norm, batch_norm_in_place, instance_normalization, layer_normalization, log_softmax,
-        softmax,
+        batch_norm, batch_norm_in_place, group_normalization, instance_normalization,
+        layer_normalization, log_softmax, rms_normalization, softmax,
     };
 
     #[test]
@@ -608,6 +896,137 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_group_normalization() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+        let input = Tensor::rand(&[2, 4, 3, 3], &mut rng);
+        let scale = Tensor::rand(&[4], &mut rng);
+        let bias = Tensor::rand(&[4], &mut rng);
+
+        // With one channel per group, this is equivalent to instance
+        // normalization.
+        let result = group_normalization(
+            input.view(),
+            scale.nd_view(),
+            bias.nd_view(),
+            4,    /* num_groups */
+            None, /* epsilon */
+        )?;
+        let expected = instance_normalization(input.view(), scale.nd_view(), bias.nd_view(), None)?;
+        expect_eq_1e4(&result, &expected)?;
+
+        // With a single group, this is equivalent to layer normalization over
+        // all dims after the batch, with per-channel scale and bias.
+        let result = group_normalization(
+            input.view(),
+            scale.nd_view(),
+            bias.nd_view(),
+            1,    /* num_groups */
+            None, /* epsilon */
+        )?;
+        let expected = layer_normalization(
+            input.view(),
+            scale.reshaped([4, 1, 1]).as_dyn(),
+            Some(bias.reshaped([4, 1, 1]).as_dyn()),
+            1,    /* axis */
+            None, /* epsilon */
+        )?;
+        expect_eq_1e4(&result, &expected)?;
+
+        // Scale and bias with one entry per group are equivalent to repeating
+        // each entry for every channel in the group.
+        let group_scale = tensor!([0.5, 2.0]);
+        let group_bias = tensor!([0.1, -0.2]);
+        let result = group_normalization(
+            input.view(),
+            group_scale.nd_view(),
+            group_bias.nd_view(),
+            2,    /* num_groups */
+            None, /* epsilon */
+        )?;
+        let expected = group_normalization(
+            input.view(),
+            tensor!([0.5, 0.5, 2.0, 2.0]).nd_view(),
+            tensor!([0.1, 0.1, -0.2, -0.2]).nd_view(),
+            2,    /* num_groups */
+            None, /* epsilon */
+        )?;
+        expect_equal(&result, &expected)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_group_normalization_invalid() {
+        let input = Tensor::<f32>::zeros(&[1, 4, 2]);
+        let scale = Tensor::<f32>::zeros(&[4]);
+        let bias = Tensor::<f32>::zeros(&[4]);
+
+        let result = group_normalization(input.view(), scale.nd_view(), bias.nd_view(), 3, None);
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "channel count should be a multiple of group count"
+            ))
+        );
+
+        let scale = Tensor::<f32>::zeros(&[3]);
+        let result = group_normalization(input.view(), scale.nd_view(), bias.nd_view(), 2, None);
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "scale length should match channel or group count"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_rms_normalization() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+        let input = Tensor::rand(&[2, 3, 20], &mut rng);
+        let epsilon = 1e-5;
+
+        // Reference implementation of RMS normalization over dims from `axis` onwards.
+        let reference_rms_norm = |scale: &Tensor, axis: usize| {
+            let normalized_len: usize = input.shape()[axis..].iter().product();
+            let scale = scale.broadcast(&input.shape()[axis..]).to_vec();
+            let mut expected = input.clone();
+            for row in expected.data_mut().unwrap().chunks_mut(normalized_len) {
+                let mean_square = row.iter().map(|x| x * x).sum::<f32>() / row.len() as f32;
+                let rms = (mean_square + epsilon).sqrt();
+                for (x, scale) in row.iter_mut().zip(&scale) {
+                    *x = *x / rms * scale;
+                }
+            }
+            expected
+        };
+
+        // Normalize over last axis.
+        let scale = Tensor::rand(&[20], &mut rng);
+        let result = rms_normalization(input.view(), scale.view(), -1, Some(epsilon))?;
+        expect_eq_1e4(&result, &reference_rms_norm(&scale, 2))?;
+
+        // Normalize over multiple axes, with broadcasting of scale.
+        let result = rms_normalization(input.view(), scale.view(), 1, Some(epsilon))?;
+        expect_eq_1e4(&result, &reference_rms_norm(&scale, 1))?;
+
+        // Non-contiguous input
+        let input_t = input.transposed().to_tensor();
+        let result = rms_normalization(input_t.transposed(), scale.view(), -1, Some(epsilon))?;
+        expect_eq_1e4(&result, &reference_rms_norm(&scale, 2))?;
+
+        // Invalid scale shape
+        let result = rms_normalization(input.view(), tensor!([1., 2.]).view(), -1, None);
+        assert_eq!(
+            result.err(),
+            Some(OpError::IncompatibleInputShapes(
+                "`scale` cannot be broadcast to normalized shape"
+            ))
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_log_softmax() -> Result<(), Box<dyn Error>> {
         // 1D input
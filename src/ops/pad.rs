@@ -1,17 +1,17 @@
 use std::iter::zip;
 
 use rten_tensor::prelude::*;
-use rten_tensor::{NdTensorView, SliceItem, Tensor, TensorView};
+use rten_tensor::{DynIndices, NdTensor, NdTensorView, SliceItem, Tensor, TensorView};
 
-use crate::ops::{Input, InputList, IntoOpResult, OpError, Operator, Output};
+use crate::ops::{
+    resolve_axes, Input, InputList, IntoOpResult, MetaInputList, MetaOutput, OpError, Operator,
+    Output,
+};
 use crate::static_dims;
 
-pub fn pad<T: Copy>(
-    input: TensorView<T>,
-    padding: &NdTensorView<i32, 1>,
-    const_val: T,
-) -> Result<Tensor<T>, OpError> {
-    if padding.size(0) != input.ndim() * 2 {
+/// Compute the output shape of a [pad] operation.
+fn padded_shape(shape: &[usize], padding: &NdTensorView<i32, 1>) -> Result<Vec<usize>, OpError> {
+    if padding.size(0) != shape.len() * 2 {
         return Err(OpError::InvalidValue(
             "padding length should be 2 * input dims",
         ));
@@ -20,18 +20,88 @@ pub fn pad<T: Copy>(
         return Err(OpError::InvalidValue("Pad only supports positive pads"));
     }
 
-    let out_shape: Vec<_> = input
-        .shape()
+    Ok(shape
         .iter()
         .enumerate()
         .map(|(i, size)| {
             let start_pad = padding[[i]] as usize;
-            let end_pad = padding[[input.ndim() + i]] as usize;
+            let end_pad = padding[[shape.len() + i]] as usize;
             start_pad + size + end_pad
         })
-        .collect();
-    let out_len = out_shape.iter().product();
+        .collect())
+}
+
+/// Specifies how the padded region is filled by [pad].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum PadMode {
+    /// Fill the padded region with a constant value.
+    #[default]
+    Constant,
+
+    /// Mirror the input around the first and last elements of each axis,
+    /// excluding the edge element itself. eg. `[1, 2, 3]` padded by 2 on
+    /// each side gives `[3, 2, 1, 2, 3, 2, 1]`.
+    Reflect,
+
+    /// Repeat the first and last elements of each axis.
+    Edge,
 
+    /// Wrap around to the other end of each axis.
+    Wrap,
+}
+
+/// Map a coordinate `index` in the padded region before or after an axis of
+/// size `size` to the coordinate of the input element it copies.
+fn pad_source_index(mode: PadMode, index: isize, size: usize) -> usize {
+    let size = size as isize;
+    match mode {
+        PadMode::Constant => unreachable!("constant padding does not copy input elements"),
+        PadMode::Edge => index.clamp(0, size - 1) as usize,
+        PadMode::Wrap => index.rem_euclid(size) as usize,
+        PadMode::Reflect => {
+            if size == 1 {
+                return 0;
+            }
+            let period = 2 * (size - 1);
+            let index = index.rem_euclid(period);
+            if index < size {
+                index as usize
+            } else {
+                (period - index) as usize
+            }
+        }
+    }
+}
+
+/// Pad a tensor with a constant value.
+///
+/// `padding` specifies the `[starts..., ends...]` amounts to pad for each
+/// dim. This is equivalent to [pad_with_mode] with [`PadMode::Constant`].
+pub fn pad<T: Copy>(
+    input: TensorView<T>,
+    padding: &NdTensorView<i32, 1>,
+    const_val: T,
+) -> Result<Tensor<T>, OpError> {
+    pad_with_mode(input, padding, PadMode::Constant, const_val)
+}
+
+/// Pad a tensor.
+///
+/// `padding` specifies the `[starts..., ends...]` amounts to pad for each
+/// dim. `const_val` is the value used to fill the padded region if `mode` is
+/// [`PadMode::Constant`].
+pub fn pad_with_mode<T: Copy>(
+    input: TensorView<T>,
+    padding: &NdTensorView<i32, 1>,
+    mode: PadMode,
+    const_val: T,
+) -> Result<Tensor<T>, OpError> {
+    let out_shape = padded_shape(input.shape(), padding)?;
+    if mode != PadMode::Constant {
+        return pad_copy(input, padding, &out_shape, mode);
+    }
+
+    let out_len = out_shape.iter().product();
     let non_pad_region: Vec<SliceItem> = input
         .shape()
         .iter()
@@ -53,8 +123,83 @@ pub fn pad<T: Copy>(
     Ok(output)
 }
 
-#[derive(Debug)]
-pub struct Pad {}
+/// Pad a tensor using a mode which fills the padded region with copies of
+/// input elements.
+///
+/// Each output element is read directly from the input element that it
+/// copies, which is found independently for each axis.
+fn pad_copy<T: Copy>(
+    input: TensorView<T>,
+    padding: &NdTensorView<i32, 1>,
+    out_shape: &[usize],
+    mode: PadMode,
+) -> Result<Tensor<T>, OpError> {
+    let ndim = input.ndim();
+    let out_len: usize = out_shape.iter().product();
+    if ndim == 0 || out_len == 0 {
+        return Ok(Tensor::from_data(
+            out_shape,
+            input.iter().copied().collect(),
+        ));
+    }
+
+    // For each axis, the input position that each output position copies.
+    let src_positions = (0..ndim)
+        .map(|axis| {
+            let size = input.size(axis);
+            let start_pad = padding[[axis]] as usize;
+            if size == 0 {
+                return Err(OpError::InvalidValue(
+                    "Cannot pad empty axis in non-constant mode",
+                ));
+            }
+            Ok((0..out_shape[axis])
+                .map(|pos| pad_source_index(mode, pos as isize - start_pad as isize, size))
+                .collect::<Vec<_>>())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let input = input.to_contiguous();
+    let in_data = input.data().expect("input should be contiguous");
+    let in_strides = input.strides();
+    let (inner_positions, outer_positions) = src_positions.split_last().unwrap();
+
+    let mut out_data = Vec::with_capacity(out_len);
+    for index in DynIndices::from_shape(&out_shape[..ndim - 1]) {
+        let offset: usize = zip(index.iter(), zip(outer_positions, in_strides))
+            .map(|(&pos, (src_positions, stride))| src_positions[pos] * stride)
+            .sum();
+        out_data.extend(inner_positions.iter().map(|&pos| in_data[offset + pos]));
+    }
+
+    Ok(Tensor::from_data(out_shape, out_data))
+}
+
+/// Expand pads for the axes in `axes` into pads for every dim of a tensor
+/// with `ndim` dims. Dims not in `axes` are not padded.
+fn expand_pads(
+    pads: NdTensorView<i32, 1>,
+    axes: NdTensorView<i32, 1>,
+    ndim: usize,
+) -> Result<NdTensor<i32, 1>, OpError> {
+    let axes = resolve_axes(ndim, axes.iter())?;
+    if pads.size(0) != axes.len() * 2 {
+        return Err(OpError::InvalidValue(
+            "padding length should be 2 * axes length",
+        ));
+    }
+    let mut expanded = NdTensor::zeros([ndim * 2]);
+    for (i, &axis) in axes.iter().enumerate() {
+        expanded[[axis]] = pads[[i]];
+        expanded[[ndim + axis]] = pads[[axes.len() + i]];
+    }
+    Ok(expanded)
+}
+
+#[derive(Debug, Default)]
+pub struct Pad {
+    pub mode: PadMode,
+}
 
 impl Operator for Pad {
     fn name(&self) -> &str {
@@ -65,25 +210,49 @@ impl Operator for Pad {
         let input = inputs.require(0)?;
         let pads = inputs.require_as::<i32>(1)?;
         let pads = static_dims!(pads, 1)?;
-        let axes = inputs.get_as::<i32>(3)?;
-
-        if axes.is_some() {
-            return Err(OpError::UnsupportedValue(
-                "Pad operator does not yet support `axes` input",
-            ));
-        }
+        let axes = inputs
+            .get_as::<i32>(3)?
+            .map(|axes| static_dims!(axes, 1))
+            .transpose()?;
+        let pads = match axes {
+            Some(axes) => expand_pads(pads, axes, input.ndim())?,
+            None => pads.to_tensor(),
+        };
+        let pads = pads.view();
 
         match input {
             Input::IntTensor(t) => {
                 let const_val = inputs.get_as_scalar::<i32>(2)?;
-                pad(t, &pads, const_val.unwrap_or(0)).into_op_result()
+                pad_with_mode(t, &pads, self.mode, const_val.unwrap_or(0)).into_op_result()
             }
             Input::FloatTensor(t) => {
                 let const_val = inputs.get_as_scalar::<f32>(2)?;
-                pad(t, &pads, const_val.unwrap_or(0.0)).into_op_result()
+                pad_with_mode(t, &pads, self.mode, const_val.unwrap_or(0.0)).into_op_result()
             }
+            _ => Err(OpError::IncorrectInputType),
         }
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        Some(0)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| {
+            let shape = inputs.require_shape(0)?;
+            let pads = inputs.require_value_as::<i32>(1)?;
+            let pads = static_dims!(pads, 1)?;
+            let axes = inputs
+                .get_value_as::<i32>(3)?
+                .map(|axes| static_dims!(axes, 1))
+                .transpose()?;
+            let pads = match axes {
+                Some(axes) => expand_pads(pads, axes, shape.len())?,
+                None => pads.to_tensor(),
+            };
+            Ok(vec![padded_shape(shape, &pads.view())?.into()])
+        })())
+    }
 }
 
 #[cfg(test)]
@@ -94,7 +263,7 @@ mod tests {
     use rten_tensor::test_util::expect_equal;
     use rten_tensor::Tensor;
 
-    use crate::ops::{pad, OpError, Operator, Pad};
+    use crate::ops::{pad, pad_with_mode, InputList, OpError, Operator, Pad, PadMode};
 
     fn from_slice<T: Clone>(data: &[T]) -> Tensor<T> {
         Tensor::from_data(&[data.len()], data.to_vec())
@@ -155,7 +324,7 @@ mod tests {
             ],
         );
 
-        let op = Pad {};
+        let op = Pad::default();
         let result = op
             .run((&input, &pads).into())
             .unwrap()
@@ -167,10 +336,144 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_pad_modes() -> Result<(), Box<dyn Error>> {
+        struct Case {
+            input: Tensor,
+            pads: Vec<i32>,
+            mode: PadMode,
+            expected: Tensor,
+        }
+
+        let input = Tensor::from([[1.0, 1.2], [2.3, 3.4], [4.5, 5.7]]);
+
+        let cases = [
+            Case {
+                input: input.clone(),
+                pads: vec![0, 2, 0, 0],
+                mode: PadMode::Reflect,
+                expected: Tensor::from([
+                    [1.0, 1.2, 1.0, 1.2],
+                    [2.3, 3.4, 2.3, 3.4],
+                    [4.5, 5.7, 4.5, 5.7],
+                ]),
+            },
+            Case {
+                input: input.clone(),
+                pads: vec![0, 2, 0, 0],
+                mode: PadMode::Edge,
+                expected: Tensor::from([
+                    [1.0, 1.0, 1.0, 1.2],
+                    [2.3, 2.3, 2.3, 3.4],
+                    [4.5, 4.5, 4.5, 5.7],
+                ]),
+            },
+            Case {
+                input: input.clone(),
+                pads: vec![2, 1, 1, 1],
+                mode: PadMode::Wrap,
+                expected: Tensor::from([
+                    [3.4, 2.3, 3.4, 2.3],
+                    [5.7, 4.5, 5.7, 4.5],
+                    [1.2, 1.0, 1.2, 1.0],
+                    [3.4, 2.3, 3.4, 2.3],
+                    [5.7, 4.5, 5.7, 4.5],
+                    [1.2, 1.0, 1.2, 1.0],
+                ]),
+            },
+            // Padding larger than the input
+            Case {
+                input: Tensor::from([1., 2., 3.]),
+                pads: vec![4, 4],
+                mode: PadMode::Reflect,
+                expected: Tensor::from([1., 2., 3., 2., 1., 2., 3., 2., 1., 2., 3.]),
+            },
+            Case {
+                input: Tensor::from([1., 2.]),
+                pads: vec![3, 2],
+                mode: PadMode::Wrap,
+                expected: Tensor::from([2., 1., 2., 1., 2., 1., 2.]),
+            },
+        ];
+
+        for case in cases {
+            let pads = case.pads.as_slice().into();
+            let result = pad_with_mode(case.input.view(), &pads, case.mode, 0.).unwrap();
+            expect_equal(&result, &case.expected)?;
+        }
+
+        // Non-contiguous input.
+        let pads = &[1, 0, 0, 1];
+        let result =
+            pad_with_mode(input.view().transposed(), &pads.into(), PadMode::Edge, 0.).unwrap();
+        expect_equal(
+            &result,
+            &Tensor::from([
+                [1.0, 2.3, 4.5, 4.5],
+                [1.0, 2.3, 4.5, 4.5],
+                [1.2, 3.4, 5.7, 5.7],
+            ]),
+        )?;
+
+        // Non-constant padding of an empty axis.
+        let input = Tensor::<f32>::zeros(&[0, 2]);
+        let pads = &[1, 0, 1, 0];
+        let result = pad_with_mode(input.view(), &pads.into(), PadMode::Edge, 0.);
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "Cannot pad empty axis in non-constant mode"
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pad_axes() -> Result<(), Box<dyn Error>> {
+        let input = Tensor::from([[1, 2], [3, 4]]);
+        let pads = from_slice(&[1, 2]);
+        let axes = from_slice(&[-1]);
+        let op = Pad {
+            mode: PadMode::Edge,
+        };
+        let inputs = [
+            Some((&input).into()),
+            Some((&pads).into()),
+            None,
+            Some((&axes).into()),
+        ];
+        let result = op
+            .run(InputList::from_optional(&inputs))
+            .unwrap()
+            .remove(0)
+            .into_int()
+            .unwrap();
+        expect_equal(&result, &Tensor::from([[1, 1, 2, 2, 2], [3, 3, 4, 4, 4]]))?;
+
+        // Number of pads must match number of axes.
+        let pads = from_slice(&[1, 1, 1, 1]);
+        let inputs = [
+            Some((&input).into()),
+            Some((&pads).into()),
+            None,
+            Some((&axes).into()),
+        ];
+        let result = op.run(InputList::from_optional(&inputs));
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "padding length should be 2 * axes length"
+            ))
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_pad_invalid_inputs() {
         let input = Tensor::from_data(&[2, 2], vec![1.0, 2.0, 3.0, 4.0]);
-        let op = Pad {};
+        let op = Pad::default();
 
         // Wrong padding vector length.
         let invalid_pads = from_slice(&[1]);
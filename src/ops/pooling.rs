@@ -6,7 +6,10 @@ use rten_tensor::{NdTensor, NdTensorView, NdTensorViewMut, Tensor, TensorView};
 
 use crate::check_dims;
 use crate::gemm::div_ceil;
-use crate::ops::{InputList, IntoOpResult, OpError, Operator, Output, Padding};
+use crate::ops::{
+    input_elementwise_flops, num_elements, InputList, IntoOpResult, MetaInputList, MetaOutput,
+    OpError, Operator, Output, Padding,
+};
 
 /// Calculate the output size and padding for a convolution or pooling operation.
 ///
@@ -86,27 +89,245 @@ pub fn calc_output_size_and_padding(
     Ok((out_h, out_w, padding))
 }
 
-pub fn average_pool(
-    input: TensorView,
-    kernel_size: [usize; 2],
-    strides: [usize; 2],
+/// Calculate the output size and padding for a convolution or pooling
+/// operation with any number of spatial dimensions.
+///
+/// This is a generalization of [calc_output_size_and_padding]. Padding values
+/// are specified and returned in ONNX order, ie. `[start_0, start_1 ...,
+/// end_0, end_1 ...]`.
+///
+/// Returns an `(out_size, padding)` tuple.
+pub fn calc_output_size_and_padding_nd(
+    in_size: &[usize],
+    kernel_size: &[usize],
+    strides: &[usize],
     padding: Padding,
-    count_include_pad: bool,
-) -> Result<Tensor, OpError> {
-    let [batch, in_c, in_h, in_w] = check_dims!(input, 4, "NCHW");
-    let (out_h, out_w, fixed_padding) = calc_output_size_and_padding(
-        (in_h, in_w),
-        (kernel_size[0], kernel_size[1]),
-        (strides[0], strides[1]),
+    dilations: Option<&[usize]>,
+) -> Result<(Vec<usize>, Vec<usize>), OpError> {
+    let ndim = in_size.len();
+    if kernel_size.len() != ndim {
+        return Err(OpError::InvalidValue(
+            "Kernel size does not match input spatial dims",
+        ));
+    }
+    if strides.len() != ndim {
+        return Err(OpError::InvalidValue("Wrong number of stride values"));
+    }
+    let dilations = dilations.map(|d| d.to_vec()).unwrap_or(vec![1; ndim]);
+    if dilations.len() != ndim {
+        return Err(OpError::InvalidValue("Wrong number of dilation values"));
+    }
+
+    if dilations.contains(&0) {
+        return Err(OpError::InvalidValue("Dilations must be > 0"));
+    }
+
+    if strides.contains(&0) {
+        return Err(OpError::InvalidValue("Strides must be > 0"));
+    }
+
+    let mut out_size = Vec::with_capacity(ndim);
+    let mut pads = vec![0; ndim * 2];
+
+    match padding {
+        Padding::Same => {
+            for i in 0..ndim {
+                let out = div_ceil(in_size[i], strides[i]);
+                let pad_total = ((out - 1) * strides[i] + (kernel_size[i] - 1) * dilations[i] + 1)
+                    .saturating_sub(in_size[i]);
+
+                // As in `calc_output_size_and_padding`, any odd unit of
+                // padding is assigned to the end of the axis.
+                pads[i] = pad_total / 2;
+                pads[ndim + i] = div_ceil(pad_total, 2);
+                out_size.push(out);
+            }
+        }
+        Padding::Fixed(fixed_pads) => {
+            if fixed_pads.len() != ndim * 2 {
+                return Err(OpError::InvalidValue("Wrong number of padding values"));
+            }
+            for i in 0..ndim {
+                let padded_in = in_size[i] + fixed_pads[i] + fixed_pads[ndim + i];
+                let dilated_k = kernel_size[i] + (kernel_size[i] - 1) * (dilations[i] - 1);
+                if padded_in < dilated_k {
+                    return Err(OpError::InvalidValue("Input too small for kernel size"));
+                }
+                out_size
+                    .push((padded_in - dilations[i] * (kernel_size[i] - 1) - 1) / strides[i] + 1);
+            }
+            pads.copy_from_slice(&fixed_pads);
+        }
+    }
+
+    Ok((out_size, pads))
+}
+
+/// Calculate the output size and padding for a pooling operation with any
+/// number of spatial dimensions.
+///
+/// This extends [calc_output_size_and_padding_nd] with support for
+/// `ceil_mode`, which rounds the output size up rather than down when using
+/// fixed padding. As in PyTorch and ONNX Runtime, windows which would start in
+/// the end padding are excluded.
+fn calc_pool_output_size_and_padding(
+    in_size: &[usize],
+    kernel_size: &[usize],
+    strides: &[usize],
+    padding: Padding,
+    dilations: Option<&[usize]>,
+    ceil_mode: bool,
+) -> Result<(Vec<usize>, Vec<usize>), OpError> {
+    let round_up = ceil_mode && matches!(padding, Padding::Fixed(_));
+    let (mut out_size, pads) =
+        calc_output_size_and_padding_nd(in_size, kernel_size, strides, padding, dilations)?;
+
+    if round_up {
+        let ndim = in_size.len();
+        for i in 0..ndim {
+            let dilation = dilations.map(|d| d[i]).unwrap_or(1);
+            let padded_in = in_size[i] + pads[i] + pads[ndim + i];
+            let dilated_k = (kernel_size[i] - 1) * dilation + 1;
+            let mut out = div_ceil(padded_in - dilated_k, strides[i]) + 1;
+            if (out - 1) * strides[i] >= in_size[i] + pads[i] {
+                out -= 1;
+            }
+            out_size[i] = out;
+        }
+    }
+
+    Ok((out_size, pads))
+}
+
+/// Compute the output shape of a pooling operation on an input with 1-3
+/// spatial dimensions (eg. NCHW for 2D pooling).
+fn pool_output_shape(
+    input_shape: &[usize],
+    kernel_size: &[usize],
+    strides: &[usize],
+    padding: Padding,
+    dilations: Option<&[usize]>,
+    ceil_mode: bool,
+) -> Result<Vec<usize>, OpError> {
+    if input_shape.len() != kernel_size.len() + 2 {
+        return Err(OpError::InvalidValue(
+            "Input must have 2 more dims than kernel (NC + spatial dims)",
+        ));
+    }
+    let (out_size, _) = calc_pool_output_size_and_padding(
+        &input_shape[2..],
+        kernel_size,
+        strides,
         padding,
-        None,
+        dilations,
+        ceil_mode,
     )?;
-    let [pad_top, pad_left, _pad_bottom, _pad_right] = fixed_padding;
-    let [kernel_h, kernel_w] = kernel_size;
-    let [stride_h, stride_w] = strides;
+    let mut shape = input_shape[..2].to_vec();
+    shape.extend(out_size);
+    Ok(shape)
+}
 
-    let mut output = NdTensor::zeros([batch, in_c, out_h, out_w]);
-    let input = input.nd_view::<4>();
+/// Spatial parameters for a pooling operation, expanded to three spatial
+/// dimensions.
+struct PoolParams {
+    kernel_size: [usize; 3],
+    strides: [usize; 3],
+    dilations: [usize; 3],
+
+    /// Padding at the start of each spatial dimension.
+    pad_start: [usize; 3],
+
+    /// Padding at the end of each spatial dimension.
+    pad_end: [usize; 3],
+
+    out_size: [usize; 3],
+}
+
+/// Prepare for a pooling operation on an input with 1-3 spatial dimensions.
+///
+/// Pooling is implemented for NCDHW inputs only. Inputs with fewer spatial
+/// dimensions are handled by inserting size-1 dimensions after the channel
+/// dimension, and padding the kernel size etc. to match.
+///
+/// Returns a tuple of `(input, params, output_shape)` where `input` is the
+/// expanded NCDHW input and `output_shape` is the shape of the unexpanded
+/// output.
+fn prepare_pool<'a>(
+    input: TensorView<'a>,
+    kernel_size: &[usize],
+    strides: &[usize],
+    padding: Padding,
+    dilations: Option<&[usize]>,
+    ceil_mode: bool,
+) -> Result<(NdTensorView<'a, f32, 5>, PoolParams, Vec<usize>), OpError> {
+    let spatial_dims = kernel_size.len();
+    if !(1..=3).contains(&spatial_dims) {
+        return Err(OpError::InvalidValue("Kernel must have 1, 2 or 3 dims"));
+    }
+    if input.ndim() != spatial_dims + 2 {
+        return Err(OpError::InvalidValue(
+            "Input must have 2 more dims than kernel (NC + spatial dims)",
+        ));
+    }
+
+    let (out_size, pads) = calc_pool_output_size_and_padding(
+        &input.shape()[2..],
+        kernel_size,
+        strides,
+        padding,
+        dilations,
+        ceil_mode,
+    )?;
+    let mut output_shape = input.shape()[..2].to_vec();
+    output_shape.extend(&out_size);
+
+    // Expand spatial parameters to 3D.
+    let expand = |vals: &[usize], fill: usize| -> [usize; 3] {
+        let mut expanded = [fill; 3];
+        expanded[3 - vals.len()..].copy_from_slice(vals);
+        expanded
+    };
+    let params = PoolParams {
+        kernel_size: expand(kernel_size, 1),
+        strides: expand(strides, 1),
+        dilations: expand(dilations.unwrap_or(&[1; 3][..spatial_dims]), 1),
+        pad_start: expand(&pads[..spatial_dims], 0),
+        pad_end: expand(&pads[spatial_dims..], 0),
+        out_size: expand(&out_size, 1),
+    };
+
+    let mut input = input;
+    while input.ndim() < 5 {
+        input.insert_axis(2);
+    }
+
+    Ok((input.nd_view(), params, output_shape))
+}
+
+/// Pool an NCDHW input by summing a function of the elements in each window.
+///
+/// `map` is applied to each input element in the window. `finalize` computes
+/// the output value from the sum, the number of window positions that are
+/// inside the input and the number that are inside the padded input.
+fn sum_pool<M: Fn(f32) -> f32, F: Fn(f32, usize, usize) -> f32>(
+    input: NdTensorView<f32, 5>,
+    params: &PoolParams,
+    map: M,
+    finalize: F,
+) -> NdTensor<f32, 5> {
+    let [batch, in_c, in_d, in_h, in_w] = input.shape();
+    let [kernel_d, kernel_h, kernel_w] = params.kernel_size;
+    let [stride_d, stride_h, stride_w] = params.strides;
+    let [dilation_d, dilation_h, dilation_w] = params.dilations;
+    let [pad_front, pad_top, pad_left] = params.pad_start;
+    let [pad_back, pad_bottom, pad_right] = params.pad_end;
+    let [out_d, out_h, out_w] = params.out_size;
+
+    let padded_d = in_d + pad_front + pad_back;
+    let padded_h = in_h + pad_top + pad_bottom;
+    let padded_w = in_w + pad_left + pad_right;
+
+    let mut output = NdTensor::zeros([batch, in_c, out_d, out_h, out_w]);
 
     for n in 0..batch {
         for chan in 0..in_c {
@@ -114,48 +335,104 @@ pub fn average_pool(
             let mut out_view = out_view.weakly_checked_view_mut();
             let in_view = input.slice([n, chan]).weakly_checked_view();
 
-            for out_y in 0..out_h {
-                for out_x in 0..out_w {
-                    let mut accumulator = 0.0;
-                    let mut non_padding_elements = 0.0;
-
-                    for k_y in 0..kernel_h {
-                        for k_x in 0..kernel_w {
-                            let in_y = out_y * stride_h + k_y;
-                            let in_x = out_x * stride_w + k_x;
-                            if in_y >= pad_top
-                                && in_y < in_h + pad_top
-                                && in_x >= pad_left
-                                && in_x < in_w + pad_left
-                            {
-                                let val = in_view[[in_y - pad_top, in_x - pad_left]];
-                                accumulator += val;
-                                non_padding_elements += 1.0;
+            for out_z in 0..out_d {
+                for out_y in 0..out_h {
+                    for out_x in 0..out_w {
+                        let mut accumulator = 0.0;
+                        let mut non_padding_elements = 0;
+                        let mut padded_elements = 0;
+
+                        for k_z in 0..kernel_d {
+                            let in_z = out_z * stride_d + k_z * dilation_d;
+                            if in_z >= padded_d {
+                                continue;
+                            }
+                            let z_valid = in_z >= pad_front && in_z < in_d + pad_front;
+
+                            for k_y in 0..kernel_h {
+                                let in_y = out_y * stride_h + k_y * dilation_h;
+                                if in_y >= padded_h {
+                                    continue;
+                                }
+                                let y_valid = in_y >= pad_top && in_y < in_h + pad_top;
+
+                                for k_x in 0..kernel_w {
+                                    let in_x = out_x * stride_w + k_x * dilation_w;
+                                    if in_x >= padded_w {
+                                        continue;
+                                    }
+                                    padded_elements += 1;
+
+                                    let x_valid = in_x >= pad_left && in_x < in_w + pad_left;
+                                    if z_valid && y_valid && x_valid {
+                                        let val = in_view
+                                            [[in_z - pad_front, in_y - pad_top, in_x - pad_left]];
+                                        accumulator += map(val);
+                                        non_padding_elements += 1;
+                                    }
+                                }
                             }
                         }
-                    }
 
-                    let counted_elems = if count_include_pad {
-                        (kernel_h * kernel_w) as f32
-                    } else {
-                        non_padding_elements
-                    };
-
-                    out_view[[out_y, out_x]] = accumulator / counted_elems;
+                        out_view[[out_z, out_y, out_x]] =
+                            finalize(accumulator, non_padding_elements, padded_elements);
+                    }
                 }
             }
         }
     }
 
-    Ok(output.into_dyn())
+    output
+}
+
+/// Perform average pooling over the spatial dimensions of an input.
+///
+/// `input` has 1-3 spatial dimensions (eg. NCW, NCHW or NCDHW) and the lengths
+/// of `kernel_size`, `strides` and `dilations` must match the number of
+/// spatial dimensions.
+///
+/// If `count_include_pad` is true, padding elements inside the window are
+/// included when computing the average. Elements beyond the end padding, which
+/// can occur when `ceil_mode` is true, are never included.
+pub fn average_pool(
+    input: TensorView,
+    kernel_size: &[usize],
+    strides: &[usize],
+    padding: Padding,
+    dilations: Option<&[usize]>,
+    ceil_mode: bool,
+    count_include_pad: bool,
+) -> Result<Tensor, OpError> {
+    let (input, params, output_shape) =
+        prepare_pool(input, kernel_size, strides, padding, dilations, ceil_mode)?;
+
+    let output = sum_pool(
+        input,
+        &params,
+        |x| x,
+        |sum, non_padding_elements, padded_elements| {
+            let counted_elems = if count_include_pad {
+                padded_elements
+            } else {
+                non_padding_elements
+            };
+            sum / counted_elems as f32
+        },
+    );
+
+    let mut output = output.into_dyn();
+    output.reshape(&output_shape);
+    Ok(output)
 }
 
 #[derive(Debug)]
 pub struct AveragePool {
-    pub kernel_size: [usize; 2],
+    pub kernel_size: Vec<usize>,
     pub padding: Padding,
     pub count_include_pad: bool,
-    pub strides: [usize; 2],
+    pub strides: Vec<usize>,
+    pub dilations: Vec<usize>,
+    pub ceil_mode: bool,
 }
 
 impl Operator for AveragePool {
@@ -167,13 +444,115 @@ impl Operator for AveragePool {
         let input = inputs.require_as(0)?;
         average_pool(
             input,
-            self.kernel_size,
-            self.strides,
+            &self.kernel_size,
+            &self.strides,
             self.padding.clone(),
+            Some(&self.dilations),
+            self.ceil_mode,
             self.count_include_pad,
         )
         .into_op_result()
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], output_shapes: &[&[usize]]) -> Option<u64> {
+        let kernel_len: usize = self.kernel_size.iter().product();
+        Some(num_elements(output_shapes.first()?) * kernel_len as u64)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| {
+            let shape = pool_output_shape(
+                inputs.require_shape(0)?,
+                &self.kernel_size,
+                &self.strides,
+                self.padding.clone(),
+                Some(&self.dilations),
+                self.ceil_mode,
+            )?;
+            Ok(vec![shape.into()])
+        })())
+    }
+}
+
+/// Perform Lp pooling over the spatial dimensions of an input.
+///
+/// Each output is the p-norm (`sum(|x|^p)^(1/p)`) of the elements in the
+/// corresponding window. Padding elements are treated as zeros. Other
+/// arguments are the same as for [average_pool].
+pub fn lp_pool(
+    input: TensorView,
+    kernel_size: &[usize],
+    strides: &[usize],
+    padding: Padding,
+    dilations: Option<&[usize]>,
+    ceil_mode: bool,
+    p: i32,
+) -> Result<Tensor, OpError> {
+    if p <= 0 {
+        return Err(OpError::InvalidValue("p must be > 0"));
+    }
+    let (input, params, output_shape) =
+        prepare_pool(input, kernel_size, strides, padding, dilations, ceil_mode)?;
+
+    let output = sum_pool(
+        input,
+        &params,
+        |x| x.abs().powi(p),
+        |sum, _, _| sum.powf(1. / p as f32),
+    );
+
+    let mut output = output.into_dyn();
+    output.reshape(&output_shape);
+    Ok(output)
+}
+
+#[derive(Debug)]
+pub struct LpPool {
+    pub kernel_size: Vec<usize>,
+    pub padding: Padding,
+    pub strides: Vec<usize>,
+    pub dilations: Vec<usize>,
+    pub ceil_mode: bool,
+    pub p: i32,
+}
+
+impl Operator for LpPool {
+    fn name(&self) -> &str {
+        "LpPool"
+    }
+
+    fn run(&self, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let input = inputs.require_as(0)?;
+        lp_pool(
+            input,
+            &self.kernel_size,
+            &self.strides,
+            self.padding.clone(),
+            Some(&self.dilations),
+            self.ceil_mode,
+            self.p,
+        )
+        .into_op_result()
+    }
+
+    fn flops(&self, _: &[Option<&[usize]>], output_shapes: &[&[usize]]) -> Option<u64> {
+        let kernel_len: usize = self.kernel_size.iter().product();
+        Some(num_elements(output_shapes.first()?) * 2 * kernel_len as u64)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| {
+            let shape = pool_output_shape(
+                inputs.require_shape(0)?,
+                &self.kernel_size,
+                &self.strides,
+                self.padding.clone(),
+                Some(&self.dilations),
+                self.ceil_mode,
+            )?;
+            Ok(vec![shape.into()])
+        })())
+    }
 }
 
 pub fn global_average_pool(input: TensorView) -> Result<Tensor, OpError> {
@@ -232,70 +611,206 @@ impl Operator for GlobalAveragePool {
         let input = inputs.require_as(0)?;
         global_average_pool(input).into_op_result()
     }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        input_elementwise_flops(input_shapes, 1)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| match inputs.require_shape(0)? {
+            &[batch, chans, _, _] => Ok(vec![vec![batch, chans, 1, 1].into()]),
+            _ => Err(OpError::InvalidValue("Input must have 4 dims (NCHW)")),
+        })())
+    }
+}
+
+/// Reduce the spatial dimensions of each channel of an input with shape
+/// `[N, C, ...]` to a single value. Returns an output with shape
+/// `[N, C, 1, ...]`.
+fn global_pool<F: Fn(&[f32]) -> f32>(input: TensorView, reduce: F) -> Result<Tensor, OpError> {
+    let out_shape = global_pool_output_shape(input.shape())?;
+    let spatial_len: usize = input.shape()[2..].iter().product();
+    if spatial_len == 0 {
+        return Err(OpError::InvalidValue("Spatial dims must be non-empty"));
+    }
+
+    let input = input.to_contiguous();
+    let out_data: Vec<f32> = input
+        .data()
+        .unwrap()
+        .chunks(spatial_len)
+        .map(reduce)
+        .collect();
+    Ok(Tensor::from_data(&out_shape, out_data))
+}
+
+/// Compute the output shape of a global pooling operation.
+fn global_pool_output_shape(input_shape: &[usize]) -> Result<Vec<usize>, OpError> {
+    if input_shape.len() < 3 {
+        return Err(OpError::InvalidValue(
+            "Input must have at least 3 dims (NC + spatial dims)",
+        ));
+    }
+    let mut shape = input_shape.to_vec();
+    shape[2..].fill(1);
+    Ok(shape)
+}
+
+/// Perform max pooling over all spatial dimensions of an input with shape
+/// `[N, C, ...]`.
+pub fn global_max_pool(input: TensorView) -> Result<Tensor, OpError> {
+    global_pool(input, |chan| {
+        chan.iter()
+            .copied()
+            .fold(f32::NEG_INFINITY, |max, x| max.max(x))
+    })
+}
+
+#[derive(Debug)]
+pub struct GlobalMaxPool {}
+
+impl Operator for GlobalMaxPool {
+    fn name(&self) -> &str {
+        "GlobalMaxPool"
+    }
+
+    fn run(&self, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let input = inputs.require_as(0)?;
+        global_max_pool(input).into_op_result()
+    }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        input_elementwise_flops(input_shapes, 1)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| {
+            let shape = global_pool_output_shape(inputs.require_shape(0)?)?;
+            Ok(vec![shape.into()])
+        })())
+    }
+}
+
+/// Perform Lp pooling over all spatial dimensions of an input with shape
+/// `[N, C, ...]`.
+pub fn global_lp_pool(input: TensorView, p: i32) -> Result<Tensor, OpError> {
+    if p <= 0 {
+        return Err(OpError::InvalidValue("p must be > 0"));
+    }
+    global_pool(input, |chan| {
+        let sum: f32 = chan.iter().map(|x| x.abs().powi(p)).sum();
+        sum.powf(1. / p as f32)
+    })
+}
+
+#[derive(Debug)]
+pub struct GlobalLpPool {
+    pub p: i32,
 }
 
+impl Operator for GlobalLpPool {
+    fn name(&self) -> &str {
+        "GlobalLpPool"
+    }
+
+    fn run(&self, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let input = inputs.require_as(0)?;
+        global_lp_pool(input, self.p).into_op_result()
+    }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        input_elementwise_flops(input_shapes, 2)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| {
+            let shape = global_pool_output_shape(inputs.require_shape(0)?)?;
+            Ok(vec![shape.into()])
+        })())
+    }
+}
+
+/// Perform max pooling over the spatial dimensions of an input.
+///
+/// `input` has 1-3 spatial dimensions (eg. NCW, NCHW or NCDHW) and the lengths
+/// of `kernel_size`, `strides` and `dilations` must match the number of
+/// spatial dimensions.
 pub fn max_pool(
     input: TensorView,
-    kernel_size: [usize; 2],
-    strides: [usize; 2],
+    kernel_size: &[usize],
+    strides: &[usize],
     padding: Padding,
+    dilations: Option<&[usize]>,
+    ceil_mode: bool,
 ) -> Result<Tensor, OpError> {
-    let [batch, in_c, in_h, in_w] = check_dims!(input, 4, "NCHW");
-    let (out_h, out_w, fixed_padding) = calc_output_size_and_padding(
-        (in_h, in_w),
-        (kernel_size[0], kernel_size[1]),
-        (strides[0], strides[1]),
-        padding,
-        None, /* dilations */
-    )?;
-    let [pad_top, pad_left, _pad_bottom, _pad_right] = fixed_padding;
-    let mut output = Tensor::zeros(&[batch, in_c, out_h, out_w]);
+    let (input, params, output_shape) =
+        prepare_pool(input, kernel_size, strides, padding, dilations, ceil_mode)?;
+    let [batch, in_c, ..] = input.shape();
+    let [out_d, out_h, out_w] = params.out_size;
+    let mut output = NdTensor::zeros([batch, in_c, out_d, out_h, out_w]);
 
     // Apply max-pooling to the channel indexes specified by `chans`.
     // Assuming `N` is chosen appropriately the inner loop should get unrolled /
     // autovectorized.
     fn max_pool_chans<const N: usize>(
-        mut out: NdTensorViewMut<f32, 3>,
-        in_view: NdTensorView<f32, 3>,
+        mut out: NdTensorViewMut<f32, 4>,
+        in_view: NdTensorView<f32, 4>,
         chans: [usize; N],
-        [kernel_h, kernel_w]: [usize; 2],
-        [stride_h, stride_w]: [usize; 2],
-        [pad_top, pad_left]: [usize; 2],
+        params: &PoolParams,
     ) {
-        let [out_chans, out_h, out_w] = out.shape();
-        let [in_chans, in_h, in_w] = in_view.shape();
+        let [out_chans, out_d, out_h, out_w] = out.shape();
+        let [in_chans, in_d, in_h, in_w] = in_view.shape();
+        let [kernel_d, kernel_h, kernel_w] = params.kernel_size;
+        let [stride_d, stride_h, stride_w] = params.strides;
+        let [dilation_d, dilation_h, dilation_w] = params.dilations;
+        let [pad_front, pad_top, pad_left] = params.pad_start;
         assert!(chans.into_iter().all(|c| c < out_chans && c < in_chans));
 
-        for out_y in 0..out_h {
-            for out_x in 0..out_w {
-                let mut accumulator = [f32::NEG_INFINITY; N];
-                for k_y in 0..kernel_h {
-                    for k_x in 0..kernel_w {
-                        let in_y = out_y * stride_h + k_y;
-                        let in_x = out_x * stride_w + k_x;
-                        if in_y >= pad_top
-                            && in_y < in_h + pad_top
-                            && in_x >= pad_left
-                            && in_x < in_w + pad_left
-                        {
-                            for (i, chan) in chans.into_iter().enumerate() {
-                                // Safety:
-                                //  - We checked all `chans` are in-bounds
-                                //  - `in_y` and `in_x` are >= pad_top and pad_left
-                                let val = unsafe {
-                                    *in_view.get_unchecked([chan, in_y - pad_top, in_x - pad_left])
-                                };
-                                accumulator[i] = accumulator[i].max(val);
+        for out_z in 0..out_d {
+            for out_y in 0..out_h {
+                for out_x in 0..out_w {
+                    let mut accumulator = [f32::NEG_INFINITY; N];
+                    for k_z in 0..kernel_d {
+                        let in_z = out_z * stride_d + k_z * dilation_d;
+                        if in_z < pad_front || in_z >= in_d + pad_front {
+                            continue;
+                        }
+
+                        for k_y in 0..kernel_h {
+                            for k_x in 0..kernel_w {
+                                let in_y = out_y * stride_h + k_y * dilation_h;
+                                let in_x = out_x * stride_w + k_x * dilation_w;
+                                if in_y >= pad_top
+                                    && in_y < in_h + pad_top
+                                    && in_x >= pad_left
+                                    && in_x < in_w + pad_left
+                                {
+                                    for (i, chan) in chans.into_iter().enumerate() {
+                                        // Safety:
+                                        //  - We checked all `chans` are in-bounds
+                                        //  - `in_z`, `in_y` and `in_x` are >= the
+                                        //    padding at the start of each dim
+                                        let val = unsafe {
+                                            *in_view.get_unchecked([
+                                                chan,
+                                                in_z - pad_front,
+                                                in_y - pad_top,
+                                                in_x - pad_left,
+                                            ])
+                                        };
+                                        accumulator[i] = accumulator[i].max(val);
+                                    }
+                                }
                             }
                         }
                     }
-                }
-                for (i, chan) in chans.into_iter().enumerate() {
-                    // Safety:
-                    //  - We checked all `chans` are in-bounds
-                    //  - `out_y` and `out_x` are in 0..out_h, 0..out_w
-                    unsafe {
-                        *out.get_unchecked_mut([chan, out_y, out_x]) = accumulator[i];
+                    for (i, chan) in chans.into_iter().enumerate() {
+                        // Safety:
+                        //  - We checked all `chans` are in-bounds
+                        //  - `out_z`, `out_y` and `out_x` are in bounds
+                        unsafe {
+                            *out.get_unchecked_mut([chan, out_z, out_y, out_x]) = accumulator[i];
+                        }
                     }
                 }
             }
@@ -318,33 +833,120 @@ pub fn max_pool(
                     out_item.view_mut(),
                     in_item,
                     [chan, chan + 1, chan + 2, chan + 3],
-                    kernel_size,
-                    strides,
-                    [pad_top, pad_left],
+                    &params,
                 );
             }
 
             // Loop over remaining channels.
             for chan in (in_c - in_c % N)..in_c {
-                max_pool_chans(
-                    out_item.view_mut(),
-                    in_item,
-                    [chan],
-                    kernel_size,
-                    strides,
-                    [pad_top, pad_left],
-                );
+                max_pool_chans(out_item.view_mut(), in_item, [chan], &params);
             }
         });
 
+    let mut output = output.into_dyn();
+    output.reshape(&output_shape);
     Ok(output)
 }
 
+/// Perform max pooling over the spatial dimensions of an input and return
+/// the indices of the selected elements.
+///
+/// This is like [max_pool] but additionally returns a tensor with the same
+/// shape as the output containing the index of each maximum value in the
+/// flattened input. If a window contains several equal maximum values, the
+/// first is selected. Indices are `i64`, as for the ONNX `MaxPool` operator.
+pub fn max_pool_with_indices(
+    input: TensorView,
+    kernel_size: &[usize],
+    strides: &[usize],
+    padding: Padding,
+    dilations: Option<&[usize]>,
+    ceil_mode: bool,
+) -> Result<(Tensor, Tensor<i64>), OpError> {
+    let (input, params, output_shape) =
+        prepare_pool(input, kernel_size, strides, padding, dilations, ceil_mode)?;
+    let [batch, in_c, in_d, in_h, in_w] = input.shape();
+    let [kernel_d, kernel_h, kernel_w] = params.kernel_size;
+    let [stride_d, stride_h, stride_w] = params.strides;
+    let [dilation_d, dilation_h, dilation_w] = params.dilations;
+    let [pad_front, pad_top, pad_left] = params.pad_start;
+    let [out_d, out_h, out_w] = params.out_size;
+
+    let mut output = NdTensor::zeros([batch, in_c, out_d, out_h, out_w]);
+    let mut indices = NdTensor::zeros([batch, in_c, out_d, out_h, out_w]);
+
+    // Pool each (batch, channel) item in parallel.
+    zip(
+        zip(output.inner_iter_mut::<3>(), indices.inner_iter_mut::<3>()),
+        input.inner_iter::<3>(),
+    )
+    .enumerate()
+    .par_bridge()
+    .for_each(|(item, ((mut out_item, mut indices_item), in_item))| {
+        // Offset of this channel in the flattened input. Since the dimensions
+        // inserted by `prepare_pool` have size 1, this is the same for the
+        // expanded and original input.
+        let chan_offset = item * in_d * in_h * in_w;
+
+        for out_z in 0..out_d {
+            for out_y in 0..out_h {
+                for out_x in 0..out_w {
+                    let mut max_val = f32::NEG_INFINITY;
+                    let mut max_index = None;
+
+                    for k_z in 0..kernel_d {
+                        let in_z = out_z * stride_d + k_z * dilation_d;
+                        if in_z < pad_front || in_z >= in_d + pad_front {
+                            continue;
+                        }
+                        for k_y in 0..kernel_h {
+                            let in_y = out_y * stride_h + k_y * dilation_h;
+                            if in_y < pad_top || in_y >= in_h + pad_top {
+                                continue;
+                            }
+                            for k_x in 0..kernel_w {
+                                let in_x = out_x * stride_w + k_x * dilation_w;
+                                if in_x < pad_left || in_x >= in_w + pad_left {
+                                    continue;
+                                }
+                                let [z, y, x] = [in_z - pad_front, in_y - pad_top, in_x - pad_left];
+                                let val = in_item[[z, y, x]];
+                                if max_index.is_none() || val > max_val {
+                                    max_val = val;
+                                    max_index = Some((z * in_h + y) * in_w + x);
+                                }
+                            }
+                        }
+                    }
+
+                    out_item[[out_z, out_y, out_x]] = max_val;
+                    indices_item[[out_z, out_y, out_x]] = max_index
+                        .map(|idx| (chan_offset + idx) as i64)
+                        .unwrap_or(-1);
+                }
+            }
+        }
+    });
+
+    let mut output = output.into_dyn();
+    output.reshape(&output_shape);
+    let mut indices = indices.into_dyn();
+    indices.reshape(&output_shape);
+
+    Ok((output, indices))
+}
+
 #[derive(Debug)]
 pub struct MaxPool {
-    pub kernel_size: [usize; 2],
+    pub kernel_size: Vec<usize>,
     pub padding: Padding,
-    pub strides: [usize; 2],
+    pub strides: Vec<usize>,
+    pub dilations: Vec<usize>,
+    pub ceil_mode: bool,
+
+    /// Whether to produce a second output containing the indices of the
+    /// selected elements in the flattened input.
+    pub return_indices: bool,
 }
 
 impl Operator for MaxPool {
@@ -354,7 +956,49 @@ impl Operator for MaxPool {
 
     fn run(&self, inputs: InputList) -> Result<Vec<Output>, OpError> {
         let input = inputs.require_as(0)?;
-        max_pool(input, self.kernel_size, self.strides, self.padding.clone()).into_op_result()
+        if self.return_indices {
+            let (output, indices) = max_pool_with_indices(
+                input,
+                &self.kernel_size,
+                &self.strides,
+                self.padding.clone(),
+                Some(&self.dilations),
+                self.ceil_mode,
+            )?;
+            return Ok([output.into(), indices.into()].into_iter().collect());
+        }
+        max_pool(
+            input,
+            &self.kernel_size,
+            &self.strides,
+            self.padding.clone(),
+            Some(&self.dilations),
+            self.ceil_mode,
+        )
+        .into_op_result()
+    }
+
+    fn flops(&self, _: &[Option<&[usize]>], output_shapes: &[&[usize]]) -> Option<u64> {
+        let kernel_len: usize = self.kernel_size.iter().product();
+        Some(num_elements(output_shapes.first()?) * kernel_len as u64)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| {
+            let shape = pool_output_shape(
+                inputs.require_shape(0)?,
+                &self.kernel_size,
+                &self.strides,
+                self.padding.clone(),
+                Some(&self.dilations),
+                self.ceil_mode,
+            )?;
+            if self.return_indices {
+                Ok(vec![shape.clone().into(), shape.into()])
+            } else {
+                Ok(vec![shape.into()])
+            }
+        })())
     }
 }
 
@@ -366,9 +1010,12 @@ mod tests {
     use rten_tensor::test_util::expect_equal;
     use rten_tensor::Tensor;
 
-    use super::calc_output_size_and_padding;
+    use super::{calc_output_size_and_padding, calc_output_size_and_padding_nd};
     use crate::ops::tests::expect_eq_1e4;
-    use crate::ops::{average_pool, global_average_pool, max_pool, OpError, Padding};
+    use crate::ops::{
+        average_pool, global_average_pool, global_lp_pool, global_max_pool, lp_pool, max_pool,
+        max_pool_with_indices, MaxPool, OpError, Operator, Output, Padding,
+    };
 
     #[test]
     fn test_average_pool() -> Result<(), Box<dyn Error>> {
@@ -438,9 +1085,11 @@ mod tests {
         for case in cases {
             let result = average_pool(
                 input.view(),
-                case.kernel_size,
-                case.strides,
+                &case.kernel_size,
+                &case.strides,
                 [0, 0, 0, 0].into(),
+                None,  /* dilations */
+                false, /* ceil_mode */
                 false, /* count_include_pad */
             )
             .unwrap();
@@ -473,9 +1122,11 @@ mod tests {
 
         let result = average_pool(
             input.view(),
-            [2, 2],
-            [2, 2], /* stride */
+            &[2, 2],
+            &[2, 2], /* stride */
             [1, 1, 1, 1].into(),
+            None,  /* dilations */
+            false, /* ceil_mode */
             false, /* count_include_pad */
         )
         .unwrap();
@@ -491,10 +1142,12 @@ mod tests {
         .into_dyn();
         let result = average_pool(
             input.view(),
-            [2, 2],
-            [2, 2], /* stride */
+            &[2, 2],
+            &[2, 2], /* stride */
             [1, 1, 1, 1].into(),
-            true, /* count_include_pad */
+            None,  /* dilations */
+            false, /* ceil_mode */
+            true,  /* count_include_pad */
         )
         .unwrap();
         expect_eq_1e4(&result, &expected_include_pad)?;
@@ -578,9 +1231,11 @@ mod tests {
         for case in cases {
             let result = max_pool(
                 input.view(),
-                case.kernel_size,
-                case.strides,
+                &case.kernel_size,
+                &case.strides,
                 [0, 0, 0, 0].into(),
+                None,  /* dilations */
+                false, /* ceil_mode */
             )
             .unwrap();
             expect_equal(&result, &case.expected)?;
@@ -593,22 +1248,407 @@ mod tests {
     fn test_max_pool_padding() {
         let input = Tensor::zeros(&[1, 1, 9, 9]);
 
-        let result = max_pool(input.view(), [2, 2], [2, 2], [0, 0, 0, 0].into()).unwrap();
+        let result = max_pool(
+            input.view(),
+            &[2, 2],
+            &[2, 2],
+            [0, 0, 0, 0].into(),
+            None,
+            false,
+        )
+        .unwrap();
         assert_eq!(result.shape(), &[1, 1, 4, 4]);
 
-        let result = max_pool(input.view(), [2, 2], [2, 2], [1, 1, 1, 1].into()).unwrap();
+        let result = max_pool(
+            input.view(),
+            &[2, 2],
+            &[2, 2],
+            [1, 1, 1, 1].into(),
+            None,
+            false,
+        )
+        .unwrap();
         assert_eq!(result.shape(), &[1, 1, 5, 5]);
 
-        let result = max_pool(input.view(), [2, 2], [2, 2], [2, 2, 2, 2].into()).unwrap();
+        let result = max_pool(
+            input.view(),
+            &[2, 2],
+            &[2, 2],
+            [2, 2, 2, 2].into(),
+            None,
+            false,
+        )
+        .unwrap();
         assert_eq!(result.shape(), &[1, 1, 6, 6]);
 
-        let result = max_pool(input.view(), [2, 2], [2, 2], Padding::Same).unwrap();
+        let result = max_pool(input.view(), &[2, 2], &[2, 2], Padding::Same, None, false).unwrap();
         assert_eq!(result.shape(), &[1, 1, 5, 5]);
 
-        let result = max_pool(input.view(), [2, 2], [3, 3], Padding::Same).unwrap();
+        let result = max_pool(input.view(), &[2, 2], &[3, 3], Padding::Same, None, false).unwrap();
         assert_eq!(result.shape(), &[1, 1, 3, 3]);
     }
 
+    #[test]
+    fn test_pool_3d() -> Result<(), Box<dyn Error>> {
+        // 2x2x2 input with values 1..=8.
+        let input = Tensor::from_data(&[1, 1, 2, 2, 2], (1..=8).map(|x| x as f32).collect());
+
+        let result = max_pool(
+            input.view(),
+            &[2, 2, 2],
+            &[1, 1, 1],
+            [0; 6].into(),
+            None,
+            false,
+        )
+        .unwrap();
+        expect_equal(&result, &Tensor::from_data(&[1, 1, 1, 1, 1], vec![8.]))?;
+
+        let result = max_pool(
+            input.view(),
+            &[2, 1, 1],
+            &[1, 1, 1],
+            [0; 6].into(),
+            None,
+            false,
+        )
+        .unwrap();
+        expect_equal(
+            &result,
+            &Tensor::from_data(&[1, 1, 1, 2, 2], vec![5., 6., 7., 8.]),
+        )?;
+
+        let result = average_pool(
+            input.view(),
+            &[2, 2, 2],
+            &[1, 1, 1],
+            [0; 6].into(),
+            None,  /* dilations */
+            false, /* ceil_mode */
+            false, /* count_include_pad */
+        )
+        .unwrap();
+        expect_equal(&result, &Tensor::from_data(&[1, 1, 1, 1, 1], vec![4.5]))?;
+
+        // Padding only along the depth axis.
+        let result = average_pool(
+            input.view(),
+            &[2, 2, 2],
+            &[1, 2, 2],
+            [1, 0, 0, 0, 0, 0].into(),
+            None,  /* dilations */
+            false, /* ceil_mode */
+            true,  /* count_include_pad */
+        )
+        .unwrap();
+        expect_equal(
+            &result,
+            &Tensor::from_data(&[1, 1, 2, 1, 1], vec![10. / 8., 36. / 8.]),
+        )?;
+
+        let input = Tensor::zeros(&[2, 3, 9, 9, 9]);
+        let result = max_pool(
+            input.view(),
+            &[2, 2, 2],
+            &[2, 2, 2],
+            Padding::Same,
+            None,
+            false,
+        )
+        .unwrap();
+        assert_eq!(result.shape(), &[2, 3, 5, 5, 5]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pool_1d() -> Result<(), Box<dyn Error>> {
+        let input = Tensor::from_data(&[1, 1, 5], vec![1., 3., 2., 5., 4.]);
+
+        let result = max_pool(input.view(), &[2], &[2], [0, 1].into(), None, false).unwrap();
+        expect_equal(&result, &Tensor::from_data(&[1, 1, 3], vec![3., 5., 4.]))?;
+
+        let result =
+            average_pool(input.view(), &[2], &[2], [0, 0].into(), None, false, false).unwrap();
+        expect_equal(&result, &Tensor::from_data(&[1, 1, 2], vec![2., 3.5]))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pool_ceil_mode_and_dilations() -> Result<(), Box<dyn Error>> {
+        let input = Tensor::from_data(&[1, 1, 5], vec![1., 3., 2., 5., 4.]);
+
+        // With `ceil_mode`, the final partial window is included.
+        let result = max_pool(input.view(), &[2], &[2], [0, 0].into(), None, true).unwrap();
+        expect_equal(&result, &Tensor::from_data(&[1, 1, 3], vec![3., 5., 4.]))?;
+
+        let result = average_pool(
+            input.view(),
+            &[2],
+            &[2],
+            [0, 0].into(),
+            None,  /* dilations */
+            true,  /* ceil_mode */
+            false, /* count_include_pad */
+        )
+        .unwrap();
+        expect_equal(&result, &Tensor::from_data(&[1, 1, 3], vec![2., 3.5, 4.]))?;
+
+        // Positions in the final window that are beyond the end padding are
+        // not counted, even if `count_include_pad` is true.
+        let result = average_pool(
+            input.view(),
+            &[2],
+            &[2],
+            [0, 0].into(),
+            None, /* dilations */
+            true, /* ceil_mode */
+            true, /* count_include_pad */
+        )
+        .unwrap();
+        expect_equal(&result, &Tensor::from_data(&[1, 1, 3], vec![2., 3.5, 4.]))?;
+
+        // Windows that would start in the end padding are excluded.
+        let input_4 = Tensor::zeros(&[1, 1, 4]);
+        let result = max_pool(input_4.view(), &[2], &[3], [1, 1].into(), None, true).unwrap();
+        assert_eq!(result.shape(), &[1, 1, 2]);
+
+        // `ceil_mode` has no effect with "same" padding.
+        let result = max_pool(input.view(), &[2], &[2], Padding::Same, None, true).unwrap();
+        assert_eq!(result.shape(), &[1, 1, 3]);
+
+        // Dilations
+        let result = max_pool(input.view(), &[2], &[1], [0, 0].into(), Some(&[2]), false).unwrap();
+        expect_equal(&result, &Tensor::from_data(&[1, 1, 3], vec![2., 5., 4.]))?;
+
+        let result = average_pool(
+            input.view(),
+            &[2],
+            &[1],
+            [0, 0].into(),
+            Some(&[2]), /* dilations */
+            false,      /* ceil_mode */
+            false,      /* count_include_pad */
+        )
+        .unwrap();
+        expect_equal(&result, &Tensor::from_data(&[1, 1, 3], vec![1.5, 4., 3.]))?;
+
+        let input = Tensor::from_data(&[1, 1, 4, 4], (0..16).map(|x| x as f32).collect());
+        let result = max_pool(
+            input.view(),
+            &[2, 2],
+            &[1, 1],
+            [0, 0, 0, 0].into(),
+            Some(&[2, 3]),
+            false,
+        )
+        .unwrap();
+        expect_equal(&result, &Tensor::from_data(&[1, 1, 2, 1], vec![11., 15.]))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_pool_with_indices() -> Result<(), Box<dyn Error>> {
+        struct Case {
+            input: Tensor,
+            kernel_size: Vec<usize>,
+            strides: Vec<usize>,
+            padding: Padding,
+            expected: Tensor,
+            expected_indices: Tensor<i64>,
+        }
+
+        let cases = [
+            // Indices are offsets into the flattened input, including the
+            // batch and channel dims.
+            Case {
+                input: Tensor::from_data(
+                    &[2, 2, 2, 2],
+                    vec![
+                        1., 4., 3., 2., // N=0, C=0
+                        5., 6., 8., 7., // N=0, C=1
+                        0., 0., 0., 0., // N=1, C=0
+                        1., 2., 3., 9., // N=1, C=1
+                    ],
+                ),
+                kernel_size: vec![2, 2],
+                strides: vec![2, 2],
+                padding: [0, 0, 0, 0].into(),
+                expected: Tensor::from_data(&[2, 2, 1, 1], vec![4., 8., 0., 9.]),
+                // Ties select the first maximum.
+                expected_indices: Tensor::from_data(&[2, 2, 1, 1], vec![1, 6, 8, 15]),
+            },
+            // Padding
+            Case {
+                input: Tensor::from_data(&[1, 1, 3], vec![1., 3., 2.]),
+                kernel_size: vec![2],
+                strides: vec![2],
+                padding: [1, 1].into(),
+                expected: Tensor::from_data(&[1, 1, 2], vec![1., 3.]),
+                expected_indices: Tensor::from_data(&[1, 1, 2], vec![0, 1]),
+            },
+        ];
+
+        for Case {
+            input,
+            kernel_size,
+            strides,
+            padding,
+            expected,
+            expected_indices,
+        } in cases
+        {
+            let (result, indices) = max_pool_with_indices(
+                input.view(),
+                &kernel_size,
+                &strides,
+                padding.clone(),
+                None,
+                false,
+            )
+            .unwrap();
+            expect_equal(&result, &expected)?;
+            assert_eq!(indices, expected_indices);
+
+            // Values should match `max_pool`.
+            let result = max_pool(input.view(), &kernel_size, &strides, padding, None, false)?;
+            expect_equal(&result, &expected)?;
+        }
+
+        // The operator returns indices as an `Int64` tensor.
+        let op = MaxPool {
+            kernel_size: vec![2],
+            padding: [0, 0].into(),
+            strides: vec![2],
+            dilations: vec![1],
+            ceil_mode: false,
+            return_indices: true,
+        };
+        let input = Tensor::from_data(&[1, 1, 2], vec![1., 3.]);
+        let outputs = op.run((&input).into())?;
+        assert_eq!(
+            outputs[1],
+            Output::from(Tensor::from_data(&[1, 1, 1], vec![1i64]))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lp_pool() -> Result<(), Box<dyn Error>> {
+        let input = Tensor::from_data(&[1, 1, 4], vec![-3., 4., 1., 0.]);
+
+        let result = lp_pool(input.view(), &[2], &[2], [0, 0].into(), None, false, 2).unwrap();
+        expect_eq_1e4(&result, &Tensor::from_data(&[1, 1, 2], vec![5., 1.]))?;
+
+        let result = lp_pool(input.view(), &[2], &[2], [0, 0].into(), None, false, 1).unwrap();
+        expect_eq_1e4(&result, &Tensor::from_data(&[1, 1, 2], vec![7., 1.]))?;
+
+        // Padding elements are treated as zeros.
+        let result = lp_pool(input.view(), &[2], &[2], [1, 1].into(), None, false, 2).unwrap();
+        expect_eq_1e4(
+            &result,
+            &Tensor::from_data(&[1, 1, 3], vec![3., 17f32.sqrt(), 0.]),
+        )?;
+
+        let result = lp_pool(input.view(), &[2], &[2], [0, 0].into(), None, false, 0);
+        assert_eq!(result.err(), Some(OpError::InvalidValue("p must be > 0")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_global_max_and_lp_pool() -> Result<(), Box<dyn Error>> {
+        let input = Tensor::from_data(&[1, 2, 2, 2], vec![1., 4., 3., 2., -5., -6., -8., -7.]);
+
+        let result = global_max_pool(input.view()).unwrap();
+        expect_equal(&result, &Tensor::from_data(&[1, 2, 1, 1], vec![4., -5.]))?;
+
+        let result = global_lp_pool(input.view(), 1).unwrap();
+        expect_eq_1e4(&result, &Tensor::from_data(&[1, 2, 1, 1], vec![10., 26.]))?;
+
+        // 1D and 3D inputs
+        let input = Tensor::from_data(&[1, 1, 2], vec![3., 4.]);
+        let result = global_lp_pool(input.view(), 2).unwrap();
+        expect_eq_1e4(&result, &Tensor::from_data(&[1, 1, 1], vec![5.]))?;
+
+        let input = Tensor::from_data(&[1, 1, 2, 1, 2], vec![3., 4., 1., 2.]);
+        let result = global_max_pool(input.view()).unwrap();
+        expect_equal(&result, &Tensor::from_data(&[1, 1, 1, 1, 1], vec![4.]))?;
+
+        let input = Tensor::<f32>::zeros(&[2, 3]);
+        let result = global_max_pool(input.view());
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "Input must have at least 3 dims (NC + spatial dims)"
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pool_invalid() {
+        let input = Tensor::zeros(&[1, 1, 4, 4]);
+
+        let result = max_pool(
+            input.view(),
+            &[2, 2, 2],
+            &[1, 1, 1],
+            [0; 6].into(),
+            None,
+            false,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "Input must have 2 more dims than kernel (NC + spatial dims)"
+            ))
+        );
+
+        let result = max_pool(input.view(), &[2, 2], &[1], [0; 4].into(), None, false);
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("Wrong number of stride values"))
+        );
+    }
+
+    #[test]
+    fn test_calc_output_size_and_padding_nd() {
+        // Fixed padding
+        assert_eq!(
+            calc_output_size_and_padding_nd(
+                &[5, 6, 7],
+                &[3, 3, 3],
+                &[1, 2, 1],
+                [1, 0, 1, 1, 0, 1].into(),
+                Some(&[1, 1, 2]),
+            ),
+            Ok((vec![5, 2, 5], vec![1, 0, 1, 1, 0, 1]))
+        );
+
+        // `Same` padding, uneven
+        assert_eq!(
+            calc_output_size_and_padding_nd(&[4, 20], &[2, 3], &[1, 1], Padding::Same, None),
+            Ok((vec![4, 20], vec![0, 1, 1, 1]))
+        );
+
+        // Incorrect padding length
+        assert_eq!(
+            calc_output_size_and_padding_nd(
+                &[5, 5, 5],
+                &[3, 3, 3],
+                &[1, 1, 1],
+                [0; 4].into(),
+                None
+            ),
+            Err(OpError::InvalidValue("Wrong number of padding values"))
+        );
+    }
+
     #[test]
     fn test_calc_output_size_and_padding() {
         struct Case {
@@ -8,7 +8,8 @@ use rten_tensor::{DynIndices, NdTensor, NdTensorView, SliceItem, Tensor, TensorV
 use crate::number::Identities;
 use crate::ops::layout::squeeze_in_place;
 use crate::ops::{
-    resolve_axes, resolve_axis, Input, InputList, IntoOpResult, OpError, Operator, Output,
+    input_elementwise_flops, resolve_axes, resolve_axis, same_shape_as_input, Input, InputList,
+    IntoOpResult, MetaInputList, MetaOutput, OpError, Operator, Output,
 };
 use crate::slice_reductions::slice_sum;
 
@@ -79,6 +80,14 @@ impl Operator for ArgMax {
         let input = inputs.require_as::<f32>(0)?;
         arg_max(input, self.axis, self.keep_dims).into_op_result()
     }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        input_elementwise_flops(input_shapes, 1)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        infer_reduce_shape(inputs, Some(&[self.axis as i32]), self.keep_dims)
+    }
 }
 
 /// Return the index of the minimum value along a given axis.
@@ -110,6 +119,14 @@ impl Operator for ArgMin {
         let input = inputs.require_as::<f32>(0)?;
         arg_min(input, self.axis, self.keep_dims).into_op_result()
     }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        input_elementwise_flops(input_shapes, 1)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        infer_reduce_shape(inputs, Some(&[self.axis as i32]), self.keep_dims)
+    }
 }
 
 pub fn cum_sum<T: Copy + Default + Identities + std::ops::AddAssign>(
@@ -148,8 +165,17 @@ impl Operator for CumSum {
         match input {
             Input::IntTensor(input) => cum_sum(input, axis as isize).into_op_result(),
             Input::FloatTensor(input) => cum_sum(input, axis as isize).into_op_result(),
+            _ => Err(OpError::IncorrectInputType),
         }
     }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        input_elementwise_flops(input_shapes, 1)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(same_shape_as_input(inputs))
+    }
 }
 
 /// Return the indices of nonzero elements in `input` as a `(dim, index)` tensor.
@@ -189,8 +215,13 @@ impl Operator for NonZero {
         match input {
             Input::IntTensor(input) => nonzero(input).into_op_result(),
             Input::FloatTensor(input) => nonzero(input).into_op_result(),
+            _ => Err(OpError::IncorrectInputType),
         }
     }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        input_elementwise_flops(input_shapes, 1)
+    }
 }
 
 /// Trait for reducing a subset of elements from a tensor to a single value.
@@ -209,6 +240,44 @@ trait Reducer<T> {
     }
 }
 
+/// Compute the output shape of a reduction over `axes`.
+fn reduced_shape(
+    shape: &[usize],
+    axes: Option<&[i32]>,
+    keep_dims: bool,
+) -> Result<Vec<usize>, OpError> {
+    let resolved_axes = match axes {
+        Some(axes) if !axes.is_empty() => resolve_axes(shape.len(), axes.iter())?,
+        _ => (0..shape.len()).collect(),
+    };
+    Ok(shape
+        .iter()
+        .enumerate()
+        .filter_map(
+            |(dim, &size)| match (resolved_axes.contains(&dim), keep_dims) {
+                (false, _) => Some(size),
+                (true, true) => Some(1),
+                (true, false) => None,
+            },
+        )
+        .collect())
+}
+
+/// Infer the output shape of a reduction over `axes` of the first input.
+///
+/// This implements [Operator::infer_shapes] for reduction operators.
+fn infer_reduce_shape(
+    inputs: &MetaInputList,
+    axes: Option<&[i32]>,
+    keep_dims: bool,
+) -> Option<Result<Vec<MetaOutput>, OpError>> {
+    let output_shape = || {
+        let shape = reduced_shape(inputs.require_shape(0)?, axes, keep_dims)?;
+        Ok(vec![shape.into()])
+    };
+    Some(output_shape())
+}
+
 fn reduce<T: Copy, R: Reducer<T>>(
     input: TensorView<T>,
     axes: Option<&[i32]>,
@@ -354,6 +423,14 @@ impl Operator for ReduceMean {
         )
         .into_op_result()
     }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        input_elementwise_flops(input_shapes, 1)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        infer_reduce_shape(inputs, self.axes.as_deref(), self.keep_dims)
+    }
 }
 
 pub fn reduce_l2(
@@ -392,6 +469,14 @@ impl Operator for ReduceL2 {
         )
         .into_op_result()
     }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        input_elementwise_flops(input_shapes, 2)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        infer_reduce_shape(inputs, self.axes.as_deref(), self.keep_dims)
+    }
 }
 
 macro_rules! dispatch_reduce_op {
@@ -403,6 +488,7 @@ macro_rules! dispatch_reduce_op {
             Input::IntTensor(input) => {
                 $reduce_op(input, $axes.as_ref().map(|axis| &axis[..]), $keep_dims).into_op_result()
             }
+            _ => Err(OpError::IncorrectInputType),
         }
     };
 }
@@ -484,6 +570,14 @@ impl Operator for ReduceMin {
         let input = inputs.require(0)?;
         dispatch_reduce_op!(input, reduce_min, self.axes, self.keep_dims)
     }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        input_elementwise_flops(input_shapes, 1)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        infer_reduce_shape(inputs, self.axes.as_deref(), self.keep_dims)
+    }
 }
 
 pub fn reduce_max<T: Copy + PartialOrd>(
@@ -509,6 +603,14 @@ impl Operator for ReduceMax {
         let input = inputs.require(0)?;
         dispatch_reduce_op!(input, reduce_max, self.axes, self.keep_dims)
     }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        input_elementwise_flops(input_shapes, 1)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        infer_reduce_shape(inputs, self.axes.as_deref(), self.keep_dims)
+    }
 }
 
 pub fn reduce_prod<T: Copy + std::iter::Product>(
@@ -540,6 +642,14 @@ impl Operator for ReduceProd {
         let input = inputs.require(0)?;
         dispatch_reduce_op!(input, reduce_prod, self.axes, self.keep_dims)
     }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        input_elementwise_flops(input_shapes, 1)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        infer_reduce_shape(inputs, self.axes.as_deref(), self.keep_dims)
+    }
 }
 
 pub fn reduce_sum<T: Copy + std::iter::Sum>(
@@ -571,6 +681,14 @@ impl Operator for ReduceSum {
         let input = inputs.require(0)?;
         dispatch_reduce_op!(input, reduce_sum, self.axes, self.keep_dims)
     }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        input_elementwise_flops(input_shapes, 1)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        infer_reduce_shape(inputs, self.axes.as_deref(), self.keep_dims)
+    }
 }
 
 pub fn reduce_sum_square<T: Copy + std::ops::Mul<T, Output = T> + std::iter::Sum>(
@@ -602,6 +720,14 @@ impl Operator for ReduceSumSquare {
         let input = inputs.require(0)?;
         dispatch_reduce_op!(input, reduce_sum_square, self.axes, self.keep_dims)
     }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        input_elementwise_flops(input_shapes, 2)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        infer_reduce_shape(inputs, self.axes.as_deref(), self.keep_dims)
+    }
 }
 
 pub fn topk<T: Copy + Default + PartialOrd>(
@@ -706,8 +832,31 @@ impl Operator for TopK {
                 let (values, indices) = topk(values, k, self.axis, self.largest, self.sorted)?;
                 Ok([values.into(), indices.into()].into_iter().collect())
             }
+            _ => Err(OpError::IncorrectInputType),
         }
     }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        input_elementwise_flops(input_shapes, 1)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| {
+            let shape = inputs.require_shape(0)?;
+            let k = inputs.require_value_as::<i32>(1)?;
+            let k = k
+                .item()
+                .copied()
+                .ok_or(OpError::InvalidValue("k must be a scalar"))?;
+            if k < 0 {
+                return Err(OpError::InvalidValue("k must be positive"));
+            }
+            let axis = resolve_axis(shape.len(), self.axis.unwrap_or(-1))?;
+            let mut out_shape = shape.to_vec();
+            out_shape[axis] = k as usize;
+            Ok(vec![out_shape.clone().into(), out_shape.into()])
+        })())
+    }
 }
 
 #[cfg(test)]
@@ -4,7 +4,10 @@ use rayon::prelude::*;
 use rten_tensor::prelude::*;
 use rten_tensor::{NdTensor, NdTensorView, NdTensorViewMut, Tensor, TensorView};
 
-use crate::ops::{Input, InputList, IntoOpResult, OpError, Operator, Output};
+use crate::ops::{
+    resolve_axes, Input, InputList, IntoOpResult, MetaInputList, MetaOutput, OpError, Operator,
+    Output,
+};
 use crate::{check_dims, static_dims};
 
 /// Specifies an output size for a resize operation.
@@ -25,6 +28,9 @@ pub enum ResizeTarget<'a> {
 ///   `coordinate_transformation_mode` attribute.
 /// - `length_original` is the size of the axis in the input
 /// - `length_resized` is the size of the axis in the output
+/// - `roi` is the `[start, end]` region of interest along the axis, as a
+///   fraction of the input size. This is only used by
+///   [`CoordTransformMode::TfCropAndResize`].
 ///
 /// See https://github.com/onnx/onnx/blob/v1.15.0/docs/Operators.md#resize
 /// for the formulae for different transform modes.
@@ -39,13 +45,35 @@ fn input_coord(
     mode: CoordTransformMode,
     length_original: usize,
     length_resized: usize,
+    roi: [f32; 2],
 ) -> f32 {
     type Ctm = CoordTransformMode;
     match mode {
         Ctm::HalfPixel => scale * (dest_coord as f32 + 0.5) - 0.5,
+        Ctm::PytorchHalfPixel => {
+            if length_resized > 1 {
+                scale * (dest_coord as f32 + 0.5) - 0.5
+            } else {
+                0.
+            }
+        }
         Ctm::Asymmetric => scale * dest_coord as f32,
         Ctm::AlignCorners => {
-            dest_coord as f32 * (length_original - 1) as f32 / (length_resized - 1) as f32
+            if length_resized > 1 {
+                dest_coord as f32 * (length_original - 1) as f32 / (length_resized - 1) as f32
+            } else {
+                0.
+            }
+        }
+        Ctm::TfCropAndResize => {
+            let [start, end] = roi;
+            let max_coord = (length_original - 1) as f32;
+            if length_resized > 1 {
+                start * max_coord
+                    + dest_coord as f32 * (end - start) * max_coord / (length_resized - 1) as f32
+            } else {
+                0.5 * (start + end) * max_coord
+            }
         }
     }
 }
@@ -62,13 +90,49 @@ pub enum NearestMode {
     RoundPreferFloor,
 }
 
+impl NearestMode {
+    /// Round a non-negative fractional coordinate to an integer coordinate.
+    fn round(self, coord: f32) -> usize {
+        match self {
+            NearestMode::Ceil => coord.ceil() as usize,
+            NearestMode::Floor => coord as usize,
+
+            // `f32::round` has round-away-from-zero behavior. For `RoundPreferCeil`
+            // and `RoundPreferFloor` we need to always round up or down.
+            NearestMode::RoundPreferCeil => {
+                if coord.fract() == 0.5 {
+                    coord.ceil() as usize
+                } else {
+                    coord.round() as usize
+                }
+            }
+            NearestMode::RoundPreferFloor => {
+                if coord.fract() == 0.5 {
+                    coord.floor() as usize
+                } else {
+                    coord.round() as usize
+                }
+            }
+        }
+    }
+}
+
 /// Specifies how resizing maps output coordinates to input coordinates.
-#[derive(Copy, Clone, Debug, Default)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
 pub enum CoordTransformMode {
     #[default]
     HalfPixel,
     Asymmetric,
     AlignCorners,
+
+    /// Same as `HalfPixel`, except that output axes of size 1 map to input
+    /// coordinate 0.
+    PytorchHalfPixel,
+
+    /// Sample from the region of interest given by the `roi` input. Output
+    /// coordinates which map to points outside the input are filled with
+    /// [`ResizeOptions::extrapolation_value`].
+    TfCropAndResize,
 }
 
 const CHAN_GROUP_SIZE: usize = 4;
@@ -79,48 +143,28 @@ fn lerp(a: f32, b: f32, weight: f32) -> f32 {
 }
 
 /// Resize a group of channels in a CHW tensor using nearest neighbor resizing.
+///
+/// `inv_scales` are the factors which map output Y and X coordinates to input
+/// coordinates.
 fn nearest_resize(
     input: NdTensorView<f32, 3>,
     mut output: NdTensorViewMut<f32, 3>,
+    inv_scales: [f32; 2],
     mode: NearestMode,
     coord_mode: CoordTransformMode,
 ) {
     let [chans, rows, cols] = output.shape();
     let [_, in_rows, in_cols] = input.shape();
-
-    // Scale factors to map output coords to input coords.
-    let inv_scale_y = in_rows as f32 / rows as f32;
-    let inv_scale_x = in_cols as f32 / cols as f32;
-
-    let round_coord = |coord: f32| match mode {
-        NearestMode::Ceil => coord.ceil() as usize,
-        NearestMode::Floor => coord as usize,
-
-        // `f32::round` has round-away-from-zero behavior. For `RoundPreferCeil`
-        // and `RoundPreferFloor` we need to always round up or down.
-        NearestMode::RoundPreferCeil => {
-            if coord.fract() == 0.5 {
-                coord.ceil() as usize
-            } else {
-                coord.round() as usize
-            }
-        }
-        NearestMode::RoundPreferFloor => {
-            if coord.fract() == 0.5 {
-                coord.floor() as usize
-            } else {
-                coord.round() as usize
-            }
-        }
-    };
+    let [inv_scale_y, inv_scale_x] = inv_scales;
 
     for y in 0..rows {
-        let in_y = round_coord(
-            input_coord(y, inv_scale_y, coord_mode, in_rows, rows).clamp(0., in_rows as f32 - 1.),
+        let in_y = mode.round(
+            input_coord(y, inv_scale_y, coord_mode, in_rows, rows, [0., 1.])
+                .clamp(0., in_rows as f32 - 1.),
         );
         for x in 0..cols {
-            let in_x = round_coord(
-                input_coord(x, inv_scale_x, coord_mode, in_cols, cols)
+            let in_x = mode.round(
+                input_coord(x, inv_scale_x, coord_mode, in_cols, cols, [0., 1.])
                     .clamp(0., in_cols as f32 - 1.),
             );
 
@@ -132,27 +176,27 @@ fn nearest_resize(
 }
 
 /// Resize a group of channels in a CHW tensor using bilinear resizing.
+///
+/// See [nearest_resize] for a description of `inv_scales`.
 fn bilinear_resize(
     input: NdTensorView<f32, 3>,
     mut output: NdTensorViewMut<f32, 3>,
+    inv_scales: [f32; 2],
     coord_mode: CoordTransformMode,
 ) {
     let [chans, rows, cols] = output.shape();
     let [_, in_rows, in_cols] = input.shape();
-
-    // Scale factors to map output coords to input coords.
-    let inv_scale_y = in_rows as f32 / rows as f32;
-    let inv_scale_x = in_cols as f32 / cols as f32;
+    let [inv_scale_y, inv_scale_x] = inv_scales;
 
     for y in 0..rows {
-        let in_y =
-            input_coord(y, inv_scale_y, coord_mode, in_rows, rows).clamp(0., in_rows as f32 - 1.);
+        let in_y = input_coord(y, inv_scale_y, coord_mode, in_rows, rows, [0., 1.])
+            .clamp(0., in_rows as f32 - 1.);
         let in_y1 = in_y as usize;
         let in_y2 = (in_y1 + 1).min(in_rows - 1);
         let weight_y = in_y - (in_y1 as f32);
 
         for x in 0..cols {
-            let in_x = input_coord(x, inv_scale_x, coord_mode, in_cols, cols)
+            let in_x = input_coord(x, inv_scale_x, coord_mode, in_cols, cols, [0., 1.])
                 .clamp(0., in_cols as f32 - 1.);
             let in_x1 = in_x as usize;
             let in_x2 = (in_x1 + 1).min(in_cols - 1);
@@ -205,27 +249,38 @@ pub fn resize_image(input: TensorView, size: [usize; 2]) -> Result<Tensor, OpErr
     resize(
         input,
         ResizeTarget::Sizes(out_shape.as_slice().into()),
-        ResizeMode::Linear,
-        CoordTransformMode::default(),
-        NearestMode::default(),
+        None,
+        &ResizeOptions {
+            mode: ResizeMode::Linear,
+            ..ResizeOptions::default()
+        },
     )
 }
 
-pub fn resize(
-    input: TensorView,
+/// Compute the output shape of a [resize] of an input with shape `shape`.
+///
+/// If `roi` is specified and the target is given as scales, the scale factors
+/// are applied to the size of the region of interest rather than the whole
+/// input.
+fn resized_shape(
+    shape: &[usize],
     target: ResizeTarget,
-    mode: ResizeMode,
-    coord_mode: CoordTransformMode,
-    nearest_mode: NearestMode,
-) -> Result<Tensor, OpError> {
+    roi: Option<&[f32]>,
+) -> Result<Vec<usize>, OpError> {
     let sizes: NdTensor<i32, 1> = match target {
-        ResizeTarget::Scales(scales) => zip(input.shape().iter(), scales.iter())
-            .map(|(&in_size, scale)| ((in_size as f32) * scale).floor() as i32)
+        ResizeTarget::Scales(scales) => zip(shape.iter(), scales.iter())
+            .enumerate()
+            .map(|(dim, (&in_size, scale))| {
+                let roi_size = roi
+                    .map(|roi| roi[shape.len() + dim] - roi[dim])
+                    .unwrap_or(1.);
+                ((in_size as f32) * roi_size * scale).floor() as i32
+            })
             .collect(),
         ResizeTarget::Sizes(sizes) => sizes.to_tensor(),
     };
 
-    if sizes.len() != input.ndim() {
+    if sizes.len() != shape.len() {
         return Err(OpError::IncompatibleInputShapes(
             "scales/sizes length should equal input rank",
         ));
@@ -233,27 +288,160 @@ pub fn resize(
     if sizes.iter().any(|size| *size < 0) {
         return Err(OpError::InvalidValue("scales/sizes must be positive"));
     }
+    Ok(sizes.iter().map(|size| *size as usize).collect())
+}
 
-    // The current implementation only supports NCHW tensors with scale factors
-    // other than 1.0 for the H and W dims.
-    let [batch, _chans, _height, _width] = check_dims!(input, 4, "NCHW");
-    let sizes_valid = zip(0..input.ndim(), input.shape().iter()).all(|(dim, &in_size)| {
-        dim == input.ndim() - 1 || dim == input.ndim() - 2 || sizes[[dim]] == in_size as i32
-    });
-    if !sizes_valid {
-        return Err(OpError::UnsupportedValue(
-            "only height and width dimensions can be resized",
-        ));
+/// Evaluate the cubic convolution kernel with coefficient `a` at distance `x`.
+///
+/// See https://en.wikipedia.org/wiki/Bicubic_interpolation#Bicubic_convolution_algorithm.
+fn cubic_weight(x: f32, a: f32) -> f32 {
+    let x = x.abs();
+    if x <= 1. {
+        ((a + 2.) * x - (a + 3.)) * x * x + 1.
+    } else if x < 2. {
+        ((a * x - 5. * a) * x + 8. * a) * x - 4. * a
+    } else {
+        0.
     }
+}
+
+/// Input positions and weights that contribute to one output position along
+/// an axis, or `None` if the output should be set to the extrapolation value.
+type Taps = Option<Vec<(usize, f32)>>;
+
+/// Compute the interpolation taps for each output position when resizing an
+/// axis from `in_len` to `out_len` elements with scale factor `scale`.
+fn axis_taps(
+    in_len: usize,
+    out_len: usize,
+    scale: f32,
+    roi: [f32; 2],
+    opts: &ResizeOptions,
+) -> Vec<Taps> {
+    let inv_scale = 1. / scale;
+    let max_coord = (in_len - 1) as f32;
+
+    // When antialiasing, the interpolation filter is stretched by the inverse
+    // of the scale factor when downsampling, so that every input element
+    // contributes to the output.
+    let filter_scale = if opts.antialias { scale.min(1.) } else { 1. };
+    let radius = match opts.mode {
+        ResizeMode::Nearest | ResizeMode::Linear => 1.,
+        ResizeMode::Cubic => 2.,
+    };
+    let start = (-radius / filter_scale).floor() as isize + 1;
+    let end = 2 - start;
+
+    (0..out_len)
+        .map(|out_coord| {
+            let coord = input_coord(out_coord, inv_scale, opts.coord_mode, in_len, out_len, roi);
+            if opts.coord_mode == CoordTransformMode::TfCropAndResize
+                && !(0. ..=max_coord).contains(&coord)
+            {
+                return None;
+            }
+
+            if let ResizeMode::Nearest = opts.mode {
+                let pos = opts.nearest_mode.round(coord.clamp(0., max_coord));
+                return Some(vec![(pos, 1.)]);
+            }
+
+            let coord_floor = coord.floor();
+            let frac = coord - coord_floor;
+            let mut taps: Vec<(usize, f32)> = (start..end)
+                .filter_map(|offset| {
+                    let pos = coord_floor as isize + offset;
+                    let outside = pos < 0 || pos >= in_len as isize;
+                    if outside && opts.exclude_outside {
+                        return None;
+                    }
+                    let dist = (offset as f32 - frac) * filter_scale;
+                    let weight = match opts.mode {
+                        ResizeMode::Cubic => cubic_weight(dist, opts.cubic_coeff_a),
+                        _ => (1. - dist.abs()).max(0.),
+                    };
+                    Some((pos.clamp(0, in_len as isize - 1) as usize, weight))
+                })
+                .collect();
+
+            let weight_sum: f32 = taps.iter().map(|(_, weight)| weight).sum();
+            if weight_sum != 0. {
+                for (_, weight) in taps.iter_mut() {
+                    *weight /= weight_sum;
+                }
+            }
 
-    let sizes_usize: Vec<_> = sizes.iter().map(|size| *size as usize).collect();
-    let mut output = Tensor::zeros(&sizes_usize);
+            Some(taps)
+        })
+        .collect()
+}
 
+/// Resize a single axis of `input` to have `out_len` elements.
+fn resize_axis(
+    input: TensorView,
+    axis: usize,
+    out_len: usize,
+    scale: f32,
+    roi: [f32; 2],
+    opts: &ResizeOptions,
+) -> Result<Tensor, OpError> {
+    let mut out_shape = input.shape().to_vec();
+    out_shape[axis] = out_len;
+    let mut output = Tensor::zeros(&out_shape);
     if output.is_empty() {
         return Ok(output);
     }
 
-    for n in 0..batch {
+    let in_len = input.size(axis);
+    if in_len == 0 {
+        return Err(OpError::InvalidValue("cannot resize an empty axis"));
+    }
+
+    let input = input.to_contiguous();
+    let in_data = input.data().unwrap();
+    let inner: usize = out_shape[axis + 1..].iter().product();
+    let taps = axis_taps(in_len, out_len, scale, roi, opts);
+
+    output
+        .data_mut()
+        .unwrap()
+        .par_chunks_mut(out_len * inner)
+        .zip(in_data.par_chunks(in_len * inner))
+        .for_each(|(out_chunk, in_chunk)| {
+            for (out_row, taps) in zip(out_chunk.chunks_mut(inner), taps.iter()) {
+                let Some(taps) = taps else {
+                    out_row.fill(opts.extrapolation_value);
+                    continue;
+                };
+                for &(pos, weight) in taps {
+                    let in_row = &in_chunk[pos * inner..(pos + 1) * inner];
+                    for (out_el, in_el) in zip(out_row.iter_mut(), in_row) {
+                        *out_el += weight * in_el;
+                    }
+                }
+            }
+        });
+
+    Ok(output)
+}
+
+/// Resize the H and W dims of an NCHW tensor using the optimized nearest and
+/// bilinear kernels.
+fn resize_image_nchw(
+    input: TensorView,
+    out_shape: &[usize],
+    inv_scales: [f32; 2],
+    mode: ResizeMode,
+    coord_mode: CoordTransformMode,
+    nearest_mode: NearestMode,
+) -> Tensor {
+    let mut output = Tensor::zeros(out_shape);
+
+    if output.is_empty() {
+        return output;
+    }
+
+    for n in 0..input.size(0) {
         let in_image = input.slice::<3, _>([n]);
         let mut out_image = output.slice_mut::<3, _>([n]);
 
@@ -267,17 +455,99 @@ pub fn resize(
                         nearest_resize(
                             in_chans.nd_view(),
                             out_chans.nd_view_mut(),
+                            inv_scales,
                             nearest_mode,
                             coord_mode,
                         );
                     }
                     ResizeMode::Linear => {
-                        bilinear_resize(in_chans.nd_view(), out_chans.nd_view_mut(), coord_mode);
+                        bilinear_resize(
+                            in_chans.nd_view(),
+                            out_chans.nd_view_mut(),
+                            inv_scales,
+                            coord_mode,
+                        );
                     }
+                    ResizeMode::Cubic => unreachable!("cubic mode is not supported"),
                 };
             });
     }
 
+    output
+}
+
+/// Resize a tensor to a given size or by given scale factors.
+///
+/// `roi` specifies the region of interest as `[starts..., ends...]`
+/// fractions of the input size for each dim. It is only used with
+/// [`CoordTransformMode::TfCropAndResize`], where it is required.
+pub fn resize(
+    input: TensorView,
+    target: ResizeTarget,
+    roi: Option<NdTensorView<f32, 1>>,
+    opts: &ResizeOptions,
+) -> Result<Tensor, OpError> {
+    let roi = if opts.coord_mode == CoordTransformMode::TfCropAndResize {
+        let roi = roi.ok_or(OpError::MissingInputs)?.to_vec();
+        if roi.len() != input.ndim() * 2 {
+            return Err(OpError::IncompatibleInputShapes(
+                "roi length should be twice the input rank",
+            ));
+        }
+        Some(roi)
+    } else {
+        None
+    };
+    let scales = match &target {
+        ResizeTarget::Scales(scales) => Some(scales.to_vec()),
+        ResizeTarget::Sizes(_) => None,
+    };
+    let sizes = resized_shape(input.shape(), target, roi.as_deref())?;
+
+    // Scale factors used when mapping output coordinates to input coordinates.
+    // When sizes are given, these are the ratios of output to input size.
+    let scales = scales.unwrap_or_else(|| {
+        zip(&sizes, input.shape())
+            .map(|(&out_size, &in_size)| out_size as f32 / in_size as f32)
+            .collect()
+    });
+
+    // Use the optimized kernels for the common case of resizing the spatial
+    // dims of an NCHW image.
+    let resizes_hw_only = input.ndim() == 4 && sizes[..2] == input.shape()[..2];
+    let simple_mode = matches!(opts.mode, ResizeMode::Nearest | ResizeMode::Linear);
+    if resizes_hw_only && simple_mode && !opts.antialias && roi.is_none() {
+        return Ok(resize_image_nchw(
+            input,
+            &sizes,
+            [1. / scales[2], 1. / scales[3]],
+            opts.mode,
+            opts.coord_mode,
+            opts.nearest_mode,
+        ));
+    }
+
+    // Otherwise resize each axis in turn. All supported interpolation modes
+    // are separable, so this gives the same result as resizing all axes at
+    // once.
+    let mut output = input.to_tensor();
+    for (axis, &out_len) in sizes.iter().enumerate() {
+        let axis_roi = roi
+            .as_ref()
+            .map(|roi| [roi[axis], roi[input.ndim() + axis]]);
+        if out_len == output.size(axis) && scales[axis] == 1. && axis_roi.is_none() {
+            continue;
+        }
+        output = resize_axis(
+            output.view(),
+            axis,
+            out_len,
+            scales[axis],
+            axis_roi.unwrap_or([0., 1.]),
+            opts,
+        )?;
+    }
+
     Ok(output)
 }
 
@@ -286,6 +556,44 @@ pub enum ResizeMode {
     #[default]
     Nearest,
     Linear,
+    Cubic,
+}
+
+/// Interpolation settings for [resize].
+#[derive(Clone, Copy, Debug)]
+pub struct ResizeOptions {
+    pub mode: ResizeMode,
+    pub coord_mode: CoordTransformMode,
+    pub nearest_mode: NearestMode,
+
+    /// Coefficient used by the cubic interpolation kernel.
+    pub cubic_coeff_a: f32,
+
+    /// Widen the interpolation filter when downsampling with linear or cubic
+    /// interpolation, so that it acts as a low-pass filter.
+    pub antialias: bool,
+
+    /// Give zero weight to input positions outside the tensor and
+    /// renormalize the remaining weights.
+    pub exclude_outside: bool,
+
+    /// Value used for outputs which map to positions outside the input
+    /// when using [`CoordTransformMode::TfCropAndResize`].
+    pub extrapolation_value: f32,
+}
+
+impl Default for ResizeOptions {
+    fn default() -> ResizeOptions {
+        ResizeOptions {
+            mode: ResizeMode::default(),
+            coord_mode: CoordTransformMode::default(),
+            nearest_mode: NearestMode::default(),
+            cubic_coeff_a: -0.75,
+            antialias: false,
+            exclude_outside: false,
+            extrapolation_value: 0.,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -293,14 +601,28 @@ pub struct Resize {
     pub mode: ResizeMode,
     pub coord_mode: CoordTransformMode,
     pub nearest_mode: NearestMode,
+    pub cubic_coeff_a: f32,
+    pub antialias: bool,
+    pub exclude_outside: bool,
+    pub extrapolation_value: f32,
+
+    /// Axes that the `roi`, `scales` and `sizes` inputs apply to. If `None`,
+    /// these inputs specify values for every axis.
+    pub axes: Option<Vec<i32>>,
 }
 
 impl Default for Resize {
     fn default() -> Resize {
+        let opts = ResizeOptions::default();
         Resize {
             mode: ResizeMode::Nearest,
-            coord_mode: CoordTransformMode::default(),
-            nearest_mode: NearestMode::default(),
+            coord_mode: opts.coord_mode,
+            nearest_mode: opts.nearest_mode,
+            cubic_coeff_a: opts.cubic_coeff_a,
+            antialias: opts.antialias,
+            exclude_outside: opts.exclude_outside,
+            extrapolation_value: opts.extrapolation_value,
+            axes: None,
         }
     }
 }
@@ -321,6 +643,91 @@ where
     Ok(tensor)
 }
 
+/// Expand values of the `roi`, `scales` or `sizes` inputs which are specified
+/// for a subset of axes into values for all dims. `fill` provides the values
+/// for dims that are not in `axes`.
+fn expand_to_rank<T: Copy>(
+    values: NdTensorView<T, 1>,
+    axes: &[usize],
+    ndim: usize,
+    fill: impl Fn(usize) -> T,
+) -> Result<NdTensor<T, 1>, OpError> {
+    if values.len() != axes.len() {
+        return Err(OpError::IncompatibleInputShapes(
+            "scales/sizes length should equal number of axes",
+        ));
+    }
+    let mut expanded: Vec<T> = (0..ndim).map(fill).collect();
+    for (&axis, &value) in zip(axes, values.iter()) {
+        expanded[axis] = value;
+    }
+    Ok(expanded.into())
+}
+
+impl Resize {
+    fn options(&self) -> ResizeOptions {
+        ResizeOptions {
+            mode: self.mode,
+            coord_mode: self.coord_mode,
+            nearest_mode: self.nearest_mode,
+            cubic_coeff_a: self.cubic_coeff_a,
+            antialias: self.antialias,
+            exclude_outside: self.exclude_outside,
+            extrapolation_value: self.extrapolation_value,
+        }
+    }
+
+    /// Convert the `roi`, `scales` and `sizes` inputs into values that cover
+    /// every dim of an input with shape `shape`, taking into account the
+    /// `axes` attribute.
+    #[allow(clippy::type_complexity)]
+    fn full_rank_inputs(
+        &self,
+        shape: &[usize],
+        roi: Option<NdTensorView<f32, 1>>,
+        scales: Option<NdTensorView<f32, 1>>,
+        sizes: Option<NdTensorView<i32, 1>>,
+    ) -> Result<
+        (
+            Option<NdTensor<f32, 1>>,
+            Option<NdTensor<f32, 1>>,
+            Option<NdTensor<i32, 1>>,
+        ),
+        OpError,
+    > {
+        let Some(axes) = self.axes.as_ref() else {
+            return Ok((
+                roi.map(|r| r.to_tensor()),
+                scales.map(|s| s.to_tensor()),
+                sizes.map(|s| s.to_tensor()),
+            ));
+        };
+        let ndim = shape.len();
+        let axes = resolve_axes(ndim, axes.iter())?;
+
+        let roi = roi
+            .map(|roi| {
+                if roi.len() != axes.len() * 2 {
+                    return Err(OpError::IncompatibleInputShapes(
+                        "roi length should be twice the number of axes",
+                    ));
+                }
+                let starts = expand_to_rank(roi.slice(..axes.len()), &axes, ndim, |_| 0.)?;
+                let ends = expand_to_rank(roi.slice(axes.len()..), &axes, ndim, |_| 1.)?;
+                Ok(starts.iter().chain(ends.iter()).copied().collect())
+            })
+            .transpose()?;
+        let scales = scales
+            .map(|scales| expand_to_rank(scales, &axes, ndim, |_| 1.))
+            .transpose()?;
+        let sizes = sizes
+            .map(|sizes| expand_to_rank(sizes, &axes, ndim, |dim| shape[dim] as i32))
+            .transpose()?;
+
+        Ok((roi, scales, sizes))
+    }
+}
+
 impl Operator for Resize {
     fn name(&self) -> &str {
         "Resize"
@@ -329,21 +736,76 @@ impl Operator for Resize {
     fn run(&self, inputs: InputList) -> Result<Vec<Output>, OpError> {
         let input = inputs.require_as(0)?;
 
-        // The `roi` input is only used if the `coordinate_transformation_mode`
-        // ONNX attr is `tf_crop_and_resize`, which is not currently supported.
-        let _roi = get_optional_input::<f32>(&inputs, 1)?;
-
+        // The `roi` input is only used in `TfCropAndResize` mode.
+        let roi = if self.coord_mode == CoordTransformMode::TfCropAndResize {
+            get_optional_input(&inputs, 1)?
+                .map(|roi| static_dims!(roi, 1))
+                .transpose()?
+        } else {
+            None
+        };
         let scales = get_optional_input(&inputs, 2)?
             .map(|scales| static_dims!(scales, 1))
-            .transpose()?
-            .map(ResizeTarget::Scales);
+            .transpose()?;
         let sizes = get_optional_input(&inputs, 3)?
             .map(|sizes| static_dims!(sizes, 1))
-            .transpose()?
-            .map(ResizeTarget::Sizes);
+            .transpose()?;
+        let (roi, scales, sizes) = self.full_rank_inputs(input.shape(), roi, scales, sizes)?;
+
+        let scales = scales.as_ref().map(|s| ResizeTarget::Scales(s.view()));
+        let sizes = sizes.as_ref().map(|s| ResizeTarget::Sizes(s.view()));
         let target = scales.or(sizes).ok_or(OpError::MissingInputs)?;
 
-        resize(input, target, self.mode, self.coord_mode, self.nearest_mode).into_op_result()
+        resize(
+            input,
+            target,
+            roi.as_ref().map(|r| r.view()),
+            &self.options(),
+        )
+        .into_op_result()
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| {
+            let shape = inputs.require_shape(0)?;
+
+            // The `roi` input only affects the output shape in
+            // `TfCropAndResize` mode.
+            let roi = if self.coord_mode == CoordTransformMode::TfCropAndResize {
+                inputs
+                    .get_value_as(1)?
+                    .filter(|t| !t.is_empty())
+                    .map(|roi| static_dims!(roi, 1))
+                    .transpose()?
+            } else {
+                None
+            };
+            let scales = inputs
+                .get_value_as(2)?
+                .filter(|t| !t.is_empty())
+                .map(|scales| static_dims!(scales, 1))
+                .transpose()?;
+            let sizes = inputs
+                .get_value_as(3)?
+                .filter(|t| !t.is_empty())
+                .map(|sizes| static_dims!(sizes, 1))
+                .transpose()?;
+            let (roi, scales, sizes) = self.full_rank_inputs(shape, roi, scales, sizes)?;
+
+            let roi = match roi {
+                Some(roi) if roi.len() != shape.len() * 2 => {
+                    return Err(OpError::IncompatibleInputShapes(
+                        "roi length should be twice the input rank",
+                    ));
+                }
+                Some(roi) => Some(roi.to_vec()),
+                None => None,
+            };
+            let scales = scales.as_ref().map(|s| ResizeTarget::Scales(s.view()));
+            let sizes = sizes.as_ref().map(|s| ResizeTarget::Sizes(s.view()));
+            let target = scales.or(sizes).ok_or(OpError::MissingInputs)?;
+            Ok(vec![resized_shape(shape, target, roi.as_deref())?.into()])
+        })())
     }
 }
 
@@ -358,7 +820,7 @@ mod tests {
     use crate::ops::tests::expect_eq_1e4;
     use crate::ops::{
         resize, CoordTransformMode, InputList, NearestMode, OpError, Operator, Resize, ResizeMode,
-        ResizeTarget,
+        ResizeOptions, ResizeTarget,
     };
 
     // Reference values for these tests can be computed with either OpenCV
@@ -440,9 +902,13 @@ mod tests {
             let result = resize(
                 case.image.view(),
                 ResizeTarget::Scales(case.scales.as_slice().into()),
-                ResizeMode::Nearest,
-                CoordTransformMode::HalfPixel,
-                NearestMode::RoundPreferFloor,
+                None,
+                &ResizeOptions {
+                    mode: ResizeMode::Nearest,
+                    coord_mode: CoordTransformMode::HalfPixel,
+                    nearest_mode: NearestMode::RoundPreferFloor,
+                    ..ResizeOptions::default()
+                },
             )
             .unwrap();
 
@@ -505,9 +971,13 @@ mod tests {
             let result = resize(
                 image.view(),
                 ResizeTarget::Scales(scales.into()),
-                ResizeMode::Nearest,
-                CoordTransformMode::Asymmetric,
-                case.mode,
+                None,
+                &ResizeOptions {
+                    mode: ResizeMode::Nearest,
+                    coord_mode: CoordTransformMode::Asymmetric,
+                    nearest_mode: case.mode,
+                    ..ResizeOptions::default()
+                },
             )
             .unwrap();
 
@@ -624,10 +1094,15 @@ mod tests {
             let result = resize(
                 case.image.as_dyn(),
                 ResizeTarget::Scales(case.scales.as_slice().into()),
-                ResizeMode::Linear,
-                case.coord_transform_mode
-                    .unwrap_or(CoordTransformMode::HalfPixel),
-                NearestMode::Floor,
+                None,
+                &ResizeOptions {
+                    mode: ResizeMode::Linear,
+                    coord_mode: case
+                        .coord_transform_mode
+                        .unwrap_or(CoordTransformMode::HalfPixel),
+                    nearest_mode: NearestMode::Floor,
+                    ..ResizeOptions::default()
+                },
             )
             .unwrap();
 
@@ -637,6 +1112,327 @@ mod tests {
         Ok(())
     }
 
+    // Reference values for the cubic, antialias and coordinate mode tests are
+    // taken from the ONNX backend test data.
+    fn onnx_test_image() -> Tensor {
+        Tensor::arange(1., 17., None).into_shape([1, 1, 4, 4].as_slice())
+    }
+
+    #[test]
+    fn test_resize_cubic() -> Result<(), Box<dyn Error>> {
+        struct Case {
+            scales: Vec<f32>,
+            opts: ResizeOptions,
+            expected: Tensor,
+        }
+
+        let cubic = ResizeOptions {
+            mode: ResizeMode::Cubic,
+            ..ResizeOptions::default()
+        };
+
+        let cases = [
+            // Upsample
+            Case {
+                scales: vec![1., 1., 2., 2.],
+                opts: cubic,
+                expected: Tensor::from([
+                    [
+                        0.4727, 0.7695, 1.2461, 1.875, 2.2813, 2.9102, 3.3867, 3.6836,
+                    ],
+                    [
+                        1.6602, 1.957, 2.4336, 3.0625, 3.4688, 4.0977, 4.5742, 4.8711,
+                    ],
+                    [
+                        3.5664, 3.8633, 4.3398, 4.9688, 5.375, 6.0039, 6.4805, 6.7773,
+                    ],
+                    [6.082, 6.3789, 6.8555, 7.4844, 7.8906, 8.5195, 8.9961, 9.293],
+                    [
+                        7.707, 8.0039, 8.4805, 9.1094, 9.5156, 10.1445, 10.6211, 10.918,
+                    ],
+                    [
+                        10.2227, 10.5195, 10.9961, 11.625, 12.0313, 12.6602, 13.1367, 13.4336,
+                    ],
+                    [
+                        12.1289, 12.4258, 12.9023, 13.5313, 13.9375, 14.5664, 15.043, 15.3398,
+                    ],
+                    [
+                        13.3164, 13.6133, 14.0898, 14.7188, 15.125, 15.7539, 16.2305, 16.5273,
+                    ],
+                ])
+                .into_shape([1, 1, 8, 8].as_slice()),
+            },
+            // Downsample
+            Case {
+                scales: vec![1., 1., 0.8, 0.8],
+                opts: cubic,
+                expected: Tensor::from([
+                    [1.4712, 2.7813, 4.0825],
+                    [6.7114, 8.0215, 9.3228],
+                    [11.9165, 13.2266, 14.5278],
+                ])
+                .into_shape([1, 1, 3, 3].as_slice()),
+            },
+            // Align corners
+            Case {
+                scales: vec![1., 1., 1., 2.],
+                opts: ResizeOptions {
+                    coord_mode: CoordTransformMode::AlignCorners,
+                    ..cubic
+                },
+                expected: Tensor::from([
+                    [1., 1.3411, 1.8003, 2.3294, 2.6706, 3.1997, 3.6589, 4.],
+                    [5., 5.3411, 5.8003, 6.3294, 6.6706, 7.1997, 7.6589, 8.],
+                    [9., 9.3411, 9.8003, 10.3294, 10.6706, 11.1997, 11.6589, 12.],
+                    [
+                        13., 13.3411, 13.8003, 14.3294, 14.6706, 15.1997, 15.6589, 16.,
+                    ],
+                ])
+                .into_shape([1, 1, 4, 8].as_slice()),
+            },
+            // Custom coefficient, excluding points outside the input
+            Case {
+                scales: vec![1., 1., 2., 2.],
+                opts: ResizeOptions {
+                    cubic_coeff_a: -0.5,
+                    exclude_outside: true,
+                    ..cubic
+                },
+                expected: Tensor::from([
+                    [
+                        0.5588, 0.8149, 1.357, 1.8971, 2.3971, 2.9371, 3.4792, 3.7353,
+                    ],
+                    [
+                        1.5833, 1.8394, 2.3815, 2.9215, 3.4215, 3.9616, 4.5036, 4.7598,
+                    ],
+                    [
+                        3.7515, 4.0076, 4.5496, 5.0897, 5.5897, 6.1298, 6.6718, 6.9279,
+                    ],
+                    [5.9118, 6.1679, 6.7099, 7.25, 7.75, 8.2901, 8.8321, 9.0882],
+                    [
+                        7.9118, 8.1679, 8.7099, 9.25, 9.75, 10.2901, 10.8321, 11.0882,
+                    ],
+                    [
+                        10.0721, 10.3282, 10.8702, 11.4103, 11.9103, 12.4504, 12.9924, 13.2485,
+                    ],
+                    [
+                        12.2402, 12.4964, 13.0384, 13.5785, 14.0785, 14.6185, 15.1606, 15.4167,
+                    ],
+                    [
+                        13.2647, 13.5208, 14.0629, 14.6029, 15.1029, 15.643, 16.1851, 16.4412,
+                    ],
+                ])
+                .into_shape([1, 1, 8, 8].as_slice()),
+            },
+        ];
+
+        let image = onnx_test_image();
+        for case in cases {
+            let result = resize(
+                image.view(),
+                ResizeTarget::Scales(case.scales.as_slice().into()),
+                None,
+                &case.opts,
+            )
+            .unwrap();
+
+            expect_eq_1e4(&result, &case.expected)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resize_antialias() -> Result<(), Box<dyn Error>> {
+        let image = onnx_test_image();
+        let scales = [1., 1., 0.6, 0.6];
+
+        for (mode, expected) in [
+            (ResizeMode::Linear, [[2.875, 4.5], [9.375, 11.0]]),
+            (ResizeMode::Cubic, [[2.5181, 4.2859], [9.5893, 11.3571]]),
+        ] {
+            let result = resize(
+                image.view(),
+                ResizeTarget::Scales(scales.as_slice().into()),
+                None,
+                &ResizeOptions {
+                    mode,
+                    antialias: true,
+                    ..ResizeOptions::default()
+                },
+            )
+            .unwrap();
+            let expected = Tensor::from(expected).into_shape([1, 1, 2, 2].as_slice());
+
+            expect_eq_1e4(&result, &expected)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resize_coord_modes() -> Result<(), Box<dyn Error>> {
+        struct Case {
+            sizes: Vec<i32>,
+            roi: Option<Tensor>,
+            opts: ResizeOptions,
+            expected: Tensor,
+        }
+
+        let linear = ResizeOptions {
+            mode: ResizeMode::Linear,
+            ..ResizeOptions::default()
+        };
+
+        let cases = [
+            Case {
+                sizes: vec![1, 1, 3, 1],
+                roi: None,
+                opts: ResizeOptions {
+                    coord_mode: CoordTransformMode::PytorchHalfPixel,
+                    ..linear
+                },
+                expected: Tensor::from([[1.6667], [7.], [12.3333]])
+                    .into_shape([1, 1, 3, 1].as_slice()),
+            },
+            Case {
+                sizes: vec![1, 1, 3, 3],
+                roi: Some(Tensor::from([0., 0., 0.4, 0.6, 1., 1., 0.6, 0.8])),
+                opts: ResizeOptions {
+                    coord_mode: CoordTransformMode::TfCropAndResize,
+                    ..linear
+                },
+                expected: Tensor::from([[7.6, 7.9, 8.2], [8.8, 9.1, 9.4], [10., 10.3, 10.6]])
+                    .into_shape([1, 1, 3, 3].as_slice()),
+            },
+            Case {
+                sizes: vec![1, 1, 3, 3],
+                roi: Some(Tensor::from([0., 0., 0.4, 0.6, 1., 1., 1.2, 1.7])),
+                opts: ResizeOptions {
+                    coord_mode: CoordTransformMode::TfCropAndResize,
+                    extrapolation_value: 10.,
+                    ..linear
+                },
+                expected: Tensor::from([[7.6, 10., 10.], [12.4, 10., 10.], [10., 10., 10.]])
+                    .into_shape([1, 1, 3, 3].as_slice()),
+            },
+        ];
+
+        let image = onnx_test_image();
+        for case in cases {
+            let result = resize(
+                image.view(),
+                ResizeTarget::Sizes(case.sizes.as_slice().into()),
+                case.roi.as_ref().map(|roi| roi.nd_view()),
+                &case.opts,
+            )
+            .unwrap();
+
+            expect_eq_1e4(&result, &case.expected)?;
+        }
+
+        // `roi` is required for `TfCropAndResize`.
+        let result = resize(
+            image.view(),
+            ResizeTarget::Sizes([1, 1, 3, 3].as_slice().into()),
+            None,
+            &ResizeOptions {
+                coord_mode: CoordTransformMode::TfCropAndResize,
+                ..linear
+            },
+        );
+        assert_eq!(result.err(), Some(OpError::MissingInputs));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resize_any_axis() -> Result<(), Box<dyn Error>> {
+        // Linear resize of a 1D sequence in an NCW tensor.
+        let input = Tensor::from([0.2, 0.7]).into_shape([1, 1, 2].as_slice());
+        let result = resize(
+            input.view(),
+            ResizeTarget::Scales([1., 1., 2.].as_slice().into()),
+            None,
+            &ResizeOptions {
+                mode: ResizeMode::Linear,
+                ..ResizeOptions::default()
+            },
+        )
+        .unwrap();
+        let expected = Tensor::from([0.2, 0.325, 0.575, 0.7]).into_shape([1, 1, 4].as_slice());
+        expect_eq_1e4(&result, &expected)?;
+
+        // Nearest resize of the batch dim.
+        let input = Tensor::from([1., 2.]).into_shape([2, 1].as_slice());
+        let result = resize(
+            input.view(),
+            ResizeTarget::Sizes([4, 1].as_slice().into()),
+            None,
+            &ResizeOptions::default(),
+        )
+        .unwrap();
+        expect_equal(
+            &result,
+            &Tensor::from([1., 1., 2., 2.]).into_shape([4, 1].as_slice()),
+        )?;
+
+        // Resizing the spatial dims of an NCDHW tensor should give the same
+        // result as resizing each depth slice as an NCHW tensor.
+        let input = Tensor::arange(0., 16., None).into_shape([1, 2, 1, 2, 4].as_slice());
+        let result = resize(
+            input.view(),
+            ResizeTarget::Scales([1., 1., 1., 2., 0.5].as_slice().into()),
+            None,
+            &ResizeOptions {
+                mode: ResizeMode::Linear,
+                ..ResizeOptions::default()
+            },
+        )
+        .unwrap();
+        let input_2d = input.clone().into_shape([1, 2, 2, 4].as_slice());
+        let expected = resize(
+            input_2d.view(),
+            ResizeTarget::Scales([1., 1., 2., 0.5].as_slice().into()),
+            None,
+            &ResizeOptions {
+                mode: ResizeMode::Linear,
+                ..ResizeOptions::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(result.shape(), &[1, 2, 1, 4, 2]);
+        expect_eq_1e4(&result, &expected.into_shape([1, 2, 1, 4, 2].as_slice()))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resize_axes() {
+        let image = onnx_test_image();
+        let scales = Tensor::from([2., 0.5]);
+        let op = Resize {
+            mode: ResizeMode::Linear,
+            axes: Some(vec![-1, 2]),
+            ..Resize::default()
+        };
+        let inputs = [Some((&image).into()), None, Some((&scales).into())];
+        let result = op.run(InputList::from_optional(&inputs)).unwrap();
+        let result = result[0].as_float_ref().unwrap();
+        assert_eq!(result.shape(), &[1, 1, 2, 8]);
+
+        // Number of scales must match number of axes.
+        let scales = Tensor::from([2., 0.5, 1.]);
+        let inputs = [Some((&image).into()), None, Some((&scales).into())];
+        let result = op.run(InputList::from_optional(&inputs));
+        assert_eq!(
+            result.err(),
+            Some(OpError::IncompatibleInputShapes(
+                "scales/sizes length should equal number of axes"
+            ))
+        );
+    }
+
     #[test]
     fn test_resize_scales_sizes() {
         enum CaseOutput {
@@ -702,21 +1498,25 @@ mod tests {
                 sizes: None,
                 expected: CaseOutput::Error(OpError::InvalidValue("scales must have 1 dims")),
             },
-            // Values for scales/sizes and input shapes which are legal according to the spec,
-            // but not currently supported in our implementation.
+            // Resize non-spatial dims
             Case {
                 image: Tensor::from_data(&[1, 1, 2, 2], vec![0.2, 0.7, 0.3, 0.8]),
                 scales: Some(Tensor::from_vec(vec![2., 1., 3., 3.])),
                 sizes: None,
-                expected: CaseOutput::Error(OpError::UnsupportedValue(
-                    "only height and width dimensions can be resized",
-                )),
+                expected: CaseOutput::Shape(vec![2, 1, 6, 6]),
             },
+            // Resize non-NCHW inputs
             Case {
                 image: Tensor::from_vec(vec![1., 1.]),
-                scales: Some(Tensor::from_vec(vec![1.])),
+                scales: Some(Tensor::from_vec(vec![2.])),
                 sizes: None,
-                expected: CaseOutput::Error(OpError::InvalidValue("input must have 4 dims (NCHW)")),
+                expected: CaseOutput::Shape(vec![4]),
+            },
+            Case {
+                image: Tensor::zeros(&[1, 2, 2, 2, 2]),
+                scales: None,
+                sizes: Some(Tensor::from_vec(vec![1, 2, 3, 3, 3])),
+                expected: CaseOutput::Shape(vec![1, 2, 3, 3, 3]),
             },
         ];
 
@@ -1,15 +1,16 @@
 use std::iter::{zip, Rev};
 use std::ops::Range;
 
+use rayon::prelude::*;
 use rten_tensor::prelude::*;
 use rten_tensor::Matrix;
-use rten_tensor::{NdTensorView, Tensor, TensorView, TensorViewMut};
+use rten_tensor::{NdTensor, NdTensorView, NdTensorViewMut, Tensor, TensorView};
+use rten_vecmath::{vec_sigmoid_in_place, vec_tanh_in_place};
 
 use crate::check_dims;
 use crate::gemm::{GemmExecutor, GemmInputA, GemmInputB, PackedBMatrix};
 use crate::ops::{
-    add_in_place, sigmoid_in_place, tanh_in_place, InputList, IntoOpResult, OpError, Operator,
-    Output,
+    num_elements, InputList, IntoOpResult, MetaInputList, MetaOutput, OpError, Operator, Output,
 };
 
 /// Direction that an RNN operator will traverse the input sequence in.
@@ -82,26 +83,83 @@ fn zip4<T1, T2, T3, T4>(
     zip3(a, b, zip(c, d)).map(|(a, b, (c, d))| (a, b, c, d))
 }
 
-/// Like [std::iter::zip], but combines 5 iterators.
-fn zip5<T1, T2, T3, T4, T5>(
-    a: impl Iterator<Item = T1>,
-    b: impl Iterator<Item = T2>,
-    c: impl Iterator<Item = T3>,
-    d: impl Iterator<Item = T4>,
-    e: impl Iterator<Item = T5>,
-) -> impl Iterator<Item = (T1, T2, T3, T4, T5)> {
-    zip4(a, b, c, zip(d, e)).map(|(a, b, c, (d, e))| (a, b, c, d, e))
+/// Activation functions used by RNN operators.
+///
+/// See https://onnx.ai/onnx/operators/onnx__RNN.html for definitions.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum RnnActivation {
+    Relu,
+    Tanh,
+    Sigmoid,
+    Affine { alpha: f32, beta: f32 },
+    LeakyRelu { alpha: f32 },
+    ThresholdedRelu { alpha: f32 },
+    ScaledTanh { alpha: f32, beta: f32 },
+    HardSigmoid { alpha: f32, beta: f32 },
+    Elu { alpha: f32 },
+    Softsign,
+    Softplus,
 }
 
-#[derive(Copy, Clone)]
-enum Activation {
-    Sigmoid,
-    Tanh,
+impl RnnActivation {
+    /// Apply the activation to a single value.
+    fn apply(self, x: f32) -> f32 {
+        match self {
+            Self::Relu => x.max(0.),
+            Self::Tanh => x.tanh(),
+            Self::Sigmoid => 1. / (1. + (-x).exp()),
+            Self::Affine { alpha, beta } => alpha * x + beta,
+            Self::LeakyRelu { alpha } => {
+                if x >= 0. {
+                    x
+                } else {
+                    alpha * x
+                }
+            }
+            Self::ThresholdedRelu { alpha } => {
+                if x > alpha {
+                    x
+                } else {
+                    0.
+                }
+            }
+            Self::ScaledTanh { alpha, beta } => alpha * (beta * x).tanh(),
+            Self::HardSigmoid { alpha, beta } => (alpha * x + beta).clamp(0., 1.),
+            Self::Elu { alpha } => {
+                if x >= 0. {
+                    x
+                } else {
+                    alpha * (x.exp() - 1.)
+                }
+            }
+            Self::Softsign => x / (1. + x.abs()),
+            Self::Softplus => x.exp().ln_1p(),
+        }
+    }
+
+    /// Clip the elements of `output` to `[-clip, clip]`, if `clip` is set,
+    /// and then apply the activation.
+    fn apply_in_place(self, output: &mut [f32], clip: Option<f32>) {
+        if let Some(clip) = clip {
+            for x in output.iter_mut() {
+                *x = x.clamp(-clip, clip);
+            }
+        }
+        match self {
+            Self::Sigmoid => vec_sigmoid_in_place(output),
+            Self::Tanh => vec_tanh_in_place(output),
+            act => {
+                for x in output.iter_mut() {
+                    *x = act.apply(*x);
+                }
+            }
+        }
+    }
 }
 
 /// Compute `output = dot(a, b)`
-fn matmul(gemm: &GemmExecutor, mut output: TensorViewMut, a: Matrix, b: GemmInputB) {
-    let row_stride = output.stride(output.ndim() - 2);
+fn matmul(gemm: &GemmExecutor, mut output: NdTensorViewMut<f32, 2>, a: Matrix, b: GemmInputB) {
+    let row_stride = output.stride(0);
     gemm.gemm(
         output.data_mut().expect("expected contiguous input"),
         row_stride,
@@ -113,8 +171,8 @@ fn matmul(gemm: &GemmExecutor, mut output: TensorViewMut, a: Matrix, b: GemmInpu
 }
 
 /// Compute `output += dot(a, b)`
-fn add_matmul(gemm: &GemmExecutor, mut output: TensorViewMut, a: Matrix, b: GemmInputB) {
-    let row_stride = output.stride(output.ndim() - 2);
+fn add_matmul(gemm: &GemmExecutor, mut output: NdTensorViewMut<f32, 2>, a: Matrix, b: GemmInputB) {
+    let row_stride = output.stride(0);
     gemm.gemm(
         output.data_mut().expect("expected contiguous input"),
         row_stride,
@@ -125,124 +183,166 @@ fn add_matmul(gemm: &GemmExecutor, mut output: TensorViewMut, a: Matrix, b: Gemm
     );
 }
 
-/// Compute output of an RNN gate as:
-///
-/// `output = act(dot(input, input_weight) + dot(hidden, hidden_weight) + input_bias + hidden_bias)`.
-///
-/// `output` has shape (batch, hidden_size)
-/// `input` has shape (batch, input_size)
-/// `input_weight` has shape (input_size, hidden_size)
-/// `hidden` has shape (batch, hidden_size)
-/// `hidden_weight` has shape (hidden_size, hidden_size)
-/// `bias` is a tuple of `(input_bias, hidden_bias)` where each bias has length `hidden_size`
-fn compute_rnn_gate(
-    gemm: &GemmExecutor,
-    mut output: TensorViewMut,
-    act: Activation,
-    input: &NdTensorView<f32, 2>,
-    hidden: &NdTensorView<f32, 2>,
-    weights_and_bias: &GateWeightsAndBias,
-) {
-    matmul(
-        gemm,
-        output.view_mut(),
-        input.nd_view(),
-        weights_and_bias.weights.as_gemm_input(),
-    );
-    add_matmul(
-        gemm,
-        output.view_mut(),
-        hidden.nd_view(),
-        weights_and_bias.recurrent_weights.as_gemm_input(),
-    );
+/// Input projections `Xt*(W^T) + Wb` for every step of the sequence, for all
+/// directions and gates of an RNN operator.
+struct InputProjection {
+    /// Projections with shape `[seq, batch, directions, num_gates * hidden_size]`.
+    proj: NdTensor<f32, 4>,
+}
 
-    if let Some((in_bias, hidden_bias)) = weights_and_bias.bias {
-        add_in_place(output.view_mut(), in_bias.as_dyn());
-        add_in_place(output.view_mut(), hidden_bias.as_dyn());
-    }
+impl InputProjection {
+    /// Compute the input projections using a single matrix multiplication.
+    ///
+    /// `input` has shape `[seq, batch, input_size]` and `weights` has shape
+    /// `[directions, num_gates * hidden_size, input_size]`. `bias`, if
+    /// present, has shape `[directions, num_gates * hidden_size]`.
+    fn new(
+        gemm: &GemmExecutor,
+        input: NdTensorView<f32, 3>,
+        weights: NdTensorView<f32, 3>,
+        bias: Option<NdTensorView<f32, 2>>,
+    ) -> InputProjection {
+        let [seq_len, batch, input_size] = input.shape();
+        let [num_directions, gates_x_hidden, _] = weights.shape();
+        let row_len = num_directions * gates_x_hidden;
+
+        let input = input.to_contiguous();
+        let weights = weights.to_contiguous();
+        let mut proj = NdTensor::zeros([seq_len, batch, num_directions, gates_x_hidden]);
+        gemm.gemm(
+            proj.data_mut().unwrap(),
+            row_len,
+            GemmInputA::Unpacked(input.view().reshaped([seq_len * batch, input_size])),
+            GemmInputB::Unpacked(weights.view().reshaped([row_len, input_size]).transposed()),
+            1., /* alpha */
+            0., /* beta */
+        );
+
+        if let Some(bias) = bias {
+            let bias = bias.to_contiguous();
+            let bias = bias.data().unwrap();
+            for row in proj.data_mut().unwrap().chunks_mut(row_len) {
+                for (x, bias) in zip(row, bias) {
+                    *x += bias;
+                }
+            }
+        }
 
-    match act {
-        Activation::Sigmoid => sigmoid_in_place(output),
-        Activation::Tanh => tanh_in_place(output),
+        InputProjection { proj }
     }
-}
 
-/// Extract a gate weight matrix from a tensor. The tensor has dims
-/// `[direction, num_gates * hidden_size, x]`. The result has shape
-/// `[x, hidden_size]`.
-fn extract_matrix(tensor: TensorView, dir: usize, num_gates: usize, gate_index: usize) -> Matrix {
-    let hidden_total = tensor.size(1);
-    assert!(hidden_total % num_gates == 0);
-    let hidden_size = hidden_total / num_gates;
-    tensor.slice::<2, _>((
-        dir,
-        (gate_index * hidden_size..(gate_index + 1) * hidden_size),
-    ))
+    /// Return the projections for a single batch item, step and direction,
+    /// as a `[num_gates * hidden_size]` slice.
+    fn get(&self, seq: usize, batch: usize, dir: usize) -> &[f32] {
+        let [_, batch_size, num_directions, row_len] = self.proj.shape();
+        let offset = ((seq * batch_size + batch) * num_directions + dir) * row_len;
+        &self.proj.data().unwrap()[offset..offset + row_len]
+    }
 }
 
-struct GateWeightsAndBias<'a> {
-    /// Gate weights. Has shape `[input_size, hidden_size]`.
-    weights: GateWeights<'a>,
-
-    /// Hidden/recurrent gate weights. Has shape `[hidden_size, hidden_size]`.
-    recurrent_weights: GateWeights<'a>,
-
-    /// Tuple of (input_bias, hidden_bias) where each vector has length
-    /// `hidden_size`.
-    bias: Option<(NdTensorView<'a, f32, 1>, NdTensorView<'a, f32, 1>)>,
+/// Combine the input and recurrent biases of an RNN operator into a single
+/// bias which can be added to the input projections.
+///
+/// `bias` has shape `[directions, 2 * num_gates * hidden_size]`, where the
+/// last dimension is a concatenation of input biases for each gate followed
+/// by recurrent biases for each gate. The result has shape
+/// `[directions, num_gates * hidden_size]`. The recurrent bias for
+/// `exclude_gate` is not included, for gates where it has to be added
+/// separately.
+fn combine_bias(
+    bias: NdTensorView<f32, 2>,
+    num_gates: usize,
+    exclude_gate: Option<usize>,
+) -> NdTensor<f32, 2> {
+    let [num_directions, gates_x_hidden_x2] = bias.shape();
+    let hidden_size = gates_x_hidden_x2 / (2 * num_gates);
+    let gates_x_hidden = num_gates * hidden_size;
+
+    let mut combined = bias.slice::<2, _>((.., ..gates_x_hidden)).to_tensor();
+    let recurrent_bias = bias.slice::<2, _>((.., gates_x_hidden..));
+    for dir in 0..num_directions {
+        for i in 0..gates_x_hidden {
+            if exclude_gate != Some(i / hidden_size) {
+                combined[[dir, i]] += recurrent_bias[[dir, i]];
+            }
+        }
+    }
+    combined
 }
 
-/// Extract weights and biases for a specific RNN gate/output from a tensor that
-/// contains concatenated weights/biases for different gates.
-///
-/// `weights` has shape `[num_directions, num_gates * hidden_size, input_size]`
-/// `recurrent_weights` has shape `[num_directions, num_gates * hidden_size, hidden_size]`
-/// `bias` has shape `[num_directions, 2 * num_gates * hidden_size]`.
+/// Extract the recurrent weights for a range of consecutive gates and
+/// prepare them for multiplication with the hidden state.
 ///
-/// Depending on the sequence length, the weights may be prepacked for use with
-/// `gemm`.
-fn extract_weights_and_bias<'a>(
+/// `recurrent_weights` has shape `[directions, num_gates * hidden_size, hidden_size]`.
+/// The result has shape `[hidden_size, gates.len() * hidden_size]`.
+fn recurrent_gate_weights<'a>(
     gemm: &GemmExecutor,
-    weights: TensorView<'a>,
-    recurrent_weights: TensorView<'a>,
-    bias: Option<TensorView<'a>>,
+    recurrent_weights: NdTensorView<'a, f32, 3>,
     dir: usize,
-    num_gates: usize,
-    gate_index: usize,
+    gates: Range<usize>,
     sequence_len: usize,
-) -> GateWeightsAndBias<'a> {
-    let hidden_size = weights.size(1) / num_gates;
-    let weights = extract_matrix(weights, dir, num_gates, gate_index).transposed();
-    let recurrent_weights =
-        extract_matrix(recurrent_weights, dir, num_gates, gate_index).transposed();
-    let bias = bias.map(|bias| {
-        let nth_gate = |gate_index| (gate_index * hidden_size)..((gate_index + 1) * hidden_size);
-        let input_bias = bias.slice::<1, _>((dir, nth_gate(gate_index)));
-        let hidden_bias = bias.slice::<1, _>((dir, nth_gate(gate_index + num_gates)));
-        (input_bias, hidden_bias)
-    });
-
-    // For sufficiently long input sequences, prepacking weights can speed up
-    // execution by amortizing packing costs over the sequence length. For
-    // short sequences the added memory usage means this won't be worthwhile.
-    let prepack = sequence_len > 4;
-
-    let weights = if prepack {
+) -> GateWeights<'a> {
+    let hidden_size = recurrent_weights.size(2);
+    let weights = recurrent_weights
+        .slice::<2, _>((dir, gates.start * hidden_size..gates.end * hidden_size))
+        .transposed();
+
+    // The recurrent weights are used once per step. For sufficiently long
+    // input sequences, prepacking them can speed up execution by amortizing
+    // packing costs over the sequence length. For short sequences the added
+    // memory usage means this won't be worthwhile.
+    if sequence_len > 4 {
         GateWeights::Packed(gemm.prepack_b(weights))
     } else {
         GateWeights::Unpacked(weights)
-    };
-    let recurrent_weights = if prepack {
-        GateWeights::Packed(gemm.prepack_b(recurrent_weights))
-    } else {
-        GateWeights::Unpacked(recurrent_weights)
-    };
+    }
+}
 
-    GateWeightsAndBias {
-        weights,
-        recurrent_weights,
-        bias,
+/// Return the initial value of a state (eg. hidden or cell state) for
+/// direction `dir` from a `[directions, batch, hidden_size]` tensor, or zeros
+/// if no initial value was provided.
+fn initial_state(
+    init: Option<&TensorView>,
+    dir: usize,
+    batch: usize,
+    hidden_size: usize,
+) -> NdTensor<f32, 2> {
+    init.map(|init| init.slice::<2, _>([dir]).to_tensor())
+        .unwrap_or_else(|| NdTensor::zeros([batch, hidden_size]))
+}
+
+/// Run an RNN operator for each direction and combine the outputs.
+///
+/// Directions are independent, so they are run in parallel. `run_dir`
+/// returns the hidden states for each step in the sequence as
+/// `[seq, batch, hidden_size]` and the final value of each of the `N` states
+/// as `[batch, hidden_size]`. The result contains the hidden sequence as
+/// `[seq, directions, batch, hidden_size]` followed by each of the final
+/// states as `[directions, batch, hidden_size]`.
+fn run_directions<const N: usize, F>(num_directions: usize, run_dir: F) -> Vec<Tensor>
+where
+    F: Fn(usize) -> (NdTensor<f32, 3>, [NdTensor<f32, 2>; N]) + Send + Sync,
+{
+    let dir_outputs: Vec<_> = (0..num_directions).into_par_iter().map(run_dir).collect();
+
+    let [seq_len, batch, hidden_size] = dir_outputs[0].0.shape();
+    let mut hidden_seq = NdTensor::zeros([seq_len, num_directions, batch, hidden_size]);
+    let mut states: [NdTensor<f32, 3>; N] =
+        std::array::from_fn(|_| NdTensor::zeros([num_directions, batch, hidden_size]));
+
+    for (dir, (dir_hidden_seq, dir_states)) in dir_outputs.into_iter().enumerate() {
+        hidden_seq
+            .slice_mut::<3, _>((.., dir))
+            .copy_from(&dir_hidden_seq);
+        for (state, dir_state) in zip(states.iter_mut(), dir_states) {
+            state.slice_mut::<2, _>([dir]).copy_from(&dir_state);
+        }
     }
+
+    [hidden_seq.into_dyn()]
+        .into_iter()
+        .chain(states.map(|state| state.into_dyn()))
+        .collect()
 }
 
 /// Gated Recurrent Unit operator.
@@ -254,10 +354,38 @@ pub struct GRU {
     /// When computing the output of the hidden gate, apply the linear
     /// transformation before multiplying by the output of the reset gate.
     pub linear_before_reset: bool,
+
+    /// Clip the inputs to activations to `[-clip, clip]`.
+    pub clip: Option<f32>,
 }
 
 /// Compute the output for a single GRU layer.
 ///
+/// This is equivalent to [gru_with_clip] with no clipping.
+#[allow(clippy::too_many_arguments)]
+pub fn gru(
+    direction: Direction,
+    input: TensorView,
+    weights: TensorView,
+    recurrent_weights: TensorView,
+    bias: Option<TensorView>,
+    initial_hidden: Option<TensorView>,
+    linear_before_reset: bool,
+) -> Result<Vec<Tensor>, OpError> {
+    gru_with_clip(
+        direction,
+        input,
+        weights,
+        recurrent_weights,
+        bias,
+        initial_hidden,
+        linear_before_reset,
+        None,
+    )
+}
+
+/// Compute the output for a single GRU layer, with optional clipping.
+///
 /// `input` has shape [sequence_length, batch, input_size].
 ///
 /// `weights` has shape `[directions, 3 * hidden_size, input_size]`. The middle
@@ -273,7 +401,10 @@ pub struct GRU {
 /// followed by hidden biases for the same gates.
 ///
 /// `initial_hidden` has shape `[directions, batch, hidden_size]`.
-pub fn gru(
+///
+/// If `clip` is set, inputs to activations are clipped to `[-clip, clip]`.
+#[allow(clippy::too_many_arguments)]
+pub fn gru_with_clip(
     direction: Direction,
     input: TensorView,
     weights: TensorView,
@@ -281,67 +412,84 @@ pub fn gru(
     bias: Option<TensorView>,
     initial_hidden: Option<TensorView>,
     linear_before_reset: bool,
+    clip: Option<f32>,
 ) -> Result<Vec<Tensor>, OpError> {
     let [seq_len, batch, _input_size] = check_dims!(input, 3, "seq, batch, input");
-    let [_directions, hidden_x3, _input_size] = check_dims!(weights, 3, "dir, hidden x 3, input");
+    check_dims!(weights, 3, "dir, hidden x 3, input");
     check_dims!(recurrent_weights, 3);
+    check_dims!(bias?, 2);
     check_dims!(initial_hidden?, 3);
 
     let num_directions = direction.num_directions();
-    let hidden_size = hidden_x3 / 3;
+    let hidden_size = check_rnn_input_sizes(
+        direction,
+        3,
+        &input,
+        &weights,
+        &recurrent_weights,
+        bias.as_ref(),
+        &[initial_hidden.as_ref()],
+    )?;
 
-    let mut hidden = initial_hidden
-        .map(|t| t.to_tensor())
-        .unwrap_or_else(|| Tensor::zeros(&[num_directions, batch, hidden_size]));
-    let mut hidden_seq = Tensor::zeros(&[seq_len, num_directions, batch, hidden_size]);
-    let new_gate = || Tensor::zeros(&[batch, hidden_size]);
-
-    let mut update_gate = new_gate();
-    let mut reset_gate = new_gate();
-    let mut hidden_gate = new_gate();
-
-    // `extract_weights_and_bias` requires a contiguous tensor.
-    let bias = bias.map(|t| t.to_contiguous());
+    // Indices of gates in the concatenated weight and bias tensors.
+    const UPDATE_GATE: usize = 0;
+    const RESET_GATE: usize = 1;
+    const HIDDEN_GATE: usize = 2;
 
     let gemm = GemmExecutor::new();
+    let recurrent_weights = recurrent_weights.nd_view::<3>();
+    let bias = bias.as_ref().map(|b| b.nd_view::<2>());
+
+    // Compute the input projections for all steps up front. When
+    // `linear_before_reset` is true, the recurrent bias for the hidden gate
+    // is multiplied by the reset gate, so it can't be combined with the input
+    // bias.
+    let input_proj = InputProjection::new(
+        &gemm,
+        input.nd_view(),
+        weights.nd_view(),
+        bias.map(|b| combine_bias(b, 3, linear_before_reset.then_some(HIDDEN_GATE)))
+            .as_ref()
+            .map(|b| b.view()),
+    );
 
-    // Extract and prepack weights for a gate.
-    let extract_gru_weights_and_bias = |dir, gate_index| {
-        extract_weights_and_bias(
+    let run_dir = |dir| {
+        // The update and reset gates are computed together, as they use the
+        // same input.
+        let update_reset_weights = recurrent_gate_weights(
             &gemm,
-            weights.view(),
-            recurrent_weights.view(),
-            bias.as_ref().map(|b| b.view()),
+            recurrent_weights,
             dir,
-            3,
-            gate_index,
+            UPDATE_GATE..RESET_GATE + 1,
             seq_len,
-        )
-    };
-
-    // Indices of gates in the concatenated weight and bias tensors.
-    const UPDATE_GATE: usize = 0;
-    const RESET_GATE: usize = 1;
-    const HIDDEN_GATE: usize = 2;
+        );
+        let hidden_weights = recurrent_gate_weights(
+            &gemm,
+            recurrent_weights,
+            dir,
+            HIDDEN_GATE..HIDDEN_GATE + 1,
+            seq_len,
+        );
+        let rec_hidden_bias = bias.filter(|_| linear_before_reset).map(|b| {
+            let start = (3 + HIDDEN_GATE) * hidden_size;
+            b.slice::<1, _>((dir, start..start + hidden_size)).to_vec()
+        });
 
-    // Scratch buffer for computing new hidden state.
-    let mut hidden_tmp = new_gate();
+        let mut hidden = initial_state(initial_hidden.as_ref(), dir, batch, hidden_size);
+        let mut hidden_seq = NdTensor::zeros([seq_len, batch, hidden_size]);
+        let mut update_reset_gates = NdTensor::zeros([batch, 2 * hidden_size]);
+        let mut hidden_gate = NdTensor::zeros([batch, hidden_size]);
 
-    for dir in 0..num_directions {
-        let update_weights = extract_gru_weights_and_bias(dir, UPDATE_GATE);
-        let reset_weights = extract_gru_weights_and_bias(dir, RESET_GATE);
-        let hidden_weights = extract_gru_weights_and_bias(dir, HIDDEN_GATE);
+        // Scratch buffer for computing new hidden state.
+        let mut hidden_tmp = NdTensor::zeros([batch, hidden_size]);
 
         for seq in sequence_for_dir(direction, dir, seq_len) {
-            let in_item = input.slice::<2, _>([seq]);
-            let hidden_item = hidden.slice::<2, _>([dir]);
-
             // From the ONNX spec, the intermediate values are computed as:
             //
             //   zt = f(Xt*(Wz^T) + Ht-1*(Rz^T) + Wbz + Rbz)
             //   rt = f(Xt*(Wr^T) + Ht-1*(Rr^T) + Wbr + Rbr)
             //
-            //   If `linear_before_reset` is false:
+            //   If `linear_before_reset` is true:
             //     ht = tanh(dot(input, hidden_w) + reset * (dot(hidden, rec_hidden_w) + rec_hidden_bias) + hidden_bias)
             //   Else:
             //     ht = tanh(dot(input, hidden_w) + dot((reset * hidden), rec_hidden_w) + rec_hidden_bias + hidden_bias)
@@ -355,91 +503,99 @@ pub fn gru(
             //  - `W{z,r,h}` and `R{z,r,h}` are the input and recurrent weights
             //  - `Wb{z,r,h}` and `Rb{z,r,h}` are the input and recurrent biases
             //  - `f` and `g` are activations. f=sigmoid, g=tanh
-
-            // Compute update gate.
-            compute_rnn_gate(
-                &gemm,
-                update_gate.view_mut(),
-                Activation::Sigmoid,
-                &in_item,
-                &hidden_item,
-                &update_weights,
-            );
-
-            // Compute reset gate.
-            compute_rnn_gate(
+            //
+            // The `Xt*(W^T) + Wb + Rb` terms come from the input projections.
+
+            // Compute update and reset gates.
+            for (b, gates) in update_reset_gates
+                .data_mut()
+                .unwrap()
+                .chunks_mut(2 * hidden_size)
+                .enumerate()
+            {
+                gates.copy_from_slice(&input_proj.get(seq, b, dir)[..2 * hidden_size]);
+            }
+            add_matmul(
                 &gemm,
-                reset_gate.view_mut(),
-                Activation::Sigmoid,
-                &in_item,
-                &hidden_item,
-                &reset_weights,
+                update_reset_gates.view_mut(),
+                hidden.view(),
+                update_reset_weights.as_gemm_input(),
             );
+            RnnActivation::Sigmoid.apply_in_place(update_reset_gates.data_mut().unwrap(), clip);
 
             // Compute hidden gate.
-            matmul(
-                &gemm,
-                hidden_gate.view_mut(),
-                in_item.nd_view(),
-                hidden_weights.weights.as_gemm_input(),
-            );
+            let update_reset_rows = update_reset_gates.data().unwrap().chunks(2 * hidden_size);
             if linear_before_reset {
                 matmul(
                     &gemm,
-                    hidden_tmp.view_mut(),
-                    hidden_item.nd_view(),
-                    hidden_weights.recurrent_weights.as_gemm_input(),
+                    hidden_gate.view_mut(),
+                    hidden.view(),
+                    hidden_weights.as_gemm_input(),
                 );
-
-                // Compute `hidden_gate = tanh(hidden_gate + hidden_bias + reset * (dot(update_tmp) + rec_hidden_bias))`
-                if let Some((hidden_bias, rec_hidden_bias)) = hidden_weights.bias {
-                    for (hidden_gate, update_tmp, reset, rec_hidden_bias, hidden_bias) in zip5(
-                        hidden_gate.iter_mut(),
-                        hidden_tmp.iter(),
-                        reset_gate.iter(),
-                        // Cycle to repeat for each item in batch.
-                        rec_hidden_bias.iter().cycle(),
-                        hidden_bias.iter().cycle(),
-                    ) {
-                        let update = reset * (update_tmp + rec_hidden_bias);
-                        *hidden_gate = (*hidden_gate + update + hidden_bias).tanh();
-                    }
-                } else {
-                    for (hidden_gate, update_tmp, reset) in
-                        zip3(hidden_gate.iter_mut(), hidden_tmp.iter(), reset_gate.iter())
-                    {
-                        let update = reset * update_tmp;
-                        *hidden_gate = (*hidden_gate + update).tanh();
+                for (b, (hidden_gate, update_reset)) in zip(
+                    hidden_gate.data_mut().unwrap().chunks_mut(hidden_size),
+                    update_reset_rows,
+                )
+                .enumerate()
+                {
+                    let proj = &input_proj.get(seq, b, dir)[2 * hidden_size..];
+                    let reset = &update_reset[hidden_size..];
+                    for (i, hidden_gate) in hidden_gate.iter_mut().enumerate() {
+                        let rec_bias = rec_hidden_bias.as_ref().map(|rb| rb[i]).unwrap_or(0.);
+                        *hidden_gate = proj[i] + reset[i] * (*hidden_gate + rec_bias);
                     }
                 }
             } else {
-                // TODO - Support alternate GRU variant where hidden gate is
-                // computed as:
-                //
-                //   `hidden_gate = tanh(dot(input, hidden_w) + dot((reset * hidden), rec_hidden_w) + rec_hidden_bias + hidden_bias)
-                //
-                // Note that cuDNN and PyTorch use the semantics of
-                // `linear_before_reset = true`.
-                unimplemented!("`linear_before_reset == false` is not supported");
+                for (b, ((hidden_gate, tmp), (update_reset, hidden))) in zip(
+                    zip(
+                        hidden_gate.data_mut().unwrap().chunks_mut(hidden_size),
+                        hidden_tmp.data_mut().unwrap().chunks_mut(hidden_size),
+                    ),
+                    zip(
+                        update_reset_rows,
+                        hidden.data().unwrap().chunks(hidden_size),
+                    ),
+                )
+                .enumerate()
+                {
+                    hidden_gate.copy_from_slice(&input_proj.get(seq, b, dir)[2 * hidden_size..]);
+                    for (tmp, reset, hidden) in zip3(
+                        tmp.iter_mut(),
+                        update_reset[hidden_size..].iter(),
+                        hidden.iter(),
+                    ) {
+                        *tmp = reset * hidden;
+                    }
+                }
+                add_matmul(
+                    &gemm,
+                    hidden_gate.view_mut(),
+                    hidden_tmp.view(),
+                    hidden_weights.as_gemm_input(),
+                );
             }
+            RnnActivation::Tanh.apply_in_place(hidden_gate.data_mut().unwrap(), clip);
 
             // Compute next hidden state
-            let mut hidden_item = hidden.slice_mut::<2, _>([dir]);
-            for (hidden, update, hidden_gate) in zip3(
-                hidden_item.iter_mut(),
-                update_gate.iter(),
-                hidden_gate.iter(),
+            for (hidden, update_reset, hidden_gate) in zip3(
+                hidden.data_mut().unwrap().chunks_mut(hidden_size),
+                update_reset_gates.data().unwrap().chunks(2 * hidden_size),
+                hidden_gate.data().unwrap().chunks(hidden_size),
             ) {
-                *hidden = (1. - update) * hidden_gate + update * (*hidden);
+                for (hidden, update, hidden_gate) in
+                    zip3(hidden.iter_mut(), update_reset.iter(), hidden_gate.iter())
+                {
+                    *hidden = (1. - update) * hidden_gate + update * (*hidden);
+                }
             }
 
-            hidden_seq
-                .slice_mut::<2, _>([seq, dir])
-                .copy_from(&hidden_item);
+            hidden_seq.slice_mut::<2, _>([seq]).copy_from(&hidden);
         }
-    }
 
-    Ok([hidden_seq, hidden].into())
+        (hidden_seq, [hidden])
+    };
+
+    Ok(run_directions(num_directions, run_dir))
 }
 
 impl Operator for GRU {
@@ -455,7 +611,7 @@ impl Operator for GRU {
         let _seq_len = inputs.get_as::<i32>(4)?;
         let initial_hidden = inputs.get_as(5)?;
 
-        gru(
+        gru_with_clip(
             self.direction,
             input,
             weights,
@@ -463,9 +619,111 @@ impl Operator for GRU {
             bias,
             initial_hidden,
             self.linear_before_reset,
+            self.clip,
         )
         .into_op_result()
     }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        rnn_flops(input_shapes)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(rnn_output_shapes(inputs, self.direction, 3, 1))
+    }
+}
+
+/// Check that the sizes of the weights, bias and initial states of an RNN
+/// operator with `num_gates` gates are consistent with the input and
+/// `direction`, and return the hidden size.
+///
+/// The number of dims of each tensor must already have been checked.
+fn check_rnn_input_sizes(
+    direction: Direction,
+    num_gates: usize,
+    input: &TensorView,
+    weights: &TensorView,
+    recurrent_weights: &TensorView,
+    bias: Option<&TensorView>,
+    initial_states: &[Option<&TensorView>],
+) -> Result<usize, OpError> {
+    let [_seq_len, batch, input_size] = input.shape().try_into().unwrap();
+    let [num_directions, gates_x_hidden, weights_input_size] = weights.shape().try_into().unwrap();
+
+    if num_directions != direction.num_directions() {
+        return Err(OpError::InvalidValue(
+            "weights dim 0 must match number of directions",
+        ));
+    }
+    if gates_x_hidden % num_gates != 0 {
+        return Err(OpError::InvalidValue(
+            "weights dim 1 must be num_gates * hidden_size",
+        ));
+    }
+    if weights_input_size != input_size {
+        return Err(OpError::InvalidValue("weights dim 2 must match input size"));
+    }
+
+    let hidden_size = gates_x_hidden / num_gates;
+    if recurrent_weights.shape() != [num_directions, gates_x_hidden, hidden_size] {
+        return Err(OpError::InvalidValue(
+            "recurrent_weights must have shape [directions, num_gates * hidden_size, hidden_size]",
+        ));
+    }
+    if bias.is_some_and(|bias| bias.shape() != [num_directions, 2 * gates_x_hidden]) {
+        return Err(OpError::InvalidValue(
+            "bias must have shape [directions, 2 * num_gates * hidden_size]",
+        ));
+    }
+    if initial_states
+        .iter()
+        .flatten()
+        .any(|state| state.shape() != [num_directions, batch, hidden_size])
+    {
+        return Err(OpError::InvalidValue(
+            "initial states must have shape [directions, batch, hidden_size]",
+        ));
+    }
+
+    Ok(hidden_size)
+}
+
+/// Estimate the number of arithmetic operations for an RNN operator, given
+/// the shapes of its inputs.
+fn rnn_flops(input_shapes: &[Option<&[usize]>]) -> Option<u64> {
+    // Dominated by the input and hidden state matrix multiplications at each
+    // step, for each direction.
+    let [seq_len, batch, ..] = *input_shapes.first().copied().flatten()? else {
+        return None;
+    };
+    let weights_len = num_elements(input_shapes.get(1).copied().flatten()?);
+    let recurrent_weights_len = num_elements(input_shapes.get(2).copied().flatten()?);
+    Some(2 * (seq_len * batch) as u64 * (weights_len + recurrent_weights_len))
+}
+
+/// Compute the output shapes of an RNN operator with `num_gates` gates.
+///
+/// The first output is the hidden state for each step in the sequence. The
+/// remaining `num_states` outputs are the final states (eg. hidden, cell).
+fn rnn_output_shapes(
+    inputs: &MetaInputList,
+    direction: Direction,
+    num_gates: usize,
+    num_states: usize,
+) -> Result<Vec<MetaOutput>, OpError> {
+    let (&[seq_len, batch, _], &[_, hidden_x_gates, _]) =
+        (inputs.require_shape(0)?, inputs.require_shape(1)?)
+    else {
+        return Err(OpError::InvalidValue("input and weights must have 3 dims"));
+    };
+    let hidden_size = hidden_x_gates / num_gates;
+    let num_directions = direction.num_directions();
+
+    let mut shapes = vec![vec![seq_len, num_directions, batch, hidden_size].into()];
+    for _ in 0..num_states {
+        shapes.push(vec![num_directions, batch, hidden_size].into());
+    }
+    Ok(shapes)
 }
 
 /// Weights for an RNN gate, which may or may not be prepacked.
@@ -488,10 +746,41 @@ impl<'a> GateWeights<'a> {
 pub struct LSTM {
     pub direction: Direction,
     pub hidden_size: usize,
+
+    /// Clip the inputs to activations to `[-clip, clip]`.
+    pub clip: Option<f32>,
 }
 
 /// Compute the output for a single LSTM layer.
 ///
+/// This is equivalent to [lstm_with_peepholes_and_clip] with no peephole
+/// weights and no clipping.
+#[allow(clippy::too_many_arguments)]
+pub fn lstm(
+    direction: Direction,
+    input: TensorView,
+    weights: TensorView,
+    recurrent_weights: TensorView,
+    bias: Option<TensorView>,
+    initial_hidden: Option<TensorView>,
+    initial_cell: Option<TensorView>,
+) -> Result<Vec<Tensor>, OpError> {
+    lstm_with_peepholes_and_clip(
+        direction,
+        input,
+        weights,
+        recurrent_weights,
+        bias,
+        initial_hidden,
+        initial_cell,
+        None,
+        None,
+    )
+}
+
+/// Compute the output for a single LSTM layer, with optional peephole
+/// connections and clipping.
+///
 /// `input` has shape [sequence_length, batch, input_size].
 ///
 /// `weights` has shape `[directions, 4 * hidden_size, input_size]`. The middle
@@ -508,7 +797,13 @@ pub struct LSTM {
 ///
 /// `initial_hidden` has shape `[directions, batch, hidden_size]`.
 /// `initial_cell` has shape `[directions, batch, hidden_size]`.
-pub fn lstm(
+///
+/// `peephole` has shape `[directions, 3 * hidden_size]`. The last dimension is
+/// a concatenation of peephole weights for the input, output and forget gates.
+///
+/// If `clip` is set, inputs to activations are clipped to `[-clip, clip]`.
+#[allow(clippy::too_many_arguments)]
+pub fn lstm_with_peepholes_and_clip(
     direction: Direction,
     input: TensorView,
     weights: TensorView,
@@ -516,32 +811,35 @@ pub fn lstm(
     bias: Option<TensorView>,
     initial_hidden: Option<TensorView>,
     initial_cell: Option<TensorView>,
+    peephole: Option<TensorView>,
+    clip: Option<f32>,
 ) -> Result<Vec<Tensor>, OpError> {
-    // TODO - Add validation of the sizes of individual dimensions in the inputs.
     let [seq_len, batch, _input_size] = check_dims!(input, 3, "seq, batch, input");
-    let [_directions, hidden_x4, _input_size] = check_dims!(weights, 3, "dir, hidden x 4, input");
+    check_dims!(weights, 3, "dir, hidden x 4, input");
     check_dims!(recurrent_weights, 3);
+    check_dims!(bias?, 2);
+    check_dims!(initial_hidden?, 3);
+    check_dims!(initial_cell?, 3);
 
     let num_directions = direction.num_directions();
-    let hidden_size = hidden_x4 / 4;
-
-    if weights.size(1) % 4 != 0 {
-        return Err(OpError::InvalidValue(
-            "weights dim 1 must be 4 * hidden_size",
-        ));
-    }
-    if let Some(bias) = bias.as_ref() {
-        check_dims!(bias, 2);
-        if bias.size(1) % 8 != 0 {
-            return Err(OpError::InvalidValue("bias dim 1 must be 8 * hidden_size"));
+    let hidden_size = check_rnn_input_sizes(
+        direction,
+        4,
+        &input,
+        &weights,
+        &recurrent_weights,
+        bias.as_ref(),
+        &[initial_hidden.as_ref(), initial_cell.as_ref()],
+    )?;
+
+    if let Some(peephole) = peephole.as_ref() {
+        check_dims!(peephole, 2, "dir, hidden x 3");
+        if peephole.shape() != [num_directions, 3 * hidden_size] {
+            return Err(OpError::InvalidValue(
+                "peephole must have shape [directions, 3 * hidden_size]",
+            ));
         }
     }
-    check_dims!(initial_hidden?, 3);
-    check_dims!(initial_cell?, 3);
-
-    // Contiguous input and bias needed to allow reshaping below.
-    let input = input.to_contiguous();
-    let bias = bias.map(|t| t.to_contiguous());
 
     // Indices of gates in the concatenated weight and bias tensors.
     const INPUT_GATE: usize = 0;
@@ -549,41 +847,44 @@ pub fn lstm(
     const FORGET_GATE: usize = 2;
     const CELL_GATE: usize = 3;
 
-    let new_gate = || Tensor::zeros(&[batch, hidden_size]);
-    let mut input_gate = new_gate();
-    let mut out_gate = new_gate();
-    let mut forget_gate = new_gate();
-    let mut cell_gate = new_gate();
-
-    let mut cell = initial_cell
-        .map(|t| t.to_tensor())
-        .unwrap_or_else(|| Tensor::zeros(&[num_directions, batch, hidden_size]));
-    let mut hidden = initial_hidden
-        .map(|t| t.to_tensor())
-        .unwrap_or_else(|| Tensor::zeros(&[num_directions, batch, hidden_size]));
-
-    let mut hidden_seq = Tensor::<f32>::zeros(&[seq_len, num_directions, batch, hidden_size]);
-
     let gemm = GemmExecutor::new();
+    let recurrent_weights = recurrent_weights.nd_view::<3>();
 
-    let extract_lstm_weights_and_bias = |dir, gate_index| {
-        extract_weights_and_bias(
+    // Compute the input projections for all steps up front.
+    let input_proj = InputProjection::new(
+        &gemm,
+        input.nd_view(),
+        weights.nd_view(),
+        bias.as_ref()
+            .map(|b| combine_bias(b.nd_view(), 4, None))
+            .as_ref()
+            .map(|b| b.view()),
+    );
+
+    let run_dir = |dir| {
+        let weights = recurrent_gate_weights(
             &gemm,
-            weights.view(),
-            recurrent_weights.view(),
-            bias.as_ref().map(|b| b.view()),
+            recurrent_weights,
             dir,
-            4,
-            gate_index,
+            INPUT_GATE..CELL_GATE + 1,
             seq_len,
-        )
-    };
+        );
+
+        // Peephole weights for a gate, in (input, output, forget) order.
+        let peephole = peephole.as_ref().map(|p| p.slice::<1, _>([dir]).to_vec());
+        let peephole_weights = |gate_index: usize| {
+            peephole
+                .as_ref()
+                .map(|p| &p[gate_index * hidden_size..(gate_index + 1) * hidden_size])
+        };
+        let input_peephole = peephole_weights(INPUT_GATE);
+        let output_peephole = peephole_weights(OUTPUT_GATE);
+        let forget_peephole = peephole_weights(FORGET_GATE);
 
-    for dir in 0..num_directions {
-        let input_weights = extract_lstm_weights_and_bias(dir, INPUT_GATE);
-        let output_weights = extract_lstm_weights_and_bias(dir, OUTPUT_GATE);
-        let forget_weights = extract_lstm_weights_and_bias(dir, FORGET_GATE);
-        let cell_weights = extract_lstm_weights_and_bias(dir, CELL_GATE);
+        let mut cell = initial_state(initial_cell.as_ref(), dir, batch, hidden_size);
+        let mut hidden = initial_state(initial_hidden.as_ref(), dir, batch, hidden_size);
+        let mut hidden_seq = NdTensor::zeros([seq_len, batch, hidden_size]);
+        let mut gates = NdTensor::zeros([batch, 4 * hidden_size]);
 
         for seq in sequence_for_dir(direction, dir, seq_len) {
             // From the ONNX spec, the intermediate values are computed as:
@@ -601,81 +902,250 @@ pub fn lstm(
             //  - `Xt`, `Ht` and `Ct` are the input, hidden state and cell state at time `t`
             //  - `W{i,o,f,c}` and `R{i,o,f,c}` are the input and recurrent gate weights
             //  - `Wb{i,o,f,c}` and `Rb{i,o,f,c}` are the input and recurrent gate biases
-            //  - `P{i,o,f,c}` are peephole weights. These are not currently
-            //    supported.
+            //  - `P{i,o,f}` are peephole weights
             //  - `f`, `g` and `h` are activations. `f`=sigmoid, `g` and `h`
             //    are tanh.
-            let in_item = input.slice::<2, _>([seq]);
-            let hidden_item = hidden.slice::<2, _>([dir]);
-
-            // Compute outputs for input, forget, cell and output gates.
-            compute_rnn_gate(
+            //
+            // The `Xt*(W^T) + Wb + Rb` terms come from the input projections.
+
+            // Compute the linear part of all gates.
+            for (b, gates) in gates
+                .data_mut()
+                .unwrap()
+                .chunks_mut(4 * hidden_size)
+                .enumerate()
+            {
+                gates.copy_from_slice(input_proj.get(seq, b, dir));
+            }
+            add_matmul(
                 &gemm,
-                input_gate.view_mut(),
-                Activation::Sigmoid,
-                &in_item,
-                &hidden_item,
-                &input_weights,
+                gates.view_mut(),
+                hidden.view(),
+                weights.as_gemm_input(),
             );
 
-            compute_rnn_gate(
-                &gemm,
-                forget_gate.view_mut(),
-                Activation::Sigmoid,
-                &in_item,
-                &hidden_item,
-                &forget_weights,
-            );
+            for (gates, (cell, hidden)) in zip(
+                gates.data_mut().unwrap().chunks_mut(4 * hidden_size),
+                zip(
+                    cell.data_mut().unwrap().chunks_mut(hidden_size),
+                    hidden.data_mut().unwrap().chunks_mut(hidden_size),
+                ),
+            ) {
+                // Gates are in (input, output, forget, cell) order.
+                let mut gates = gates.chunks_mut(hidden_size);
+                let [input_gate, out_gate, forget_gate, cell_gate] =
+                    std::array::from_fn(|_| gates.next().unwrap());
+
+                // Compute outputs for input, forget and cell gates.
+                for (gate, peephole) in [
+                    (&mut *input_gate, input_peephole),
+                    (&mut *forget_gate, forget_peephole),
+                ] {
+                    if let Some(peephole) = peephole {
+                        add_peephole(gate, peephole, cell);
+                    }
+                    RnnActivation::Sigmoid.apply_in_place(gate, clip);
+                }
+                RnnActivation::Tanh.apply_in_place(cell_gate, clip);
+
+                // Compute new value of cell state
+                for (cell, forget_gate, input_gate, cell_gate) in zip4(
+                    cell.iter_mut(),
+                    forget_gate.iter(),
+                    input_gate.iter(),
+                    cell_gate.iter(),
+                ) {
+                    *cell = forget_gate * *cell + input_gate * cell_gate;
+                }
+
+                // Compute output gate. This uses the new cell state.
+                if let Some(peephole) = output_peephole {
+                    add_peephole(out_gate, peephole, cell);
+                }
+                RnnActivation::Sigmoid.apply_in_place(out_gate, clip);
+
+                // Compute new hidden state
+                hidden.copy_from_slice(cell);
+                RnnActivation::Tanh.apply_in_place(hidden, clip);
+                for (hidden, out_gate) in zip(hidden.iter_mut(), out_gate.iter()) {
+                    *hidden *= out_gate;
+                }
+            }
+
+            hidden_seq.slice_mut::<2, _>([seq]).copy_from(&hidden);
+        }
+
+        (hidden_seq, [hidden, cell])
+    };
+
+    Ok(run_directions(num_directions, run_dir))
+}
+
+/// Compute `gate += peephole * cell`, where all slices have length
+/// `hidden_size`.
+fn add_peephole(gate: &mut [f32], peephole: &[f32], cell: &[f32]) {
+    for (gate, peephole, cell) in zip3(gate.iter_mut(), peephole.iter(), cell.iter()) {
+        *gate += peephole * cell;
+    }
+}
+
+impl Operator for LSTM {
+    fn name(&self) -> &str {
+        "LSTM"
+    }
+
+    fn run(&self, inputs: InputList) -> Result<Vec<Output>, OpError> {
+        let input = inputs.require_as(0)?;
+        let weights = inputs.require_as(1)?;
+        let recurrent_weights = inputs.require_as(2)?;
+        let bias = inputs.get_as(3)?;
+        let _seq_len = inputs.get_as::<i32>(4)?;
+        let initial_hidden = inputs.get_as(5)?;
+        let initial_cell = inputs.get_as(6)?;
+        let peephole = inputs.get_as(7)?;
+
+        lstm_with_peepholes_and_clip(
+            self.direction,
+            input,
+            weights,
+            recurrent_weights,
+            bias,
+            initial_hidden,
+            initial_cell,
+            peephole,
+            self.clip,
+        )
+        .into_op_result()
+    }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        rnn_flops(input_shapes)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(rnn_output_shapes(inputs, self.direction, 4, 2))
+    }
+}
+
+/// Simple recurrent network operator.
+#[derive(Debug)]
+pub struct RNN {
+    pub direction: Direction,
+    pub hidden_size: usize,
+
+    /// Activation for each direction. If empty, `Tanh` is used. If there is
+    /// a single activation, it is used for all directions.
+    pub activations: Vec<RnnActivation>,
+
+    /// Clip the inputs to activations to `[-clip, clip]`.
+    pub clip: Option<f32>,
+}
+
+/// Compute the output for a single RNN layer.
+///
+/// `input` has shape [sequence_length, batch, input_size].
+///
+/// `weights` has shape `[directions, hidden_size, input_size]`.
+///
+/// `recurrent_weights` has shape `[directions, hidden_size, hidden_size]`.
+///
+/// `bias` has shape `[directions, 2 * hidden_size]`. The last dimension is a
+/// concatenation of the input and hidden biases.
+///
+/// `initial_hidden` has shape `[directions, batch, hidden_size]`.
+///
+/// `activations` specifies the activation for each direction. See [RNN]
+/// for how this is interpreted. If `clip` is set, inputs to activations are
+/// clipped to `[-clip, clip]`.
+#[allow(clippy::too_many_arguments)]
+pub fn rnn(
+    direction: Direction,
+    input: TensorView,
+    weights: TensorView,
+    recurrent_weights: TensorView,
+    bias: Option<TensorView>,
+    initial_hidden: Option<TensorView>,
+    activations: &[RnnActivation],
+    clip: Option<f32>,
+) -> Result<Vec<Tensor>, OpError> {
+    let [seq_len, batch, _input_size] = check_dims!(input, 3, "seq, batch, input");
+    check_dims!(weights, 3, "dir, hidden, input");
+    check_dims!(recurrent_weights, 3);
+    check_dims!(bias?, 2);
+    check_dims!(initial_hidden?, 3);
+
+    let num_directions = direction.num_directions();
+    let hidden_size = check_rnn_input_sizes(
+        direction,
+        1,
+        &input,
+        &weights,
+        &recurrent_weights,
+        bias.as_ref(),
+        &[initial_hidden.as_ref()],
+    )?;
+    if activations.len() > 1 && activations.len() != num_directions {
+        return Err(OpError::InvalidValue(
+            "activations length must match number of directions",
+        ));
+    }
 
-            compute_rnn_gate(
-                &gemm,
-                cell_gate.view_mut(),
-                Activation::Tanh,
-                &in_item,
-                &hidden_item,
-                &cell_weights,
-            );
+    let gemm = GemmExecutor::new();
+    let recurrent_weights = recurrent_weights.nd_view::<3>();
 
-            compute_rnn_gate(
-                &gemm,
-                out_gate.view_mut(),
-                Activation::Sigmoid,
-                &in_item,
-                &hidden_item,
-                &output_weights,
-            );
+    // Compute the input projections for all steps up front.
+    let input_proj = InputProjection::new(
+        &gemm,
+        input.nd_view(),
+        weights.nd_view(),
+        bias.as_ref()
+            .map(|b| combine_bias(b.nd_view(), 1, None))
+            .as_ref()
+            .map(|b| b.view()),
+    );
 
-            // Compute new values of cell and hidden state
-            let mut cell_item = cell.slice_mut::<2, _>([dir]);
+    let run_dir = |dir| {
+        let weights = recurrent_gate_weights(&gemm, recurrent_weights, dir, 0..1, seq_len);
+        let act = activations
+            .get(dir)
+            .or(activations.first())
+            .copied()
+            .unwrap_or(RnnActivation::Tanh);
 
-            for (cell, forget_gate, input_gate, cell_gate) in zip4(
-                cell_item.iter_mut(),
-                forget_gate.iter(),
-                input_gate.iter(),
-                cell_gate.iter(),
-            ) {
-                *cell = forget_gate * *cell + input_gate * cell_gate;
-            }
+        let mut hidden = initial_state(initial_hidden.as_ref(), dir, batch, hidden_size);
+        let mut hidden_seq = NdTensor::zeros([seq_len, batch, hidden_size]);
+        let mut gate = NdTensor::zeros([batch, hidden_size]);
 
-            let mut hidden_item = hidden.slice_mut::<2, _>([dir]);
-            for (hidden, out_gate, cell) in
-                zip3(hidden_item.iter_mut(), out_gate.iter(), cell_item.iter())
-            {
-                *hidden = out_gate * cell.tanh()
+        for seq in sequence_for_dir(direction, dir, seq_len) {
+            // From the ONNX spec, the hidden state is computed as:
+            //
+            //   Ht = f(Xt*(Wi^T) + Ht-1*(Ri^T) + Wbi + Rbi)
+            //
+            // The `Xt*(Wi^T) + Wbi + Rbi` term comes from the input projections.
+            for (b, gate) in gate.data_mut().unwrap().chunks_mut(hidden_size).enumerate() {
+                gate.copy_from_slice(input_proj.get(seq, b, dir));
             }
+            add_matmul(
+                &gemm,
+                gate.view_mut(),
+                hidden.view(),
+                weights.as_gemm_input(),
+            );
+            act.apply_in_place(gate.data_mut().unwrap(), clip);
 
-            hidden_seq
-                .slice_mut::<2, _>([seq, dir])
-                .copy_from(&hidden_item);
+            std::mem::swap(&mut hidden, &mut gate);
+            hidden_seq.slice_mut::<2, _>([seq]).copy_from(&hidden);
         }
-    }
 
-    Ok([hidden_seq, hidden, cell].into())
+        (hidden_seq, [hidden])
+    };
+
+    Ok(run_directions(num_directions, run_dir))
 }
 
-impl Operator for LSTM {
+impl Operator for RNN {
     fn name(&self) -> &str {
-        "LSTM"
+        "RNN"
     }
 
     fn run(&self, inputs: InputList) -> Result<Vec<Output>, OpError> {
@@ -685,68 +1155,45 @@ impl Operator for LSTM {
         let bias = inputs.get_as(3)?;
         let _seq_len = inputs.get_as::<i32>(4)?;
         let initial_hidden = inputs.get_as(5)?;
-        let initial_cell = inputs.get_as(6)?;
 
-        lstm(
+        rnn(
             self.direction,
             input,
             weights,
             recurrent_weights,
             bias,
             initial_hidden,
-            initial_cell,
+            &self.activations,
+            self.clip,
         )
         .into_op_result()
     }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        rnn_flops(input_shapes)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(rnn_output_shapes(inputs, self.direction, 1, 1))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::error::Error;
-    use std::fs::File;
-    use std::io::BufReader;
 
     use rten_tensor::prelude::*;
     use rten_tensor::rng::XorShiftRng;
-    use rten_tensor::test_util::expect_equal;
+    use rten_tensor::test_util::{expect_equal, expect_equal_with_tolerance};
     use rten_tensor::Tensor;
     use serde_json::Value;
 
-    use crate::ops::{concat, gru, lstm, split, Direction};
-
-    /// Read a float tensor from a JSON value.
-    ///
-    /// The JSON value is expected to be of the form `[shape, data]` where
-    /// `shape` is an int array and `data` is a float array.
-    pub fn read_tensor(val: &Value) -> Result<Tensor<f32>, &'static str> {
-        let vec = match val {
-            Value::Array(vec) => vec,
-            _ => return Err("Expected array"),
-        };
-
-        let (shape, data) = match vec.as_slice() {
-            [Value::Array(shape), Value::Array(data)] => (shape, data),
-            _ => return Err("Expected [shape, data] array"),
-        };
-
-        let shape = shape
-            .iter()
-            .map(|v| v.as_i64().map(|v| v as usize).ok_or("Expected int array"))
-            .collect::<Result<Vec<usize>, _>>()?;
-
-        let data = data
-            .iter()
-            .map(|v| v.as_f64().map(|v| v as f32).ok_or("Expected float array"))
-            .collect::<Result<Vec<f32>, _>>()?;
-
-        Ok(Tensor::from_data(&shape, data))
-    }
-
-    pub fn read_json_file(path: &str) -> Value {
-        let file = File::open(path).unwrap();
-        let reader = BufReader::new(file);
-        serde_json::from_reader(reader).unwrap()
-    }
+    use super::sequence_for_dir;
+    use crate::ops::tests::{read_json_file, read_tensor};
+    use crate::ops::{
+        concat, gru, gru_with_clip, lstm, lstm_with_peepholes_and_clip, rnn, split, Direction,
+        OpError, RnnActivation,
+    };
 
     #[derive(Clone, Copy, PartialEq)]
     enum Op {
@@ -975,7 +1422,7 @@ mod tests {
     }
 
     /// Read inputs for a PyTorch reference test for RNN ops from a JSON value.
-    fn read_pytorch_ref_test(op: Op, case: &Value) -> RNNRefTest {
+    fn read_pytorch_ref_test(op: RefOp, case: &Value) -> RNNRefTest {
         let params = &case["params"];
 
         let is_bidirectional = params.get("weight_ih_l0_reverse").is_some();
@@ -994,15 +1441,13 @@ mod tests {
         }
         expected.insert_axis(2); // Add batch dim
 
-        let read_param = |name| match op {
-            Op::Lstm => reorder_ifco_to_iofc(
-                &read_tensor(&params[name]).expect("failed to read weight"),
-                0,
-            ),
-            Op::Gru => reorder_ruh_to_urh(
-                &read_tensor(&params[name]).expect("failed to read weight"),
-                0,
-            ),
+        let read_param = |name| {
+            let param = read_tensor(&params[name]).expect("failed to read weight");
+            match op {
+                RefOp::Lstm => reorder_ifco_to_iofc(&param, 0),
+                RefOp::Gru { .. } => reorder_ruh_to_urh(&param, 0),
+                RefOp::Rnn(_) => param,
+            }
         };
 
         let mut weights = read_param("weight_ih_l0");
@@ -1101,7 +1546,13 @@ mod tests {
             } else {
                 Op::Gru
             };
-            let data = read_pytorch_ref_test(op, &dict[case.name]);
+            let ref_op = match op {
+                Op::Lstm => RefOp::Lstm,
+                Op::Gru => RefOp::Gru {
+                    linear_before_reset: true,
+                },
+            };
+            let data = read_pytorch_ref_test(ref_op, &dict[case.name]);
             let result = match op {
                 Op::Lstm => lstm(
                     case.dir,
@@ -1132,5 +1583,691 @@ mod tests {
         Ok(())
     }
 
-    // TODO - Add tests for incorrect input shapes
+    #[test]
+    fn test_rnn_pytorch_vanilla() -> Result<(), Box<dyn Error>> {
+        let dict = read_json_file("pytorch-ref-tests/rnn.json");
+
+        struct Case {
+            name: &'static str,
+            dir: Direction,
+            activation: RnnActivation,
+        }
+
+        let cases = &[
+            Case {
+                name: "rnn_tanh_forwards",
+                dir: Direction::Forward,
+                activation: RnnActivation::Tanh,
+            },
+            Case {
+                name: "rnn_tanh_initial",
+                dir: Direction::Forward,
+                activation: RnnActivation::Tanh,
+            },
+            Case {
+                name: "rnn_tanh_bidirectional",
+                dir: Direction::Bidirectional,
+                activation: RnnActivation::Tanh,
+            },
+            Case {
+                name: "rnn_relu_forwards",
+                dir: Direction::Forward,
+                activation: RnnActivation::Relu,
+            },
+            Case {
+                name: "rnn_relu_bidirectional",
+                dir: Direction::Bidirectional,
+                activation: RnnActivation::Relu,
+            },
+        ];
+
+        for case in cases {
+            let activations = [case.activation; 2];
+            let data = read_pytorch_ref_test(RefOp::Rnn(activations), &dict[case.name]);
+            let result = rnn(
+                case.dir,
+                data.input.view(),
+                data.weights.view(),
+                data.hidden_weights.view(),
+                data.bias.as_ref().map(|b| b.view()),
+                data.initial_hidden.as_ref().map(|ih| ih.view()),
+                &activations[..case.dir.num_directions()],
+                None, /* clip */
+            )
+            .expect("RNN op failed");
+
+            expect_equal(&result[0], &data.expected)?;
+        }
+
+        Ok(())
+    }
+
+    /// Test GRU and LSTM features which PyTorch does not support, against
+    /// references generated with ONNX Runtime.
+    #[test]
+    fn test_rnn_onnxruntime() -> Result<(), Box<dyn Error>> {
+        let dict = read_json_file("pytorch-ref-tests/rnn_onnxruntime.json");
+
+        let cases = [
+            "gru_forwards",
+            "gru_bidirectional",
+            "gru_clip",
+            "lstm_peephole",
+            "lstm_clip",
+            "lstm_peephole_clip_bidirectional",
+        ];
+
+        for name in cases {
+            let case = &dict[name];
+            let inputs = &case["inputs"];
+            let input = read_tensor(&inputs["X"])?;
+            let weights = read_tensor(&inputs["W"])?;
+            let recurrent_weights = read_tensor(&inputs["R"])?;
+            let bias = inputs.get("B").map(read_tensor).transpose()?;
+            let peephole = inputs.get("P").map(read_tensor).transpose()?;
+            let expected = read_tensor(&case["output"])?;
+
+            let dir = match case["direction"].as_str() {
+                Some("forward") => Direction::Forward,
+                Some("reverse") => Direction::Reverse,
+                Some("bidirectional") => Direction::Bidirectional,
+                dir => panic!("unsupported direction {:?}", dir),
+            };
+            let clip = case["clip"].as_f64().map(|clip| clip as f32);
+
+            let result = match case["op"].as_str() {
+                Some("GRU") => gru_with_clip(
+                    dir,
+                    input.view(),
+                    weights.view(),
+                    recurrent_weights.view(),
+                    bias.as_ref().map(|b| b.view()),
+                    None, /* initial_hidden */
+                    case["linear_before_reset"].as_i64() == Some(1),
+                    clip,
+                )
+                .expect("GRU op failed"),
+                Some("LSTM") => lstm_with_peepholes_and_clip(
+                    dir,
+                    input.view(),
+                    weights.view(),
+                    recurrent_weights.view(),
+                    bias.as_ref().map(|b| b.view()),
+                    None, /* initial_hidden */
+                    None, /* initial_cell */
+                    peephole.as_ref().map(|p| p.view()),
+                    clip,
+                )
+                .expect("LSTM op failed"),
+                op => panic!("unsupported op {:?}", op),
+            };
+
+            expect_equal_with_tolerance(&result[0], &expected, 1e-5, 0.)
+                .map_err(|err| format!("case {} failed: {}", name, err))?;
+        }
+
+        Ok(())
+    }
+
+    /// Variant of a recurrent operator evaluated by [`reference_rnn`].
+    #[derive(Clone, Copy)]
+    enum RefOp {
+        /// Vanilla RNN with an activation for each direction.
+        Rnn([RnnActivation; 2]),
+        Gru {
+            linear_before_reset: bool,
+        },
+        Lstm,
+    }
+
+    impl RefOp {
+        fn num_gates(self) -> usize {
+            match self {
+                RefOp::Rnn(_) => 1,
+                RefOp::Gru { .. } => 3,
+                RefOp::Lstm => 4,
+            }
+        }
+    }
+
+    struct RefInputs {
+        /// Input as [seq, batch, feature]
+        input: Tensor,
+
+        /// Input-hidden weights as [direction, num_gates * hidden, feature]
+        weights: Tensor,
+
+        /// Hidden-hidden weights as [direction, num_gates * hidden, hidden]
+        recurrent_weights: Tensor,
+
+        /// Bias as [direction, 2 * num_gates * hidden]
+        bias: Tensor,
+
+        /// Peephole weights as [direction, 3 * hidden]. LSTM only.
+        peephole: Option<Tensor>,
+    }
+
+    fn random_ref_inputs(
+        rng: &mut XorShiftRng,
+        op: RefOp,
+        dir: Direction,
+        [seq_len, batch, features, hidden_size]: [usize; 4],
+    ) -> RefInputs {
+        let num_dirs = dir.num_directions();
+        let num_gates = op.num_gates();
+        let mut rand = |shape: &[usize]| Tensor::rand(shape, rng).map(|x| (x - 0.5) * 2.);
+
+        RefInputs {
+            input: rand(&[seq_len, batch, features]),
+            weights: rand(&[num_dirs, num_gates * hidden_size, features]),
+            recurrent_weights: rand(&[num_dirs, num_gates * hidden_size, hidden_size]),
+            bias: rand(&[num_dirs, 2 * num_gates * hidden_size]),
+            peephole: matches!(op, RefOp::Lstm).then(|| rand(&[num_dirs, 3 * hidden_size])),
+        }
+    }
+
+    /// Naive implementation of the ONNX RNN, GRU and LSTM operators, which
+    /// computes each element of each gate separately. Returns the hidden
+    /// sequence as [seq, direction, batch, hidden].
+    fn reference_rnn(op: RefOp, dir: Direction, inputs: &RefInputs, clip: Option<f32>) -> Tensor {
+        let [seq_len, batch, features]: [usize; 3] = inputs.input.shape().try_into().unwrap();
+        let hidden_size = inputs.recurrent_weights.size(2);
+        let num_gates = op.num_gates();
+
+        let clip = |x: f32| clip.map(|c| x.clamp(-c, c)).unwrap_or(x);
+        let sigmoid = |x: f32| 1. / (1. + (-clip(x)).exp());
+        let tanh = |x: f32| clip(x).tanh();
+
+        let mut hidden_seq = Tensor::zeros(&[seq_len, dir.num_directions(), batch, hidden_size]);
+
+        for d in 0..dir.num_directions() {
+            for b in 0..batch {
+                let mut h = vec![0.; hidden_size];
+                let mut c = vec![0.; hidden_size];
+
+                for t in sequence_for_dir(dir, d, seq_len) {
+                    // `Xt*(W^T) + Wb` for element `j` of gate `g`.
+                    let xw = |g: usize, j: usize| {
+                        let row = g * hidden_size + j;
+                        (0..features)
+                            .map(|k| inputs.input[[t, b, k]] * inputs.weights[[d, row, k]])
+                            .sum::<f32>()
+                            + inputs.bias[[d, row]]
+                    };
+                    // `H*(R^T) + Rb` for element `j` of gate `g`.
+                    let hr = |g: usize, j: usize, h: &[f32]| {
+                        let row = g * hidden_size + j;
+                        (0..hidden_size)
+                            .map(|k| h[k] * inputs.recurrent_weights[[d, row, k]])
+                            .sum::<f32>()
+                            + inputs.bias[[d, num_gates * hidden_size + row]]
+                    };
+                    let peephole = |g: usize, j: usize| {
+                        inputs
+                            .peephole
+                            .as_ref()
+                            .map(|p| p[[d, g * hidden_size + j]])
+                            .unwrap_or(0.)
+                    };
+
+                    h = match op {
+                        RefOp::Rnn(acts) => (0..hidden_size)
+                            .map(|j| acts[d].apply(clip(xw(0, j) + hr(0, j, &h))))
+                            .collect(),
+                        RefOp::Gru {
+                            linear_before_reset,
+                        } => {
+                            // Gates are in (update, reset, hidden) order.
+                            let z: Vec<f32> = (0..hidden_size)
+                                .map(|j| sigmoid(xw(0, j) + hr(0, j, &h)))
+                                .collect();
+                            let r: Vec<f32> = (0..hidden_size)
+                                .map(|j| sigmoid(xw(1, j) + hr(1, j, &h)))
+                                .collect();
+                            let reset_h: Vec<f32> = r.iter().zip(&h).map(|(r, h)| r * h).collect();
+                            (0..hidden_size)
+                                .map(|j| {
+                                    let ht = if linear_before_reset {
+                                        tanh(xw(2, j) + r[j] * hr(2, j, &h))
+                                    } else {
+                                        tanh(xw(2, j) + hr(2, j, &reset_h))
+                                    };
+                                    (1. - z[j]) * ht + z[j] * h[j]
+                                })
+                                .collect()
+                        }
+                        RefOp::Lstm => {
+                            // Gates are in (input, output, forget, cell) order.
+                            let i: Vec<f32> = (0..hidden_size)
+                                .map(|j| sigmoid(xw(0, j) + hr(0, j, &h) + peephole(0, j) * c[j]))
+                                .collect();
+                            let f: Vec<f32> = (0..hidden_size)
+                                .map(|j| sigmoid(xw(2, j) + hr(2, j, &h) + peephole(2, j) * c[j]))
+                                .collect();
+                            let cell_gate: Vec<f32> = (0..hidden_size)
+                                .map(|j| tanh(xw(3, j) + hr(3, j, &h)))
+                                .collect();
+                            c = (0..hidden_size)
+                                .map(|j| f[j] * c[j] + i[j] * cell_gate[j])
+                                .collect();
+                            (0..hidden_size)
+                                .map(|j| {
+                                    let o =
+                                        sigmoid(xw(1, j) + hr(1, j, &h) + peephole(1, j) * c[j]);
+                                    o * tanh(c[j])
+                                })
+                                .collect()
+                        }
+                    };
+
+                    for (j, h) in h.iter().enumerate() {
+                        hidden_seq[[t, d, b, j]] = *h;
+                    }
+                }
+            }
+        }
+
+        hidden_seq
+    }
+
+    const DIRECTIONS: [Direction; 3] = [
+        Direction::Forward,
+        Direction::Reverse,
+        Direction::Bidirectional,
+    ];
+
+    #[test]
+    fn test_gru_reset_gate_modes() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+
+        for linear_before_reset in [true, false] {
+            for dir in DIRECTIONS {
+                for clip in [None, Some(0.5)] {
+                    let op = RefOp::Gru {
+                        linear_before_reset,
+                    };
+                    let inputs = random_ref_inputs(&mut rng, op, dir, [6, 2, 3, 5]);
+                    let result = gru_with_clip(
+                        dir,
+                        inputs.input.view(),
+                        inputs.weights.view(),
+                        inputs.recurrent_weights.view(),
+                        Some(inputs.bias.view()),
+                        None, /* initial_hidden */
+                        linear_before_reset,
+                        clip,
+                    )
+                    .expect("gru op failed");
+                    let expected = reference_rnn(op, dir, &inputs, clip);
+                    expect_equal_with_tolerance(&result[0], &expected, 1e-5, 0.)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lstm_peephole_and_clip() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+
+        for dir in DIRECTIONS {
+            for with_peephole in [true, false] {
+                for clip in [None, Some(0.5)] {
+                    let mut inputs = random_ref_inputs(&mut rng, RefOp::Lstm, dir, [6, 2, 3, 5]);
+                    if !with_peephole {
+                        inputs.peephole = None;
+                    }
+                    let result = lstm_with_peepholes_and_clip(
+                        dir,
+                        inputs.input.view(),
+                        inputs.weights.view(),
+                        inputs.recurrent_weights.view(),
+                        Some(inputs.bias.view()),
+                        None, /* initial_hidden */
+                        None, /* initial_cell */
+                        inputs.peephole.as_ref().map(|p| p.view()),
+                        clip,
+                    )
+                    .expect("lstm op failed");
+                    let expected = reference_rnn(RefOp::Lstm, dir, &inputs, clip);
+                    expect_equal_with_tolerance(&result[0], &expected, 1e-5, 0.)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rnn() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+
+        struct Case {
+            dir: Direction,
+            activations: Vec<RnnActivation>,
+            clip: Option<f32>,
+        }
+
+        let cases = [
+            // Default activation
+            Case {
+                dir: Direction::Forward,
+                activations: Vec::new(),
+                clip: None,
+            },
+            Case {
+                dir: Direction::Reverse,
+                activations: [RnnActivation::Relu].into(),
+                clip: None,
+            },
+            // Single activation shared by both directions
+            Case {
+                dir: Direction::Bidirectional,
+                activations: [RnnActivation::Sigmoid].into(),
+                clip: None,
+            },
+            // Activation for each direction
+            Case {
+                dir: Direction::Bidirectional,
+                activations: [
+                    RnnActivation::LeakyRelu { alpha: 0.1 },
+                    RnnActivation::HardSigmoid {
+                        alpha: 0.2,
+                        beta: 0.5,
+                    },
+                ]
+                .into(),
+                clip: None,
+            },
+            Case {
+                dir: Direction::Forward,
+                activations: [RnnActivation::Tanh].into(),
+                clip: Some(0.3),
+            },
+        ];
+
+        for Case {
+            dir,
+            activations,
+            clip,
+        } in cases
+        {
+            let ref_acts = match activations.as_slice() {
+                [] => [RnnActivation::Tanh; 2],
+                [act] => [*act; 2],
+                [fwd, rev] => [*fwd, *rev],
+                _ => unreachable!(),
+            };
+            let op = RefOp::Rnn(ref_acts);
+            let inputs = random_ref_inputs(&mut rng, op, dir, [4, 2, 3, 5]);
+            let result = rnn(
+                dir,
+                inputs.input.view(),
+                inputs.weights.view(),
+                inputs.recurrent_weights.view(),
+                Some(inputs.bias.view()),
+                None, /* initial_hidden */
+                &activations,
+                clip,
+            )
+            .expect("rnn op failed");
+            let expected = reference_rnn(op, dir, &inputs, clip);
+            expect_equal_with_tolerance(&result[0], &expected, 1e-5, 0.)?;
+
+            // Last hidden state should match the final step in each direction.
+            let last_hidden = &result[1];
+            let seq_len = inputs.input.size(0);
+            for d in 0..dir.num_directions() {
+                let last_step = if d == 0 && !matches!(dir, Direction::Reverse) {
+                    seq_len - 1
+                } else {
+                    0
+                };
+                assert_eq!(
+                    last_hidden.slice::<2, _>([d]).to_vec(),
+                    result[0].slice::<2, _>([last_step, d]).to_vec()
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rnn_invalid_activations() {
+        let mut rng = XorShiftRng::new(1234);
+        let op = RefOp::Rnn([RnnActivation::Tanh; 2]);
+        let inputs = random_ref_inputs(&mut rng, op, Direction::Forward, [4, 2, 3, 5]);
+        let result = rnn(
+            Direction::Forward,
+            inputs.input.view(),
+            inputs.weights.view(),
+            inputs.recurrent_weights.view(),
+            None, /* bias */
+            None, /* initial_hidden */
+            &[RnnActivation::Tanh, RnnActivation::Relu],
+            None,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "activations length must match number of directions"
+            ))
+        );
+    }
+
+    #[test]
+    #[ignore]
+    fn bench_rnn_ops() {
+        use rten_bench::run_bench;
+
+        let mut rng = XorShiftRng::new(1234);
+
+        // Sizes similar to the bidirectional LSTM / GRU layers in OCR and
+        // speech recognition models.
+        let seq_len = 128;
+        let batch = 1;
+        let features = 256;
+        let hidden_size = 256;
+        let dir = Direction::Bidirectional;
+        let num_dirs = dir.num_directions();
+
+        let input = Tensor::rand(&[seq_len, batch, features], &mut rng);
+
+        for op in [Op::Lstm, Op::Gru] {
+            let (name, num_gates) = match op {
+                Op::Lstm => ("lstm", 4),
+                Op::Gru => ("gru", 3),
+            };
+            let weights = Tensor::rand(&[num_dirs, num_gates * hidden_size, features], &mut rng);
+            let recurrent_weights =
+                Tensor::rand(&[num_dirs, num_gates * hidden_size, hidden_size], &mut rng);
+            let bias = Tensor::rand(&[num_dirs, 2 * num_gates * hidden_size], &mut rng);
+
+            run_bench(20, Some(name), || match op {
+                Op::Lstm => {
+                    lstm(
+                        dir,
+                        input.view(),
+                        weights.view(),
+                        recurrent_weights.view(),
+                        Some(bias.view()),
+                        None, /* initial_hidden */
+                        None, /* initial_cell */
+                    )
+                    .unwrap();
+                }
+                Op::Gru => {
+                    gru(
+                        dir,
+                        input.view(),
+                        weights.view(),
+                        recurrent_weights.view(),
+                        Some(bias.view()),
+                        None, /* initial_hidden */
+                        true, /* linear_before_reset */
+                    )
+                    .unwrap();
+                }
+            });
+        }
+    }
+
+    #[test]
+    fn test_rnn_invalid_input_sizes() {
+        let mut rng = XorShiftRng::new(1234);
+        let [seq_len, batch, features, hidden_size] = [4, 2, 3, 5];
+
+        struct Case {
+            dir: Direction,
+            input: [usize; 3],
+            weights: [usize; 3],
+            recurrent_weights: [usize; 3],
+            bias: [usize; 2],
+            initial_hidden: [usize; 3],
+            expected: &'static str,
+        }
+
+        // Shapes of inputs for operators with a single gate. The gate
+        // dimensions are scaled by the number of gates of each operator.
+        let valid = Case {
+            dir: Direction::Forward,
+            input: [seq_len, batch, features],
+            weights: [1, hidden_size, features],
+            recurrent_weights: [1, hidden_size, hidden_size],
+            bias: [1, 2 * hidden_size],
+            initial_hidden: [1, batch, hidden_size],
+            expected: "",
+        };
+
+        let cases = [
+            Case {
+                dir: Direction::Bidirectional,
+                expected: "weights dim 0 must match number of directions",
+                ..valid
+            },
+            Case {
+                weights: [1, hidden_size, features + 1],
+                expected: "weights dim 2 must match input size",
+                ..valid
+            },
+            Case {
+                recurrent_weights: [2, hidden_size, hidden_size],
+                expected: "recurrent_weights must have shape [directions, num_gates * hidden_size, hidden_size]",
+                ..valid
+            },
+            Case {
+                recurrent_weights: [1, hidden_size, hidden_size + 1],
+                expected: "recurrent_weights must have shape [directions, num_gates * hidden_size, hidden_size]",
+                ..valid
+            },
+            Case {
+                bias: [1, hidden_size],
+                expected: "bias must have shape [directions, 2 * num_gates * hidden_size]",
+                ..valid
+            },
+            Case {
+                initial_hidden: [1, batch + 1, hidden_size],
+                expected: "initial states must have shape [directions, batch, hidden_size]",
+                ..valid
+            },
+        ];
+
+        for case in cases {
+            for op in [
+                RefOp::Rnn([RnnActivation::Tanh; 2]),
+                RefOp::Gru {
+                    linear_before_reset: true,
+                },
+                RefOp::Lstm,
+            ] {
+                let num_gates = op.num_gates();
+                let scale_gates = |[dir, gates, size]: [usize; 3]| [dir, num_gates * gates, size];
+                let input = Tensor::rand(&case.input, &mut rng);
+                let weights = Tensor::rand(&scale_gates(case.weights), &mut rng);
+                let recurrent_weights =
+                    Tensor::rand(&scale_gates(case.recurrent_weights), &mut rng);
+                let bias = Tensor::rand(&[case.bias[0], num_gates * case.bias[1]], &mut rng);
+                let initial_hidden = Tensor::rand(&case.initial_hidden, &mut rng);
+
+                let result = match op {
+                    RefOp::Rnn(activations) => rnn(
+                        case.dir,
+                        input.view(),
+                        weights.view(),
+                        recurrent_weights.view(),
+                        Some(bias.view()),
+                        Some(initial_hidden.view()),
+                        &activations[..1],
+                        None, /* clip */
+                    ),
+                    RefOp::Gru {
+                        linear_before_reset,
+                    } => gru(
+                        case.dir,
+                        input.view(),
+                        weights.view(),
+                        recurrent_weights.view(),
+                        Some(bias.view()),
+                        Some(initial_hidden.view()),
+                        linear_before_reset,
+                    ),
+                    RefOp::Lstm => lstm(
+                        case.dir,
+                        input.view(),
+                        weights.view(),
+                        recurrent_weights.view(),
+                        Some(bias.view()),
+                        Some(initial_hidden.view()),
+                        None, /* initial_cell */
+                    ),
+                };
+
+                assert_eq!(result.err(), Some(OpError::InvalidValue(case.expected)));
+            }
+        }
+
+        // Weights whose gate dim is not a multiple of the number of gates.
+        let input = Tensor::rand(&[seq_len, batch, features], &mut rng);
+        let weights = Tensor::rand(&[1, 3 * hidden_size + 1, features], &mut rng);
+        let recurrent_weights = Tensor::rand(&[1, 3 * hidden_size + 1, hidden_size], &mut rng);
+        let result = gru(
+            Direction::Forward,
+            input.view(),
+            weights.view(),
+            recurrent_weights.view(),
+            None, /* bias */
+            None, /* initial_hidden */
+            true, /* linear_before_reset */
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "weights dim 1 must be num_gates * hidden_size"
+            ))
+        );
+
+        // Peephole weights for the wrong number of directions.
+        let weights = Tensor::rand(&[1, 4 * hidden_size, features], &mut rng);
+        let recurrent_weights = Tensor::rand(&[1, 4 * hidden_size, hidden_size], &mut rng);
+        let peephole = Tensor::rand(&[2, 3 * hidden_size], &mut rng);
+        let result = lstm_with_peepholes_and_clip(
+            Direction::Forward,
+            input.view(),
+            weights.view(),
+            recurrent_weights.view(),
+            None, /* bias */
+            None, /* initial_hidden */
+            None, /* initial_cell */
+            Some(peephole.view()),
+            None, /* clip */
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "peephole must have shape [directions, 3 * hidden_size]"
+            ))
+        );
+    }
 }
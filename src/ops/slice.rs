@@ -3,7 +3,10 @@ use std::iter::zip;
 use rten_tensor::prelude::*;
 use rten_tensor::{NdTensorView, SliceItem, SliceRange, Tensor, TensorView};
 
-use crate::ops::{resolve_axis, Input, InputList, IntoOpResult, OpError, Operator, Output};
+use crate::ops::{
+    resolve_axis, Input, InputList, IntoOpResult, MetaInputList, MetaOutput, OpError, Operator,
+    Output,
+};
 use crate::static_dims;
 
 /// Compute the effective starts, ends and steps for each input dimension in
@@ -124,6 +127,7 @@ impl Operator for Slice {
             Input::IntTensor(input) => {
                 slice(input, &starts, &ends, axes.as_ref(), steps.as_ref()).map(|t| t.into())
             }
+            _ => return Err(OpError::IncorrectInputType),
         };
         result.into_op_result()
     }
@@ -168,8 +172,41 @@ impl Operator for Slice {
                 slice_in_place(&mut output, &starts, &ends, axes.as_ref())?;
                 Ok(output.into())
             }
+            _ => Err(OpError::IncorrectInputType),
         }
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        Some(0)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| {
+            let shape = inputs.require_shape(0)?;
+
+            let starts = inputs.require_value_as::<i32>(1)?;
+            let starts = static_dims!(starts, 1)?;
+
+            let ends = inputs.require_value_as::<i32>(2)?;
+            let ends = static_dims!(ends, 1)?;
+
+            let axes = inputs
+                .get_value_as::<i32>(3)?
+                .map(|axes| static_dims!(axes, 1))
+                .transpose()?;
+
+            let steps = inputs
+                .get_value_as::<i32>(4)?
+                .map(|steps| static_dims!(steps, 1))
+                .transpose()?;
+
+            let ranges = slice_ranges(shape, &starts, &ends, axes.as_ref(), steps.as_ref())?;
+            let out_shape: Vec<_> = zip(ranges, shape)
+                .map(|(range, &size)| range.steps(size))
+                .collect();
+            Ok(vec![out_shape.into()])
+        })())
+    }
 }
 
 #[cfg(test)]
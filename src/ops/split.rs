@@ -1,7 +1,7 @@
 use rten_tensor::prelude::*;
 use rten_tensor::{NdTensorView, SliceItem, Tensor, TensorView};
 
-use crate::ops::{resolve_axis, InputList, OpError, Operator, Output};
+use crate::ops::{resolve_axis, InputList, MetaInputList, MetaOutput, OpError, Operator, Output};
 use crate::static_dims;
 
 pub fn split<T: Copy>(
@@ -63,6 +63,31 @@ impl Operator for Split {
         split(input, self.axis, &splits)
             .map(|tensors| tensors.into_iter().map(|t| t.into()).collect())
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        Some(0)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some((|| {
+            let shape = inputs.require_shape(0)?;
+            let splits = inputs.require_value_as::<i32>(1)?;
+            let splits = static_dims!(splits, 1)?;
+            let axis = resolve_axis(shape.len(), self.axis)?;
+
+            if splits.iter().any(|size| *size < 0) {
+                return Err(OpError::InvalidValue("Split sizes must be >= 0"));
+            }
+            Ok(splits
+                .iter()
+                .map(|&split_size| {
+                    let mut out_shape = shape.to_vec();
+                    out_shape[axis] = split_size as usize;
+                    out_shape.into()
+                })
+                .collect())
+        })())
+    }
 }
 
 #[cfg(test)]
@@ -1,7 +1,10 @@
 use rten_tensor::prelude::*;
 use rten_tensor::{Tensor, TensorView};
 
-use crate::ops::{Input, InputList, IntoOpResult, OpError, Operator, Output};
+use crate::ops::{
+    same_shape_as_input, Input, InputList, IntoOpResult, MetaInputList, MetaOutput, OpError,
+    Operator, Output,
+};
 
 pub fn trilu<T: Copy + Default>(
     input: TensorView<T>,
@@ -48,8 +51,17 @@ impl Operator for Trilu {
         match input {
             Input::FloatTensor(input) => trilu(input, k, self.upper).into_op_result(),
             Input::IntTensor(input) => trilu(input, k, self.upper).into_op_result(),
+            _ => Err(OpError::IncorrectInputType),
         }
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], _: &[&[usize]]) -> Option<u64> {
+        Some(0)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(same_shape_as_input(inputs))
+    }
 }
 
 #[cfg(test)]
@@ -7,13 +7,19 @@ use std::fmt::Debug;
 use rten_tensor::prelude::*;
 use rten_tensor::{Tensor, TensorView, TensorViewMut};
 use rten_vecmath::{
-    erf as erf_scalar, exp as exp_scalar, sigmoid as sigmoid_scalar, tanh as tanh_scalar, vec_erf,
-    vec_erf_in_place, vec_exp, vec_exp_in_place, vec_sigmoid, vec_sigmoid_in_place, vec_tanh,
+    approx_gelu as approx_gelu_scalar, erf as erf_scalar, exp as exp_scalar, gelu as gelu_scalar,
+    mish as mish_scalar, sigmoid as sigmoid_scalar, softsign as softsign_scalar,
+    tanh as tanh_scalar, vec_approx_gelu, vec_approx_gelu_in_place, vec_erf, vec_erf_in_place,
+    vec_exp, vec_exp_in_place, vec_gelu, vec_gelu_in_place, vec_mish, vec_mish_in_place,
+    vec_sigmoid, vec_sigmoid_in_place, vec_softsign, vec_softsign_in_place, vec_tanh,
     vec_tanh_in_place,
 };
 
 use crate::number::AsBool;
-use crate::ops::{Input, InputList, IntoOpResult, OpError, Operator, Output};
+use crate::ops::{
+    elementwise_flops, same_shape_as_input, Input, InputList, IntoOpResult, MetaInputList,
+    MetaOutput, OpError, Operator, Output,
+};
 
 /// Trait for operators which take a single float tensor and apply a function
 /// to each element.
@@ -53,6 +59,14 @@ impl<Op: UnaryFloatOp + Debug> Operator for Op {
         self.apply(output.view_mut());
         Ok(output.into())
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], output_shapes: &[&[usize]]) -> Option<u64> {
+        elementwise_flops(output_shapes)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(same_shape_as_input(inputs))
+    }
 }
 
 /// Define a unary operator, with no arguments, which supports all numeric
@@ -75,6 +89,7 @@ macro_rules! unary_numeric_op {
                 match input {
                     Input::FloatTensor(input) => $view_impl(input).into_op_result(),
                     Input::IntTensor(input) => $view_impl(input).into_op_result(),
+                    _ => Err(OpError::IncorrectInputType),
                 }
             }
 
@@ -92,20 +107,41 @@ macro_rules! unary_numeric_op {
                         $mut_impl(input.view_mut());
                         Ok(input.into())
                     }
+                    _ => Err(OpError::IncorrectInputType),
                 }
             }
+
+            fn flops(&self, _: &[Option<&[usize]>], output_shapes: &[&[usize]]) -> Option<u64> {
+                elementwise_flops(output_shapes)
+            }
+
+            fn infer_shapes(
+                &self,
+                inputs: &MetaInputList,
+            ) -> Option<Result<Vec<MetaOutput>, OpError>> {
+                Some(same_shape_as_input(inputs))
+            }
         }
     };
 }
 
 macro_rules! unary_float_funcs {
     ($name:ident, $func_name:ident, $in_place_func_name:ident) => {
-        pub fn $func_name(input: TensorView) -> Tensor {
-            $name {}.map(input)
+        unary_float_funcs!($name {}, $func_name, $in_place_func_name);
+    };
+
+    // Operator with fields, which become arguments of the functions.
+    (
+        $name:ident { $($field:ident: $type:ty),* },
+        $func_name:ident,
+        $in_place_func_name:ident
+    ) => {
+        pub fn $func_name(input: TensorView, $($field: $type),*) -> Tensor {
+            $name { $($field),* }.map(input)
         }
 
-        pub fn $in_place_func_name(input: TensorViewMut) {
-            $name {}.apply(input)
+        pub fn $in_place_func_name(input: TensorViewMut, $($field: $type),*) {
+            $name { $($field),* }.apply(input)
         }
     };
 }
@@ -194,6 +230,17 @@ macro_rules! parallel_unary_float_op {
                 $in_place_func_name(tensor.view_mut());
                 Ok(tensor.into())
             }
+
+            fn flops(&self, _: &[Option<&[usize]>], output_shapes: &[&[usize]]) -> Option<u64> {
+                elementwise_flops(output_shapes)
+            }
+
+            fn infer_shapes(
+                &self,
+                inputs: &MetaInputList,
+            ) -> Option<Result<Vec<MetaOutput>, OpError>> {
+                Some(same_shape_as_input(inputs))
+            }
         }
 
         pub fn $func_name(input: TensorView) -> Tensor {
@@ -206,6 +253,71 @@ macro_rules! parallel_unary_float_op {
     };
 }
 
+/// Trait for operators which take a single float tensor and compute each
+/// output element from an input element `x` and `exp(exp_arg(x))`.
+///
+/// These operators are vectorized by evaluating the exponential for blocks of
+/// elements using [vec_exp_in_place].
+pub trait UnaryExpOp {
+    /// Return the argument to `exp` for an element.
+    fn exp_arg(&self, val: f32) -> f32;
+
+    /// Compute the output for an element, given `exp(self.exp_arg(val))`.
+    fn map_element_with_exp(&self, val: f32, exp: f32) -> f32;
+}
+
+/// Number of elements in each block for which [UnaryExpOp] operators evaluate
+/// the exponential together.
+const EXP_BLOCK_SIZE: usize = 256;
+
+/// Apply a [UnaryExpOp] operator to all elements in `input`.
+fn apply_unary_exp_op<Op: UnaryExpOp + Sync>(op: &Op, mut input: TensorViewMut) {
+    let map_element = |val: f32| op.map_element_with_exp(val, exp_scalar(op.exp_arg(val)));
+    let Some(data) = input.data_mut() else {
+        input.apply(|val| map_element(*val));
+        return;
+    };
+
+    data.par_chunks_mut(CHUNK_SIZE).for_each(|chunk| {
+        let mut exp_buf = [0.; EXP_BLOCK_SIZE];
+        for block in chunk.chunks_mut(EXP_BLOCK_SIZE) {
+            let exp_buf = &mut exp_buf[..block.len()];
+            for (exp, val) in exp_buf.iter_mut().zip(block.iter()) {
+                *exp = op.exp_arg(*val);
+            }
+            vec_exp_in_place(exp_buf);
+            for (val, exp) in block.iter_mut().zip(exp_buf.iter()) {
+                *val = op.map_element_with_exp(*val, *exp);
+            }
+        }
+    });
+}
+
+/// Implement [UnaryFloatOp] for an operator which implements [UnaryExpOp].
+macro_rules! unary_exp_float_op {
+    ($name:ident) => {
+        impl UnaryFloatOp for $name {
+            fn name(&self) -> &str {
+                stringify!($name)
+            }
+
+            fn map_element(&self, val: f32) -> f32 {
+                self.map_element_with_exp(val, exp_scalar(self.exp_arg(val)))
+            }
+
+            fn map(&self, input: TensorView) -> Tensor {
+                let mut output = input.to_tensor();
+                self.apply(output.view_mut());
+                output
+            }
+
+            fn apply(&self, input: TensorViewMut) {
+                apply_unary_exp_op(self, input)
+            }
+        }
+    };
+}
+
 pub trait AbsValue {
     fn abs(&self) -> Self;
 }
@@ -236,6 +348,28 @@ unary_float_op!(Asin, asin, asin_in_place, |val: f32| val.asin());
 unary_float_op!(Atan, atan, atan_in_place, |val: f32| val.atan());
 unary_float_op!(Ceil, ceil, ceil_in_place, |val: f32| val.ceil());
 
+unary_float_funcs!(Celu { alpha: f32 }, celu, celu_in_place);
+
+/// Continuously differentiable exponential linear unit.
+///
+/// Computes `max(0, x) + min(0, alpha * (exp(x / alpha) - 1))`.
+#[derive(Debug)]
+pub struct Celu {
+    pub alpha: f32,
+}
+
+impl UnaryExpOp for Celu {
+    fn exp_arg(&self, val: f32) -> f32 {
+        val.min(0.) / self.alpha
+    }
+
+    fn map_element_with_exp(&self, val: f32, exp: f32) -> f32 {
+        val.max(0.) + (self.alpha * (exp - 1.)).min(0.)
+    }
+}
+
+unary_exp_float_op!(Celu);
+
 /// Numeric value with a finite minimum and maximum and operations to clamp
 /// values.
 pub trait Clamp: Copy + PartialOrd {
@@ -325,6 +459,7 @@ impl Operator for Clip {
                 let max = inputs.get_as_scalar(2)?;
                 clip(input, min, max).into_op_result()
             }
+            _ => Err(OpError::IncorrectInputType),
         }
     }
 
@@ -346,11 +481,46 @@ impl Operator for Clip {
                 clip_in_place(&mut input, min, max);
                 Ok(input.into())
             }
+            _ => Err(OpError::IncorrectInputType),
         }
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], output_shapes: &[&[usize]]) -> Option<u64> {
+        elementwise_flops(output_shapes)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(same_shape_as_input(inputs))
+    }
 }
 
 unary_float_op!(Cos, cos, cos_in_place, |val: f32| val.cos());
+
+unary_float_funcs!(Elu { alpha: f32 }, elu, elu_in_place);
+
+/// Exponential linear unit. Computes `alpha * (exp(x) - 1)` for negative
+/// inputs and `x` otherwise.
+#[derive(Debug)]
+pub struct Elu {
+    pub alpha: f32,
+}
+
+impl UnaryExpOp for Elu {
+    fn exp_arg(&self, val: f32) -> f32 {
+        val.min(0.)
+    }
+
+    fn map_element_with_exp(&self, val: f32, exp: f32) -> f32 {
+        if val < 0. {
+            self.alpha * (exp - 1.)
+        } else {
+            val
+        }
+    }
+}
+
+unary_exp_float_op!(Elu);
+
 parallel_unary_float_op!(
     Erf,
     erf,
@@ -369,6 +539,47 @@ parallel_unary_float_op!(
 );
 unary_float_op!(Floor, floor, floor_in_place, |val: f32| val.floor());
 
+unary_float_funcs!(Gelu { approximate: bool }, gelu, gelu_in_place);
+
+/// Gaussian error linear unit.
+///
+/// Computes `0.5 * x * (1 + erf(x / sqrt(2)))`, or an approximation using tanh
+/// if `approximate` is true.
+#[derive(Debug)]
+pub struct Gelu {
+    pub approximate: bool,
+}
+
+impl UnaryFloatOp for Gelu {
+    fn name(&self) -> &str {
+        "Gelu"
+    }
+
+    fn map_element(&self, val: f32) -> f32 {
+        if self.approximate {
+            approx_gelu_scalar(val)
+        } else {
+            gelu_scalar(val)
+        }
+    }
+
+    fn map(&self, input: TensorView) -> Tensor {
+        if self.approximate {
+            par_unary_op(input, vec_approx_gelu)
+        } else {
+            par_unary_op(input, vec_gelu)
+        }
+    }
+
+    fn apply(&self, input: TensorViewMut) {
+        if self.approximate {
+            par_unary_op_in_place(input, vec_approx_gelu_in_place, approx_gelu_scalar);
+        } else {
+            par_unary_op_in_place(input, vec_gelu_in_place, gelu_scalar);
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct HardSigmoid {
     pub alpha: f32,
@@ -385,13 +596,14 @@ impl UnaryFloatOp for HardSigmoid {
     }
 }
 
-pub fn hard_sigmoid(input: TensorView, alpha: f32, beta: f32) -> Tensor {
-    HardSigmoid { alpha, beta }.map(input)
-}
-
-pub fn hard_sigmoid_in_place(input: TensorViewMut, alpha: f32, beta: f32) {
-    HardSigmoid { alpha, beta }.apply(input)
-}
+unary_float_funcs!(
+    HardSigmoid {
+        alpha: f32,
+        beta: f32
+    },
+    hard_sigmoid,
+    hard_sigmoid_in_place
+);
 
 #[derive(Debug)]
 pub struct HardSwish {}
@@ -410,13 +622,7 @@ impl UnaryFloatOp for HardSwish {
 
 unary_float_funcs!(HardSwish, hard_swish, hard_swish_in_place);
 
-pub fn leaky_relu(input: TensorView, alpha: f32) -> Tensor {
-    LeakyRelu { alpha }.map(input)
-}
-
-pub fn leaky_relu_in_place(input: TensorViewMut, alpha: f32) {
-    LeakyRelu { alpha }.apply(input)
-}
+unary_float_funcs!(LeakyRelu { alpha: f32 }, leaky_relu, leaky_relu_in_place);
 
 #[derive(Debug)]
 pub struct LeakyRelu {
@@ -439,6 +645,15 @@ impl UnaryFloatOp for LeakyRelu {
 
 unary_float_op!(Log, log, log_in_place, |val: f32| val.ln());
 
+parallel_unary_float_op!(
+    Mish,
+    mish,
+    mish_in_place,
+    vec_mish,
+    vec_mish_in_place,
+    mish_scalar
+);
+
 pub fn neg<T: Copy + std::ops::Neg<Output = T>>(input: TensorView<T>) -> Tensor<T> {
     input.map(|x| x.neg())
 }
@@ -479,6 +694,14 @@ impl Operator for Not {
         not_in_place(output.view_mut());
         Ok(output.into())
     }
+
+    fn flops(&self, _: &[Option<&[usize]>], output_shapes: &[&[usize]]) -> Option<u64> {
+        elementwise_flops(output_shapes)
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(same_shape_as_input(inputs))
+    }
 }
 
 unary_float_op!(Reciprocal, reciprocal, reciprocal_in_place, |val: f32| 1.
@@ -509,6 +732,39 @@ pub fn round_in_place(x: TensorViewMut) {
     Round {}.apply(x)
 }
 
+unary_float_funcs!(
+    Selu {
+        alpha: f32,
+        gamma: f32
+    },
+    selu,
+    selu_in_place
+);
+
+/// Scaled exponential linear unit. Computes `gamma * alpha * (exp(x) - 1)`
+/// for inputs `<= 0` and `gamma * x` otherwise.
+#[derive(Debug)]
+pub struct Selu {
+    pub alpha: f32,
+    pub gamma: f32,
+}
+
+impl UnaryExpOp for Selu {
+    fn exp_arg(&self, val: f32) -> f32 {
+        val.min(0.)
+    }
+
+    fn map_element_with_exp(&self, val: f32, exp: f32) -> f32 {
+        if val <= 0. {
+            self.gamma * self.alpha * (exp - 1.)
+        } else {
+            self.gamma * val
+        }
+    }
+}
+
+unary_exp_float_op!(Selu);
+
 parallel_unary_float_op!(
     Sigmoid,
     sigmoid,
@@ -549,6 +805,34 @@ pub fn sign_in_place<T: Signum>(mut input: TensorViewMut<T>) {
 }
 
 unary_numeric_op!(Sign, sign, sign_in_place);
+
+/// Computes `ln(1 + exp(x))`.
+#[derive(Debug)]
+pub struct Softplus {}
+
+impl UnaryExpOp for Softplus {
+    // Use `ln(1 + exp(x)) = max(x, 0) + ln(1 + exp(-|x|))` to avoid overflow
+    // for large `x`.
+    fn exp_arg(&self, val: f32) -> f32 {
+        -val.abs()
+    }
+
+    fn map_element_with_exp(&self, val: f32, exp: f32) -> f32 {
+        val.max(0.) + exp.ln_1p()
+    }
+}
+
+unary_exp_float_op!(Softplus);
+
+unary_float_funcs!(Softplus, softplus, softplus_in_place);
+parallel_unary_float_op!(
+    Softsign,
+    softsign,
+    softsign_in_place,
+    vec_softsign,
+    vec_softsign_in_place,
+    softsign_scalar
+);
 unary_float_op!(Sqrt, sqrt, sqrt_in_place, |val: f32| val.sqrt());
 unary_float_op!(Tan, tan, tan_in_place, |val: f32| val.tan());
 parallel_unary_float_op!(
@@ -560,6 +844,55 @@ parallel_unary_float_op!(
     tanh_scalar
 );
 
+unary_float_funcs!(
+    ThresholdedRelu { alpha: f32 },
+    thresholded_relu,
+    thresholded_relu_in_place
+);
+
+/// Computes `x` for inputs greater than `alpha` and zero otherwise.
+#[derive(Debug)]
+pub struct ThresholdedRelu {
+    pub alpha: f32,
+}
+
+impl UnaryFloatOp for ThresholdedRelu {
+    fn name(&self) -> &str {
+        "ThresholdedRelu"
+    }
+
+    fn map_element(&self, val: f32) -> f32 {
+        if val > self.alpha {
+            val
+        } else {
+            0.
+        }
+    }
+
+    // `alpha` is a runtime parameter, so this can't use the `rten_vecmath`
+    // kernels. Instead contiguous chunks are processed in parallel using a
+    // compare-and-select loop, which the compiler auto-vectorizes.
+    fn map(&self, input: TensorView) -> Tensor {
+        par_unary_op(input, |xs, out| {
+            for (x, y) in xs.iter().zip(out.iter_mut()) {
+                *y = self.map_element(*x);
+            }
+        })
+    }
+
+    fn apply(&self, input: TensorViewMut) {
+        par_unary_op_in_place(
+            input,
+            |xs| {
+                for x in xs.iter_mut() {
+                    *x = self.map_element(*x);
+                }
+            },
+            |x| self.map_element(x),
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::error::Error;
@@ -567,15 +900,17 @@ mod tests {
     use rten_tensor::prelude::*;
     use rten_tensor::rng::XorShiftRng;
     use rten_tensor::test_util::{eq_with_nans, expect_equal, expect_equal_with_tolerance};
-    use rten_tensor::{tensor, RandomSource, Tensor};
+    use rten_tensor::{tensor, RandomSource, Tensor, TensorView, TensorViewMut};
 
     use crate::ops::{
-        abs, acos, acos_in_place, asin, asin_in_place, atan, atan_in_place, ceil, clip,
-        clip_in_place, cos, cos_in_place, erf, erf_in_place, exp, exp_in_place, floor,
-        hard_sigmoid, hard_swish, leaky_relu, leaky_relu_in_place, log, log_in_place, neg,
-        neg_in_place, not, not_in_place, reciprocal, relu, relu_in_place, round, round_in_place,
-        sigmoid, sigmoid_in_place, sign, sign_in_place, sin, sin_in_place, sqrt, sqrt_in_place,
-        tan, tan_in_place, tanh, tanh_in_place,
+        abs, acos, acos_in_place, asin, asin_in_place, atan, atan_in_place, ceil, celu,
+        celu_in_place, clip, clip_in_place, cos, cos_in_place, elu, elu_in_place, erf,
+        erf_in_place, exp, exp_in_place, floor, gelu, gelu_in_place, hard_sigmoid, hard_swish,
+        leaky_relu, leaky_relu_in_place, log, log_in_place, mish, mish_in_place, neg, neg_in_place,
+        not, not_in_place, reciprocal, relu, relu_in_place, round, round_in_place, selu,
+        selu_in_place, sigmoid, sigmoid_in_place, sign, sign_in_place, sin, sin_in_place, softplus,
+        softplus_in_place, softsign, softsign_in_place, sqrt, sqrt_in_place, tan, tan_in_place,
+        tanh, tanh_in_place, thresholded_relu, thresholded_relu_in_place,
     };
 
     /// Define a test for a simple unary operator which applies the function
@@ -634,6 +969,36 @@ mod tests {
         }
     }
 
+    /// Test the copying and in-place variants of an activation function
+    /// against a reference implementation, using contiguous inputs that
+    /// span multiple blocks and non-contiguous inputs.
+    fn check_activation(
+        op: impl Fn(TensorView) -> Tensor,
+        op_in_place: impl Fn(TensorViewMut),
+        reference: impl Fn(f32) -> f32,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut rng = RandomFloat::new(1234).with_range(-10., 10.);
+        let input = Tensor::rand(&[30, 40], &mut rng);
+        let expected = input.map(|x| reference(*x));
+
+        // The reference implementations can lose precision for outputs near
+        // zero, so use an absolute tolerance.
+        let (atol, rtol) = (1e-5, 1e-5);
+
+        let result = op(input.view());
+        expect_equal_with_tolerance(&result, &expected, atol, rtol)?;
+
+        let mut result = input.clone();
+        op_in_place(result.view_mut());
+        expect_equal_with_tolerance(&result, &expected, atol, rtol)?;
+
+        let mut result = input.clone();
+        op_in_place(result.permuted_mut(&[1, 0]));
+        expect_equal_with_tolerance(&result, &expected, atol, rtol)?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_abs() {
         // Float tensor
@@ -722,8 +1087,28 @@ mod tests {
     // TODO: Eliminate the duplication for tests that apply the operator
     // in-place vs returning a new tensor.
 
+    #[test]
+    fn test_celu() -> Result<(), Box<dyn Error>> {
+        let alpha = 0.5;
+        check_activation(
+            |x| celu(x, alpha),
+            |x| celu_in_place(x, alpha),
+            |x| x.max(0.) + (alpha * ((x / alpha).exp() - 1.)).min(0.),
+        )
+    }
+
     test_unary_op!(test_cos, cos, cos_in_place, |x: &f32| x.cos());
 
+    #[test]
+    fn test_elu() -> Result<(), Box<dyn Error>> {
+        let alpha = 0.7;
+        check_activation(
+            |x| elu(x, alpha),
+            |x| elu_in_place(x, alpha),
+            |x| if x < 0. { alpha * (x.exp() - 1.) } else { x },
+        )
+    }
+
     #[test]
     fn test_erf() -> Result<(), Box<dyn Error>> {
         let input = tensor!([-2.0, -0.5, 0.5, 2.0]);
@@ -829,6 +1214,22 @@ mod tests {
         assert!(eq_with_nans(result.view(), expected.view()));
     }
 
+    #[test]
+    fn test_gelu() -> Result<(), Box<dyn Error>> {
+        check_activation(
+            |x| gelu(x, false /* approximate */),
+            |x| gelu_in_place(x, false /* approximate */),
+            |x| 0.5 * x * (1. + libm::erff(x / 2f32.sqrt())),
+        )?;
+
+        let sqrt_2_pi = (2. / std::f32::consts::PI).sqrt();
+        check_activation(
+            |x| gelu(x, true /* approximate */),
+            |x| gelu_in_place(x, true /* approximate */),
+            |x| 0.5 * x * (1. + (sqrt_2_pi * (x + 0.044715 * x.powi(3))).tanh()),
+        )
+    }
+
     #[test]
     fn test_hard_sigmoid() -> Result<(), Box<dyn Error>> {
         let input = tensor!([-4., -3., -1., 0., 1., 3., 4.]);
@@ -897,6 +1298,11 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_mish() -> Result<(), Box<dyn Error>> {
+        check_activation(mish, mish_in_place, |x| x * x.exp().ln_1p().tanh())
+    }
+
     #[test]
     fn test_neg() {
         let input = tensor!([0, 1, -1, 2]);
@@ -971,6 +1377,23 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_selu() -> Result<(), Box<dyn Error>> {
+        let alpha = 1.6732632;
+        let gamma = 1.050701;
+        check_activation(
+            |x| selu(x, alpha, gamma),
+            |x| selu_in_place(x, alpha, gamma),
+            |x| {
+                if x <= 0. {
+                    gamma * (alpha * x.exp() - alpha)
+                } else {
+                    gamma * x
+                }
+            },
+        )
+    }
+
     #[test]
     fn test_sigmoid() -> Result<(), Box<dyn Error>> {
         let input: Tensor<f32> = Tensor::from_data(
@@ -992,6 +1415,21 @@ mod tests {
     test_unary_op!(test_sign, sign, sign_in_place, |x: &f32| x.signum());
     test_unary_op!(test_sin, sin, sin_in_place, |x: &f32| x.sin());
 
+    #[test]
+    fn test_softplus() -> Result<(), Box<dyn Error>> {
+        check_activation(softplus, softplus_in_place, |x| x.exp().ln_1p())?;
+
+        // Large inputs, for which `exp(x)` overflows.
+        let input = tensor!([-200., 200.]);
+        let result = softplus(input.view());
+        expect_equal(&result, &tensor!([0., 200.]))?;
+
+        Ok(())
+    }
+
+    test_unary_op!(test_softsign, softsign, softsign_in_place, |x: &f32| x
+        / (1. + x.abs()));
+
     #[test]
     fn test_sqrt() -> Result<(), Box<dyn Error>> {
         let input = tensor!([4., 9., 16.]);
@@ -1012,4 +1450,14 @@ mod tests {
 
     test_unary_op!(test_tan, tan, tan_in_place, |x: &f32| x.tan());
     test_unary_op!(test_tanh, tanh, tanh_in_place, |x: &f32| x.tanh());
+
+    #[test]
+    fn test_thresholded_relu() -> Result<(), Box<dyn Error>> {
+        let alpha = 2.;
+        check_activation(
+            |x| thresholded_relu(x, alpha),
+            |x| thresholded_relu_in_place(x, alpha),
+            |x| if x > alpha { x } else { 0. },
+        )
+    }
 }
@@ -3,9 +3,12 @@ use std::iter::zip;
 use rten_tensor::prelude::*;
 use rten_tensor::{Tensor, TensorView};
 
-use crate::ops::binary_elementwise::broadcast_shapes;
+use crate::ops::binary_elementwise::{broadcast_infer_shapes, broadcast_shapes};
 use crate::ops::reduce::{cmp_nan_greater, cmp_nan_less};
-use crate::ops::{Input, InputList, IntoOpResult, OpError, Operator, Output};
+use crate::ops::{
+    num_elements, Input, InputList, IntoOpResult, MetaInputList, MetaOutput, OpError, Operator,
+    Output,
+};
 
 /// Apply an elementwise reduction to a sequence of tensors.
 ///
@@ -21,7 +24,8 @@ fn reduce_elementwise<T: Copy + Default, R: Fn(&[T]) -> T>(
             let Some(out_shape) = broadcast_shapes(a.shape(), b.shape()) else {
                 return Err(OpError::IncompatibleInputShapes(
                     "Cannot broadcast inputs to same shape",
-                ));
+                )
+                .with_details(format!("{:?} and {:?}", a.shape(), b.shape())));
             };
 
             let mut result = Tensor::zeros(&out_shape);
@@ -40,9 +44,11 @@ fn reduce_elementwise<T: Copy + Default, R: Fn(&[T]) -> T>(
                     broadcast_shapes(&out_shape, input.shape())
                 })
             else {
+                let shapes: Vec<_> = inputs.iter().map(|input| input.shape()).collect();
                 return Err(OpError::IncompatibleInputShapes(
                     "Cannot broadcast inputs to same shape",
-                ));
+                )
+                .with_details(format!("{:?}", shapes)));
             };
 
             let mut iters: Vec<_> = inputs
@@ -95,6 +101,7 @@ macro_rules! run_typed_op {
                 let inputs: Vec<TensorView<i32>> = typed_views(&$inputs)?;
                 max(&inputs).into_op_result()
             }
+            _ => Err(OpError::IncorrectInputType),
         }
     }};
 }
@@ -110,6 +117,15 @@ impl Operator for Max {
     fn run(&self, inputs: InputList) -> Result<Vec<Output>, OpError> {
         run_typed_op!(inputs)
     }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], output_shapes: &[&[usize]]) -> Option<u64> {
+        let n_inputs = input_shapes.len() as u64;
+        Some(num_elements(output_shapes.first()?) * n_inputs.saturating_sub(1))
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(broadcast_infer_shapes(inputs))
+    }
 }
 
 pub fn mean(inputs: &[TensorView]) -> Result<Tensor, OpError> {
@@ -130,6 +146,15 @@ impl Operator for Mean {
         let inputs: Vec<TensorView<f32>> = typed_views(&inputs)?;
         mean(&inputs).into_op_result()
     }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], output_shapes: &[&[usize]]) -> Option<u64> {
+        let n_inputs = input_shapes.len() as u64;
+        Some(num_elements(output_shapes.first()?) * n_inputs.saturating_sub(1))
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(broadcast_infer_shapes(inputs))
+    }
 }
 
 pub fn min<T: Copy + Default + PartialOrd>(inputs: &[TensorView<T>]) -> Result<Tensor<T>, OpError> {
@@ -152,6 +177,15 @@ impl Operator for Min {
     fn run(&self, inputs: InputList) -> Result<Vec<Output>, OpError> {
         run_typed_op!(inputs)
     }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], output_shapes: &[&[usize]]) -> Option<u64> {
+        let n_inputs = input_shapes.len() as u64;
+        Some(num_elements(output_shapes.first()?) * n_inputs.saturating_sub(1))
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(broadcast_infer_shapes(inputs))
+    }
 }
 
 pub fn sum<T: Copy + Default + std::iter::Sum>(
@@ -171,6 +205,15 @@ impl Operator for Sum {
     fn run(&self, inputs: InputList) -> Result<Vec<Output>, OpError> {
         run_typed_op!(inputs)
     }
+
+    fn flops(&self, input_shapes: &[Option<&[usize]>], output_shapes: &[&[usize]]) -> Option<u64> {
+        let n_inputs = input_shapes.len() as u64;
+        Some(num_elements(output_shapes.first()?) * n_inputs.saturating_sub(1))
+    }
+
+    fn infer_shapes(&self, inputs: &MetaInputList) -> Option<Result<Vec<MetaOutput>, OpError>> {
+        Some(broadcast_infer_shapes(inputs))
+    }
 }
 
 #[cfg(test)]
@@ -241,7 +284,8 @@ mod tests {
                 inputs: vec![tensor!([4., 5., 6.]), tensor!((2, 2); [1., 2., 3., 4.])],
                 expected: Err(OpError::IncompatibleInputShapes(
                     "Cannot broadcast inputs to same shape",
-                )),
+                )
+                .with_details("[3] and [2, 2]")),
             },
             // Three inputs, incompatible broadcast
             Case {
@@ -252,7 +296,8 @@ mod tests {
                 ],
                 expected: Err(OpError::IncompatibleInputShapes(
                     "Cannot broadcast inputs to same shape",
-                )),
+                )
+                .with_details("[[3], [], [2, 2]]")),
             },
         ];
 
@@ -18,13 +18,13 @@ pub const ENUM_MIN_OPERATOR_TYPE: u8 = 0;
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_OPERATOR_TYPE: u8 = 95;
+pub const ENUM_MAX_OPERATOR_TYPE: u8 = 112;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_OPERATOR_TYPE: [OperatorType; 96] = [
+pub const ENUM_VALUES_OPERATOR_TYPE: [OperatorType; 113] = [
     OperatorType::Add,
     OperatorType::ArgMin,
     OperatorType::ArgMax,
@@ -121,6 +121,23 @@ pub const ENUM_VALUES_OPERATOR_TYPE: [OperatorType; 96] = [
     OperatorType::LayerNormalization,
     OperatorType::ReduceSumSquare,
     OperatorType::RandomUniform,
+    OperatorType::Custom,
+    OperatorType::GlobalMaxPool,
+    OperatorType::LpPool,
+    OperatorType::GlobalLpPool,
+    OperatorType::RNN,
+    OperatorType::Attention,
+    OperatorType::GroupNormalization,
+    OperatorType::RMSNormalization,
+    OperatorType::Celu,
+    OperatorType::Elu,
+    OperatorType::Gelu,
+    OperatorType::Mish,
+    OperatorType::PRelu,
+    OperatorType::Selu,
+    OperatorType::Softplus,
+    OperatorType::Softsign,
+    OperatorType::ThresholdedRelu,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -224,9 +241,26 @@ impl OperatorType {
     pub const LayerNormalization: Self = Self(93);
     pub const ReduceSumSquare: Self = Self(94);
     pub const RandomUniform: Self = Self(95);
+    pub const Custom: Self = Self(96);
+    pub const GlobalMaxPool: Self = Self(97);
+    pub const LpPool: Self = Self(98);
+    pub const GlobalLpPool: Self = Self(99);
+    pub const RNN: Self = Self(100);
+    pub const Attention: Self = Self(101);
+    pub const GroupNormalization: Self = Self(102);
+    pub const RMSNormalization: Self = Self(103);
+    pub const Celu: Self = Self(104);
+    pub const Elu: Self = Self(105);
+    pub const Gelu: Self = Self(106);
+    pub const Mish: Self = Self(107);
+    pub const PRelu: Self = Self(108);
+    pub const Selu: Self = Self(109);
+    pub const Softplus: Self = Self(110);
+    pub const Softsign: Self = Self(111);
+    pub const ThresholdedRelu: Self = Self(112);
 
     pub const ENUM_MIN: u8 = 0;
-    pub const ENUM_MAX: u8 = 95;
+    pub const ENUM_MAX: u8 = 112;
     pub const ENUM_VALUES: &'static [Self] = &[
         Self::Add,
         Self::ArgMin,
@@ -324,6 +358,23 @@ impl OperatorType {
         Self::LayerNormalization,
         Self::ReduceSumSquare,
         Self::RandomUniform,
+        Self::Custom,
+        Self::GlobalMaxPool,
+        Self::LpPool,
+        Self::GlobalLpPool,
+        Self::RNN,
+        Self::Attention,
+        Self::GroupNormalization,
+        Self::RMSNormalization,
+        Self::Celu,
+        Self::Elu,
+        Self::Gelu,
+        Self::Mish,
+        Self::PRelu,
+        Self::Selu,
+        Self::Softplus,
+        Self::Softsign,
+        Self::ThresholdedRelu,
     ];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
@@ -424,6 +475,23 @@ impl OperatorType {
             Self::LayerNormalization => Some("LayerNormalization"),
             Self::ReduceSumSquare => Some("ReduceSumSquare"),
             Self::RandomUniform => Some("RandomUniform"),
+            Self::Custom => Some("Custom"),
+            Self::GlobalMaxPool => Some("GlobalMaxPool"),
+            Self::LpPool => Some("LpPool"),
+            Self::GlobalLpPool => Some("GlobalLpPool"),
+            Self::RNN => Some("RNN"),
+            Self::Attention => Some("Attention"),
+            Self::GroupNormalization => Some("GroupNormalization"),
+            Self::RMSNormalization => Some("RMSNormalization"),
+            Self::Celu => Some("Celu"),
+            Self::Elu => Some("Elu"),
+            Self::Gelu => Some("Gelu"),
+            Self::Mish => Some("Mish"),
+            Self::PRelu => Some("PRelu"),
+            Self::Selu => Some("Selu"),
+            Self::Softplus => Some("Softplus"),
+            Self::Softsign => Some("Softsign"),
+            Self::ThresholdedRelu => Some("ThresholdedRelu"),
             _ => None,
         }
     }
@@ -673,13 +741,19 @@ pub const ENUM_MIN_DATA_TYPE: u8 = 0;
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_DATA_TYPE: u8 = 1;
+pub const ENUM_MAX_DATA_TYPE: u8 = 4;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_DATA_TYPE: [DataType; 2] = [DataType::Int32, DataType::Float];
+pub const ENUM_VALUES_DATA_TYPE: [DataType; 5] = [
+    DataType::Int32,
+    DataType::Float,
+    DataType::Int64,
+    DataType::Bool,
+    DataType::UInt8,
+];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(transparent)]
@@ -688,15 +762,27 @@ pub struct DataType(pub u8);
 impl DataType {
     pub const Int32: Self = Self(0);
     pub const Float: Self = Self(1);
+    pub const Int64: Self = Self(2);
+    pub const Bool: Self = Self(3);
+    pub const UInt8: Self = Self(4);
 
     pub const ENUM_MIN: u8 = 0;
-    pub const ENUM_MAX: u8 = 1;
-    pub const ENUM_VALUES: &'static [Self] = &[Self::Int32, Self::Float];
+    pub const ENUM_MAX: u8 = 4;
+    pub const ENUM_VALUES: &'static [Self] = &[
+        Self::Int32,
+        Self::Float,
+        Self::Int64,
+        Self::Bool,
+        Self::UInt8,
+    ];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
         match self {
             Self::Int32 => Some("Int32"),
             Self::Float => Some("Float"),
+            Self::Int64 => Some("Int64"),
+            Self::Bool => Some("Bool"),
+            Self::UInt8 => Some("UInt8"),
             _ => None,
         }
     }
@@ -762,16 +848,18 @@ pub const ENUM_MIN_COORD_TRANSFORM_MODE: u8 = 0;
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_COORD_TRANSFORM_MODE: u8 = 2;
+pub const ENUM_MAX_COORD_TRANSFORM_MODE: u8 = 4;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_COORD_TRANSFORM_MODE: [CoordTransformMode; 3] = [
+pub const ENUM_VALUES_COORD_TRANSFORM_MODE: [CoordTransformMode; 5] = [
     CoordTransformMode::HalfPixel,
     CoordTransformMode::Asymmetric,
     CoordTransformMode::AlignCorners,
+    CoordTransformMode::PytorchHalfPixel,
+    CoordTransformMode::TfCropAndResize,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -782,17 +870,26 @@ impl CoordTransformMode {
     pub const HalfPixel: Self = Self(0);
     pub const Asymmetric: Self = Self(1);
     pub const AlignCorners: Self = Self(2);
+    pub const PytorchHalfPixel: Self = Self(3);
+    pub const TfCropAndResize: Self = Self(4);
 
     pub const ENUM_MIN: u8 = 0;
-    pub const ENUM_MAX: u8 = 2;
-    pub const ENUM_VALUES: &'static [Self] =
-        &[Self::HalfPixel, Self::Asymmetric, Self::AlignCorners];
+    pub const ENUM_MAX: u8 = 4;
+    pub const ENUM_VALUES: &'static [Self] = &[
+        Self::HalfPixel,
+        Self::Asymmetric,
+        Self::AlignCorners,
+        Self::PytorchHalfPixel,
+        Self::TfCropAndResize,
+    ];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
         match self {
             Self::HalfPixel => Some("HalfPixel"),
             Self::Asymmetric => Some("Asymmetric"),
             Self::AlignCorners => Some("AlignCorners"),
+            Self::PytorchHalfPixel => Some("PytorchHalfPixel"),
+            Self::TfCropAndResize => Some("TfCropAndResize"),
             _ => None,
         }
     }
@@ -961,13 +1058,14 @@ pub const ENUM_MIN_RESIZE_MODE: u8 = 0;
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_RESIZE_MODE: u8 = 1;
+pub const ENUM_MAX_RESIZE_MODE: u8 = 2;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_RESIZE_MODE: [ResizeMode; 2] = [ResizeMode::Nearest, ResizeMode::Linear];
+pub const ENUM_VALUES_RESIZE_MODE: [ResizeMode; 3] =
+    [ResizeMode::Nearest, ResizeMode::Linear, ResizeMode::Cubic];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(transparent)]
@@ -976,15 +1074,17 @@ pub struct ResizeMode(pub u8);
 impl ResizeMode {
     pub const Nearest: Self = Self(0);
     pub const Linear: Self = Self(1);
+    pub const Cubic: Self = Self(2);
 
     pub const ENUM_MIN: u8 = 0;
-    pub const ENUM_MAX: u8 = 1;
-    pub const ENUM_VALUES: &'static [Self] = &[Self::Nearest, Self::Linear];
+    pub const ENUM_MAX: u8 = 2;
+    pub const ENUM_VALUES: &'static [Self] = &[Self::Nearest, Self::Linear, Self::Cubic];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
         match self {
             Self::Nearest => Some("Nearest"),
             Self::Linear => Some("Linear"),
+            Self::Cubic => Some("Cubic"),
             _ => None,
         }
     }
@@ -1045,18 +1145,129 @@ impl flatbuffers::SimpleToVerifyInSlice for ResizeMode {}
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
+pub const ENUM_MIN_CUSTOM_ATTR_TYPE: u8 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_CUSTOM_ATTR_TYPE: u8 = 5;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_CUSTOM_ATTR_TYPE: [CustomAttrType; 6] = [
+    CustomAttrType::Int,
+    CustomAttrType::Float,
+    CustomAttrType::String,
+    CustomAttrType::Ints,
+    CustomAttrType::Floats,
+    CustomAttrType::Tensor,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct CustomAttrType(pub u8);
+#[allow(non_upper_case_globals)]
+impl CustomAttrType {
+    pub const Int: Self = Self(0);
+    pub const Float: Self = Self(1);
+    pub const String: Self = Self(2);
+    pub const Ints: Self = Self(3);
+    pub const Floats: Self = Self(4);
+    pub const Tensor: Self = Self(5);
+
+    pub const ENUM_MIN: u8 = 0;
+    pub const ENUM_MAX: u8 = 5;
+    pub const ENUM_VALUES: &'static [Self] = &[
+        Self::Int,
+        Self::Float,
+        Self::String,
+        Self::Ints,
+        Self::Floats,
+        Self::Tensor,
+    ];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::Int => Some("Int"),
+            Self::Float => Some("Float"),
+            Self::String => Some("String"),
+            Self::Ints => Some("Ints"),
+            Self::Floats => Some("Floats"),
+            Self::Tensor => Some("Tensor"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Debug for CustomAttrType {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+        }
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for CustomAttrType {
+    type Inner = Self;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+        Self(b)
+    }
+}
+
+impl flatbuffers::Push for CustomAttrType {
+    type Output = CustomAttrType;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for CustomAttrType {
+    type Scalar = u8;
+    #[inline]
+    fn to_little_endian(self) -> u8 {
+        self.0.to_le()
+    }
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: u8) -> Self {
+        let b = u8::from_le(v);
+        Self(b)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for CustomAttrType {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        u8::run_verifier(v, pos)
+    }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for CustomAttrType {}
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
 pub const ENUM_MIN_OPERATOR_ATTRS: u8 = 0;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_OPERATOR_ATTRS: u8 = 31;
+pub const ENUM_MAX_OPERATOR_ATTRS: u8 = 40;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_OPERATOR_ATTRS: [OperatorAttrs; 32] = [
+pub const ENUM_VALUES_OPERATOR_ATTRS: [OperatorAttrs; 41] = [
     OperatorAttrs::NONE,
     OperatorAttrs::ArgMaxAttrs,
     OperatorAttrs::AveragePoolAttrs,
@@ -1089,6 +1300,15 @@ pub const ENUM_VALUES_OPERATOR_ATTRS: [OperatorAttrs; 32] = [
     OperatorAttrs::NonMaxSuppressionAttrs,
     OperatorAttrs::LayerNormalizationAttrs,
     OperatorAttrs::RandomUniformAttrs,
+    OperatorAttrs::CustomAttrs,
+    OperatorAttrs::LpPoolAttrs,
+    OperatorAttrs::GlobalLpPoolAttrs,
+    OperatorAttrs::PadAttrs,
+    OperatorAttrs::RNNAttrs,
+    OperatorAttrs::AttentionAttrs,
+    OperatorAttrs::GroupNormalizationAttrs,
+    OperatorAttrs::GeluAttrs,
+    OperatorAttrs::SeluAttrs,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -1128,9 +1348,18 @@ impl OperatorAttrs {
     pub const NonMaxSuppressionAttrs: Self = Self(29);
     pub const LayerNormalizationAttrs: Self = Self(30);
     pub const RandomUniformAttrs: Self = Self(31);
+    pub const CustomAttrs: Self = Self(32);
+    pub const LpPoolAttrs: Self = Self(33);
+    pub const GlobalLpPoolAttrs: Self = Self(34);
+    pub const PadAttrs: Self = Self(35);
+    pub const RNNAttrs: Self = Self(36);
+    pub const AttentionAttrs: Self = Self(37);
+    pub const GroupNormalizationAttrs: Self = Self(38);
+    pub const GeluAttrs: Self = Self(39);
+    pub const SeluAttrs: Self = Self(40);
 
     pub const ENUM_MIN: u8 = 0;
-    pub const ENUM_MAX: u8 = 31;
+    pub const ENUM_MAX: u8 = 40;
     pub const ENUM_VALUES: &'static [Self] = &[
         Self::NONE,
         Self::ArgMaxAttrs,
@@ -1164,6 +1393,15 @@ impl OperatorAttrs {
         Self::NonMaxSuppressionAttrs,
         Self::LayerNormalizationAttrs,
         Self::RandomUniformAttrs,
+        Self::CustomAttrs,
+        Self::LpPoolAttrs,
+        Self::GlobalLpPoolAttrs,
+        Self::PadAttrs,
+        Self::RNNAttrs,
+        Self::AttentionAttrs,
+        Self::GroupNormalizationAttrs,
+        Self::GeluAttrs,
+        Self::SeluAttrs,
     ];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
@@ -1200,6 +1438,15 @@ impl OperatorAttrs {
             Self::NonMaxSuppressionAttrs => Some("NonMaxSuppressionAttrs"),
             Self::LayerNormalizationAttrs => Some("LayerNormalizationAttrs"),
             Self::RandomUniformAttrs => Some("RandomUniformAttrs"),
+            Self::CustomAttrs => Some("CustomAttrs"),
+            Self::LpPoolAttrs => Some("LpPoolAttrs"),
+            Self::GlobalLpPoolAttrs => Some("GlobalLpPoolAttrs"),
+            Self::PadAttrs => Some("PadAttrs"),
+            Self::RNNAttrs => Some("RNNAttrs"),
+            Self::AttentionAttrs => Some("AttentionAttrs"),
+            Self::GroupNormalizationAttrs => Some("GroupNormalizationAttrs"),
+            Self::GeluAttrs => Some("GeluAttrs"),
+            Self::SeluAttrs => Some("SeluAttrs"),
             _ => None,
         }
     }
@@ -1351,6 +1598,137 @@ impl<'a> flatbuffers::Verifiable for Scalar {
 impl flatbuffers::SimpleToVerifyInSlice for Scalar {}
 pub struct ScalarUnionTableOffset {}
 
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MIN_RNNACTIVATION: u8 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_RNNACTIVATION: u8 = 10;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_RNNACTIVATION: [RNNActivation; 11] = [
+    RNNActivation::Relu,
+    RNNActivation::Tanh,
+    RNNActivation::Sigmoid,
+    RNNActivation::Affine,
+    RNNActivation::LeakyRelu,
+    RNNActivation::ThresholdedRelu,
+    RNNActivation::ScaledTanh,
+    RNNActivation::HardSigmoid,
+    RNNActivation::Elu,
+    RNNActivation::Softsign,
+    RNNActivation::Softplus,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct RNNActivation(pub u8);
+#[allow(non_upper_case_globals)]
+impl RNNActivation {
+    pub const Relu: Self = Self(0);
+    pub const Tanh: Self = Self(1);
+    pub const Sigmoid: Self = Self(2);
+    pub const Affine: Self = Self(3);
+    pub const LeakyRelu: Self = Self(4);
+    pub const ThresholdedRelu: Self = Self(5);
+    pub const ScaledTanh: Self = Self(6);
+    pub const HardSigmoid: Self = Self(7);
+    pub const Elu: Self = Self(8);
+    pub const Softsign: Self = Self(9);
+    pub const Softplus: Self = Self(10);
+
+    pub const ENUM_MIN: u8 = 0;
+    pub const ENUM_MAX: u8 = 10;
+    pub const ENUM_VALUES: &'static [Self] = &[
+        Self::Relu,
+        Self::Tanh,
+        Self::Sigmoid,
+        Self::Affine,
+        Self::LeakyRelu,
+        Self::ThresholdedRelu,
+        Self::ScaledTanh,
+        Self::HardSigmoid,
+        Self::Elu,
+        Self::Softsign,
+        Self::Softplus,
+    ];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::Relu => Some("Relu"),
+            Self::Tanh => Some("Tanh"),
+            Self::Sigmoid => Some("Sigmoid"),
+            Self::Affine => Some("Affine"),
+            Self::LeakyRelu => Some("LeakyRelu"),
+            Self::ThresholdedRelu => Some("ThresholdedRelu"),
+            Self::ScaledTanh => Some("ScaledTanh"),
+            Self::HardSigmoid => Some("HardSigmoid"),
+            Self::Elu => Some("Elu"),
+            Self::Softsign => Some("Softsign"),
+            Self::Softplus => Some("Softplus"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Debug for RNNActivation {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+        }
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for RNNActivation {
+    type Inner = Self;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+        Self(b)
+    }
+}
+
+impl flatbuffers::Push for RNNActivation {
+    type Output = RNNActivation;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for RNNActivation {
+    type Scalar = u8;
+    #[inline]
+    fn to_little_endian(self) -> u8 {
+        self.0.to_le()
+    }
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: u8) -> Self {
+        let b = u8::from_le(v);
+        Self(b)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for RNNActivation {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        u8::run_verifier(v, pos)
+    }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for RNNActivation {}
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
@@ -1447,6 +1825,105 @@ impl flatbuffers::SimpleToVerifyInSlice for NMSBoxOrder {}
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
+pub const ENUM_MIN_PAD_OP_MODE: u8 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_PAD_OP_MODE: u8 = 3;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_PAD_OP_MODE: [PadOpMode; 4] = [
+    PadOpMode::Constant,
+    PadOpMode::Reflect,
+    PadOpMode::Edge,
+    PadOpMode::Wrap,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct PadOpMode(pub u8);
+#[allow(non_upper_case_globals)]
+impl PadOpMode {
+    pub const Constant: Self = Self(0);
+    pub const Reflect: Self = Self(1);
+    pub const Edge: Self = Self(2);
+    pub const Wrap: Self = Self(3);
+
+    pub const ENUM_MIN: u8 = 0;
+    pub const ENUM_MAX: u8 = 3;
+    pub const ENUM_VALUES: &'static [Self] =
+        &[Self::Constant, Self::Reflect, Self::Edge, Self::Wrap];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::Constant => Some("Constant"),
+            Self::Reflect => Some("Reflect"),
+            Self::Edge => Some("Edge"),
+            Self::Wrap => Some("Wrap"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Debug for PadOpMode {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+        }
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for PadOpMode {
+    type Inner = Self;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+        Self(b)
+    }
+}
+
+impl flatbuffers::Push for PadOpMode {
+    type Output = PadOpMode;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for PadOpMode {
+    type Scalar = u8;
+    #[inline]
+    fn to_little_endian(self) -> u8 {
+        self.0.to_le()
+    }
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: u8) -> Self {
+        let b = u8::from_le(v);
+        Self(b)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for PadOpMode {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        u8::run_verifier(v, pos)
+    }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for PadOpMode {}
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
 pub const ENUM_MIN_SCATTER_REDUCTION: u8 = 0;
 #[deprecated(
     since = "2.0.0",
@@ -1659,16 +2136,18 @@ pub const ENUM_MIN_CONSTANT_DATA: u8 = 0;
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_CONSTANT_DATA: u8 = 2;
+pub const ENUM_MAX_CONSTANT_DATA: u8 = 4;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_CONSTANT_DATA: [ConstantData; 3] = [
+pub const ENUM_VALUES_CONSTANT_DATA: [ConstantData; 5] = [
     ConstantData::NONE,
     ConstantData::FloatData,
     ConstantData::IntData,
+    ConstantData::Float16Data,
+    ConstantData::BFloat16Data,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -1679,16 +2158,26 @@ impl ConstantData {
     pub const NONE: Self = Self(0);
     pub const FloatData: Self = Self(1);
     pub const IntData: Self = Self(2);
+    pub const Float16Data: Self = Self(3);
+    pub const BFloat16Data: Self = Self(4);
 
     pub const ENUM_MIN: u8 = 0;
-    pub const ENUM_MAX: u8 = 2;
-    pub const ENUM_VALUES: &'static [Self] = &[Self::NONE, Self::FloatData, Self::IntData];
+    pub const ENUM_MAX: u8 = 4;
+    pub const ENUM_VALUES: &'static [Self] = &[
+        Self::NONE,
+        Self::FloatData,
+        Self::IntData,
+        Self::Float16Data,
+        Self::BFloat16Data,
+    ];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
         match self {
             Self::NONE => Some("NONE"),
             Self::FloatData => Some("FloatData"),
             Self::IntData => Some("IntData"),
+            Self::Float16Data => Some("Float16Data"),
+            Self::BFloat16Data => Some("BFloat16Data"),
             _ => None,
         }
     }
@@ -1747,15 +2236,15 @@ impl<'a> flatbuffers::Verifiable for ConstantData {
 impl flatbuffers::SimpleToVerifyInSlice for ConstantData {}
 pub struct ConstantDataUnionTableOffset {}
 
-pub enum ArgMaxAttrsOffset {}
+pub enum AttentionAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ArgMaxAttrs<'a> {
+pub struct AttentionAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ArgMaxAttrs<'a> {
-    type Inner = ArgMaxAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for AttentionAttrs<'a> {
+    type Inner = AttentionAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -1764,7 +2253,175 @@ impl<'a> flatbuffers::Follow<'a> for ArgMaxAttrs<'a> {
     }
 }
 
-impl<'a> ArgMaxAttrs<'a> {
+impl<'a> AttentionAttrs<'a> {
+    pub const VT_NUM_HEADS: flatbuffers::VOffsetT = 4;
+    pub const VT_KV_NUM_HEADS: flatbuffers::VOffsetT = 6;
+    pub const VT_IS_CAUSAL: flatbuffers::VOffsetT = 8;
+    pub const VT_SCALE: flatbuffers::VOffsetT = 10;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        AttentionAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args AttentionAttrsArgs,
+    ) -> flatbuffers::WIPOffset<AttentionAttrs<'bldr>> {
+        let mut builder = AttentionAttrsBuilder::new(_fbb);
+        if let Some(x) = args.scale {
+            builder.add_scale(x);
+        }
+        builder.add_kv_num_heads(args.kv_num_heads);
+        builder.add_num_heads(args.num_heads);
+        builder.add_is_causal(args.is_causal);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn num_heads(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(AttentionAttrs::VT_NUM_HEADS, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn kv_num_heads(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(AttentionAttrs::VT_KV_NUM_HEADS, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn is_causal(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(AttentionAttrs::VT_IS_CAUSAL, Some(false))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn scale(&self) -> Option<f32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(AttentionAttrs::VT_SCALE, None) }
+    }
+}
+
+impl flatbuffers::Verifiable for AttentionAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<u32>("num_heads", Self::VT_NUM_HEADS, false)?
+            .visit_field::<u32>("kv_num_heads", Self::VT_KV_NUM_HEADS, false)?
+            .visit_field::<bool>("is_causal", Self::VT_IS_CAUSAL, false)?
+            .visit_field::<f32>("scale", Self::VT_SCALE, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct AttentionAttrsArgs {
+    pub num_heads: u32,
+    pub kv_num_heads: u32,
+    pub is_causal: bool,
+    pub scale: Option<f32>,
+}
+impl<'a> Default for AttentionAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        AttentionAttrsArgs {
+            num_heads: 0,
+            kv_num_heads: 0,
+            is_causal: false,
+            scale: None,
+        }
+    }
+}
+
+pub struct AttentionAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> AttentionAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_num_heads(&mut self, num_heads: u32) {
+        self.fbb_
+            .push_slot::<u32>(AttentionAttrs::VT_NUM_HEADS, num_heads, 0);
+    }
+    #[inline]
+    pub fn add_kv_num_heads(&mut self, kv_num_heads: u32) {
+        self.fbb_
+            .push_slot::<u32>(AttentionAttrs::VT_KV_NUM_HEADS, kv_num_heads, 0);
+    }
+    #[inline]
+    pub fn add_is_causal(&mut self, is_causal: bool) {
+        self.fbb_
+            .push_slot::<bool>(AttentionAttrs::VT_IS_CAUSAL, is_causal, false);
+    }
+    #[inline]
+    pub fn add_scale(&mut self, scale: f32) {
+        self.fbb_
+            .push_slot_always::<f32>(AttentionAttrs::VT_SCALE, scale);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> AttentionAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        AttentionAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<AttentionAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for AttentionAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("AttentionAttrs");
+        ds.field("num_heads", &self.num_heads());
+        ds.field("kv_num_heads", &self.kv_num_heads());
+        ds.field("is_causal", &self.is_causal());
+        ds.field("scale", &self.scale());
+        ds.finish()
+    }
+}
+pub enum ArgMaxAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ArgMaxAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ArgMaxAttrs<'a> {
+    type Inner = ArgMaxAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> ArgMaxAttrs<'a> {
     pub const VT_AXIS: flatbuffers::VOffsetT = 4;
     pub const VT_KEEP_DIMS: flatbuffers::VOffsetT = 6;
 
@@ -1891,6 +2548,8 @@ impl<'a> AveragePoolAttrs<'a> {
     pub const VT_PADS: flatbuffers::VOffsetT = 8;
     pub const VT_STRIDES: flatbuffers::VOffsetT = 10;
     pub const VT_COUNT_INCLUDE_PAD: flatbuffers::VOffsetT = 12;
+    pub const VT_DILATIONS: flatbuffers::VOffsetT = 14;
+    pub const VT_CEIL_MODE: flatbuffers::VOffsetT = 16;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -1902,6 +2561,9 @@ impl<'a> AveragePoolAttrs<'a> {
         args: &'args AveragePoolAttrsArgs<'args>,
     ) -> flatbuffers::WIPOffset<AveragePoolAttrs<'bldr>> {
         let mut builder = AveragePoolAttrsBuilder::new(_fbb);
+        if let Some(x) = args.dilations {
+            builder.add_dilations(x);
+        }
         if let Some(x) = args.strides {
             builder.add_strides(x);
         }
@@ -1911,6 +2573,7 @@ impl<'a> AveragePoolAttrs<'a> {
         if let Some(x) = args.kernel_size {
             builder.add_kernel_size(x);
         }
+        builder.add_ceil_mode(args.ceil_mode);
         builder.add_count_include_pad(args.count_include_pad);
         builder.add_pad_mode(args.pad_mode);
         builder.finish()
@@ -1978,6 +2641,30 @@ impl<'a> AveragePoolAttrs<'a> {
                 .unwrap()
         }
     }
+    #[inline]
+    pub fn dilations(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    AveragePoolAttrs::VT_DILATIONS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn ceil_mode(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(AveragePoolAttrs::VT_CEIL_MODE, Some(false))
+                .unwrap()
+        }
+    }
 }
 
 impl flatbuffers::Verifiable for AveragePoolAttrs<'_> {
@@ -2005,6 +2692,12 @@ impl flatbuffers::Verifiable for AveragePoolAttrs<'_> {
                 false,
             )?
             .visit_field::<bool>("count_include_pad", Self::VT_COUNT_INCLUDE_PAD, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "dilations",
+                Self::VT_DILATIONS,
+                false,
+            )?
+            .visit_field::<bool>("ceil_mode", Self::VT_CEIL_MODE, false)?
             .finish();
         Ok(())
     }
@@ -2015,6 +2708,8 @@ pub struct AveragePoolAttrsArgs<'a> {
     pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
     pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
     pub count_include_pad: bool,
+    pub dilations: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub ceil_mode: bool,
 }
 impl<'a> Default for AveragePoolAttrsArgs<'a> {
     #[inline]
@@ -2025,6 +2720,8 @@ impl<'a> Default for AveragePoolAttrsArgs<'a> {
             pads: None,
             strides: None,
             count_include_pad: false,
+            dilations: None,
+            ceil_mode: false,
         }
     }
 }
@@ -2068,6 +2765,21 @@ impl<'a: 'b, 'b> AveragePoolAttrsBuilder<'a, 'b> {
         );
     }
     #[inline]
+    pub fn add_dilations(
+        &mut self,
+        dilations: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            AveragePoolAttrs::VT_DILATIONS,
+            dilations,
+        );
+    }
+    #[inline]
+    pub fn add_ceil_mode(&mut self, ceil_mode: bool) {
+        self.fbb_
+            .push_slot::<bool>(AveragePoolAttrs::VT_CEIL_MODE, ceil_mode, false);
+    }
+    #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     ) -> AveragePoolAttrsBuilder<'a, 'b> {
@@ -2094,6 +2806,8 @@ impl core::fmt::Debug for AveragePoolAttrs<'_> {
         ds.field("pads", &self.pads());
         ds.field("strides", &self.strides());
         ds.field("count_include_pad", &self.count_include_pad());
+        ds.field("dilations", &self.dilations());
+        ds.field("ceil_mode", &self.ceil_mode());
         ds.finish()
     }
 }
@@ -3042,6 +3756,12 @@ impl<'a> flatbuffers::Follow<'a> for ConvTransposeAttrs<'a> {
 
 impl<'a> ConvTransposeAttrs<'a> {
     pub const VT_STRIDES: flatbuffers::VOffsetT = 4;
+    pub const VT_PAD_MODE: flatbuffers::VOffsetT = 6;
+    pub const VT_PADS: flatbuffers::VOffsetT = 8;
+    pub const VT_GROUPS: flatbuffers::VOffsetT = 10;
+    pub const VT_DILATIONS: flatbuffers::VOffsetT = 12;
+    pub const VT_OUTPUT_PADDING: flatbuffers::VOffsetT = 14;
+    pub const VT_OUTPUT_SHAPE: flatbuffers::VOffsetT = 16;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -3053,9 +3773,23 @@ impl<'a> ConvTransposeAttrs<'a> {
         args: &'args ConvTransposeAttrsArgs<'args>,
     ) -> flatbuffers::WIPOffset<ConvTransposeAttrs<'bldr>> {
         let mut builder = ConvTransposeAttrsBuilder::new(_fbb);
+        if let Some(x) = args.output_shape {
+            builder.add_output_shape(x);
+        }
+        if let Some(x) = args.output_padding {
+            builder.add_output_padding(x);
+        }
+        if let Some(x) = args.dilations {
+            builder.add_dilations(x);
+        }
+        builder.add_groups(args.groups);
+        if let Some(x) = args.pads {
+            builder.add_pads(x);
+        }
         if let Some(x) = args.strides {
             builder.add_strides(x);
         }
+        builder.add_pad_mode(args.pad_mode);
         builder.finish()
     }
 
@@ -3072,6 +3806,80 @@ impl<'a> ConvTransposeAttrs<'a> {
                 )
         }
     }
+    #[inline]
+    pub fn pad_mode(&self) -> PadMode {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<PadMode>(ConvTransposeAttrs::VT_PAD_MODE, Some(PadMode::Fixed))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    ConvTransposeAttrs::VT_PADS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn groups(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(ConvTransposeAttrs::VT_GROUPS, Some(1))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn dilations(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    ConvTransposeAttrs::VT_DILATIONS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn output_padding(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    ConvTransposeAttrs::VT_OUTPUT_PADDING,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn output_shape(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    ConvTransposeAttrs::VT_OUTPUT_SHAPE,
+                    None,
+                )
+        }
+    }
 }
 
 impl flatbuffers::Verifiable for ConvTransposeAttrs<'_> {
@@ -3087,18 +3895,54 @@ impl flatbuffers::Verifiable for ConvTransposeAttrs<'_> {
                 Self::VT_STRIDES,
                 false,
             )?
+            .visit_field::<PadMode>("pad_mode", Self::VT_PAD_MODE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "pads",
+                Self::VT_PADS,
+                false,
+            )?
+            .visit_field::<u32>("groups", Self::VT_GROUPS, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "dilations",
+                Self::VT_DILATIONS,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "output_padding",
+                Self::VT_OUTPUT_PADDING,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "output_shape",
+                Self::VT_OUTPUT_SHAPE,
+                false,
+            )?
             .finish();
         Ok(())
     }
 }
 pub struct ConvTransposeAttrsArgs<'a> {
     pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub pad_mode: PadMode,
+    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub groups: u32,
+    pub dilations: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub output_padding: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub output_shape: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
 }
 impl<'a> Default for ConvTransposeAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        ConvTransposeAttrsArgs { strides: None }
-    }
+        ConvTransposeAttrsArgs {
+            strides: None,
+            pad_mode: PadMode::Fixed,
+            pads: None,
+            groups: 1,
+            dilations: None,
+            output_padding: None,
+            output_shape: None,
+        }
+    }
 }
 
 pub struct ConvTransposeAttrsBuilder<'a: 'b, 'b> {
@@ -3112,6 +3956,51 @@ impl<'a: 'b, 'b> ConvTransposeAttrsBuilder<'a, 'b> {
             .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvTransposeAttrs::VT_STRIDES, strides);
     }
     #[inline]
+    pub fn add_pad_mode(&mut self, pad_mode: PadMode) {
+        self.fbb_
+            .push_slot::<PadMode>(ConvTransposeAttrs::VT_PAD_MODE, pad_mode, PadMode::Fixed);
+    }
+    #[inline]
+    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvTransposeAttrs::VT_PADS, pads);
+    }
+    #[inline]
+    pub fn add_groups(&mut self, groups: u32) {
+        self.fbb_
+            .push_slot::<u32>(ConvTransposeAttrs::VT_GROUPS, groups, 1);
+    }
+    #[inline]
+    pub fn add_dilations(
+        &mut self,
+        dilations: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            ConvTransposeAttrs::VT_DILATIONS,
+            dilations,
+        );
+    }
+    #[inline]
+    pub fn add_output_padding(
+        &mut self,
+        output_padding: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            ConvTransposeAttrs::VT_OUTPUT_PADDING,
+            output_padding,
+        );
+    }
+    #[inline]
+    pub fn add_output_shape(
+        &mut self,
+        output_shape: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            ConvTransposeAttrs::VT_OUTPUT_SHAPE,
+            output_shape,
+        );
+    }
+    #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     ) -> ConvTransposeAttrsBuilder<'a, 'b> {
@@ -3132,6 +4021,12 @@ impl core::fmt::Debug for ConvTransposeAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let mut ds = f.debug_struct("ConvTransposeAttrs");
         ds.field("strides", &self.strides());
+        ds.field("pad_mode", &self.pad_mode());
+        ds.field("pads", &self.pads());
+        ds.field("groups", &self.groups());
+        ds.field("dilations", &self.dilations());
+        ds.field("output_padding", &self.output_padding());
+        ds.field("output_shape", &self.output_shape());
         ds.finish()
     }
 }
@@ -3646,6 +4541,7 @@ impl<'a> GRUAttrs<'a> {
     pub const VT_DIRECTION: flatbuffers::VOffsetT = 4;
     pub const VT_HIDDEN_SIZE: flatbuffers::VOffsetT = 6;
     pub const VT_LINEAR_BEFORE_RESET: flatbuffers::VOffsetT = 8;
+    pub const VT_CLIP: flatbuffers::VOffsetT = 10;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -3657,6 +4553,7 @@ impl<'a> GRUAttrs<'a> {
         args: &'args GRUAttrsArgs,
     ) -> flatbuffers::WIPOffset<GRUAttrs<'bldr>> {
         let mut builder = GRUAttrsBuilder::new(_fbb);
+        builder.add_clip(args.clip);
         builder.add_hidden_size(args.hidden_size);
         builder.add_linear_before_reset(args.linear_before_reset);
         builder.add_direction(args.direction);
@@ -3696,6 +4593,13 @@ impl<'a> GRUAttrs<'a> {
                 .unwrap()
         }
     }
+    #[inline]
+    pub fn clip(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(GRUAttrs::VT_CLIP, Some(0.0)).unwrap() }
+    }
 }
 
 impl flatbuffers::Verifiable for GRUAttrs<'_> {
@@ -3709,6 +4613,7 @@ impl flatbuffers::Verifiable for GRUAttrs<'_> {
             .visit_field::<RNNDirection>("direction", Self::VT_DIRECTION, false)?
             .visit_field::<u32>("hidden_size", Self::VT_HIDDEN_SIZE, false)?
             .visit_field::<bool>("linear_before_reset", Self::VT_LINEAR_BEFORE_RESET, false)?
+            .visit_field::<f32>("clip", Self::VT_CLIP, false)?
             .finish();
         Ok(())
     }
@@ -3717,6 +4622,7 @@ pub struct GRUAttrsArgs {
     pub direction: RNNDirection,
     pub hidden_size: u32,
     pub linear_before_reset: bool,
+    pub clip: f32,
 }
 impl<'a> Default for GRUAttrsArgs {
     #[inline]
@@ -3725,6 +4631,7 @@ impl<'a> Default for GRUAttrsArgs {
             direction: RNNDirection::Forward,
             hidden_size: 0,
             linear_before_reset: false,
+            clip: 0.0,
         }
     }
 }
@@ -3753,6 +4660,10 @@ impl<'a: 'b, 'b> GRUAttrsBuilder<'a, 'b> {
             .push_slot::<bool>(GRUAttrs::VT_LINEAR_BEFORE_RESET, linear_before_reset, false);
     }
     #[inline]
+    pub fn add_clip(&mut self, clip: f32) {
+        self.fbb_.push_slot::<f32>(GRUAttrs::VT_CLIP, clip, 0.0);
+    }
+    #[inline]
     pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GRUAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
         GRUAttrsBuilder {
@@ -3773,6 +4684,237 @@ impl core::fmt::Debug for GRUAttrs<'_> {
         ds.field("direction", &self.direction());
         ds.field("hidden_size", &self.hidden_size());
         ds.field("linear_before_reset", &self.linear_before_reset());
+        ds.field("clip", &self.clip());
+        ds.finish()
+    }
+}
+pub enum GeluAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct GeluAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for GeluAttrs<'a> {
+    type Inner = GeluAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> GeluAttrs<'a> {
+    pub const VT_APPROXIMATE: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        GeluAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args GeluAttrsArgs,
+    ) -> flatbuffers::WIPOffset<GeluAttrs<'bldr>> {
+        let mut builder = GeluAttrsBuilder::new(_fbb);
+        builder.add_approximate(args.approximate);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn approximate(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(GeluAttrs::VT_APPROXIMATE, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for GeluAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<bool>("approximate", Self::VT_APPROXIMATE, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct GeluAttrsArgs {
+    pub approximate: bool,
+}
+impl<'a> Default for GeluAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        GeluAttrsArgs { approximate: false }
+    }
+}
+
+pub struct GeluAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> GeluAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_approximate(&mut self, approximate: bool) {
+        self.fbb_
+            .push_slot::<bool>(GeluAttrs::VT_APPROXIMATE, approximate, false);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GeluAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        GeluAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<GeluAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for GeluAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("GeluAttrs");
+        ds.field("approximate", &self.approximate());
+        ds.finish()
+    }
+}
+pub enum GroupNormalizationAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct GroupNormalizationAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for GroupNormalizationAttrs<'a> {
+    type Inner = GroupNormalizationAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> GroupNormalizationAttrs<'a> {
+    pub const VT_NUM_GROUPS: flatbuffers::VOffsetT = 4;
+    pub const VT_EPSILON: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        GroupNormalizationAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args GroupNormalizationAttrsArgs,
+    ) -> flatbuffers::WIPOffset<GroupNormalizationAttrs<'bldr>> {
+        let mut builder = GroupNormalizationAttrsBuilder::new(_fbb);
+        builder.add_epsilon(args.epsilon);
+        builder.add_num_groups(args.num_groups);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn num_groups(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(GroupNormalizationAttrs::VT_NUM_GROUPS, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn epsilon(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(GroupNormalizationAttrs::VT_EPSILON, Some(0.0))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for GroupNormalizationAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<u32>("num_groups", Self::VT_NUM_GROUPS, false)?
+            .visit_field::<f32>("epsilon", Self::VT_EPSILON, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct GroupNormalizationAttrsArgs {
+    pub num_groups: u32,
+    pub epsilon: f32,
+}
+impl<'a> Default for GroupNormalizationAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        GroupNormalizationAttrsArgs {
+            num_groups: 0,
+            epsilon: 0.0,
+        }
+    }
+}
+
+pub struct GroupNormalizationAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> GroupNormalizationAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_num_groups(&mut self, num_groups: u32) {
+        self.fbb_
+            .push_slot::<u32>(GroupNormalizationAttrs::VT_NUM_GROUPS, num_groups, 0);
+    }
+    #[inline]
+    pub fn add_epsilon(&mut self, epsilon: f32) {
+        self.fbb_
+            .push_slot::<f32>(GroupNormalizationAttrs::VT_EPSILON, epsilon, 0.0);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> GroupNormalizationAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        GroupNormalizationAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<GroupNormalizationAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for GroupNormalizationAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("GroupNormalizationAttrs");
+        ds.field("num_groups", &self.num_groups());
+        ds.field("epsilon", &self.epsilon());
         ds.finish()
     }
 }
@@ -4026,6 +5168,7 @@ impl<'a> flatbuffers::Follow<'a> for LSTMAttrs<'a> {
 impl<'a> LSTMAttrs<'a> {
     pub const VT_DIRECTION: flatbuffers::VOffsetT = 4;
     pub const VT_HIDDEN_SIZE: flatbuffers::VOffsetT = 6;
+    pub const VT_CLIP: flatbuffers::VOffsetT = 8;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -4037,6 +5180,7 @@ impl<'a> LSTMAttrs<'a> {
         args: &'args LSTMAttrsArgs,
     ) -> flatbuffers::WIPOffset<LSTMAttrs<'bldr>> {
         let mut builder = LSTMAttrsBuilder::new(_fbb);
+        builder.add_clip(args.clip);
         builder.add_hidden_size(args.hidden_size);
         builder.add_direction(args.direction);
         builder.finish()
@@ -4064,6 +5208,13 @@ impl<'a> LSTMAttrs<'a> {
                 .unwrap()
         }
     }
+    #[inline]
+    pub fn clip(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(LSTMAttrs::VT_CLIP, Some(0.0)).unwrap() }
+    }
 }
 
 impl flatbuffers::Verifiable for LSTMAttrs<'_> {
@@ -4076,6 +5227,7 @@ impl flatbuffers::Verifiable for LSTMAttrs<'_> {
         v.visit_table(pos)?
             .visit_field::<RNNDirection>("direction", Self::VT_DIRECTION, false)?
             .visit_field::<u32>("hidden_size", Self::VT_HIDDEN_SIZE, false)?
+            .visit_field::<f32>("clip", Self::VT_CLIP, false)?
             .finish();
         Ok(())
     }
@@ -4083,6 +5235,7 @@ impl flatbuffers::Verifiable for LSTMAttrs<'_> {
 pub struct LSTMAttrsArgs {
     pub direction: RNNDirection,
     pub hidden_size: u32,
+    pub clip: f32,
 }
 impl<'a> Default for LSTMAttrsArgs {
     #[inline]
@@ -4090,6 +5243,7 @@ impl<'a> Default for LSTMAttrsArgs {
         LSTMAttrsArgs {
             direction: RNNDirection::Forward,
             hidden_size: 0,
+            clip: 0.0,
         }
     }
 }
@@ -4113,6 +5267,10 @@ impl<'a: 'b, 'b> LSTMAttrsBuilder<'a, 'b> {
             .push_slot::<u32>(LSTMAttrs::VT_HIDDEN_SIZE, hidden_size, 0);
     }
     #[inline]
+    pub fn add_clip(&mut self, clip: f32) {
+        self.fbb_.push_slot::<f32>(LSTMAttrs::VT_CLIP, clip, 0.0);
+    }
+    #[inline]
     pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> LSTMAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
         LSTMAttrsBuilder {
@@ -4132,18 +5290,19 @@ impl core::fmt::Debug for LSTMAttrs<'_> {
         let mut ds = f.debug_struct("LSTMAttrs");
         ds.field("direction", &self.direction());
         ds.field("hidden_size", &self.hidden_size());
+        ds.field("clip", &self.clip());
         ds.finish()
     }
 }
-pub enum MaxPoolAttrsOffset {}
+pub enum RNNAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct MaxPoolAttrs<'a> {
+pub struct RNNAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for MaxPoolAttrs<'a> {
-    type Inner = MaxPoolAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for RNNAttrs<'a> {
+    type Inner = RNNAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4152,51 +5311,306 @@ impl<'a> flatbuffers::Follow<'a> for MaxPoolAttrs<'a> {
     }
 }
 
-impl<'a> MaxPoolAttrs<'a> {
-    pub const VT_KERNEL_SIZE: flatbuffers::VOffsetT = 4;
-    pub const VT_PAD_MODE: flatbuffers::VOffsetT = 6;
-    pub const VT_PADS: flatbuffers::VOffsetT = 8;
-    pub const VT_STRIDES: flatbuffers::VOffsetT = 10;
+impl<'a> RNNAttrs<'a> {
+    pub const VT_DIRECTION: flatbuffers::VOffsetT = 4;
+    pub const VT_HIDDEN_SIZE: flatbuffers::VOffsetT = 6;
+    pub const VT_ACTIVATIONS: flatbuffers::VOffsetT = 8;
+    pub const VT_ACTIVATION_ALPHA: flatbuffers::VOffsetT = 10;
+    pub const VT_ACTIVATION_BETA: flatbuffers::VOffsetT = 12;
+    pub const VT_CLIP: flatbuffers::VOffsetT = 14;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        MaxPoolAttrs { _tab: table }
+        RNNAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args MaxPoolAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<MaxPoolAttrs<'bldr>> {
-        let mut builder = MaxPoolAttrsBuilder::new(_fbb);
-        if let Some(x) = args.strides {
-            builder.add_strides(x);
+        args: &'args RNNAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<RNNAttrs<'bldr>> {
+        let mut builder = RNNAttrsBuilder::new(_fbb);
+        builder.add_clip(args.clip);
+        if let Some(x) = args.activation_beta {
+            builder.add_activation_beta(x);
         }
-        if let Some(x) = args.pads {
-            builder.add_pads(x);
+        if let Some(x) = args.activation_alpha {
+            builder.add_activation_alpha(x);
         }
-        if let Some(x) = args.kernel_size {
-            builder.add_kernel_size(x);
+        if let Some(x) = args.activations {
+            builder.add_activations(x);
         }
-        builder.add_pad_mode(args.pad_mode);
+        builder.add_hidden_size(args.hidden_size);
+        builder.add_direction(args.direction);
         builder.finish()
     }
 
     #[inline]
-    pub fn kernel_size(&self) -> flatbuffers::Vector<'a, u32> {
+    pub fn direction(&self) -> RNNDirection {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    MaxPoolAttrs::VT_KERNEL_SIZE,
-                    None,
-                )
+                .get::<RNNDirection>(RNNAttrs::VT_DIRECTION, Some(RNNDirection::Forward))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn pad_mode(&self) -> PadMode {
+    pub fn hidden_size(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(RNNAttrs::VT_HIDDEN_SIZE, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn activations(&self) -> Option<flatbuffers::Vector<'a, RNNActivation>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, RNNActivation>>>(
+                    RNNAttrs::VT_ACTIVATIONS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn activation_alpha(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
+                    RNNAttrs::VT_ACTIVATION_ALPHA,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn activation_beta(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
+                    RNNAttrs::VT_ACTIVATION_BETA,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn clip(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(RNNAttrs::VT_CLIP, Some(0.0)).unwrap() }
+    }
+}
+
+impl flatbuffers::Verifiable for RNNAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<RNNDirection>("direction", Self::VT_DIRECTION, false)?
+            .visit_field::<u32>("hidden_size", Self::VT_HIDDEN_SIZE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, RNNActivation>>>(
+                "activations",
+                Self::VT_ACTIVATIONS,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>(
+                "activation_alpha",
+                Self::VT_ACTIVATION_ALPHA,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>(
+                "activation_beta",
+                Self::VT_ACTIVATION_BETA,
+                false,
+            )?
+            .visit_field::<f32>("clip", Self::VT_CLIP, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct RNNAttrsArgs<'a> {
+    pub direction: RNNDirection,
+    pub hidden_size: u32,
+    pub activations: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, RNNActivation>>>,
+    pub activation_alpha: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+    pub activation_beta: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+    pub clip: f32,
+}
+impl<'a> Default for RNNAttrsArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        RNNAttrsArgs {
+            direction: RNNDirection::Forward,
+            hidden_size: 0,
+            activations: None,
+            activation_alpha: None,
+            activation_beta: None,
+            clip: 0.0,
+        }
+    }
+}
+
+pub struct RNNAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> RNNAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_direction(&mut self, direction: RNNDirection) {
+        self.fbb_.push_slot::<RNNDirection>(
+            RNNAttrs::VT_DIRECTION,
+            direction,
+            RNNDirection::Forward,
+        );
+    }
+    #[inline]
+    pub fn add_hidden_size(&mut self, hidden_size: u32) {
+        self.fbb_
+            .push_slot::<u32>(RNNAttrs::VT_HIDDEN_SIZE, hidden_size, 0);
+    }
+    #[inline]
+    pub fn add_activations(
+        &mut self,
+        activations: flatbuffers::WIPOffset<flatbuffers::Vector<'b, RNNActivation>>,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(RNNAttrs::VT_ACTIVATIONS, activations);
+    }
+    #[inline]
+    pub fn add_activation_alpha(
+        &mut self,
+        activation_alpha: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            RNNAttrs::VT_ACTIVATION_ALPHA,
+            activation_alpha,
+        );
+    }
+    #[inline]
+    pub fn add_activation_beta(
+        &mut self,
+        activation_beta: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            RNNAttrs::VT_ACTIVATION_BETA,
+            activation_beta,
+        );
+    }
+    #[inline]
+    pub fn add_clip(&mut self, clip: f32) {
+        self.fbb_.push_slot::<f32>(RNNAttrs::VT_CLIP, clip, 0.0);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> RNNAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        RNNAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<RNNAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for RNNAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("RNNAttrs");
+        ds.field("direction", &self.direction());
+        ds.field("hidden_size", &self.hidden_size());
+        ds.field("activations", &self.activations());
+        ds.field("activation_alpha", &self.activation_alpha());
+        ds.field("activation_beta", &self.activation_beta());
+        ds.field("clip", &self.clip());
+        ds.finish()
+    }
+}
+pub enum MaxPoolAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct MaxPoolAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for MaxPoolAttrs<'a> {
+    type Inner = MaxPoolAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> MaxPoolAttrs<'a> {
+    pub const VT_KERNEL_SIZE: flatbuffers::VOffsetT = 4;
+    pub const VT_PAD_MODE: flatbuffers::VOffsetT = 6;
+    pub const VT_PADS: flatbuffers::VOffsetT = 8;
+    pub const VT_STRIDES: flatbuffers::VOffsetT = 10;
+    pub const VT_DILATIONS: flatbuffers::VOffsetT = 12;
+    pub const VT_CEIL_MODE: flatbuffers::VOffsetT = 14;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        MaxPoolAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args MaxPoolAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<MaxPoolAttrs<'bldr>> {
+        let mut builder = MaxPoolAttrsBuilder::new(_fbb);
+        if let Some(x) = args.dilations {
+            builder.add_dilations(x);
+        }
+        if let Some(x) = args.strides {
+            builder.add_strides(x);
+        }
+        if let Some(x) = args.pads {
+            builder.add_pads(x);
+        }
+        if let Some(x) = args.kernel_size {
+            builder.add_kernel_size(x);
+        }
+        builder.add_ceil_mode(args.ceil_mode);
+        builder.add_pad_mode(args.pad_mode);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn kernel_size(&self) -> flatbuffers::Vector<'a, u32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    MaxPoolAttrs::VT_KERNEL_SIZE,
+                    None,
+                )
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn pad_mode(&self) -> PadMode {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
@@ -4232,6 +5646,30 @@ impl<'a> MaxPoolAttrs<'a> {
                 )
         }
     }
+    #[inline]
+    pub fn dilations(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    MaxPoolAttrs::VT_DILATIONS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn ceil_mode(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(MaxPoolAttrs::VT_CEIL_MODE, Some(false))
+                .unwrap()
+        }
+    }
 }
 
 impl flatbuffers::Verifiable for MaxPoolAttrs<'_> {
@@ -4258,6 +5696,12 @@ impl flatbuffers::Verifiable for MaxPoolAttrs<'_> {
                 Self::VT_STRIDES,
                 false,
             )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "dilations",
+                Self::VT_DILATIONS,
+                false,
+            )?
+            .visit_field::<bool>("ceil_mode", Self::VT_CEIL_MODE, false)?
             .finish();
         Ok(())
     }
@@ -4267,6 +5711,8 @@ pub struct MaxPoolAttrsArgs<'a> {
     pub pad_mode: PadMode,
     pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
     pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub dilations: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub ceil_mode: bool,
 }
 impl<'a> Default for MaxPoolAttrsArgs<'a> {
     #[inline]
@@ -4276,6 +5722,8 @@ impl<'a> Default for MaxPoolAttrsArgs<'a> {
             pad_mode: PadMode::Same,
             pads: None,
             strides: None,
+            dilations: None,
+            ceil_mode: false,
         }
     }
 }
@@ -4311,6 +5759,19 @@ impl<'a: 'b, 'b> MaxPoolAttrsBuilder<'a, 'b> {
             .push_slot_always::<flatbuffers::WIPOffset<_>>(MaxPoolAttrs::VT_STRIDES, strides);
     }
     #[inline]
+    pub fn add_dilations(
+        &mut self,
+        dilations: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(MaxPoolAttrs::VT_DILATIONS, dilations);
+    }
+    #[inline]
+    pub fn add_ceil_mode(&mut self, ceil_mode: bool) {
+        self.fbb_
+            .push_slot::<bool>(MaxPoolAttrs::VT_CEIL_MODE, ceil_mode, false);
+    }
+    #[inline]
     pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> MaxPoolAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
         MaxPoolAttrsBuilder {
@@ -4334,18 +5795,811 @@ impl core::fmt::Debug for MaxPoolAttrs<'_> {
         ds.field("pad_mode", &self.pad_mode());
         ds.field("pads", &self.pads());
         ds.field("strides", &self.strides());
+        ds.field("dilations", &self.dilations());
+        ds.field("ceil_mode", &self.ceil_mode());
+        ds.finish()
+    }
+}
+pub enum LpPoolAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct LpPoolAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for LpPoolAttrs<'a> {
+    type Inner = LpPoolAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> LpPoolAttrs<'a> {
+    pub const VT_KERNEL_SIZE: flatbuffers::VOffsetT = 4;
+    pub const VT_PAD_MODE: flatbuffers::VOffsetT = 6;
+    pub const VT_PADS: flatbuffers::VOffsetT = 8;
+    pub const VT_STRIDES: flatbuffers::VOffsetT = 10;
+    pub const VT_DILATIONS: flatbuffers::VOffsetT = 12;
+    pub const VT_CEIL_MODE: flatbuffers::VOffsetT = 14;
+    pub const VT_P: flatbuffers::VOffsetT = 16;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        LpPoolAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args LpPoolAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<LpPoolAttrs<'bldr>> {
+        let mut builder = LpPoolAttrsBuilder::new(_fbb);
+        builder.add_p(args.p);
+        if let Some(x) = args.dilations {
+            builder.add_dilations(x);
+        }
+        if let Some(x) = args.strides {
+            builder.add_strides(x);
+        }
+        if let Some(x) = args.pads {
+            builder.add_pads(x);
+        }
+        if let Some(x) = args.kernel_size {
+            builder.add_kernel_size(x);
+        }
+        builder.add_ceil_mode(args.ceil_mode);
+        builder.add_pad_mode(args.pad_mode);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn kernel_size(&self) -> flatbuffers::Vector<'a, u32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    LpPoolAttrs::VT_KERNEL_SIZE,
+                    None,
+                )
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn pad_mode(&self) -> PadMode {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<PadMode>(LpPoolAttrs::VT_PAD_MODE, Some(PadMode::Same))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    LpPoolAttrs::VT_PADS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn strides(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    LpPoolAttrs::VT_STRIDES,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn dilations(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    LpPoolAttrs::VT_DILATIONS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn ceil_mode(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(LpPoolAttrs::VT_CEIL_MODE, Some(false))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn p(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<i32>(LpPoolAttrs::VT_P, Some(2)).unwrap() }
+    }
+}
+
+impl flatbuffers::Verifiable for LpPoolAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "kernel_size",
+                Self::VT_KERNEL_SIZE,
+                true,
+            )?
+            .visit_field::<PadMode>("pad_mode", Self::VT_PAD_MODE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "pads",
+                Self::VT_PADS,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "strides",
+                Self::VT_STRIDES,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "dilations",
+                Self::VT_DILATIONS,
+                false,
+            )?
+            .visit_field::<bool>("ceil_mode", Self::VT_CEIL_MODE, false)?
+            .visit_field::<i32>("p", Self::VT_P, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct LpPoolAttrsArgs<'a> {
+    pub kernel_size: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub pad_mode: PadMode,
+    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub dilations: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub ceil_mode: bool,
+    pub p: i32,
+}
+impl<'a> Default for LpPoolAttrsArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        LpPoolAttrsArgs {
+            kernel_size: None, // required field
+            pad_mode: PadMode::Same,
+            pads: None,
+            strides: None,
+            dilations: None,
+            ceil_mode: false,
+            p: 2,
+        }
+    }
+}
+
+pub struct LpPoolAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> LpPoolAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_kernel_size(
+        &mut self,
+        kernel_size: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            LpPoolAttrs::VT_KERNEL_SIZE,
+            kernel_size,
+        );
+    }
+    #[inline]
+    pub fn add_pad_mode(&mut self, pad_mode: PadMode) {
+        self.fbb_
+            .push_slot::<PadMode>(LpPoolAttrs::VT_PAD_MODE, pad_mode, PadMode::Same);
+    }
+    #[inline]
+    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(LpPoolAttrs::VT_PADS, pads);
+    }
+    #[inline]
+    pub fn add_strides(&mut self, strides: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(LpPoolAttrs::VT_STRIDES, strides);
+    }
+    #[inline]
+    pub fn add_dilations(
+        &mut self,
+        dilations: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(LpPoolAttrs::VT_DILATIONS, dilations);
+    }
+    #[inline]
+    pub fn add_ceil_mode(&mut self, ceil_mode: bool) {
+        self.fbb_
+            .push_slot::<bool>(LpPoolAttrs::VT_CEIL_MODE, ceil_mode, false);
+    }
+    #[inline]
+    pub fn add_p(&mut self, p: i32) {
+        self.fbb_.push_slot::<i32>(LpPoolAttrs::VT_P, p, 2);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> LpPoolAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        LpPoolAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<LpPoolAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        self.fbb_
+            .required(o, LpPoolAttrs::VT_KERNEL_SIZE, "kernel_size");
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for LpPoolAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("LpPoolAttrs");
+        ds.field("kernel_size", &self.kernel_size());
+        ds.field("pad_mode", &self.pad_mode());
+        ds.field("pads", &self.pads());
+        ds.field("strides", &self.strides());
+        ds.field("dilations", &self.dilations());
+        ds.field("ceil_mode", &self.ceil_mode());
+        ds.field("p", &self.p());
+        ds.finish()
+    }
+}
+pub enum GlobalLpPoolAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct GlobalLpPoolAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for GlobalLpPoolAttrs<'a> {
+    type Inner = GlobalLpPoolAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> GlobalLpPoolAttrs<'a> {
+    pub const VT_P: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        GlobalLpPoolAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args GlobalLpPoolAttrsArgs,
+    ) -> flatbuffers::WIPOffset<GlobalLpPoolAttrs<'bldr>> {
+        let mut builder = GlobalLpPoolAttrsBuilder::new(_fbb);
+        builder.add_p(args.p);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn p(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<i32>(GlobalLpPoolAttrs::VT_P, Some(2))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for GlobalLpPoolAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<i32>("p", Self::VT_P, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct GlobalLpPoolAttrsArgs {
+    pub p: i32,
+}
+impl<'a> Default for GlobalLpPoolAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        GlobalLpPoolAttrsArgs { p: 2 }
+    }
+}
+
+pub struct GlobalLpPoolAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> GlobalLpPoolAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_p(&mut self, p: i32) {
+        self.fbb_.push_slot::<i32>(GlobalLpPoolAttrs::VT_P, p, 2);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> GlobalLpPoolAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        GlobalLpPoolAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<GlobalLpPoolAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for GlobalLpPoolAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("GlobalLpPoolAttrs");
+        ds.field("p", &self.p());
+        ds.finish()
+    }
+}
+pub enum ModAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ModAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ModAttrs<'a> {
+    type Inner = ModAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> ModAttrs<'a> {
+    pub const VT_FMOD: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ModAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args ModAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ModAttrs<'bldr>> {
+        let mut builder = ModAttrsBuilder::new(_fbb);
+        builder.add_fmod(args.fmod);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn fmod(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(ModAttrs::VT_FMOD, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ModAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<bool>("fmod", Self::VT_FMOD, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ModAttrsArgs {
+    pub fmod: bool,
+}
+impl<'a> Default for ModAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        ModAttrsArgs { fmod: false }
+    }
+}
+
+pub struct ModAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ModAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_fmod(&mut self, fmod: bool) {
+        self.fbb_.push_slot::<bool>(ModAttrs::VT_FMOD, fmod, false);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ModAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        ModAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ModAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ModAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ModAttrs");
+        ds.field("fmod", &self.fmod());
+        ds.finish()
+    }
+}
+pub enum NonMaxSuppressionAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct NonMaxSuppressionAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for NonMaxSuppressionAttrs<'a> {
+    type Inner = NonMaxSuppressionAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> NonMaxSuppressionAttrs<'a> {
+    pub const VT_BOX_ORDER: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        NonMaxSuppressionAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args NonMaxSuppressionAttrsArgs,
+    ) -> flatbuffers::WIPOffset<NonMaxSuppressionAttrs<'bldr>> {
+        let mut builder = NonMaxSuppressionAttrsBuilder::new(_fbb);
+        builder.add_box_order(args.box_order);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn box_order(&self) -> NMSBoxOrder {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<NMSBoxOrder>(
+                    NonMaxSuppressionAttrs::VT_BOX_ORDER,
+                    Some(NMSBoxOrder::TopLeftBottomRight),
+                )
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for NonMaxSuppressionAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<NMSBoxOrder>("box_order", Self::VT_BOX_ORDER, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct NonMaxSuppressionAttrsArgs {
+    pub box_order: NMSBoxOrder,
+}
+impl<'a> Default for NonMaxSuppressionAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        NonMaxSuppressionAttrsArgs {
+            box_order: NMSBoxOrder::TopLeftBottomRight,
+        }
+    }
+}
+
+pub struct NonMaxSuppressionAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> NonMaxSuppressionAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_box_order(&mut self, box_order: NMSBoxOrder) {
+        self.fbb_.push_slot::<NMSBoxOrder>(
+            NonMaxSuppressionAttrs::VT_BOX_ORDER,
+            box_order,
+            NMSBoxOrder::TopLeftBottomRight,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> NonMaxSuppressionAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        NonMaxSuppressionAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<NonMaxSuppressionAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for NonMaxSuppressionAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("NonMaxSuppressionAttrs");
+        ds.field("box_order", &self.box_order());
+        ds.finish()
+    }
+}
+pub enum OneHotAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct OneHotAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for OneHotAttrs<'a> {
+    type Inner = OneHotAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> OneHotAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        OneHotAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args OneHotAttrsArgs,
+    ) -> flatbuffers::WIPOffset<OneHotAttrs<'bldr>> {
+        let mut builder = OneHotAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn axis(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<i32>(OneHotAttrs::VT_AXIS, Some(0)).unwrap() }
+    }
+}
+
+impl flatbuffers::Verifiable for OneHotAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct OneHotAttrsArgs {
+    pub axis: i32,
+}
+impl<'a> Default for OneHotAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        OneHotAttrsArgs { axis: 0 }
+    }
+}
+
+pub struct OneHotAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> OneHotAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_.push_slot::<i32>(OneHotAttrs::VT_AXIS, axis, 0);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> OneHotAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        OneHotAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<OneHotAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for OneHotAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("OneHotAttrs");
+        ds.field("axis", &self.axis());
+        ds.finish()
+    }
+}
+pub enum PadAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PadAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PadAttrs<'a> {
+    type Inner = PadAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> PadAttrs<'a> {
+    pub const VT_MODE: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        PadAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args PadAttrsArgs,
+    ) -> flatbuffers::WIPOffset<PadAttrs<'bldr>> {
+        let mut builder = PadAttrsBuilder::new(_fbb);
+        builder.add_mode(args.mode);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn mode(&self) -> PadOpMode {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<PadOpMode>(PadAttrs::VT_MODE, Some(PadOpMode::Constant))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for PadAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<PadOpMode>("mode", Self::VT_MODE, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct PadAttrsArgs {
+    pub mode: PadOpMode,
+}
+impl<'a> Default for PadAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        PadAttrsArgs {
+            mode: PadOpMode::Constant,
+        }
+    }
+}
+
+pub struct PadAttrsBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PadAttrsBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_mode(&mut self, mode: PadOpMode) {
+        self.fbb_
+            .push_slot::<PadOpMode>(PadAttrs::VT_MODE, mode, PadOpMode::Constant);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PadAttrsBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        PadAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<PadAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for PadAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("PadAttrs");
+        ds.field("mode", &self.mode());
         ds.finish()
     }
 }
-pub enum ModAttrsOffset {}
+pub enum RandomUniformAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ModAttrs<'a> {
+pub struct RandomUniformAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ModAttrs<'a> {
-    type Inner = ModAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for RandomUniformAttrs<'a> {
+    type Inner = RandomUniformAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4354,37 +6608,78 @@ impl<'a> flatbuffers::Follow<'a> for ModAttrs<'a> {
     }
 }
 
-impl<'a> ModAttrs<'a> {
-    pub const VT_FMOD: flatbuffers::VOffsetT = 4;
+impl<'a> RandomUniformAttrs<'a> {
+    pub const VT_SHAPE: flatbuffers::VOffsetT = 4;
+    pub const VT_HIGH: flatbuffers::VOffsetT = 6;
+    pub const VT_LOW: flatbuffers::VOffsetT = 8;
+    pub const VT_SEED: flatbuffers::VOffsetT = 10;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ModAttrs { _tab: table }
+        RandomUniformAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args ModAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ModAttrs<'bldr>> {
-        let mut builder = ModAttrsBuilder::new(_fbb);
-        builder.add_fmod(args.fmod);
+        args: &'args RandomUniformAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<RandomUniformAttrs<'bldr>> {
+        let mut builder = RandomUniformAttrsBuilder::new(_fbb);
+        if let Some(x) = args.seed {
+            builder.add_seed(x);
+        }
+        builder.add_low(args.low);
+        builder.add_high(args.high);
+        if let Some(x) = args.shape {
+            builder.add_shape(x);
+        }
         builder.finish()
     }
 
     #[inline]
-    pub fn fmod(&self) -> bool {
+    pub fn shape(&self) -> Option<flatbuffers::Vector<'a, u32>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<bool>(ModAttrs::VT_FMOD, Some(false))
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    RandomUniformAttrs::VT_SHAPE,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn high(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(RandomUniformAttrs::VT_HIGH, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn low(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(RandomUniformAttrs::VT_LOW, Some(0.0))
                 .unwrap()
         }
     }
+    #[inline]
+    pub fn seed(&self) -> Option<f32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(RandomUniformAttrs::VT_SEED, None) }
+    }
 }
 
-impl flatbuffers::Verifiable for ModAttrs<'_> {
+impl flatbuffers::Verifiable for RandomUniformAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4392,61 +6687,97 @@ impl flatbuffers::Verifiable for ModAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<bool>("fmod", Self::VT_FMOD, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "shape",
+                Self::VT_SHAPE,
+                false,
+            )?
+            .visit_field::<f32>("high", Self::VT_HIGH, false)?
+            .visit_field::<f32>("low", Self::VT_LOW, false)?
+            .visit_field::<f32>("seed", Self::VT_SEED, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ModAttrsArgs {
-    pub fmod: bool,
+pub struct RandomUniformAttrsArgs<'a> {
+    pub shape: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub high: f32,
+    pub low: f32,
+    pub seed: Option<f32>,
 }
-impl<'a> Default for ModAttrsArgs {
+impl<'a> Default for RandomUniformAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        ModAttrsArgs { fmod: false }
+        RandomUniformAttrsArgs {
+            shape: None,
+            high: 0.0,
+            low: 0.0,
+            seed: None,
+        }
     }
 }
 
-pub struct ModAttrsBuilder<'a: 'b, 'b> {
+pub struct RandomUniformAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> ModAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> RandomUniformAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_fmod(&mut self, fmod: bool) {
-        self.fbb_.push_slot::<bool>(ModAttrs::VT_FMOD, fmod, false);
+    pub fn add_shape(&mut self, shape: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(RandomUniformAttrs::VT_SHAPE, shape);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ModAttrsBuilder<'a, 'b> {
+    pub fn add_high(&mut self, high: f32) {
+        self.fbb_
+            .push_slot::<f32>(RandomUniformAttrs::VT_HIGH, high, 0.0);
+    }
+    #[inline]
+    pub fn add_low(&mut self, low: f32) {
+        self.fbb_
+            .push_slot::<f32>(RandomUniformAttrs::VT_LOW, low, 0.0);
+    }
+    #[inline]
+    pub fn add_seed(&mut self, seed: f32) {
+        self.fbb_
+            .push_slot_always::<f32>(RandomUniformAttrs::VT_SEED, seed);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> RandomUniformAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        ModAttrsBuilder {
+        RandomUniformAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ModAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<RandomUniformAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ModAttrs<'_> {
+impl core::fmt::Debug for RandomUniformAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ModAttrs");
-        ds.field("fmod", &self.fmod());
+        let mut ds = f.debug_struct("RandomUniformAttrs");
+        ds.field("shape", &self.shape());
+        ds.field("high", &self.high());
+        ds.field("low", &self.low());
+        ds.field("seed", &self.seed());
         ds.finish()
     }
 }
-pub enum NonMaxSuppressionAttrsOffset {}
+pub enum ReduceMeanAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct NonMaxSuppressionAttrs<'a> {
+pub struct ReduceMeanAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for NonMaxSuppressionAttrs<'a> {
-    type Inner = NonMaxSuppressionAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for ReduceMeanAttrs<'a> {
+    type Inner = ReduceMeanAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4455,40 +6786,54 @@ impl<'a> flatbuffers::Follow<'a> for NonMaxSuppressionAttrs<'a> {
     }
 }
 
-impl<'a> NonMaxSuppressionAttrs<'a> {
-    pub const VT_BOX_ORDER: flatbuffers::VOffsetT = 4;
+impl<'a> ReduceMeanAttrs<'a> {
+    pub const VT_AXES: flatbuffers::VOffsetT = 4;
+    pub const VT_KEEP_DIMS: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        NonMaxSuppressionAttrs { _tab: table }
+        ReduceMeanAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args NonMaxSuppressionAttrsArgs,
-    ) -> flatbuffers::WIPOffset<NonMaxSuppressionAttrs<'bldr>> {
-        let mut builder = NonMaxSuppressionAttrsBuilder::new(_fbb);
-        builder.add_box_order(args.box_order);
+        args: &'args ReduceMeanAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<ReduceMeanAttrs<'bldr>> {
+        let mut builder = ReduceMeanAttrsBuilder::new(_fbb);
+        if let Some(x) = args.axes {
+            builder.add_axes(x);
+        }
+        builder.add_keep_dims(args.keep_dims);
         builder.finish()
     }
 
     #[inline]
-    pub fn box_order(&self) -> NMSBoxOrder {
+    pub fn axes(&self) -> Option<flatbuffers::Vector<'a, i32>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<NMSBoxOrder>(
-                    NonMaxSuppressionAttrs::VT_BOX_ORDER,
-                    Some(NMSBoxOrder::TopLeftBottomRight),
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
+                    ReduceMeanAttrs::VT_AXES,
+                    None,
                 )
+        }
+    }
+    #[inline]
+    pub fn keep_dims(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(ReduceMeanAttrs::VT_KEEP_DIMS, Some(false))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for NonMaxSuppressionAttrs<'_> {
+impl flatbuffers::Verifiable for ReduceMeanAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4496,69 +6841,77 @@ impl flatbuffers::Verifiable for NonMaxSuppressionAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<NMSBoxOrder>("box_order", Self::VT_BOX_ORDER, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>(
+                "axes",
+                Self::VT_AXES,
+                false,
+            )?
+            .visit_field::<bool>("keep_dims", Self::VT_KEEP_DIMS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct NonMaxSuppressionAttrsArgs {
-    pub box_order: NMSBoxOrder,
+pub struct ReduceMeanAttrsArgs<'a> {
+    pub axes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
+    pub keep_dims: bool,
 }
-impl<'a> Default for NonMaxSuppressionAttrsArgs {
+impl<'a> Default for ReduceMeanAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        NonMaxSuppressionAttrsArgs {
-            box_order: NMSBoxOrder::TopLeftBottomRight,
+        ReduceMeanAttrsArgs {
+            axes: None,
+            keep_dims: false,
         }
     }
 }
 
-pub struct NonMaxSuppressionAttrsBuilder<'a: 'b, 'b> {
+pub struct ReduceMeanAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> NonMaxSuppressionAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> ReduceMeanAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_box_order(&mut self, box_order: NMSBoxOrder) {
-        self.fbb_.push_slot::<NMSBoxOrder>(
-            NonMaxSuppressionAttrs::VT_BOX_ORDER,
-            box_order,
-            NMSBoxOrder::TopLeftBottomRight,
-        );
+    pub fn add_axes(&mut self, axes: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ReduceMeanAttrs::VT_AXES, axes);
     }
     #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-    ) -> NonMaxSuppressionAttrsBuilder<'a, 'b> {
+    pub fn add_keep_dims(&mut self, keep_dims: bool) {
+        self.fbb_
+            .push_slot::<bool>(ReduceMeanAttrs::VT_KEEP_DIMS, keep_dims, false);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ReduceMeanAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        NonMaxSuppressionAttrsBuilder {
+        ReduceMeanAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<NonMaxSuppressionAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ReduceMeanAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for NonMaxSuppressionAttrs<'_> {
+impl core::fmt::Debug for ReduceMeanAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("NonMaxSuppressionAttrs");
-        ds.field("box_order", &self.box_order());
+        let mut ds = f.debug_struct("ReduceMeanAttrs");
+        ds.field("axes", &self.axes());
+        ds.field("keep_dims", &self.keep_dims());
         ds.finish()
     }
 }
-pub enum OneHotAttrsOffset {}
+pub enum ReshapeAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct OneHotAttrs<'a> {
+pub struct ReshapeAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for OneHotAttrs<'a> {
-    type Inner = OneHotAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for ReshapeAttrs<'a> {
+    type Inner = ReshapeAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4567,33 +6920,37 @@ impl<'a> flatbuffers::Follow<'a> for OneHotAttrs<'a> {
     }
 }
 
-impl<'a> OneHotAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+impl<'a> ReshapeAttrs<'a> {
+    pub const VT_ALLOW_ZERO: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        OneHotAttrs { _tab: table }
+        ReshapeAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args OneHotAttrsArgs,
-    ) -> flatbuffers::WIPOffset<OneHotAttrs<'bldr>> {
-        let mut builder = OneHotAttrsBuilder::new(_fbb);
-        builder.add_axis(args.axis);
+        args: &'args ReshapeAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ReshapeAttrs<'bldr>> {
+        let mut builder = ReshapeAttrsBuilder::new(_fbb);
+        builder.add_allow_zero(args.allow_zero);
         builder.finish()
     }
 
     #[inline]
-    pub fn axis(&self) -> i32 {
+    pub fn allow_zero(&self) -> bool {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(OneHotAttrs::VT_AXIS, Some(0)).unwrap() }
+        unsafe {
+            self._tab
+                .get::<bool>(ReshapeAttrs::VT_ALLOW_ZERO, Some(false))
+                .unwrap()
+        }
     }
 }
 
-impl flatbuffers::Verifiable for OneHotAttrs<'_> {
+impl flatbuffers::Verifiable for ReshapeAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4601,61 +6958,62 @@ impl flatbuffers::Verifiable for OneHotAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<bool>("allow_zero", Self::VT_ALLOW_ZERO, false)?
             .finish();
         Ok(())
     }
 }
-pub struct OneHotAttrsArgs {
-    pub axis: i32,
+pub struct ReshapeAttrsArgs {
+    pub allow_zero: bool,
 }
-impl<'a> Default for OneHotAttrsArgs {
+impl<'a> Default for ReshapeAttrsArgs {
     #[inline]
     fn default() -> Self {
-        OneHotAttrsArgs { axis: 0 }
+        ReshapeAttrsArgs { allow_zero: false }
     }
 }
 
-pub struct OneHotAttrsBuilder<'a: 'b, 'b> {
+pub struct ReshapeAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> OneHotAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> ReshapeAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_.push_slot::<i32>(OneHotAttrs::VT_AXIS, axis, 0);
+    pub fn add_allow_zero(&mut self, allow_zero: bool) {
+        self.fbb_
+            .push_slot::<bool>(ReshapeAttrs::VT_ALLOW_ZERO, allow_zero, false);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> OneHotAttrsBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ReshapeAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        OneHotAttrsBuilder {
+        ReshapeAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<OneHotAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ReshapeAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for OneHotAttrs<'_> {
+impl core::fmt::Debug for ReshapeAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("OneHotAttrs");
-        ds.field("axis", &self.axis());
+        let mut ds = f.debug_struct("ReshapeAttrs");
+        ds.field("allow_zero", &self.allow_zero());
         ds.finish()
     }
 }
-pub enum RandomUniformAttrsOffset {}
+pub enum ResizeAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct RandomUniformAttrs<'a> {
+pub struct ResizeAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for RandomUniformAttrs<'a> {
-    type Inner = RandomUniformAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for ResizeAttrs<'a> {
+    type Inner = ResizeAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4664,78 +7022,135 @@ impl<'a> flatbuffers::Follow<'a> for RandomUniformAttrs<'a> {
     }
 }
 
-impl<'a> RandomUniformAttrs<'a> {
-    pub const VT_SHAPE: flatbuffers::VOffsetT = 4;
-    pub const VT_HIGH: flatbuffers::VOffsetT = 6;
-    pub const VT_LOW: flatbuffers::VOffsetT = 8;
-    pub const VT_SEED: flatbuffers::VOffsetT = 10;
+impl<'a> ResizeAttrs<'a> {
+    pub const VT_MODE: flatbuffers::VOffsetT = 4;
+    pub const VT_COORD_MODE: flatbuffers::VOffsetT = 6;
+    pub const VT_NEAREST_MODE: flatbuffers::VOffsetT = 8;
+    pub const VT_CUBIC_COEFF_A: flatbuffers::VOffsetT = 10;
+    pub const VT_ANTIALIAS: flatbuffers::VOffsetT = 12;
+    pub const VT_EXCLUDE_OUTSIDE: flatbuffers::VOffsetT = 14;
+    pub const VT_EXTRAPOLATION_VALUE: flatbuffers::VOffsetT = 16;
+    pub const VT_AXES: flatbuffers::VOffsetT = 18;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        RandomUniformAttrs { _tab: table }
+        ResizeAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args RandomUniformAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<RandomUniformAttrs<'bldr>> {
-        let mut builder = RandomUniformAttrsBuilder::new(_fbb);
-        if let Some(x) = args.seed {
-            builder.add_seed(x);
-        }
-        builder.add_low(args.low);
-        builder.add_high(args.high);
-        if let Some(x) = args.shape {
-            builder.add_shape(x);
+        args: &'args ResizeAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<ResizeAttrs<'bldr>> {
+        let mut builder = ResizeAttrsBuilder::new(_fbb);
+        if let Some(x) = args.axes {
+            builder.add_axes(x);
         }
+        builder.add_extrapolation_value(args.extrapolation_value);
+        builder.add_cubic_coeff_a(args.cubic_coeff_a);
+        builder.add_exclude_outside(args.exclude_outside);
+        builder.add_antialias(args.antialias);
+        builder.add_nearest_mode(args.nearest_mode);
+        builder.add_coord_mode(args.coord_mode);
+        builder.add_mode(args.mode);
         builder.finish()
     }
 
     #[inline]
-    pub fn shape(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    pub fn mode(&self) -> ResizeMode {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    RandomUniformAttrs::VT_SHAPE,
-                    None,
+                .get::<ResizeMode>(ResizeAttrs::VT_MODE, Some(ResizeMode::Nearest))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn coord_mode(&self) -> CoordTransformMode {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<CoordTransformMode>(
+                    ResizeAttrs::VT_COORD_MODE,
+                    Some(CoordTransformMode::HalfPixel),
                 )
+                .unwrap()
         }
     }
     #[inline]
-    pub fn high(&self) -> f32 {
+    pub fn nearest_mode(&self) -> NearestMode {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(RandomUniformAttrs::VT_HIGH, Some(0.0))
+                .get::<NearestMode>(ResizeAttrs::VT_NEAREST_MODE, Some(NearestMode::Floor))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn low(&self) -> f32 {
+    pub fn cubic_coeff_a(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(RandomUniformAttrs::VT_LOW, Some(0.0))
+                .get::<f32>(ResizeAttrs::VT_CUBIC_COEFF_A, Some(-0.75))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn seed(&self) -> Option<f32> {
+    pub fn antialias(&self) -> bool {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<f32>(RandomUniformAttrs::VT_SEED, None) }
+        unsafe {
+            self._tab
+                .get::<bool>(ResizeAttrs::VT_ANTIALIAS, Some(false))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn exclude_outside(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(ResizeAttrs::VT_EXCLUDE_OUTSIDE, Some(false))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn extrapolation_value(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(ResizeAttrs::VT_EXTRAPOLATION_VALUE, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn axes(&self) -> Option<flatbuffers::Vector<'a, i32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
+                    ResizeAttrs::VT_AXES,
+                    None,
+                )
+        }
     }
 }
 
-impl flatbuffers::Verifiable for RandomUniformAttrs<'_> {
+impl flatbuffers::Verifiable for ResizeAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4743,97 +7158,140 @@ impl flatbuffers::Verifiable for RandomUniformAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "shape",
-                Self::VT_SHAPE,
+            .visit_field::<ResizeMode>("mode", Self::VT_MODE, false)?
+            .visit_field::<CoordTransformMode>("coord_mode", Self::VT_COORD_MODE, false)?
+            .visit_field::<NearestMode>("nearest_mode", Self::VT_NEAREST_MODE, false)?
+            .visit_field::<f32>("cubic_coeff_a", Self::VT_CUBIC_COEFF_A, false)?
+            .visit_field::<bool>("antialias", Self::VT_ANTIALIAS, false)?
+            .visit_field::<bool>("exclude_outside", Self::VT_EXCLUDE_OUTSIDE, false)?
+            .visit_field::<f32>("extrapolation_value", Self::VT_EXTRAPOLATION_VALUE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>(
+                "axes",
+                Self::VT_AXES,
                 false,
             )?
-            .visit_field::<f32>("high", Self::VT_HIGH, false)?
-            .visit_field::<f32>("low", Self::VT_LOW, false)?
-            .visit_field::<f32>("seed", Self::VT_SEED, false)?
             .finish();
         Ok(())
     }
 }
-pub struct RandomUniformAttrsArgs<'a> {
-    pub shape: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub high: f32,
-    pub low: f32,
-    pub seed: Option<f32>,
+pub struct ResizeAttrsArgs<'a> {
+    pub mode: ResizeMode,
+    pub coord_mode: CoordTransformMode,
+    pub nearest_mode: NearestMode,
+    pub cubic_coeff_a: f32,
+    pub antialias: bool,
+    pub exclude_outside: bool,
+    pub extrapolation_value: f32,
+    pub axes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
 }
-impl<'a> Default for RandomUniformAttrsArgs<'a> {
+impl<'a> Default for ResizeAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        RandomUniformAttrsArgs {
-            shape: None,
-            high: 0.0,
-            low: 0.0,
-            seed: None,
+        ResizeAttrsArgs {
+            mode: ResizeMode::Nearest,
+            coord_mode: CoordTransformMode::HalfPixel,
+            nearest_mode: NearestMode::Floor,
+            cubic_coeff_a: -0.75,
+            antialias: false,
+            exclude_outside: false,
+            extrapolation_value: 0.0,
+            axes: None,
         }
     }
 }
 
-pub struct RandomUniformAttrsBuilder<'a: 'b, 'b> {
+pub struct ResizeAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> RandomUniformAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> ResizeAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_shape(&mut self, shape: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+    pub fn add_mode(&mut self, mode: ResizeMode) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(RandomUniformAttrs::VT_SHAPE, shape);
+            .push_slot::<ResizeMode>(ResizeAttrs::VT_MODE, mode, ResizeMode::Nearest);
     }
     #[inline]
-    pub fn add_high(&mut self, high: f32) {
+    pub fn add_coord_mode(&mut self, coord_mode: CoordTransformMode) {
+        self.fbb_.push_slot::<CoordTransformMode>(
+            ResizeAttrs::VT_COORD_MODE,
+            coord_mode,
+            CoordTransformMode::HalfPixel,
+        );
+    }
+    #[inline]
+    pub fn add_nearest_mode(&mut self, nearest_mode: NearestMode) {
+        self.fbb_.push_slot::<NearestMode>(
+            ResizeAttrs::VT_NEAREST_MODE,
+            nearest_mode,
+            NearestMode::Floor,
+        );
+    }
+    #[inline]
+    pub fn add_cubic_coeff_a(&mut self, cubic_coeff_a: f32) {
         self.fbb_
-            .push_slot::<f32>(RandomUniformAttrs::VT_HIGH, high, 0.0);
+            .push_slot::<f32>(ResizeAttrs::VT_CUBIC_COEFF_A, cubic_coeff_a, -0.75);
     }
     #[inline]
-    pub fn add_low(&mut self, low: f32) {
+    pub fn add_antialias(&mut self, antialias: bool) {
         self.fbb_
-            .push_slot::<f32>(RandomUniformAttrs::VT_LOW, low, 0.0);
+            .push_slot::<bool>(ResizeAttrs::VT_ANTIALIAS, antialias, false);
     }
     #[inline]
-    pub fn add_seed(&mut self, seed: f32) {
+    pub fn add_exclude_outside(&mut self, exclude_outside: bool) {
         self.fbb_
-            .push_slot_always::<f32>(RandomUniformAttrs::VT_SEED, seed);
+            .push_slot::<bool>(ResizeAttrs::VT_EXCLUDE_OUTSIDE, exclude_outside, false);
     }
     #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-    ) -> RandomUniformAttrsBuilder<'a, 'b> {
+    pub fn add_extrapolation_value(&mut self, extrapolation_value: f32) {
+        self.fbb_.push_slot::<f32>(
+            ResizeAttrs::VT_EXTRAPOLATION_VALUE,
+            extrapolation_value,
+            0.0,
+        );
+    }
+    #[inline]
+    pub fn add_axes(&mut self, axes: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ResizeAttrs::VT_AXES, axes);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ResizeAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        RandomUniformAttrsBuilder {
+        ResizeAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<RandomUniformAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ResizeAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for RandomUniformAttrs<'_> {
+impl core::fmt::Debug for ResizeAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("RandomUniformAttrs");
-        ds.field("shape", &self.shape());
-        ds.field("high", &self.high());
-        ds.field("low", &self.low());
-        ds.field("seed", &self.seed());
+        let mut ds = f.debug_struct("ResizeAttrs");
+        ds.field("mode", &self.mode());
+        ds.field("coord_mode", &self.coord_mode());
+        ds.field("nearest_mode", &self.nearest_mode());
+        ds.field("cubic_coeff_a", &self.cubic_coeff_a());
+        ds.field("antialias", &self.antialias());
+        ds.field("exclude_outside", &self.exclude_outside());
+        ds.field("extrapolation_value", &self.extrapolation_value());
+        ds.field("axes", &self.axes());
         ds.finish()
     }
 }
-pub enum ReduceMeanAttrsOffset {}
+pub enum ScatterElementsAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ReduceMeanAttrs<'a> {
+pub struct ScatterElementsAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ReduceMeanAttrs<'a> {
-    type Inner = ReduceMeanAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for ScatterElementsAttrs<'a> {
+    type Inner = ScatterElementsAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4842,54 +7300,53 @@ impl<'a> flatbuffers::Follow<'a> for ReduceMeanAttrs<'a> {
     }
 }
 
-impl<'a> ReduceMeanAttrs<'a> {
-    pub const VT_AXES: flatbuffers::VOffsetT = 4;
-    pub const VT_KEEP_DIMS: flatbuffers::VOffsetT = 6;
+impl<'a> ScatterElementsAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_REDUCTION: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ReduceMeanAttrs { _tab: table }
+        ScatterElementsAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args ReduceMeanAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<ReduceMeanAttrs<'bldr>> {
-        let mut builder = ReduceMeanAttrsBuilder::new(_fbb);
-        if let Some(x) = args.axes {
-            builder.add_axes(x);
-        }
-        builder.add_keep_dims(args.keep_dims);
+        args: &'args ScatterElementsAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ScatterElementsAttrs<'bldr>> {
+        let mut builder = ScatterElementsAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
+        builder.add_reduction(args.reduction);
         builder.finish()
     }
 
     #[inline]
-    pub fn axes(&self) -> Option<flatbuffers::Vector<'a, i32>> {
+    pub fn axis(&self) -> i32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
-                    ReduceMeanAttrs::VT_AXES,
-                    None,
-                )
+                .get::<i32>(ScatterElementsAttrs::VT_AXIS, Some(0))
+                .unwrap()
         }
     }
     #[inline]
-    pub fn keep_dims(&self) -> bool {
+    pub fn reduction(&self) -> ScatterReduction {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<bool>(ReduceMeanAttrs::VT_KEEP_DIMS, Some(false))
+                .get::<ScatterReduction>(
+                    ScatterElementsAttrs::VT_REDUCTION,
+                    Some(ScatterReduction::None),
+                )
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for ReduceMeanAttrs<'_> {
+impl flatbuffers::Verifiable for ScatterElementsAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4897,77 +7354,78 @@ impl flatbuffers::Verifiable for ReduceMeanAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>(
-                "axes",
-                Self::VT_AXES,
-                false,
-            )?
-            .visit_field::<bool>("keep_dims", Self::VT_KEEP_DIMS, false)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<ScatterReduction>("reduction", Self::VT_REDUCTION, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ReduceMeanAttrsArgs<'a> {
-    pub axes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
-    pub keep_dims: bool,
+pub struct ScatterElementsAttrsArgs {
+    pub axis: i32,
+    pub reduction: ScatterReduction,
 }
-impl<'a> Default for ReduceMeanAttrsArgs<'a> {
+impl<'a> Default for ScatterElementsAttrsArgs {
     #[inline]
     fn default() -> Self {
-        ReduceMeanAttrsArgs {
-            axes: None,
-            keep_dims: false,
+        ScatterElementsAttrsArgs {
+            axis: 0,
+            reduction: ScatterReduction::None,
         }
     }
 }
 
-pub struct ReduceMeanAttrsBuilder<'a: 'b, 'b> {
+pub struct ScatterElementsAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> ReduceMeanAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> ScatterElementsAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_axes(&mut self, axes: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>) {
+    pub fn add_axis(&mut self, axis: i32) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(ReduceMeanAttrs::VT_AXES, axes);
+            .push_slot::<i32>(ScatterElementsAttrs::VT_AXIS, axis, 0);
     }
     #[inline]
-    pub fn add_keep_dims(&mut self, keep_dims: bool) {
-        self.fbb_
-            .push_slot::<bool>(ReduceMeanAttrs::VT_KEEP_DIMS, keep_dims, false);
+    pub fn add_reduction(&mut self, reduction: ScatterReduction) {
+        self.fbb_.push_slot::<ScatterReduction>(
+            ScatterElementsAttrs::VT_REDUCTION,
+            reduction,
+            ScatterReduction::None,
+        );
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ReduceMeanAttrsBuilder<'a, 'b> {
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> ScatterElementsAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        ReduceMeanAttrsBuilder {
+        ScatterElementsAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ReduceMeanAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ScatterElementsAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ReduceMeanAttrs<'_> {
+impl core::fmt::Debug for ScatterElementsAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ReduceMeanAttrs");
-        ds.field("axes", &self.axes());
-        ds.field("keep_dims", &self.keep_dims());
+        let mut ds = f.debug_struct("ScatterElementsAttrs");
+        ds.field("axis", &self.axis());
+        ds.field("reduction", &self.reduction());
         ds.finish()
     }
 }
-pub enum ReshapeAttrsOffset {}
+pub enum ScatterNDAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ReshapeAttrs<'a> {
+pub struct ScatterNDAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ReshapeAttrs<'a> {
-    type Inner = ReshapeAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for ScatterNDAttrs<'a> {
+    type Inner = ScatterNDAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4976,37 +7434,37 @@ impl<'a> flatbuffers::Follow<'a> for ReshapeAttrs<'a> {
     }
 }
 
-impl<'a> ReshapeAttrs<'a> {
-    pub const VT_ALLOW_ZERO: flatbuffers::VOffsetT = 4;
+impl<'a> ScatterNDAttrs<'a> {
+    pub const VT_REDUCTION: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ReshapeAttrs { _tab: table }
+        ScatterNDAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args ReshapeAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ReshapeAttrs<'bldr>> {
-        let mut builder = ReshapeAttrsBuilder::new(_fbb);
-        builder.add_allow_zero(args.allow_zero);
+        args: &'args ScatterNDAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ScatterNDAttrs<'bldr>> {
+        let mut builder = ScatterNDAttrsBuilder::new(_fbb);
+        builder.add_reduction(args.reduction);
         builder.finish()
     }
 
     #[inline]
-    pub fn allow_zero(&self) -> bool {
+    pub fn reduction(&self) -> ScatterReduction {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<bool>(ReshapeAttrs::VT_ALLOW_ZERO, Some(false))
+                .get::<ScatterReduction>(ScatterNDAttrs::VT_REDUCTION, Some(ScatterReduction::None))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for ReshapeAttrs<'_> {
+impl flatbuffers::Verifiable for ScatterNDAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5014,62 +7472,67 @@ impl flatbuffers::Verifiable for ReshapeAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<bool>("allow_zero", Self::VT_ALLOW_ZERO, false)?
+            .visit_field::<ScatterReduction>("reduction", Self::VT_REDUCTION, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ReshapeAttrsArgs {
-    pub allow_zero: bool,
+pub struct ScatterNDAttrsArgs {
+    pub reduction: ScatterReduction,
 }
-impl<'a> Default for ReshapeAttrsArgs {
+impl<'a> Default for ScatterNDAttrsArgs {
     #[inline]
     fn default() -> Self {
-        ReshapeAttrsArgs { allow_zero: false }
+        ScatterNDAttrsArgs {
+            reduction: ScatterReduction::None,
+        }
     }
 }
 
-pub struct ReshapeAttrsBuilder<'a: 'b, 'b> {
+pub struct ScatterNDAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> ReshapeAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> ScatterNDAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_allow_zero(&mut self, allow_zero: bool) {
-        self.fbb_
-            .push_slot::<bool>(ReshapeAttrs::VT_ALLOW_ZERO, allow_zero, false);
+    pub fn add_reduction(&mut self, reduction: ScatterReduction) {
+        self.fbb_.push_slot::<ScatterReduction>(
+            ScatterNDAttrs::VT_REDUCTION,
+            reduction,
+            ScatterReduction::None,
+        );
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ReshapeAttrsBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ScatterNDAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        ReshapeAttrsBuilder {
+        ScatterNDAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ReshapeAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ScatterNDAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ReshapeAttrs<'_> {
+impl core::fmt::Debug for ScatterNDAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ReshapeAttrs");
-        ds.field("allow_zero", &self.allow_zero());
+        let mut ds = f.debug_struct("ScatterNDAttrs");
+        ds.field("reduction", &self.reduction());
         ds.finish()
     }
 }
-pub enum ResizeAttrsOffset {}
+pub enum SeluAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ResizeAttrs<'a> {
+pub struct SeluAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ResizeAttrs<'a> {
-    type Inner = ResizeAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for SeluAttrs<'a> {
+    type Inner = SeluAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5078,66 +7541,50 @@ impl<'a> flatbuffers::Follow<'a> for ResizeAttrs<'a> {
     }
 }
 
-impl<'a> ResizeAttrs<'a> {
-    pub const VT_MODE: flatbuffers::VOffsetT = 4;
-    pub const VT_COORD_MODE: flatbuffers::VOffsetT = 6;
-    pub const VT_NEAREST_MODE: flatbuffers::VOffsetT = 8;
+impl<'a> SeluAttrs<'a> {
+    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
+    pub const VT_GAMMA: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ResizeAttrs { _tab: table }
+        SeluAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args ResizeAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ResizeAttrs<'bldr>> {
-        let mut builder = ResizeAttrsBuilder::new(_fbb);
-        builder.add_nearest_mode(args.nearest_mode);
-        builder.add_coord_mode(args.coord_mode);
-        builder.add_mode(args.mode);
+        args: &'args SeluAttrsArgs,
+    ) -> flatbuffers::WIPOffset<SeluAttrs<'bldr>> {
+        let mut builder = SeluAttrsBuilder::new(_fbb);
+        builder.add_gamma(args.gamma);
+        builder.add_alpha(args.alpha);
         builder.finish()
     }
 
     #[inline]
-    pub fn mode(&self) -> ResizeMode {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<ResizeMode>(ResizeAttrs::VT_MODE, Some(ResizeMode::Nearest))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn coord_mode(&self) -> CoordTransformMode {
+    pub fn alpha(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<CoordTransformMode>(
-                    ResizeAttrs::VT_COORD_MODE,
-                    Some(CoordTransformMode::HalfPixel),
-                )
+                .get::<f32>(SeluAttrs::VT_ALPHA, Some(0.0))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn nearest_mode(&self) -> NearestMode {
+    pub fn gamma(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<NearestMode>(ResizeAttrs::VT_NEAREST_MODE, Some(NearestMode::Floor))
+                .get::<f32>(SeluAttrs::VT_GAMMA, Some(0.0))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for ResizeAttrs<'_> {
+impl flatbuffers::Verifiable for SeluAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5145,88 +7592,71 @@ impl flatbuffers::Verifiable for ResizeAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<ResizeMode>("mode", Self::VT_MODE, false)?
-            .visit_field::<CoordTransformMode>("coord_mode", Self::VT_COORD_MODE, false)?
-            .visit_field::<NearestMode>("nearest_mode", Self::VT_NEAREST_MODE, false)?
+            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
+            .visit_field::<f32>("gamma", Self::VT_GAMMA, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ResizeAttrsArgs {
-    pub mode: ResizeMode,
-    pub coord_mode: CoordTransformMode,
-    pub nearest_mode: NearestMode,
+pub struct SeluAttrsArgs {
+    pub alpha: f32,
+    pub gamma: f32,
 }
-impl<'a> Default for ResizeAttrsArgs {
+impl<'a> Default for SeluAttrsArgs {
     #[inline]
     fn default() -> Self {
-        ResizeAttrsArgs {
-            mode: ResizeMode::Nearest,
-            coord_mode: CoordTransformMode::HalfPixel,
-            nearest_mode: NearestMode::Floor,
+        SeluAttrsArgs {
+            alpha: 0.0,
+            gamma: 0.0,
         }
     }
 }
 
-pub struct ResizeAttrsBuilder<'a: 'b, 'b> {
+pub struct SeluAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> ResizeAttrsBuilder<'a, 'b> {
-    #[inline]
-    pub fn add_mode(&mut self, mode: ResizeMode) {
-        self.fbb_
-            .push_slot::<ResizeMode>(ResizeAttrs::VT_MODE, mode, ResizeMode::Nearest);
-    }
+impl<'a: 'b, 'b> SeluAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_coord_mode(&mut self, coord_mode: CoordTransformMode) {
-        self.fbb_.push_slot::<CoordTransformMode>(
-            ResizeAttrs::VT_COORD_MODE,
-            coord_mode,
-            CoordTransformMode::HalfPixel,
-        );
+    pub fn add_alpha(&mut self, alpha: f32) {
+        self.fbb_.push_slot::<f32>(SeluAttrs::VT_ALPHA, alpha, 0.0);
     }
     #[inline]
-    pub fn add_nearest_mode(&mut self, nearest_mode: NearestMode) {
-        self.fbb_.push_slot::<NearestMode>(
-            ResizeAttrs::VT_NEAREST_MODE,
-            nearest_mode,
-            NearestMode::Floor,
-        );
+    pub fn add_gamma(&mut self, gamma: f32) {
+        self.fbb_.push_slot::<f32>(SeluAttrs::VT_GAMMA, gamma, 0.0);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ResizeAttrsBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SeluAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        ResizeAttrsBuilder {
+        SeluAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ResizeAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<SeluAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ResizeAttrs<'_> {
+impl core::fmt::Debug for SeluAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ResizeAttrs");
-        ds.field("mode", &self.mode());
-        ds.field("coord_mode", &self.coord_mode());
-        ds.field("nearest_mode", &self.nearest_mode());
+        let mut ds = f.debug_struct("SeluAttrs");
+        ds.field("alpha", &self.alpha());
+        ds.field("gamma", &self.gamma());
         ds.finish()
     }
 }
-pub enum ScatterElementsAttrsOffset {}
+pub enum SoftmaxAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ScatterElementsAttrs<'a> {
+pub struct SoftmaxAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ScatterElementsAttrs<'a> {
-    type Inner = ScatterElementsAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for SoftmaxAttrs<'a> {
+    type Inner = SoftmaxAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5235,22 +7665,20 @@ impl<'a> flatbuffers::Follow<'a> for ScatterElementsAttrs<'a> {
     }
 }
 
-impl<'a> ScatterElementsAttrs<'a> {
+impl<'a> SoftmaxAttrs<'a> {
     pub const VT_AXIS: flatbuffers::VOffsetT = 4;
-    pub const VT_REDUCTION: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ScatterElementsAttrs { _tab: table }
+        SoftmaxAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args ScatterElementsAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ScatterElementsAttrs<'bldr>> {
-        let mut builder = ScatterElementsAttrsBuilder::new(_fbb);
+        args: &'args SoftmaxAttrsArgs,
+    ) -> flatbuffers::WIPOffset<SoftmaxAttrs<'bldr>> {
+        let mut builder = SoftmaxAttrsBuilder::new(_fbb);
         builder.add_axis(args.axis);
-        builder.add_reduction(args.reduction);
         builder.finish()
     }
 
@@ -5260,28 +7688,14 @@ impl<'a> ScatterElementsAttrs<'a> {
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
-            self._tab
-                .get::<i32>(ScatterElementsAttrs::VT_AXIS, Some(0))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn reduction(&self) -> ScatterReduction {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<ScatterReduction>(
-                    ScatterElementsAttrs::VT_REDUCTION,
-                    Some(ScatterReduction::None),
-                )
+            self._tab
+                .get::<i32>(SoftmaxAttrs::VT_AXIS, Some(0))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for ScatterElementsAttrs<'_> {
+impl flatbuffers::Verifiable for SoftmaxAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5290,77 +7704,60 @@ impl flatbuffers::Verifiable for ScatterElementsAttrs<'_> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
             .visit_field::<i32>("axis", Self::VT_AXIS, false)?
-            .visit_field::<ScatterReduction>("reduction", Self::VT_REDUCTION, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ScatterElementsAttrsArgs {
+pub struct SoftmaxAttrsArgs {
     pub axis: i32,
-    pub reduction: ScatterReduction,
 }
-impl<'a> Default for ScatterElementsAttrsArgs {
+impl<'a> Default for SoftmaxAttrsArgs {
     #[inline]
     fn default() -> Self {
-        ScatterElementsAttrsArgs {
-            axis: 0,
-            reduction: ScatterReduction::None,
-        }
+        SoftmaxAttrsArgs { axis: 0 }
     }
 }
 
-pub struct ScatterElementsAttrsBuilder<'a: 'b, 'b> {
+pub struct SoftmaxAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> ScatterElementsAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> SoftmaxAttrsBuilder<'a, 'b> {
     #[inline]
     pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_
-            .push_slot::<i32>(ScatterElementsAttrs::VT_AXIS, axis, 0);
-    }
-    #[inline]
-    pub fn add_reduction(&mut self, reduction: ScatterReduction) {
-        self.fbb_.push_slot::<ScatterReduction>(
-            ScatterElementsAttrs::VT_REDUCTION,
-            reduction,
-            ScatterReduction::None,
-        );
+        self.fbb_.push_slot::<i32>(SoftmaxAttrs::VT_AXIS, axis, 0);
     }
     #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-    ) -> ScatterElementsAttrsBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SoftmaxAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        ScatterElementsAttrsBuilder {
+        SoftmaxAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ScatterElementsAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<SoftmaxAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ScatterElementsAttrs<'_> {
+impl core::fmt::Debug for SoftmaxAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ScatterElementsAttrs");
+        let mut ds = f.debug_struct("SoftmaxAttrs");
         ds.field("axis", &self.axis());
-        ds.field("reduction", &self.reduction());
         ds.finish()
     }
 }
-pub enum ScatterNDAttrsOffset {}
+pub enum SplitAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ScatterNDAttrs<'a> {
+pub struct SplitAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ScatterNDAttrs<'a> {
-    type Inner = ScatterNDAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for SplitAttrs<'a> {
+    type Inner = SplitAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5369,37 +7766,33 @@ impl<'a> flatbuffers::Follow<'a> for ScatterNDAttrs<'a> {
     }
 }
 
-impl<'a> ScatterNDAttrs<'a> {
-    pub const VT_REDUCTION: flatbuffers::VOffsetT = 4;
+impl<'a> SplitAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ScatterNDAttrs { _tab: table }
+        SplitAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args ScatterNDAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ScatterNDAttrs<'bldr>> {
-        let mut builder = ScatterNDAttrsBuilder::new(_fbb);
-        builder.add_reduction(args.reduction);
+        args: &'args SplitAttrsArgs,
+    ) -> flatbuffers::WIPOffset<SplitAttrs<'bldr>> {
+        let mut builder = SplitAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
         builder.finish()
     }
 
     #[inline]
-    pub fn reduction(&self) -> ScatterReduction {
+    pub fn axis(&self) -> i32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<ScatterReduction>(ScatterNDAttrs::VT_REDUCTION, Some(ScatterReduction::None))
-                .unwrap()
-        }
+        unsafe { self._tab.get::<i32>(SplitAttrs::VT_AXIS, Some(0)).unwrap() }
     }
 }
 
-impl flatbuffers::Verifiable for ScatterNDAttrs<'_> {
+impl flatbuffers::Verifiable for SplitAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5407,67 +7800,61 @@ impl flatbuffers::Verifiable for ScatterNDAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<ScatterReduction>("reduction", Self::VT_REDUCTION, false)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ScatterNDAttrsArgs {
-    pub reduction: ScatterReduction,
+pub struct SplitAttrsArgs {
+    pub axis: i32,
 }
-impl<'a> Default for ScatterNDAttrsArgs {
+impl<'a> Default for SplitAttrsArgs {
     #[inline]
     fn default() -> Self {
-        ScatterNDAttrsArgs {
-            reduction: ScatterReduction::None,
-        }
+        SplitAttrsArgs { axis: 0 }
     }
 }
 
-pub struct ScatterNDAttrsBuilder<'a: 'b, 'b> {
+pub struct SplitAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> ScatterNDAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> SplitAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_reduction(&mut self, reduction: ScatterReduction) {
-        self.fbb_.push_slot::<ScatterReduction>(
-            ScatterNDAttrs::VT_REDUCTION,
-            reduction,
-            ScatterReduction::None,
-        );
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_.push_slot::<i32>(SplitAttrs::VT_AXIS, axis, 0);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ScatterNDAttrsBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SplitAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        ScatterNDAttrsBuilder {
+        SplitAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ScatterNDAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<SplitAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ScatterNDAttrs<'_> {
+impl core::fmt::Debug for SplitAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ScatterNDAttrs");
-        ds.field("reduction", &self.reduction());
+        let mut ds = f.debug_struct("SplitAttrs");
+        ds.field("axis", &self.axis());
         ds.finish()
     }
 }
-pub enum SoftmaxAttrsOffset {}
+pub enum TopKAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct SoftmaxAttrs<'a> {
+pub struct TopKAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for SoftmaxAttrs<'a> {
-    type Inner = SoftmaxAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for TopKAttrs<'a> {
+    type Inner = TopKAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5476,37 +7863,59 @@ impl<'a> flatbuffers::Follow<'a> for SoftmaxAttrs<'a> {
     }
 }
 
-impl<'a> SoftmaxAttrs<'a> {
+impl<'a> TopKAttrs<'a> {
     pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_LARGEST: flatbuffers::VOffsetT = 6;
+    pub const VT_SORTED: flatbuffers::VOffsetT = 8;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        SoftmaxAttrs { _tab: table }
+        TopKAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args SoftmaxAttrsArgs,
-    ) -> flatbuffers::WIPOffset<SoftmaxAttrs<'bldr>> {
-        let mut builder = SoftmaxAttrsBuilder::new(_fbb);
+        args: &'args TopKAttrsArgs,
+    ) -> flatbuffers::WIPOffset<TopKAttrs<'bldr>> {
+        let mut builder = TopKAttrsBuilder::new(_fbb);
         builder.add_axis(args.axis);
+        builder.add_sorted(args.sorted);
+        builder.add_largest(args.largest);
         builder.finish()
     }
 
     #[inline]
     pub fn axis(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<i32>(TopKAttrs::VT_AXIS, Some(0)).unwrap() }
+    }
+    #[inline]
+    pub fn largest(&self) -> bool {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<i32>(SoftmaxAttrs::VT_AXIS, Some(0))
+                .get::<bool>(TopKAttrs::VT_LARGEST, Some(false))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn sorted(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(TopKAttrs::VT_SORTED, Some(false))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for SoftmaxAttrs<'_> {
+impl flatbuffers::Verifiable for TopKAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5515,60 +7924,80 @@ impl flatbuffers::Verifiable for SoftmaxAttrs<'_> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
             .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<bool>("largest", Self::VT_LARGEST, false)?
+            .visit_field::<bool>("sorted", Self::VT_SORTED, false)?
             .finish();
         Ok(())
     }
 }
-pub struct SoftmaxAttrsArgs {
+pub struct TopKAttrsArgs {
     pub axis: i32,
+    pub largest: bool,
+    pub sorted: bool,
 }
-impl<'a> Default for SoftmaxAttrsArgs {
+impl<'a> Default for TopKAttrsArgs {
     #[inline]
     fn default() -> Self {
-        SoftmaxAttrsArgs { axis: 0 }
+        TopKAttrsArgs {
+            axis: 0,
+            largest: false,
+            sorted: false,
+        }
     }
 }
 
-pub struct SoftmaxAttrsBuilder<'a: 'b, 'b> {
+pub struct TopKAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> SoftmaxAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> TopKAttrsBuilder<'a, 'b> {
     #[inline]
     pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_.push_slot::<i32>(SoftmaxAttrs::VT_AXIS, axis, 0);
+        self.fbb_.push_slot::<i32>(TopKAttrs::VT_AXIS, axis, 0);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SoftmaxAttrsBuilder<'a, 'b> {
+    pub fn add_largest(&mut self, largest: bool) {
+        self.fbb_
+            .push_slot::<bool>(TopKAttrs::VT_LARGEST, largest, false);
+    }
+    #[inline]
+    pub fn add_sorted(&mut self, sorted: bool) {
+        self.fbb_
+            .push_slot::<bool>(TopKAttrs::VT_SORTED, sorted, false);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TopKAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        SoftmaxAttrsBuilder {
+        TopKAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<SoftmaxAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<TopKAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for SoftmaxAttrs<'_> {
+impl core::fmt::Debug for TopKAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("SoftmaxAttrs");
+        let mut ds = f.debug_struct("TopKAttrs");
         ds.field("axis", &self.axis());
+        ds.field("largest", &self.largest());
+        ds.field("sorted", &self.sorted());
         ds.finish()
     }
 }
-pub enum SplitAttrsOffset {}
+pub enum TransposeAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct SplitAttrs<'a> {
+pub struct TransposeAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for SplitAttrs<'a> {
-    type Inner = SplitAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for TransposeAttrs<'a> {
+    type Inner = TransposeAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5577,33 +8006,41 @@ impl<'a> flatbuffers::Follow<'a> for SplitAttrs<'a> {
     }
 }
 
-impl<'a> SplitAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+impl<'a> TransposeAttrs<'a> {
+    pub const VT_PERM: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        SplitAttrs { _tab: table }
+        TransposeAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args SplitAttrsArgs,
-    ) -> flatbuffers::WIPOffset<SplitAttrs<'bldr>> {
-        let mut builder = SplitAttrsBuilder::new(_fbb);
-        builder.add_axis(args.axis);
+        args: &'args TransposeAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<TransposeAttrs<'bldr>> {
+        let mut builder = TransposeAttrsBuilder::new(_fbb);
+        if let Some(x) = args.perm {
+            builder.add_perm(x);
+        }
         builder.finish()
     }
 
     #[inline]
-    pub fn axis(&self) -> i32 {
+    pub fn perm(&self) -> Option<flatbuffers::Vector<'a, u32>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(SplitAttrs::VT_AXIS, Some(0)).unwrap() }
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    TransposeAttrs::VT_PERM,
+                    None,
+                )
+        }
     }
 }
 
-impl flatbuffers::Verifiable for SplitAttrs<'_> {
+impl flatbuffers::Verifiable for TransposeAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5611,61 +8048,66 @@ impl flatbuffers::Verifiable for SplitAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "perm",
+                Self::VT_PERM,
+                false,
+            )?
             .finish();
         Ok(())
     }
 }
-pub struct SplitAttrsArgs {
-    pub axis: i32,
+pub struct TransposeAttrsArgs<'a> {
+    pub perm: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
 }
-impl<'a> Default for SplitAttrsArgs {
+impl<'a> Default for TransposeAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        SplitAttrsArgs { axis: 0 }
+        TransposeAttrsArgs { perm: None }
     }
 }
 
-pub struct SplitAttrsBuilder<'a: 'b, 'b> {
+pub struct TransposeAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> SplitAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> TransposeAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_.push_slot::<i32>(SplitAttrs::VT_AXIS, axis, 0);
+    pub fn add_perm(&mut self, perm: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(TransposeAttrs::VT_PERM, perm);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SplitAttrsBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TransposeAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        SplitAttrsBuilder {
+        TransposeAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<SplitAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<TransposeAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for SplitAttrs<'_> {
+impl core::fmt::Debug for TransposeAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("SplitAttrs");
-        ds.field("axis", &self.axis());
+        let mut ds = f.debug_struct("TransposeAttrs");
+        ds.field("perm", &self.perm());
         ds.finish()
     }
 }
-pub enum TopKAttrsOffset {}
+pub enum TriluAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct TopKAttrs<'a> {
+pub struct TriluAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for TopKAttrs<'a> {
-    type Inner = TopKAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for TriluAttrs<'a> {
+    type Inner = TriluAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5674,59 +8116,37 @@ impl<'a> flatbuffers::Follow<'a> for TopKAttrs<'a> {
     }
 }
 
-impl<'a> TopKAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
-    pub const VT_LARGEST: flatbuffers::VOffsetT = 6;
-    pub const VT_SORTED: flatbuffers::VOffsetT = 8;
+impl<'a> TriluAttrs<'a> {
+    pub const VT_UPPER: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        TopKAttrs { _tab: table }
+        TriluAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args TopKAttrsArgs,
-    ) -> flatbuffers::WIPOffset<TopKAttrs<'bldr>> {
-        let mut builder = TopKAttrsBuilder::new(_fbb);
-        builder.add_axis(args.axis);
-        builder.add_sorted(args.sorted);
-        builder.add_largest(args.largest);
+        args: &'args TriluAttrsArgs,
+    ) -> flatbuffers::WIPOffset<TriluAttrs<'bldr>> {
+        let mut builder = TriluAttrsBuilder::new(_fbb);
+        builder.add_upper(args.upper);
         builder.finish()
     }
 
     #[inline]
-    pub fn axis(&self) -> i32 {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(TopKAttrs::VT_AXIS, Some(0)).unwrap() }
-    }
-    #[inline]
-    pub fn largest(&self) -> bool {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<bool>(TopKAttrs::VT_LARGEST, Some(false))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn sorted(&self) -> bool {
+    pub fn upper(&self) -> bool {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<bool>(TopKAttrs::VT_SORTED, Some(false))
+                .get::<bool>(TriluAttrs::VT_UPPER, Some(false))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for TopKAttrs<'_> {
+impl flatbuffers::Verifiable for TriluAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5734,81 +8154,62 @@ impl flatbuffers::Verifiable for TopKAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
-            .visit_field::<bool>("largest", Self::VT_LARGEST, false)?
-            .visit_field::<bool>("sorted", Self::VT_SORTED, false)?
+            .visit_field::<bool>("upper", Self::VT_UPPER, false)?
             .finish();
         Ok(())
     }
 }
-pub struct TopKAttrsArgs {
-    pub axis: i32,
-    pub largest: bool,
-    pub sorted: bool,
+pub struct TriluAttrsArgs {
+    pub upper: bool,
 }
-impl<'a> Default for TopKAttrsArgs {
+impl<'a> Default for TriluAttrsArgs {
     #[inline]
     fn default() -> Self {
-        TopKAttrsArgs {
-            axis: 0,
-            largest: false,
-            sorted: false,
-        }
+        TriluAttrsArgs { upper: false }
     }
 }
 
-pub struct TopKAttrsBuilder<'a: 'b, 'b> {
+pub struct TriluAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> TopKAttrsBuilder<'a, 'b> {
-    #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_.push_slot::<i32>(TopKAttrs::VT_AXIS, axis, 0);
-    }
-    #[inline]
-    pub fn add_largest(&mut self, largest: bool) {
-        self.fbb_
-            .push_slot::<bool>(TopKAttrs::VT_LARGEST, largest, false);
-    }
+impl<'a: 'b, 'b> TriluAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_sorted(&mut self, sorted: bool) {
+    pub fn add_upper(&mut self, upper: bool) {
         self.fbb_
-            .push_slot::<bool>(TopKAttrs::VT_SORTED, sorted, false);
+            .push_slot::<bool>(TriluAttrs::VT_UPPER, upper, false);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TopKAttrsBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TriluAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        TopKAttrsBuilder {
+        TriluAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<TopKAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<TriluAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for TopKAttrs<'_> {
+impl core::fmt::Debug for TriluAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("TopKAttrs");
-        ds.field("axis", &self.axis());
-        ds.field("largest", &self.largest());
-        ds.field("sorted", &self.sorted());
+        let mut ds = f.debug_struct("TriluAttrs");
+        ds.field("upper", &self.upper());
         ds.finish()
     }
 }
-pub enum TransposeAttrsOffset {}
+pub enum CustomAttrOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct TransposeAttrs<'a> {
+pub struct CustomAttr<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for TransposeAttrs<'a> {
-    type Inner = TransposeAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for CustomAttr<'a> {
+    type Inner = CustomAttr<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5817,41 +8218,140 @@ impl<'a> flatbuffers::Follow<'a> for TransposeAttrs<'a> {
     }
 }
 
-impl<'a> TransposeAttrs<'a> {
-    pub const VT_PERM: flatbuffers::VOffsetT = 4;
+impl<'a> CustomAttr<'a> {
+    pub const VT_NAME: flatbuffers::VOffsetT = 4;
+    pub const VT_TYPE_: flatbuffers::VOffsetT = 6;
+    pub const VT_INT_VALUE: flatbuffers::VOffsetT = 8;
+    pub const VT_FLOAT_VALUE: flatbuffers::VOffsetT = 10;
+    pub const VT_STRING_VALUE: flatbuffers::VOffsetT = 12;
+    pub const VT_INTS: flatbuffers::VOffsetT = 14;
+    pub const VT_FLOATS: flatbuffers::VOffsetT = 16;
+    pub const VT_TENSOR: flatbuffers::VOffsetT = 18;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        TransposeAttrs { _tab: table }
+        CustomAttr { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args TransposeAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<TransposeAttrs<'bldr>> {
-        let mut builder = TransposeAttrsBuilder::new(_fbb);
-        if let Some(x) = args.perm {
-            builder.add_perm(x);
+        args: &'args CustomAttrArgs<'args>,
+    ) -> flatbuffers::WIPOffset<CustomAttr<'bldr>> {
+        let mut builder = CustomAttrBuilder::new(_fbb);
+        builder.add_int_value(args.int_value);
+        if let Some(x) = args.tensor {
+            builder.add_tensor(x);
+        }
+        if let Some(x) = args.floats {
+            builder.add_floats(x);
+        }
+        if let Some(x) = args.ints {
+            builder.add_ints(x);
+        }
+        if let Some(x) = args.string_value {
+            builder.add_string_value(x);
+        }
+        builder.add_float_value(args.float_value);
+        if let Some(x) = args.name {
+            builder.add_name(x);
         }
+        builder.add_type_(args.type_);
         builder.finish()
     }
 
     #[inline]
-    pub fn perm(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    pub fn name(&self) -> &'a str {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    TransposeAttrs::VT_PERM,
+                .get::<flatbuffers::ForwardsUOffset<&str>>(CustomAttr::VT_NAME, None)
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn type_(&self) -> CustomAttrType {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<CustomAttrType>(CustomAttr::VT_TYPE_, Some(CustomAttrType::Int))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn int_value(&self) -> i64 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<i64>(CustomAttr::VT_INT_VALUE, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn float_value(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(CustomAttr::VT_FLOAT_VALUE, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn string_value(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(CustomAttr::VT_STRING_VALUE, None)
+        }
+    }
+    #[inline]
+    pub fn ints(&self) -> Option<flatbuffers::Vector<'a, i64>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i64>>>(
+                    CustomAttr::VT_INTS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn floats(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
+                    CustomAttr::VT_FLOATS,
                     None,
                 )
         }
     }
+    #[inline]
+    pub fn tensor(&self) -> Option<ConstantNode<'a>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<ConstantNode>>(CustomAttr::VT_TENSOR, None)
+        }
+    }
 }
 
-impl flatbuffers::Verifiable for TransposeAttrs<'_> {
+impl flatbuffers::Verifiable for CustomAttr<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5859,66 +8359,149 @@ impl flatbuffers::Verifiable for TransposeAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "perm",
-                Self::VT_PERM,
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, true)?
+            .visit_field::<CustomAttrType>("type_", Self::VT_TYPE_, false)?
+            .visit_field::<i64>("int_value", Self::VT_INT_VALUE, false)?
+            .visit_field::<f32>("float_value", Self::VT_FLOAT_VALUE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                "string_value",
+                Self::VT_STRING_VALUE,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i64>>>(
+                "ints",
+                Self::VT_INTS,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>(
+                "floats",
+                Self::VT_FLOATS,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<ConstantNode>>(
+                "tensor",
+                Self::VT_TENSOR,
                 false,
             )?
             .finish();
         Ok(())
     }
 }
-pub struct TransposeAttrsArgs<'a> {
-    pub perm: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+pub struct CustomAttrArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub type_: CustomAttrType,
+    pub int_value: i64,
+    pub float_value: f32,
+    pub string_value: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub ints: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i64>>>,
+    pub floats: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+    pub tensor: Option<flatbuffers::WIPOffset<ConstantNode<'a>>>,
 }
-impl<'a> Default for TransposeAttrsArgs<'a> {
+impl<'a> Default for CustomAttrArgs<'a> {
     #[inline]
     fn default() -> Self {
-        TransposeAttrsArgs { perm: None }
+        CustomAttrArgs {
+            name: None, // required field
+            type_: CustomAttrType::Int,
+            int_value: 0,
+            float_value: 0.0,
+            string_value: None,
+            ints: None,
+            floats: None,
+            tensor: None,
+        }
     }
 }
 
-pub struct TransposeAttrsBuilder<'a: 'b, 'b> {
+pub struct CustomAttrBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> TransposeAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> CustomAttrBuilder<'a, 'b> {
     #[inline]
-    pub fn add_perm(&mut self, perm: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+    pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(TransposeAttrs::VT_PERM, perm);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(CustomAttr::VT_NAME, name);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TransposeAttrsBuilder<'a, 'b> {
+    pub fn add_type_(&mut self, type_: CustomAttrType) {
+        self.fbb_
+            .push_slot::<CustomAttrType>(CustomAttr::VT_TYPE_, type_, CustomAttrType::Int);
+    }
+    #[inline]
+    pub fn add_int_value(&mut self, int_value: i64) {
+        self.fbb_
+            .push_slot::<i64>(CustomAttr::VT_INT_VALUE, int_value, 0);
+    }
+    #[inline]
+    pub fn add_float_value(&mut self, float_value: f32) {
+        self.fbb_
+            .push_slot::<f32>(CustomAttr::VT_FLOAT_VALUE, float_value, 0.0);
+    }
+    #[inline]
+    pub fn add_string_value(&mut self, string_value: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            CustomAttr::VT_STRING_VALUE,
+            string_value,
+        );
+    }
+    #[inline]
+    pub fn add_ints(&mut self, ints: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i64>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(CustomAttr::VT_INTS, ints);
+    }
+    #[inline]
+    pub fn add_floats(&mut self, floats: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(CustomAttr::VT_FLOATS, floats);
+    }
+    #[inline]
+    pub fn add_tensor(&mut self, tensor: flatbuffers::WIPOffset<ConstantNode<'b>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<ConstantNode>>(
+                CustomAttr::VT_TENSOR,
+                tensor,
+            );
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CustomAttrBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        TransposeAttrsBuilder {
+        CustomAttrBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<TransposeAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<CustomAttr<'a>> {
         let o = self.fbb_.end_table(self.start_);
+        self.fbb_.required(o, CustomAttr::VT_NAME, "name");
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for TransposeAttrs<'_> {
+impl core::fmt::Debug for CustomAttr<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("TransposeAttrs");
-        ds.field("perm", &self.perm());
+        let mut ds = f.debug_struct("CustomAttr");
+        ds.field("name", &self.name());
+        ds.field("type_", &self.type_());
+        ds.field("int_value", &self.int_value());
+        ds.field("float_value", &self.float_value());
+        ds.field("string_value", &self.string_value());
+        ds.field("ints", &self.ints());
+        ds.field("floats", &self.floats());
+        ds.field("tensor", &self.tensor());
         ds.finish()
     }
 }
-pub enum TriluAttrsOffset {}
+pub enum CustomAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct TriluAttrs<'a> {
+pub struct CustomAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for TriluAttrs<'a> {
-    type Inner = TriluAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for CustomAttrs<'a> {
+    type Inner = CustomAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5927,37 +8510,70 @@ impl<'a> flatbuffers::Follow<'a> for TriluAttrs<'a> {
     }
 }
 
-impl<'a> TriluAttrs<'a> {
-    pub const VT_UPPER: flatbuffers::VOffsetT = 4;
+impl<'a> CustomAttrs<'a> {
+    pub const VT_OP_TYPE: flatbuffers::VOffsetT = 4;
+    pub const VT_DOMAIN: flatbuffers::VOffsetT = 6;
+    pub const VT_ATTRS: flatbuffers::VOffsetT = 8;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        TriluAttrs { _tab: table }
+        CustomAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args TriluAttrsArgs,
-    ) -> flatbuffers::WIPOffset<TriluAttrs<'bldr>> {
-        let mut builder = TriluAttrsBuilder::new(_fbb);
-        builder.add_upper(args.upper);
+        args: &'args CustomAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<CustomAttrs<'bldr>> {
+        let mut builder = CustomAttrsBuilder::new(_fbb);
+        if let Some(x) = args.attrs {
+            builder.add_attrs(x);
+        }
+        if let Some(x) = args.domain {
+            builder.add_domain(x);
+        }
+        if let Some(x) = args.op_type {
+            builder.add_op_type(x);
+        }
         builder.finish()
     }
 
     #[inline]
-    pub fn upper(&self) -> bool {
+    pub fn op_type(&self) -> &'a str {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<bool>(TriluAttrs::VT_UPPER, Some(false))
+                .get::<flatbuffers::ForwardsUOffset<&str>>(CustomAttrs::VT_OP_TYPE, None)
                 .unwrap()
         }
     }
+    #[inline]
+    pub fn domain(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(CustomAttrs::VT_DOMAIN, None)
+        }
+    }
+    #[inline]
+    pub fn attrs(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CustomAttr<'a>>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CustomAttr>>,
+            >>(CustomAttrs::VT_ATTRS, None)
+        }
+    }
 }
 
-impl flatbuffers::Verifiable for TriluAttrs<'_> {
+impl flatbuffers::Verifiable for CustomAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5965,50 +8581,82 @@ impl flatbuffers::Verifiable for TriluAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<bool>("upper", Self::VT_UPPER, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("op_type", Self::VT_OP_TYPE, true)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("domain", Self::VT_DOMAIN, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<CustomAttr>>,
+            >>("attrs", Self::VT_ATTRS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct TriluAttrsArgs {
-    pub upper: bool,
+pub struct CustomAttrsArgs<'a> {
+    pub op_type: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub domain: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub attrs: Option<
+        flatbuffers::WIPOffset<
+            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CustomAttr<'a>>>,
+        >,
+    >,
 }
-impl<'a> Default for TriluAttrsArgs {
+impl<'a> Default for CustomAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        TriluAttrsArgs { upper: false }
+        CustomAttrsArgs {
+            op_type: None, // required field
+            domain: None,
+            attrs: None,
+        }
     }
 }
 
-pub struct TriluAttrsBuilder<'a: 'b, 'b> {
+pub struct CustomAttrsBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> TriluAttrsBuilder<'a, 'b> {
+impl<'a: 'b, 'b> CustomAttrsBuilder<'a, 'b> {
     #[inline]
-    pub fn add_upper(&mut self, upper: bool) {
+    pub fn add_op_type(&mut self, op_type: flatbuffers::WIPOffset<&'b str>) {
         self.fbb_
-            .push_slot::<bool>(TriluAttrs::VT_UPPER, upper, false);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(CustomAttrs::VT_OP_TYPE, op_type);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TriluAttrsBuilder<'a, 'b> {
+    pub fn add_domain(&mut self, domain: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(CustomAttrs::VT_DOMAIN, domain);
+    }
+    #[inline]
+    pub fn add_attrs(
+        &mut self,
+        attrs: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<CustomAttr<'b>>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(CustomAttrs::VT_ATTRS, attrs);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CustomAttrsBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        TriluAttrsBuilder {
+        CustomAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<TriluAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<CustomAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
+        self.fbb_.required(o, CustomAttrs::VT_OP_TYPE, "op_type");
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for TriluAttrs<'_> {
+impl core::fmt::Debug for CustomAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("TriluAttrs");
-        ds.field("upper", &self.upper());
+        let mut ds = f.debug_struct("CustomAttrs");
+        ds.field("op_type", &self.op_type());
+        ds.field("domain", &self.domain());
+        ds.field("attrs", &self.attrs());
         ds.finish()
     }
 }
@@ -6585,6 +9233,141 @@ impl<'a> OperatorNode<'a> {
             None
         }
     }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_custom_attrs(&self) -> Option<CustomAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::CustomAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { CustomAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_lp_pool_attrs(&self) -> Option<LpPoolAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::LpPoolAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { LpPoolAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_global_lp_pool_attrs(&self) -> Option<GlobalLpPoolAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::GlobalLpPoolAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { GlobalLpPoolAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_pad_attrs(&self) -> Option<PadAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::PadAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { PadAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_rnnattrs(&self) -> Option<RNNAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::RNNAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { RNNAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_attention_attrs(&self) -> Option<AttentionAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::AttentionAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { AttentionAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_group_normalization_attrs(&self) -> Option<GroupNormalizationAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::GroupNormalizationAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { GroupNormalizationAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_gelu_attrs(&self) -> Option<GeluAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::GeluAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { GeluAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_selu_attrs(&self) -> Option<SeluAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::SeluAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { SeluAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
 }
 
 impl flatbuffers::Verifiable for OperatorNode<'_> {
@@ -6629,6 +9412,15 @@ impl flatbuffers::Verifiable for OperatorNode<'_> {
           OperatorAttrs::NonMaxSuppressionAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<NonMaxSuppressionAttrs>>("OperatorAttrs::NonMaxSuppressionAttrs", pos),
           OperatorAttrs::LayerNormalizationAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<LayerNormalizationAttrs>>("OperatorAttrs::LayerNormalizationAttrs", pos),
           OperatorAttrs::RandomUniformAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RandomUniformAttrs>>("OperatorAttrs::RandomUniformAttrs", pos),
+          OperatorAttrs::CustomAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<CustomAttrs>>("OperatorAttrs::CustomAttrs", pos),
+          OperatorAttrs::LpPoolAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<LpPoolAttrs>>("OperatorAttrs::LpPoolAttrs", pos),
+          OperatorAttrs::GlobalLpPoolAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GlobalLpPoolAttrs>>("OperatorAttrs::GlobalLpPoolAttrs", pos),
+          OperatorAttrs::PadAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PadAttrs>>("OperatorAttrs::PadAttrs", pos),
+          OperatorAttrs::RNNAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RNNAttrs>>("OperatorAttrs::RNNAttrs", pos),
+          OperatorAttrs::AttentionAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<AttentionAttrs>>("OperatorAttrs::AttentionAttrs", pos),
+          OperatorAttrs::GroupNormalizationAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GroupNormalizationAttrs>>("OperatorAttrs::GroupNormalizationAttrs", pos),
+          OperatorAttrs::GeluAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GeluAttrs>>("OperatorAttrs::GeluAttrs", pos),
+          OperatorAttrs::SeluAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SeluAttrs>>("OperatorAttrs::SeluAttrs", pos),
           _ => Ok(()),
         }
      })?
@@ -6902,8 +9694,98 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::SoftmaxAttrs => {
-                if let Some(x) = self.attrs_as_softmax_attrs() {
+            OperatorAttrs::SoftmaxAttrs => {
+                if let Some(x) = self.attrs_as_softmax_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::TransposeAttrs => {
+                if let Some(x) = self.attrs_as_transpose_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::ModAttrs => {
+                if let Some(x) = self.attrs_as_mod_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::ScatterElementsAttrs => {
+                if let Some(x) = self.attrs_as_scatter_elements_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::OneHotAttrs => {
+                if let Some(x) = self.attrs_as_one_hot_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::TopKAttrs => {
+                if let Some(x) = self.attrs_as_top_kattrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::HardSigmoidAttrs => {
+                if let Some(x) = self.attrs_as_hard_sigmoid_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::TriluAttrs => {
+                if let Some(x) = self.attrs_as_trilu_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::ScatterNDAttrs => {
+                if let Some(x) = self.attrs_as_scatter_ndattrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::NonMaxSuppressionAttrs => {
+                if let Some(x) = self.attrs_as_non_max_suppression_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -6912,8 +9794,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::TransposeAttrs => {
-                if let Some(x) = self.attrs_as_transpose_attrs() {
+            OperatorAttrs::LayerNormalizationAttrs => {
+                if let Some(x) = self.attrs_as_layer_normalization_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -6922,8 +9804,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::ModAttrs => {
-                if let Some(x) = self.attrs_as_mod_attrs() {
+            OperatorAttrs::RandomUniformAttrs => {
+                if let Some(x) = self.attrs_as_random_uniform_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -6932,8 +9814,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::ScatterElementsAttrs => {
-                if let Some(x) = self.attrs_as_scatter_elements_attrs() {
+            OperatorAttrs::CustomAttrs => {
+                if let Some(x) = self.attrs_as_custom_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -6942,8 +9824,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::OneHotAttrs => {
-                if let Some(x) = self.attrs_as_one_hot_attrs() {
+            OperatorAttrs::LpPoolAttrs => {
+                if let Some(x) = self.attrs_as_lp_pool_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -6952,8 +9834,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::TopKAttrs => {
-                if let Some(x) = self.attrs_as_top_kattrs() {
+            OperatorAttrs::GlobalLpPoolAttrs => {
+                if let Some(x) = self.attrs_as_global_lp_pool_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -6962,8 +9844,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::HardSigmoidAttrs => {
-                if let Some(x) = self.attrs_as_hard_sigmoid_attrs() {
+            OperatorAttrs::PadAttrs => {
+                if let Some(x) = self.attrs_as_pad_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -6972,8 +9854,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::TriluAttrs => {
-                if let Some(x) = self.attrs_as_trilu_attrs() {
+            OperatorAttrs::RNNAttrs => {
+                if let Some(x) = self.attrs_as_rnnattrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -6982,8 +9864,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::ScatterNDAttrs => {
-                if let Some(x) = self.attrs_as_scatter_ndattrs() {
+            OperatorAttrs::AttentionAttrs => {
+                if let Some(x) = self.attrs_as_attention_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -6992,8 +9874,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::NonMaxSuppressionAttrs => {
-                if let Some(x) = self.attrs_as_non_max_suppression_attrs() {
+            OperatorAttrs::GroupNormalizationAttrs => {
+                if let Some(x) = self.attrs_as_group_normalization_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7002,8 +9884,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::LayerNormalizationAttrs => {
-                if let Some(x) = self.attrs_as_layer_normalization_attrs() {
+            OperatorAttrs::GeluAttrs => {
+                if let Some(x) = self.attrs_as_gelu_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7012,8 +9894,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::RandomUniformAttrs => {
-                if let Some(x) = self.attrs_as_random_uniform_attrs() {
+            OperatorAttrs::SeluAttrs => {
+                if let Some(x) = self.attrs_as_selu_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -7260,6 +10142,234 @@ impl core::fmt::Debug for IntData<'_> {
         ds.finish()
     }
 }
+pub enum Float16DataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Float16Data<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Float16Data<'a> {
+    type Inner = Float16Data<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> Float16Data<'a> {
+    pub const VT_DATA: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Float16Data { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args Float16DataArgs<'args>,
+    ) -> flatbuffers::WIPOffset<Float16Data<'bldr>> {
+        let mut builder = Float16DataBuilder::new(_fbb);
+        if let Some(x) = args.data {
+            builder.add_data(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn data(&self) -> flatbuffers::Vector<'a, u16> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u16>>>(
+                    Float16Data::VT_DATA,
+                    None,
+                )
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for Float16Data<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u16>>>(
+                "data",
+                Self::VT_DATA,
+                true,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct Float16DataArgs<'a> {
+    pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u16>>>,
+}
+impl<'a> Default for Float16DataArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        Float16DataArgs {
+            data: None, // required field
+        }
+    }
+}
+
+pub struct Float16DataBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> Float16DataBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u16>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Float16Data::VT_DATA, data);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> Float16DataBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        Float16DataBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<Float16Data<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        self.fbb_.required(o, Float16Data::VT_DATA, "data");
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for Float16Data<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("Float16Data");
+        ds.field("data", &self.data());
+        ds.finish()
+    }
+}
+pub enum BFloat16DataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct BFloat16Data<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for BFloat16Data<'a> {
+    type Inner = BFloat16Data<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> BFloat16Data<'a> {
+    pub const VT_DATA: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        BFloat16Data { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args BFloat16DataArgs<'args>,
+    ) -> flatbuffers::WIPOffset<BFloat16Data<'bldr>> {
+        let mut builder = BFloat16DataBuilder::new(_fbb);
+        if let Some(x) = args.data {
+            builder.add_data(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn data(&self) -> flatbuffers::Vector<'a, u16> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u16>>>(
+                    BFloat16Data::VT_DATA,
+                    None,
+                )
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for BFloat16Data<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u16>>>(
+                "data",
+                Self::VT_DATA,
+                true,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct BFloat16DataArgs<'a> {
+    pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u16>>>,
+}
+impl<'a> Default for BFloat16DataArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        BFloat16DataArgs {
+            data: None, // required field
+        }
+    }
+}
+
+pub struct BFloat16DataBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> BFloat16DataBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u16>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(BFloat16Data::VT_DATA, data);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> BFloat16DataBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        BFloat16DataBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<BFloat16Data<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        self.fbb_.required(o, BFloat16Data::VT_DATA, "data");
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for BFloat16Data<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("BFloat16Data");
+        ds.field("data", &self.data());
+        ds.finish()
+    }
+}
 pub enum ConstantNodeOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
@@ -7368,6 +10478,34 @@ impl<'a> ConstantNode<'a> {
             None
         }
     }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn data_as_float_16_data(&self) -> Option<Float16Data<'a>> {
+        if self.data_type() == ConstantData::Float16Data {
+            let u = self.data();
+            // Safety:
+            // Created from a valid Table for this object
+            // Which contains a valid union in this slot
+            Some(unsafe { Float16Data::init_from_table(u) })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn data_as_bfloat_16_data(&self) -> Option<BFloat16Data<'a>> {
+        if self.data_type() == ConstantData::BFloat16Data {
+            let u = self.data();
+            // Safety:
+            // Created from a valid Table for this object
+            // Which contains a valid union in this slot
+            Some(unsafe { BFloat16Data::init_from_table(u) })
+        } else {
+            None
+        }
+    }
 }
 
 impl flatbuffers::Verifiable for ConstantNode<'_> {
@@ -7400,6 +10538,16 @@ impl flatbuffers::Verifiable for ConstantNode<'_> {
                             "ConstantData::IntData",
                             pos,
                         ),
+                    ConstantData::Float16Data => v
+                        .verify_union_variant::<flatbuffers::ForwardsUOffset<Float16Data>>(
+                            "ConstantData::Float16Data",
+                            pos,
+                        ),
+                    ConstantData::BFloat16Data => v
+                        .verify_union_variant::<flatbuffers::ForwardsUOffset<BFloat16Data>>(
+                            "ConstantData::BFloat16Data",
+                            pos,
+                        ),
                     _ => Ok(()),
                 },
             )?
@@ -7489,6 +10637,26 @@ impl core::fmt::Debug for ConstantNode<'_> {
                     )
                 }
             }
+            ConstantData::Float16Data => {
+                if let Some(x) = self.data_as_float_16_data() {
+                    ds.field("data", &x)
+                } else {
+                    ds.field(
+                        "data",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            ConstantData::BFloat16Data => {
+                if let Some(x) = self.data_as_bfloat_16_data() {
+                    ds.field("data", &x)
+                } else {
+                    ds.field(
+                        "data",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
             _ => {
                 let x: Option<()> = None;
                 ds.field("data", &x)
@@ -7638,6 +10806,7 @@ impl<'a> flatbuffers::Follow<'a> for ValueNode<'a> {
 
 impl<'a> ValueNode<'a> {
     pub const VT_SHAPE: flatbuffers::VOffsetT = 4;
+    pub const VT_DTYPE: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -7652,6 +10821,9 @@ impl<'a> ValueNode<'a> {
         if let Some(x) = args.shape {
             builder.add_shape(x);
         }
+        if let Some(x) = args.dtype {
+            builder.add_dtype(x);
+        }
         builder.finish()
     }
 
@@ -7666,6 +10838,13 @@ impl<'a> ValueNode<'a> {
             >>(ValueNode::VT_SHAPE, None)
         }
     }
+    #[inline]
+    pub fn dtype(&self) -> Option<DataType> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<DataType>(ValueNode::VT_DTYPE, None) }
+    }
 }
 
 impl flatbuffers::Verifiable for ValueNode<'_> {
@@ -7679,6 +10858,7 @@ impl flatbuffers::Verifiable for ValueNode<'_> {
             .visit_field::<flatbuffers::ForwardsUOffset<
                 flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Dim>>,
             >>("shape", Self::VT_SHAPE, false)?
+            .visit_field::<DataType>("dtype", Self::VT_DTYPE, false)?
             .finish();
         Ok(())
     }
@@ -7687,11 +10867,15 @@ pub struct ValueNodeArgs<'a> {
     pub shape: Option<
         flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Dim<'a>>>>,
     >,
+    pub dtype: Option<DataType>,
 }
 impl<'a> Default for ValueNodeArgs<'a> {
     #[inline]
     fn default() -> Self {
-        ValueNodeArgs { shape: None }
+        ValueNodeArgs {
+            shape: None,
+            dtype: None,
+        }
     }
 }
 
@@ -7711,6 +10895,11 @@ impl<'a: 'b, 'b> ValueNodeBuilder<'a, 'b> {
             .push_slot_always::<flatbuffers::WIPOffset<_>>(ValueNode::VT_SHAPE, shape);
     }
     #[inline]
+    pub fn add_dtype(&mut self, dtype: DataType) {
+        self.fbb_
+            .push_slot_always::<DataType>(ValueNode::VT_DTYPE, dtype);
+    }
+    #[inline]
     pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ValueNodeBuilder<'a, 'b> {
         let start = _fbb.start_table();
         ValueNodeBuilder {
@@ -7729,6 +10918,7 @@ impl core::fmt::Debug for ValueNode<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let mut ds = f.debug_struct("ValueNode");
         ds.field("shape", &self.shape());
+        ds.field("dtype", &self.dtype());
         ds.finish()
     }
 }
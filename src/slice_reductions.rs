@@ -43,12 +43,34 @@ pub fn slice_sum<T: Copy + Default + std::ops::Add<Output = T>>(xs: &[T]) -> T {
         .fold(T::default(), |x, y| x + y)
 }
 
+/// Return the sum of squares of `xs - sub` for a slice of numbers.
+///
+/// Passing the mean of `xs` as `sub` gives the sum of squared deviations,
+/// used to compute variance.
+pub fn slice_sum_square(xs: &[f32], sub: f32) -> f32 {
+    const CHUNK_SIZE: usize = 8;
+    xs.chunks(CHUNK_SIZE)
+        .map(|chunk| {
+            if chunk.len() == CHUNK_SIZE {
+                // Writing the code this way encourages better autovectorization.
+                let d: [f32; CHUNK_SIZE] = std::array::from_fn(|i| chunk[i] - sub);
+                let x = [d[0] * d[0], d[1] * d[1], d[2] * d[2], d[3] * d[3]];
+                let y = [d[4] * d[4], d[5] * d[5], d[6] * d[6], d[7] * d[7]];
+                let z = [x[0] + y[0], x[1] + y[1], x[2] + y[2], x[3] + y[3]];
+                z[0] + z[1] + z[2] + z[3]
+            } else {
+                chunk.iter().fold(0., |sum, x| sum + (x - sub) * (x - sub))
+            }
+        })
+        .sum()
+}
+
 #[cfg(test)]
 mod tests {
     use rten_tensor::rng::XorShiftRng;
     use rten_tensor::test_util::ApproxEq;
 
-    use super::{slice_max, slice_sum};
+    use super::{slice_max, slice_sum, slice_sum_square};
 
     #[test]
     fn test_slice_max() {
@@ -69,4 +91,14 @@ mod tests {
             .collect();
         assert!(xs.iter().sum::<f32>().approx_eq(&slice_sum(&xs)));
     }
+
+    #[test]
+    fn test_slice_sum_square() {
+        let mut rng = XorShiftRng::new(1234);
+        let xs: Vec<_> = std::iter::from_fn(|| Some(rng.next_f32()))
+            .take(259)
+            .collect();
+        let expected: f32 = xs.iter().map(|x| (x - 0.5) * (x - 0.5)).sum();
+        assert!(expected.approx_eq(&slice_sum_square(&xs, 0.5)));
+    }
 }
@@ -3,11 +3,13 @@ use std::fmt;
 
 use smallvec::SmallVec;
 
+use crate::ops::OpCost;
+
 /// Trait for text data table sources.
 ///
 /// Tables can be formatted using [Table::display] to get a wrapper that
 /// implements [Display].
-trait Table {
+pub(crate) trait Table {
     /// Return the number of rows in this table.
     fn rows(&self) -> usize;
 
@@ -39,7 +41,7 @@ trait Table {
     }
 }
 
-struct DisplayTable<'a, T: Table> {
+pub(crate) struct DisplayTable<'a, T: Table> {
     table: &'a T,
     indent: usize,
 }
@@ -152,6 +154,18 @@ struct TimingByShapeRecord {
     ///
     /// This count is for each run of the operator.
     input_elements: usize,
+
+    /// Estimated cost of each run of the operator, if available.
+    cost: Option<OpCost>,
+}
+
+/// Format an operation rate as a GFLOP/s or GB/s figure, given a count of
+/// FLOPs or bytes and a time in milliseconds.
+fn format_giga_rate(count: u64, time_ms: f32) -> String {
+    if time_ms <= 0. {
+        return String::new();
+    }
+    format!("{:.2}", count as f64 / (time_ms as f64 * 1e6))
 }
 
 /// [Display]-able table containing a breakdown of operator execution time
@@ -166,7 +180,15 @@ impl Table for TimingByShapeTable {
     }
 
     fn headings(&self) -> &[&str] {
-        &["Shape", "Count", "Mean (ms)", "Total (ms)", "ns/input elem"]
+        &[
+            "Shape",
+            "Count",
+            "Mean (ms)",
+            "Total (ms)",
+            "ns/input elem",
+            "GFLOP/s",
+            "GB/s",
+        ]
     }
 
     fn cell(&self, row: usize, col: usize) -> String {
@@ -180,6 +202,17 @@ impl Table for TimingByShapeTable {
                 "{:.3}",
                 (row.total_ms * 1_000_000.0) / (row.input_elements * row.count) as f32
             ),
+            5 => row
+                .cost
+                .map(|cost| format_giga_rate(cost.flops * row.count as u64, row.total_ms))
+                .unwrap_or_default(),
+            6 => row
+                .cost
+                .map(|cost| {
+                    let bytes = cost.bytes_read + cost.bytes_written;
+                    format_giga_rate(bytes * row.count as u64, row.total_ms)
+                })
+                .unwrap_or_default(),
             _ => panic!("invalid column"),
         }
     }
@@ -242,21 +275,22 @@ impl<'a> FormattedRunTiming<'a> {
                             .unwrap_or(0)
                     })
                     .sum::<usize>();
-                let (cum_time, count, _) =
+                let (cum_time, count, _, _) =
                     timings
                         .entry(formatted_shapes)
-                        .or_insert((0., 0, input_elements));
+                        .or_insert((0., 0, input_elements, record.cost));
                 *cum_time += record.elapsed_micros / 1000.0;
                 *count += 1;
                 timings
             })
             .into_iter()
             .map(
-                |(shape, (total_ms, count, input_elements))| TimingByShapeRecord {
+                |(shape, (total_ms, count, input_elements, cost))| TimingByShapeRecord {
                     shape,
                     total_ms,
                     count,
                     input_elements,
+                    cost,
                 },
             )
             .collect();
@@ -271,13 +305,21 @@ impl<'a> FormattedRunTiming<'a> {
 
 impl<'a> fmt::Display for FormattedRunTiming<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
+        // Total time and FLOPs for each operator type. FLOPs are only
+        // counted for runs where the operator provided an estimate, so the
+        // time for those runs is tracked separately.
         let mut op_timings: Vec<_> = self
             .timing
             .records
             .iter()
             .fold(HashMap::new(), |mut timings, record| {
-                let total_op_time = timings.entry(record.name.as_str()).or_insert(0.);
+                let (total_op_time, flops, flops_time) =
+                    timings.entry(record.name.as_str()).or_insert((0., 0, 0.));
                 *total_op_time += record.elapsed_micros / 1000.0;
+                if let Some(cost) = record.cost {
+                    *flops += cost.flops;
+                    *flops_time += record.elapsed_micros / 1000.0;
+                }
                 timings
             })
             .into_iter()
@@ -287,36 +329,55 @@ impl<'a> fmt::Display for FormattedRunTiming<'a> {
         let total_op_time = self.timing.total_op_time();
         op_timings.push((
             "[Other]",
-            self.timing.total_time - total_op_time - self.timing.alloc_time,
+            (
+                self.timing.total_time - total_op_time - self.timing.alloc_time,
+                0,
+                0.,
+            ),
         ));
-        op_timings.push(("[Mem alloc/free]", self.timing.alloc_time));
+        op_timings.push(("[Mem alloc/free]", (self.timing.alloc_time, 0, 0.)));
 
-        op_timings.sort_by(|(a_name, a_time), (b_name, b_time)| match self.sort {
-            TimingSort::ByName => a_name.cmp(b_name),
-            TimingSort::ByTime => a_time.total_cmp(b_time).reverse(),
-        });
+        op_timings.sort_by(
+            |(a_name, (a_time, _, _)), (b_name, (b_time, _, _))| match self.sort {
+                TimingSort::ByName => a_name.cmp(b_name),
+                TimingSort::ByTime => a_time.total_cmp(b_time).reverse(),
+            },
+        );
 
         let rows: Vec<_> = op_timings
             .iter()
-            .map(|(op_name, op_total_time)| {
+            .map(|(op_name, (op_total_time, flops, flops_time))| {
                 let run_percent = (*op_total_time / self.timing.total_time) * 100.;
+                let gflops = if *flops > 0 && *flops_time > 0. {
+                    format!("{} GFLOP/s", format_giga_rate(*flops, *flops_time))
+                } else {
+                    String::new()
+                };
                 [
                     op_name.to_string(),
                     format!("{:.2}ms", op_total_time),
                     format!("({:.2}%)", run_percent),
+                    gflops,
                 ]
             })
             .collect();
-        let col_widths: Vec<usize> = (0..3)
+        let col_widths: Vec<usize> = (0..4)
             .map(|col| rows.iter().fold(0, |width, row| row[col].len().max(width)))
             .collect();
 
         for row in rows {
-            writeln!(
-                f,
-                "{0:1$} {2:3$} {4:5$}",
-                row[0], col_widths[0], row[1], col_widths[1], row[2], col_widths[2]
-            )?;
+            let line = format!(
+                "{0:1$} {2:3$} {4:5$} {6:7$}",
+                row[0],
+                col_widths[0],
+                row[1],
+                col_widths[1],
+                row[2],
+                col_widths[2],
+                row[3],
+                col_widths[3]
+            );
+            writeln!(f, "{}", line.trim_end())?;
 
             let op_name = &row[0];
             if self.include_shapes && !op_name.starts_with('[') {
@@ -346,10 +407,13 @@ pub struct TimingRecord {
 
     /// Execution time of this step in microseconds
     pub elapsed_micros: f32,
+
+    /// Estimated cost of this step, if the operator provides one.
+    pub cost: Option<OpCost>,
 }
 
 /// Specifies sort order for graph run timings.
-#[derive(Default)]
+#[derive(Clone, Copy, Default)]
 pub enum TimingSort {
     /// Sort timings by operator name
     ByName,
@@ -8,7 +8,7 @@ use wasm_bindgen::prelude::*;
 
 use crate::graph::Dimension;
 use crate::model;
-use crate::ops::{matmul, Input, Output};
+use crate::ops::{matmul, DataType, Input, Output};
 
 #[wasm_bindgen]
 pub struct Model {
@@ -38,6 +38,7 @@ impl Model {
         self.model.node_info(id).map(|ni| NodeInfo {
             name: ni.name().map(|n| n.to_string()),
             shape: ni.shape(),
+            dtype: ni.dtype(),
         })
     }
 
@@ -90,6 +91,7 @@ impl Model {
 pub struct NodeInfo {
     name: Option<String>,
     shape: Option<Vec<Dimension>>,
+    dtype: Option<DataType>,
 }
 
 #[wasm_bindgen]
@@ -116,6 +118,22 @@ impl NodeInfo {
                 .collect()
         })
     }
+
+    /// Returns the element type of a node in the graph, if known.
+    ///
+    /// This is one of "float32", "int32", "int64", "bool" or "uint8".
+    pub fn dtype(&self) -> Option<String> {
+        self.dtype.map(|dtype| {
+            match dtype {
+                DataType::Float => "float32",
+                DataType::Int32 => "int32",
+                DataType::Int64 => "int64",
+                DataType::Bool => "bool",
+                DataType::UInt8 => "uint8",
+            }
+            .to_string()
+        })
+    }
 }
 
 /// A wrapper around a multi-dimensional array model input or output.
@@ -7,8 +7,8 @@ from debug_utils import read_tensor
 
 def main():
     parser = ArgumentParser(description="Compare two binary tensors")
-    parser.add_argument('tensor_a', help="File containing first tensor")
-    parser.add_argument('tensor_b', help="File containing second_tensor")
+    parser.add_argument('tensor_a', help="File containing first tensor (binary or .npy)")
+    parser.add_argument('tensor_b', help="File containing second tensor (binary or .npy)")
     args = parser.parse_args()
 
     x = read_tensor(args.tensor_a)
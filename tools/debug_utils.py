@@ -1,4 +1,6 @@
 import math
+import os
+import re
 import struct
 
 import numpy as np
@@ -7,11 +9,14 @@ def read_tensor(path: str) -> np.ndarray:
     """
     Read a tensor from a file.
 
-    The file is expected to contain the tensor data in the little-endian
-    binary format:
+    Files with a `.npy` extension are loaded using `np.load`. Other files are
+    expected to contain the tensor data in the little-endian binary format:
 
     [rank:u32][dims:u32 * rank][data:f32 * product(dims)]
     """
+    if path.endswith('.npy'):
+        return np.load(path)
+
     with open(path, 'rb') as file:
         ndim, = struct.unpack('<I', file.read(4))
         dims = struct.unpack('<' + 'I'*ndim, file.read(4 * ndim))
@@ -31,3 +36,16 @@ def write_tensor(tensor: np.ndarray, path: str):
         file.write(struct.pack('<' + 'I'*tensor.ndim, *tensor.shape))
         file.write(struct.pack('<' + 'f'*tensor.size, *list(tensor.flatten())))
 
+
+def write_reference_tensors(values: dict[str, np.ndarray], dir: str):
+    """
+    Write tensors to `.npy` files in a directory, for use with
+    `rten --reference`.
+
+    Files are named after the keys of `values`, with characters other than
+    ASCII letters, digits, `-`, `_` and `.` replaced with `_`.
+    """
+    os.makedirs(dir, exist_ok=True)
+    for name, value in values.items():
+        file_stem = re.sub(r'[^A-Za-z0-9\-_.]', '_', name)
+        np.save(os.path.join(dir, file_stem + '.npy'), value)